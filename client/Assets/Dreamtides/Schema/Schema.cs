@@ -152,6 +152,13 @@ namespace Dreamtides.Schema
         [JsonProperty("enemy")]
         public PlayerType Enemy { get; set; }
 
+        /// <summary>
+        /// If specified, the enemy's AI search will be biased toward this play
+        /// style.
+        /// </summary>
+        [JsonProperty("enemy_personality")]
+        public AiPersonality? EnemyPersonality { get; set; }
+
         /// <summary>
         /// If specified, the battle will be seeded with the given value. Otherwise
         /// a random seed will be used.
@@ -176,6 +183,9 @@ namespace Dreamtides.Schema
 
         [JsonProperty("MonteCarloSingleThreaded", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
         public long? MonteCarloSingleThreaded { get; set; }
+
+        [JsonProperty("Difficulty", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
+        public AiDifficulty? Difficulty { get; set; }
     }
 
     public partial class DisplayProperties
@@ -259,6 +269,9 @@ namespace Dreamtides.Schema
         [JsonProperty("UpdateQuest", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
         public UpdateQuestCommand UpdateQuest { get; set; }
 
+        [JsonProperty("UpdateCollection", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
+        public UpdateCollectionCommand UpdateCollection { get; set; }
+
         [JsonProperty("Wait", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
         public Milliseconds Wait { get; set; }
 
@@ -306,6 +319,9 @@ namespace Dreamtides.Schema
 
         [JsonProperty("AnchorToScreenPosition", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
         public AnchorToScreenPositionCommand AnchorToScreenPosition { get; set; }
+
+        [JsonProperty("DisplayCinematic", Required = Required.DisallowNull, NullValueHandling = NullValueHandling.Ignore)]
+        public DisplayCinematicCommand DisplayCinematic { get; set; }
     }
 
     public partial class AnchorToScreenPositionCommand
@@ -1381,6 +1397,50 @@ namespace Dreamtides.Schema
         public Milliseconds ShowDuration { get; set; }
     }
 
+    /// <summary>
+    /// Plays a full-screen cinematic marking a change in the flow of the battle.
+    /// </summary>
+    public partial class DisplayCinematicCommand
+    {
+        /// <summary>
+        /// The cinematic to play.
+        /// </summary>
+        [JsonProperty("cinematic", Required = Required.Always)]
+        public CinematicType Cinematic { get; set; }
+
+        /// <summary>
+        /// The player the cinematic is about: the player whose turn is starting,
+        /// the player being judged, or the winner of the battle.
+        /// </summary>
+        [JsonProperty("player", Required = Required.Always)]
+        public DisplayPlayer Player { get; set; }
+
+        /// <summary>
+        /// Name to display for `player`.
+        /// </summary>
+        [JsonProperty("player_name", Required = Required.Always)]
+        public string PlayerName { get; set; }
+
+        /// <summary>
+        /// Points gained by `player` as a result of this event. Zero if no points
+        /// were gained.
+        /// </summary>
+        [JsonProperty("points_delta", Required = Required.Always)]
+        public long PointsDelta { get; set; }
+
+        /// <summary>
+        /// Score of `player` after this event.
+        /// </summary>
+        [JsonProperty("score", Required = Required.Always)]
+        public long Score { get; set; }
+
+        /// <summary>
+        /// Turn number during which the cinematic is played.
+        /// </summary>
+        [JsonProperty("turn_number", Required = Required.Always)]
+        public long TurnNumber { get; set; }
+    }
+
     public partial class DisplayJudgmentCommand
     {
         /// <summary>
@@ -1859,6 +1919,13 @@ namespace Dreamtides.Schema
         [JsonProperty("on_click")]
         public OnClickUnion? OnClick { get; set; }
 
+        /// <summary>
+        /// Preview of the battle state after this card is clicked, e.g. when it is
+        /// an option in a targeting or modal effect prompt.
+        /// </summary>
+        [JsonProperty("on_click_effect_preview")]
+        public BattlePreviewView OnClickEffectPreview { get; set; }
+
         /// <summary>
         /// Sound to play when this card is played.
         /// </summary>
@@ -1950,6 +2017,18 @@ namespace Dreamtides.Schema
     /// </summary>
     public partial class PlayerPreviewView
     {
+        /// <summary>
+        /// Number of cards this player will draw, if any
+        /// </summary>
+        [JsonProperty("cards_drawn")]
+        public long? CardsDrawn { get; set; }
+
+        /// <summary>
+        /// Number of this player's characters which will be dissolved, if any
+        /// </summary>
+        [JsonProperty("characters_dissolved")]
+        public long? CharactersDissolved { get; set; }
+
         /// <summary>
         /// New energy available to this player
         /// </summary>
@@ -2235,6 +2314,15 @@ namespace Dreamtides.Schema
         /// </summary>
         [JsonProperty("user", Required = Required.Always)]
         public PlayerView User { get; set; }
+
+        /// <summary>
+        /// Estimated probability that the user wins this battle, from 0.0 to 1.0,
+        /// used to display an evaluation bar.
+        ///
+        /// None if no estimate is available.
+        /// </summary>
+        [JsonProperty("win_probability")]
+        public double? WinProbability { get; set; }
     }
 
     public partial class DisplayArrow
@@ -2480,6 +2568,77 @@ namespace Dreamtides.Schema
         public TemptingOfferView TemptingOffer { get; set; }
     }
 
+    public partial class UpdateCollectionCommand
+    {
+        /// <summary>
+        /// The collection to display in the collection browser.
+        /// </summary>
+        [JsonProperty("collection", Required = Required.Always)]
+        public CollectionView Collection { get; set; }
+    }
+
+    /// <summary>
+    /// The collection to display in the collection browser.
+    ///
+    /// Represents the visual state of the collection browser.
+    /// </summary>
+    public partial class CollectionView
+    {
+        /// <summary>
+        /// Cards owned by the player, sorted by name.
+        /// </summary>
+        [JsonProperty("cards", Required = Required.Always)]
+        public List<CollectionCardView> Cards { get; set; }
+
+        /// <summary>
+        /// Total number of cards owned, counting each copy.
+        /// </summary>
+        [JsonProperty("total_cards", Required = Required.Always)]
+        public long TotalCards { get; set; }
+    }
+
+    /// <summary>
+    /// A card owned by the player within the collection browser.
+    /// </summary>
+    public partial class CollectionCardView
+    {
+        /// <summary>
+        /// Identifies the card definition.
+        /// </summary>
+        [JsonProperty("card_id", Required = Required.Always)]
+        public Guid CardId { get; set; }
+
+        /// <summary>
+        /// Number of copies of this card which are owned.
+        /// </summary>
+        [JsonProperty("count", Required = Required.Always)]
+        public long Count { get; set; }
+
+        /// <summary>
+        /// Image to display for this card, from its selected variant if any.
+        /// </summary>
+        [JsonProperty("image", Required = Required.Always)]
+        public DisplayImage Image { get; set; }
+
+        /// <summary>
+        /// Whether this card is displayed with a foil treatment.
+        /// </summary>
+        [JsonProperty("is_foil", Required = Required.Always)]
+        public bool IsFoil { get; set; }
+
+        /// <summary>
+        /// Name of this card.
+        /// </summary>
+        [JsonProperty("name", Required = Required.Always)]
+        public string Name { get; set; }
+
+        /// <summary>
+        /// Descriptions of how copies of this card were unlocked.
+        /// </summary>
+        [JsonProperty("unlocked_by", Required = Required.Always)]
+        public List<string> UnlockedBy { get; set; }
+    }
+
     public partial class CloseButtonView
     {
         /// <summary>
@@ -2619,8 +2778,19 @@ namespace Dreamtides.Schema
     /// </summary>
     public enum DreamwellCardIdList { DreamwellBasic5, TestDreamwellBasic5, TestDreamwellNoAbilities };
 
+    /// <summary>
+    /// Calibrated difficulty levels for AI opponents.
+    /// </summary>
+    public enum AiDifficulty { Easy, Hard, Max, Medium };
+
     public enum GameAiEnum { AlwaysPanic, FirstAvailableAction, RandomAction, WaitFiveSeconds };
 
+    /// <summary>
+    /// Play styles which bias AI opponents toward a particular strategy,
+    /// independently of their search strength.
+    /// </summary>
+    public enum AiPersonality { Aggro, Combo, Control };
+
     public enum GameActionEnum { NoOp };
 
     /// <summary>
@@ -2659,11 +2829,11 @@ namespace Dreamtides.Schema
     ///
     /// Toggles the visibility of the stack.
     /// </summary>
-    public enum BattleDisplayActionEnum { CloseCardBrowser, CloseCurrentPanel, ToggleStackVisibility };
+    public enum BattleDisplayActionEnum { CloseCardBrowser, CloseCurrentPanel, RequestHint, ToggleStackVisibility };
 
     public enum CardBrowserType { EnemyDeck, EnemyStatus, EnemyVoid, QuestDeck, UserDeck, UserStatus, UserVoid };
 
-    public enum PanelAddressEnum { AddCardToHand, Developer, PlayOpponentCard, SetOpponentAgent };
+    public enum PanelAddressEnum { AddCardToHand, AiDecision, Developer, PlayOpponentCard, SetOpponentAgent };
 
     public enum DebugActionEnum { RestartBattle, SetOpponentAsHuman };
 
@@ -2721,6 +2891,17 @@ namespace Dreamtides.Schema
 
     public enum GameMessageType { Defeat, EnemyTurn, Victory, YourTurn };
 
+    /// <summary>
+    /// The cinematic to play.
+    ///
+    /// Banner shown at the start of a player's turn.
+    ///
+    /// Sweep shown as a player's judgment phase resolves.
+    ///
+    /// Shown when a player wins the battle.
+    /// </summary>
+    public enum CinematicType { JudgmentSweep, TurnStart, Victory };
+
     /// <summary>
     /// Object position used in interface elements like the deck viewer which
     /// don't rely on game positioning.
@@ -2974,8 +3155,10 @@ namespace Dreamtides.Schema
                 LogSpanNameConverter.Singleton,
                 TestDeckNameConverter.Singleton,
                 DreamwellCardIdListConverter.Singleton,
+                AiDifficultyConverter.Singleton,
                 GameAiConverter.Singleton,
                 GameAiEnumConverter.Singleton,
+                AiPersonalityConverter.Singleton,
                 OnClickUnionConverter.Singleton,
                 BattleActionConverter.Singleton,
                 DebugBattleActionConverter.Singleton,
@@ -3014,6 +3197,7 @@ namespace Dreamtides.Schema
                 DisplayPlayerConverter.Singleton,
                 QuestObjectIdConverter.Singleton,
                 GameMessageTypeConverter.Singleton,
+                CinematicTypeConverter.Singleton,
                 PositionConverter.Singleton,
                 CardOrderSelectionTargetDiscriminantsConverter.Singleton,
                 StackTypeConverter.Singleton,
@@ -3249,6 +3433,57 @@ namespace Dreamtides.Schema
         public static readonly DreamwellCardIdListConverter Singleton = new DreamwellCardIdListConverter();
     }
 
+    internal class AiDifficultyConverter : JsonConverter
+    {
+        public override bool CanConvert(Type t) => t == typeof(AiDifficulty) || t == typeof(AiDifficulty?);
+
+        public override object ReadJson(JsonReader reader, Type t, object existingValue, JsonSerializer serializer)
+        {
+            if (reader.TokenType == JsonToken.Null) return null;
+            var value = serializer.Deserialize<string>(reader);
+            switch (value)
+            {
+                case "Easy":
+                    return AiDifficulty.Easy;
+                case "Hard":
+                    return AiDifficulty.Hard;
+                case "Max":
+                    return AiDifficulty.Max;
+                case "Medium":
+                    return AiDifficulty.Medium;
+            }
+            throw new Exception("Cannot unmarshal type AiDifficulty");
+        }
+
+        public override void WriteJson(JsonWriter writer, object untypedValue, JsonSerializer serializer)
+        {
+            if (untypedValue == null)
+            {
+                serializer.Serialize(writer, null);
+                return;
+            }
+            var value = (AiDifficulty)untypedValue;
+            switch (value)
+            {
+                case AiDifficulty.Easy:
+                    serializer.Serialize(writer, "Easy");
+                    return;
+                case AiDifficulty.Hard:
+                    serializer.Serialize(writer, "Hard");
+                    return;
+                case AiDifficulty.Max:
+                    serializer.Serialize(writer, "Max");
+                    return;
+                case AiDifficulty.Medium:
+                    serializer.Serialize(writer, "Medium");
+                    return;
+            }
+            throw new Exception("Cannot marshal type AiDifficulty");
+        }
+
+        public static readonly AiDifficultyConverter Singleton = new AiDifficultyConverter();
+    }
+
     internal class GameAiConverter : JsonConverter
     {
         public override bool CanConvert(Type t) => t == typeof(GameAi) || t == typeof(GameAi?);
@@ -3362,6 +3597,52 @@ namespace Dreamtides.Schema
         public static readonly GameAiEnumConverter Singleton = new GameAiEnumConverter();
     }
 
+    internal class AiPersonalityConverter : JsonConverter
+    {
+        public override bool CanConvert(Type t) => t == typeof(AiPersonality) || t == typeof(AiPersonality?);
+
+        public override object ReadJson(JsonReader reader, Type t, object existingValue, JsonSerializer serializer)
+        {
+            if (reader.TokenType == JsonToken.Null) return null;
+            var value = serializer.Deserialize<string>(reader);
+            switch (value)
+            {
+                case "Aggro":
+                    return AiPersonality.Aggro;
+                case "Combo":
+                    return AiPersonality.Combo;
+                case "Control":
+                    return AiPersonality.Control;
+            }
+            throw new Exception("Cannot unmarshal type AiPersonality");
+        }
+
+        public override void WriteJson(JsonWriter writer, object untypedValue, JsonSerializer serializer)
+        {
+            if (untypedValue == null)
+            {
+                serializer.Serialize(writer, null);
+                return;
+            }
+            var value = (AiPersonality)untypedValue;
+            switch (value)
+            {
+                case AiPersonality.Aggro:
+                    serializer.Serialize(writer, "Aggro");
+                    return;
+                case AiPersonality.Combo:
+                    serializer.Serialize(writer, "Combo");
+                    return;
+                case AiPersonality.Control:
+                    serializer.Serialize(writer, "Control");
+                    return;
+            }
+            throw new Exception("Cannot marshal type AiPersonality");
+        }
+
+        public static readonly AiPersonalityConverter Singleton = new AiPersonalityConverter();
+    }
+
     internal class OnClickUnionConverter : JsonConverter
     {
         public override bool CanConvert(Type t) => t == typeof(OnClickUnion) || t == typeof(OnClickUnion?);
@@ -3773,6 +4054,8 @@ namespace Dreamtides.Schema
                             return new BattleDisplayAction { Enum = BattleDisplayActionEnum.CloseCardBrowser };
                         case "CloseCurrentPanel":
                             return new BattleDisplayAction { Enum = BattleDisplayActionEnum.CloseCurrentPanel };
+                        case "RequestHint":
+                            return new BattleDisplayAction { Enum = BattleDisplayActionEnum.RequestHint };
                         case "ToggleStackVisibility":
                             return new BattleDisplayAction { Enum = BattleDisplayActionEnum.ToggleStackVisibility };
                     }
@@ -3797,6 +4080,9 @@ namespace Dreamtides.Schema
                     case BattleDisplayActionEnum.CloseCurrentPanel:
                         serializer.Serialize(writer, "CloseCurrentPanel");
                         return;
+                    case BattleDisplayActionEnum.RequestHint:
+                        serializer.Serialize(writer, "RequestHint");
+                        return;
                     case BattleDisplayActionEnum.ToggleStackVisibility:
                         serializer.Serialize(writer, "ToggleStackVisibility");
                         return;
@@ -3894,6 +4180,8 @@ namespace Dreamtides.Schema
                     {
                         case "AddCardToHand":
                             return new PanelAddress { Enum = PanelAddressEnum.AddCardToHand };
+                        case "AiDecision":
+                            return new PanelAddress { Enum = PanelAddressEnum.AiDecision };
                         case "Developer":
                             return new PanelAddress { Enum = PanelAddressEnum.Developer };
                         case "PlayOpponentCard":
@@ -3919,6 +4207,9 @@ namespace Dreamtides.Schema
                     case PanelAddressEnum.AddCardToHand:
                         serializer.Serialize(writer, "AddCardToHand");
                         return;
+                    case PanelAddressEnum.AiDecision:
+                        serializer.Serialize(writer, "AiDecision");
+                        return;
                     case PanelAddressEnum.Developer:
                         serializer.Serialize(writer, "Developer");
                         return;
@@ -3953,6 +4244,8 @@ namespace Dreamtides.Schema
             {
                 case "AddCardToHand":
                     return PanelAddressEnum.AddCardToHand;
+                case "AiDecision":
+                    return PanelAddressEnum.AiDecision;
                 case "Developer":
                     return PanelAddressEnum.Developer;
                 case "PlayOpponentCard":
@@ -3976,6 +4269,9 @@ namespace Dreamtides.Schema
                 case PanelAddressEnum.AddCardToHand:
                     serializer.Serialize(writer, "AddCardToHand");
                     return;
+                case PanelAddressEnum.AiDecision:
+                    serializer.Serialize(writer, "AiDecision");
+                    return;
                 case PanelAddressEnum.Developer:
                     serializer.Serialize(writer, "Developer");
                     return;
@@ -4006,6 +4302,8 @@ namespace Dreamtides.Schema
                     return BattleDisplayActionEnum.CloseCardBrowser;
                 case "CloseCurrentPanel":
                     return BattleDisplayActionEnum.CloseCurrentPanel;
+                case "RequestHint":
+                    return BattleDisplayActionEnum.RequestHint;
                 case "ToggleStackVisibility":
                     return BattleDisplayActionEnum.ToggleStackVisibility;
             }
@@ -4028,6 +4326,9 @@ namespace Dreamtides.Schema
                 case BattleDisplayActionEnum.CloseCurrentPanel:
                     serializer.Serialize(writer, "CloseCurrentPanel");
                     return;
+                case BattleDisplayActionEnum.RequestHint:
+                    serializer.Serialize(writer, "RequestHint");
+                    return;
                 case BattleDisplayActionEnum.ToggleStackVisibility:
                     serializer.Serialize(writer, "ToggleStackVisibility");
                     return;
@@ -5317,6 +5618,52 @@ namespace Dreamtides.Schema
         public static readonly GameMessageTypeConverter Singleton = new GameMessageTypeConverter();
     }
 
+    internal class CinematicTypeConverter : JsonConverter
+    {
+        public override bool CanConvert(Type t) => t == typeof(CinematicType) || t == typeof(CinematicType?);
+
+        public override object ReadJson(JsonReader reader, Type t, object existingValue, JsonSerializer serializer)
+        {
+            if (reader.TokenType == JsonToken.Null) return null;
+            var value = serializer.Deserialize<string>(reader);
+            switch (value)
+            {
+                case "JudgmentSweep":
+                    return CinematicType.JudgmentSweep;
+                case "TurnStart":
+                    return CinematicType.TurnStart;
+                case "Victory":
+                    return CinematicType.Victory;
+            }
+            throw new Exception("Cannot unmarshal type CinematicType");
+        }
+
+        public override void WriteJson(JsonWriter writer, object untypedValue, JsonSerializer serializer)
+        {
+            if (untypedValue == null)
+            {
+                serializer.Serialize(writer, null);
+                return;
+            }
+            var value = (CinematicType)untypedValue;
+            switch (value)
+            {
+                case CinematicType.JudgmentSweep:
+                    serializer.Serialize(writer, "JudgmentSweep");
+                    return;
+                case CinematicType.TurnStart:
+                    serializer.Serialize(writer, "TurnStart");
+                    return;
+                case CinematicType.Victory:
+                    serializer.Serialize(writer, "Victory");
+                    return;
+            }
+            throw new Exception("Cannot marshal type CinematicType");
+        }
+
+        public static readonly CinematicTypeConverter Singleton = new CinematicTypeConverter();
+    }
+
     internal class PositionConverter : JsonConverter
     {
         public override bool CanConvert(Type t) => t == typeof(Position) || t == typeof(Position?);
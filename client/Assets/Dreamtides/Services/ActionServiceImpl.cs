@@ -826,6 +826,17 @@ namespace Dreamtides.Services
           Registry.LoggingService.Log("ActionService", "Applying command: AnchorToScreenPosition");
           Registry.DocumentService.RenderScreenAnchoredNode(command.AnchorToScreenPosition);
         }
+
+        if (command.DisplayCinematic != null)
+        {
+          Registry.LoggingService.Log(
+            "ActionService",
+            "Applying command: DisplayCinematic",
+            ("cinematic", command.DisplayCinematic.Cinematic.ToString()),
+            ("player", command.DisplayCinematic.PlayerName),
+            ("pointsDelta", command.DisplayCinematic.PointsDelta.ToString())
+          );
+        }
       }
 
       foreach (var coroutine in coroutines)
@@ -881,6 +892,8 @@ namespace Dreamtides.Services
             commandNames.Add("ShuffleVoidIntoDeck");
           if (command.UpdateScreenOverlay != null)
             commandNames.Add("UpdateScreenOverlay");
+          if (command.DisplayCinematic != null)
+            commandNames.Add("DisplayCinematic");
         }
       }
 
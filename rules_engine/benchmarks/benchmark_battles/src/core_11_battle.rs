@@ -95,10 +95,12 @@ fn generate_core_11_battle_with_logging(enable_logging: bool) -> BattleState {
         CreateBattlePlayer {
             player_type: PlayerType::Agent(GameAI::AlwaysPanic),
             deck_name: TestDeckName::Benchmark1,
+            personality: None,
         },
         CreateBattlePlayer {
             player_type: PlayerType::Agent(GameAI::AlwaysPanic),
             deck_name: TestDeckName::Benchmark1,
+            personality: None,
         },
         RequestContext { logging_options: LoggingOptions::default() },
     );
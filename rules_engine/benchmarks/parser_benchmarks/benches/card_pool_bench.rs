@@ -0,0 +1,30 @@
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use parser_benchmarks::allocation_counter::{self, CountingAllocator};
+use parser_benchmarks::card_pool;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn card_pool_benchmarks(c: &mut Criterion) {
+    let pool = card_pool::load_card_pool();
+    let corpus = card_pool::synthetic_corpus(&pool);
+    let mut group = c.benchmark_group("card_pool");
+    for (name, entries) in [("tabula", &pool), ("synthetic_10x", &corpus)] {
+        let (abilities, count) =
+            allocation_counter::count_allocations(|| card_pool::parse_card_pool(entries));
+        println!(
+            "{name}: {} rules texts, {abilities} abilities, {} allocations, {} bytes allocated",
+            entries.len(),
+            count.allocations,
+            count.bytes
+        );
+        group.throughput(Throughput::Elements(entries.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| card_pool::parse_card_pool(black_box(entries)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, card_pool_benchmarks);
+criterion_main!(benches);
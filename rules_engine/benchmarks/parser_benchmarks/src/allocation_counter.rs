@@ -0,0 +1,60 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator which counts allocations made through it.
+///
+/// Benchmarks which report allocation counts must install this with
+/// `#[global_allocator]`, otherwise [count_allocations] reports zero.
+pub struct CountingAllocator;
+
+/// Allocations made while running a closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationCount {
+    /// Number of allocations and reallocations.
+    pub allocations: usize,
+
+    /// Total bytes requested by those allocations.
+    pub bytes: usize,
+}
+
+/// Runs `f`, returning its result along with the allocations it made.
+///
+/// Allocations made by other threads while `f` runs are also counted.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, AllocationCount) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let result = f();
+    (result, AllocationCount {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    })
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+fn record(bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
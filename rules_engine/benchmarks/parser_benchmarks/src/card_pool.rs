@@ -0,0 +1,67 @@
+use std::path::Path;
+use std::{fs, hint};
+
+use parser::rules_text_parser;
+use parser::variables::parser_bindings::VariableBindings;
+use toml::{Table, Value};
+
+/// Number of copies of the card pool in the synthetic corpus.
+pub const SYNTHETIC_CORPUS_SCALE: usize = 10;
+
+/// Tabula files containing rules text, along with the name of the table
+/// holding their rows.
+const CARD_POOL_SOURCES: &[(&str, &str)] = &[
+    ("cards.toml", "cards"),
+    ("dreamwell.toml", "dreamwell"),
+    ("test-cards.toml", "test-cards"),
+    ("test-dreamwell.toml", "test-dreamwell"),
+];
+
+/// Rules text of a single card, with its variables already parsed so that
+/// only lexing, resolution, and parsing are measured.
+#[derive(Debug, Clone)]
+pub struct CardPoolEntry {
+    pub rules_text: String,
+    pub bindings: VariableBindings,
+}
+
+/// Loads the rules text of every row in the tabula card pool.
+pub fn load_card_pool() -> Vec<CardPoolEntry> {
+    let tabula = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tabula");
+    CARD_POOL_SOURCES
+        .iter()
+        .flat_map(|(file, table)| {
+            let path = tabula.join(file);
+            let content = fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+            let document = toml::from_str::<Table>(&content)
+                .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+            let rows = document.get(*table).and_then(Value::as_array).cloned();
+            rows.unwrap_or_default()
+        })
+        .filter_map(|row| {
+            let rules_text = row.get("rules-text")?.as_str()?.to_string();
+            let variables = row.get("variables").and_then(Value::as_str).unwrap_or_default();
+            let bindings = VariableBindings::parse(variables).ok()?;
+            Some(CardPoolEntry { rules_text, bindings })
+        })
+        .collect()
+}
+
+/// Returns [SYNTHETIC_CORPUS_SCALE] copies of `pool`, approximating a much
+/// larger card pool.
+pub fn synthetic_corpus(pool: &[CardPoolEntry]) -> Vec<CardPoolEntry> {
+    pool.iter().cycle().take(pool.len() * SYNTHETIC_CORPUS_SCALE).cloned().collect()
+}
+
+/// Parses every rules text in `entries`, returning the number of abilities
+/// which parsed successfully.
+pub fn parse_card_pool(entries: &[CardPoolEntry]) -> usize {
+    entries
+        .iter()
+        .map(|entry| {
+            let parsed = rules_text_parser::parse_rules_text(&entry.rules_text, &entry.bindings);
+            hint::black_box(parsed).abilities.len()
+        })
+        .sum()
+}
@@ -1 +1,3 @@
+pub mod allocation_counter;
 pub mod benchmark_utils;
+pub mod card_pool;
@@ -39,6 +39,12 @@ pub enum Ability {
     /// A named ability is a special keyword which is expanded to one or more
     /// other abilities.
     Named(NamedAbility),
+
+    /// An ability prefixed with an ability word, written as "Word -- ability".
+    ///
+    /// The ability word has no rules meaning of its own. It names the ability
+    /// so that other abilities can refer to it.
+    WithAbilityWord(AbilityWithWord),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -54,3 +60,15 @@ pub struct EventAbility {
     /// Effect of this ability when it resolves.
     pub effect: Effect,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityWithWord {
+    pub word: AbilityWord,
+    pub ability: Box<Ability>,
+}
+
+/// Names which can be attached to an ability as an ability word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AbilityWord {
+    Resolve,
+}
@@ -0,0 +1,593 @@
+use crate::ability::{Ability, AbilityWithWord, EventAbility};
+use crate::activated_ability::ActivatedAbility;
+use crate::collection_expression::CollectionExpression;
+use crate::condition::Condition;
+use crate::cost::Cost;
+use crate::duration::Duration;
+use crate::effect::{Effect, EffectWithOptions, IfYouDoEffect, ListWithOptions, ModalEffectChoice};
+use crate::named_ability::NamedAbility;
+use crate::predicate::{CardPredicate, Predicate};
+use crate::quantity_expression_data::QuantityExpression;
+use crate::standard_effect::StandardEffect;
+use crate::static_ability::{
+    AlternateCost, PlayFromHandOrVoidForCost, PlayFromVoid, StandardStaticAbility, StaticAbility,
+    StaticAbilityWithOptions,
+};
+use crate::trigger_event::TriggerEvent;
+use crate::triggered_ability::TriggeredAbility;
+
+crate::define_ability_visitor!(AbilityVisitor);
+
+/// Generates a visitor trait over the ability AST along with a `walk_*`
+/// function for each node type.
+///
+/// Every `visit_*` method defaults to calling the matching `walk_*` function,
+/// which visits each child node in source order. Implementations override the
+/// methods for the nodes they care about and call the `walk_*` function
+/// themselves to keep descending.
+///
+/// Invoked with `mut` to generate a visitor over mutable references, which
+/// can be used to rewrite abilities in place. See
+/// [crate::ability_visitor_mut].
+#[macro_export]
+macro_rules! define_ability_visitor {
+    ($visitor:ident $(, $mut:tt)?) => {
+        pub trait $visitor {
+            fn visit_ability(&mut self, ability: &$($mut)? Ability) {
+                walk_ability(self, ability);
+            }
+
+            fn visit_event_ability(&mut self, ability: &$($mut)? EventAbility) {
+                walk_event_ability(self, ability);
+            }
+
+            fn visit_activated_ability(&mut self, ability: &$($mut)? ActivatedAbility) {
+                walk_activated_ability(self, ability);
+            }
+
+            fn visit_triggered_ability(&mut self, ability: &$($mut)? TriggeredAbility) {
+                walk_triggered_ability(self, ability);
+            }
+
+            fn visit_static_ability(&mut self, ability: &$($mut)? StaticAbility) {
+                walk_static_ability(self, ability);
+            }
+
+            fn visit_standard_static_ability(
+                &mut self,
+                ability: &$($mut)? StandardStaticAbility,
+            ) {
+                walk_standard_static_ability(self, ability);
+            }
+
+            fn visit_named_ability(&mut self, ability: &$($mut)? NamedAbility) {
+                walk_named_ability(self, ability);
+            }
+
+            fn visit_effect(&mut self, effect: &$($mut)? Effect) {
+                walk_effect(self, effect);
+            }
+
+            fn visit_effect_with_options(&mut self, effect: &$($mut)? EffectWithOptions) {
+                walk_effect_with_options(self, effect);
+            }
+
+            fn visit_standard_effect(&mut self, effect: &$($mut)? StandardEffect) {
+                walk_standard_effect(self, effect);
+            }
+
+            fn visit_trigger_event(&mut self, trigger: &$($mut)? TriggerEvent) {
+                walk_trigger_event(self, trigger);
+            }
+
+            fn visit_cost(&mut self, cost: &$($mut)? Cost) {
+                walk_cost(self, cost);
+            }
+
+            fn visit_condition(&mut self, condition: &$($mut)? Condition) {
+                walk_condition(self, condition);
+            }
+
+            fn visit_predicate(&mut self, predicate: &$($mut)? Predicate) {
+                walk_predicate(self, predicate);
+            }
+
+            fn visit_card_predicate(&mut self, predicate: &$($mut)? CardPredicate) {
+                walk_card_predicate(self, predicate);
+            }
+
+            fn visit_quantity_expression(&mut self, quantity: &$($mut)? QuantityExpression) {
+                walk_quantity_expression(self, quantity);
+            }
+
+            fn visit_collection_expression(
+                &mut self,
+                _collection: &$($mut)? CollectionExpression,
+            ) {
+            }
+
+            fn visit_duration(&mut self, _duration: &$($mut)? Duration) {}
+        }
+
+        pub fn walk_ability<V: $visitor + ?Sized>(visitor: &mut V, ability: &$($mut)? Ability) {
+            match ability {
+                Ability::Event(event) => visitor.visit_event_ability(event),
+                Ability::Static(ability) => visitor.visit_static_ability(ability),
+                Ability::Activated(ability) => visitor.visit_activated_ability(ability),
+                Ability::Triggered(ability) => visitor.visit_triggered_ability(ability),
+                Ability::Named(ability) => visitor.visit_named_ability(ability),
+                Ability::WithAbilityWord(AbilityWithWord { word: _, ability }) => {
+                    visitor.visit_ability(ability)
+                }
+            }
+        }
+
+        pub fn walk_event_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? EventAbility,
+        ) {
+            let EventAbility { additional_cost, effect } = ability;
+            if let Some(cost) = additional_cost {
+                visitor.visit_cost(cost);
+            }
+            visitor.visit_effect(effect);
+        }
+
+        pub fn walk_activated_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? ActivatedAbility,
+        ) {
+            let ActivatedAbility { costs, effect, options: _, paragraphs: _ } = ability;
+            for cost in costs {
+                visitor.visit_cost(cost);
+            }
+            visitor.visit_effect(effect);
+        }
+
+        pub fn walk_triggered_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? TriggeredAbility,
+        ) {
+            let TriggeredAbility { trigger, effect, options: _ } = ability;
+            visitor.visit_trigger_event(trigger);
+            visitor.visit_effect(effect);
+        }
+
+        pub fn walk_static_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? StaticAbility,
+        ) {
+            match ability {
+                StaticAbility::StaticAbility(ability) => {
+                    visitor.visit_standard_static_ability(ability)
+                }
+                StaticAbility::WithOptions(StaticAbilityWithOptions { ability, condition }) => {
+                    if let Some(condition) = condition {
+                        visitor.visit_condition(condition);
+                    }
+                    visitor.visit_standard_static_ability(ability);
+                }
+            }
+        }
+
+        pub fn walk_standard_static_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? StandardStaticAbility,
+        ) {
+            let (additional_cost, if_you_do) = match ability {
+                StandardStaticAbility::AdditionalCostToPlay(cost) => {
+                    visitor.visit_cost(cost);
+                    return;
+                }
+                StandardStaticAbility::CardsInYourVoidHaveReclaim { matching }
+                | StandardStaticAbility::EnemyCardsCostIncrease { matching, increase: _ }
+                | StandardStaticAbility::OncePerTurnPlayFromVoid { matching }
+                | StandardStaticAbility::SparkBonusYourCharacters { matching, added_spark: _ }
+                | StandardStaticAbility::SparkBonusOtherCharacters { matching, added_spark: _ }
+                | StandardStaticAbility::YouMayPlayFromTopOfDeck { matching }
+                | StandardStaticAbility::YourCardsCostIncrease { matching, increase: _ }
+                | StandardStaticAbility::YourCardsCostReduction { matching, reduction: _ } => {
+                    visitor.visit_card_predicate(matching);
+                    return;
+                }
+                StandardStaticAbility::CostReductionForEach { reduction: _, quantity } => {
+                    visitor.visit_quantity_expression(quantity);
+                    return;
+                }
+                StandardStaticAbility::JudgmentTriggersWhenMaterialized { predicate }
+                | StandardStaticAbility::SparkEqualToPredicateCount { predicate } => {
+                    visitor.visit_predicate(predicate);
+                    return;
+                }
+                StandardStaticAbility::PlayForAlternateCost(AlternateCost {
+                    energy_cost: _,
+                    additional_cost,
+                    if_you_do,
+                    card_type: _,
+                }) => (additional_cost, if_you_do),
+                StandardStaticAbility::PlayFromHandOrVoidForCost(PlayFromHandOrVoidForCost {
+                    energy_cost: _,
+                    additional_cost,
+                    if_you_do,
+                }) => (additional_cost, if_you_do),
+                StandardStaticAbility::PlayFromVoid(PlayFromVoid {
+                    energy_cost: _,
+                    additional_cost,
+                    if_you_do,
+                }) => (additional_cost, if_you_do),
+                StandardStaticAbility::CharactersInHandHaveFast
+                | StandardStaticAbility::DisableEnemyMaterializedAbilities
+                | StandardStaticAbility::HasAllCharacterTypes
+                | StandardStaticAbility::MultiplyCardDrawFromCardEffects { .. }
+                | StandardStaticAbility::MultiplyEnergyGainFromCardEffects { .. }
+                | StandardStaticAbility::PlayOnlyFromVoid
+                | StandardStaticAbility::RevealTopCardOfYourDeck
+                | StandardStaticAbility::YouMayLookAtTopCardOfYourDeck => return,
+            };
+            if let Some(cost) = additional_cost {
+                visitor.visit_cost(cost);
+            }
+            if let Some(effect) = if_you_do {
+                visitor.visit_effect(effect);
+            }
+        }
+
+        pub fn walk_named_ability<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            ability: &$($mut)? NamedAbility,
+        ) {
+            match ability {
+                NamedAbility::Reclaim(_) => {}
+                NamedAbility::ReclaimForCost(cost) => visitor.visit_cost(cost),
+            }
+        }
+
+        pub fn walk_effect<V: $visitor + ?Sized>(visitor: &mut V, effect: &$($mut)? Effect) {
+            match effect {
+                Effect::Effect(effect) => visitor.visit_standard_effect(effect),
+                Effect::WithOptions(effect) => visitor.visit_effect_with_options(effect),
+                Effect::List(effects) => {
+                    for effect in effects {
+                        visitor.visit_effect_with_options(effect);
+                    }
+                }
+                Effect::ListWithOptions(ListWithOptions { effects, trigger_cost, condition }) => {
+                    if let Some(condition) = condition {
+                        visitor.visit_condition(condition);
+                    }
+                    if let Some(cost) = trigger_cost {
+                        visitor.visit_cost(cost);
+                    }
+                    for effect in effects {
+                        visitor.visit_effect_with_options(effect);
+                    }
+                }
+                Effect::Modal(choices) => {
+                    for ModalEffectChoice { energy_cost: _, effect } in choices {
+                        visitor.visit_effect(effect);
+                    }
+                }
+                Effect::IfYouDo(IfYouDoEffect { effect, optional: _, follow_up }) => {
+                    visitor.visit_standard_effect(effect);
+                    for effect in follow_up {
+                        visitor.visit_effect_with_options(effect);
+                    }
+                }
+            }
+        }
+
+        pub fn walk_effect_with_options<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            effect: &$($mut)? EffectWithOptions,
+        ) {
+            let EffectWithOptions { effect, optional: _, trigger_cost, condition } = effect;
+            if let Some(condition) = condition {
+                visitor.visit_condition(condition);
+            }
+            if let Some(cost) = trigger_cost {
+                visitor.visit_cost(cost);
+            }
+            visitor.visit_standard_effect(effect);
+        }
+
+        pub fn walk_standard_effect<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            effect: &$($mut)? StandardEffect,
+        ) {
+            match effect {
+                StandardEffect::AbandonAndGainEnergyForSpark { target, energy_per_spark: _ }
+                | StandardEffect::AbandonAtEndOfTurn { target }
+                | StandardEffect::BanishCharacter { target }
+                | StandardEffect::BanishWhenLeavesPlay { target }
+                | StandardEffect::Copy { target }
+                | StandardEffect::CopyNextPlayed { matching: target, times: _ }
+                | StandardEffect::Counterspell { target }
+                | StandardEffect::DissolveCharacter { target }
+                | StandardEffect::GainControl { target }
+                | StandardEffect::GainEnergyEqualToCost { target }
+                | StandardEffect::GainsSpark { target, gains: _ }
+                | StandardEffect::MaterializeCharacter { target }
+                | StandardEffect::MaterializeCharacterAtEndOfTurn { target }
+                | StandardEffect::PutOnTopOfEnemyDeck { target }
+                | StandardEffect::PutSparkCounters { target, count: _ }
+                | StandardEffect::RemoveSparkCounters { target, count: _ }
+                | StandardEffect::ReturnFromYourVoidToHand { target }
+                | StandardEffect::ReturnFromYourVoidToPlay { target }
+                | StandardEffect::ReturnToHand { target }
+                | StandardEffect::ReturnUpToCountFromYourVoidToHand { target, count: _ } => {
+                    visitor.visit_predicate(target);
+                }
+                StandardEffect::BanishCharacterUntilLeavesPlay { target, until_leaves } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_predicate(until_leaves);
+                }
+                StandardEffect::BanishCollection { target, count }
+                | StandardEffect::BanishThenMaterialize { target, count }
+                | StandardEffect::DissolveCharactersCount { target, count }
+                | StandardEffect::MaterializeCollection { target, count }
+                | StandardEffect::TriggerJudgmentAbility {
+                    matching: target,
+                    collection: count,
+                } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_collection_expression(count);
+                }
+                StandardEffect::BanishForDuration { target, duration }
+                | StandardEffect::DisableActivatedAbilities { target, duration }
+                | StandardEffect::PreventDissolve { target, duration } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_duration(duration);
+                }
+                StandardEffect::GainsReclaim { target, count, duration, cost: _ } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_collection_expression(count);
+                    if let Some(duration) = duration {
+                        visitor.visit_duration(duration);
+                    }
+                }
+                StandardEffect::CounterspellUnlessPaysCost { target, cost } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_cost(cost);
+                }
+                StandardEffect::OpponentPaysCost { cost } | StandardEffect::PayCost { cost } => {
+                    visitor.visit_cost(cost);
+                }
+                StandardEffect::CreateStaticAbility { ability, duration } => {
+                    visitor.visit_standard_static_ability(ability);
+                    visitor.visit_duration(duration);
+                }
+                StandardEffect::CreateTrigger { trigger, duration } => {
+                    visitor.visit_triggered_ability(trigger);
+                    visitor.visit_duration(duration);
+                }
+                StandardEffect::DiscardCardFromEnemyHand { predicate }
+                | StandardEffect::DiscardCardFromEnemyHandThenTheyDraw { predicate }
+                | StandardEffect::Discover { predicate }
+                | StandardEffect::DiscoverAndThenMaterialize { predicate }
+                | StandardEffect::DrawMatchingCard { predicate }
+                | StandardEffect::EachMatchingGainsSpark { each: predicate, gains: _ }
+                | StandardEffect::EachPlayerAbandonsCharacters { matching: predicate, count: _ }
+                | StandardEffect::MaterializeCharacterFromVoid { target: predicate }
+                | StandardEffect::MaterializeRandomFromDeck { count: _, predicate }
+                | StandardEffect::PutCardsFromVoidOnTopOfDeck { count: _, matching: predicate }
+                | StandardEffect::ReturnRandomFromYourVoidToPlay { predicate } => {
+                    visitor.visit_card_predicate(predicate);
+                }
+                StandardEffect::EachMatchingGainsSparkForEach { each, gains: _, for_each } => {
+                    visitor.visit_card_predicate(each);
+                    visitor.visit_card_predicate(for_each);
+                }
+                StandardEffect::SparkBecomes { collection, matching, spark: _ } => {
+                    visitor.visit_collection_expression(collection);
+                    visitor.visit_card_predicate(matching);
+                }
+                StandardEffect::DissolveCharactersQuantity { target, quantity }
+                | StandardEffect::GainsSparkForQuantity {
+                    target,
+                    gains: _,
+                    for_quantity: quantity,
+                }
+                | StandardEffect::MaterializeSilentCopy { target, count: _, quantity } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_quantity_expression(quantity);
+                }
+                StandardEffect::DrawCardsForEach { count: _, for_each: quantity }
+                | StandardEffect::GainPointsForEach { gain: _, for_count: quantity }
+                | StandardEffect::MaterializeFigmentsQuantity {
+                    figment: _,
+                    count: _,
+                    quantity,
+                } => {
+                    visitor.visit_quantity_expression(quantity);
+                }
+                StandardEffect::GainEnergyForEach { gains: _, for_each } => {
+                    visitor.visit_predicate(for_each);
+                }
+                StandardEffect::GainsSparkForEach { target, gains: _, for_each, duration } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_predicate(for_each);
+                    visitor.visit_duration(duration);
+                }
+                StandardEffect::BanishCardsFromEnemyVoid { .. }
+                | StandardEffect::BanishEnemyVoid
+                | StandardEffect::DiscardCards { .. }
+                | StandardEffect::MultiplyYourEnergy { .. }
+                | StandardEffect::DrawCards { .. }
+                | StandardEffect::EachPlayerDiscardCards { .. }
+                | StandardEffect::EachPlayerShufflesHandAndVoidIntoDeckAndDraws { .. }
+                | StandardEffect::EnemyGainsPoints { .. }
+                | StandardEffect::EnemyGainsPointsEqualToItsSpark
+                | StandardEffect::EnemyLosesPoints { .. }
+                | StandardEffect::Foresee { .. }
+                | StandardEffect::GainEnergy { .. }
+                | StandardEffect::GainPoints { .. }
+                | StandardEffect::GainTwiceThatMuchEnergyInstead
+                | StandardEffect::Kindle { .. }
+                | StandardEffect::LosePoints { .. }
+                | StandardEffect::MaterializeFigments { .. }
+                | StandardEffect::NoEffect
+                | StandardEffect::PutCardsFromYourDeckIntoVoid { .. }
+                | StandardEffect::SpendAllEnergyDissolveEnemy
+                | StandardEffect::SpendAllEnergyDrawAndDiscard
+                | StandardEffect::TakeExtraTurn
+                | StandardEffect::ThenMaterializeIt
+                | StandardEffect::TriggerAdditionalJudgmentPhaseAtEndOfTurn
+                | StandardEffect::TriggerThisCardsAbility { .. }
+                | StandardEffect::YouWinTheGame => {}
+            }
+        }
+
+        pub fn walk_trigger_event<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            trigger: &$($mut)? TriggerEvent,
+        ) {
+            match trigger {
+                TriggerEvent::Abandon(predicate)
+                | TriggerEvent::Banished(predicate)
+                | TriggerEvent::Discard(predicate)
+                | TriggerEvent::Dissolved(predicate)
+                | TriggerEvent::LeavesPlay(predicate)
+                | TriggerEvent::PutIntoVoid(predicate)
+                | TriggerEvent::Materialize(predicate)
+                | TriggerEvent::MaterializeNthThisTurn(predicate, _)
+                | TriggerEvent::Play(predicate)
+                | TriggerEvent::PlayDuringTurn(predicate, _)
+                | TriggerEvent::PlayFromHand(predicate)
+                | TriggerEvent::OpponentPlays(predicate) => visitor.visit_predicate(predicate),
+                TriggerEvent::AbandonCardsInTurn(_)
+                | TriggerEvent::DrawAllCardsInCopyOfDeck
+                | TriggerEvent::DrawCardsInTurn(_)
+                | TriggerEvent::EndOfYourTurn
+                | TriggerEvent::GainEnergy
+                | TriggerEvent::Keywords(_)
+                | TriggerEvent::PlayCardsInTurn(_)
+                | TriggerEvent::StartOfYourTurn => {}
+            }
+        }
+
+        pub fn walk_cost<V: $visitor + ?Sized>(visitor: &mut V, cost: &$($mut)? Cost) {
+            match cost {
+                Cost::AbandonCharactersCount { target, count }
+                | Cost::ReturnToHand { target, count } => {
+                    visitor.visit_predicate(target);
+                    visitor.visit_collection_expression(count);
+                }
+                Cost::BanishFromHand(target) | Cost::DiscardCards { target, count: _ } => {
+                    visitor.visit_predicate(target);
+                }
+                Cost::Choice(costs) | Cost::CostList(costs) => {
+                    for cost in costs {
+                        visitor.visit_cost(cost);
+                    }
+                }
+                Cost::BanishAllCardsFromYourVoid
+                | Cost::BanishAllCardsFromYourVoidWithMinCount(_)
+                | Cost::BanishCardsFromEnemyVoid(_)
+                | Cost::BanishCardsFromYourVoid(_)
+                | Cost::DiscardHand
+                | Cost::Energy(_)
+                | Cost::LoseMaximumEnergy(_)
+                | Cost::SpendOneOrMoreEnergy
+                | Cost::VariableEnergy => {}
+            }
+        }
+
+        pub fn walk_condition<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            condition: &$($mut)? Condition,
+        ) {
+            match condition {
+                Condition::CardsDiscardedThisTurn { count: _, predicate } => {
+                    visitor.visit_card_predicate(predicate);
+                }
+                Condition::DissolvedThisTurn { predicate }
+                | Condition::PredicateCount { count: _, predicate } => {
+                    visitor.visit_predicate(predicate);
+                }
+                Condition::AlliesThatShareACharacterType { .. }
+                | Condition::CardsDrawnThisTurn { .. }
+                | Condition::CardsInVoidCount { .. }
+                | Condition::ThisCardIsInYourVoid => {}
+            }
+        }
+
+        pub fn walk_predicate<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            predicate: &$($mut)? Predicate,
+        ) {
+            match predicate {
+                Predicate::Enemy(predicate)
+                | Predicate::Another(predicate)
+                | Predicate::Your(predicate)
+                | Predicate::Any(predicate)
+                | Predicate::AnyOther(predicate)
+                | Predicate::YourVoid(predicate)
+                | Predicate::EnemyVoid(predicate) => visitor.visit_card_predicate(predicate),
+                Predicate::This | Predicate::It | Predicate::Them | Predicate::That => {}
+            }
+        }
+
+        pub fn walk_card_predicate<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            predicate: &$($mut)? CardPredicate,
+        ) {
+            match predicate {
+                CardPredicate::Or(predicates) => {
+                    for predicate in predicates {
+                        visitor.visit_card_predicate(predicate);
+                    }
+                }
+                CardPredicate::CardWithCost { target, .. }
+                | CardPredicate::CharacterWithCostComparedToAbandoned { target, .. }
+                | CardPredicate::CharacterWithSparkComparedToAbandoned { target, .. }
+                | CardPredicate::CharacterWithSparkComparedToAbandonedCountThisTurn {
+                    target,
+                    ..
+                }
+                | CardPredicate::CharacterWithSparkComparedToEnergySpent { target, .. }
+                | CardPredicate::CharacterWithCostComparedToVoidCount { target, .. }
+                | CardPredicate::Fast { target } => visitor.visit_card_predicate(target),
+                CardPredicate::CharacterWithCostComparedToControlled {
+                    target,
+                    cost_operator: _,
+                    count_matching,
+                } => {
+                    visitor.visit_card_predicate(target);
+                    visitor.visit_card_predicate(count_matching);
+                }
+                CardPredicate::CouldDissolve { target } => visitor.visit_predicate(target),
+                CardPredicate::Card
+                | CardPredicate::Character
+                | CardPredicate::Event
+                | CardPredicate::CharacterType(_)
+                | CardPredicate::NotCharacterType(_)
+                | CardPredicate::CharacterWithSpark(..)
+                | CardPredicate::CharacterWithMaterializedAbility
+                | CardPredicate::CharacterWithMultiActivatedAbility => {}
+            }
+        }
+
+        pub fn walk_quantity_expression<V: $visitor + ?Sized>(
+            visitor: &mut V,
+            quantity: &$($mut)? QuantityExpression,
+        ) {
+            match quantity {
+                QuantityExpression::AbandonedThisTurn(predicate)
+                | QuantityExpression::AbandonedThisWay(predicate)
+                | QuantityExpression::CardsDrawnThisTurn(predicate)
+                | QuantityExpression::DiscardedThisTurn(predicate)
+                | QuantityExpression::DissolvedThisTurn(predicate)
+                | QuantityExpression::PlayedThisTurn(predicate)
+                | QuantityExpression::ReturnedToHandThisWay(predicate) => {
+                    visitor.visit_card_predicate(predicate);
+                }
+                QuantityExpression::Matching(predicate)
+                | QuantityExpression::SparkCountersOn(predicate) => {
+                    visitor.visit_predicate(predicate);
+                }
+                QuantityExpression::ForEachEnergySpentOnThisCard
+                | QuantityExpression::VariableEnergyPaid => {}
+            }
+        }
+    };
+}
@@ -0,0 +1,19 @@
+use crate::ability::{Ability, AbilityWithWord, EventAbility};
+use crate::activated_ability::ActivatedAbility;
+use crate::collection_expression::CollectionExpression;
+use crate::condition::Condition;
+use crate::cost::Cost;
+use crate::duration::Duration;
+use crate::effect::{Effect, EffectWithOptions, IfYouDoEffect, ListWithOptions, ModalEffectChoice};
+use crate::named_ability::NamedAbility;
+use crate::predicate::{CardPredicate, Predicate};
+use crate::quantity_expression_data::QuantityExpression;
+use crate::standard_effect::StandardEffect;
+use crate::static_ability::{
+    AlternateCost, PlayFromHandOrVoidForCost, PlayFromVoid, StandardStaticAbility, StaticAbility,
+    StaticAbilityWithOptions,
+};
+use crate::trigger_event::TriggerEvent;
+use crate::triggered_ability::TriggeredAbility;
+
+crate::define_ability_visitor!(AbilityVisitorMut, mut);
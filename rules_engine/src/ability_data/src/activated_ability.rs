@@ -7,6 +7,15 @@ use crate::effect::Effect;
 /// controlling player to pay some cost in order to achieve an effect. This is
 /// written as "> cost: effect".
 ///
+/// An activated ability can also be written in block layout, with its costs on
+/// their own line followed by one "> effect" line per paragraph:
+///
+/// ```text
+/// {energy($e)}:
+/// > Draw {cards($c)}.
+/// > Discard {cards($d)}.
+/// ```
+///
 /// An activated ability on an *event* card describes an additional cost to play
 /// that event and must be paid immediately.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -20,6 +29,15 @@ pub struct ActivatedAbility {
     /// Configuration for this activated ability
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<ActivatedAbilityOptions>,
+
+    /// Number of effects in each paragraph if this ability is written in
+    /// block layout, or None if it is written on a single line.
+    ///
+    /// The effect of a block layout ability is an [Effect::List] containing
+    /// the effects of every paragraph in order, which are applied
+    /// sequentially.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paragraphs: Option<Vec<usize>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
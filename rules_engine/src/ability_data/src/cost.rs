@@ -22,6 +22,7 @@ pub enum Cost {
     LoseMaximumEnergy(u32),
     ReturnToHand { target: Predicate, count: CollectionExpression },
     SpendOneOrMoreEnergy,
+    VariableEnergy,
 }
 
 impl Cost {
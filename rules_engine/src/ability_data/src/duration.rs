@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Expression for how long a continuous effect or delayed trigger lasts. For
+/// example, this is used in parsing "Until end of turn, ..." or "... while
+/// this character is in play."
+///
+/// Durations are relative to the controller of the effect which created them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Duration {
+    /// Lasts until the end of the current turn. Indicated in card text by
+    /// "this turn" or "until end of turn".
+    ThisTurn,
+
+    /// Lasts until the start of the controller's next main phase.
+    UntilYourNextMain,
+
+    /// Lasts until the start of the controller's next turn.
+    UntilYourNextTurn,
+
+    /// Lasts while the card which created this effect remains in play.
+    WhileInPlay,
+}
@@ -14,6 +14,7 @@ pub enum Effect {
     List(Vec<EffectWithOptions>),
     ListWithOptions(ListWithOptions),
     Modal(Vec<ModalEffectChoice>),
+    IfYouDo(IfYouDoEffect),
 }
 
 /// Identifies a [ModalEffectChoice] in a [Effect::Modal].
@@ -76,12 +77,33 @@ pub struct ListWithOptions {
     pub condition: Option<Condition>,
 }
 
+/// An effect followed by effects which occur only if it was performed, usually
+/// phrased as "You may {effect}. If you do, {follow up}."
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IfYouDoEffect {
+    /// Effect which must be performed for the follow-up effects to occur.
+    pub effect: StandardEffect,
+
+    /// True if the controller may choose whether to perform `effect`.
+    pub optional: bool,
+
+    /// Effects to apply only if `effect` was performed.
+    pub follow_up: Vec<EffectWithOptions>,
+}
+
 impl ModelEffectChoiceIndex {
     pub fn value(self) -> usize {
         self.0
     }
 }
 
+impl IfYouDoEffect {
+    /// Returns `effect` followed by each of the follow-up effects.
+    pub fn effects(&self) -> impl Iterator<Item = &StandardEffect> {
+        std::iter::once(&self.effect).chain(self.follow_up.iter().map(|e| &e.effect))
+    }
+}
+
 impl EffectWithOptions {
     pub fn new(effect: StandardEffect) -> Self {
         Self { effect, optional: false, trigger_cost: None, condition: None }
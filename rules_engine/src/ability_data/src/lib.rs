@@ -1,8 +1,11 @@
 pub mod ability;
+pub mod ability_visitor;
+pub mod ability_visitor_mut;
 pub mod activated_ability;
 pub mod collection_expression;
 pub mod condition;
 pub mod cost;
+pub mod duration;
 pub mod effect;
 pub mod named_ability;
 pub mod predicate;
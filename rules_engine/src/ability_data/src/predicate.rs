@@ -56,6 +56,8 @@ pub enum CardPredicate {
     Event,
     CharacterType(CardSubtype),
     NotCharacterType(CardSubtype),
+    /// Cards matching any of the given predicates, e.g. "a Warrior or Mage".
+    Or(Vec<CardPredicate>),
     CharacterWithSpark(Spark, Operator<Spark>),
     CardWithCost {
         target: Box<CardPredicate>,
@@ -15,4 +15,6 @@ pub enum QuantityExpression {
     Matching(Predicate),
     PlayedThisTurn(CardPredicate),
     ReturnedToHandThisWay(CardPredicate),
+    SparkCountersOn(Predicate),
+    VariableEnergyPaid,
 }
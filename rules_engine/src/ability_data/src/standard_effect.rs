@@ -1,10 +1,12 @@
 use core_data::figment_type::FigmentType;
 use core_data::numerics::{Energy, Points, Spark};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use strum::{Display, EnumDiscriminants};
 
+use crate::ability::AbilityWord;
 use crate::collection_expression::CollectionExpression;
 use crate::cost::Cost;
+use crate::duration::Duration;
 use crate::predicate::{CardPredicate, Predicate};
 use crate::quantity_expression_data::QuantityExpression;
 use crate::static_ability::StandardStaticAbility;
@@ -13,6 +15,10 @@ use crate::triggered_ability::TriggeredAbility;
 /// Effects are the primary way in which cards modify the game state. This can
 /// be as part of the resolution of an event card, or via the effect text of a
 /// triggered or activated ability on a character card.
+///
+/// Effects which gained a [Duration] still deserialize from their previous
+/// names, e.g. `PreventDissolveThisTurn`, so that saved battles containing
+/// them continue to load.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumDiscriminants)]
 #[strum_discriminants(derive(Display))]
 pub enum StandardEffect {
@@ -42,8 +48,11 @@ pub enum StandardEffect {
         count: CollectionExpression,
     },
     BanishEnemyVoid,
-    BanishUntilNextMain {
+    #[serde(alias = "BanishUntilNextMain")]
+    BanishForDuration {
         target: Predicate,
+        #[serde(default = "until_your_next_main")]
+        duration: Duration,
     },
     BanishWhenLeavesPlay {
         target: Predicate,
@@ -51,7 +60,8 @@ pub enum StandardEffect {
     GainsReclaim {
         target: Predicate,
         count: CollectionExpression,
-        this_turn: bool,
+        #[serde(default, alias = "this_turn", deserialize_with = "deserialize_reclaim_duration")]
+        duration: Option<Duration>,
         cost: Option<Energy>,
     },
     Copy {
@@ -68,14 +78,23 @@ pub enum StandardEffect {
         target: Predicate,
         cost: Cost,
     },
-    CreateStaticAbilityUntilEndOfTurn {
+    #[serde(alias = "CreateStaticAbilityUntilEndOfTurn")]
+    CreateStaticAbility {
         ability: Box<StandardStaticAbility>,
+        #[serde(default = "this_turn")]
+        duration: Duration,
     },
-    CreateTriggerUntilEndOfTurn {
+    #[serde(alias = "CreateTriggerUntilEndOfTurn")]
+    CreateTrigger {
         trigger: Box<TriggeredAbility>,
+        #[serde(default = "this_turn")]
+        duration: Duration,
     },
-    DisableActivatedAbilitiesWhileInPlay {
+    #[serde(alias = "DisableActivatedAbilitiesWhileInPlay")]
+    DisableActivatedAbilities {
         target: Predicate,
+        #[serde(default = "while_in_play")]
+        duration: Duration,
     },
     DiscardCardFromEnemyHand {
         predicate: CardPredicate,
@@ -174,10 +193,13 @@ pub enum StandardEffect {
         gains: Spark,
         for_quantity: QuantityExpression,
     },
-    GainsSparkUntilYourNextMainForEach {
+    #[serde(alias = "GainsSparkUntilYourNextMainForEach")]
+    GainsSparkForEach {
         target: Predicate,
         gains: Spark,
         for_each: Predicate,
+        #[serde(default = "until_your_next_main")]
+        duration: Duration,
     },
     GainTwiceThatMuchEnergyInstead,
     Kindle {
@@ -224,8 +246,11 @@ pub enum StandardEffect {
     PayCost {
         cost: Cost,
     },
-    PreventDissolveThisTurn {
+    #[serde(alias = "PreventDissolveThisTurn")]
+    PreventDissolve {
         target: Predicate,
+        #[serde(default = "this_turn")]
+        duration: Duration,
     },
     PutCardsFromVoidOnTopOfDeck {
         count: u32,
@@ -237,6 +262,14 @@ pub enum StandardEffect {
     PutOnTopOfEnemyDeck {
         target: Predicate,
     },
+    PutSparkCounters {
+        target: Predicate,
+        count: u32,
+    },
+    RemoveSparkCounters {
+        target: Predicate,
+        count: u32,
+    },
     ReturnFromYourVoidToHand {
         target: Predicate,
     },
@@ -267,5 +300,39 @@ pub enum StandardEffect {
         matching: Predicate,
         collection: CollectionExpression,
     },
+    TriggerThisCardsAbility {
+        word: AbilityWord,
+    },
     YouWinTheGame,
 }
+
+fn this_turn() -> Duration {
+    Duration::ThisTurn
+}
+
+fn until_your_next_main() -> Duration {
+    Duration::UntilYourNextMain
+}
+
+fn while_in_play() -> Duration {
+    Duration::WhileInPlay
+}
+
+/// Reads the duration of [StandardEffect::GainsReclaim], or the `this_turn`
+/// flag which it replaced.
+fn deserialize_reclaim_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ReclaimDuration {
+        Duration(Option<Duration>),
+        ThisTurn(bool),
+    }
+
+    Ok(match ReclaimDuration::deserialize(deserializer)? {
+        ReclaimDuration::Duration(duration) => duration,
+        ReclaimDuration::ThisTurn(this_turn) => this_turn.then_some(Duration::ThisTurn),
+    })
+}
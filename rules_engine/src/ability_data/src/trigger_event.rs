@@ -24,6 +24,7 @@ pub enum TriggerEvent {
     PlayCardsInTurn(u32),
     PlayDuringTurn(Predicate, PlayerTurn),
     PlayFromHand(Predicate),
+    StartOfYourTurn,
     OpponentPlays(Predicate),
 }
 
@@ -1,3 +1,4 @@
+use battle_state::battle::card_id::CardId;
 use core_data::numerics::Energy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,18 @@ pub enum BattleDisplayAction {
 
     /// Toggles the visibility of the stack.
     ToggleStackVisibility,
+
+    /// Starts searching for an action to suggest to the user.
+    RequestHint,
+
+    /// Opens the card inspection screen, showing full details of a card.
+    InspectCard(CardId),
+
+    /// Closes the card inspection screen.
+    CloseCardInspection,
+
+    /// Sets whether emotes sent by the opponent are hidden.
+    SetOpponentEmotesMuted(bool),
 }
 
 #[derive(
@@ -0,0 +1,25 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A fixed phrase a player can send to their opponent during a battle.
+#[derive(Clone, Copy, Debug, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
+pub enum Emote {
+    Greetings,
+    WellPlayed,
+    Thanks,
+    Oops,
+    Thinking,
+    GoodGame,
+}
+
+impl Emote {
+    /// All emotes, in the order they are offered to the user.
+    pub const ALL: [Emote; 6] = [
+        Emote::Greetings,
+        Emote::WellPlayed,
+        Emote::Thanks,
+        Emote::Oops,
+        Emote::Thinking,
+        Emote::GoodGame,
+    ];
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::battle_display_action::BattleDisplayAction;
 use crate::debug_action_data::DebugAction;
+use crate::emote_data::Emote;
 
 /// All possible user interface actions
 #[derive(Clone, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
@@ -17,6 +18,9 @@ pub enum GameAction {
     BattleAction(BattleAction),
     BattleDisplayAction(BattleDisplayAction),
     Undo(PlayerName),
+
+    /// Sends an emote to the opponent.
+    Emote(Emote),
 }
 
 impl Debug for GameAction {
@@ -27,6 +31,7 @@ impl Debug for GameAction {
             GameAction::BattleAction(action) => write!(f, "{action:?}"),
             GameAction::BattleDisplayAction(action) => write!(f, "{action:?}"),
             GameAction::Undo(player) => write!(f, "Undo({player:?})"),
+            GameAction::Emote(emote) => write!(f, "Emote({emote:?})"),
         }
     }
 }
@@ -1,4 +1,5 @@
 pub mod battle_display_action;
 pub mod debug_action_data;
+pub mod emote_data;
 pub mod game_action_data;
 pub mod panel_address;
@@ -1,3 +1,4 @@
+use core_data::types::PlayerName;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,4 +10,32 @@ pub enum PanelAddress {
     AddCardToHand,
     PlayOpponentCard,
     ViewLogs(Option<String>),
+    AiDecision,
+    BattleLog,
+    VoidBrowser { player: PlayerName, filter: VoidBrowserFilter, sort: VoidBrowserSort },
+    MatchHistory,
+    DeckList,
+    Emotes,
+}
+
+/// Cards shown in the void browser panel.
+#[derive(Clone, Copy, Debug, Default, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
+pub enum VoidBrowserFilter {
+    #[default]
+    All,
+    Characters,
+    Events,
+
+    /// Cards which have an ability allowing them to be played from the void.
+    Reclaimable,
+}
+
+/// Order of cards in the void browser panel.
+#[derive(Clone, Copy, Debug, Default, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
+pub enum VoidBrowserSort {
+    /// Most recently added to the void first.
+    #[default]
+    Recent,
+    Cost,
+    Name,
 }
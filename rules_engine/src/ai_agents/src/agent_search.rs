@@ -1,19 +1,45 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use ai_data::difficulty::AiDifficulty;
 use ai_data::game_ai::GameAI;
-use ai_uct::uct_config::UctConfig;
+use ai_data::personality::AiPersonality;
+use ai_uct::action_pruning::PruningRule;
+use ai_uct::uct_config::{SearchTimeLimits, UctConfig};
 use ai_uct::uct_search;
 use battle_mutations::player_mutations::player_state;
 use battle_queries::legal_action_queries::legal_actions;
 use battle_queries::panic_with;
 use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::BattleState;
+use battle_state::battle_trace::decision_explanation::{DecisionExplanation, ScoredAction};
 use core_data::types::PlayerName;
+use enumset::EnumSet;
 use rand::Rng;
 use rand::seq::IndexedRandom;
 use tracing::{debug, instrument};
 
+use crate::opening_book;
+
+/// Iterations per candidate action when predicting an opponent's likely
+/// actions.
+const PREDICTION_ITERATIONS_PER_ACTION: u32 = 250;
+
+/// Iterations per candidate action when suggesting an action to a human
+/// player.
+const HINT_ITERATIONS_PER_ACTION: u32 = 2000;
+
+/// Maximum time spent searching when suggesting an action to a human player.
+const HINT_TIME_BUDGET: Duration = Duration::from_secs(3);
+
+/// Rules used to prune dominated actions when agents search.
+///
+/// Rules stay disabled until a benchmark against an agent without them shows
+/// they do not weaken play.
+const AGENT_PRUNING_RULES: EnumSet<PruningRule> = EnumSet::empty();
+
 /// Selects an action using a custom UctConfig (exposed for benchmarks to allow
 /// forcing iteration multipliers like setting iteration_multiplier_override).
 pub fn select_action_with_uct_config(
@@ -30,8 +56,141 @@ pub fn select_action_with_uct_config(
 }
 
 /// Selects an action for the given player using the given AI agent.
-#[instrument(skip_all, level = "debug")]
 pub fn select_action(battle: &BattleState, player: PlayerName, game_ai: &GameAI) -> BattleAction {
+    select_action_with_explanation(battle, player, game_ai).0
+}
+
+/// Selects an action for the given player using the given AI agent, along
+/// with an explanation of the decision.
+///
+/// The explanation is None if the action was not chosen by tree search, e.g.
+/// because only one legal action exists or it was found in the opening book.
+#[instrument(skip_all, level = "debug")]
+pub fn select_action_with_explanation(
+    battle: &BattleState,
+    player: PlayerName,
+    game_ai: &GameAI,
+) -> (BattleAction, Option<DecisionExplanation>) {
+    select_checked(battle, player, game_ai, None)
+}
+
+/// Selects an action as in [select_action_with_explanation], stopping tree
+/// search early once `cancelled` is set.
+///
+/// Used to search for responses to opponent actions which may never be taken.
+/// The result of a cancelled search is the best action found so far.
+#[instrument(skip_all, level = "debug")]
+pub fn select_action_cancellable(
+    battle: &BattleState,
+    player: PlayerName,
+    game_ai: &GameAI,
+    cancelled: Arc<AtomicBool>,
+) -> (BattleAction, Option<DecisionExplanation>) {
+    select_checked(battle, player, game_ai, Some(cancelled))
+}
+
+/// Predicts the actions the given player is most likely to take, most likely
+/// first, returning at most `count` actions.
+///
+/// Runs a short tree search from this player's perspective, with the
+/// opponent's hidden information randomized. Search stops early once
+/// `cancelled` is set.
+pub fn predict_actions(
+    battle: &BattleState,
+    player: PlayerName,
+    count: usize,
+    cancelled: Arc<AtomicBool>,
+) -> Vec<BattleAction> {
+    let legal_actions = legal_actions::compute(battle, player);
+    if legal_actions.len() <= 1 {
+        return legal_actions.all();
+    }
+
+    let battle =
+        &player_state::randomize_battle_player(battle, player.opponent(), rand::rng().random());
+    let config = UctConfig {
+        max_iterations_per_action: PREDICTION_ITERATIONS_PER_ACTION,
+        max_total_actions_multiplier: 6,
+        iteration_multiplier_override: Some(1.0),
+        single_threaded: false,
+        time_limits: None,
+        max_tree_depth: None,
+        evaluation_noise: 0.0,
+        cancellation: Some(cancelled),
+        // Pruned duplicate actions may still be played by the opponent, so
+        // every legal action remains a candidate prediction.
+        pruning_rules: EnumSet::empty(),
+        personality: None,
+    };
+    uct_search::search_with_explanation(battle, player, &config)
+        .alternatives
+        .into_iter()
+        .take(count)
+        .map(|scored| scored.action)
+        .collect()
+}
+
+/// Suggests an action for the given player, returning the best candidate
+/// found along with its search results.
+///
+/// Runs a short, time-limited tree search from this player's perspective,
+/// with the opponent's hidden information randomized. Returns None if the
+/// player has fewer than two legal actions or if `cancelled` is set before
+/// search completes.
+pub fn suggest_action(
+    battle: &BattleState,
+    player: PlayerName,
+    cancelled: Arc<AtomicBool>,
+) -> Option<ScoredAction> {
+    if legal_actions::compute(battle, player).len() <= 1 {
+        return None;
+    }
+
+    let battle =
+        &player_state::randomize_battle_player(battle, player.opponent(), rand::rng().random());
+    let config = UctConfig {
+        max_iterations_per_action: HINT_ITERATIONS_PER_ACTION,
+        max_total_actions_multiplier: 6,
+        iteration_multiplier_override: Some(1.0),
+        single_threaded: false,
+        time_limits: Some(SearchTimeLimits::from_budget(HINT_TIME_BUDGET)),
+        max_tree_depth: None,
+        evaluation_noise: 0.0,
+        cancellation: Some(cancelled.clone()),
+        pruning_rules: AGENT_PRUNING_RULES,
+        personality: None,
+    };
+    let explanation = uct_search::search_with_explanation(battle, player, &config);
+    if cancelled.load(Ordering::Relaxed) {
+        return None;
+    }
+    explanation
+        .alternatives
+        .iter()
+        .find(|scored| scored.action == explanation.action)
+        .or(explanation.alternatives.first())
+        .copied()
+}
+
+/// Selects an action for the given player using the given AI agent, without
+/// checking for validity.
+///
+/// Mostly intended for use in benchmarking agents.
+pub fn select_action_unchecked(
+    initial_battle: &BattleState,
+    player: PlayerName,
+    game_ai: &GameAI,
+    iteration_multiplier_override: Option<f64>,
+) -> BattleAction {
+    select(initial_battle, player, game_ai, iteration_multiplier_override, None).0
+}
+
+fn select_checked(
+    battle: &BattleState,
+    player: PlayerName,
+    game_ai: &GameAI,
+    cancellation: Option<Arc<AtomicBool>>,
+) -> (BattleAction, Option<DecisionExplanation>) {
     assert_eq!(legal_actions::next_to_act(battle), Some(player));
 
     let legal_actions = legal_actions::compute(battle, player);
@@ -41,46 +200,64 @@ pub fn select_action(battle: &BattleState, player: PlayerName, game_ai: &GameAI)
 
     if legal_actions.len() == 1 {
         debug!("Automatically selecting action {:?}", legal_actions.all()[0]);
-        return legal_actions.all()[0];
+        return (legal_actions.all()[0], None);
     }
 
     let start_time = Instant::now();
-    let action = select_action_unchecked(battle, player, game_ai, None);
+    let (action, explanation) = select(battle, player, game_ai, None, cancellation);
     debug!(
         "Agent selected action {:?} in {:.3} seconds",
         action,
         start_time.elapsed().as_secs_f64()
     );
-    action
+    (action, explanation)
 }
 
-/// Selects an action for the given player using the given AI agent, without
-/// checking for validity.
-///
-/// Mostly intended for use in benchmarking agents.
-pub fn select_action_unchecked(
+fn select(
     initial_battle: &BattleState,
     player: PlayerName,
     game_ai: &GameAI,
     iteration_multiplier_override: Option<f64>,
-) -> BattleAction {
-    let battle = &player_state::randomize_battle_player(
-        initial_battle,
-        player.opponent(),
-        rand::rng().random(),
-    );
+    cancellation: Option<Arc<AtomicBool>>,
+) -> (BattleAction, Option<DecisionExplanation>) {
+    let mut rng = uct_search::decision_rng(initial_battle);
+    if let GameAI::Difficulty(difficulty) = game_ai
+        && rng.random_bool(difficulty.preset().blunder_rate)
+    {
+        debug!(?difficulty, "Selecting random action for difficulty preset");
+        return (random_action(initial_battle, player, &mut rng), None);
+    }
+
+    if matches!(
+        game_ai,
+        GameAI::MonteCarlo(_) | GameAI::MonteCarloSingleThreaded(_) | GameAI::Difficulty(_)
+    ) && let Some(action) = opening_book::lookup(initial_battle, player)
+    {
+        debug!(?action, "Selected action from opening book");
+        return (action, None);
+    }
+
+    let personality = initial_battle.players.player(player).personality;
+    let battle =
+        &player_state::randomize_battle_player(initial_battle, player.opponent(), rng.random());
     match game_ai {
         GameAI::AlwaysPanic => panic!("Always panic agent called for an action"),
-        GameAI::FirstAvailableAction => first_available_action(battle, player),
-        GameAI::RandomAction => random_action(battle, player),
+        GameAI::FirstAvailableAction => (first_available_action(battle, player), None),
+        GameAI::RandomAction => (random_action(battle, player, &mut rng), None),
         GameAI::MonteCarlo(thousands_of_iterations) => {
             let config = UctConfig {
                 max_iterations_per_action: *thousands_of_iterations * 1000,
                 max_total_actions_multiplier: 6,
                 iteration_multiplier_override,
                 single_threaded: false,
+                time_limits: None,
+                max_tree_depth: None,
+                evaluation_noise: 0.0,
+                cancellation,
+                pruning_rules: AGENT_PRUNING_RULES,
+                personality,
             };
-            uct_search::search(battle, player, &config)
+            explained(uct_search::search_with_explanation(battle, player, &config))
         }
         GameAI::MonteCarloSingleThreaded(thousands_of_iterations) => {
             let config = UctConfig {
@@ -88,22 +265,62 @@ pub fn select_action_unchecked(
                 max_total_actions_multiplier: 6,
                 iteration_multiplier_override,
                 single_threaded: true,
+                time_limits: None,
+                max_tree_depth: None,
+                evaluation_noise: 0.0,
+                cancellation,
+                pruning_rules: AGENT_PRUNING_RULES,
+                personality,
             };
-            uct_search::search(battle, player, &config)
+            explained(uct_search::search_with_explanation(battle, player, &config))
+        }
+        GameAI::Difficulty(difficulty) => {
+            let config = difficulty_config(
+                *difficulty,
+                personality,
+                iteration_multiplier_override,
+                cancellation,
+            );
+            explained(uct_search::search_with_explanation(battle, player, &config))
         }
         GameAI::WaitFiveSeconds => {
             thread::sleep(Duration::from_secs(5));
-            first_available_action(battle, player)
+            (first_available_action(battle, player), None)
         }
     }
 }
 
+fn explained(explanation: DecisionExplanation) -> (BattleAction, Option<DecisionExplanation>) {
+    (explanation.action, Some(explanation))
+}
+
+fn difficulty_config(
+    difficulty: AiDifficulty,
+    personality: Option<AiPersonality>,
+    iteration_multiplier_override: Option<f64>,
+    cancellation: Option<Arc<AtomicBool>>,
+) -> UctConfig {
+    let preset = difficulty.preset();
+    UctConfig {
+        max_iterations_per_action: preset.thousands_of_iterations * 1000,
+        max_total_actions_multiplier: 6,
+        iteration_multiplier_override,
+        single_threaded: false,
+        time_limits: Some(SearchTimeLimits::from_budget(preset.time_budget)),
+        max_tree_depth: preset.max_tree_depth,
+        evaluation_noise: preset.evaluation_noise,
+        cancellation,
+        pruning_rules: AGENT_PRUNING_RULES,
+        personality,
+    }
+}
+
 fn first_available_action(battle: &BattleState, player: PlayerName) -> BattleAction {
     let actions = legal_actions::compute(battle, player).all();
     *actions.first().unwrap()
 }
 
-fn random_action(battle: &BattleState, player: PlayerName) -> BattleAction {
+fn random_action(battle: &BattleState, player: PlayerName, rng: &mut impl Rng) -> BattleAction {
     let actions = legal_actions::compute(battle, player).all();
-    *actions.choose(&mut rand::rng()).unwrap()
+    *actions.choose(rng).unwrap()
 }
@@ -1 +1,2 @@
 pub mod agent_search;
+pub mod opening_book;
@@ -0,0 +1,158 @@
+use std::sync::LazyLock;
+
+use battle_queries::battle_card_queries::card;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_queries::legal_action_queries::legal_actions_data::ForPlayer;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_turn_phase::BattleTurnPhase;
+use battle_state::battle_player::battle_player_state::TestDeckName;
+use core_data::identifiers::BaseCardId;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+use serde::{Deserialize, Serialize};
+
+static OPENING_BOOK: LazyLock<OpeningBook> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../data/opening_book.json"))
+        .expect("Error parsing opening book")
+});
+
+/// Last turn, counted from 1 for each player, for which the opening book is
+/// consulted.
+pub const MAX_BOOK_TURN: u32 = 3;
+
+/// Minimum number of recorded self-play games required before an opening book
+/// entry is trusted over search.
+pub const MIN_BOOK_GAMES: u32 = 5;
+
+/// Preferred actions for early-game positions, produced by self-play.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpeningBook {
+    pub entries: Vec<OpeningBookEntry>,
+}
+
+/// Statistics for taking a given action from a given early-game position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpeningBookEntry {
+    pub signature: OpeningSignature,
+    pub action: OpeningAction,
+    pub games: u32,
+    pub wins: u32,
+}
+
+/// Identifies an early-game position independently of battle card IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OpeningSignature {
+    pub deck: TestDeckName,
+    pub turn: u32,
+    pub energy: Energy,
+
+    /// Cards in the player's hand, sorted.
+    pub hand: Vec<BaseCardId>,
+}
+
+/// An action stored in the opening book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OpeningAction {
+    PlayCardFromHand(BaseCardId),
+    EndTurn,
+}
+
+/// Returns the action the built-in opening book prefers for `player`, if any.
+pub fn lookup(battle: &BattleState, player: PlayerName) -> Option<BattleAction> {
+    OPENING_BOOK.select(battle, player)
+}
+
+/// Returns the opening book signature for `player` in the given battle.
+///
+/// Returns None if this is not an early-game main phase decision for the
+/// active player.
+pub fn signature(battle: &BattleState, player: PlayerName) -> Option<OpeningSignature> {
+    let turn = battle.turn.turn_id.0.div_euclid(2) + 1;
+    if turn > MAX_BOOK_TURN
+        || battle.turn.active_player != player
+        || battle.phase != BattleTurnPhase::Main
+        || battle.cards.has_stack()
+        || !battle.prompts.is_empty()
+    {
+        return None;
+    }
+
+    let mut hand = battle
+        .cards
+        .hand(player)
+        .iter()
+        .map(|card_id| card::get_base_card_id(battle, card_id))
+        .collect::<Vec<_>>();
+    hand.sort();
+    let state = battle.players.player(player);
+    Some(OpeningSignature { deck: state.deck_name, turn, energy: state.current_energy, hand })
+}
+
+/// Converts a battle action into its opening book representation, if it can
+/// be stored in the book.
+pub fn to_opening_action(battle: &BattleState, action: BattleAction) -> Option<OpeningAction> {
+    match action {
+        BattleAction::PlayCardFromHand(card_id) => {
+            Some(OpeningAction::PlayCardFromHand(card::get_base_card_id(battle, card_id)))
+        }
+        BattleAction::EndTurn => Some(OpeningAction::EndTurn),
+        _ => None,
+    }
+}
+
+impl OpeningBook {
+    /// Returns the legal action this book prefers for `player` in the given
+    /// battle, if any.
+    pub fn select(&self, battle: &BattleState, player: PlayerName) -> Option<BattleAction> {
+        let signature = signature(battle, player)?;
+        let entry = self
+            .entries
+            .iter()
+            .filter(|entry| entry.signature == signature && entry.games >= MIN_BOOK_GAMES)
+            .max_by(|a, b| a.win_rate().total_cmp(&b.win_rate()))?;
+        let action = to_battle_action(battle, player, entry.action)?;
+        legal_actions::compute(battle, player).contains(action, ForPlayer::Agent).then_some(action)
+    }
+
+    /// Records the outcome of a self-play game in which `action` was taken
+    /// from the position described by `signature`.
+    pub fn record(&mut self, signature: OpeningSignature, action: OpeningAction, won: bool) {
+        if let Some(entry) =
+            self.entries.iter_mut().find(|e| e.signature == signature && e.action == action)
+        {
+            entry.games += 1;
+            entry.wins += u32::from(won);
+        } else {
+            self.entries.push(OpeningBookEntry {
+                signature,
+                action,
+                games: 1,
+                wins: u32::from(won),
+            });
+        }
+    }
+}
+
+impl OpeningBookEntry {
+    /// Fraction of recorded games won after taking this action.
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 { 0.0 } else { f64::from(self.wins) / f64::from(self.games) }
+    }
+}
+
+fn to_battle_action(
+    battle: &BattleState,
+    player: PlayerName,
+    action: OpeningAction,
+) -> Option<BattleAction> {
+    match action {
+        OpeningAction::PlayCardFromHand(base_card_id) => battle
+            .cards
+            .hand(player)
+            .iter()
+            .find(|&card_id| card::get_base_card_id(battle, card_id) == base_card_id)
+            .map(BattleAction::PlayCardFromHand),
+        OpeningAction::EndTurn => Some(BattleAction::EndTurn),
+    }
+}
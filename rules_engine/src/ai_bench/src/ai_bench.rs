@@ -0,0 +1,290 @@
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use ai_agents::agent_search;
+use ai_data::game_ai::GameAI;
+use battle_mutations::actions::apply_battle_action;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::battle::battle_state::{LoggingOptions, RequestContext};
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle_cards::dreamwell_data::Dreamwell;
+use battle_state::battle_player::battle_player_state::{
+    CreateBattlePlayer, PlayerType, TestDeckName,
+};
+use clap::Parser;
+use core_data::identifiers::BattleId;
+use core_data::types::PlayerName;
+use game_creation::new_test_battle;
+use state_provider::state_provider::StateProvider;
+use state_provider::test_state_provider::TestStateProvider;
+use tabula_generated::card_lists::DreamwellCardIdList;
+use tracing::subscriber;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer};
+use uuid::Uuid;
+
+/// z-score for a two-sided 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Benchmark a candidate AI against a baseline with SPRT early stopping",
+    after_help = "Exits with a non-zero status if the candidate is a regression.\n\nEXAMPLE:\n    ai_bench '{\"MonteCarlo\":1}' '{\"MonteCarlo\":2}' --max-games 400"
+)]
+struct Args {
+    #[arg(help = "JSON serialized GameAI for the baseline")]
+    baseline: String,
+
+    #[arg(help = "JSON serialized GameAI for the candidate")]
+    candidate: String,
+
+    #[arg(
+        long,
+        default_value = "3141592653",
+        help = "Base random seed. Each seed is played twice with positions swapped"
+    )]
+    seed: u64,
+
+    #[arg(long, default_value = "StartingFive", value_parser = parse_deck, help = "Deck used by both players")]
+    deck: TestDeckName,
+
+    #[arg(long, default_value = "1000", help = "Maximum number of games to play")]
+    max_games: usize,
+
+    #[arg(
+        long,
+        default_value = "-10.0",
+        allow_hyphen_values = true,
+        help = "Elo difference of the null hypothesis (candidate is a regression)"
+    )]
+    elo0: f64,
+
+    #[arg(
+        long,
+        default_value = "0.0",
+        allow_hyphen_values = true,
+        help = "Elo difference of the alternative hypothesis (candidate is not a regression)"
+    )]
+    elo1: f64,
+
+    #[arg(long, default_value = "0.05", help = "False positive rate for SPRT")]
+    alpha: f64,
+
+    #[arg(long, default_value = "0.05", help = "False negative rate for SPRT")]
+    beta: f64,
+}
+
+/// Game results from the candidate's perspective.
+#[derive(Debug, Default, Clone, Copy)]
+struct Tally {
+    wins: usize,
+    losses: usize,
+    draws: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SprtResult {
+    AcceptH0,
+    AcceptH1,
+    Continue,
+}
+
+impl Tally {
+    fn games(&self) -> usize {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Average score per game, counting draws as half a win.
+    fn score(&self) -> f64 {
+        (self.wins as f64 + 0.5 * self.draws as f64) / self.games() as f64
+    }
+
+    /// Per-game variance of the score.
+    fn variance(&self) -> f64 {
+        let score = self.score();
+        let games = self.games() as f64;
+        (self.wins as f64 * (1.0 - score).powi(2)
+            + self.losses as f64 * score.powi(2)
+            + self.draws as f64 * (0.5 - score).powi(2))
+            / games
+    }
+
+    /// Estimated Elo difference of the candidate over the baseline.
+    fn elo(&self) -> f64 {
+        elo_from_score(self.score())
+    }
+
+    /// Bounds of the 95% confidence interval for the Elo difference.
+    fn confidence_interval(&self) -> (f64, f64) {
+        let margin = CONFIDENCE_Z * (self.variance() / self.games() as f64).sqrt();
+        let score = self.score();
+        (elo_from_score(score - margin), elo_from_score(score + margin))
+    }
+
+    /// Log-likelihood ratio of H1 (`elo1`) over H0 (`elo0`), using the normal
+    /// approximation to the generalized SPRT.
+    fn log_likelihood_ratio(&self, elo0: f64, elo1: f64) -> f64 {
+        let variance = self.variance();
+        if variance <= 0.0 {
+            return 0.0;
+        }
+        let (score0, score1) = (score_from_elo(elo0), score_from_elo(elo1));
+        (score1 - score0) * (2.0 * self.score() - score0 - score1) * self.games() as f64
+            / (2.0 * variance)
+    }
+}
+
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    if args.max_games == 0 {
+        return Err("Number of games must be greater than 0".into());
+    }
+    if args.elo1 <= args.elo0 {
+        return Err("elo1 must be greater than elo0".into());
+    }
+    let baseline: GameAI = serde_json::from_str(&args.baseline)?;
+    let candidate: GameAI = serde_json::from_str(&args.candidate)?;
+
+    let provider = TestStateProvider::new();
+    let streaming_assets_path = logging::get_developer_mode_streaming_assets_path();
+    let _ = provider.initialize("/tmp/test", &streaming_assets_path);
+
+    let lower_bound = (args.beta / (1.0 - args.alpha)).ln();
+    let upper_bound = ((1.0 - args.beta) / args.alpha).ln();
+    println!(
+        "Benchmarking {} (candidate) against {} (baseline), H0: {} Elo, H1: {} Elo",
+        args.candidate, args.baseline, args.elo0, args.elo1
+    );
+
+    let filter = EnvFilter::new("warn");
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_subscriber::fmt::layer().with_filter(filter));
+    let start_time = Instant::now();
+    let mut tally = Tally::default();
+    let mut result = SprtResult::Continue;
+    subscriber::with_default(subscriber, || {
+        for game in 0..args.max_games {
+            let candidate_player = if game % 2 == 0 { PlayerName::One } else { PlayerName::Two };
+            let seed = args.seed.wrapping_add(game.div_euclid(2) as u64);
+            match run_game(&provider, baseline, candidate, candidate_player, args.deck, seed) {
+                Some(winner) if winner == candidate_player => tally.wins += 1,
+                Some(_) => tally.losses += 1,
+                None => tally.draws += 1,
+            }
+
+            let llr = tally.log_likelihood_ratio(args.elo0, args.elo1);
+            print!(
+                "\r\x1B[2KGame {}: +{} -{} ={} Elo {:+.1} LLR {:.2} [{:.2}, {:.2}]",
+                tally.games(),
+                tally.wins,
+                tally.losses,
+                tally.draws,
+                tally.elo(),
+                llr,
+                lower_bound,
+                upper_bound
+            );
+            let _ = io::stdout().flush();
+
+            // Only stop after complete pairs so both players have played each
+            // seed from both positions.
+            if game % 2 == 1 {
+                if llr >= upper_bound {
+                    result = SprtResult::AcceptH1;
+                    break;
+                } else if llr <= lower_bound {
+                    result = SprtResult::AcceptH0;
+                    break;
+                }
+            }
+        }
+    });
+    println!();
+
+    let (elo_low, elo_high) = tally.confidence_interval();
+    println!("\n===== Benchmark Results =====");
+    println!("Games: {} in {:.2?}", tally.games(), start_time.elapsed());
+    println!("Candidate W/L/D: {}/{}/{}", tally.wins, tally.losses, tally.draws);
+    println!("Elo difference: {:+.1} (95% CI {:+.1} to {:+.1})", tally.elo(), elo_low, elo_high);
+
+    let regression = match result {
+        SprtResult::AcceptH1 => {
+            println!("SPRT: accepted H1, candidate is not a regression");
+            false
+        }
+        SprtResult::AcceptH0 => {
+            println!("SPRT: accepted H0, candidate is a regression");
+            true
+        }
+        SprtResult::Continue => {
+            println!("SPRT: inconclusive after {} games", tally.games());
+            tally.elo() <= args.elo0
+        }
+    };
+
+    Ok(if regression { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+/// Plays a single game, returning the winner or None for a draw.
+fn run_game(
+    provider: &TestStateProvider,
+    baseline: GameAI,
+    candidate: GameAI,
+    candidate_player: PlayerName,
+    deck: TestDeckName,
+    seed: u64,
+) -> Option<PlayerName> {
+    let ai_for = |player: PlayerName| if player == candidate_player { candidate } else { baseline };
+    let mut battle = new_test_battle::create_and_start(
+        BattleId(Uuid::new_v4()),
+        provider.tabula(),
+        seed,
+        Dreamwell::from_card_list(
+            &provider.tabula(),
+            DreamwellCardIdList::TestDreamwellNoAbilities,
+        ),
+        CreateBattlePlayer {
+            player_type: PlayerType::Agent(ai_for(PlayerName::One)),
+            deck_name: deck,
+            personality: None,
+        },
+        CreateBattlePlayer {
+            player_type: PlayerType::Agent(ai_for(PlayerName::Two)),
+            deck_name: deck,
+            personality: None,
+        },
+        RequestContext { logging_options: LoggingOptions::default() },
+    );
+
+    while let Some(player) = legal_actions::next_to_act(&battle) {
+        let legal = legal_actions::compute(&battle, player);
+        let action = if legal.len() == 1 {
+            legal.all()[0]
+        } else {
+            agent_search::select_action_unchecked(&battle, player, &ai_for(player), None)
+        };
+        apply_battle_action::execute(&mut battle, player, action);
+    }
+
+    match battle.status {
+        BattleStatus::GameOver { winner } => winner,
+        _ => panic!("Battle ended without reaching game over"),
+    }
+}
+
+/// Converts an expected score to an Elo difference.
+fn elo_from_score(score: f64) -> f64 {
+    let score = score.clamp(0.001, 0.999);
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+/// Converts an Elo difference to an expected score.
+fn score_from_elo(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+fn parse_deck(name: &str) -> Result<TestDeckName, String> {
+    serde_json::from_str(&format!("\"{name}\"")).map_err(|_| format!("Unknown deck '{name}'"))
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Difficulty levels for AI opponents.
+///
+/// Levels are ordered by search effort. Their presets have not been measured
+/// against each other, so the relative strength of adjacent levels is not
+/// known.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
+pub enum AiDifficulty {
+    Easy,
+    Medium,
+    Hard,
+    Max,
+}
+
+/// Search parameters for an [AiDifficulty] level.
+///
+/// Blunders and evaluation noise are drawn from the battle's seeded random
+/// number generator, so a given battle state always makes the same choices.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DifficultyPreset {
+    /// Monte Carlo iterations per candidate action, in thousands.
+    pub thousands_of_iterations: u32,
+
+    /// Maximum depth of the search tree below each candidate action, if any.
+    ///
+    /// Nodes at this depth are never expanded further, and are evaluated
+    /// directly by random rollout.
+    pub max_tree_depth: Option<u32>,
+
+    /// Maximum random noise added to each candidate's average reward (in the
+    /// range -1.0 to 1.0) before picking the best action.
+    pub evaluation_noise: f64,
+
+    /// Probability of skipping search entirely and picking a random legal
+    /// action.
+    pub blunder_rate: f64,
+
+    /// Wall-clock time budget for each decision.
+    ///
+    /// Search returns the best action found so far once this elapses, even if
+    /// the iteration budget has not been used up.
+    pub time_budget: Duration,
+}
+
+impl AiDifficulty {
+    /// Returns the search parameters for this difficulty level.
+    pub fn preset(self) -> DifficultyPreset {
+        match self {
+            AiDifficulty::Easy => DifficultyPreset {
+                thousands_of_iterations: 1,
+                max_tree_depth: Some(2),
+                evaluation_noise: 0.3,
+                blunder_rate: 0.2,
+                time_budget: Duration::from_millis(250),
+            },
+            AiDifficulty::Medium => DifficultyPreset {
+                thousands_of_iterations: 5,
+                max_tree_depth: Some(4),
+                evaluation_noise: 0.1,
+                blunder_rate: 0.05,
+                time_budget: Duration::from_secs(1),
+            },
+            AiDifficulty::Hard => DifficultyPreset {
+                thousands_of_iterations: 25,
+                max_tree_depth: None,
+                evaluation_noise: 0.0,
+                blunder_rate: 0.0,
+                time_budget: Duration::from_secs(2),
+            },
+            AiDifficulty::Max => DifficultyPreset {
+                thousands_of_iterations: 100,
+                max_tree_depth: None,
+                evaluation_noise: 0.0,
+                blunder_rate: 0.0,
+                time_budget: Duration::from_secs(5),
+            },
+        }
+    }
+}
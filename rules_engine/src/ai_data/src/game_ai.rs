@@ -1,6 +1,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::difficulty::AiDifficulty;
+
 #[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
 pub enum GameAI {
     AlwaysPanic,
@@ -8,6 +10,7 @@ pub enum GameAI {
     RandomAction,
     MonteCarlo(u32),
     MonteCarloSingleThreaded(u32),
+    Difficulty(AiDifficulty),
     WaitFiveSeconds,
 }
 
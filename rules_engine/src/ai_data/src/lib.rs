@@ -1 +1,3 @@
+pub mod difficulty;
 pub mod game_ai;
+pub mod personality;
@@ -0,0 +1,95 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Play styles which bias AI opponents toward a particular strategy,
+/// independently of their search strength.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash, Deserialize, JsonSchema)]
+pub enum AiPersonality {
+    /// Develops the battlefield quickly and races for points.
+    Aggro,
+
+    /// Trades resources with the opponent and wins through attrition.
+    Control,
+
+    /// Holds cards and energy back to assemble a single powerful turn.
+    Combo,
+}
+
+/// Search biases for an [AiPersonality].
+///
+/// Features are compared between the searching player and their opponent at
+/// the position reached by the tree policy, before the random rollout.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PersonalityWeights {
+    /// Fraction of each rollout's reward taken from the weighted feature score
+    /// instead of from the rollout's winner, in the range 0.0 to 1.0.
+    pub style_weight: f64,
+
+    /// Weight of the difference in points.
+    pub points: f64,
+
+    /// Weight of the difference in total spark.
+    pub spark: f64,
+
+    /// Weight of the difference in characters on the battlefield.
+    pub characters: f64,
+
+    /// Weight of the difference in cards in hand.
+    pub hand: f64,
+
+    /// Weight of the searching player's unspent energy.
+    pub energy: f64,
+
+    /// Bonus added to the average reward of candidate actions which play a
+    /// character.
+    pub develop_bonus: f64,
+
+    /// Bonus added to the average reward of candidate actions which play an
+    /// event, activate an ability or select a target.
+    pub interact_bonus: f64,
+
+    /// Bonus added to the average reward of candidate actions which pass
+    /// priority or end the turn.
+    pub hold_bonus: f64,
+}
+
+impl AiPersonality {
+    /// Returns the search biases for this personality.
+    pub fn weights(self) -> PersonalityWeights {
+        match self {
+            AiPersonality::Aggro => PersonalityWeights {
+                style_weight: 0.25,
+                points: 1.0,
+                spark: 0.75,
+                characters: 0.25,
+                hand: 0.0,
+                energy: 0.0,
+                develop_bonus: 0.05,
+                interact_bonus: 0.0,
+                hold_bonus: 0.0,
+            },
+            AiPersonality::Control => PersonalityWeights {
+                style_weight: 0.25,
+                points: 0.25,
+                spark: 0.0,
+                characters: 1.0,
+                hand: 0.75,
+                energy: 0.0,
+                develop_bonus: 0.0,
+                interact_bonus: 0.05,
+                hold_bonus: 0.0,
+            },
+            AiPersonality::Combo => PersonalityWeights {
+                style_weight: 0.2,
+                points: 0.25,
+                spark: 0.0,
+                characters: 0.0,
+                hand: 1.0,
+                energy: 0.5,
+                develop_bonus: 0.0,
+                interact_bonus: 0.0,
+                hold_bonus: 0.05,
+            },
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use ai_agents::agent_search;
+use ai_agents::opening_book::{self, OpeningAction, OpeningBook, OpeningSignature};
 use battle_mutations::actions::apply_battle_action;
 use battle_queries::legal_action_queries::legal_actions;
 use battle_state::battle::battle_state::{LoggingOptions, RequestContext};
@@ -57,6 +60,12 @@ struct Args {
         help = "Number of matches to run, alternating player position"
     )]
     matches: usize,
+
+    #[arg(
+        long,
+        help = "Record early-turn decisions and results into the opening book JSON file at this path"
+    )]
+    opening_book: Option<PathBuf>,
 }
 
 struct MatchResult {
@@ -95,6 +104,7 @@ impl AgentTimingStats {
 struct MatchActionStats {
     ai_one: AgentTimingStats,
     ai_two: AgentTimingStats,
+    opening_decisions: Vec<(PlayerName, OpeningSignature, OpeningAction)>,
 }
 
 #[derive(Debug)]
@@ -157,8 +167,16 @@ fn run_match(
             &provider.tabula(),
             DreamwellCardIdList::TestDreamwellNoAbilities,
         ),
-        CreateBattlePlayer { player_type: battle_ai_one, deck_name: TestDeckName::StartingFive },
-        CreateBattlePlayer { player_type: battle_ai_two, deck_name: TestDeckName::StartingFive },
+        CreateBattlePlayer {
+            player_type: battle_ai_one,
+            deck_name: TestDeckName::StartingFive,
+            personality: None,
+        },
+        CreateBattlePlayer {
+            player_type: battle_ai_two,
+            deck_name: TestDeckName::StartingFive,
+            personality: None,
+        },
         RequestContext { logging_options: LoggingOptions::default() },
     );
 
@@ -166,6 +184,7 @@ fn run_match(
     let mut turn_count = 0;
     let mut ai_one_stats = AgentTimingStats::default();
     let mut ai_two_stats = AgentTimingStats::default();
+    let mut opening_decisions = Vec::new();
 
     subscriber::with_default(subscriber, || {
         while !matches!(battle.status, BattleStatus::GameOver { .. }) {
@@ -191,6 +210,11 @@ fn run_match(
                     agent_search::select_action_unchecked(&battle, player, &player_ai, None)
                 };
                 let action_time = action_start.elapsed();
+                if let Some(signature) = opening_book::signature(&battle, player)
+                    && let Some(opening_action) = opening_book::to_opening_action(&battle, action)
+                {
+                    opening_decisions.push((player, signature, opening_action));
+                }
                 match (player, swap_positions) {
                     (PlayerName::One, false) | (PlayerName::Two, true) => {
                         ai_one_stats.record(action_time);
@@ -224,7 +248,7 @@ fn run_match(
     }
 
     let elapsed = start_time.elapsed();
-    let stats = MatchActionStats { ai_one: ai_one_stats, ai_two: ai_two_stats };
+    let stats = MatchActionStats { ai_one: ai_one_stats, ai_two: ai_two_stats, opening_decisions };
     match battle.status {
         BattleStatus::GameOver { winner: None } => (MatchOutcome::Draw(turn_count, elapsed), stats),
         BattleStatus::GameOver { winner: Some(winner) } => {
@@ -253,6 +277,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ai_two_timing: AgentTimingStats::default(),
     };
 
+    let mut book = args.opening_book.as_deref().map(load_opening_book).transpose()?;
+
     if args.matches > 1 {
         println!(
             "Running {} matches between {} and {}",
@@ -289,6 +315,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         if stats.ai_two.max > results.ai_two_timing.max {
             results.ai_two_timing.max = stats.ai_two.max;
         }
+        if let Some(book) = &mut book {
+            let winner = match outcome {
+                MatchOutcome::Winner(winner, ..) => Some(winner),
+                MatchOutcome::Draw(..) => None,
+            };
+            for (player, signature, action) in stats.opening_decisions {
+                book.record(signature, action, winner == Some(player));
+            }
+        }
+
         match outcome {
             MatchOutcome::Winner(winner, turns, elapsed) => {
                 match (winner, swap_positions) {
@@ -421,6 +457,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let (Some(path), Some(book)) = (&args.opening_book, &book) {
+        fs::write(path, serde_json::to_string_pretty(book)?)?;
+        println!("Wrote {} opening book entries to {}", book.entries.len(), path.display());
+    }
+
     Ok(())
 }
 
@@ -443,3 +484,11 @@ fn print_match_summary(
         );
     }
 }
+
+fn load_opening_book(path: &Path) -> Result<OpeningBook, Box<dyn std::error::Error>> {
+    if path.exists() {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    } else {
+        Ok(OpeningBook::default())
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+
+use battle_queries::battle_card_queries::{card, card_properties};
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_card_definitions::BattleCardIdentity;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardId, CardIdType};
+use battle_state::prompt_types::prompt_data::{HandCardEffect, PromptType};
+use core_data::numerics::{Energy, Spark};
+use enumset::{EnumSet, EnumSetType};
+
+/// A rule identifying legal actions which are dominated by another legal
+/// action and can be skipped during search.
+///
+/// Each rule is described by an entry in [RULES], and can be disabled
+/// individually via `UctConfig::pruning_rules` so that strength regressions
+/// can be bisected.
+#[derive(Debug, Hash, Ord, PartialOrd, EnumSetType)]
+pub enum PruningRule {
+    /// Playing a card from hand when an identical copy in hand costs the same
+    /// or less.
+    DuplicateHandCard,
+
+    /// Playing a card from the void when an identical copy in the void costs
+    /// the same or less.
+    DuplicateVoidCard,
+
+    /// Discarding a card which was returned to hand from the void this turn,
+    /// when another card could be discarded instead.
+    DiscardReturnedCard,
+}
+
+/// Kind of action a [PruningRule] compares.
+#[derive(Debug, Clone, Copy)]
+enum ActionKind {
+    PlayFromHand,
+    PlayFromVoid,
+    SelectCardToDiscard,
+}
+
+/// How a [PruningRule] decides which of the actions it compares are
+/// dominated.
+#[derive(Debug, Clone, Copy)]
+enum Dominance {
+    /// Of actions for identical cards, all but the cheapest are dominated.
+    ///
+    /// Cards are identical if they share a card identity, base spark and fast
+    /// flag. Ties in cost are broken by action order.
+    CostlierIdenticalCard,
+
+    /// Actions for cards returned to hand from the void this turn are
+    /// dominated if an action of the same kind exists for any other card.
+    ReturnedToHandThisTurn,
+}
+
+/// Describes the actions a [PruningRule] applies to.
+struct RuleDefinition {
+    rule: PruningRule,
+    kind: ActionKind,
+    dominance: Dominance,
+}
+
+/// Every pruning rule, in the order they are applied.
+const RULES: &[RuleDefinition] = &[
+    RuleDefinition {
+        rule: PruningRule::DuplicateHandCard,
+        kind: ActionKind::PlayFromHand,
+        dominance: Dominance::CostlierIdenticalCard,
+    },
+    RuleDefinition {
+        rule: PruningRule::DuplicateVoidCard,
+        kind: ActionKind::PlayFromVoid,
+        dominance: Dominance::CostlierIdenticalCard,
+    },
+    RuleDefinition {
+        rule: PruningRule::DiscardReturnedCard,
+        kind: ActionKind::SelectCardToDiscard,
+        dominance: Dominance::ReturnedToHandThisTurn,
+    },
+];
+
+/// Removes actions from `actions` which are dominated by another action under
+/// one of the enabled `rules`.
+///
+/// Actions not covered by any enabled rule are always kept, and the relative
+/// order of kept actions is preserved.
+pub fn prune(
+    battle: &BattleState,
+    actions: Vec<BattleAction>,
+    rules: EnumSet<PruningRule>,
+) -> Vec<BattleAction> {
+    RULES
+        .iter()
+        .filter(|definition| rules.contains(definition.rule))
+        .fold(actions, |actions, definition| apply_rule(battle, actions, definition))
+}
+
+fn apply_rule(
+    battle: &BattleState,
+    actions: Vec<BattleAction>,
+    definition: &RuleDefinition,
+) -> Vec<BattleAction> {
+    let cards = actions
+        .iter()
+        .filter_map(|&action| Some((action, definition.kind.card(battle, action)?)))
+        .collect::<Vec<_>>();
+    let dominated = match definition.dominance {
+        Dominance::CostlierIdenticalCard => costlier_identical_cards(battle, &cards),
+        Dominance::ReturnedToHandThisTurn => returned_to_hand_this_turn(battle, &cards),
+    };
+    actions.into_iter().filter(|action| !dominated.contains(action)).collect()
+}
+
+impl ActionKind {
+    /// Returns the card `action` uses, if it is an action of this kind.
+    fn card(self, battle: &BattleState, action: BattleAction) -> Option<CardId> {
+        match (self, action) {
+            (ActionKind::PlayFromHand, BattleAction::PlayCardFromHand(card_id)) => {
+                Some(card_id.card_id())
+            }
+            (ActionKind::PlayFromVoid, BattleAction::PlayCardFromVoid(card_id)) => {
+                Some(card_id.card_id())
+            }
+            (ActionKind::SelectCardToDiscard, BattleAction::SelectHandCardTarget(card_id))
+                if is_discard_prompt(battle) =>
+            {
+                Some(card_id.card_id())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_discard_prompt(battle: &BattleState) -> bool {
+    battle.prompts.front().is_some_and(|prompt| {
+        matches!(
+            &prompt.prompt_type,
+            PromptType::ChooseHandCards(choose) if matches!(choose.effect, HandCardEffect::Discard)
+        )
+    })
+}
+
+/// Cards which are interchangeable for [Dominance::CostlierIdenticalCard].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct IdenticalCardKey {
+    identity: BattleCardIdentity,
+    base_spark: Option<Spark>,
+    is_fast: bool,
+}
+
+fn costlier_identical_cards(
+    battle: &BattleState,
+    cards: &[(BattleAction, CardId)],
+) -> Vec<BattleAction> {
+    let mut cheapest: BTreeMap<IdenticalCardKey, (Energy, BattleAction)> = BTreeMap::new();
+    for &(action, card_id) in cards {
+        let state = card::get(battle, card_id);
+        let key = IdenticalCardKey {
+            identity: state.identity,
+            base_spark: state.base_spark,
+            is_fast: state.is_fast,
+        };
+        let cost = card_properties::converted_energy_cost(battle, card_id);
+        cheapest
+            .entry(key)
+            .and_modify(|best| {
+                if (cost, action) < *best {
+                    *best = (cost, action);
+                }
+            })
+            .or_insert((cost, action));
+    }
+
+    let kept = cheapest.into_values().map(|(_, action)| action).collect::<Vec<_>>();
+    cards.iter().map(|&(action, _)| action).filter(|action| !kept.contains(action)).collect()
+}
+
+fn returned_to_hand_this_turn(
+    battle: &BattleState,
+    cards: &[(BattleAction, CardId)],
+) -> Vec<BattleAction> {
+    let returned = &battle.ability_state.until_end_of_turn.returned_to_hand;
+    let (dominated, alternatives): (Vec<_>, Vec<_>) =
+        cards.iter().partition(|(_, card_id)| returned.contains(*card_id));
+    if alternatives.is_empty() {
+        return Vec::new();
+    }
+    dominated.into_iter().map(|&(action, _)| action).collect()
+}
@@ -1,4 +1,7 @@
+pub mod action_pruning;
 pub mod log_search_results;
+pub mod personality_evaluation;
+pub mod prompt_cache;
 pub mod uct_config;
 pub mod uct_search;
 pub mod uct_tree;
@@ -0,0 +1,103 @@
+use ai_data::personality::PersonalityWeights;
+use battle_queries::battle_card_queries::card_properties;
+use battle_queries::battle_player_queries::player_properties;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use core_data::card_types::CardType;
+use core_data::types::PlayerName;
+use ordered_float::OrderedFloat;
+
+/// Scores `battle` for `player` using the feature weights of a personality,
+/// returning a value in the range -1.0 to 1.0.
+///
+/// Each feature compares `player` to their opponent, so a position where both
+/// players are even scores 0.0.
+pub fn style_score(battle: &BattleState, player: PlayerName, weights: &PersonalityWeights) -> f64 {
+    let opponent = player.opponent();
+    let mine = battle.players.player(player);
+    let theirs = battle.players.player(opponent);
+    let features = [
+        (weights.points, relative(mine.points.0, theirs.points.0)),
+        (
+            weights.spark,
+            relative(
+                player_properties::spark_total(battle, player).0,
+                player_properties::spark_total(battle, opponent).0,
+            ),
+        ),
+        (
+            weights.characters,
+            relative(
+                battle.cards.battlefield(player).len() as u32,
+                battle.cards.battlefield(opponent).len() as u32,
+            ),
+        ),
+        (
+            weights.hand,
+            relative(
+                battle.cards.hand(player).len() as u32,
+                battle.cards.hand(opponent).len() as u32,
+            ),
+        ),
+        (
+            weights.energy,
+            f64::from(mine.current_energy.0) / f64::from(mine.produced_energy.0.max(1)),
+        ),
+    ];
+
+    let total_weight = features.iter().map(|(weight, _)| weight).sum::<f64>();
+    if total_weight <= 0.0 {
+        return 0.0;
+    }
+    let score = features.iter().map(|(weight, value)| weight * value).sum::<f64>() / total_weight;
+    score.clamp(-1.0, 1.0)
+}
+
+/// Blends the reward from a rollout's winner with a [style_score] for the
+/// position the rollout started from.
+pub fn shape(
+    outcome: OrderedFloat<f64>,
+    style: f64,
+    weights: &PersonalityWeights,
+) -> OrderedFloat<f64> {
+    OrderedFloat((1.0 - weights.style_weight) * outcome.0 + weights.style_weight * style)
+}
+
+/// Returns the bonus added to the average reward of `action` when picking a
+/// final candidate, ordering otherwise similar actions by play style.
+pub fn action_bonus(
+    battle: &BattleState,
+    action: BattleAction,
+    weights: &PersonalityWeights,
+) -> f64 {
+    match action {
+        BattleAction::PlayCardFromHand(card_id) => {
+            if card_properties::card_type(battle, card_id) == CardType::Character {
+                weights.develop_bonus
+            } else {
+                weights.interact_bonus
+            }
+        }
+        BattleAction::PlayCardFromVoid(card_id) => {
+            if card_properties::card_type(battle, card_id) == CardType::Character {
+                weights.develop_bonus
+            } else {
+                weights.interact_bonus
+            }
+        }
+        BattleAction::ActivateAbilityForCharacter(_)
+        | BattleAction::SelectCharacterTarget(_)
+        | BattleAction::SelectStackCardTarget(_)
+        | BattleAction::SelectVoidCardTarget(_)
+        | BattleAction::SelectHandCardTarget(_) => weights.interact_bonus,
+        BattleAction::PassPriority | BattleAction::EndTurn => weights.hold_bonus,
+        _ => 0.0,
+    }
+}
+
+/// Compares two non-negative quantities, returning a value in the range -1.0
+/// to 1.0.
+fn relative(mine: u32, theirs: u32) -> f64 {
+    let total = mine + theirs;
+    if total == 0 { 0.0 } else { (f64::from(mine) - f64::from(theirs)) / f64::from(total) }
+}
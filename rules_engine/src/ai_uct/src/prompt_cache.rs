@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use core_data::types::PlayerName;
+use enumset::EnumSet;
+
+/// Responses to prompts resolved during a single AI decision, shared between
+/// the search trees of every candidate action.
+///
+/// The same prompt frequently recurs in an identical battle state along
+/// different branches of search, for example when several candidate actions
+/// lead to the same triggered ability. Once one search tree has explored a
+/// prompt enough to trust its best response, later encounters with the same
+/// state and prompt apply that response directly instead of expanding another
+/// subtree for it.
+#[derive(Default)]
+pub struct PromptCache {
+    responses: Mutex<HashMap<PromptKey, BattleAction>>,
+    lookups: AtomicU32,
+    hits: AtomicU32,
+}
+
+/// Identifies a prompt in a particular battle state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PromptKey {
+    state_hash: u64,
+    prompt_id: u64,
+}
+
+/// Returns the cache key for the currently-active prompt in `battle`, or None
+/// if no prompt is active.
+///
+/// The state hash covers public and private information about both players
+/// but not the random number generator, so states which differ only in
+/// future randomness share a key.
+pub fn prompt_key(battle: &BattleState) -> Option<PromptKey> {
+    let prompt = battle.prompts.front()?;
+    let mut hasher = DefaultHasher::new();
+    prompt.player.hash(&mut hasher);
+    prompt.source.hash(&mut hasher);
+    mem::discriminant(&prompt.prompt_type).hash(&mut hasher);
+    let prompt_id = hasher.finish();
+    Some(PromptKey { state_hash: state_hash(battle), prompt_id })
+}
+
+impl PromptCache {
+    /// Returns the cached response for the prompt identified by `key`, if
+    /// any, and records the lookup in this cache's statistics.
+    pub fn lookup(&self, key: PromptKey) -> Option<BattleAction> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let response = self.responses.lock().unwrap().get(&key).copied();
+        if response.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+        response
+    }
+
+    /// Records `action` as the resolved response to the prompt identified by
+    /// `key`, keeping any response which was already recorded.
+    pub fn insert(&self, key: PromptKey, action: BattleAction) {
+        self.responses.lock().unwrap().entry(key).or_insert(action);
+    }
+
+    /// Number of lookups performed against this cache.
+    pub fn lookups(&self) -> u32 {
+        self.lookups.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups which returned a cached response.
+    pub fn hits(&self) -> u32 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+/// Returns a hash of public and private information about both players in
+/// `battle`, excluding the random number generator.
+pub fn state_hash(battle: &BattleState) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    battle.turn.hash(&mut hasher);
+    battle.phase.hash(&mut hasher);
+    battle.stack_priority.hash(&mut hasher);
+    battle.prompts.len().hash(&mut hasher);
+    battle.pending_effects.len().hash(&mut hasher);
+    for player in EnumSet::<PlayerName>::all() {
+        let state = battle.players.player(player);
+        state.points.hash(&mut hasher);
+        state.current_energy.hash(&mut hasher);
+        state.produced_energy.hash(&mut hasher);
+        state.spark_bonus.hash(&mut hasher);
+        state.deck.len().hash(&mut hasher);
+        battle.cards.hand(player).hash(&mut hasher);
+        battle.cards.void(player).hash(&mut hasher);
+        battle.cards.stack_set(player).hash(&mut hasher);
+        battle.cards.shuffled_into_deck(player).hash(&mut hasher);
+        for character_id in battle.cards.battlefield(player) {
+            character_id.hash(&mut hasher);
+            battle.cards.spark(player, character_id).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
@@ -1,3 +1,12 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use ai_data::personality::AiPersonality;
+use enumset::EnumSet;
+
+use crate::action_pruning::PruningRule;
+
 #[derive(Debug, Clone)]
 pub struct UctConfig {
     /// How many iterations of the monte carlo algorithm to run for each
@@ -24,4 +33,118 @@ pub struct UctConfig {
     ///
     /// Used for benchmarking.
     pub single_threaded: bool,
+
+    /// Wall-clock limits for this search, if any.
+    ///
+    /// When time limits are set, search normally stops once the iteration
+    /// budget is exhausted, but continues past it until the hard limit while
+    /// the best action keeps changing.
+    pub time_limits: Option<SearchTimeLimits>,
+
+    /// Maximum depth of the search tree below each candidate action, if any.
+    ///
+    /// Used to weaken search for lower difficulty levels.
+    pub max_tree_depth: Option<u32>,
+
+    /// Maximum random noise added to each candidate's average reward before
+    /// picking the final action.
+    ///
+    /// Used to weaken search for lower difficulty levels. Zero disables noise.
+    pub evaluation_noise: f64,
+
+    /// Flag which stops search early once set, returning the best action found
+    /// so far.
+    ///
+    /// Used to abandon speculative searches whose results are no longer
+    /// needed.
+    pub cancellation: Option<Arc<AtomicBool>>,
+
+    /// Rules used to remove dominated actions before search begins.
+    ///
+    /// Only actions available to the searching player at the root are pruned.
+    pub pruning_rules: EnumSet<PruningRule>,
+
+    /// Play style used to bias rollout rewards and the final choice between
+    /// candidate actions, if any.
+    pub personality: Option<AiPersonality>,
+}
+
+/// Wall-clock limits for a single search.
+///
+/// See [SearchTimeLimits::stop_reason] for how these limits decide when
+/// search ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchTimeLimits {
+    /// Time after which search stops as soon as the best action is stable.
+    pub soft: Duration,
+
+    /// Time after which search always stops.
+    pub hard: Duration,
+}
+
+/// State of a time-managed search after a batch of iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchProgress {
+    /// Time elapsed since search began.
+    pub elapsed: Duration,
+
+    /// Iterations completed per candidate action.
+    pub completed: u32,
+
+    /// Iterations per candidate action the search was configured to run.
+    pub iteration_budget: u32,
+
+    /// Whether the best action is the same as it was after the previous
+    /// batch.
+    pub stable: bool,
+
+    /// Whether one action's average reward dominates all alternatives.
+    pub dominant: bool,
+
+    /// Whether search has been cancelled.
+    pub cancelled: bool,
+}
+
+/// Reason a time-managed search stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStop {
+    HardLimit,
+    Cancelled,
+    Dominant,
+    SoftLimit,
+    IterationBudget,
+}
+
+impl SearchTimeLimits {
+    /// Limits for a fixed per-decision time budget.
+    ///
+    /// Search may stop once half the budget has elapsed if the best action is
+    /// stable, and always stops when the budget is exhausted.
+    pub fn from_budget(budget: Duration) -> Self {
+        Self { soft: budget / 2, hard: budget }
+    }
+
+    /// Returns the reason search should stop after reaching `progress`, or
+    /// None if it should run another batch.
+    ///
+    /// Search always stops at the hard limit, on cancellation, or once one
+    /// action dominates. Otherwise it stops once the best action is stable and
+    /// either the soft limit has elapsed or the iteration budget is exhausted.
+    /// While the best action is unstable, search extends past both the soft
+    /// limit and the iteration budget until the hard limit.
+    pub fn stop_reason(&self, progress: SearchProgress) -> Option<SearchStop> {
+        if progress.elapsed >= self.hard {
+            Some(SearchStop::HardLimit)
+        } else if progress.cancelled {
+            Some(SearchStop::Cancelled)
+        } else if progress.dominant {
+            Some(SearchStop::Dominant)
+        } else if progress.stable && progress.elapsed >= self.soft {
+            Some(SearchStop::SoftLimit)
+        } else if progress.stable && progress.completed >= progress.iteration_budget {
+            Some(SearchStop::IterationBudget)
+        } else {
+            None
+        }
+    }
 }
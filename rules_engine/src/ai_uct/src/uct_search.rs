@@ -1,6 +1,9 @@
 use std::cmp;
 use std::f64::consts;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 
+use ai_data::personality::PersonalityWeights;
 use battle_mutations::actions::apply_battle_action;
 use battle_mutations::player_mutations::player_state;
 use battle_queries::legal_action_queries::legal_actions;
@@ -10,6 +13,9 @@ use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::battle_status::BattleStatus;
 use battle_state::battle::battle_turn_phase::BattleTurnPhase;
+use battle_state::battle_trace::decision_explanation::{
+    DecisionExplanation, NodeStatistics, ScoredAction,
+};
 use core_data::types::PlayerName;
 use ordered_float::OrderedFloat;
 use petgraph::Direction;
@@ -22,9 +28,32 @@ use tracing::debug;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt;
 
-use crate::log_search_results;
-use crate::uct_config::UctConfig;
+use crate::prompt_cache::PromptCache;
+use crate::uct_config::{SearchProgress, SearchTimeLimits, UctConfig};
 use crate::uct_tree::{SearchEdge, SearchGraph, SearchNode, SelectionMode};
+use crate::{action_pruning, log_search_results, personality_evaluation, prompt_cache};
+
+/// Number of batches the iteration budget is split into when searching with
+/// time limits.
+const TIME_MANAGED_BATCHES: u32 = 20;
+
+/// Minimum visits every candidate needs before any action can be considered
+/// dominant.
+const DOMINANCE_MIN_VISITS: u32 = 100;
+
+/// Difference in average reward at which the best action is considered to
+/// dominate all alternatives.
+const DOMINANCE_MARGIN: f64 = 0.5;
+
+/// Number of candidate actions to include in a [DecisionExplanation].
+const EXPLANATION_ALTERNATIVES: usize = 5;
+
+/// Maximum number of actions to include in a principal variation.
+const PRINCIPAL_VARIATION_MAX_LENGTH: usize = 8;
+
+/// Visits a fully-expanded prompt node needs before its best response is
+/// added to the [PromptCache].
+const PROMPT_RESOLVED_MIN_VISITS: u32 = 50;
 
 /// Monte Carlo search algorithm.
 ///
@@ -61,30 +90,61 @@ pub fn search(
     player: PlayerName,
     config: &UctConfig,
 ) -> BattleAction {
-    let legal = legal_actions::compute(initial_battle, player);
-    let iterations_per_action = iterations_per_action(&legal, config, initial_battle, player);
+    search_with_explanation(initial_battle, player, config).action
+}
 
-    let action_results: Vec<_> = legal
-        .all()
-        .par_iter()
-        .with_min_len(if config.single_threaded { usize::MAX } else { 1 })
-        .map(|&action| {
-            search_action_candidate(initial_battle, player, iterations_per_action, action, None)
+/// Runs [search] and returns a [DecisionExplanation] describing the selected
+/// action, its principal variation, and the best-scoring alternatives.
+pub fn search_with_explanation(
+    initial_battle: &BattleState,
+    player: PlayerName,
+    config: &UctConfig,
+) -> DecisionExplanation {
+    let legal = legal_actions::compute(initial_battle, player);
+    let actions = action_pruning::prune(initial_battle, legal.all(), config.pruning_rules);
+    let iterations_per_action =
+        iterations_per_action(&legal, actions.len(), config, initial_battle, player);
+
+    let personality = config.personality.map(|personality| personality.weights());
+    let mut action_results: Vec<_> = actions
+        .into_iter()
+        .map(|action| {
+            ActionSearchResult::new(player, action, None, config.max_tree_depth, personality)
         })
         .collect();
 
-    let Some(best_result) = action_results.iter().max_by_key(|result| {
-        if result.visit_count == 0 {
-            OrderedFloat(-f64::INFINITY)
-        } else {
-            OrderedFloat(result.total_reward.0 / result.visit_count as f64)
+    let prompt_cache = PromptCache::default();
+    match &config.time_limits {
+        Some(limits) => {
+            run_time_managed(
+                initial_battle,
+                &mut action_results,
+                iterations_per_action,
+                limits,
+                config,
+                &prompt_cache,
+            );
         }
-    }) else {
+        None => {
+            run_iterations(
+                initial_battle,
+                &mut action_results,
+                iterations_per_action,
+                None,
+                config,
+                &prompt_cache,
+            );
+        }
+    }
+
+    let Some(best_result) =
+        final_candidate(initial_battle, &action_results, config.evaluation_noise, personality)
+    else {
         panic_with!("No legal actions available", initial_battle, player);
     };
 
     let action = best_result.action;
-    let total_iterations = iterations_per_action * legal.len() as u32;
+    let total_iterations = action_results.iter().map(|result| result.visit_count).sum::<u32>();
     let num_threads = rayon::current_num_threads();
 
     debug!(?total_iterations, ?action, ?num_threads, "Picked AI action");
@@ -101,10 +161,11 @@ pub fn search(
     // searches, and it does improve win rates somewhat, but the
     // complexity/performance costs seem to not be worth it.
 
-    action
+    explain(player, best_result, &action_results, total_iterations, &prompt_cache)
 }
 
-/// Public version of `search_action_candidate` for use in benchmark tests.
+/// Searches only the first legal action for a fixed number of iterations, for
+/// use in benchmark tests.
 pub fn search_first_action_candidate_for_benchmarking(
     initial_battle: &BattleState,
     player: PlayerName,
@@ -113,10 +174,26 @@ pub fn search_first_action_candidate_for_benchmarking(
     let legal = legal_actions::compute(initial_battle, player);
     let all_actions = legal.all();
     let action = all_actions.first().expect("No legal actions available");
-    let result = search_action_candidate(initial_battle, player, 10, *action, Some(31415926535897));
+    let mut result = ActionSearchResult::new(player, *action, Some(31415926535897), None, None);
+    result.search(initial_battle, 10, None, None, &PromptCache::default());
     result.action
 }
 
+/// Searches a single candidate `action` for a fixed number of iterations and
+/// returns its search graph and root node, for use in tests.
+pub fn search_candidate_tree_for_testing(
+    initial_battle: &BattleState,
+    player: PlayerName,
+    action: BattleAction,
+    iterations: u32,
+    prompt_cache: &PromptCache,
+) -> (SearchGraph, NodeIndex) {
+    fastrand::seed(31415926535897);
+    let mut result = ActionSearchResult::new(player, action, Some(31415926535897), None, None);
+    result.search(initial_battle, iterations, None, None, prompt_cache);
+    (result.graph, result.root)
+}
+
 /// Public version of `evaluate` for use in benchmark tests.
 pub fn evaluate_for_benchmarking(
     battle: &mut BattleState,
@@ -126,49 +203,180 @@ pub fn evaluate_for_benchmarking(
     evaluate(battle, maximizing_player)
 }
 
-fn search_action_candidate(
+/// Runs `iterations` additional search iterations for every candidate action
+/// in parallel, stopping early at `deadline` if provided or once search is
+/// cancelled.
+fn run_iterations(
     initial_battle: &BattleState,
-    player: PlayerName,
+    action_results: &mut [ActionSearchResult],
+    iterations: u32,
+    deadline: Option<Instant>,
+    config: &UctConfig,
+    prompt_cache: &PromptCache,
+) {
+    let cancellation = config.cancellation.as_deref();
+    action_results
+        .par_iter_mut()
+        .with_min_len(if config.single_threaded { usize::MAX } else { 1 })
+        .for_each(|result| {
+            result.search(initial_battle, iterations, deadline, cancellation, prompt_cache)
+        });
+}
+
+/// Runs search in batches until the provided [SearchTimeLimits] say to stop.
+///
+/// After each batch, [SearchTimeLimits::stop_reason] decides whether to run
+/// another. Batches past the iteration budget run only while the best action
+/// is unstable, extending search up to the hard limit.
+///
+/// The hard limit is also enforced within each batch, so search returns the
+/// best action found so far once it elapses. Every candidate action is always
+/// evaluated at least once before search stops.
+fn run_time_managed(
+    initial_battle: &BattleState,
+    action_results: &mut [ActionSearchResult],
     iterations_per_action: u32,
-    action: BattleAction,
-    randomize_player_seed: Option<u64>,
-) -> ActionSearchResult {
-    let subscriber = tracing_subscriber::registry().with(EnvFilter::new("warn"));
-    tracing::subscriber::with_default(subscriber, || {
-        let mut graph = SearchGraph::default();
-        let root = graph.add_node(SearchNode {
-            player,
-            total_reward: OrderedFloat(0.0),
-            visit_count: 0,
-            tried: Vec::new(),
+    limits: &SearchTimeLimits,
+    config: &UctConfig,
+    prompt_cache: &PromptCache,
+) {
+    let start = Instant::now();
+    let deadline = start + limits.hard;
+    let batch_size = cmp::max(1, iterations_per_action.div_ceil(TIME_MANAGED_BATCHES));
+    let mut completed = 0;
+    let mut previous_best = None;
+    loop {
+        let remaining = iterations_per_action.saturating_sub(completed);
+        let batch = if remaining > 0 { cmp::min(batch_size, remaining) } else { batch_size };
+        run_iterations(initial_battle, action_results, batch, Some(deadline), config, prompt_cache);
+        completed += batch;
+
+        let elapsed = start.elapsed();
+        let best = best_candidate(action_results).map(|result| result.action);
+        let progress = SearchProgress {
+            elapsed,
+            completed,
+            iteration_budget: iterations_per_action,
+            stable: best.is_some() && best == previous_best,
+            dominant: is_dominant(action_results),
+            cancelled: is_cancelled(config.cancellation.as_deref()),
+        };
+        if let Some(reason) = limits.stop_reason(progress) {
+            debug!(?elapsed, ?completed, ?reason, "Time-managed search stopped");
+            break;
+        }
+        previous_best = best;
+    }
+}
+
+/// Returns a random number generator for choices made while selecting an
+/// action in `battle`, such as evaluation noise and deliberate blunders.
+///
+/// Values come from the battle's seeded random number generator, which is not
+/// advanced, mixed with a hash of the battle state so that successive
+/// decisions draw different values. The same battle state always produces the
+/// same choices.
+pub fn decision_rng(battle: &BattleState) -> Xoshiro256PlusPlus {
+    let mut rng = battle.rng.clone();
+    rng.long_jump();
+    Xoshiro256PlusPlus::seed_from_u64(rng.random::<u64>() ^ prompt_cache::state_hash(battle))
+}
+
+/// Returns the candidate with the highest average reward.
+fn best_candidate(action_results: &[ActionSearchResult]) -> Option<&ActionSearchResult> {
+    action_results.iter().max_by_key(|result| result.average_reward())
+}
+
+/// Returns the candidate to play after search completes.
+///
+/// If `noise` is positive, a uniformly random value in `-noise..=noise` drawn
+/// from [decision_rng] is added to each candidate's average reward before
+/// comparing them. If `personality` is provided, its bonus for each action is
+/// added as well.
+fn final_candidate<'a>(
+    battle: &BattleState,
+    action_results: &'a [ActionSearchResult],
+    noise: f64,
+    personality: Option<PersonalityWeights>,
+) -> Option<&'a ActionSearchResult> {
+    if noise <= 0.0 && personality.is_none() {
+        return best_candidate(action_results);
+    }
+    let mut rng = decision_rng(battle);
+    action_results.iter().max_by_key(|result| {
+        let bonus = personality.as_ref().map_or(0.0, |weights| {
+            personality_evaluation::action_bonus(battle, result.action, weights)
         });
+        let noise = if noise > 0.0 { rng.random_range(-noise..=noise) } else { 0.0 };
+        result.average_reward() + bonus + noise
+    })
+}
 
-        let mut randomize_player_rng = Xoshiro256PlusPlus::seed_from_u64(
-            randomize_player_seed.unwrap_or_else(|| rand::rng().random()),
-        );
+/// Builds a [DecisionExplanation] for the selected candidate.
+fn explain(
+    player: PlayerName,
+    selected: &ActionSearchResult,
+    action_results: &[ActionSearchResult],
+    total_iterations: u32,
+    prompt_cache: &PromptCache,
+) -> DecisionExplanation {
+    let mut alternatives = action_results
+        .iter()
+        .map(|result| ScoredAction {
+            action: result.action,
+            average_reward: result.average_reward().0,
+            visit_count: result.visit_count,
+        })
+        .collect::<Vec<_>>();
+    alternatives.sort_by(|a, b| b.average_reward.total_cmp(&a.average_reward));
+    alternatives.truncate(EXPLANATION_ALTERNATIVES);
 
-        for _ in 0..iterations_per_action {
-            // Use a different random state every time. Doing this less
-            // frequently does improve performance, but also pretty
-            // consistently reduces play skill.
-            let mut battle = player_state::randomize_battle_player(
-                initial_battle,
-                player.opponent(),
-                randomize_player_rng.random(),
-            );
-            battle.request_context.logging_options.enable_action_legality_check = false;
+    DecisionExplanation {
+        player,
+        action: selected.action,
+        principal_variation: principal_variation(&selected.graph, selected.root),
+        alternatives,
+        statistics: NodeStatistics {
+            candidates: action_results.len(),
+            total_iterations,
+            total_nodes: action_results.iter().map(|result| result.graph.node_count()).sum(),
+            selected_nodes: selected.graph.node_count(),
+            prompt_cache_lookups: prompt_cache.lookups(),
+            prompt_cache_hits: prompt_cache.hits(),
+        },
+    }
+}
 
-            apply_battle_action::execute(&mut battle, player, action);
+/// Returns the sequence of actions found by following the most-visited child
+/// from `root`, up to `PRINCIPAL_VARIATION_MAX_LENGTH` actions.
+fn principal_variation(graph: &SearchGraph, root: NodeIndex) -> Vec<BattleAction> {
+    let mut result = Vec::new();
+    let mut node = root;
+    while result.len() < PRINCIPAL_VARIATION_MAX_LENGTH
+        && let Some(edge) = graph.edges(node).max_by_key(|edge| graph[edge.target()].visit_count)
+    {
+        result.push(edge.weight().action);
+        node = edge.target();
+    }
+    result
+}
 
-            let node = next_evaluation_target(&mut battle, &mut graph, root);
-            let reward = evaluate(&mut battle, player);
-            back_propagate_rewards(&mut graph, player, node, reward);
-        }
+fn is_cancelled(cancellation: Option<&AtomicBool>) -> bool {
+    cancellation.is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+}
 
-        let total_reward = graph[root].total_reward;
-        let visit_count = graph[root].visit_count;
-        ActionSearchResult { action, graph, root, total_reward, visit_count }
-    })
+/// Returns true if the best candidate's average reward exceeds every other
+/// candidate's by at least `DOMINANCE_MARGIN`.
+fn is_dominant(action_results: &[ActionSearchResult]) -> bool {
+    if action_results.len() < 2
+        || action_results.iter().any(|result| result.visit_count < DOMINANCE_MIN_VISITS)
+    {
+        return false;
+    }
+    let mut rewards =
+        action_results.iter().map(|result| result.average_reward()).collect::<Vec<_>>();
+    rewards.sort_by(|a, b| b.cmp(a));
+    rewards[0].0 - rewards[1].0 >= DOMINANCE_MARGIN
 }
 
 struct ActionSearchResult {
@@ -177,6 +385,104 @@ struct ActionSearchResult {
     root: NodeIndex,
     total_reward: OrderedFloat<f64>,
     visit_count: u32,
+    randomize_player_rng: Xoshiro256PlusPlus,
+    max_tree_depth: Option<u32>,
+    personality: Option<PersonalityWeights>,
+}
+
+impl ActionSearchResult {
+    fn new(
+        player: PlayerName,
+        action: BattleAction,
+        randomize_player_seed: Option<u64>,
+        max_tree_depth: Option<u32>,
+        personality: Option<PersonalityWeights>,
+    ) -> Self {
+        let mut graph = SearchGraph::default();
+        let root = graph.add_node(SearchNode {
+            player,
+            total_reward: OrderedFloat(0.0),
+            visit_count: 0,
+            tried: Vec::new(),
+        });
+        let randomize_player_rng = Xoshiro256PlusPlus::seed_from_u64(
+            randomize_player_seed.unwrap_or_else(|| rand::rng().random()),
+        );
+        Self {
+            action,
+            graph,
+            root,
+            total_reward: OrderedFloat(0.0),
+            visit_count: 0,
+            randomize_player_rng,
+            max_tree_depth,
+            personality,
+        }
+    }
+
+    /// Runs `iterations` additional iterations of search for this candidate.
+    ///
+    /// Stops early once `deadline` has passed or `cancellation` is set, but
+    /// only after this candidate has been evaluated at least once.
+    fn search(
+        &mut self,
+        initial_battle: &BattleState,
+        iterations: u32,
+        deadline: Option<Instant>,
+        cancellation: Option<&AtomicBool>,
+        prompt_cache: &PromptCache,
+    ) {
+        let player = self.graph[self.root].player;
+        let subscriber = tracing_subscriber::registry().with(EnvFilter::new("warn"));
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..iterations {
+                if self.graph[self.root].visit_count > 0
+                    && (deadline.is_some_and(|deadline| Instant::now() >= deadline)
+                        || is_cancelled(cancellation))
+                {
+                    break;
+                }
+
+                // Use a different random state every time. Doing this less
+                // frequently does improve performance, but also pretty
+                // consistently reduces play skill.
+                let mut battle = player_state::randomize_battle_player(
+                    initial_battle,
+                    player.opponent(),
+                    self.randomize_player_rng.random(),
+                );
+                battle.request_context.logging_options.enable_action_legality_check = false;
+
+                apply_battle_action::execute(&mut battle, player, self.action);
+
+                let node = next_evaluation_target(
+                    &mut battle,
+                    &mut self.graph,
+                    self.root,
+                    self.max_tree_depth,
+                    prompt_cache,
+                );
+                let style = self.personality.map(|weights| {
+                    (weights, personality_evaluation::style_score(&battle, player, &weights))
+                });
+                let mut reward = evaluate(&mut battle, player);
+                if let Some((weights, style)) = style {
+                    reward = personality_evaluation::shape(reward, style, &weights);
+                }
+                back_propagate_rewards(&mut self.graph, player, node, reward);
+            }
+        });
+        self.total_reward = self.graph[self.root].total_reward;
+        self.visit_count = self.graph[self.root].visit_count;
+    }
+
+    fn average_reward(&self) -> OrderedFloat<f64> {
+        if self.visit_count == 0 {
+            OrderedFloat(-f64::INFINITY)
+        } else {
+            OrderedFloat(self.total_reward.0 / self.visit_count as f64)
+        }
+    }
 }
 
 /// Returns a descendant node to evaluate next for the provided parent node.
@@ -195,6 +501,16 @@ struct ActionSearchResult {
 /// Mutates the provided [BattleState] to represent the game state at the
 /// returned node.
 ///
+/// If `max_depth` is provided, nodes at that depth below `from_node` are
+/// returned without being expanded further.
+///
+/// Prompts are answered from the [PromptCache] where possible, descending
+/// into the child node for the cached response without evaluating it, and
+/// adding that child if it does not yet exist. Once every response to a
+/// prompt has been tried and the prompt's node reaches
+/// `PROMPT_RESOLVED_MIN_VISITS` visits, its highest-reward response is added
+/// to the cache.
+///
 /// Cᵖ is the exploration constant, Cᵖ = 1/√2 was suggested by Kocsis and
 /// Szepesvári as a good choice.
 ///
@@ -212,10 +528,33 @@ fn next_evaluation_target(
     battle: &mut BattleState,
     graph: &mut SearchGraph,
     from_node: NodeIndex,
+    max_depth: Option<u32>,
+    prompt_cache: &PromptCache,
 ) -> NodeIndex {
     let mut node = from_node;
+    let mut depth = 0;
     while let Some(player) = legal_actions::next_to_act(battle) {
+        if max_depth.is_some_and(|max| depth >= max) {
+            return node;
+        }
         let actions = legal_actions::compute(battle, player);
+        let prompt_key = if actions.is_prompt() { prompt_cache::prompt_key(battle) } else { None };
+        if let Some(key) = prompt_key
+            && let Some(response) = prompt_cache.lookup(key)
+            && actions.contains(response, ForPlayer::Agent)
+        {
+            node = match child_for_action(graph, node, response) {
+                Some(child) => {
+                    battle.request_context.logging_options.enable_action_legality_check = false;
+                    apply_battle_action::execute(battle, player, response);
+                    child
+                }
+                None => add_child(battle, graph, player, node, response),
+            };
+            depth += 1;
+            continue;
+        }
+
         let explored = &graph[node].tried;
         // Keeping track of tried actions on the node is a small performance boost
         // over iterating through edges (~3% benchmark improvement).
@@ -225,10 +564,17 @@ fn next_evaluation_target(
         } else {
             // All actions from this node have been tried, recursively search
             // the best candidate
+            if let Some(key) = prompt_key
+                && graph[node].visit_count == PROMPT_RESOLVED_MIN_VISITS
+            {
+                let resolved = best_child(graph, node, &actions, SelectionMode::RewardOnly);
+                prompt_cache.insert(key, resolved.action);
+            }
             let best = best_child(graph, node, &actions, SelectionMode::Exploration);
             battle.request_context.logging_options.enable_action_legality_check = false;
             apply_battle_action::execute(battle, player, best.action);
             node = best.node;
+            depth += 1;
         }
     }
     node
@@ -269,6 +615,16 @@ fn add_child(
     child
 }
 
+/// Returns the child of `parent` reached by taking `action`, if it has been
+/// added to the search graph.
+fn child_for_action(
+    graph: &SearchGraph,
+    parent: NodeIndex,
+    action: BattleAction,
+) -> Option<NodeIndex> {
+    graph.edges(parent).find(|edge| edge.weight().action == action).map(|edge| edge.target())
+}
+
 struct BestChild {
     action: BattleAction,
     node: NodeIndex,
@@ -407,11 +763,12 @@ fn child_score(
 /// iterations as they require faster response times.
 fn iterations_per_action(
     legal: &LegalActions,
+    action_count: usize,
     config: &UctConfig,
     battle: &BattleState,
     agent: PlayerName,
 ) -> u32 {
-    let base_iterations = match legal.len() {
+    let base_iterations = match action_count {
         0 => config.max_iterations_per_action,
         action_count => {
             let total_budget =
@@ -0,0 +1,95 @@
+use battle_queries::battle_player_queries::player_properties;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_queries::legal_action_queries::legal_actions_data::ForPlayer;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::prompt_types::effect_preview::EffectPreview;
+use core_data::types::PlayerName;
+
+use crate::actions::apply_battle_action;
+
+/// Returns a preview of the effects of each option in the given player's
+/// active prompt, if any.
+///
+/// Previews are returned for modal effect choices, prompt choices, and
+/// character or stack card targets.
+pub fn for_prompt(battle: &BattleState, player: PlayerName) -> Vec<(BattleAction, EffectPreview)> {
+    if battle.prompts.front().is_none_or(|prompt| prompt.player != player) {
+        return vec![];
+    }
+    legal_actions::compute(battle, player)
+        .all()
+        .into_iter()
+        .filter(|&action| is_prompt_option(action))
+        .map(|action| (action, compute(battle, player, action)))
+        .collect()
+}
+
+/// Returns a preview of the effects of `player` taking `action` under the
+/// current battle state.
+pub fn compute(battle: &BattleState, player: PlayerName, action: BattleAction) -> EffectPreview {
+    simulate(battle, player, action).1
+}
+
+/// Simulates `player` taking `action`, followed by their opponent passing
+/// priority if they are able to, so that cards on the stack resolve.
+///
+/// Returns the simulated battle along with a preview of the action's effects.
+pub fn simulate(
+    battle: &BattleState,
+    player: PlayerName,
+    action: BattleAction,
+) -> (BattleState, EffectPreview) {
+    let mut simulation = battle.logical_clone();
+    let mut preview = EffectPreview::default();
+    apply_battle_action::execute(&mut simulation, player, action);
+    record_action_history(&simulation, &mut preview);
+
+    let opponent = player.opponent();
+    if legal_actions::compute(&simulation, opponent)
+        .contains(BattleAction::PassPriority, ForPlayer::Human)
+    {
+        apply_battle_action::execute(&mut simulation, opponent, BattleAction::PassPriority);
+        record_action_history(&simulation, &mut preview);
+    }
+
+    for name in [PlayerName::One, PlayerName::Two] {
+        let original = battle.players.player(name);
+        let simulated = simulation.players.player(name);
+        let result = preview.players.player_mut(name);
+        result.points_delta = delta(original.points.0, simulated.points.0);
+        result.energy_delta = delta(original.current_energy.0, simulated.current_energy.0);
+        result.spark_delta = delta(
+            player_properties::spark_total(battle, name).0,
+            player_properties::spark_total(&simulation, name).0,
+        );
+    }
+    (simulation, preview)
+}
+
+/// Returns true if `action` selects an option in a prompt whose effects can
+/// be previewed.
+pub fn is_prompt_option(action: BattleAction) -> bool {
+    matches!(
+        action,
+        BattleAction::SelectCharacterTarget(..)
+            | BattleAction::SelectStackCardTarget(..)
+            | BattleAction::SelectPromptChoice(..)
+            | BattleAction::SelectModalEffectChoice(..)
+    )
+}
+
+/// Adds the cards drawn and characters dissolved while resolving the most
+/// recent action to `preview`.
+fn record_action_history(simulation: &BattleState, preview: &mut EffectPreview) {
+    for name in [PlayerName::One, PlayerName::Two] {
+        let history = simulation.turn_history.current_action_history.player(name);
+        let result = preview.players.player_mut(name);
+        result.cards_drawn += history.cards_drawn;
+        result.characters_dissolved += history.characters_dissolved;
+    }
+}
+
+fn delta(original: u32, simulated: u32) -> i64 {
+    i64::from(simulated) - i64::from(original)
+}
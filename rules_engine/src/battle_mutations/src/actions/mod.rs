@@ -1,3 +1,4 @@
 pub mod apply_battle_action;
 pub mod apply_card_order_action;
 pub mod apply_debug_battle_action;
+pub mod effect_preview;
@@ -244,6 +244,7 @@ fn draw_card_internal(
         });
     }
 
+    battle.turn_history.current_action_history.player_mut(player).cards_drawn += 1;
     Some(move_card::from_deck_to_hand(battle, source, player, id))
 }
 
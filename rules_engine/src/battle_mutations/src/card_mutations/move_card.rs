@@ -175,6 +175,7 @@ pub fn from_void_to_hand(
     // Mark the card as revealed to the opponent
     let card_state = card::get_mut(battle, card_id.card_id());
     *card_state.revealed_to_player_override.player_mut(controller.opponent()) = true;
+    battle.ability_state.until_end_of_turn.returned_to_hand.insert(card_id.card_id());
 
     HandCardId(card_id.card_id())
 }
@@ -286,6 +287,10 @@ fn on_leave_battlefield(
     }
 
     battle.cards.battlefield_state_mut(controller).remove(&CharacterId(card_id));
+    battle
+        .ability_state
+        .prevent_dissolved
+        .retain(|effect| effect.value.card_id.card_id() != card_id);
 
     if battle.ability_state.banish_when_leaves_play.contains(card_id) {
         battle.ability_state.banish_when_leaves_play.remove(card_id);
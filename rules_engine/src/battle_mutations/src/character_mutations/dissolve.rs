@@ -29,12 +29,9 @@ pub fn execute(
             effect_name: TargetedEffectName::Dissolve,
             targets: vec![id.card_id()],
         });
-        let id = move_card::from_battlefield_to_void(
-            battle,
-            source,
-            card_properties::controller(battle, id),
-            id,
-        );
+        let controller = card_properties::controller(battle, id);
+        let id = move_card::from_battlefield_to_void(battle, source, controller, id);
+        battle.turn_history.current_action_history.player_mut(controller).characters_dissolved += 1;
         battle.triggers.push(source, Trigger::Dissolved(id));
         Some(id)
     }
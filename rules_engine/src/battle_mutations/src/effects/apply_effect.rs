@@ -1,5 +1,8 @@
 use ability_data::ability::EventAbility;
-use ability_data::effect::{Effect, EffectWithOptions, ModalEffectChoice, ModelEffectChoiceIndex};
+use ability_data::effect::{
+    Effect, EffectWithOptions, IfYouDoEffect, ModalEffectChoice, ModelEffectChoiceIndex,
+};
+use ability_data::standard_effect::StandardEffect;
 use battle_queries::battle_card_queries::valid_target_queries;
 use battle_queries::panic_with;
 use battle_state::battle::battle_state::{BattleState, PendingEffect};
@@ -7,7 +10,11 @@ use battle_state::battle::battle_status::BattleStatus;
 use battle_state::battle_cards::ability_list::AbilityData;
 use battle_state::battle_cards::stack_card_state::EffectTargets;
 use battle_state::core::effect_source::EffectSource;
+use battle_state::prompt_types::prompt_data::{
+    PromptChoice, PromptChoiceLabel, PromptConfiguration, PromptData, PromptType,
+};
 use core_data::identifiers::AbilityNumber;
+use strings::strings;
 
 use crate::effects::apply_standard_effect;
 
@@ -96,6 +103,10 @@ pub fn execute(
                 panic_with!("Modal effect requires an effect choice", battle);
             }
         }
+        Effect::IfYouDo(if_you_do) => {
+            execute_if_you_do(battle, source, if_you_do, requested_targets);
+            execute_pending_effects_if_no_active_prompt(battle);
+        }
     };
 }
 
@@ -213,6 +224,14 @@ fn execute_pending_effects_if_no_active_prompt_internal(battle: &mut BattleState
                     panic_with!("Modal effect requires an effect choice", battle);
                 }
             }
+            Effect::IfYouDo(if_you_do) => {
+                execute_if_you_do(
+                    battle,
+                    pending_effect.source,
+                    &if_you_do,
+                    pending_effect.requested_targets.as_ref(),
+                );
+            }
         }
     }
 }
@@ -231,6 +250,59 @@ fn execute_modal_effect(
     Some(EffectWasApplied)
 }
 
+/// Applies the first effect of an [IfYouDoEffect], queueing its follow-up
+/// effects to resolve next if it was performed.
+///
+/// If the effect is optional, the controller is first prompted to choose
+/// whether to perform it.
+fn execute_if_you_do(
+    battle: &mut BattleState,
+    source: EffectSource,
+    if_you_do: &IfYouDoEffect,
+    requested_targets: Option<&EffectTargets>,
+) {
+    if if_you_do.optional {
+        battle.prompts.push_back(PromptData {
+            source,
+            player: source.controller(),
+            prompt_type: PromptType::Choose {
+                choices: vec![
+                    PromptChoice {
+                        label: PromptChoiceLabel::String(strings::phrase_ids::ACCEPT_PROMPT_BUTTON),
+                        effect: Effect::IfYouDo(IfYouDoEffect {
+                            optional: false,
+                            ..if_you_do.clone()
+                        }),
+                        targets: requested_targets.cloned(),
+                    },
+                    PromptChoice {
+                        label: PromptChoiceLabel::String(
+                            strings::phrase_ids::DECLINE_PROMPT_BUTTON,
+                        ),
+                        effect: Effect::Effect(StandardEffect::NoEffect),
+                        targets: None,
+                    },
+                ],
+            },
+            configuration: PromptConfiguration::default(),
+            prompt_description: strings::prompt_optional_effect_description().to_string(),
+        });
+        return;
+    }
+
+    let mut targets = valid_target_queries::valid_targets(battle, requested_targets);
+    let performed = apply_standard_effect::apply(battle, source, &if_you_do.effect, &mut targets);
+    remove_stack_priority_if_empty(battle);
+    if performed.is_some() && !if_you_do.follow_up.is_empty() {
+        battle.pending_effects.push_front(PendingEffect {
+            source,
+            effect: Effect::List(if_you_do.follow_up.clone()),
+            requested_targets: requested_targets.cloned(),
+            modal_choice: None,
+        });
+    }
+}
+
 fn execute_with_options(
     battle: &mut BattleState,
     source: EffectSource,
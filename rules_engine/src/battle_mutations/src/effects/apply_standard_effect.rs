@@ -1,4 +1,5 @@
 use ability_data::cost::Cost;
+use ability_data::duration::Duration;
 use ability_data::quantity_expression_data::QuantityExpression;
 use ability_data::standard_effect::StandardEffect;
 use battle_queries::battle_card_queries::{card, card_properties};
@@ -7,6 +8,7 @@ use battle_queries::battle_trace;
 use battle_state::battle::battle_animation_data::{BattleAnimation, TargetedEffectName};
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::card_id::CardIdType;
+use battle_state::battle_cards::ability_state::TimedEffect;
 use battle_state::battle_cards::battle_card_state::CardObjectId;
 use battle_state::battle_cards::card_set::CardSet;
 use battle_state::battle_cards::stack_card_state::EffectTargets;
@@ -23,6 +25,8 @@ use crate::card_mutations::battle_deck::SetRevealedToPlayer;
 use crate::card_mutations::{battle_deck, counterspell, move_card, spark};
 use crate::character_mutations::dissolve;
 use crate::effects::apply_effect::EffectWasApplied;
+#[cfg(feature = "effect_coverage")]
+use crate::effects::effect_coverage;
 use crate::effects::{counterspell_unless_pays_cost, discard_cards, pay_cost, targeting};
 use crate::player_mutations::{energy, points};
 
@@ -37,6 +41,8 @@ pub fn apply(
     targets: &mut Option<EffectTargets>,
 ) -> Option<EffectWasApplied> {
     battle_trace!("Applying effect", battle, effect, targets);
+    #[cfg(feature = "effect_coverage")]
+    effect_coverage::record(battle, source, effect);
     match effect {
         StandardEffect::BanishWhenLeavesPlay { .. } => banish_when_leaves_play(battle, targets),
         StandardEffect::Counterspell { .. } => counterspell(battle, source, targets),
@@ -57,6 +63,7 @@ pub fn apply(
         StandardEffect::GainEnergy { gains } => gain_energy(battle, source, *gains),
         StandardEffect::GainPoints { gains } => gain_points(battle, source, *gains),
         StandardEffect::GainsSpark { gains, .. } => gains_spark(battle, source, targets, *gains),
+        StandardEffect::NoEffect => None,
         StandardEffect::OpponentPaysCost { cost } => opponent_pays_cost(battle, source, cost),
         StandardEffect::ReturnFromYourVoidToHand { .. } => {
             return_from_your_void_to_hand(battle, source, targets)
@@ -67,8 +74,8 @@ pub fn apply(
         StandardEffect::ReturnToHand { .. } => {
             return_from_battlefield_to_hand(battle, source, targets)
         }
-        StandardEffect::PreventDissolveThisTurn { .. } => {
-            prevent_dissolve_this_turn(battle, targets)
+        StandardEffect::PreventDissolve { duration, .. } => {
+            prevent_dissolve(battle, source, targets, *duration)
         }
         StandardEffect::PutCardsFromYourDeckIntoVoid { count } => {
             put_cards_from_your_deck_into_void(battle, source, *count)
@@ -209,17 +216,19 @@ fn opponent_pays_cost(
     Some(EffectWasApplied)
 }
 
-fn prevent_dissolve_this_turn(
+fn prevent_dissolve(
     battle: &mut BattleState,
+    source: EffectSource,
     targets: &mut Option<EffectTargets>,
+    duration: Duration,
 ) -> Option<EffectWasApplied> {
     let id = targeting::character_id(targets)?;
     let object_id = card::get(battle, id).object_id;
-    battle
-        .ability_state
-        .until_end_of_turn
-        .prevent_dissolved
-        .push(CardObjectId { card_id: id, object_id });
+    battle.ability_state.prevent_dissolved.push(TimedEffect {
+        value: CardObjectId { card_id: id, object_id },
+        duration,
+        controller: source.controller(),
+    });
     Some(EffectWasApplied)
 }
 
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::{env, process};
+
+use ability_data::standard_effect::{StandardEffect, StandardEffectDiscriminants};
+use battle_queries::battle_card_queries::card;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::core::effect_source::EffectSource;
+use core_data::identifiers::BaseCardId;
+
+static RECORDER: OnceLock<Option<Mutex<Recorder>>> = OnceLock::new();
+
+/// Environment variable naming the directory effect coverage records are
+/// written to.
+pub const EFFECT_COVERAGE_DIR: &str = "EFFECT_COVERAGE_DIR";
+
+/// Written in place of a card ID for effects with no source card, e.g. effects
+/// caused by the rules of the game.
+pub const NO_SOURCE_CARD: &str = "-";
+
+/// Records that `effect` was applied from `source`, for effect coverage
+/// reports.
+///
+/// Does nothing unless the `EFFECT_COVERAGE_DIR` environment variable is set.
+/// Each process writes the source card and effect variant of every distinct
+/// effect it applies to its own file in that directory, one tab-separated pair
+/// per line.
+pub fn record(battle: &BattleState, source: EffectSource, effect: &StandardEffect) {
+    let Some(recorder) = RECORDER.get_or_init(open_recorder) else {
+        return;
+    };
+    let card = source.card_id().map(|card_id| card::get_base_card_id(battle, card_id));
+    let variant = StandardEffectDiscriminants::from(effect).to_string();
+    let Ok(mut recorder) = recorder.lock() else {
+        return;
+    };
+    if recorder.seen.insert((card, variant.clone())) {
+        let card = card.map_or_else(|| NO_SOURCE_CARD.to_string(), |card| card.0.to_string());
+        let _ = writeln!(recorder.file, "{card}\t{variant}");
+    }
+}
+
+struct Recorder {
+    file: File,
+    seen: BTreeSet<(Option<BaseCardId>, String)>,
+}
+
+fn open_recorder() -> Option<Mutex<Recorder>> {
+    let directory = PathBuf::from(env::var_os(EFFECT_COVERAGE_DIR)?);
+    fs::create_dir_all(&directory).ok()?;
+    let path = directory.join(format!("effect_coverage_{}.tsv", process::id()));
+    let file = OpenOptions::new().create(true).append(true).open(path).ok()?;
+    Some(Mutex::new(Recorder { file, seen: BTreeSet::new() }))
+}
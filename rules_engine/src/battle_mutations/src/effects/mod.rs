@@ -4,5 +4,7 @@ pub mod apply_hand_card_effect;
 pub mod apply_standard_effect;
 pub mod counterspell_unless_pays_cost;
 pub mod discard_cards;
+#[cfg(feature = "effect_coverage")]
+pub mod effect_coverage;
 pub mod pay_cost;
 pub mod targeting;
@@ -109,6 +109,7 @@ fn set_displayed_active_triggers(battle: &mut BattleState, previous: &mut Vec<Tr
                     controller,
                     character_id,
                     ability_number: ability_data.ability_number,
+                    trigger: trigger_for_listener.trigger,
                 });
             }
         }
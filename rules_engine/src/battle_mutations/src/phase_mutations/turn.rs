@@ -37,6 +37,10 @@ pub fn run_turn_state_machine_if_no_active_prompts(battle: &mut BattleState) {
 
                 battle_trace!("Starting turn for", battle, next_player);
                 battle.ability_state.until_end_of_turn = UntilEndOfTurn::default();
+                battle
+                    .ability_state
+                    .prevent_dissolved
+                    .retain(|effect| !effect.ends_at_turn_start(next_player));
                 battle
                     .activated_abilities
                     .player_mut(previous_player)
@@ -55,6 +59,13 @@ pub fn run_turn_state_machine_if_no_active_prompts(battle: &mut BattleState) {
                 });
             }
             BattleTurnPhase::Starting => {
+                battle.phase = BattleTurnPhase::FiringStartOfTurnTriggers;
+                let source = EffectSource::Game { controller: battle.turn.active_player };
+                battle.triggers.push(source, Trigger::StartOfTurn(battle.turn.active_player));
+                apply_effect::execute_pending_effects_if_no_active_prompt(battle);
+                fire_triggers::execute_if_no_active_prompt(battle);
+            }
+            BattleTurnPhase::FiringStartOfTurnTriggers => {
                 battle.phase = BattleTurnPhase::Judgment;
                 judgment_phase::run(battle, battle.turn.active_player, EffectSource::Game {
                     controller: battle.turn.active_player,
@@ -82,6 +93,11 @@ pub fn run_turn_state_machine_if_no_active_prompts(battle: &mut BattleState) {
             }
             BattleTurnPhase::Draw => {
                 battle.phase = BattleTurnPhase::Main;
+                let player = battle.turn.active_player;
+                battle
+                    .ability_state
+                    .prevent_dissolved
+                    .retain(|effect| !effect.ends_at_main_phase(player));
             }
             _ => {
                 break;
@@ -30,20 +30,22 @@ pub fn apply(battle: &mut BattleState, source: EffectSource, player: PlayerName)
         return None;
     }
 
-    let (target_id, _) =
-        battle.cards.battlefield_state(player).iter().min_by_key(|(id, state)| {
-            (state.spark, card_properties::converted_energy_cost(battle, **id))
-        })?;
+    let target_id = battle.cards.battlefield(player).iter().min_by_key(|id| {
+        (
+            card_properties::spark(battle, player, *id),
+            card_properties::converted_energy_cost(battle, *id),
+        )
+    })?;
 
     battle
         .turn_history
         .current_action_history
         .player_mut(player)
         .character_limit_characters_abandoned
-        .insert(*target_id);
+        .insert(target_id);
 
-    let spark_value = battle.cards.spark(player, *target_id)?;
-    abandon::apply(battle, source, *target_id);
+    let spark_value = card_properties::spark(battle, player, target_id)?;
+    abandon::apply(battle, source, target_id);
     battle.players.player_mut(player).spark_bonus += spark_value;
     Some(())
 }
@@ -104,12 +104,17 @@ fn apply_if_you_do_effect(
 ) {
     let source = EffectSource::IfYouDo { controller: player, ability_id: via_ability };
     let abilities = card::ability_list(battle, via_ability.card_id);
-    if let Some(ability_data) =
+    let Some(ability_data) =
         abilities.static_abilities.iter().find(|a| a.ability_number == via_ability.ability_number)
-        && let StandardStaticAbility::PlayFromVoid(play) =
-            ability_data.ability.standard_static_ability()
-        && let Some(effect) = &play.if_you_do
-    {
+    else {
+        return;
+    };
+    let if_you_do = match ability_data.ability.standard_static_ability() {
+        StandardStaticAbility::PlayFromVoid(play) => &play.if_you_do,
+        StandardStaticAbility::PlayFromHandOrVoidForCost(play) => &play.if_you_do,
+        _ => &None,
+    };
+    if let Some(effect) = if_you_do {
         apply_effect_with_prompt_for_targets::execute(
             battle,
             source,
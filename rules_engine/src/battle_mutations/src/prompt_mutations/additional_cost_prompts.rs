@@ -50,7 +50,7 @@ pub fn add(battle: &mut BattleState, controller: PlayerName, card_id: StackCardI
 
 /// Returns true if this cost requires an interactive prompt to pay.
 fn requires_prompt(cost: &Cost) -> bool {
-    matches!(cost, Cost::SpendOneOrMoreEnergy)
+    matches!(cost, Cost::SpendOneOrMoreEnergy | Cost::VariableEnergy)
 }
 
 fn create_prompt_for_cost(
@@ -68,6 +68,16 @@ fn create_prompt_for_cost(
                     .to_string(),
             )
         }
+        Cost::VariableEnergy => {
+            let energy = battle.players.player(player).current_energy;
+            (
+                PromptType::ChooseEnergyValue { minimum: Energy(0), maximum: energy },
+                strings::capitalized_sentence_with_period(strings::pay_prefix(
+                    strings::x_energy_cost(),
+                ))
+                .to_string(),
+            )
+        }
         _ => todo!("Implement additional cost prompt for {:?}", cost),
     };
 
@@ -87,6 +87,21 @@ pub fn query(
             }
             AutomaticEffectTargets::Targets(Some(EffectTargets::EffectList(target_list)))
         }
+        Effect::IfYouDo(if_you_do) => {
+            let mut target_list = VecDeque::new();
+            for standard_effect in if_you_do.effects() {
+                if let Some(targets) =
+                    standard_effect_automatic_targets(battle, source, standard_effect, that_card)
+                {
+                    target_list.push_back(Some(targets));
+                } else if target_predicates::has_targets(standard_effect) {
+                    return AutomaticEffectTargets::RequiresPrompt;
+                } else {
+                    target_list.push_back(None);
+                }
+            }
+            AutomaticEffectTargets::Targets(Some(EffectTargets::EffectList(target_list)))
+        }
         Effect::Modal(modal) => {
             if let Some(modal_choice) = modal_choice {
                 query(battle, source, &modal[modal_choice.value()].effect, that_card, None)
@@ -46,36 +46,7 @@ fn build_ability_list(
     let mut ability_list = AbilityList::default();
 
     for (ability_number, ability) in abilities {
-        match ability {
-            Ability::Event(event_ability) => {
-                ability_list
-                    .event_abilities
-                    .push(AbilityData { ability_number, ability: event_ability.clone() });
-            }
-            Ability::Static(static_ability) => {
-                ability_list
-                    .static_abilities
-                    .push(AbilityData { ability_number, ability: static_ability.clone() });
-            }
-            Ability::Activated(activated_ability) => {
-                ability_list
-                    .activated_abilities
-                    .push(AbilityData { ability_number, ability: activated_ability.clone() });
-            }
-            Ability::Triggered(triggered_ability) => {
-                ability_list
-                    .triggered_abilities
-                    .push(AbilityData { ability_number, ability: triggered_ability.clone() });
-            }
-            Ability::Named(named_ability) => {
-                build_named_abilities::build(
-                    definition,
-                    &mut ability_list,
-                    named_ability,
-                    ability_number,
-                );
-            }
-        }
+        add_ability(definition, &mut ability_list, ability_number, ability);
     }
 
     ability_list.can_play_restriction = merge_can_play_restrictions(vec![
@@ -86,10 +57,47 @@ fn build_ability_list(
     ability_list.stack_triggers = stack_triggers(&ability_list);
     ability_list.has_battlefield_activated_abilities = !ability_list.activated_abilities.is_empty();
     ability_list.has_play_from_void_ability = has_play_from_void_ability(&ability_list);
+    ability_list.has_spark_bonus_ability = has_spark_bonus_ability(&ability_list);
 
     ability_list
 }
 
+fn add_ability(
+    definition: &CardDefinition,
+    ability_list: &mut AbilityList,
+    ability_number: AbilityNumber,
+    ability: Ability,
+) {
+    match ability {
+        Ability::Event(event_ability) => {
+            ability_list
+                .event_abilities
+                .push(AbilityData { ability_number, ability: event_ability });
+        }
+        Ability::Static(static_ability) => {
+            ability_list
+                .static_abilities
+                .push(AbilityData { ability_number, ability: static_ability });
+        }
+        Ability::Activated(activated_ability) => {
+            ability_list
+                .activated_abilities
+                .push(AbilityData { ability_number, ability: activated_ability });
+        }
+        Ability::Triggered(triggered_ability) => {
+            ability_list
+                .triggered_abilities
+                .push(AbilityData { ability_number, ability: triggered_ability });
+        }
+        Ability::Named(named_ability) => {
+            build_named_abilities::build(definition, ability_list, named_ability, ability_number);
+        }
+        Ability::WithAbilityWord(with_word) => {
+            add_ability(definition, ability_list, ability_number, *with_word.ability);
+        }
+    }
+}
+
 fn merge_can_play_restrictions(
     restrictions: Vec<Option<CanPlayRestriction>>,
 ) -> Option<CanPlayRestriction> {
@@ -269,6 +277,36 @@ fn event_effect_predicates(list: &AbilityList) -> Vec<EventEffectPredicate<'_>>
                     .flatten()
                 })
                 .collect(),
+            Effect::IfYouDo(if_you_do) => if_you_do
+                .effects()
+                .flat_map(|effect| {
+                    vec![
+                        target_predicates::get_character_target_predicate(effect).map(
+                            |predicate| EventEffectPredicate {
+                                effect,
+                                predicate,
+                                predicate_type: PredicateType::Character,
+                            },
+                        ),
+                        target_predicates::get_stack_target_predicate(effect).map(|predicate| {
+                            EventEffectPredicate {
+                                effect,
+                                predicate,
+                                predicate_type: PredicateType::Stack,
+                            }
+                        }),
+                        target_predicates::get_void_target_predicate(effect).map(|predicate| {
+                            EventEffectPredicate {
+                                effect,
+                                predicate,
+                                predicate_type: PredicateType::Void,
+                            }
+                        }),
+                    ]
+                    .into_iter()
+                    .flatten()
+                })
+                .collect(),
             Effect::Modal(_) => vec![],
         })
         .collect()
@@ -294,6 +332,7 @@ fn compute_event_additional_cost_restriction(list: &AbilityList) -> Option<CanPl
         Cost::SpendOneOrMoreEnergy => {
             Some(CanPlayRestriction::AdditionalEnergyAvailable(Energy(1)))
         }
+        Cost::VariableEnergy => Some(CanPlayRestriction::Unrestricted),
         _ => None,
     }
 }
@@ -365,6 +404,11 @@ fn watch_for_battlefield_triggers(event: &TriggerEvent) -> EnumSet<TriggerName>
             triggers.insert(TriggerName::Dissolved);
             triggers
         }
+        TriggerEvent::StartOfYourTurn => {
+            let mut triggers = EnumSet::new();
+            triggers.insert(TriggerName::StartOfTurn);
+            triggers
+        }
         _ => todo!("Implement watch_for_trigger() for {:?}", event),
     }
 }
@@ -373,7 +417,8 @@ fn stack_triggers(list: &AbilityList) -> EnumSet<TriggerName> {
     let mut triggers = EnumSet::new();
 
     for ability_data in &list.static_abilities {
-        if let StandardStaticAbility::PlayFromVoid { .. } =
+        if let StandardStaticAbility::PlayFromVoid { .. }
+        | StandardStaticAbility::PlayFromHandOrVoidForCost { .. } =
             ability_data.ability.standard_static_ability()
         {
             triggers.insert(TriggerName::PlayedCardFromVoid);
@@ -388,6 +433,9 @@ fn has_play_from_void_ability(list: &AbilityList) -> bool {
             StandardStaticAbility::PlayFromVoid(PlayFromVoid { .. }) => {
                 return true;
             }
+            StandardStaticAbility::PlayFromHandOrVoidForCost(_) => {
+                return true;
+            }
             StandardStaticAbility::PlayOnlyFromVoid => {
                 return true;
             }
@@ -397,3 +445,12 @@ fn has_play_from_void_ability(list: &AbilityList) -> bool {
 
     false
 }
+
+fn has_spark_bonus_ability(list: &AbilityList) -> bool {
+    list.static_abilities.iter().any(|ability| {
+        matches!(
+            ability.ability.standard_static_ability(),
+            StandardStaticAbility::SparkBonusOtherCharacters { .. }
+        )
+    })
+}
@@ -5,6 +5,7 @@ use core_data::numerics::{Energy, Spark};
 use core_data::types::PlayerName;
 
 use crate::battle_card_queries::card;
+use crate::card_ability_queries::spark_bonus_queries;
 
 /// Returns the energy cost of a card, or 0 if it has no energy cost.
 ///
@@ -25,8 +26,11 @@ pub fn controller(battle: &BattleState, card_id: impl CardIdType) -> PlayerName
     card::get(battle, card_id).owner
 }
 
+/// Returns the current spark of a character, including bonuses from the
+/// static abilities of other cards.
 pub fn spark(battle: &BattleState, controller: PlayerName, id: CharacterId) -> Option<Spark> {
-    battle.cards.spark(controller, id)
+    let spark = battle.cards.spark(controller, id)?;
+    Some(spark + spark_bonus_queries::static_spark_bonus(battle, controller, id))
 }
 
 pub fn base_spark(battle: &BattleState, card_id: impl CardIdType) -> Option<Spark> {
@@ -37,8 +41,8 @@ pub fn card_type(battle: &BattleState, card_id: impl CardIdType) -> CardType {
     card::get(battle, card_id).card_type
 }
 
-pub fn card_subtype(_battle: &BattleState, _card_id: impl CardIdType) -> Option<CardSubtype> {
-    todo!("Implement card_subtype");
+pub fn card_subtype(battle: &BattleState, card_id: impl CardIdType) -> Option<CardSubtype> {
+    card::get_definition(battle, card_id).card_subtype
 }
 
 pub fn is_fast(battle: &BattleState, card_id: impl CardIdType) -> bool {
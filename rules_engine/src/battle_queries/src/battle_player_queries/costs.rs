@@ -1,17 +1,52 @@
+use ability_data::collection_expression::CollectionExpression;
 use ability_data::cost::Cost;
+use ability_data::predicate::Predicate;
 use battle_state::battle::battle_state::BattleState;
+use battle_state::core::effect_source::EffectSource;
 use battle_state::prompt_types::prompt_data::PromptChoiceLabel;
 use core_data::types::PlayerName;
 use strings::strings::phrase_ids;
 
+use crate::card_ability_queries::effect_predicates::{self, CharacterTargetingFlags};
+
 /// Returns true if the [PlayerName] player can pay a [Cost].
 pub fn can_pay(battle: &BattleState, player: PlayerName, cost: &Cost) -> bool {
     match cost {
         Cost::Energy(energy) => battle.players.player(player).current_energy >= *energy,
+        Cost::AbandonCharactersCount { target, count } => {
+            can_abandon(battle, EffectSource::Player { controller: player }, target, count)
+        }
         _ => todo!("Implement {:?}", cost),
     }
 }
 
+/// Returns true if the controller of `source` controls enough characters
+/// matching `target` to abandon `count` of them as a cost.
+pub fn can_abandon(
+    battle: &BattleState,
+    source: EffectSource,
+    target: &Predicate,
+    count: &CollectionExpression,
+) -> bool {
+    let matching = effect_predicates::matching_characters(
+        battle,
+        source,
+        target,
+        None,
+        CharacterTargetingFlags::default(),
+    );
+    match count {
+        CollectionExpression::Exactly(n) | CollectionExpression::OrMore(n) => {
+            matching.len() >= *n as usize
+        }
+        CollectionExpression::AllButOne => !matching.is_empty(),
+        CollectionExpression::All
+        | CollectionExpression::EachOther
+        | CollectionExpression::AnyNumberOf
+        | CollectionExpression::UpTo(_) => true,
+    }
+}
+
 /// Returns a [PromptChoiceLabel] for choosing to pay a [Cost].
 pub fn pay_cost_label(cost: &Cost) -> PromptChoiceLabel {
     match cost {
@@ -2,13 +2,15 @@ use battle_state::battle::battle_state::BattleState;
 use core_data::numerics::Spark;
 use core_data::types::PlayerName;
 
+use crate::battle_card_queries::card_properties;
+
 /// Returns the total spark value for a player.
 pub fn spark_total(battle: &BattleState, player: PlayerName) -> Spark {
     battle
         .cards
-        .battlefield_state(player)
-        .values()
-        .map(|character_state| character_state.spark)
+        .battlefield(player)
+        .iter()
+        .filter_map(|id| card_properties::spark(battle, player, id))
         .sum::<Spark>()
         + battle.players.player(player).spark_bonus
 }
@@ -9,7 +9,8 @@ use battle_state::core::effect_source::EffectSource;
 /// additional costs paid for a card which is no longer present on the stack.
 pub fn count(battle: &BattleState, source: EffectSource, expression: &QuantityExpression) -> u32 {
     match expression {
-        QuantityExpression::ForEachEnergySpentOnThisCard => {
+        QuantityExpression::ForEachEnergySpentOnThisCard
+        | QuantityExpression::VariableEnergyPaid => {
             if let EffectSource::Event { stack_card_id, .. } = source
                 && let Some(item) = battle.cards.stack_item(stack_card_id)
                 && let StackCardAdditionalCostsPaid::Energy(energy) = item.additional_costs_paid
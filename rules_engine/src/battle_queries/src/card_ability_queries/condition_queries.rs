@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use ability_data::condition::Condition;
+use ability_data::predicate::{CardPredicate, Predicate};
+use ability_data::static_ability::StaticAbility;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardId, CardIdType, VoidCardId};
+use core_data::card_types::CardType;
+use core_data::types::PlayerName;
+
+use crate::battle_card_queries::card_properties;
+
+/// Returns true if the given static ability of `card_id` currently applies.
+///
+/// Conditions are evaluated against the current battle state on every call,
+/// so a conditional static ability turns on and off as the quantities it
+/// references change.
+pub fn static_ability_is_active(
+    battle: &BattleState,
+    card_id: CardId,
+    ability: &StaticAbility,
+) -> bool {
+    match ability {
+        StaticAbility::StaticAbility(_) => true,
+        StaticAbility::WithOptions(options) => {
+            options.condition.as_ref().is_none_or(|condition| is_met(battle, card_id, condition))
+        }
+    }
+}
+
+/// Returns true if `condition` currently holds for the controller of
+/// `card_id`.
+///
+/// Conditions which refer to events earlier in the turn are not tracked by
+/// the battle state yet, and are never met.
+pub fn is_met(battle: &BattleState, card_id: CardId, condition: &Condition) -> bool {
+    let controller = card_properties::controller(battle, card_id);
+    match condition {
+        Condition::AlliesThatShareACharacterType { count } => {
+            allies_sharing_a_character_type(battle, controller, card_id) >= *count
+        }
+        Condition::CardsInVoidCount { count } => {
+            battle.cards.void(controller).len() >= *count as usize
+        }
+        Condition::PredicateCount { count, predicate } => {
+            predicate_count(battle, controller, card_id, predicate) >= *count
+        }
+        Condition::ThisCardIsInYourVoid => {
+            battle.cards.void(controller).contains(VoidCardId(card_id))
+        }
+        Condition::CardsDiscardedThisTurn { .. }
+        | Condition::CardsDrawnThisTurn { .. }
+        | Condition::DissolvedThisTurn { .. } => false,
+    }
+}
+
+/// Returns the largest number of characters `controller` has on the
+/// battlefield, other than `card_id`, which share a character type.
+fn allies_sharing_a_character_type(
+    battle: &BattleState,
+    controller: PlayerName,
+    card_id: CardId,
+) -> u32 {
+    let mut counts = BTreeMap::new();
+    for id in battle.cards.battlefield(controller).iter() {
+        if id.card_id() == card_id {
+            continue;
+        }
+        if let Some(subtype) = card_properties::card_subtype(battle, id) {
+            *counts.entry(subtype).or_insert(0) += 1;
+        }
+    }
+    counts.into_values().max().unwrap_or(0)
+}
+
+/// Returns the number of cards matching `predicate` from the perspective of
+/// `controller`.
+///
+/// Predicates which refer to a specific card, such as "that character", have
+/// nothing to refer to in a condition and match no cards.
+fn predicate_count(
+    battle: &BattleState,
+    controller: PlayerName,
+    card_id: CardId,
+    predicate: &Predicate,
+) -> u32 {
+    let battlefield = |player: PlayerName, card_predicate: &CardPredicate, exclude_self: bool| {
+        battle
+            .cards
+            .battlefield(player)
+            .iter()
+            .filter(|id| !exclude_self || id.card_id() != card_id)
+            .filter(|id| matches_card_predicate(battle, *id, card_predicate))
+            .count() as u32
+    };
+    let void = |player: PlayerName, card_predicate: &CardPredicate| {
+        battle
+            .cards
+            .void(player)
+            .iter()
+            .filter(|id| matches_card_predicate(battle, *id, card_predicate))
+            .count() as u32
+    };
+
+    match predicate {
+        Predicate::Your(card_predicate) => battlefield(controller, card_predicate, false),
+        Predicate::Another(card_predicate) => battlefield(controller, card_predicate, true),
+        Predicate::Enemy(card_predicate) => {
+            battlefield(controller.opponent(), card_predicate, false)
+        }
+        Predicate::Any(card_predicate) => {
+            battlefield(controller, card_predicate, false)
+                + battlefield(controller.opponent(), card_predicate, false)
+        }
+        Predicate::AnyOther(card_predicate) => {
+            battlefield(controller, card_predicate, true)
+                + battlefield(controller.opponent(), card_predicate, true)
+        }
+        Predicate::YourVoid(card_predicate) => void(controller, card_predicate),
+        Predicate::EnemyVoid(card_predicate) => void(controller.opponent(), card_predicate),
+        Predicate::This | Predicate::It | Predicate::Them | Predicate::That => 0,
+    }
+}
+
+/// Returns true if `card_id` matches `predicate`.
+///
+/// Predicates which compare against other game quantities are not supported
+/// in conditions and match no cards.
+pub fn matches_card_predicate(
+    battle: &BattleState,
+    card_id: impl CardIdType,
+    predicate: &CardPredicate,
+) -> bool {
+    match predicate {
+        CardPredicate::Card => true,
+        CardPredicate::Character => {
+            card_properties::card_type(battle, card_id) == CardType::Character
+        }
+        CardPredicate::Event => card_properties::card_type(battle, card_id) == CardType::Event,
+        CardPredicate::CharacterType(subtype) => {
+            card_properties::card_subtype(battle, card_id) == Some(*subtype)
+        }
+        CardPredicate::NotCharacterType(subtype) => {
+            card_properties::card_type(battle, card_id) == CardType::Character
+                && card_properties::card_subtype(battle, card_id) != Some(*subtype)
+        }
+        CardPredicate::Or(predicates) => {
+            predicates.iter().any(|p| matches_card_predicate(battle, card_id, p))
+        }
+        _ => false,
+    }
+}
@@ -103,6 +103,9 @@ fn has_dissolve_effect_targeting(
         Effect::Modal(choices) => choices.iter().any(|choice| {
             has_dissolve_effect_targeting(battle, source, &choice.effect, target_characters)
         }),
+        Effect::IfYouDo(if_you_do) => if_you_do.effects().any(|effect| {
+            has_dissolve_effect_targeting_standard(battle, source, effect, target_characters)
+        }),
     }
 }
 
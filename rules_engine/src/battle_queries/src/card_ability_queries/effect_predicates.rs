@@ -118,13 +118,29 @@ pub fn matching_cards_in_void(
 
 /// Returns all characters from `collection` which match a `predicate`.
 fn on_battlefield(
-    _battle: &BattleState,
-    _source: EffectSource,
+    battle: &BattleState,
+    source: EffectSource,
     collection: CardSet<CharacterId>,
     predicate: &CardPredicate,
 ) -> CardSet<CharacterId> {
     match predicate {
         CardPredicate::Card | CardPredicate::Character => collection,
+        CardPredicate::CharacterType(subtype) => {
+            let mut characters = CardSet::default();
+            for id in collection.iter() {
+                if card_properties::card_subtype(battle, id) == Some(*subtype) {
+                    characters.insert(id);
+                }
+            }
+            characters
+        }
+        CardPredicate::Or(predicates) => {
+            let mut result = CardSet::default();
+            for predicate in predicates {
+                result.union_with(&on_battlefield(battle, source, collection.clone(), predicate));
+            }
+            result
+        }
         _ => todo!("Implement {:?}", predicate),
     }
 }
@@ -159,6 +175,13 @@ fn on_stack(
         CardPredicate::CouldDissolve { target } => {
             could_dissolve::filter_could_dissolve(battle, source, collection, target)
         }
+        CardPredicate::Or(predicates) => {
+            let mut result = CardSet::default();
+            for predicate in predicates {
+                result.union_with(&on_stack(battle, source, collection.clone(), predicate));
+            }
+            result
+        }
         _ => todo!("Implement {:?}", predicate),
     }
 }
@@ -166,7 +189,7 @@ fn on_stack(
 /// Returns all void cards from `collection` which match a `predicate`.
 fn in_void(
     battle: &BattleState,
-    _source: EffectSource,
+    source: EffectSource,
     collection: CardSet<VoidCardId>,
     predicate: &CardPredicate,
 ) -> CardSet<VoidCardId> {
@@ -181,6 +204,22 @@ fn in_void(
             }
             events
         }
+        CardPredicate::CharacterType(subtype) => {
+            let mut characters = CardSet::default();
+            for id in collection.iter() {
+                if card_properties::card_subtype(battle, id) == Some(*subtype) {
+                    characters.insert(id);
+                }
+            }
+            characters
+        }
+        CardPredicate::Or(predicates) => {
+            let mut result = CardSet::default();
+            for predicate in predicates {
+                result.union_with(&in_void(battle, source, collection.clone(), predicate));
+            }
+            result
+        }
         _ => todo!("Implement {:?}", predicate),
     }
 }
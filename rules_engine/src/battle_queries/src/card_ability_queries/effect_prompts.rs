@@ -93,6 +93,20 @@ pub fn query(
                 )
             })
             .collect(),
+        Effect::IfYouDo(if_you_do) => std::iter::once((&if_you_do.effect, if_you_do.optional))
+            .chain(if_you_do.follow_up.iter().map(|e| (&e.effect, e.optional)))
+            .filter_map(|(effect, optional)| {
+                standard_effect_targeting_prompt(
+                    battle,
+                    player,
+                    source,
+                    effect,
+                    optional,
+                    that_card,
+                    on_selected,
+                )
+            })
+            .collect(),
         Effect::Modal(modal) => {
             if let Some(choice) = modal_choice {
                 query(
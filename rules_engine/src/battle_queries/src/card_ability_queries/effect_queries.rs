@@ -18,14 +18,19 @@ pub fn character_targeting_flags(effect: &StandardEffect) -> CharacterTargetingF
 
 /// Returns the set of [CharacterId]s for characters which cannot currently be
 /// dissolved.
+///
+/// Effects only apply to the object they were created for, so a card which
+/// left play and returned is not protected.
 pub fn prevent_dissolved_set(battle: &BattleState) -> CardSet<CharacterId> {
     let mut result = CardSet::default();
-    if battle.ability_state.until_end_of_turn.prevent_dissolved.is_empty() {
+    if battle.ability_state.prevent_dissolved.is_empty() {
         return result;
     }
 
-    for character_id in battle.ability_state.until_end_of_turn.prevent_dissolved.iter() {
-        result.insert(character_id.card_id);
+    for effect in battle.ability_state.prevent_dissolved.iter() {
+        if battle.cards.is_valid_object_id(effect.value.card_id, effect.value.object_id) {
+            result.insert(effect.value.card_id);
+        }
     }
 
     result
@@ -34,12 +39,7 @@ pub fn prevent_dissolved_set(battle: &BattleState) -> CardSet<CharacterId> {
 /// Returns true if the given character cannot currently be dissolved.
 pub fn should_prevent_dissolve(battle: &BattleState, id: CharacterId) -> bool {
     let object_id = card::get(battle, id).object_id;
-    battle
-        .ability_state
-        .until_end_of_turn
-        .prevent_dissolved
-        .iter()
-        .any(|card_object_id| card_object_id.object_id == object_id)
+    battle.ability_state.prevent_dissolved.iter().any(|effect| effect.value.object_id == object_id)
 }
 
 /// Returns true if the given effect is a dissolve effect.
@@ -1,7 +1,9 @@
+pub mod condition_queries;
 pub mod could_dissolve;
 pub mod effect_predicates;
 pub mod effect_prompts;
 pub mod effect_queries;
+pub mod spark_bonus_queries;
 pub mod target_predicates;
 pub mod trigger_predicates;
 pub mod trigger_queries;
@@ -0,0 +1,67 @@
+use ability_data::condition::Condition;
+use ability_data::static_ability::{StandardStaticAbility, StaticAbility};
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardId, CardIdType, CharacterId};
+use core_data::numerics::Spark;
+use core_data::types::PlayerName;
+
+use crate::battle_card_queries::card;
+use crate::card_ability_queries::condition_queries;
+
+/// Returns the spark `id` currently receives from static abilities of other
+/// cards controlled by `controller`.
+///
+/// Bonuses are recomputed from the battle state on every call, so a bonus
+/// with a condition applies exactly while that condition is met.
+pub fn static_spark_bonus(battle: &BattleState, controller: PlayerName, id: CharacterId) -> Spark {
+    let battlefield =
+        battle.cards.battlefield(controller).iter().map(|source| (source.card_id(), false));
+    let void = battle.cards.void(controller).iter().map(|source| (source.card_id(), true));
+    battlefield
+        .chain(void)
+        .filter(|(source, _)| *source != id.card_id())
+        .map(|(source, in_void)| bonus_from_source(battle, source, in_void, id))
+        .sum()
+}
+
+/// Returns the spark bonus the static abilities of `source` grant to `id`.
+///
+/// Abilities function while their card is on the battlefield, except for
+/// abilities conditional on their card being in the void, which function only
+/// while it is there.
+fn bonus_from_source(
+    battle: &BattleState,
+    source: CardId,
+    in_void: bool,
+    id: CharacterId,
+) -> Spark {
+    let abilities = card::ability_list(battle, source);
+    if !abilities.has_spark_bonus_ability {
+        return Spark(0);
+    }
+
+    abilities
+        .static_abilities
+        .iter()
+        .filter(|ability_data| functions_in_void(&ability_data.ability) == in_void)
+        .filter(|ability_data| {
+            condition_queries::static_ability_is_active(battle, source, &ability_data.ability)
+        })
+        .filter_map(|ability_data| match ability_data.ability.standard_static_ability() {
+            StandardStaticAbility::SparkBonusOtherCharacters { matching, added_spark }
+                if condition_queries::matches_card_predicate(battle, id, matching) =>
+            {
+                Some(*added_spark)
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+fn functions_in_void(ability: &StaticAbility) -> bool {
+    matches!(
+        ability,
+        StaticAbility::WithOptions(options)
+            if options.condition == Some(Condition::ThisCardIsInYourVoid)
+    )
+}
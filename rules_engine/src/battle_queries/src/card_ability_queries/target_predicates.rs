@@ -20,11 +20,11 @@ pub fn get_character_target_predicate(effect: &StandardEffect) -> Option<&Predic
         StandardEffect::AbandonAndGainEnergyForSpark { target, .. } => Some(target),
         StandardEffect::BanishCharacter { target } => Some(target),
         StandardEffect::BanishCharacterUntilLeavesPlay { target, .. } => Some(target),
-        StandardEffect::BanishUntilNextMain { target } => Some(target),
+        StandardEffect::BanishForDuration { target, .. } => Some(target),
         StandardEffect::BanishCollection { target, .. } => Some(target),
         StandardEffect::BanishThenMaterialize { target, .. } => Some(target),
         StandardEffect::Copy { target } => Some(target),
-        StandardEffect::DisableActivatedAbilitiesWhileInPlay { target } => Some(target),
+        StandardEffect::DisableActivatedAbilities { target, .. } => Some(target),
         StandardEffect::DissolveCharacter { target } => Some(target),
         StandardEffect::DissolveCharactersCount { target, .. } => Some(target),
         StandardEffect::DissolveCharactersQuantity { target, .. } => Some(target),
@@ -33,12 +33,14 @@ pub fn get_character_target_predicate(effect: &StandardEffect) -> Option<&Predic
         StandardEffect::GainsReclaim { target, .. } => Some(target),
         StandardEffect::GainsSpark { target, .. } => Some(target),
         StandardEffect::GainsSparkForQuantity { target, .. } => Some(target),
-        StandardEffect::GainsSparkUntilYourNextMainForEach { target, .. } => Some(target),
+        StandardEffect::GainsSparkForEach { target, .. } => Some(target),
         StandardEffect::MaterializeCharacterAtEndOfTurn { target } => Some(target),
         StandardEffect::MaterializeCollection { target, .. } => Some(target),
         StandardEffect::MaterializeSilentCopy { target, .. } => Some(target),
-        StandardEffect::PreventDissolveThisTurn { target } => Some(target),
+        StandardEffect::PreventDissolve { target, .. } => Some(target),
         StandardEffect::PutOnTopOfEnemyDeck { target } => Some(target),
+        StandardEffect::PutSparkCounters { target, .. } => Some(target),
+        StandardEffect::RemoveSparkCounters { target, .. } => Some(target),
         StandardEffect::ReturnToHand { target } => Some(target),
 
         StandardEffect::AbandonAtEndOfTurn { .. } => None,
@@ -48,8 +50,8 @@ pub fn get_character_target_predicate(effect: &StandardEffect) -> Option<&Predic
         StandardEffect::CopyNextPlayed { .. } => None,
         StandardEffect::Counterspell { .. } => None,
         StandardEffect::CounterspellUnlessPaysCost { .. } => None,
-        StandardEffect::CreateStaticAbilityUntilEndOfTurn { .. } => None,
-        StandardEffect::CreateTriggerUntilEndOfTurn { .. } => None,
+        StandardEffect::CreateStaticAbility { .. } => None,
+        StandardEffect::CreateTrigger { .. } => None,
         StandardEffect::DiscardCardFromEnemyHand { .. } => None,
         StandardEffect::DiscardCardFromEnemyHandThenTheyDraw { .. } => None,
         StandardEffect::DiscardCards { .. } => None,
@@ -96,6 +98,7 @@ pub fn get_character_target_predicate(effect: &StandardEffect) -> Option<&Predic
         StandardEffect::ThenMaterializeIt => None,
         StandardEffect::TriggerAdditionalJudgmentPhaseAtEndOfTurn => None,
         StandardEffect::TriggerJudgmentAbility { .. } => None,
+        StandardEffect::TriggerThisCardsAbility { .. } => None,
         StandardEffect::YouWinTheGame => None,
     }
 }
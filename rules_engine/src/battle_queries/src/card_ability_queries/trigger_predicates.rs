@@ -84,6 +84,9 @@ fn matches_card_predicate(
         CardPredicate::NotCharacterType(character_type) => {
             card_properties::card_subtype(battle, trigger_card_id) != Some(*character_type)
         }
+        CardPredicate::Or(predicates) => predicates
+            .iter()
+            .any(|predicate| matches_card_predicate(battle, predicate, trigger_card_id)),
         CardPredicate::CouldDissolve { .. } => {
             todo!("Implement CouldDissolve card predicate")
         }
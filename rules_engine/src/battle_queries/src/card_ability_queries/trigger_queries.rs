@@ -163,6 +163,10 @@ pub fn matches(
             ),
             _ => false,
         },
+        TriggerEvent::StartOfYourTurn => match trigger {
+            Trigger::StartOfTurn(player) => owning_card_controller == player,
+            _ => false,
+        },
         TriggerEvent::OpponentPlays(predicate) => match trigger {
             Trigger::PlayedCard(card_id) => {
                 let card_controller = card_properties::controller(battle, card_id);
@@ -197,6 +201,7 @@ pub fn triggering_card_id(trigger: Trigger) -> Option<CardId> {
         Trigger::PlayedCard(card_id) => Some(card_id.card_id()),
         Trigger::PlayedCardFromHand(card_id) => Some(card_id.card_id()),
         Trigger::PlayedCardFromVoid(card_id) => Some(card_id.card_id()),
+        Trigger::StartOfTurn(..) => None,
     }
 }
 
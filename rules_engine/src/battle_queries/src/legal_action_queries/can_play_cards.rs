@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use ability_data::static_ability::StandardStaticAbility;
+use ability_data::static_ability::{StandardStaticAbility, StaticAbility};
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::card_id::{AbilityId, CardId, CardIdType, HandCardId, VoidCardId};
 use battle_state::battle_cards::ability_list::CanPlayRestriction;
@@ -11,7 +11,7 @@ use core_data::numerics::Energy;
 use core_data::types::PlayerName;
 
 use crate::battle_card_queries::{card, card_properties};
-use crate::card_ability_queries::effect_queries;
+use crate::card_ability_queries::{condition_queries, effect_queries};
 use crate::legal_action_queries::{
     has_legal_additional_costs, has_legal_targets, legal_actions_cache, legal_modal_effect_choices,
 };
@@ -129,9 +129,82 @@ pub fn play_from_void_energy_cost(
 /// e.g. validate that the player has sufficient energy to pay this cost.
 ///
 /// Other costs are handled in the 'meets_restriction' step.
+///
+/// Static abilities with a condition are only considered while that condition
+/// is met.
 pub fn can_play_from_void_energy_cost(
     battle: &BattleState,
     card_id: VoidCardId,
+) -> Option<FromVoidWithCost> {
+    lowest_play_from_void_cost(battle, card_id, |ability| {
+        condition_queries::static_ability_is_active(battle, card_id.card_id(), ability)
+    })
+}
+
+/// Equivalent to [can_play_from_void_energy_cost], but treats every
+/// conditional static ability as active.
+///
+/// Used to build the legal actions cache, which is not recomputed when the
+/// quantities referenced by conditions change. Conditions are instead checked
+/// again by [can_play_from_void_energy_cost] when legal actions are queried.
+pub fn potential_play_from_void_energy_cost(
+    battle: &BattleState,
+    card_id: VoidCardId,
+) -> Option<FromVoidWithCost> {
+    lowest_play_from_void_cost(battle, card_id, |_| true)
+}
+
+/// Returns true if the given player currently meets a card's 'can play'
+/// restriction when paying `energy_cost` to play it.
+pub fn meets_restriction(
+    battle: &BattleState,
+    controller: PlayerName,
+    restriction: CanPlayRestriction,
+    energy_cost: Energy,
+) -> bool {
+    match restriction {
+        CanPlayRestriction::Unrestricted => true,
+        CanPlayRestriction::EnemyCharacterOnBattlefield => {
+            !battle.cards.battlefield(controller.opponent()).is_empty()
+        }
+        CanPlayRestriction::DissolveEnemyCharacter => {
+            let prevent = effect_queries::prevent_dissolved_set(battle);
+            battle.cards.battlefield(controller.opponent()).iter().any(|c| !prevent.contains(c))
+        }
+        CanPlayRestriction::EnemyCardOnStack => {
+            !battle.cards.stack_set(controller.opponent()).is_empty()
+        }
+        CanPlayRestriction::EnemyEventCardOnStack => battle
+            .cards
+            .stack_set(controller.opponent())
+            .iter()
+            .any(|id| card_properties::card_type(battle, id) == CardType::Event),
+        CanPlayRestriction::EnemyCharacterCardOnStack => battle
+            .cards
+            .stack_set(controller.opponent())
+            .iter()
+            .any(|id| card_properties::card_type(battle, id) == CardType::Character),
+        CanPlayRestriction::AdditionalEnergyAvailable(required_energy) => {
+            battle.players.player(controller).current_energy - energy_cost >= required_energy
+        }
+    }
+}
+
+impl From<CanPlayFromVoid> for CardId {
+    fn from(value: CanPlayFromVoid) -> Self {
+        value.card_id.card_id()
+    }
+}
+
+/// Returns the lowest cost play-from-void ability of a card for which
+/// `include` returns true.
+///
+/// `include` is only called for abilities which allow playing the card from
+/// the void.
+fn lowest_play_from_void_cost(
+    battle: &BattleState,
+    card_id: VoidCardId,
+    include: impl Fn(&StaticAbility) -> bool,
 ) -> Option<FromVoidWithCost> {
     card::ability_list(battle, card_id)
         .static_abilities
@@ -143,16 +216,11 @@ pub fn can_play_from_void_energy_cost(
                 ability_data.ability_number,
                 ability_data.ability.standard_static_ability(),
             )
+            .filter(|_| include(&ability_data.ability))
         })
         .min()
 }
 
-impl From<CanPlayFromVoid> for CardId {
-    fn from(value: CanPlayFromVoid) -> Self {
-        value.card_id.card_id()
-    }
-}
-
 /// Returns the energy cost of playing a card from the void with a given static
 /// ability, if it can be played.
 fn can_play_from_void_with_static_ability(
@@ -172,6 +240,9 @@ fn can_play_from_void_with_static_ability(
             };
             Some(FromVoidWithCost { cost, via_ability_id: ability_id })
         }
+        StandardStaticAbility::PlayFromHandOrVoidForCost(play) => {
+            Some(FromVoidWithCost { cost: play.energy_cost, via_ability_id: ability_id })
+        }
         StandardStaticAbility::PlayOnlyFromVoid => {
             let cost = card_properties::converted_energy_cost(battle, card_id);
             Some(FromVoidWithCost { cost, via_ability_id: ability_id })
@@ -216,37 +287,3 @@ fn add_if_meets_restriction<TId>(
         legal_cards.insert(card_id);
     }
 }
-
-fn meets_restriction(
-    battle: &BattleState,
-    controller: PlayerName,
-    restriction: CanPlayRestriction,
-    energy_cost: Energy,
-) -> bool {
-    match restriction {
-        CanPlayRestriction::Unrestricted => true,
-        CanPlayRestriction::EnemyCharacterOnBattlefield => {
-            !battle.cards.battlefield(controller.opponent()).is_empty()
-        }
-        CanPlayRestriction::DissolveEnemyCharacter => {
-            let prevent = effect_queries::prevent_dissolved_set(battle);
-            battle.cards.battlefield(controller.opponent()).iter().any(|c| !prevent.contains(c))
-        }
-        CanPlayRestriction::EnemyCardOnStack => {
-            !battle.cards.stack_set(controller.opponent()).is_empty()
-        }
-        CanPlayRestriction::EnemyEventCardOnStack => battle
-            .cards
-            .stack_set(controller.opponent())
-            .iter()
-            .any(|id| card_properties::card_type(battle, id) == CardType::Event),
-        CanPlayRestriction::EnemyCharacterCardOnStack => battle
-            .cards
-            .stack_set(controller.opponent())
-            .iter()
-            .any(|id| card_properties::card_type(battle, id) == CardType::Character),
-        CanPlayRestriction::AdditionalEnergyAvailable(required_energy) => {
-            battle.players.player(controller).current_energy - energy_cost >= required_energy
-        }
-    }
-}
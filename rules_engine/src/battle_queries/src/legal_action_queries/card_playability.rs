@@ -0,0 +1,165 @@
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle::battle_turn_phase::BattleTurnPhase;
+use battle_state::battle::card_id::{CardIdType, HandCardId};
+use battle_state::battle_cards::ability_list::CanPlayRestriction;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+
+use crate::battle_card_queries::{card, card_properties};
+use crate::legal_action_queries::can_play_cards::{self, FastOnly};
+use crate::legal_action_queries::{
+    has_legal_additional_costs, has_legal_targets, legal_modal_effect_choices,
+};
+
+/// Whether a card in hand can currently be played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playability {
+    Playable,
+    Unplayable(UnplayableReason),
+}
+
+/// Reason a card in hand cannot currently be played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnplayableReason {
+    /// The player cannot play cards at this point in the turn, e.g. because it
+    /// is the opponent's turn or a prompt must be resolved first.
+    WrongTiming,
+
+    /// The player can only play cards with the "fast" property right now.
+    NotFast,
+
+    /// The player does not have enough energy to play this card.
+    InsufficientEnergy { cost: Energy, available: Energy },
+
+    /// The card requires targets and none are available.
+    NoLegalTargets,
+
+    /// The card has additional costs which cannot be paid.
+    CannotPayAdditionalCosts,
+
+    /// None of the card's modal choices can be selected.
+    NoLegalModalChoices,
+}
+
+/// Playability verdict for a single card in hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardPlayability {
+    pub card_id: HandCardId,
+    pub playability: Playability,
+}
+
+/// Returns a playability verdict for every card in the given player's hand.
+///
+/// A card is reported as [Playability::Playable] if and only if playing it
+/// from hand is a legal action for this player.
+pub fn for_hand(battle: &BattleState, player: PlayerName) -> Vec<CardPlayability> {
+    let timing = allowed_timing(battle, player);
+    battle
+        .cards
+        .hand(player)
+        .iter()
+        .map(|card_id| CardPlayability {
+            card_id,
+            playability: match check_card(battle, player, card_id, timing) {
+                Some(reason) => Playability::Unplayable(reason),
+                None => Playability::Playable,
+            },
+        })
+        .collect()
+}
+
+/// Returns the playability of a single card in the given player's hand.
+pub fn for_card(battle: &BattleState, player: PlayerName, card_id: HandCardId) -> Playability {
+    match check_card(battle, player, card_id, allowed_timing(battle, player)) {
+        Some(reason) => Playability::Unplayable(reason),
+        None => Playability::Playable,
+    }
+}
+
+impl Playability {
+    pub fn is_playable(&self) -> bool {
+        matches!(self, Playability::Playable)
+    }
+}
+
+/// Returns which cards the player may play at the current point in the turn,
+/// mirroring the checks in `legal_actions::compute`.
+///
+/// Returns None if the player cannot play any cards right now.
+fn allowed_timing(battle: &BattleState, player: PlayerName) -> Option<FastOnly> {
+    if matches!(battle.status, BattleStatus::GameOver { .. }) || !battle.prompts.is_empty() {
+        return None;
+    }
+
+    if let Some(priority) = battle.stack_priority {
+        (priority == player).then_some(FastOnly::Yes)
+    } else if battle.turn.active_player == player && battle.phase == BattleTurnPhase::Main {
+        Some(FastOnly::No)
+    } else if battle.turn.active_player != player && battle.phase == BattleTurnPhase::Ending {
+        Some(FastOnly::Yes)
+    } else {
+        None
+    }
+}
+
+/// Returns the first reason this card cannot be played, or None if it can be.
+fn check_card(
+    battle: &BattleState,
+    player: PlayerName,
+    card_id: HandCardId,
+    timing: Option<FastOnly>,
+) -> Option<UnplayableReason> {
+    let Some(fast_only) = timing else {
+        return Some(UnplayableReason::WrongTiming);
+    };
+    if fast_only == FastOnly::Yes && !card_properties::is_fast(battle, card_id) {
+        return Some(UnplayableReason::NotFast);
+    }
+
+    let cost = card_properties::converted_energy_cost(battle, card_id);
+    let available = battle.players.player(player).current_energy;
+    if cost > available {
+        return Some(UnplayableReason::InsufficientEnergy { cost, available });
+    }
+
+    match card::get(battle, card_id).can_play_restriction {
+        Some(restriction)
+            if !can_play_cards::meets_restriction(battle, player, restriction, cost) =>
+        {
+            Some(restriction_reason(restriction, cost, available))
+        }
+        Some(_) => None,
+        None => {
+            let card_id = card_id.card_id();
+            if !has_legal_targets::for_event(battle, player, card_id) {
+                Some(UnplayableReason::NoLegalTargets)
+            } else if !has_legal_additional_costs::for_event(battle, player, card_id, cost) {
+                Some(UnplayableReason::CannotPayAdditionalCosts)
+            } else if !legal_modal_effect_choices::event_has_legal_choices(battle, player, card_id)
+            {
+                Some(UnplayableReason::NoLegalModalChoices)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn restriction_reason(
+    restriction: CanPlayRestriction,
+    cost: Energy,
+    available: Energy,
+) -> UnplayableReason {
+    match restriction {
+        CanPlayRestriction::AdditionalEnergyAvailable(required) => {
+            UnplayableReason::InsufficientEnergy { cost: cost + required, available }
+        }
+        CanPlayRestriction::Unrestricted
+        | CanPlayRestriction::EnemyCharacterOnBattlefield
+        | CanPlayRestriction::DissolveEnemyCharacter
+        | CanPlayRestriction::EnemyCardOnStack
+        | CanPlayRestriction::EnemyEventCardOnStack
+        | CanPlayRestriction::EnemyCharacterCardOnStack => UnplayableReason::NoLegalTargets,
+    }
+}
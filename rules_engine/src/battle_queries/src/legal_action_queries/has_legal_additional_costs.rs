@@ -6,6 +6,7 @@ use core_data::numerics::Energy;
 use core_data::types::PlayerName;
 
 use crate::battle_card_queries::card;
+use crate::battle_player_queries::costs;
 
 /// Returns true if the given card has legal additional cost choices for its
 /// event abilities after paying the energy cost value `paid`, or if this
@@ -40,6 +41,10 @@ fn has_legal_additional_cost_choices_for_effect(
         Cost::SpendOneOrMoreEnergy => {
             battle.players.player(source.controller()).current_energy > already_paid
         }
+        Cost::VariableEnergy => true,
+        Cost::AbandonCharactersCount { target, count } => {
+            costs::can_abandon(battle, source, target, count)
+        }
         _ => todo!("Implement additional cost choices"),
     }
 }
@@ -50,6 +50,9 @@ pub fn has_legal_targets_for_effect(
         Effect::Modal(modal) => {
             modal.iter().any(|choice| has_legal_targets_for_effect(battle, source, &choice.effect))
         }
+        Effect::IfYouDo(if_you_do) => if_you_do.effects().all(|standard_effect| {
+            has_legal_targets_for_standard_effect(battle, source, standard_effect)
+        }),
     }
 }
 
@@ -98,7 +98,7 @@ fn for_available_energy(
         }
 
         if let Some(play_from_void) =
-            can_play_cards::can_play_from_void_energy_cost(battle, VoidCardId(card_id))
+            can_play_cards::potential_play_from_void_energy_cost(battle, VoidCardId(card_id))
             && play_from_void.cost <= energy
         {
             result.play_from_void.insert(VoidCardId(card_id));
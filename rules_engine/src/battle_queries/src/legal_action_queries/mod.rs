@@ -1,5 +1,6 @@
 pub mod can_activate_abilities;
 pub mod can_play_cards;
+pub mod card_playability;
 pub mod has_legal_additional_costs;
 pub mod has_legal_targets;
 pub mod legal_actions;
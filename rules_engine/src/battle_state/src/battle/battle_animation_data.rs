@@ -12,6 +12,7 @@ use crate::battle_cards::stack_card_state::{EffectTargets, StackItemId};
 use crate::battle_cards::zone::Zone;
 use crate::core::effect_source::EffectSource;
 use crate::prompt_types::prompt_data::PromptChoiceLabel;
+use crate::triggers::trigger::Trigger;
 
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
 pub enum TargetedEffectName {
@@ -110,4 +111,9 @@ pub struct TriggerAnimation {
     pub controller: PlayerName,
     pub character_id: CharacterId,
     pub ability_number: AbilityNumber,
+
+    /// Event which caused this ability to trigger.
+    ///
+    /// Triggers with the same event are displayed as a group.
+    pub trigger: Trigger,
 }
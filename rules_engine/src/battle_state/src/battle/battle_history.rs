@@ -1,3 +1,4 @@
+use core_data::numerics::TurnId;
 use core_data::types::PlayerName;
 use serde::{Deserialize, Serialize};
 
@@ -13,10 +14,14 @@ pub struct BattleHistory {
 pub struct BattleHistoryAction {
     pub player: PlayerName,
     pub action: BattleAction,
+
+    /// Turn during which the action was taken.
+    #[serde(default)]
+    pub turn: TurnId,
 }
 
 impl BattleHistory {
-    pub fn push_action(&mut self, player: PlayerName, action: BattleAction) {
-        self.actions.push(BattleHistoryAction { player, action });
+    pub fn push_action(&mut self, player: PlayerName, action: BattleAction, turn: TurnId) {
+        self.actions.push(BattleHistoryAction { player, action, turn });
     }
 }
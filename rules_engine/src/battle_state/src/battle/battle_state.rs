@@ -29,6 +29,7 @@ use crate::battle_player::battle_player_state::BattlePlayerState;
 use crate::battle_player::legal_actions_cache_data::LegalActionsCacheData;
 use crate::battle_player::player_map::PlayerMap;
 use crate::battle_trace::battle_tracing::BattleTracing;
+use crate::battle_trace::decision_explanation::DecisionExplanation;
 use crate::core::effect_source::EffectSource;
 use crate::prompt_types::prompt_data::PromptData;
 use crate::triggers::trigger_state::TriggerState;
@@ -112,6 +113,11 @@ pub struct BattleState {
     #[serde(skip)]
     pub tracing: Option<BattleTracing>,
 
+    /// Explanation of the most recent action selected by an AI agent in this
+    /// battle, shown in developer tools.
+    #[serde(skip)]
+    pub last_ai_decision: Option<Arc<DecisionExplanation>>,
+
     /// History of actions and events during this battle.
     ///
     /// Can be None if history tracking is disabled, e.g. during AI simulation.
@@ -209,6 +215,7 @@ impl BattleState {
             pending_effects: self.pending_effects.clone(),
             animations: None,
             tracing: None,
+            last_ai_decision: None,
             action_history: None,
             turn_history: self.turn_history.clone(),
             request_context: self.request_context.clone(),
@@ -249,6 +256,7 @@ impl BattleState {
                 pending_effects: self.pending_effects.clone(),
                 animations: None,
                 tracing: None,
+                last_ai_decision: None,
                 action_history: None,
                 turn_history: self.turn_history.clone(),
                 request_context: self.request_context.clone(),
@@ -261,7 +269,7 @@ impl BattleState {
     /// Adds a new action to the history of this battle.
     pub fn push_history_action(&mut self, player: PlayerName, action: BattleAction) {
         if let Some(history) = &mut self.action_history {
-            history.push_action(player, action);
+            history.push_action(player, action, self.turn.turn_id);
         }
     }
 
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Ord, PartialOrd, Hash, EnumSetType, Sequence, Serialize, Deserialize)]
 pub enum BattleTurnPhase {
     Starting,
+    FiringStartOfTurnTriggers,
     Judgment,
     Dreamwell,
     Draw,
@@ -13,6 +13,7 @@ pub struct TurnHistory {
 
 /// Tracks history of actions and events while resolving a single action.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CurrentActionHistory {
     /// Whether the hand size limit was exceeded while resolving the action.
     pub hand_size_limit_exceeded: bool,
@@ -20,6 +21,13 @@ pub struct CurrentActionHistory {
     /// IDs of the characters that were abandoned due to the character limit
     /// while resolving this action.
     pub character_limit_characters_abandoned: CardSet<CharacterId>,
+
+    /// Number of cards drawn while resolving this action.
+    pub cards_drawn: u32,
+
+    /// Number of this player's characters dissolved while resolving this
+    /// action.
+    pub characters_dissolved: u32,
 }
 
 impl TurnHistory {
@@ -36,6 +36,10 @@ pub struct AbilityList {
     /// Indicates whether this card has static abilities which may let it be
     /// played from the void.
     pub has_play_from_void_ability: bool,
+
+    /// Indicates whether this card has static abilities which may add spark to
+    /// other characters.
+    pub has_spark_bonus_ability: bool,
 }
 
 pub struct AbilityEffectFlags {}
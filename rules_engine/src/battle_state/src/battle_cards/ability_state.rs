@@ -1,3 +1,5 @@
+use ability_data::duration::Duration;
+use core_data::types::PlayerName;
 use serde::{Deserialize, Serialize};
 
 use crate::battle::card_id::{CardId, CharacterId};
@@ -13,11 +15,31 @@ pub struct AbilityState {
     #[serde(default)]
     pub banish_when_leaves_play: CardSet<CardId>,
 
+    /// Characters which should be prevented from being dissolved.
+    ///
+    /// Effects are removed when their character leaves play.
+    #[serde(default)]
+    pub prevent_dissolved: Vec<TimedEffect<CardObjectId<CharacterId>>>,
+
     /// State for abilities which persist until the end of the current turn.
     #[serde(default)]
     pub until_end_of_turn: UntilEndOfTurn,
 }
 
+/// A continuous effect applied to a value which lasts for a [Duration].
+///
+/// Effects lasting [Duration::WhileInPlay] are never removed based on time.
+/// Values should be tracked via object IDs and removed when their target
+/// leaves play.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TimedEffect<T> {
+    pub value: T,
+    pub duration: Duration,
+
+    /// Player who controlled the ability which created this effect.
+    pub controller: PlayerName,
+}
+
 /// Stores state for abilities which persist until the end of the current turn.
 ///
 /// This struct is automatically dropped by the rules engine when a new turn
@@ -29,6 +51,49 @@ pub struct UntilEndOfTurn {
     /// Selected during the 'dreamwell' phase of the turn.
     pub active_dreamwell_card: Option<BattleDreamwellCardId>,
 
-    /// Characters which should be prevented from being dissolved this turn.
-    pub prevent_dissolved: Vec<CardObjectId<CharacterId>>,
+    /// Cards which were returned to a player's hand from their void this turn.
+    #[serde(default)]
+    pub returned_to_hand: CardSet<CardId>,
+
+    /// Characters prevented from being dissolved this turn, as stored by
+    /// battles saved before these effects were tracked in
+    /// [AbilityState::prevent_dissolved].
+    ///
+    /// Only populated while loading a saved battle, see
+    /// [AbilityState::upgrade_saved_effects].
+    #[serde(default, rename = "prevent_dissolved", skip_serializing)]
+    pub saved_prevent_dissolved: Vec<CardObjectId<CharacterId>>,
+}
+
+impl AbilityState {
+    /// Moves effects read from an older saved battle into their current
+    /// representation.
+    ///
+    /// `owner` returns the owner of a character, which is used as the
+    /// controller of effects which did not record one.
+    pub fn upgrade_saved_effects(&mut self, owner: impl Fn(CharacterId) -> PlayerName) {
+        let saved = std::mem::take(&mut self.until_end_of_turn.saved_prevent_dissolved);
+        self.prevent_dissolved.extend(saved.into_iter().map(|value| TimedEffect {
+            value,
+            duration: Duration::ThisTurn,
+            controller: owner(value.card_id),
+        }));
+    }
+}
+
+impl<T> TimedEffect<T> {
+    /// Returns true if this effect ends when a new turn begins for `player`.
+    pub fn ends_at_turn_start(&self, player: PlayerName) -> bool {
+        match self.duration {
+            Duration::ThisTurn => true,
+            Duration::UntilYourNextTurn => player == self.controller,
+            Duration::UntilYourNextMain | Duration::WhileInPlay => false,
+        }
+    }
+
+    /// Returns true if this effect ends when the main phase begins for
+    /// `player`.
+    pub fn ends_at_main_phase(&self, player: PlayerName) -> bool {
+        self.duration == Duration::UntilYourNextMain && player == self.controller
+    }
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use ai_data::game_ai::GameAI;
+use ai_data::personality::AiPersonality;
 use core_data::identifiers::UserId;
 use core_data::numerics::{Energy, Points, Spark};
 use quest_state::quest::quest_state::QuestState;
@@ -19,6 +20,10 @@ pub struct BattlePlayerState {
     /// Contains the player's UserId or AI game agent info.
     pub player_type: PlayerType,
 
+    /// Play style biasing this player's AI search, if any.
+    #[serde(default)]
+    pub personality: Option<AiPersonality>,
+
     /// Current score
     #[serde(default)]
     pub points: Points,
@@ -67,10 +72,16 @@ pub enum TestDeckName {
 pub struct CreateBattlePlayer {
     pub player_type: PlayerType,
     pub deck_name: TestDeckName,
+    #[serde(default)]
+    pub personality: Option<AiPersonality>,
 }
 
 impl BattlePlayerState {
     pub fn as_create_battle_player(&self) -> CreateBattlePlayer {
-        CreateBattlePlayer { player_type: self.player_type.clone(), deck_name: self.deck_name }
+        CreateBattlePlayer {
+            player_type: self.player_type.clone(),
+            deck_name: self.deck_name,
+            personality: self.personality,
+        }
     }
 }
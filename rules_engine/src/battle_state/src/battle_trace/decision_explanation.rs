@@ -0,0 +1,59 @@
+use core_data::types::PlayerName;
+
+use crate::actions::battle_actions::BattleAction;
+
+/// Explanation of why a tree-search agent selected an action, for display in
+/// developer tools.
+#[derive(Debug, Clone)]
+pub struct DecisionExplanation {
+    /// Player the search was run for
+    pub player: PlayerName,
+
+    /// Action the agent selected
+    pub action: BattleAction,
+
+    /// Expected line of play following the selected action, found by
+    /// repeatedly following the most-visited child in its search tree.
+    ///
+    /// Does not include the selected action itself.
+    pub principal_variation: Vec<BattleAction>,
+
+    /// Highest-scoring candidate actions, ordered by average reward.
+    pub alternatives: Vec<ScoredAction>,
+
+    /// Statistics about the search trees built for this decision
+    pub statistics: NodeStatistics,
+}
+
+/// A candidate action with its search results.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoredAction {
+    pub action: BattleAction,
+
+    /// Average reward of the candidate's search tree, from -1.0 to 1.0.
+    pub average_reward: f64,
+
+    /// Number of search iterations which evaluated this candidate.
+    pub visit_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeStatistics {
+    /// Number of legal actions which were searched
+    pub candidates: usize,
+
+    /// Total search iterations run across all candidates
+    pub total_iterations: u32,
+
+    /// Total number of nodes across all search trees
+    pub total_nodes: usize,
+
+    /// Number of nodes in the selected action's search tree
+    pub selected_nodes: usize,
+
+    /// Number of times search looked up a cached response to a prompt
+    pub prompt_cache_lookups: u32,
+
+    /// Number of prompt cache lookups which found a cached response
+    pub prompt_cache_hits: u32,
+}
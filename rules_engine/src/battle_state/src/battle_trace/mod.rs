@@ -1 +1,2 @@
 pub mod battle_tracing;
+pub mod decision_explanation;
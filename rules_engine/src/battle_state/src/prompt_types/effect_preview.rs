@@ -0,0 +1,37 @@
+use crate::battle_player::player_map::PlayerMap;
+
+/// Predicted consequences of taking an action, computed by simulating it under
+/// the current battle state.
+///
+/// Used to describe the options in a prompt, e.g. "this choice will draw 2
+/// cards".
+#[derive(Debug, Clone, Default)]
+pub struct EffectPreview {
+    pub players: PlayerMap<PlayerEffectPreview>,
+}
+
+/// Predicted changes to a single player's state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlayerEffectPreview {
+    /// Number of cards this player will draw.
+    pub cards_drawn: u32,
+
+    /// Number of this player's characters which will be dissolved.
+    pub characters_dissolved: u32,
+
+    /// Change in this player's score.
+    pub points_delta: i64,
+
+    /// Change in this player's current energy.
+    pub energy_delta: i64,
+
+    /// Change in this player's total spark on the battlefield.
+    pub spark_delta: i64,
+}
+
+impl PlayerEffectPreview {
+    /// Returns true if this preview predicts no changes.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
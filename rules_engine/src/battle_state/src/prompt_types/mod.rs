@@ -1 +1,2 @@
+pub mod effect_preview;
 pub mod prompt_data;
@@ -21,9 +21,10 @@ pub enum Trigger {
     PlayedCard(StackCardId),
     PlayedCardFromHand(StackCardId),
     PlayedCardFromVoid(StackCardId),
+    StartOfTurn(PlayerName),
 }
 
-#[derive(EnumSetType, Debug)]
+#[derive(EnumSetType, Debug, Deserialize)]
 pub enum TriggerName {
     Abandonded,
     Banished,
@@ -38,6 +39,7 @@ pub enum TriggerName {
     PlayedCard,
     PlayedCardFromHand,
     PlayedCardFromVoid,
+    StartOfTurn,
 }
 
 impl Trigger {
@@ -57,6 +59,7 @@ impl Trigger {
             Trigger::PlayedCard(..) => TriggerName::PlayedCard,
             Trigger::PlayedCardFromHand(..) => TriggerName::PlayedCardFromHand,
             Trigger::PlayedCardFromVoid(..) => TriggerName::PlayedCardFromVoid,
+            Trigger::StartOfTurn(..) => TriggerName::StartOfTurn,
         }
     }
 }
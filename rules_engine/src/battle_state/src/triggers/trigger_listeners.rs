@@ -5,7 +5,11 @@ use crate::battle_cards::card_set::CardSet;
 use crate::triggers::trigger::TriggerName;
 
 /// Tracks cards which are currently listening for a trigger.
+///
+/// Triggers added since a battle was saved have no listeners when it is
+/// loaded.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TriggerListeners {
     pub abandoned: CardSet<CardId>,
     pub banished: CardSet<CardId>,
@@ -20,6 +24,7 @@ pub struct TriggerListeners {
     pub played_card: CardSet<CardId>,
     pub played_card_from_hand: CardSet<CardId>,
     pub played_card_from_void: CardSet<CardId>,
+    pub start_of_turn: CardSet<CardId>,
 }
 
 impl TriggerListeners {
@@ -39,6 +44,7 @@ impl TriggerListeners {
             TriggerName::PlayedCard => &self.played_card,
             TriggerName::PlayedCardFromHand => &self.played_card_from_hand,
             TriggerName::PlayedCardFromVoid => &self.played_card_from_void,
+            TriggerName::StartOfTurn => &self.start_of_turn,
         }
     }
 
@@ -59,6 +65,7 @@ impl TriggerListeners {
             TriggerName::PlayedCard => &mut self.played_card,
             TriggerName::PlayedCardFromHand => &mut self.played_card_from_hand,
             TriggerName::PlayedCardFromVoid => &mut self.played_card_from_void,
+            TriggerName::StartOfTurn => &mut self.start_of_turn,
         }
     }
 
@@ -31,6 +31,24 @@ pub struct BattleId(pub Uuid);
 )]
 pub struct QuestId(pub Uuid);
 
+/// Identifies one of a user's save slots. Each slot holds an independent
+/// quest, and slot 0 is the slot used by default.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub struct SaveSlotId(pub u32);
+
 /// Identifies the base rules for a card.
 ///
 /// The 'base card' describes the abilities of a card before any modifications
@@ -40,6 +58,16 @@ pub struct QuestId(pub Uuid);
 )]
 pub struct BaseCardId(pub Uuid);
 
+/// Identifies an alternate presentation of a base card, such as promotional
+/// art or a foil treatment.
+///
+/// Variants share the rules of their base card and only change how it is
+/// displayed.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CardVariantId(pub Uuid);
+
 /// Identifies a dreamwell card.
 #[derive(
     Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use core_data::identifiers::{SaveSlotId, UserId};
+use core_data::initialization_error::InitializationError;
+use tracing::warn;
+
+use crate::save_file::SaveFile;
+use crate::save_file_encoding::SaveFileOptions;
+use crate::save_file_io;
+
+/// Controls how often the engine writes autosaves during a battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutosavePolicy {
+    /// Number of actions after which an autosave is written. Zero disables
+    /// action-based autosaves.
+    pub action_interval: u32,
+
+    /// Whether to write an autosave whenever the battle moves to a new turn
+    /// phase.
+    pub on_phase_change: bool,
+
+    /// Number of autosaves kept for each save slot. The oldest autosave is
+    /// replaced once this many exist. Zero disables autosaves.
+    pub rotation_size: u32,
+}
+
+/// Returns the path to one of the rotating autosaves for a user's save slot.
+///
+/// Autosave file names are never parsed as save slots, see
+/// [crate::save_slots::list_slots].
+pub fn autosave_path(dir: &Path, user_id: UserId, slot: SaveSlotId, index: u32) -> PathBuf {
+    let save_path = save_file_io::save_slot_path(dir, user_id, slot);
+    let stem = save_path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    dir.join(format!("{stem}-autosave_{index}.json"))
+}
+
+/// Writes an autosave, replacing the oldest autosave for this save slot once
+/// [AutosavePolicy::rotation_size] autosaves exist.
+///
+/// Returns the index of the autosave which was written.
+pub fn write_autosave(
+    dir: &Path,
+    save: &SaveFile,
+    slot: SaveSlotId,
+    policy: AutosavePolicy,
+    options: SaveFileOptions,
+) -> Result<u32, Vec<InitializationError>> {
+    let index = (0..policy.rotation_size)
+        .map(|index| (modified_time(&autosave_path(dir, save.id(), slot, index)), index))
+        .min()
+        .map_or(0, |(_, index)| index);
+    save_file_io::write_save_to_path(&autosave_path(dir, save.id(), slot, index), save, options)?;
+    Ok(index)
+}
+
+/// Reads the most recent autosave for a user's save slot.
+///
+/// Autosaves which cannot be read are skipped in favor of older ones. Returns
+/// the error from the newest autosave if none can be read.
+pub fn read_latest_autosave(
+    dir: &Path,
+    user_id: UserId,
+    slot: SaveSlotId,
+    policy: AutosavePolicy,
+) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+    let mut paths = (0..policy.rotation_size)
+        .map(|index| autosave_path(dir, user_id, slot, index))
+        .filter_map(|path| Some((modified_time(&path)?, path)))
+        .collect::<Vec<_>>();
+    paths.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut first_error = None;
+    for (_, path) in paths {
+        match save_file_io::read_save_from_path(&path) {
+            Ok(Some(save)) => return Ok(Some(save)),
+            Ok(None) => {}
+            Err(errors) => {
+                warn!(?path, "Skipping unreadable autosave");
+                first_error.get_or_insert(errors);
+            }
+        }
+    }
+    match first_error {
+        Some(errors) => Err(errors),
+        None => Ok(None),
+    }
+}
+
+impl Default for AutosavePolicy {
+    fn default() -> Self {
+        Self { action_interval: 10, on_phase_change: true, rotation_size: 3 }
+    }
+}
+
+impl AutosavePolicy {
+    /// Returns true if an autosave should be written after an action.
+    ///
+    /// `actions_since_autosave` includes the action which was just performed.
+    pub fn should_autosave(&self, actions_since_autosave: u32, phase_changed: bool) -> bool {
+        if self.rotation_size == 0 {
+            return false;
+        }
+        (self.on_phase_change && phase_changed)
+            || (self.action_interval > 0 && actions_since_autosave >= self.action_interval)
+    }
+}
+
+/// Returns the modification time of a file, or None if it does not exist.
+///
+/// Files whose modification time is unavailable are treated as the oldest.
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    let metadata = fs::metadata(path).ok()?;
+    Some(metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH))
+}
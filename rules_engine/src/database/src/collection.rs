@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use core_data::identifiers::{BaseCardId, CardVariantId, QuestId};
+use serde::{Deserialize, Serialize};
+
+/// Cards owned by a user, which decks may be constructed from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Collection {
+    pub cards: BTreeMap<BaseCardId, OwnedCard>,
+}
+
+/// Number of copies of a card a user owns, along with how they were acquired.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OwnedCard {
+    pub count: u32,
+
+    /// Each source which has granted copies of this card, in the order they
+    /// were granted.
+    pub unlock_sources: Vec<UnlockSource>,
+
+    /// Variant the player has chosen to display this card with, if any.
+    #[serde(default)]
+    pub selected_variant: Option<CardVariantId>,
+}
+
+/// Describes how copies of a card were added to a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnlockSource {
+    StartingCollection,
+    QuestReward(QuestId),
+    Debug,
+}
+
+/// Error returned when a deck contains cards which are not in a collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckValidationError {
+    pub missing: Vec<MissingCard>,
+}
+
+/// A card for which a deck contains more copies than are owned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingCard {
+    pub card_id: BaseCardId,
+    pub required: u32,
+    pub owned: u32,
+}
+
+/// Checks that the collection contains enough copies of each card in a deck.
+///
+/// Returns every card for which the deck requires more copies than are owned.
+pub fn validate_deck(
+    collection: &Collection,
+    deck: impl IntoIterator<Item = BaseCardId>,
+) -> Result<(), DeckValidationError> {
+    let mut required: BTreeMap<BaseCardId, u32> = BTreeMap::new();
+    for card_id in deck {
+        *required.entry(card_id).or_insert(0) += 1;
+    }
+
+    let missing = required
+        .into_iter()
+        .filter_map(|(card_id, required)| {
+            let owned = collection.count(card_id);
+            (required > owned).then_some(MissingCard { card_id, required, owned })
+        })
+        .collect::<Vec<_>>();
+    if missing.is_empty() { Ok(()) } else { Err(DeckValidationError { missing }) }
+}
+
+impl Collection {
+    /// Returns the number of copies of a card which are owned.
+    pub fn count(&self, card_id: BaseCardId) -> u32 {
+        self.cards.get(&card_id).map_or(0, |card| card.count)
+    }
+
+    /// Adds `count` copies of a card to the collection.
+    pub fn grant(&mut self, card_id: BaseCardId, count: u32, source: UnlockSource) {
+        if count == 0 {
+            return;
+        }
+        let card = self.cards.entry(card_id).or_default();
+        card.count += count;
+        if !card.unlock_sources.contains(&source) {
+            card.unlock_sources.push(source);
+        }
+    }
+
+    /// Adds one copy of each card rewarded by a quest to the collection.
+    pub fn grant_quest_reward(
+        &mut self,
+        quest_id: QuestId,
+        cards: impl IntoIterator<Item = BaseCardId>,
+    ) {
+        for card_id in cards {
+            self.grant(card_id, 1, UnlockSource::QuestReward(quest_id));
+        }
+    }
+
+    /// Selects the variant to display an owned card with, or its standard
+    /// presentation if `variant` is None.
+    ///
+    /// Does nothing if the card is not owned.
+    pub fn select_variant(&mut self, card_id: BaseCardId, variant: Option<CardVariantId>) {
+        if let Some(card) = self.cards.get_mut(&card_id) {
+            card.selected_variant = variant;
+        }
+    }
+
+    /// Returns the variant selected for each owned card which has one.
+    pub fn selected_variants(&self) -> BTreeMap<BaseCardId, CardVariantId> {
+        self.cards
+            .iter()
+            .filter_map(|(&card_id, card)| Some((card_id, card.selected_variant?)))
+            .collect()
+    }
+
+    /// Total number of cards owned, counting each copy.
+    pub fn total_cards(&self) -> u32 {
+        self.cards.values().map(|card| card.count).sum()
+    }
+}
@@ -1,3 +1,10 @@
+pub mod autosave;
+pub mod collection;
+pub mod match_history;
 pub mod quest_save_file;
 pub mod save_file;
+pub mod save_file_encoding;
 pub mod save_file_io;
+pub mod save_file_migration;
+pub mod save_slots;
+pub mod save_sync;
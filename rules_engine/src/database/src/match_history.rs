@@ -0,0 +1,75 @@
+use battle_state::battle_player::battle_player_state::{PlayerType, TestDeckName};
+use core_data::identifiers::BattleId;
+use serde::{Deserialize, Serialize};
+
+/// Battles a user has completed, most recent first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatchHistory {
+    pub entries: Vec<MatchRecord>,
+}
+
+/// Summary of a single completed battle, from the perspective of the user who
+/// owns the save file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    pub battle_id: BattleId,
+    pub result: MatchResult,
+    pub user_deck: TestDeckName,
+    pub opponent_deck: TestDeckName,
+    pub opponent: PlayerType,
+
+    /// Number of turns taken in the battle, counting both players.
+    pub turn_count: u32,
+
+    /// Time the battle ended, in seconds since the Unix epoch.
+    pub completed_at: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchResult {
+    Victory,
+    Defeat,
+    Draw,
+}
+
+/// Limits on how much match history is retained in a save file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchHistoryPruning {
+    /// Maximum number of records to keep. Older records are discarded first.
+    pub max_entries: usize,
+
+    /// Records completed more than this many seconds before the newest record
+    /// are discarded, if set.
+    pub max_age_seconds: Option<u64>,
+}
+
+impl Default for MatchHistoryPruning {
+    fn default() -> Self {
+        Self { max_entries: 50, max_age_seconds: None }
+    }
+}
+
+impl MatchHistory {
+    /// Adds a record for a completed battle, then prunes old records.
+    ///
+    /// Does nothing if a record for this battle already exists.
+    pub fn record(&mut self, record: MatchRecord, pruning: MatchHistoryPruning) {
+        if self.entries.iter().any(|entry| entry.battle_id == record.battle_id) {
+            return;
+        }
+        self.entries.insert(0, record);
+        self.prune(pruning);
+    }
+
+    /// Discards records which fall outside the given [MatchHistoryPruning]
+    /// limits.
+    pub fn prune(&mut self, pruning: MatchHistoryPruning) {
+        if let Some(max_age) = pruning.max_age_seconds
+            && let Some(newest) = self.entries.iter().map(|entry| entry.completed_at).max()
+        {
+            let cutoff = newest.saturating_sub(max_age);
+            self.entries.retain(|entry| entry.completed_at >= cutoff);
+        }
+        self.entries.truncate(pruning.max_entries);
+    }
+}
@@ -1,6 +1,8 @@
 use core_data::identifiers::UserId;
 use serde::{Deserialize, Serialize};
 
+use crate::collection::Collection;
+use crate::match_history::MatchHistory;
 use crate::quest_save_file::QuestSaveFile;
 
 /// Represents the entirety of a user's game state.
@@ -16,6 +18,14 @@ pub enum SaveFile {
 pub struct SaveFileV1 {
     pub id: UserId,
     pub quest: Option<QuestSaveFile>,
+
+    /// Cards owned by this user.
+    #[serde(default)]
+    pub collection: Collection,
+
+    /// Battles this user has completed, most recent first.
+    #[serde(default)]
+    pub match_history: MatchHistory,
 }
 
 impl SaveFile {
@@ -24,4 +34,16 @@ impl SaveFile {
             SaveFile::V1(v1) => v1.id,
         }
     }
+
+    pub fn collection(&self) -> &Collection {
+        match self {
+            SaveFile::V1(v1) => &v1.collection,
+        }
+    }
+
+    pub fn match_history(&self) -> &MatchHistory {
+        match self {
+            SaveFile::V1(v1) => &v1.match_history,
+        }
+    }
 }
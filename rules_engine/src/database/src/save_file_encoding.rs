@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::Write;
+
+/// Identifies a compressed save file written with a binary [SaveFileHeader].
+///
+/// Save files written before headers were added contain only JSON, and so
+/// can never begin with these bytes.
+pub const MAGIC: &[u8; 4] = b"DTSV";
+
+/// Start of an uncompressed save file written inside a JSON envelope.
+///
+/// Envelopes have the exact layout
+/// `{"dtsv":1,"length":123,"crc32":"0a1b2c3d","save":<JSON>}`, so the file
+/// stays valid JSON while the checksum covers the save's exact bytes. Legacy
+/// save files are a serialized `SaveFile`, which never has a `dtsv` key.
+pub const ENVELOPE_PREFIX: &str = "{\"dtsv\":";
+
+/// Version of the header layout written by this version of the game.
+pub const HEADER_VERSION: u8 = 1;
+
+/// Length in bytes of an encoded binary [SaveFileHeader].
+pub const HEADER_LENGTH: usize = 18;
+
+/// How the JSON contents of a save file are stored on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFileCompression {
+    None,
+    Zstd,
+}
+
+/// Options controlling how save files are written.
+///
+/// Save files are uncompressed by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveFileOptions {
+    pub compression: SaveFileCompression,
+}
+
+/// Metadata written before the contents of a save file.
+///
+/// Uncompressed saves store this in a JSON envelope, see [ENVELOPE_PREFIX].
+/// Compressed saves use a binary layout: magic (4 bytes), header version (1
+/// byte), compression (1 byte), payload length (u64 LE), CRC-32 of the payload
+/// (u32 LE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveFileHeader {
+    pub compression: SaveFileCompression,
+
+    /// Length of the stored payload in bytes, after compression.
+    pub payload_length: u64,
+
+    /// CRC-32 checksum of the stored payload, after compression.
+    pub checksum: u32,
+}
+
+/// Reasons a save file could not be encoded or decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveFileEncodingError {
+    /// The file ends before the header or payload is complete.
+    Truncated { expected: u64, actual: u64 },
+
+    /// The file contains data after the end of its payload.
+    TrailingData { expected: u64, actual: u64 },
+
+    /// The file was written with a newer header layout.
+    UnsupportedHeaderVersion(u8),
+
+    /// The header names an unknown compression format.
+    UnknownCompression(u8),
+
+    /// The JSON envelope of an uncompressed save does not have the expected
+    /// layout.
+    MalformedEnvelope,
+
+    /// The payload does not match its checksum.
+    ChecksumMismatch { expected: u32, actual: u32 },
+
+    /// The save file is compressed but compression support is disabled.
+    CompressionUnavailable,
+
+    /// The payload could not be compressed or decompressed.
+    Compression(String),
+}
+
+/// Wraps serialized save file JSON in a [SaveFileHeader], compressing it if
+/// requested.
+///
+/// Uncompressed saves are written inside a JSON envelope, so they remain valid
+/// JSON.
+pub fn encode(json: &[u8], options: SaveFileOptions) -> Result<Vec<u8>, SaveFileEncodingError> {
+    match options.compression {
+        SaveFileCompression::None => Ok(encode_envelope(json)),
+        SaveFileCompression::Zstd => Ok(encode_binary(&compress(json)?)),
+    }
+}
+
+/// Returns the JSON contents of a save file, verifying its checksum and
+/// decompressing it if needed.
+///
+/// Save files without a header are returned unchanged.
+pub fn decode(data: &[u8]) -> Result<Cow<'_, [u8]>, SaveFileEncodingError> {
+    let Some(layout) = read_layout(data)? else {
+        return Ok(Cow::Borrowed(data));
+    };
+    let expected =
+        (layout.payload_start + layout.suffix.len()) as u64 + layout.header.payload_length;
+    let actual = data.len() as u64;
+    if actual < expected {
+        return Err(SaveFileEncodingError::Truncated { expected, actual });
+    } else if actual > expected {
+        return Err(SaveFileEncodingError::TrailingData { expected, actual });
+    }
+
+    let payload_end = data.len() - layout.suffix.len();
+    if &data[payload_end..] != layout.suffix {
+        return Err(SaveFileEncodingError::MalformedEnvelope);
+    }
+    let payload = &data[layout.payload_start..payload_end];
+    let checksum = crc32fast::hash(payload);
+    if checksum != layout.header.checksum {
+        return Err(SaveFileEncodingError::ChecksumMismatch {
+            expected: layout.header.checksum,
+            actual: checksum,
+        });
+    }
+
+    match layout.header.compression {
+        SaveFileCompression::None => Ok(Cow::Borrowed(payload)),
+        SaveFileCompression::Zstd => Ok(Cow::Owned(decompress(payload)?)),
+    }
+}
+
+/// Reads the header of a save file, or returns None if the file was written
+/// without one.
+pub fn read_header(data: &[u8]) -> Result<Option<SaveFileHeader>, SaveFileEncodingError> {
+    Ok(read_layout(data)?.map(|layout| layout.header))
+}
+
+impl Default for SaveFileOptions {
+    /// Writes uncompressed save files, which are valid JSON.
+    ///
+    /// Compressed save files are binary despite their `.json` extension, so
+    /// compression must be requested explicitly, and only readers built with
+    /// the `compression` feature can load the result.
+    fn default() -> Self {
+        Self { compression: SaveFileCompression::None }
+    }
+}
+
+impl fmt::Display for SaveFileEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveFileEncodingError::Truncated { expected, actual } => {
+                write!(f, "save file is truncated: expected {expected} bytes, found {actual}")
+            }
+            SaveFileEncodingError::TrailingData { expected, actual } => {
+                write!(f, "save file has trailing data: expected {expected} bytes, found {actual}")
+            }
+            SaveFileEncodingError::UnsupportedHeaderVersion(version) => {
+                write!(f, "save file header version {version} is newer than {HEADER_VERSION}")
+            }
+            SaveFileEncodingError::UnknownCompression(value) => {
+                write!(f, "unknown save file compression {value}")
+            }
+            SaveFileEncodingError::MalformedEnvelope => {
+                write!(f, "save file JSON envelope is malformed")
+            }
+            SaveFileEncodingError::ChecksumMismatch { expected, actual } => {
+                write!(f, "checksum mismatch: expected {expected:08x}, found {actual:08x}")
+            }
+            SaveFileEncodingError::CompressionUnavailable => {
+                write!(f, "save file is compressed but compression support is disabled")
+            }
+            SaveFileEncodingError::Compression(message) => {
+                write!(f, "save file compression error: {message}")
+            }
+        }
+    }
+}
+
+/// Location of the payload within a save file written with a header.
+struct Layout {
+    header: SaveFileHeader,
+
+    /// Offset of the first byte of the payload.
+    payload_start: usize,
+
+    /// Bytes which must follow the payload.
+    suffix: &'static [u8],
+}
+
+fn encode_envelope(json: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(json.len() + 64);
+    write!(
+        result,
+        "{ENVELOPE_PREFIX}{HEADER_VERSION},\"length\":{},\"crc32\":\"{:08x}\",\"save\":",
+        json.len(),
+        crc32fast::hash(json)
+    )
+    .expect("writing to a Vec cannot fail");
+    result.extend_from_slice(json);
+    result.push(b'}');
+    result
+}
+
+fn encode_binary(payload: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(HEADER_LENGTH + payload.len());
+    result.extend_from_slice(MAGIC);
+    result.push(HEADER_VERSION);
+    result.push(compression_byte(SaveFileCompression::Zstd));
+    result.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    result.extend_from_slice(&crc32fast::hash(payload).to_le_bytes());
+    result.extend_from_slice(payload);
+    result
+}
+
+fn read_layout(data: &[u8]) -> Result<Option<Layout>, SaveFileEncodingError> {
+    if data.starts_with(MAGIC) {
+        read_binary_header(data).map(Some)
+    } else if data.starts_with(ENVELOPE_PREFIX.as_bytes()) {
+        read_envelope(data).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn read_binary_header(data: &[u8]) -> Result<Layout, SaveFileEncodingError> {
+    if data.len() < HEADER_LENGTH {
+        return Err(SaveFileEncodingError::Truncated {
+            expected: HEADER_LENGTH as u64,
+            actual: data.len() as u64,
+        });
+    }
+    if data[4] != HEADER_VERSION {
+        return Err(SaveFileEncodingError::UnsupportedHeaderVersion(data[4]));
+    }
+    let compression = match data[5] {
+        0 => SaveFileCompression::None,
+        1 => SaveFileCompression::Zstd,
+        other => return Err(SaveFileEncodingError::UnknownCompression(other)),
+    };
+    let payload_length = u64::from_le_bytes(data[6..14].try_into().expect("8 bytes"));
+    let checksum = u32::from_le_bytes(data[14..18].try_into().expect("4 bytes"));
+    Ok(Layout {
+        header: SaveFileHeader { compression, payload_length, checksum },
+        payload_start: HEADER_LENGTH,
+        suffix: b"",
+    })
+}
+
+fn read_envelope(data: &[u8]) -> Result<Layout, SaveFileEncodingError> {
+    let mut rest = &data[ENVELOPE_PREFIX.len()..];
+    let version = envelope_field(&mut rest, b",\"length\":", |s| s.parse::<u8>().ok())?;
+    if version != HEADER_VERSION {
+        return Err(SaveFileEncodingError::UnsupportedHeaderVersion(version));
+    }
+    let payload_length = envelope_field(&mut rest, b",\"crc32\":\"", |s| s.parse::<u64>().ok())?;
+    let checksum = envelope_field(&mut rest, b"\",\"save\":", |s| {
+        if s.len() == 8 { u32::from_str_radix(s, 16).ok() } else { None }
+    })?;
+    Ok(Layout {
+        header: SaveFileHeader { compression: SaveFileCompression::None, payload_length, checksum },
+        payload_start: data.len() - rest.len(),
+        suffix: b"}",
+    })
+}
+
+/// Parses the envelope field value at the start of `rest`, which ends at
+/// `delimiter`, and advances `rest` past the delimiter.
+fn envelope_field<T>(
+    rest: &mut &[u8],
+    delimiter: &[u8],
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Result<T, SaveFileEncodingError> {
+    let end = rest
+        .windows(delimiter.len())
+        .position(|window| window == delimiter)
+        .ok_or(SaveFileEncodingError::MalformedEnvelope)?;
+    let value = std::str::from_utf8(&rest[..end])
+        .ok()
+        .and_then(parse)
+        .ok_or(SaveFileEncodingError::MalformedEnvelope)?;
+    *rest = &rest[end + delimiter.len()..];
+    Ok(value)
+}
+
+fn compression_byte(compression: SaveFileCompression) -> u8 {
+    match compression {
+        SaveFileCompression::None => 0,
+        SaveFileCompression::Zstd => 1,
+    }
+}
+
+#[cfg(feature = "compression")]
+fn compress(data: &[u8]) -> Result<Vec<u8>, SaveFileEncodingError> {
+    zstd::encode_all(data, 0).map_err(|e| SaveFileEncodingError::Compression(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+fn compress(_data: &[u8]) -> Result<Vec<u8>, SaveFileEncodingError> {
+    Err(SaveFileEncodingError::CompressionUnavailable)
+}
+
+#[cfg(feature = "compression")]
+fn decompress(data: &[u8]) -> Result<Vec<u8>, SaveFileEncodingError> {
+    zstd::decode_all(data).map_err(|e| SaveFileEncodingError::Compression(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress(_data: &[u8]) -> Result<Vec<u8>, SaveFileEncodingError> {
+    Err(SaveFileEncodingError::CompressionUnavailable)
+}
@@ -1,9 +1,14 @@
+use std::borrow::Cow;
+use std::env;
 use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::io::Read;
+#[cfg(feature = "native")]
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "native")]
 use atomic_write_file::AtomicWriteFile;
-use core_data::identifiers::UserId;
+use core_data::identifiers::{SaveSlotId, UserId};
 use core_data::initialization_error::{ErrorCode, InitializationError};
 use serde_json;
 use serde_json::error::Category;
@@ -11,51 +16,190 @@ use serde_path_to_error;
 use tracing::debug;
 
 use crate::save_file::SaveFile;
+use crate::save_file_encoding::{self, SaveFileEncodingError, SaveFileOptions};
+use crate::save_file_migration::{self, CURRENT_VERSION};
 
-/// Returns the path to the save file for the given user.
+/// Environment variable which overrides the default save directory.
+pub const SAVE_DIR_ENV_VAR: &str = "DREAMTIDES_SAVE_DIR";
+
+/// Returns the directory the game client stores save files in on this
+/// platform, or None if it cannot be determined.
+///
+/// The `DREAMTIDES_SAVE_DIR` environment variable takes precedence if set.
+/// Otherwise this is `Dreamtides/Dreamtides` inside the platform data
+/// directory:
+///
+/// - macOS: `~/Library/Application Support`
+/// - Windows: `%APPDATA%`
+/// - Linux: `$XDG_DATA_HOME`, or `~/.local/share` if unset
+pub fn default_save_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(SAVE_DIR_ENV_VAR).filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    Some(dirs::data_dir()?.join("Dreamtides").join("Dreamtides"))
+}
+
+/// Returns the path to the save file for the given user's default save slot.
 pub fn save_path(dir: &Path, user_id: UserId) -> PathBuf {
-    dir.join(format!("save-{}.json", user_id.0))
+    save_slot_path(dir, user_id, SaveSlotId::default())
+}
+
+/// Returns the path to the save file for the given user and save slot.
+///
+/// The default slot uses the same path as saves written before save slots were
+/// added.
+pub fn save_slot_path(dir: &Path, user_id: UserId, slot: SaveSlotId) -> PathBuf {
+    if slot == SaveSlotId::default() {
+        dir.join(format!("save-{}.json", user_id.0))
+    } else {
+        dir.join(format!("save-{}-slot{}.json", user_id.0, slot.0))
+    }
 }
 
-/// Reads a save file from the given directory.
+/// Reads a save file for the given user's default save slot from the given
+/// directory.
 pub fn read_save_from_dir(
     dir: &Path,
     user_id: UserId,
 ) -> Result<Option<SaveFile>, Vec<InitializationError>> {
-    let file_path = save_path(dir, user_id);
+    read_save_from_slot(dir, user_id, SaveSlotId::default())
+}
+
+/// Reads the save file in a save slot from the given directory.
+pub fn read_save_from_slot(
+    dir: &Path,
+    user_id: UserId,
+    slot: SaveSlotId,
+) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+    read_save_from_path(&save_slot_path(dir, user_id, slot))
+}
+
+/// Reads the save file at the given path, if it exists.
+pub fn read_save_from_path(file_path: &Path) -> Result<Option<SaveFile>, Vec<InitializationError>> {
     if !file_path.exists() {
         return Ok(None);
     }
-    read_and_parse_save(&file_path)
+    read_and_parse_save(file_path)
 }
 
-/// Writes a save file to the given directory.
+/// Writes a save file to its user's default save slot in the given directory
+/// using the default [SaveFileOptions].
 pub fn write_save_to_dir(dir: &Path, save: &SaveFile) -> Result<(), Vec<InitializationError>> {
-    fs::create_dir_all(dir).map_err(|e| {
+    write_save_to_slot(dir, save, SaveSlotId::default(), SaveFileOptions::default())
+}
+
+/// Writes a save file to its user's default save slot in the given directory,
+/// compressing it if requested by `options`.
+pub fn write_save_to_dir_with_options(
+    dir: &Path,
+    save: &SaveFile,
+    options: SaveFileOptions,
+) -> Result<(), Vec<InitializationError>> {
+    write_save_to_slot(dir, save, SaveSlotId::default(), options)
+}
+
+/// Writes a save file to a save slot for its user in the given directory.
+pub fn write_save_to_slot(
+    dir: &Path,
+    save: &SaveFile,
+    slot: SaveSlotId,
+    options: SaveFileOptions,
+) -> Result<(), Vec<InitializationError>> {
+    write_save_to_path(&save_slot_path(dir, save.id(), slot), save, options)
+}
+
+/// Writes a save file to the given path, creating its parent directory if
+/// needed.
+///
+/// On native targets the file is replaced atomically, so a crash during the
+/// write leaves the previous contents intact.
+pub fn write_save_to_path(
+    file_path: &Path,
+    save: &SaveFile,
+    options: SaveFileOptions,
+) -> Result<(), Vec<InitializationError>> {
+    if let Some(dir) = file_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| {
+            vec![InitializationError::with_details(
+                ErrorCode::IOError,
+                "Failed to create save directory",
+                e.to_string(),
+            )]
+        })?;
+    }
+    debug!(?file_path, "Writing save file");
+    let buf = serialize_save(save).map_err(|e| vec![*e])?;
+    validate_serialized_json(&buf).map_err(|e| vec![*e])?;
+    let encoded = save_file_encoding::encode(&buf, options).map_err(|e| {
         vec![InitializationError::with_details(
-            ErrorCode::IOError,
-            "Failed to create save directory",
+            ErrorCode::DatabaseError,
+            "Failed to encode save file",
             e.to_string(),
         )]
     })?;
-    let file_path = save_path(dir, save.id());
-    debug!(?file_path, "Writing save file");
-    let buf = serialize_save(save).map_err(|e| vec![*e])?;
-    validate_serialized_json(&buf).map_err(|e| vec![*e])?;
-    atomic_write(&file_path, &buf).map_err(|e| vec![*e])?;
+    atomic_write(file_path, &encoded).map_err(|e| vec![*e])?;
     Ok(())
 }
 
+/// Parses the contents of a save file, first migrating it to the current
+/// version if it was written by an older version of the game.
+///
+/// Accepts save files with a checksum header, compressed or not, and legacy
+/// plain JSON save files.
+pub fn parse_save(data: &[u8]) -> Result<SaveFile, Vec<InitializationError>> {
+    let json = decode_save(data).map_err(|e| vec![*e])?;
+    parse_with_details(&json, None).map_err(|e| vec![*e])
+}
+
+fn decode_save(data: &[u8]) -> Result<Cow<'_, [u8]>, Box<InitializationError>> {
+    save_file_encoding::decode(data).map_err(|e| {
+        let name = match e {
+            SaveFileEncodingError::CompressionUnavailable
+            | SaveFileEncodingError::UnsupportedHeaderVersion(_) => "Unsupported save file format",
+            _ => "Save file is corrupted",
+        };
+        Box::new(InitializationError::with_details(ErrorCode::DatabaseError, name, e.to_string()))
+    })
+}
+
 fn parse_with_details(
     data: &[u8],
     length_hint: Option<usize>,
 ) -> Result<SaveFile, Box<InitializationError>> {
-    match serde_json::from_slice::<SaveFile>(data) {
-        Ok(save) => Ok(save),
-        Err(e) => Err(Box::new(build_parse_error(data, &e, length_hint))),
+    match save_file_migration::read_version(data) {
+        Ok(Some(version)) if version != CURRENT_VERSION => {
+            debug!(?version, "Migrating save file");
+            parse_migrated(data, length_hint)
+        }
+        _ => match serde_json::from_slice::<SaveFile>(data) {
+            Ok(save) => Ok(save),
+            Err(e) => Err(Box::new(build_parse_error(data, &e, length_hint))),
+        },
     }
 }
 
+fn parse_migrated(
+    data: &[u8],
+    length_hint: Option<usize>,
+) -> Result<SaveFile, Box<InitializationError>> {
+    let value = serde_json::from_slice::<serde_json::Value>(data)
+        .map_err(|e| Box::new(build_parse_error(data, &e, length_hint)))?;
+    let migrated = save_file_migration::migrate(value).map_err(|e| {
+        Box::new(InitializationError::with_details(
+            ErrorCode::JsonError,
+            "Failed to migrate save file",
+            e.to_string(),
+        ))
+    })?;
+    serde_json::from_value::<SaveFile>(migrated).map_err(|e| {
+        Box::new(InitializationError::with_details(
+            ErrorCode::JsonError,
+            "Failed to parse migrated save file",
+            e.to_string(),
+        ))
+    })
+}
+
 fn build_parse_error(
     data: &[u8],
     error: &serde_json::Error,
@@ -129,7 +273,8 @@ fn read_and_parse_save(file_path: &Path) -> Result<Option<SaveFile>, Vec<Initial
             e.to_string(),
         )]
     })?;
-    match parse_with_details(&data, Some(data.len())) {
+    let json = decode_save(&data).map_err(|e| vec![*e])?;
+    match parse_with_details(&json, Some(json.len())) {
         Ok(save) => Ok(Some(save)),
         Err(err) => Err(vec![*err]),
     }
@@ -150,6 +295,8 @@ fn validate_serialized_json(data: &[u8]) -> Result<(), Box<InitializationError>>
     Ok(())
 }
 
+/// Writes save data by atomically replacing the file at `final_path`.
+#[cfg(feature = "native")]
 fn atomic_write(final_path: &Path, data: &[u8]) -> Result<(), Box<InitializationError>> {
     let mut f = AtomicWriteFile::options().open(final_path).map_err(|e| {
         Box::new(InitializationError::with_details(
@@ -175,6 +322,18 @@ fn atomic_write(final_path: &Path, data: &[u8]) -> Result<(), Box<Initialization
     Ok(())
 }
 
+/// Writes save data directly, for targets without atomic file replacement.
+#[cfg(not(feature = "native"))]
+fn atomic_write(final_path: &Path, data: &[u8]) -> Result<(), Box<InitializationError>> {
+    fs::write(final_path, data).map_err(|e| {
+        Box::new(InitializationError::with_details(
+            ErrorCode::IOError,
+            "Failed to write save file",
+            e.to_string(),
+        ))
+    })
+}
+
 fn serialize_save(save: &SaveFile) -> Result<Vec<u8>, Box<InitializationError>> {
     let mut buf = Vec::new();
     let mut json = serde_json::Serializer::pretty(&mut buf);
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::IgnoredAny;
+use serde_json::{Map, Value};
+
+/// Version of [crate::save_file::SaveFile] written by this version of the
+/// game, i.e. the number of its newest variant.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Migrations applied when loading older save files, in version order.
+pub const MIGRATIONS: &[SaveFileMigration] = &[];
+
+/// Upgrades the serialized contents of a save file from one version to the
+/// next.
+///
+/// Changes which serde can read from older saves, e.g. a new field marked
+/// `#[serde(default)]` or a renamed variant with a `#[serde(alias)]`, do not
+/// need a migration. When a change to the save file format would otherwise
+/// stop older saves from deserializing, add a new `SaveFile` variant,
+/// increment `CURRENT_VERSION`, and add a migration from the previous version
+/// to `MIGRATIONS`. Also add fixture saves for the previous version, with and
+/// without a battle in progress, to the database tests.
+#[derive(Clone, Copy)]
+pub struct SaveFileMigration {
+    /// Version this migration upgrades from. The result has version
+    /// `from_version + 1`.
+    pub from_version: u32,
+
+    /// Transforms the contents of a save file, without its version tag.
+    pub migrate: fn(Value) -> Result<Value, String>,
+}
+
+/// Reasons a serialized save file could not be migrated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveFileMigrationError {
+    /// The save file is not tagged with a version.
+    MissingVersion,
+
+    /// The save file was written by a newer version of the game.
+    UnsupportedVersion { version: u32 },
+
+    /// No migration exists from this version.
+    MissingMigration { from_version: u32 },
+
+    /// A migration returned an error.
+    MigrationFailed { from_version: u32, message: String },
+}
+
+/// Returns the version of a serialized save file without deserializing its
+/// contents, or None if it is not tagged with a version.
+pub fn read_version(data: &[u8]) -> Result<Option<u32>, serde_json::Error> {
+    let tags = serde_json::from_slice::<BTreeMap<String, IgnoredAny>>(data)?;
+    let mut keys = tags.keys();
+    Ok(match (keys.next(), keys.next()) {
+        (Some(tag), None) => parse_version_tag(tag),
+        _ => None,
+    })
+}
+
+/// Migrates a serialized save file to `CURRENT_VERSION`.
+pub fn migrate(save: Value) -> Result<Value, SaveFileMigrationError> {
+    migrate_to(save, MIGRATIONS, CURRENT_VERSION)
+}
+
+/// Migrates a serialized save file to `target_version`, applying each
+/// required migration from `migrations` in turn.
+pub fn migrate_to(
+    save: Value,
+    migrations: &[SaveFileMigration],
+    target_version: u32,
+) -> Result<Value, SaveFileMigrationError> {
+    let Value::Object(tagged) = save else {
+        return Err(SaveFileMigrationError::MissingVersion);
+    };
+    let mut entries = tagged.into_iter();
+    let (Some((tag, mut contents)), None) = (entries.next(), entries.next()) else {
+        return Err(SaveFileMigrationError::MissingVersion);
+    };
+    let mut version = parse_version_tag(&tag).ok_or(SaveFileMigrationError::MissingVersion)?;
+    if version > target_version {
+        return Err(SaveFileMigrationError::UnsupportedVersion { version });
+    }
+
+    while version < target_version {
+        let migration = migrations
+            .iter()
+            .find(|migration| migration.from_version == version)
+            .ok_or(SaveFileMigrationError::MissingMigration { from_version: version })?;
+        contents = (migration.migrate)(contents).map_err(|message| {
+            SaveFileMigrationError::MigrationFailed { from_version: version, message }
+        })?;
+        version += 1;
+    }
+
+    let mut result = Map::new();
+    result.insert(version_tag(version), contents);
+    Ok(Value::Object(result))
+}
+
+/// Returns the tag identifying the given save file version, e.g. "V1".
+pub fn version_tag(version: u32) -> String {
+    format!("V{version}")
+}
+
+impl fmt::Display for SaveFileMigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveFileMigrationError::MissingVersion => write!(f, "save file has no version tag"),
+            SaveFileMigrationError::UnsupportedVersion { version } => {
+                write!(f, "save file version {version} is newer than {CURRENT_VERSION}")
+            }
+            SaveFileMigrationError::MissingMigration { from_version } => {
+                write!(f, "no migration from save file version {from_version}")
+            }
+            SaveFileMigrationError::MigrationFailed { from_version, message } => {
+                write!(f, "migration from save file version {from_version} failed: {message}")
+            }
+        }
+    }
+}
+
+fn parse_version_tag(tag: &str) -> Option<u32> {
+    tag.strip_prefix('V')?.parse().ok()
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use core_data::identifiers::{SaveSlotId, UserId};
+use core_data::initialization_error::{ErrorCode, InitializationError};
+use uuid::Uuid;
+
+use crate::collection::Collection;
+use crate::match_history::MatchHistory;
+use crate::save_file::{SaveFile, SaveFileV1};
+use crate::save_file_encoding::SaveFileOptions;
+use crate::save_file_io;
+
+/// Creates a new player profile with an empty save in its default slot.
+///
+/// Each profile is a separate user, see [SaveFile].
+pub fn create_profile(dir: &Path) -> Result<UserId, Vec<InitializationError>> {
+    let user_id = UserId(Uuid::new_v4());
+    write_empty_save(dir, user_id, SaveSlotId::default())?;
+    Ok(user_id)
+}
+
+/// Returns every profile with at least one save slot in the given directory.
+pub fn list_profiles(dir: &Path) -> Result<Vec<UserId>, Vec<InitializationError>> {
+    let profiles = save_file_names(dir)?
+        .into_iter()
+        .filter_map(|name| parse_save_file_name(&name))
+        .map(|(user_id, _)| user_id)
+        .collect::<BTreeSet<_>>();
+    Ok(profiles.into_iter().collect())
+}
+
+/// Deletes every save slot for a profile.
+pub fn delete_profile(dir: &Path, user_id: UserId) -> Result<(), Vec<InitializationError>> {
+    for slot in list_slots(dir, user_id)? {
+        delete_slot(dir, user_id, slot)?;
+    }
+    Ok(())
+}
+
+/// Returns the save slots in use for a profile, in order.
+pub fn list_slots(
+    dir: &Path,
+    user_id: UserId,
+) -> Result<Vec<SaveSlotId>, Vec<InitializationError>> {
+    let slots = save_file_names(dir)?
+        .into_iter()
+        .filter_map(|name| parse_save_file_name(&name))
+        .filter(|&(id, _)| id == user_id)
+        .map(|(_, slot)| slot)
+        .collect::<BTreeSet<_>>();
+    Ok(slots.into_iter().collect())
+}
+
+/// Creates an empty save in the lowest unused save slot for a profile and
+/// returns that slot.
+pub fn create_slot(dir: &Path, user_id: UserId) -> Result<SaveSlotId, Vec<InitializationError>> {
+    let mut next = 0;
+    for slot in list_slots(dir, user_id)? {
+        if slot.0 == next {
+            next += 1;
+        }
+    }
+    let slot = SaveSlotId(next);
+    write_empty_save(dir, user_id, slot)?;
+    Ok(slot)
+}
+
+/// Deletes the save in a save slot. Does nothing if the slot is unused.
+pub fn delete_slot(
+    dir: &Path,
+    user_id: UserId,
+    slot: SaveSlotId,
+) -> Result<(), Vec<InitializationError>> {
+    let path = save_file_io::save_slot_path(dir, user_id, slot);
+    if !path.exists() {
+        return Ok(());
+    }
+    fs::remove_file(&path).map_err(|e| {
+        vec![InitializationError::with_details(
+            ErrorCode::IOError,
+            "Failed to delete save slot",
+            e.to_string(),
+        )]
+    })
+}
+
+/// Copies the save in one of a profile's save slots to another slot.
+///
+/// Fails if the source slot is unused or the destination slot is in use.
+pub fn copy_slot(
+    dir: &Path,
+    user_id: UserId,
+    from: SaveSlotId,
+    to: SaveSlotId,
+) -> Result<(), Vec<InitializationError>> {
+    let destination = save_file_io::save_slot_path(dir, user_id, to);
+    if destination.exists() {
+        return Err(vec![InitializationError::with_details(
+            ErrorCode::DatabaseError,
+            "Save slot is already in use",
+            format!("slot {} for user {}", to.0, user_id.0),
+        )]);
+    }
+    let Some(save) = save_file_io::read_save_from_slot(dir, user_id, from)? else {
+        return Err(vec![InitializationError::with_details(
+            ErrorCode::DatabaseError,
+            "Save slot is empty",
+            format!("slot {} for user {}", from.0, user_id.0),
+        )]);
+    };
+    save_file_io::write_save_to_slot(dir, &save, to, SaveFileOptions::default())
+}
+
+fn write_empty_save(
+    dir: &Path,
+    user_id: UserId,
+    slot: SaveSlotId,
+) -> Result<(), Vec<InitializationError>> {
+    let save = SaveFile::V1(Box::new(SaveFileV1 {
+        id: user_id,
+        quest: None,
+        collection: Collection::default(),
+        match_history: MatchHistory::default(),
+    }));
+    save_file_io::write_save_to_slot(dir, &save, slot, SaveFileOptions::default())
+}
+
+fn save_file_names(dir: &Path) -> Result<Vec<String>, Vec<InitializationError>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) if !dir.exists() => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(vec![InitializationError::with_details(
+                ErrorCode::IOError,
+                "Failed to read save directory",
+                e.to_string(),
+            )]);
+        }
+    };
+    Ok(entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect())
+}
+
+/// Parses a file name produced by [save_file_io::save_slot_path].
+fn parse_save_file_name(name: &str) -> Option<(UserId, SaveSlotId)> {
+    let stem = name.strip_prefix("save-")?.strip_suffix(".json")?;
+    let (id, slot) = match stem.split_once("-slot") {
+        Some((id, slot)) => (id, SaveSlotId(slot.parse().ok()?)),
+        None => (stem, SaveSlotId::default()),
+    };
+    Some((UserId(Uuid::parse_str(id).ok()?), slot))
+}
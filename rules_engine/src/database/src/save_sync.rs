@@ -0,0 +1,359 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use core_data::identifiers::UserId;
+use serde::{Deserialize, Serialize};
+
+/// Stores save files remotely so they can be shared between devices.
+///
+/// Platform layers (Steam Cloud, iCloud, etc.) implement this trait. The
+/// engine only interacts with providers via [sync] and [apply_resolution].
+pub trait SaveSync: Send + Sync {
+    /// Uploads a save file.
+    ///
+    /// Must fail with [SaveSyncError::Conflict] unless the pushed version is
+    /// equal to or after the stored version, see [check_push].
+    fn push(&self, save: &SyncedSave) -> Result<(), SaveSyncError>;
+
+    /// Downloads the save file for a user, if one is stored.
+    fn pull(&self, user_id: UserId) -> Result<Option<SyncedSave>, SaveSyncError>;
+
+    /// Returns metadata for every stored save file.
+    fn list(&self) -> Result<Vec<SaveSyncMetadata>, SaveSyncError>;
+}
+
+/// Policy for resolving conflicts detected by [sync].
+pub trait ConflictPolicy: Send + Sync {
+    fn resolve(&self, conflict: &SaveConflict) -> ConflictResolution;
+}
+
+/// Tracks how many times each device has written a save file, used to detect
+/// saves which were modified independently on two devices.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionVector {
+    pub counters: BTreeMap<String, u64>,
+}
+
+/// Result of comparing two [VersionVector]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionOrdering {
+    Equal,
+
+    /// Every write in this version is also included in the other version.
+    Before,
+
+    /// Every write in the other version is also included in this version.
+    After,
+
+    /// Each version contains writes the other does not.
+    Concurrent,
+}
+
+/// Describes a save file stored by a [SaveSync] provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveSyncMetadata {
+    pub user_id: UserId,
+    pub version: VersionVector,
+
+    /// Device which most recently wrote this save.
+    pub device: String,
+
+    /// Time this save was written, in seconds since the Unix epoch.
+    pub modified_at: u64,
+}
+
+/// A save file along with its sync metadata.
+///
+/// `data` holds the encoded save file exactly as written to disk by
+/// [crate::save_file_io].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncedSave {
+    pub metadata: SaveSyncMetadata,
+    pub data: Vec<u8>,
+}
+
+/// Two versions of a save file which were modified independently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveConflict {
+    pub local: SaveSyncMetadata,
+    pub remote: SaveSyncMetadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveSyncError {
+    /// The provider holds a version of the save which is not included in the
+    /// version being pushed.
+    Conflict(Box<SaveConflict>),
+
+    /// The provider could not be read from or written to.
+    Storage(String),
+}
+
+/// How to resolve a [SaveConflict].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepLocal,
+    KeepRemote,
+
+    /// Ask the user which version to keep.
+    Prompt,
+}
+
+/// Keeps whichever save was written most recently, preferring the local save
+/// if both were written at the same time.
+pub struct NewestWins;
+
+/// Always asks the user which save to keep.
+pub struct PromptOnConflict;
+
+/// Result of synchronizing a local save with a [SaveSync] provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncOutcome {
+    /// The local and remote saves are identical.
+    UpToDate,
+
+    /// The local save was uploaded. The local save should be updated to use
+    /// this metadata.
+    Pushed(SaveSyncMetadata),
+
+    /// The remote save is newer and should replace the local save.
+    Pulled(SyncedSave),
+
+    /// The saves conflict and the user must choose which to keep, after which
+    /// the caller should invoke [apply_resolution].
+    NeedsResolution { conflict: SaveConflict, remote: SyncedSave },
+}
+
+/// Reference [SaveSync] implementation which stores saves in a directory,
+/// e.g. one synchronized by a platform client.
+///
+/// Each save is stored as `sync-{user_id}.save` along with its metadata in
+/// `sync-{user_id}.meta.json`.
+pub struct FilesystemSaveSync {
+    dir: PathBuf,
+}
+
+/// Synchronizes a local save with a provider, resolving conflicts via
+/// `policy`.
+pub fn sync(
+    provider: &dyn SaveSync,
+    policy: &dyn ConflictPolicy,
+    local: &SyncedSave,
+) -> Result<SyncOutcome, SaveSyncError> {
+    let Some(remote) = provider.pull(local.metadata.user_id)? else {
+        provider.push(local)?;
+        return Ok(SyncOutcome::Pushed(local.metadata.clone()));
+    };
+
+    match local.metadata.version.compare(&remote.metadata.version) {
+        VersionOrdering::Equal => Ok(SyncOutcome::UpToDate),
+        VersionOrdering::After => {
+            provider.push(local)?;
+            Ok(SyncOutcome::Pushed(local.metadata.clone()))
+        }
+        VersionOrdering::Before => Ok(SyncOutcome::Pulled(remote)),
+        VersionOrdering::Concurrent => {
+            let conflict =
+                SaveConflict { local: local.metadata.clone(), remote: remote.metadata.clone() };
+            let resolution = policy.resolve(&conflict);
+            apply_resolution(provider, local, remote, resolution)
+        }
+    }
+}
+
+/// Applies a resolution to conflicting local and remote saves.
+///
+/// Keeping the local save pushes it with a version that supersedes both
+/// saves.
+pub fn apply_resolution(
+    provider: &dyn SaveSync,
+    local: &SyncedSave,
+    remote: SyncedSave,
+    resolution: ConflictResolution,
+) -> Result<SyncOutcome, SaveSyncError> {
+    match resolution {
+        ConflictResolution::KeepLocal => {
+            let mut version = local.metadata.version.merge(&remote.metadata.version);
+            version.increment(&local.metadata.device);
+            let resolved = SyncedSave {
+                metadata: SaveSyncMetadata { version, ..local.metadata.clone() },
+                data: local.data.clone(),
+            };
+            provider.push(&resolved)?;
+            Ok(SyncOutcome::Pushed(resolved.metadata))
+        }
+        ConflictResolution::KeepRemote => Ok(SyncOutcome::Pulled(remote)),
+        ConflictResolution::Prompt => Ok(SyncOutcome::NeedsResolution {
+            conflict: SaveConflict {
+                local: local.metadata.clone(),
+                remote: remote.metadata.clone(),
+            },
+            remote,
+        }),
+    }
+}
+
+/// Checks whether `pushed` may replace the `stored` save.
+///
+/// Returns [SaveSyncError::Conflict] if the stored save contains writes which
+/// the pushed save does not.
+pub fn check_push(
+    stored: Option<&SaveSyncMetadata>,
+    pushed: &SaveSyncMetadata,
+) -> Result<(), SaveSyncError> {
+    let Some(stored) = stored else {
+        return Ok(());
+    };
+    match pushed.version.compare(&stored.version) {
+        VersionOrdering::Equal | VersionOrdering::After => Ok(()),
+        VersionOrdering::Before | VersionOrdering::Concurrent => {
+            Err(SaveSyncError::Conflict(Box::new(SaveConflict {
+                local: pushed.clone(),
+                remote: stored.clone(),
+            })))
+        }
+    }
+}
+
+impl VersionVector {
+    /// Returns the number of writes recorded for a device.
+    pub fn get(&self, device: &str) -> u64 {
+        self.counters.get(device).copied().unwrap_or(0)
+    }
+
+    /// Records a new write by a device.
+    pub fn increment(&mut self, device: &str) {
+        *self.counters.entry(device.to_string()).or_insert(0) += 1;
+    }
+
+    /// Returns a version which includes every write from both versions.
+    pub fn merge(&self, other: &VersionVector) -> VersionVector {
+        let mut result = self.clone();
+        for (device, &count) in &other.counters {
+            let entry = result.counters.entry(device.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+        result
+    }
+
+    /// Compares this version to another.
+    pub fn compare(&self, other: &VersionVector) -> VersionOrdering {
+        let mut before = false;
+        let mut after = false;
+        for device in self.counters.keys().chain(other.counters.keys()) {
+            match self.get(device).cmp(&other.get(device)) {
+                Ordering::Less => before = true,
+                Ordering::Greater => after = true,
+                Ordering::Equal => {}
+            }
+        }
+        match (before, after) {
+            (false, false) => VersionOrdering::Equal,
+            (true, false) => VersionOrdering::Before,
+            (false, true) => VersionOrdering::After,
+            (true, true) => VersionOrdering::Concurrent,
+        }
+    }
+}
+
+impl fmt::Display for SaveSyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveSyncError::Conflict(conflict) => write!(
+                f,
+                "save for user {} was modified on both {} and {}",
+                conflict.local.user_id.0, conflict.local.device, conflict.remote.device
+            ),
+            SaveSyncError::Storage(message) => write!(f, "save sync storage error: {message}"),
+        }
+    }
+}
+
+impl ConflictPolicy for NewestWins {
+    fn resolve(&self, conflict: &SaveConflict) -> ConflictResolution {
+        if conflict.remote.modified_at > conflict.local.modified_at {
+            ConflictResolution::KeepRemote
+        } else {
+            ConflictResolution::KeepLocal
+        }
+    }
+}
+
+impl ConflictPolicy for PromptOnConflict {
+    fn resolve(&self, _conflict: &SaveConflict) -> ConflictResolution {
+        ConflictResolution::Prompt
+    }
+}
+
+impl FilesystemSaveSync {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn data_path(&self, user_id: UserId) -> PathBuf {
+        self.dir.join(format!("sync-{}.save", user_id.0))
+    }
+
+    fn metadata_path(&self, user_id: UserId) -> PathBuf {
+        self.dir.join(format!("sync-{}.meta.json", user_id.0))
+    }
+
+    fn read_metadata(&self, user_id: UserId) -> Result<Option<SaveSyncMetadata>, SaveSyncError> {
+        match fs::read(self.metadata_path(user_id)) {
+            Ok(data) => serde_json::from_slice(&data).map(Some).map_err(storage_error),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(storage_error(e)),
+        }
+    }
+}
+
+impl SaveSync for FilesystemSaveSync {
+    fn push(&self, save: &SyncedSave) -> Result<(), SaveSyncError> {
+        let user_id = save.metadata.user_id;
+        check_push(self.read_metadata(user_id)?.as_ref(), &save.metadata)?;
+        fs::create_dir_all(&self.dir).map_err(storage_error)?;
+        fs::write(self.data_path(user_id), &save.data).map_err(storage_error)?;
+        let metadata = serde_json::to_vec_pretty(&save.metadata).map_err(storage_error)?;
+        fs::write(self.metadata_path(user_id), metadata).map_err(storage_error)
+    }
+
+    fn pull(&self, user_id: UserId) -> Result<Option<SyncedSave>, SaveSyncError> {
+        let Some(metadata) = self.read_metadata(user_id)? else {
+            return Ok(None);
+        };
+        let data = fs::read(self.data_path(user_id)).map_err(storage_error)?;
+        Ok(Some(SyncedSave { metadata, data }))
+    }
+
+    fn list(&self) -> Result<Vec<SaveSyncMetadata>, SaveSyncError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(storage_error(e)),
+        };
+        let mut result = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(storage_error)?.path();
+            let is_metadata = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("sync-") && name.ends_with(".meta.json"));
+            if is_metadata {
+                let data = fs::read(&path).map_err(storage_error)?;
+                let metadata: SaveSyncMetadata =
+                    serde_json::from_slice(&data).map_err(storage_error)?;
+                result.push(metadata);
+            }
+        }
+        result.sort_by_key(|metadata| metadata.user_id);
+        Ok(result)
+    }
+}
+
+fn storage_error(error: impl fmt::Display) -> SaveSyncError {
+    SaveSyncError::Storage(error.to_string())
+}
@@ -11,7 +11,7 @@ use display_data::client_log_request::ClientLogRequest;
 use display_data::command::CommandSequence;
 use display_data::request_data::{
     ConnectRequest, ConnectResponse, PerformActionRequest, PerformActionResponse, PollRequest,
-    PollResponse, PollResponseType,
+    PollResponse, PollResponseType, ResyncRequest, ResyncResponse,
 };
 use rules_engine::{client_logging, engine};
 use serde::Serialize;
@@ -139,6 +139,14 @@ async fn poll(body: String) -> AppResult<Json<PollResponse>> {
     Ok(response)
 }
 
+async fn resync(body: String) -> AppResult<Json<ResyncResponse>> {
+    let req: ResyncRequest = parse_json(&body)?;
+    let user_id = req.metadata.user_id;
+    info!(?user_id, "Got resync request");
+    let response = engine::resync(&req);
+    Ok(check_response_size(&response, true))
+}
+
 async fn log(body: String) -> AppResult<StatusCode> {
     let req: ClientLogRequest = parse_json(&body)?;
     client_logging::log_client_events(req);
@@ -166,6 +174,7 @@ async fn main() {
         .route("/connect", get(connect).post(connect))
         .route("/perform_action", post(perform_action))
         .route("/poll", get(poll).post(poll))
+        .route("/resync", post(resync))
         .route("/log", post(log));
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:26598").await.unwrap_or_else(|e| {
@@ -46,3 +46,7 @@ pub fn modal_effect_choice_client_id(
 ) -> ClientCardId {
     format!("M{}/{}", client_card_id(card_id), choice_index.value())
 }
+
+pub fn related_card_client_id(card_id: CardId, index: usize) -> ClientCardId {
+    format!("R{}/{}", client_card_id(card_id), index)
+}
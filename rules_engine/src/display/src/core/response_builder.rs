@@ -2,13 +2,20 @@ use std::sync::Arc;
 
 use battle_state::battle::battle_animation_data::TriggerAnimation;
 use battle_state::battle::card_id::ActivatedAbilityId;
+use core_data::display_types::Milliseconds;
 use core_data::identifiers::UserId;
 use core_data::types::PlayerName;
 use display_data::battle_view::{BattleView, DisplayPlayer};
-use display_data::command::{Command, CommandSequence, ParallelCommandGroup, UpdateBattleCommand};
+use display_data::command::{
+    Command, CommandSequence, UpdateBattleCommand, UpdateBattleDeltaCommand,
+};
+use display_data::request_data::AnimationPreferences;
 use state_provider::display_state_provider::{DisplayState, DisplayStateProvider};
 use tabula_data::tabula::Tabula;
 
+use crate::rendering::animation_timeline::{AnimationStep, AnimationTimeline};
+use crate::rendering::battle_view_diff;
+
 /// Primary builder used to render game state.
 pub struct ResponseBuilder {
     /// Player for whom we are rendering
@@ -23,8 +30,8 @@ pub struct ResponseBuilder {
     /// Whether to animate the commands.
     animate: bool,
 
-    /// Commands to be executed as part of the response.
-    commands: CommandSequence,
+    /// Animation steps to be executed as part of the response.
+    timeline: AnimationTimeline,
 
     /// Whether this is an intermediate update for animation purposes.
     for_animation: bool,
@@ -34,6 +41,16 @@ pub struct ResponseBuilder {
 
     /// Triggers which are currently active.
     active_triggers: Vec<TriggerAnimation>,
+
+    /// Whether to send battle view updates as deltas where possible.
+    battle_view_deltas: bool,
+
+    /// Most recent battle view sent to the client, used as the base for the
+    /// next battle view delta.
+    last_battle_view: Option<Arc<BattleView>>,
+
+    /// How the user has requested animations be played.
+    animation_preferences: AnimationPreferences,
 }
 
 #[derive(Clone, Debug, Copy)]
@@ -49,34 +66,52 @@ impl ResponseBuilder {
         provider: impl DisplayStateProvider + 'static,
         animate: bool,
     ) -> Self {
+        let display_state = provider.get_display_state(user_id);
         Self {
             player,
             user_id,
             provider: Arc::new(provider),
             animate,
-            commands: CommandSequence::default(),
+            timeline: AnimationTimeline::default(),
             for_animation: false,
             pending_commands: Vec::new(),
             active_triggers: Vec::new(),
+            battle_view_deltas: display_state.battle_view_deltas,
+            last_battle_view: display_state.last_battle_view,
+            animation_preferences: display_state.animation_preferences,
         }
     }
 
     pub fn push(&mut self, command: Command) {
-        self.commands.groups.push(ParallelCommandGroup { commands: vec![command] });
+        self.timeline.push(AnimationStep::single(command));
+    }
+
+    /// Adds an animation step, whose tracks run in parallel, to the response.
+    pub fn push_step(&mut self, step: AnimationStep) {
+        self.timeline.push(step);
     }
 
+    /// Adds a battle view update to the response.
+    ///
+    /// If the client supports battle view deltas, this is sent as the changes
+    /// from the previous battle view where possible.
     pub fn push_battle_view(&mut self, view: BattleView) {
-        let mut commands = vec![Command::UpdateBattle(Box::new(UpdateBattleCommand {
-            battle: view,
-            update_sound: None,
-        }))];
+        let update = if self.battle_view_deltas {
+            self.battle_view_update(view)
+        } else {
+            Command::UpdateBattle(Box::new(UpdateBattleCommand {
+                battle: view,
+                update_sound: None,
+            }))
+        };
+        let mut commands = vec![update];
         commands.append(&mut self.pending_commands);
-        self.commands.groups.push(ParallelCommandGroup { commands });
+        self.timeline.push(AnimationStep::parallel(commands));
     }
 
     /// Appends all of the groups from a [CommandSequence] to the response.
     pub fn extend(&mut self, commands: CommandSequence) {
-        self.commands.groups.extend(commands.groups);
+        self.timeline.extend(commands);
     }
 
     /// Optional equivalent of [Self::extend].
@@ -86,6 +121,12 @@ impl ResponseBuilder {
         }
     }
 
+    /// Causes the next battle view update to be sent as a full snapshot, e.g.
+    /// because the client is reconnecting.
+    pub fn clear_last_battle_view(&mut self) {
+        self.last_battle_view = None;
+    }
+
     /// Adds a command to run in parallel with the next `UpdateBattleCommand`
     /// that is received via [Self::push_battle_view].
     pub fn run_with_next_battle_view(&mut self, command: Command) {
@@ -114,8 +155,39 @@ impl ResponseBuilder {
         self.player
     }
 
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
     pub fn commands(self) -> CommandSequence {
-        self.commands
+        if self.battle_view_deltas {
+            self.update_display_state(|state| {
+                state.last_battle_view = self.last_battle_view.clone();
+            });
+        }
+        self.timeline.commands()
+    }
+
+    /// Returns the duration to use for an animation which normally takes
+    /// `milliseconds`, scaled by the user's animation speed preference.
+    pub fn animation_duration(&self, milliseconds: u32) -> Milliseconds {
+        let multiplier = self.animation_preferences.speed_multiplier;
+        if multiplier > 0.0 {
+            Milliseconds::new((milliseconds as f32 / multiplier).round() as u32)
+        } else {
+            Milliseconds::new(milliseconds)
+        }
+    }
+
+    /// True if purely decorative effects should be omitted from the response.
+    pub fn skip_non_essential_effects(&self) -> bool {
+        self.animation_preferences.skip_non_essential_effects
+    }
+
+    /// True if animations which move objects across the screen should be
+    /// omitted or played without staggering.
+    pub fn reduce_motion(&self) -> bool {
+        self.animation_preferences.reduce_motion
     }
 
     pub fn set_for_animation(&mut self, for_animation: bool) {
@@ -154,4 +226,23 @@ impl ResponseBuilder {
     pub fn active_triggers(&self) -> &[TriggerAnimation] {
         &self.active_triggers
     }
+
+    fn battle_view_update(&mut self, view: BattleView) -> Command {
+        let delta = self
+            .last_battle_view
+            .as_ref()
+            .and_then(|previous| battle_view_diff::diff(previous, &view));
+        let view = Arc::new(view);
+        self.last_battle_view = Some(view.clone());
+        match delta {
+            Some(delta) => Command::UpdateBattleDelta(Box::new(UpdateBattleDeltaCommand {
+                delta,
+                update_sound: None,
+            })),
+            None => Command::UpdateBattle(Box::new(UpdateBattleCommand {
+                battle: (*view).clone(),
+                update_sound: None,
+            })),
+        }
+    }
 }
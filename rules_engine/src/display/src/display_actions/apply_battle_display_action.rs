@@ -1,5 +1,10 @@
+use std::sync::atomic::Ordering;
+
 use action_data::battle_display_action::{BattleDisplayAction, CardBrowserType};
 use action_data::game_action_data::GameAction;
+use action_data::panel_address::{PanelAddress, VoidBrowserFilter, VoidBrowserSort};
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
 use core_data::display_types::StudioAnimation;
 use core_data::identifiers::UserId;
 use core_data::numerics::Energy;
@@ -12,10 +17,15 @@ use state_provider::display_state_provider::DisplayStateProvider;
 use crate::core::response_builder::ResponseBuilder;
 use crate::display_actions::display_state;
 
+/// Maximum number of void cards shown in the card browser. Larger voids are
+/// shown in the void browser panel.
+pub const MAX_VOID_BROWSER_CARDS: usize = 12;
+
 /// Modifies the display state of a battle and returns commands in response to
 /// the action.
 pub fn execute(
     provider: impl DisplayStateProvider + 'static,
+    battle: &BattleState,
     action: BattleDisplayAction,
     player: PlayerName,
     user_id: UserId,
@@ -24,7 +34,7 @@ pub fn execute(
 
     match action {
         BattleDisplayAction::BrowseCards(card_browser_type) => {
-            browse_cards(card_browser_type, &mut builder);
+            browse_cards(battle, card_browser_type, &mut builder);
         }
         BattleDisplayAction::CloseCardBrowser => close_card_browser(&builder),
         BattleDisplayAction::SetSelectedEnergyAdditionalCost(energy) => {
@@ -39,6 +49,18 @@ pub fn execute(
         BattleDisplayAction::ToggleStackVisibility => {
             toggle_stack_visibility(&builder);
         }
+        BattleDisplayAction::RequestHint => {
+            // Search is started by the engine, since it requires the battle.
+        }
+        BattleDisplayAction::InspectCard(card_id) => {
+            display_state::set_inspected_card(&builder, Some(card_id));
+        }
+        BattleDisplayAction::CloseCardInspection => {
+            display_state::set_inspected_card(&builder, None);
+        }
+        BattleDisplayAction::SetOpponentEmotesMuted(muted) => {
+            display_state::set_opponent_emotes_muted(&builder, muted);
+        }
     }
 
     builder.commands()
@@ -51,12 +73,39 @@ pub fn on_action_performed(
     action: &GameAction,
     user_id: UserId,
 ) {
-    if action != &GameAction::BattleDisplayAction(BattleDisplayAction::ToggleStackVisibility) {
+    if !matches!(
+        action,
+        GameAction::BattleDisplayAction(BattleDisplayAction::ToggleStackVisibility)
+            | GameAction::Emote(..)
+    ) {
         // Stop hiding stack on any other action received.
         let mut state = provider.get_display_state(user_id);
         state.overlay_hidden = false;
         provider.set_display_state(user_id, state);
     }
+
+    if matches!(
+        action,
+        GameAction::BattleAction(..) | GameAction::DebugAction(..) | GameAction::Undo(..)
+    ) {
+        // Suggestions no longer apply once the battle changes.
+        let mut state = provider.get_display_state(user_id);
+        if let Some(hint) = state.hint.take() {
+            hint.cancelled.store(true, Ordering::Relaxed);
+            provider.set_display_state(user_id, state);
+        }
+    }
+
+    if let GameAction::BattleAction(battle_action) = action
+        && !matches!(battle_action, BattleAction::Debug(..))
+    {
+        // The current tutorial message has been seen once the user responds.
+        let mut state = provider.get_display_state(user_id);
+        if let Some(message) = state.tutorial_message.take() {
+            provider.mark_tutorial_message_seen(user_id, message);
+            provider.set_display_state(user_id, state);
+        }
+    }
 }
 
 /// Returns whether a card browser is currently active and what source position
@@ -65,7 +114,32 @@ pub fn current_browser_source(builder: &ResponseBuilder) -> Option<Position> {
     display_state::get_card_browser_source(builder)
 }
 
-fn browse_cards(card_browser: CardBrowserType, builder: &mut ResponseBuilder) {
+fn browse_cards(
+    battle: &BattleState,
+    card_browser: CardBrowserType,
+    builder: &mut ResponseBuilder,
+) {
+    let void_player = match card_browser {
+        CardBrowserType::UserVoid => Some(builder.act_for_player()),
+        CardBrowserType::EnemyVoid => Some(builder.act_for_player().opponent()),
+        _ => None,
+    };
+    if let Some(player) = void_player
+        && battle.cards.void(player).len() > MAX_VOID_BROWSER_CARDS
+    {
+        // Large voids do not fit in the card browser, so are listed in a
+        // panel instead.
+        display_state::set_current_panel_address(
+            builder,
+            Some(PanelAddress::VoidBrowser {
+                player,
+                filter: VoidBrowserFilter::default(),
+                sort: VoidBrowserSort::default(),
+            }),
+        );
+        return;
+    }
+
     let source_position = match card_browser {
         CardBrowserType::UserDeck => Position::InDeck(DisplayPlayer::User),
         CardBrowserType::EnemyDeck => Position::InDeck(DisplayPlayer::Enemy),
@@ -1,6 +1,8 @@
 use action_data::panel_address::PanelAddress;
+use battle_state::battle::card_id::CardId;
 use core_data::numerics::Energy;
 use display_data::object_position::Position;
+use state_provider::display_state_provider::{HintState, TutorialMessageId};
 
 use crate::core::response_builder::ResponseBuilder;
 
@@ -59,3 +61,44 @@ pub fn set_overlay_hidden(builder: &ResponseBuilder, hidden: bool) {
 pub fn is_battlefield_shown(builder: &ResponseBuilder) -> bool {
     builder.get_display_state().overlay_hidden
 }
+
+/// Updates the card shown on the card inspection screen.
+pub fn set_inspected_card(builder: &ResponseBuilder, card_id: Option<CardId>) {
+    builder.update_display_state(|state| {
+        state.inspected_card = card_id;
+    });
+}
+
+/// Gets the card shown on the card inspection screen, if any.
+pub fn get_inspected_card(builder: &ResponseBuilder) -> Option<CardId> {
+    builder.get_display_state().inspected_card
+}
+
+/// Gets the action suggestion requested by the user, if any.
+pub fn get_hint(builder: &ResponseBuilder) -> Option<HintState> {
+    builder.get_display_state().hint
+}
+
+/// Returns true if the user has already been shown the given tutorial message.
+pub fn has_seen_tutorial_message(builder: &ResponseBuilder, message: TutorialMessageId) -> bool {
+    builder.provider().has_seen_tutorial_message(builder.user_id(), message)
+}
+
+/// Updates the tutorial message currently shown to the user.
+pub fn set_tutorial_message(builder: &ResponseBuilder, message: Option<TutorialMessageId>) {
+    builder.update_display_state(|state| {
+        state.tutorial_message = message;
+    });
+}
+
+/// Sets whether emotes sent by the user's opponent are hidden.
+pub fn set_opponent_emotes_muted(builder: &ResponseBuilder, muted: bool) {
+    builder.update_display_state(|state| {
+        state.opponent_emotes_muted = muted;
+    });
+}
+
+/// Returns true if emotes sent by the user's opponent are hidden.
+pub fn is_opponent_emotes_muted(builder: &ResponseBuilder) -> bool {
+    builder.get_display_state().opponent_emotes_muted
+}
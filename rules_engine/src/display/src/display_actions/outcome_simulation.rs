@@ -1,10 +1,8 @@
 use std::collections::HashMap;
 
-use battle_mutations::actions::apply_battle_action;
+use battle_mutations::actions::effect_preview;
 use battle_queries::battle_card_queries::card_properties;
 use battle_queries::battle_player_queries::player_properties;
-use battle_queries::legal_action_queries::legal_actions;
-use battle_queries::legal_action_queries::legal_actions_data::ForPlayer;
 use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::battle_status::BattleStatus;
@@ -56,15 +54,9 @@ pub fn action_effect_preview(
     player: PlayerName,
     action: BattleAction,
 ) -> BattlePreviewView {
-    let mut simulation = battle.logical_clone();
     let subscriber = tracing_subscriber::registry().with(EnvFilter::new("warn"));
-    tracing::subscriber::with_default(subscriber, || {
-        apply_battle_action::execute(&mut simulation, player, action);
-        let opponent = player.opponent();
-        let legal_actions_for_opponent = legal_actions::compute(&simulation, opponent);
-        if legal_actions_for_opponent.contains(BattleAction::PassPriority, ForPlayer::Human) {
-            apply_battle_action::execute(&mut simulation, opponent, BattleAction::PassPriority);
-        }
+    let (simulation, effects) = tracing::subscriber::with_default(subscriber, || {
+        effect_preview::simulate(battle, player, action)
     });
 
     let simulated_user_state = simulation.players.player(player);
@@ -86,6 +78,8 @@ pub fn action_effect_preview(
             let original_spark = player_properties::spark_total(battle, player);
             (simulated_spark != original_spark).then_some(simulated_spark)
         },
+        cards_drawn: nonzero(effects.players.player(player).cards_drawn),
+        characters_dissolved: nonzero(effects.players.player(player).characters_dissolved),
     };
 
     let enemy_preview = PlayerPreviewView {
@@ -101,6 +95,10 @@ pub fn action_effect_preview(
             let original_spark = player_properties::spark_total(battle, player.opponent());
             (simulated_spark != original_spark).then_some(simulated_spark)
         },
+        cards_drawn: nonzero(effects.players.player(player.opponent()).cards_drawn),
+        characters_dissolved: nonzero(
+            effects.players.player(player.opponent()).characters_dissolved,
+        ),
     };
 
     let preview_message = get_preview_message(&simulation, player);
@@ -109,6 +107,17 @@ pub fn action_effect_preview(
     BattlePreviewView { user: user_preview, enemy: enemy_preview, cards, preview_message }
 }
 
+/// Returns a preview of the battle state after selecting `action` as an option
+/// in the given player's active prompt, or None if `action` is not a prompt
+/// option whose effects can be previewed.
+pub fn prompt_option_preview(
+    battle: &BattleState,
+    player: PlayerName,
+    action: BattleAction,
+) -> Option<BattlePreviewView> {
+    effect_preview::is_prompt_option(action).then(|| action_effect_preview(battle, player, action))
+}
+
 /// Returns a unified preview of the battle state based on the current prompt
 /// and selected display state.
 ///
@@ -137,6 +146,10 @@ pub fn current_prompt_battle_preview(
     }
 }
 
+fn nonzero(value: u32) -> Option<u32> {
+    (value > 0).then_some(value)
+}
+
 fn get_preview_message(simulation: &BattleState, player: PlayerName) -> Option<FlexNode> {
     let hand_size_exceeded =
         simulation.turn_history.current_action_history.player(player).hand_size_limit_exceeded;
@@ -0,0 +1,98 @@
+use std::fmt::Write;
+
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle_trace::decision_explanation::DecisionExplanation;
+use bon::Builder;
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, TextAlign, WhiteSpace};
+use masonry::flex_style::FlexStyle;
+use ui_components::box_component::BoxComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+use ui_components::scroll_view_component::ScrollViewComponent;
+use ui_components::text_component::TextComponent;
+use ui_components::typography::Typography;
+
+/// Shows why the AI selected its most recent action in this battle.
+#[derive(Clone, Builder)]
+pub struct AiDecisionPanel<'a> {
+    pub battle: &'a BattleState,
+}
+
+impl Component for AiDecisionPanel<'_> {
+    fn render(self) -> Option<impl Component> {
+        let text = match &self.battle.last_ai_decision {
+            Some(explanation) => explanation_text(explanation),
+            None => "No AI search has run in this battle yet.".to_string(),
+        };
+
+        Some(
+            PanelComponent::builder()
+                .title("AI Decision")
+                .content(
+                    ScrollViewComponent::builder()
+                        .child(
+                            BoxComponent::builder()
+                                .name("AI Decision Content")
+                                .style(
+                                    FlexStyle::builder()
+                                        .align_items(FlexAlign::Stretch)
+                                        .flex_direction(FlexDirection::Column)
+                                        .flex_grow(1)
+                                        .justify_content(FlexJustify::FlexStart)
+                                        .padding((8, 8, 8, 8))
+                                        .build(),
+                                )
+                                .child(
+                                    TextComponent::builder()
+                                        .text(text)
+                                        .typography(Typography::StackTrace)
+                                        .text_align(TextAlign::UpperLeft)
+                                        .white_space(WhiteSpace::Normal)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
+
+fn explanation_text(explanation: &DecisionExplanation) -> String {
+    let statistics = &explanation.statistics;
+    let mut text = format!("{:?} selected {:?}\n", explanation.player, explanation.action);
+    let _ = writeln!(
+        text,
+        "{} iterations across {} candidates, {} nodes ({} in selected tree)",
+        statistics.total_iterations,
+        statistics.candidates,
+        statistics.total_nodes,
+        statistics.selected_nodes
+    );
+    if statistics.prompt_cache_lookups > 0 {
+        let _ = writeln!(
+            text,
+            "Prompt cache: {} of {} lookups hit ({:.0}%)",
+            statistics.prompt_cache_hits,
+            statistics.prompt_cache_lookups,
+            100.0 * f64::from(statistics.prompt_cache_hits)
+                / f64::from(statistics.prompt_cache_lookups)
+        );
+    }
+
+    text.push_str("\nPrincipal variation:\n");
+    for (i, action) in explanation.principal_variation.iter().enumerate() {
+        let _ = writeln!(text, "  {}. {action:?}", i + 1);
+    }
+
+    text.push_str("\nTop candidates:\n");
+    for scored in &explanation.alternatives {
+        let _ = writeln!(
+            text,
+            "  {:+.3} ({} visits) {:?}",
+            scored.average_reward, scored.visit_count, scored.action
+        );
+    }
+    text
+}
@@ -0,0 +1,67 @@
+use bon::Builder;
+use display_data::battle_log_view::BattleLogView;
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, TextAlign, WhiteSpace};
+use masonry::flex_node::FlexNode;
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+use ui_components::scroll_view_component::ScrollViewComponent;
+use ui_components::text_component::TextComponent;
+use ui_components::typography::Typography;
+
+/// Shows the actions players have taken so far in this battle, grouped by
+/// turn.
+#[derive(Clone, Builder)]
+pub struct BattleLogPanel {
+    pub log: BattleLogView,
+}
+
+impl Component for BattleLogPanel {
+    fn render(self) -> Option<impl Component> {
+        let mut lines = Vec::new();
+        if self.log.turns.is_empty() {
+            lines.extend(log_line(strings::battle_log_empty().to_string()));
+        }
+        for turn in self.log.turns {
+            lines.extend(log_line(turn.header));
+            lines.extend(turn.entries.into_iter().filter_map(|entry| log_line(entry.text)));
+        }
+
+        Some(
+            PanelComponent::builder()
+                .title(strings::battle_log_panel_title().to_string())
+                .content(
+                    ScrollViewComponent::builder()
+                        .child(
+                            BoxComponent::builder()
+                                .name("Battle Log Content")
+                                .style(
+                                    FlexStyle::builder()
+                                        .align_items(FlexAlign::Stretch)
+                                        .flex_direction(FlexDirection::Column)
+                                        .flex_grow(1)
+                                        .justify_content(FlexJustify::FlexStart)
+                                        .padding((8, 8, 8, 8))
+                                        .build(),
+                                )
+                                .children(lines)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
+
+fn log_line(text: String) -> Option<FlexNode> {
+    TextComponent::builder()
+        .text(text)
+        .typography(Typography::Body2)
+        .text_align(TextAlign::MiddleLeft)
+        .white_space(WhiteSpace::Normal)
+        .build()
+        .flex_node()
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CardIdType;
+use bon::Builder;
+use core_data::types::PlayerName;
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, TextAlign, WhiteSpace};
+use masonry::flex_node::FlexNode;
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+use ui_components::scroll_view_component::ScrollViewComponent;
+use ui_components::text_component::TextComponent;
+use ui_components::typography::Typography;
+
+use crate::rendering::{card_display_state, card_rendering};
+
+/// Shows the cards remaining in the user's deck, grouped by name so that
+/// the order of the deck is not revealed.
+///
+/// Cards the user knows to be on top of their deck, e.g. from foresee effects,
+/// are listed separately in order.
+#[derive(Clone, Builder)]
+pub struct DeckListPanel<'a> {
+    pub user_player: PlayerName,
+    pub battle: &'a BattleState,
+}
+
+/// Returns the number of copies of each card remaining in a player's deck,
+/// keyed by card name.
+pub fn deck_contents(battle: &BattleState, player: PlayerName) -> BTreeMap<String, u32> {
+    let mut contents = BTreeMap::new();
+    for card_id in battle.cards.all_deck_cards(player) {
+        *contents.entry(card_rendering::card_name(battle, card_id.card_id())).or_insert(0) += 1;
+    }
+    contents
+}
+
+/// Returns the names of cards on top of a player's deck which have been
+/// revealed to them, along with their position counting down from the top
+/// card at position 1.
+pub fn known_top_of_deck(battle: &BattleState, player: PlayerName) -> Vec<(u32, String)> {
+    battle
+        .cards
+        .top_of_deck(player)
+        .iter()
+        .rev()
+        .enumerate()
+        .filter(|(_, card_id)| {
+            card_display_state::is_revealed_to(battle, card_id.card_id(), player)
+        })
+        .map(|(index, card_id)| {
+            (index as u32 + 1, card_rendering::card_name(battle, card_id.card_id()))
+        })
+        .collect()
+}
+
+impl Component for DeckListPanel<'_> {
+    fn render(self) -> Option<impl Component> {
+        let mut lines = Vec::new();
+        let known_top = known_top_of_deck(self.battle, self.user_player);
+        if !known_top.is_empty() {
+            lines.extend(deck_list_line(strings::deck_list_top_of_deck_header().to_string()));
+            lines.extend(known_top.into_iter().filter_map(|(position, name)| {
+                deck_list_line(strings::deck_list_top_of_deck_entry(position, name).to_string())
+            }));
+        }
+
+        let contents = deck_contents(self.battle, self.user_player);
+        let total = contents.values().sum::<u32>();
+        lines.extend(deck_list_line(strings::deck_list_contents_header(total).to_string()));
+        if contents.is_empty() {
+            lines.extend(deck_list_line(strings::deck_list_empty().to_string()));
+        }
+        lines.extend(contents.into_iter().filter_map(|(name, count)| {
+            deck_list_line(strings::deck_list_entry(count, name).to_string())
+        }));
+
+        Some(
+            PanelComponent::builder()
+                .title(strings::deck_list_panel_title().to_string())
+                .content(
+                    ScrollViewComponent::builder()
+                        .child(
+                            BoxComponent::builder()
+                                .name("Deck List Content")
+                                .style(
+                                    FlexStyle::builder()
+                                        .align_items(FlexAlign::Stretch)
+                                        .flex_direction(FlexDirection::Column)
+                                        .flex_grow(1)
+                                        .justify_content(FlexJustify::FlexStart)
+                                        .padding((8, 8, 8, 8))
+                                        .build(),
+                                )
+                                .children(lines)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
+
+fn deck_list_line(text: String) -> Option<FlexNode> {
+    TextComponent::builder()
+        .text(text)
+        .typography(Typography::Body2)
+        .text_align(TextAlign::MiddleLeft)
+        .white_space(WhiteSpace::Normal)
+        .build()
+        .flex_node()
+}
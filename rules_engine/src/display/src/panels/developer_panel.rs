@@ -137,6 +137,46 @@ impl Component for DeveloperPanel {
                                 ))
                                 .build(),
                         )
+                        .child(
+                            DebugButton::builder()
+                                .label("AI Decision")
+                                .action(GameAction::BattleDisplayAction(
+                                    BattleDisplayAction::OpenPanel(PanelAddress::AiDecision),
+                                ))
+                                .build(),
+                        )
+                        .child(
+                            DebugButton::builder()
+                                .label("Battle Log")
+                                .action(GameAction::BattleDisplayAction(
+                                    BattleDisplayAction::OpenPanel(PanelAddress::BattleLog),
+                                ))
+                                .build(),
+                        )
+                        .child(
+                            DebugButton::builder()
+                                .label("Match History")
+                                .action(GameAction::BattleDisplayAction(
+                                    BattleDisplayAction::OpenPanel(PanelAddress::MatchHistory),
+                                ))
+                                .build(),
+                        )
+                        .child(
+                            DebugButton::builder()
+                                .label("Deck List")
+                                .action(GameAction::BattleDisplayAction(
+                                    BattleDisplayAction::OpenPanel(PanelAddress::DeckList),
+                                ))
+                                .build(),
+                        )
+                        .child(
+                            DebugButton::builder()
+                                .label("Emotes")
+                                .action(GameAction::BattleDisplayAction(
+                                    BattleDisplayAction::OpenPanel(PanelAddress::Emotes),
+                                ))
+                                .build(),
+                        )
                         .child(
                             DebugButton::builder()
                                 .label("Deck->1")
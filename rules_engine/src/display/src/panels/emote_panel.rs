@@ -0,0 +1,82 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::emote_data::Emote;
+use action_data::game_action_data::GameAction;
+use bon::Builder;
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, FlexWrap};
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::button_component::ButtonComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+
+use crate::rendering::emote_rendering;
+
+/// Lists the emotes the user can send to their opponent, along with a button
+/// to mute the opponent's emotes.
+#[derive(Clone, Builder)]
+pub struct EmotePanel {
+    pub opponent_emotes_muted: bool,
+}
+
+impl Component for EmotePanel {
+    fn render(self) -> Option<impl Component> {
+        let mute_label = if self.opponent_emotes_muted {
+            strings::emote_unmute_opponent()
+        } else {
+            strings::emote_mute_opponent()
+        };
+
+        Some(
+            PanelComponent::builder()
+                .title(strings::emote_panel_title().to_string())
+                .content(
+                    BoxComponent::builder()
+                        .name("Emote Panel Container")
+                        .style(
+                            FlexStyle::builder()
+                                .align_items(FlexAlign::Stretch)
+                                .flex_direction(FlexDirection::Column)
+                                .flex_grow(1)
+                                .build(),
+                        )
+                        .child(
+                            BoxComponent::builder()
+                                .name("Emote Buttons")
+                                .style(
+                                    FlexStyle::builder()
+                                        .align_items(FlexAlign::Center)
+                                        .flex_direction(FlexDirection::Row)
+                                        .justify_content(FlexJustify::Center)
+                                        .wrap(FlexWrap::Wrap)
+                                        .padding((8, 8, 8, 8))
+                                        .build(),
+                                )
+                                .children(
+                                    Emote::ALL
+                                        .into_iter()
+                                        .filter_map(|emote| {
+                                            ButtonComponent::builder()
+                                                .label(emote_rendering::emote_text(emote))
+                                                .action(GameAction::Emote(emote))
+                                                .build()
+                                                .flex_node()
+                                        })
+                                        .collect(),
+                                )
+                                .build(),
+                        )
+                        .child(
+                            ButtonComponent::builder()
+                                .label(mute_label.to_string())
+                                .action(BattleDisplayAction::SetOpponentEmotesMuted(
+                                    !self.opponent_emotes_muted,
+                                ))
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
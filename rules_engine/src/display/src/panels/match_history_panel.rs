@@ -0,0 +1,86 @@
+use battle_state::battle_player::battle_player_state::PlayerType;
+use bon::Builder;
+use database::match_history::{MatchHistory, MatchRecord, MatchResult};
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, TextAlign, WhiteSpace};
+use masonry::flex_node::FlexNode;
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+use ui_components::scroll_view_component::ScrollViewComponent;
+use ui_components::text_component::TextComponent;
+use ui_components::typography::Typography;
+
+/// Lists the battles the user has recently completed, most recent first.
+#[derive(Clone, Builder)]
+pub struct MatchHistoryPanel {
+    pub history: MatchHistory,
+}
+
+impl Component for MatchHistoryPanel {
+    fn render(self) -> Option<impl Component> {
+        let mut lines = Vec::new();
+        if self.history.entries.is_empty() {
+            lines.extend(history_line(strings::match_history_empty().to_string()));
+        }
+        lines.extend(
+            self.history.entries.iter().filter_map(|record| history_line(entry_text(record))),
+        );
+
+        Some(
+            PanelComponent::builder()
+                .title(strings::match_history_panel_title().to_string())
+                .content(
+                    ScrollViewComponent::builder()
+                        .child(
+                            BoxComponent::builder()
+                                .name("Match History Content")
+                                .style(
+                                    FlexStyle::builder()
+                                        .align_items(FlexAlign::Stretch)
+                                        .flex_direction(FlexDirection::Column)
+                                        .flex_grow(1)
+                                        .justify_content(FlexJustify::FlexStart)
+                                        .padding((8, 8, 8, 8))
+                                        .build(),
+                                )
+                                .children(lines)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
+
+fn entry_text(record: &MatchRecord) -> String {
+    let result = match record.result {
+        MatchResult::Victory => strings::match_history_victory(),
+        MatchResult::Defeat => strings::match_history_defeat(),
+        MatchResult::Draw => strings::match_history_draw(),
+    };
+    let opponent = match record.opponent {
+        PlayerType::User(_) => strings::match_history_user_opponent(),
+        PlayerType::Agent(_) => strings::match_history_ai_opponent(),
+    };
+    strings::match_history_entry(
+        result,
+        opponent,
+        format!("{:?}", record.user_deck),
+        format!("{:?}", record.opponent_deck),
+        record.turn_count,
+    )
+    .to_string()
+}
+
+fn history_line(text: String) -> Option<FlexNode> {
+    TextComponent::builder()
+        .text(text)
+        .typography(Typography::Body2)
+        .text_align(TextAlign::MiddleLeft)
+        .white_space(WhiteSpace::Normal)
+        .build()
+        .flex_node()
+}
@@ -1,6 +1,12 @@
 pub mod add_card_to_hand_panel;
+pub mod ai_decision_panel;
+pub mod battle_log_panel;
+pub mod deck_list_panel;
 pub mod developer_panel;
+pub mod emote_panel;
+pub mod match_history_panel;
 pub mod panel_rendering;
 pub mod play_opponent_card_panel;
 pub mod set_opponent_agent_panel;
 pub mod view_logs_panel;
+pub mod void_browser_panel;
@@ -3,11 +3,19 @@ use battle_state::battle::battle_state::BattleState;
 use ui_components::component::Component;
 
 use crate::core::response_builder::ResponseBuilder;
+use crate::display_actions::display_state;
 use crate::panels::add_card_to_hand_panel::AddCardToHandPanel;
+use crate::panels::ai_decision_panel::AiDecisionPanel;
+use crate::panels::battle_log_panel::BattleLogPanel;
+use crate::panels::deck_list_panel::DeckListPanel;
 use crate::panels::developer_panel::DeveloperPanel;
+use crate::panels::emote_panel::EmotePanel;
+use crate::panels::match_history_panel::MatchHistoryPanel;
 use crate::panels::play_opponent_card_panel::PlayOpponentCardPanel;
 use crate::panels::set_opponent_agent_panel::SetOpponentAgentPanel;
 use crate::panels::view_logs_panel::ViewLogsPanel;
+use crate::panels::void_browser_panel::VoidBrowserPanel;
+use crate::rendering::battle_log_rendering;
 
 /// Renders a panel based on its [PanelAddress].
 pub fn render_panel(
@@ -39,5 +47,31 @@ pub fn render_panel(
             .maybe_filter(filter)
             .build()
             .wrap(),
+        PanelAddress::AiDecision => AiDecisionPanel::builder().battle(battle).build().wrap(),
+        PanelAddress::BattleLog => BattleLogPanel::builder()
+            .log(battle_log_rendering::battle_log_view(builder, battle))
+            .build()
+            .wrap(),
+        PanelAddress::VoidBrowser { player, filter, sort } => VoidBrowserPanel::builder()
+            .user_player(builder.display_for_player())
+            .player(player)
+            .filter(filter)
+            .sort(sort)
+            .battle(battle)
+            .build()
+            .wrap(),
+        PanelAddress::MatchHistory => MatchHistoryPanel::builder()
+            .history(builder.provider().match_history(builder.user_id()))
+            .build()
+            .wrap(),
+        PanelAddress::DeckList => DeckListPanel::builder()
+            .user_player(builder.display_for_player())
+            .battle(battle)
+            .build()
+            .wrap(),
+        PanelAddress::Emotes => EmotePanel::builder()
+            .opponent_emotes_muted(display_state::is_opponent_emotes_muted(builder))
+            .build()
+            .wrap(),
     }
 }
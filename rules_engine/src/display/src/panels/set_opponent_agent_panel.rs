@@ -1,4 +1,5 @@
 use action_data::debug_action_data::DebugAction;
+use ai_data::difficulty::AiDifficulty;
 use ai_data::game_ai::GameAI;
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle_player::battle_player_state::PlayerType;
@@ -71,6 +72,26 @@ impl Component for SetOpponentAgentPanel<'_> {
                                         .build(),
                                 )
                                 .child(SetHumanCell::builder().build())
+                                .child(
+                                    SetAgentCell::builder()
+                                        .agent(GameAI::Difficulty(AiDifficulty::Easy))
+                                        .build(),
+                                )
+                                .child(
+                                    SetAgentCell::builder()
+                                        .agent(GameAI::Difficulty(AiDifficulty::Medium))
+                                        .build(),
+                                )
+                                .child(
+                                    SetAgentCell::builder()
+                                        .agent(GameAI::Difficulty(AiDifficulty::Hard))
+                                        .build(),
+                                )
+                                .child(
+                                    SetAgentCell::builder()
+                                        .agent(GameAI::Difficulty(AiDifficulty::Max))
+                                        .build(),
+                                )
                                 .child(SetAgentCell::builder().agent(GameAI::MonteCarlo(5)).build())
                                 .child(
                                     SetAgentCell::builder().agent(GameAI::MonteCarlo(10)).build(),
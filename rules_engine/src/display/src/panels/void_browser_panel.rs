@@ -0,0 +1,240 @@
+use std::cmp::Reverse;
+
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::panel_address::{PanelAddress, VoidBrowserFilter, VoidBrowserSort};
+use battle_queries::battle_card_queries::{card, card_properties};
+use battle_queries::legal_action_queries::can_play_cards;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardIdType, VoidCardId};
+use bon::Builder;
+use core_data::card_types::CardType;
+use core_data::types::PlayerName;
+use masonry::flex_enums::{FlexAlign, FlexDirection, FlexJustify, FlexWrap, TextAlign};
+use masonry::flex_node::FlexNode;
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::button_component::ButtonComponent;
+use ui_components::component::Component;
+use ui_components::panel_component::PanelComponent;
+use ui_components::scroll_view_component::ScrollViewComponent;
+use ui_components::text_component::TextComponent;
+use ui_components::typography::Typography;
+
+use crate::rendering::card_rendering;
+
+/// Lists the cards in a player's void, with buttons to filter and sort them.
+///
+/// Used instead of the card browser for large voids, which do not fit on
+/// screen.
+#[derive(Clone, Builder)]
+pub struct VoidBrowserPanel<'a> {
+    pub user_player: PlayerName,
+    pub player: PlayerName,
+    pub filter: VoidBrowserFilter,
+    pub sort: VoidBrowserSort,
+    pub battle: &'a BattleState,
+}
+
+/// Returns the cards in a player's void which match `filter`, in the order
+/// given by `sort`.
+pub fn void_cards(
+    battle: &BattleState,
+    player: PlayerName,
+    filter: VoidBrowserFilter,
+    sort: VoidBrowserSort,
+) -> Vec<VoidCardId> {
+    let mut cards = battle
+        .cards
+        .void(player)
+        .iter()
+        .filter(|card_id| match filter {
+            VoidBrowserFilter::All => true,
+            VoidBrowserFilter::Characters => {
+                card_properties::card_type(battle, *card_id) == CardType::Character
+            }
+            VoidBrowserFilter::Events => {
+                card_properties::card_type(battle, *card_id) == CardType::Event
+            }
+            VoidBrowserFilter::Reclaimable => {
+                can_play_cards::can_play_from_void_energy_cost(battle, *card_id).is_some()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match sort {
+        VoidBrowserSort::Recent => {
+            cards.sort_by_key(|card_id| Reverse(card::get(battle, *card_id).object_id));
+        }
+        VoidBrowserSort::Cost => {
+            cards.sort_by_cached_key(|card_id| {
+                (
+                    card_properties::converted_energy_cost(battle, *card_id),
+                    card_rendering::card_name(battle, card_id.card_id()),
+                )
+            });
+        }
+        VoidBrowserSort::Name => {
+            cards
+                .sort_by_cached_key(|card_id| card_rendering::card_name(battle, card_id.card_id()));
+        }
+    }
+    cards
+}
+
+impl Component for VoidBrowserPanel<'_> {
+    fn render(self) -> Option<impl Component> {
+        let title = if self.player == self.user_player {
+            strings::void_browser_user_title()
+        } else {
+            strings::void_browser_enemy_title()
+        };
+
+        let cards = void_cards(self.battle, self.player, self.filter, self.sort);
+        let entries = if cards.is_empty() {
+            TextComponent::builder()
+                .text(strings::void_browser_empty().to_string())
+                .typography(Typography::Body2)
+                .text_align(TextAlign::MiddleCenter)
+                .build()
+                .flex_node()
+                .into_iter()
+                .collect()
+        } else {
+            cards.into_iter().filter_map(|card_id| void_card_entry(self.battle, card_id)).collect()
+        };
+
+        Some(
+            PanelComponent::builder()
+                .title(title.to_string())
+                .content(
+                    BoxComponent::builder()
+                        .name("Void Browser Container")
+                        .style(
+                            FlexStyle::builder()
+                                .align_items(FlexAlign::Stretch)
+                                .flex_direction(FlexDirection::Column)
+                                .flex_grow(1)
+                                .build(),
+                        )
+                        .child(button_row(
+                            "Void Browser Filters",
+                            [
+                                VoidBrowserFilter::All,
+                                VoidBrowserFilter::Characters,
+                                VoidBrowserFilter::Events,
+                                VoidBrowserFilter::Reclaimable,
+                            ]
+                            .into_iter()
+                            .filter_map(|filter| {
+                                ButtonComponent::builder()
+                                    .label(filter_label(filter))
+                                    .action(self.address(filter, self.sort))
+                                    .is_primary(filter == self.filter)
+                                    .build()
+                                    .flex_node()
+                            })
+                            .collect(),
+                        ))
+                        .child(button_row(
+                            "Void Browser Sorting",
+                            [VoidBrowserSort::Recent, VoidBrowserSort::Cost, VoidBrowserSort::Name]
+                                .into_iter()
+                                .filter_map(|sort| {
+                                    ButtonComponent::builder()
+                                        .label(sort_label(sort))
+                                        .action(self.address(self.filter, sort))
+                                        .is_primary(sort == self.sort)
+                                        .build()
+                                        .flex_node()
+                                })
+                                .collect(),
+                        ))
+                        .child(
+                            ScrollViewComponent::builder()
+                                .child(
+                                    BoxComponent::builder()
+                                        .name("Void Browser Cards")
+                                        .style(
+                                            FlexStyle::builder()
+                                                .align_items(FlexAlign::Stretch)
+                                                .flex_direction(FlexDirection::Column)
+                                                .flex_grow(1)
+                                                .justify_content(FlexJustify::FlexStart)
+                                                .padding((8, 8, 8, 8))
+                                                .build(),
+                                        )
+                                        .children(entries)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+    }
+}
+
+impl VoidBrowserPanel<'_> {
+    fn address(&self, filter: VoidBrowserFilter, sort: VoidBrowserSort) -> BattleDisplayAction {
+        BattleDisplayAction::OpenPanel(PanelAddress::VoidBrowser {
+            player: self.player,
+            filter,
+            sort,
+        })
+    }
+}
+
+fn void_card_entry(battle: &BattleState, card_id: VoidCardId) -> Option<FlexNode> {
+    let label = strings::void_browser_entry(
+        card_rendering::card_name(battle, card_id.card_id()),
+        card_properties::converted_energy_cost(battle, card_id).0,
+    );
+    BoxComponent::builder()
+        .name(format!("Void Card {:?}", card_id.card_id()))
+        .style(FlexStyle::builder().margin(2).build())
+        .child(
+            ButtonComponent::builder()
+                .label(label.to_string())
+                .action(BattleDisplayAction::InspectCard(card_id.card_id()))
+                .build(),
+        )
+        .build()
+        .flex_node()
+}
+
+fn button_row(name: &str, buttons: Vec<FlexNode>) -> BoxComponent {
+    BoxComponent::builder()
+        .name(name)
+        .style(
+            FlexStyle::builder()
+                .align_items(FlexAlign::Center)
+                .flex_direction(FlexDirection::Row)
+                .justify_content(FlexJustify::Center)
+                .wrap(FlexWrap::Wrap)
+                .padding((4, 8, 4, 8))
+                .build(),
+        )
+        .children(buttons)
+        .build()
+}
+
+fn filter_label(filter: VoidBrowserFilter) -> String {
+    match filter {
+        VoidBrowserFilter::All => strings::void_browser_filter_all(),
+        VoidBrowserFilter::Characters => strings::void_browser_filter_characters(),
+        VoidBrowserFilter::Events => strings::void_browser_filter_events(),
+        VoidBrowserFilter::Reclaimable => strings::void_browser_filter_reclaimable(),
+    }
+    .to_string()
+}
+
+fn sort_label(sort: VoidBrowserSort) -> String {
+    match sort {
+        VoidBrowserSort::Recent => strings::void_browser_sort_recent(),
+        VoidBrowserSort::Cost => strings::void_browser_sort_cost(),
+        VoidBrowserSort::Name => strings::void_browser_sort_name(),
+    }
+    .to_string()
+}
@@ -0,0 +1,91 @@
+use core_data::display_types::Milliseconds;
+use display_data::command::{Command, CommandSequence, ParallelCommandGroup, WaitForInputCommand};
+
+/// An ordered series of [AnimationStep]s, each of which starts once the
+/// previous step has finished.
+///
+/// Converted into a [CommandSequence] for the client via [Self::commands].
+#[derive(Clone, Debug, Default)]
+pub struct AnimationTimeline {
+    steps: Vec<AnimationStep>,
+}
+
+/// A set of commands which animate simultaneously.
+#[derive(Clone, Debug, Default)]
+pub struct AnimationStep {
+    /// Parallel tracks in this step. All tracks start at the same time, and
+    /// the step finishes once every track has finished.
+    pub tracks: Vec<Command>,
+
+    /// Minimum duration of this step, e.g. to hold the result of the step on
+    /// screen before starting the next one.
+    pub duration: Option<Milliseconds>,
+
+    /// If provided, the client waits for the user to continue after this
+    /// step before starting the next one.
+    pub wait_for_input: Option<WaitForInputCommand>,
+}
+
+impl AnimationTimeline {
+    /// Appends a step to the end of this timeline.
+    pub fn push(&mut self, step: AnimationStep) {
+        self.steps.push(step);
+    }
+
+    /// Appends each group of a [CommandSequence] as a step.
+    pub fn extend(&mut self, commands: CommandSequence) {
+        self.steps.extend(
+            commands.groups.into_iter().map(|group| AnimationStep::parallel(group.commands)),
+        );
+    }
+
+    pub fn steps(&self) -> &[AnimationStep] {
+        &self.steps
+    }
+
+    /// Converts this timeline into commands for the client.
+    ///
+    /// Each step becomes a [ParallelCommandGroup], with a
+    /// [Command::Wait] track enforcing its duration. Steps which wait for
+    /// input are followed by a [Command::WaitForInput] group.
+    pub fn commands(self) -> CommandSequence {
+        let mut groups = Vec::new();
+        for step in self.steps {
+            let mut commands = step.tracks;
+            if let Some(duration) = step.duration {
+                commands.push(Command::Wait(duration));
+            }
+            if !commands.is_empty() {
+                groups.push(ParallelCommandGroup { commands });
+            }
+            if let Some(wait) = step.wait_for_input {
+                groups.push(ParallelCommandGroup { commands: vec![Command::WaitForInput(wait)] });
+            }
+        }
+        CommandSequence { groups }
+    }
+}
+
+impl AnimationStep {
+    /// Creates a step which runs the given commands in parallel.
+    pub fn parallel(tracks: Vec<Command>) -> Self {
+        Self { tracks, ..Self::default() }
+    }
+
+    /// Creates a step containing a single command.
+    pub fn single(command: Command) -> Self {
+        Self::parallel(vec![command])
+    }
+
+    pub fn with_duration(mut self, duration: Milliseconds) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Waits for the user to continue after this step, or until `timeout`
+    /// elapses if provided.
+    pub fn wait_for_input(mut self, timeout: Option<Milliseconds>) -> Self {
+        self.wait_for_input = Some(WaitForInputCommand { timeout });
+        self
+    }
+}
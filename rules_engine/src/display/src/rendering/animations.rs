@@ -21,7 +21,8 @@ use crate::core::adapter;
 use crate::core::card_view_context::CardViewContext;
 use crate::core::response_builder::ResponseBuilder;
 use crate::rendering::{
-    apply_card_fx, battle_rendering, card_rendering, labels, modal_effect_prompt_rendering,
+    apply_card_fx, battle_rendering, card_rendering, cinematic_rendering, labels,
+    modal_effect_prompt_rendering,
 };
 
 pub fn render(
@@ -38,7 +39,7 @@ pub fn render(
             if *player != builder.display_for_player() {
                 // Pause so the opponent can see the ability being activated.
                 push_snapshot(builder, snapshot);
-                builder.push(Command::Wait(Milliseconds::new(1000)));
+                builder.push(Command::Wait(builder.animation_duration(1000)));
             }
         }
 
@@ -63,8 +64,8 @@ pub fn render(
                     MoveCardsWithCustomAnimationCommand {
                         animation: MoveCardsCustomAnimation::ShowAtDrawnCardsPosition,
                         cards: card_views,
-                        stagger_interval: Milliseconds::new(500),
-                        pause_duration: Milliseconds::new(300),
+                        stagger_interval: card_move_duration(builder, 500),
+                        pause_duration: card_move_duration(builder, 300),
                         destination: Position::InHand(DisplayPlayer::User),
                         card_trail: None,
                     },
@@ -84,13 +85,16 @@ pub fn render(
 
         BattleAnimation::GainEnergy { player, source } => {
             push_snapshot(builder, snapshot);
-            if let Some(game_object_id) = effect_source_game_object_id(snapshot, *player, source) {
+            if show_moving_effects(builder)
+                && let Some(game_object_id) =
+                    effect_source_game_object_id(snapshot, *player, source)
+            {
                 builder.push(Command::FireProjectile(
                     FireProjectileCommand::builder()
                         .source_id(game_object_id)
                         .target_id(GameObjectId::Avatar(builder.to_display_player(*player)))
                         .projectile(ProjectileAddress::new("Assets/ThirdParty/Hovl Studio/AAA Projectiles Vol 1/Prefabs/Dreamtides/Projectile 6 blue fire.prefab"))
-                        .travel_duration(Milliseconds::new(300))
+                        .travel_duration(builder.animation_duration(300))
                         .fire_sound(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Generic Magic and Impacts/RPG3_Generic_SubtleWhoosh04.wav"))
                         .impact_sound(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Generic Magic and Impacts/RPG3_MagicCute2_Heal02.wav"))
                         .build()
@@ -100,17 +104,24 @@ pub fn render(
 
         BattleAnimation::GainSpark { character_id, .. } => {
             push_snapshot(builder, snapshot);
-            builder.push(Command::DisplayEffect(DisplayEffectCommand {
-                target: GameObjectId::CardId(adapter::client_card_id(character_id.card_id())),
-                effect: effect_assets::gain_spark(),
-                duration: Milliseconds::new(500),
-                scale: FlexVector3::new(5.0, 5.0, 5.0),
-                sound: Some(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Light Magic/RPG3_LightMagic_Buff01.wav")),
-            }));
+            if !builder.skip_non_essential_effects() {
+                builder.push(Command::DisplayEffect(DisplayEffectCommand {
+                    target: GameObjectId::CardId(adapter::client_card_id(character_id.card_id())),
+                    effect: effect_assets::gain_spark(),
+                    duration: builder.animation_duration(500),
+                    scale: FlexVector3::new(5.0, 5.0, 5.0),
+                    sound: Some(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Light Magic/RPG3_LightMagic_Buff01.wav")),
+                }));
+            }
         }
 
         BattleAnimation::Judgment { player, new_score } => {
             push_snapshot(builder, snapshot);
+            if show_moving_effects(builder) {
+                builder.push(cinematic_rendering::judgment_sweep(
+                    builder, snapshot, *player, *new_score,
+                ));
+            }
             builder.push(Command::DisplayJudgment(DisplayJudgmentCommand {
                 player: builder.to_display_player(*player),
                 new_score: *new_score,
@@ -126,9 +137,9 @@ pub fn render(
                 }));
                 builder.push(Command::DisplayEnemyMessage(DisplayEnemyMessageCommand {
                     message: labels::choice_label(*choice),
-                    show_duration: Milliseconds::new(2000),
+                    show_duration: builder.animation_duration(2000),
                 }));
-                builder.push(Command::Wait(Milliseconds::new(1000)));
+                builder.push(Command::Wait(builder.animation_duration(1000)));
             }
         }
 
@@ -148,7 +159,7 @@ pub fn render(
                 // If the played card is no longer on the stack, insert a pause
                 // so it can be seen.
                 push_snapshot(builder, snapshot);
-                builder.push(Command::Wait(Milliseconds::new(1000)));
+                builder.push(Command::Wait(builder.animation_duration(1000)));
             }
         }
 
@@ -173,8 +184,8 @@ pub fn render(
                     MoveCardsWithCustomAnimationCommand {
                         animation: MoveCardsCustomAnimation::ShowAtDrawnCardsPosition,
                         cards: card_views,
-                        stagger_interval: Milliseconds::new(500),
-                        pause_duration: Milliseconds::new(300),
+                        stagger_interval: card_move_duration(builder, 500),
+                        pause_duration: card_move_duration(builder, 300),
                         destination: Position::InVoid(builder.to_display_player(*player)),
                         card_trail: None,
                     },
@@ -192,13 +203,16 @@ pub fn render(
 
         BattleAnimation::ScorePoints { player, source } => {
             push_snapshot(builder, snapshot);
-            if let Some(game_object_id) = effect_source_game_object_id(snapshot, *player, source) {
+            if show_moving_effects(builder)
+                && let Some(game_object_id) =
+                    effect_source_game_object_id(snapshot, *player, source)
+            {
                 builder.push(Command::FireProjectile(
                     FireProjectileCommand::builder()
                         .source_id(game_object_id)
                         .target_id(GameObjectId::Avatar(builder.to_display_player(*player)))
                         .projectile(ProjectileAddress::new("Assets/ThirdParty/Hovl Studio/AAA Projectiles Vol 1/Prefabs/Dreamtides/Projectile 4 yellow arrow.prefab"))
-                        .travel_duration(Milliseconds::new(300))
+                        .travel_duration(builder.animation_duration(300))
                         .fire_sound(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Generic Magic and Impacts/RPG3_Generic_SubtleWhoosh03.wav"))
                         .impact_sound(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/Fire Magic/RPG3_FireMagicCannon_Impact01.wav"))
                         .build()
@@ -214,9 +228,9 @@ pub fn render(
                     modal_effect_prompt_rendering::modal_effect_descriptions(&definition.abilities);
                 builder.push(Command::DisplayEnemyMessage(DisplayEnemyMessageCommand {
                     message: descriptions[choice_index.value()].clone(),
-                    show_duration: Milliseconds::new(2000),
+                    show_duration: builder.animation_duration(2000),
                 }));
-                builder.push(Command::Wait(Milliseconds::new(1000)));
+                builder.push(Command::Wait(builder.animation_duration(1000)));
             }
         }
 
@@ -228,7 +242,7 @@ pub fn render(
             builder.set_active_triggers(triggers.clone());
             push_snapshot(builder, snapshot);
             if !triggers.is_empty() {
-                builder.push(Command::Wait(Milliseconds::new(300)));
+                builder.push(Command::Wait(builder.animation_duration(300)));
             }
         }
 
@@ -241,6 +255,9 @@ pub fn render(
 
         BattleAnimation::StartTurn { player } => {
             push_snapshot(builder, snapshot);
+            if show_moving_effects(builder) {
+                builder.push(cinematic_rendering::turn_start(builder, snapshot, *player));
+            }
             builder.push(Command::DisplayGameMessage(if *player == builder.display_for_player() {
                 GameMessageType::YourTurn
             } else {
@@ -257,6 +274,22 @@ pub fn push_snapshot(builder: &mut ResponseBuilder, snapshot: &BattleState) {
     builder.push_battle_view(battle_rendering::battle_view(builder, snapshot));
 }
 
+/// True if projectiles and cinematics should be shown. These are purely
+/// decorative and involve large amounts of motion.
+pub fn show_moving_effects(builder: &ResponseBuilder) -> bool {
+    !builder.skip_non_essential_effects() && !builder.reduce_motion()
+}
+
+/// Returns the duration to use for staggering and pausing card movement, which
+/// is skipped entirely if the user has requested reduced motion.
+fn card_move_duration(builder: &ResponseBuilder, milliseconds: u32) -> Milliseconds {
+    if builder.reduce_motion() {
+        Milliseconds::new(0)
+    } else {
+        builder.animation_duration(milliseconds)
+    }
+}
+
 /// Returns the game object ID to display as the source of an effect.
 fn effect_source_game_object_id(
     battle: &BattleState,
@@ -4,7 +4,7 @@ use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::card_id::{CardId, CardIdType};
 use battle_state::battle_cards::zone::Zone;
 use battle_state::core::effect_source::EffectSource;
-use core_data::display_types::{EffectAddress, Milliseconds};
+use core_data::display_types::EffectAddress;
 use core_data::types::PlayerName;
 use display_data::card_view::{CardEffects, ClientCardId};
 use display_data::command::{
@@ -19,8 +19,12 @@ use tracing::warn;
 
 use crate::core::adapter;
 use crate::core::response_builder::ResponseBuilder;
+use crate::rendering::animation_timeline::AnimationStep;
 use crate::rendering::animations;
 
+/// Name of the looping effect shown on characters which are anchored.
+const ANCHORED_FX: &str = "anchored-shield";
+
 /// Apply visual & sound effects for a specific card's ability.
 pub fn apply_effect(
     builder: &mut ResponseBuilder,
@@ -45,7 +49,7 @@ pub fn apply_effect(
     let animation_targets = find_target_ids(animation);
     for row in rows {
         match row.effect_type {
-            CardEffectRowType::FireProjectile => {
+            CardEffectRowType::FireProjectile if animations::show_moving_effects(builder) => {
                 fire_projectile(
                     builder,
                     battle,
@@ -62,12 +66,13 @@ pub fn apply_effect(
             CardEffectRowType::ReverseDissolveTargets => {
                 dissolve_targets(builder, row, &animation_targets, true);
             }
-            CardEffectRowType::DisplayEffect => {
+            CardEffectRowType::DisplayEffect if !builder.skip_non_essential_effects() => {
                 display_effect(builder, battle, row, source_id, controller, animation);
             }
             CardEffectRowType::SetCardTrail => {
                 set_card_trail(builder, row, source_id, animation, &animation_targets);
             }
+            CardEffectRowType::FireProjectile | CardEffectRowType::DisplayEffect => {}
         }
     }
     Some(())
@@ -82,10 +87,9 @@ pub fn persistent_card_effects(battle: &BattleState, card_id: CardId) -> CardEff
 pub fn is_anchored(battle: &BattleState, card_id: CardId) -> bool {
     battle
         .ability_state
-        .until_end_of_turn
         .prevent_dissolved
         .iter()
-        .any(|&cid| cid.card_id.card_id() == card_id)
+        .any(|effect| effect.value.card_id.card_id() == card_id)
 }
 
 fn looping_card_effect(battle: &BattleState, card_id: CardId) -> Option<EffectAddress> {
@@ -95,13 +99,20 @@ fn looping_card_effect(battle: &BattleState, card_id: CardId) -> Option<EffectAd
     }
 
     if is_anchored(battle, card_id) {
-        return Some(EffectAddress::new(
-            "Assets/ThirdParty/Hovl Studio/Magic circles/Dreamtides/Looping/Magic shield 4 loop.prefab",
-        ));
+        return named_effect_address(battle, ANCHORED_FX);
     }
     None
 }
 
+/// Returns the effect address of a named effect from the FX table.
+fn named_effect_address(battle: &BattleState, name: &str) -> Option<EffectAddress> {
+    let address = battle.tabula.fx_definitions.get(name).and_then(|fx| fx.effect_address.clone());
+    if address.is_none() {
+        warn!(?name, "Missing effect_address for named effect");
+    }
+    address
+}
+
 fn find_target_ids(animation: &BattleAnimation) -> Vec<ClientCardId> {
     match animation {
         BattleAnimation::SelectedTargetsForCard { targets, .. } => {
@@ -245,6 +256,7 @@ fn dissolve_targets(
             return;
         }
     };
+    let mut dissolves = Vec::new();
     for card_id in animation_targets {
         let mut cmd = DissolveCardCommand::builder()
             .target(card_id.clone())
@@ -257,9 +269,13 @@ fn dissolve_targets(
         if reverse {
             builder.run_with_next_battle_view(Command::DissolveCard(cmd));
         } else {
-            builder.push(Command::DissolveCard(cmd));
+            dissolves.push(Command::DissolveCard(cmd));
         }
     }
+    if !dissolves.is_empty() {
+        // Dissolve all targets simultaneously
+        builder.push_step(AnimationStep::parallel(dissolves));
+    }
 }
 
 fn display_effect(
@@ -295,10 +311,10 @@ fn display_effect(
         }
     };
     let duration = match &row.effect_duration_milliseconds {
-        Some(d) => *d,
+        Some(d) => builder.animation_duration(d.milliseconds_value),
         None => {
             warn!(?row.card_id, "Missing effect_duration_milliseconds for DisplayEffect effect; defaulting to 500ms");
-            Milliseconds::new(500)
+            builder.animation_duration(500)
         }
     };
     let Some(scale) = row.effect_scale else {
@@ -340,7 +356,7 @@ fn set_card_trail(
         }
     };
     let duration = match &row.trail_duration_milliseconds {
-        Some(d) => *d,
+        Some(d) => builder.animation_duration(d.milliseconds_value),
         None => {
             warn!(?row.card_id, "Missing trail_duration_milliseconds for SetCardTrail effect");
             return;
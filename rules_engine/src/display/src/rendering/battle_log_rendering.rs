@@ -0,0 +1,122 @@
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_history::BattleHistoryAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CardIdType;
+use display_data::battle_log_view::{BattleLogEntryView, BattleLogTurnView, BattleLogView};
+use display_data::battle_view::DisplayPlayer;
+use display_data::card_view::ClientCardId;
+use rlf::Phrase;
+use strings::strings;
+
+use crate::core::adapter;
+use crate::core::response_builder::ResponseBuilder;
+use crate::rendering::card_rendering;
+use crate::rendering::hidden_information::{self, Viewer};
+
+/// Renders the actions taken so far in this battle as a log, grouped by turn.
+///
+/// Only actions which are meaningful to a player reviewing the battle are
+/// included, e.g. passing priority and reordering cards in the deck are
+/// omitted. Returns an empty log if this battle does not record its action
+/// history.
+pub fn battle_log_view(builder: &ResponseBuilder, battle: &BattleState) -> BattleLogView {
+    let Some(history) = &battle.action_history else {
+        return BattleLogView::default();
+    };
+
+    let mut turns: Vec<BattleLogTurnView> = Vec::new();
+
+    for history_action in &history.actions {
+        let Some(entry) = log_entry(builder, battle, history_action) else {
+            continue;
+        };
+        match turns.last_mut() {
+            Some(turn) if turn.turn_number == history_action.turn => turn.entries.push(entry),
+            _ => turns.push(BattleLogTurnView {
+                turn_number: history_action.turn,
+                header: strings::battle_log_turn_header(history_action.turn.0 + 1).to_string(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    BattleLogView { turns }
+}
+
+fn log_entry(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+    history_action: &BattleHistoryAction,
+) -> Option<BattleLogEntryView> {
+    let player = builder.to_display_player(history_action.player);
+    let name = match player {
+        DisplayPlayer::User => strings::battle_log_user_name(),
+        DisplayPlayer::Enemy => strings::battle_log_enemy_name(),
+    };
+    let (text, card_id) = match history_action.action {
+        BattleAction::PlayCardFromHand(card_id) => {
+            let card = LoggedCard::new(builder, battle, card_id);
+            (strings::battle_log_played_card(name, card.name), card.client_id)
+        }
+        BattleAction::PlayCardFromVoid(card_id) => {
+            let card = LoggedCard::new(builder, battle, card_id);
+            (strings::battle_log_played_card_from_void(name, card.name), card.client_id)
+        }
+        BattleAction::ActivateAbilityForCharacter(character_id) => {
+            let card = LoggedCard::new(builder, battle, character_id);
+            (strings::battle_log_activated_ability(name, card.name), card.client_id)
+        }
+        BattleAction::SelectCharacterTarget(character_id) => {
+            let card = LoggedCard::new(builder, battle, character_id);
+            (strings::battle_log_targeted(name, card.name), card.client_id)
+        }
+        BattleAction::SelectStackCardTarget(card_id) => {
+            let card = LoggedCard::new(builder, battle, card_id);
+            (strings::battle_log_targeted(name, card.name), card.client_id)
+        }
+        BattleAction::SelectVoidCardTarget(card_id) => {
+            let card = LoggedCard::new(builder, battle, card_id);
+            (strings::battle_log_targeted(name, card.name), card.client_id)
+        }
+        BattleAction::SelectHandCardTarget(card_id) => {
+            let card = LoggedCard::new(builder, battle, card_id);
+            (strings::battle_log_targeted(name, card.name), card.client_id)
+        }
+        BattleAction::SelectEnergyAdditionalCost(energy) => {
+            (strings::battle_log_paid_energy(name, energy.0), None)
+        }
+        BattleAction::EndTurn => (strings::battle_log_ended_turn(name), None),
+        _ => return None,
+    };
+
+    Some(BattleLogEntryView {
+        player,
+        text: text.to_string(),
+        cards: card_id.into_iter().collect(),
+    })
+}
+
+/// A card referenced by a log entry.
+///
+/// Cards which are currently hidden from the viewer are described generically
+/// and not linked, so that the log does not reveal e.g. which card was chosen
+/// from a player's hand.
+struct LoggedCard {
+    name: Phrase,
+    client_id: Option<ClientCardId>,
+}
+
+impl LoggedCard {
+    fn new(builder: &ResponseBuilder, battle: &BattleState, card_id: impl CardIdType) -> Self {
+        let card_id = card_id.card_id();
+        let viewer = Viewer::Player(builder.display_for_player());
+        if hidden_information::is_visible_to(battle, card_id, viewer) {
+            Self {
+                name: strings::battle_log_card_name(card_rendering::card_name(battle, card_id)),
+                client_id: Some(adapter::client_card_id(card_id)),
+            }
+        } else {
+            Self { name: strings::battle_log_hidden_card(), client_id: None }
+        }
+    }
+}
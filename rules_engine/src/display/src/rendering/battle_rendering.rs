@@ -21,9 +21,11 @@ use crate::core::adapter;
 use crate::core::card_view_context::CardViewContext;
 use crate::core::response_builder::ResponseBuilder;
 use crate::display_actions::{display_state, outcome_simulation};
+use crate::rendering::hidden_information::Viewer;
 use crate::rendering::{
-    card_rendering, dreamwell_card_rendering, identity_card_rendering, interface_rendering,
-    modal_effect_prompt_rendering, token_rendering,
+    card_rendering, cinematic_rendering, dreamwell_card_rendering, hidden_information,
+    identity_card_rendering, interface_rendering, modal_effect_prompt_rendering, token_rendering,
+    win_probability,
 };
 
 pub fn run(builder: &mut ResponseBuilder, battle: &BattleState) {
@@ -31,6 +33,9 @@ pub fn run(builder: &mut ResponseBuilder, battle: &BattleState) {
     update_display_state(builder, battle);
 
     if let BattleStatus::GameOver { winner } = battle.status {
+        if let Some(winner) = winner {
+            builder.push(cinematic_rendering::victory(builder, battle, winner));
+        }
         builder.push(Command::DisplayGameMessage(
             if winner == Some(builder.display_for_player()) {
                 GameMessageType::Victory
@@ -71,8 +76,9 @@ pub fn battle_view(builder: &ResponseBuilder, battle: &BattleState) -> BattleVie
         }
     }
 
-    cards.extend(builder.active_triggers().iter().enumerate().map(|(index, trigger)| {
-        token_rendering::trigger_card_view(builder, battle, index, trigger)
+    let triggers = builder.active_triggers();
+    cards.extend(triggers.iter().enumerate().map(|(index, trigger)| {
+        token_rendering::trigger_card_view(builder, battle, index, trigger, triggers)
     }));
 
     let mut token_offset = 100;
@@ -119,7 +125,7 @@ pub fn battle_view(builder: &ResponseBuilder, battle: &BattleState) -> BattleVie
         builder.display_for_player().opponent(),
     ));
 
-    BattleView {
+    let mut view = BattleView {
         id: battle.id,
         user: player_view(
             battle,
@@ -134,6 +140,11 @@ pub fn battle_view(builder: &ResponseBuilder, battle: &BattleState) -> BattleVie
         cards,
         interface: interface_rendering::interface_view(builder, battle),
         arrows: current_arrows(builder, battle),
+        targeting: if builder.is_for_animation() {
+            None
+        } else {
+            card_rendering::targeting_view(builder, battle)
+        },
         preview: if builder.is_for_animation() {
             BattlePreviewState::Pending
         } else {
@@ -146,7 +157,10 @@ pub fn battle_view(builder: &ResponseBuilder, battle: &BattleState) -> BattleVie
             .unwrap_or(BattlePreviewState::None)
         },
         turn_number: battle.turn.turn_id,
-    }
+        win_probability: win_probability::estimate(builder, battle),
+    };
+    hidden_information::scrub(&mut view, battle, Viewer::Player(builder.display_for_player()));
+    view
 }
 
 fn update_display_state(builder: &ResponseBuilder, battle: &BattleState) {
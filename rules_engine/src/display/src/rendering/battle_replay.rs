@@ -0,0 +1,140 @@
+use battle_mutations::actions::apply_battle_action;
+use battle_state::battle::animation_data::AnimationData;
+use battle_state::battle::battle_history::{BattleHistory, BattleHistoryAction};
+use battle_state::battle::battle_state::BattleState;
+use core_data::identifiers::UserId;
+use core_data::types::PlayerName;
+use display_data::command::CommandSequence;
+use state_provider::display_state_provider::DisplayStateProvider;
+
+use crate::rendering::renderer;
+
+/// Number of actions between saved battle states in a [BattleReplay].
+const CHECKPOINT_INTERVAL: usize = 16;
+
+/// Renders a recorded battle as of any point in its action log.
+///
+/// A copy of the battle state is saved every `CHECKPOINT_INTERVAL` actions, so
+/// seeking to an arbitrary index replays at most that many actions, and
+/// stepping forward replays a single action.
+pub struct BattleReplay {
+    actions: Vec<BattleHistoryAction>,
+    checkpoints: Vec<BattleState>,
+    current: BattleState,
+    index: usize,
+}
+
+impl BattleReplay {
+    /// Creates a replay of an action log, which must have been recorded
+    /// starting from the `initial` battle state.
+    pub fn new(initial: BattleState, history: BattleHistory) -> Self {
+        let mut battle = initial;
+        battle.animations = None;
+        battle.action_history = None;
+        let mut checkpoints = vec![battle.clone()];
+        for (index, entry) in history.actions.iter().enumerate() {
+            apply_battle_action::execute_without_tracking_history(
+                &mut battle,
+                entry.player,
+                entry.action,
+            );
+            if (index + 1) % CHECKPOINT_INTERVAL == 0 {
+                checkpoints.push(battle.clone());
+            }
+        }
+
+        let current = checkpoints[0].clone();
+        Self { actions: history.actions, checkpoints, current, index: 0 }
+    }
+
+    /// Number of actions in the action log.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Number of actions which have been applied to the current battle state.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Battle state after the first [Self::index] actions have been applied.
+    pub fn battle(&self) -> &BattleState {
+        &self.current
+    }
+
+    /// Moves to the battle state after the first `index` actions have been
+    /// applied, returning a snapshot of that state.
+    ///
+    /// Panics if `index` is greater than the number of actions in the log.
+    pub fn seek(
+        &mut self,
+        index: usize,
+        player: PlayerName,
+        user_id: UserId,
+        provider: impl DisplayStateProvider + 'static,
+    ) -> CommandSequence {
+        self.move_to(index);
+        renderer::connect_for_player(&self.current, player, user_id, provider, false)
+    }
+
+    /// Applies the next action in the log, returning its animations followed
+    /// by a snapshot of the resulting state.
+    ///
+    /// Returns None if every action has already been applied.
+    pub fn step_forward(
+        &mut self,
+        player: PlayerName,
+        user_id: UserId,
+        provider: impl DisplayStateProvider + 'static,
+    ) -> Option<CommandSequence> {
+        let entry = self.actions.get(self.index)?;
+        self.current.animations = Some(AnimationData::default());
+        apply_battle_action::execute_without_tracking_history(
+            &mut self.current,
+            entry.player,
+            entry.action,
+        );
+        self.index += 1;
+        let commands =
+            renderer::render_updates_for_player(&self.current, player, user_id, provider);
+        self.current.animations = None;
+        Some(commands)
+    }
+
+    /// Moves back to the state before the most recently applied action,
+    /// returning a snapshot of that state.
+    ///
+    /// Returns None if no actions have been applied.
+    pub fn step_backward(
+        &mut self,
+        player: PlayerName,
+        user_id: UserId,
+        provider: impl DisplayStateProvider + 'static,
+    ) -> Option<CommandSequence> {
+        let index = self.index.checked_sub(1)?;
+        Some(self.seek(index, player, user_id, provider))
+    }
+
+    fn move_to(&mut self, index: usize) {
+        assert!(index <= self.actions.len(), "Replay index {index} is past the end of the log");
+        let checkpoint = index / CHECKPOINT_INTERVAL;
+        if index < self.index || self.index < checkpoint * CHECKPOINT_INTERVAL {
+            self.current = self.checkpoints[checkpoint].clone();
+            self.index = checkpoint * CHECKPOINT_INTERVAL;
+        }
+
+        while self.index < index {
+            let entry = &self.actions[self.index];
+            apply_battle_action::execute_without_tracking_history(
+                &mut self.current,
+                entry.player,
+                entry.action,
+            );
+            self.index += 1;
+        }
+    }
+}
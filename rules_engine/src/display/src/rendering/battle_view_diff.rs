@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+
+use display_data::battle_view::BattleView;
+use display_data::battle_view_delta::{
+    BattleViewDelta, CardStatusView, CardViewDelta, RevealedCardDelta,
+};
+use display_data::card_view::{CardView, ClientCardId};
+use serde::Serialize;
+
+/// Returns the changes required to turn `previous` into `current`.
+///
+/// Returns None if a full snapshot of `current` should be sent instead,
+/// either because the views are for different battles or because more than
+/// half of the cards in the battle changed.
+pub fn diff(previous: &BattleView, current: &BattleView) -> Option<BattleViewDelta> {
+    if previous.id != current.id {
+        return None;
+    }
+
+    let previous_cards =
+        previous.cards.iter().map(|card| (&card.id, card)).collect::<HashMap<_, _>>();
+    let mut cards = Vec::new();
+    let mut changed_cards = Vec::new();
+    for card in &current.cards {
+        let Some(previous_card) = previous_cards.get(&card.id) else {
+            cards.push(card.clone());
+            continue;
+        };
+        match card_change(previous_card, card) {
+            CardChange::Unchanged => {}
+            CardChange::Delta(delta) => changed_cards.push(delta),
+            CardChange::Replaced => cards.push(card.clone()),
+        }
+    }
+
+    let current_ids = current.cards.iter().map(|card| &card.id).collect::<HashSet<_>>();
+    let removed_cards = previous
+        .cards
+        .iter()
+        .filter(|card| !current_ids.contains(&card.id))
+        .map(|card| card.id.clone())
+        .collect::<Vec<_>>();
+    if (cards.len() + changed_cards.len() + removed_cards.len()) * 2 > current.cards.len() {
+        return None;
+    }
+
+    Some(BattleViewDelta {
+        id: current.id,
+        user: changed(&previous.user, &current.user),
+        enemy: changed(&previous.enemy, &current.enemy),
+        cards,
+        changed_cards,
+        removed_cards,
+        interface: changed(&previous.interface, &current.interface),
+        arrows: changed(&previous.arrows, &current.arrows),
+        preview: changed(&previous.preview, &current.preview),
+        targeting: current.targeting.clone(),
+        turn_number: current.turn_number,
+        win_probability: current.win_probability,
+    })
+}
+
+/// Applies `delta` to the battle view it was computed from.
+///
+/// Cards added by the delta are placed after existing cards, so card order
+/// may differ from the view the delta was computed for.
+pub fn apply(view: &mut BattleView, delta: BattleViewDelta) {
+    view.id = delta.id;
+    if let Some(user) = delta.user {
+        view.user = user;
+    }
+    if let Some(enemy) = delta.enemy {
+        view.enemy = enemy;
+    }
+
+    view.cards.retain(|card| !delta.removed_cards.contains(&card.id));
+    for change in delta.changed_cards {
+        if let Some(card) = find_card(&mut view.cards, &change.id) {
+            apply_card_delta(card, change);
+        }
+    }
+    for card in delta.cards {
+        match find_card(&mut view.cards, &card.id) {
+            Some(existing) => *existing = card,
+            None => view.cards.push(card),
+        }
+    }
+
+    if let Some(interface) = delta.interface {
+        view.interface = interface;
+    }
+    if let Some(arrows) = delta.arrows {
+        view.arrows = arrows;
+    }
+    if let Some(preview) = delta.preview {
+        view.preview = preview;
+    }
+    view.targeting = delta.targeting;
+    view.turn_number = delta.turn_number;
+    view.win_probability = delta.win_probability;
+}
+
+/// Applies the changes in `delta` to a single card.
+pub fn apply_card_delta(card: &mut CardView, delta: CardViewDelta) {
+    if let Some(position) = delta.position {
+        card.position = position;
+    }
+    match delta.revealed {
+        Some(RevealedCardDelta::Hidden) => card.revealed = None,
+        Some(RevealedCardDelta::Revealed(revealed)) => card.revealed = Some(*revealed),
+        None => {}
+    }
+    if let Some(status) = delta.status {
+        card.revealed_to_opponents = status.revealed_to_opponents;
+        card.card_facing = status.card_facing;
+        card.backless = status.backless;
+    }
+}
+
+enum CardChange {
+    Unchanged,
+    Delta(CardViewDelta),
+    Replaced,
+}
+
+/// Describes how a card changed between two views.
+///
+/// The creation position and sound of a card only apply when the card does
+/// not already exist, so they are ignored for cards present in both views.
+fn card_change(previous: &CardView, current: &CardView) -> CardChange {
+    if previous.destroy_position != current.destroy_position || previous.prefab != current.prefab {
+        return CardChange::Replaced;
+    }
+
+    let position = (previous.position != current.position).then(|| current.position.clone());
+    let revealed =
+        (!same_value(&previous.revealed, &current.revealed)).then(|| match &current.revealed {
+            Some(revealed) => RevealedCardDelta::Revealed(Box::new(revealed.clone())),
+            None => RevealedCardDelta::Hidden,
+        });
+    let status = Some(card_status(current)).filter(|status| *status != card_status(previous));
+    if position.is_none() && revealed.is_none() && status.is_none() {
+        return CardChange::Unchanged;
+    }
+    CardChange::Delta(CardViewDelta { id: current.id.clone(), position, revealed, status })
+}
+
+fn card_status(card: &CardView) -> CardStatusView {
+    CardStatusView {
+        revealed_to_opponents: card.revealed_to_opponents,
+        card_facing: card.card_facing.clone(),
+        backless: card.backless,
+    }
+}
+
+fn find_card<'a>(cards: &'a mut [CardView], id: &ClientCardId) -> Option<&'a mut CardView> {
+    cards.iter_mut().find(|card| card.id == *id)
+}
+
+/// Returns a copy of `current` if it differs from `previous`.
+fn changed<T: Clone + Serialize>(previous: &T, current: &T) -> Option<T> {
+    (!same_value(previous, current)).then(|| current.clone())
+}
+
+/// Compares two display values by their serialized form, since most display
+/// types do not implement `PartialEq`.
+fn same_value<T: Serialize>(a: &T, b: &T) -> bool {
+    matches!((serde_json::to_value(a), serde_json::to_value(b)), (Ok(a), Ok(b)) if a == b)
+}
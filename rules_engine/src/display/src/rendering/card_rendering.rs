@@ -20,9 +20,12 @@ use core_data::display_color::{self, DisplayColor};
 use core_data::display_types::SpriteAddress;
 use core_data::identifiers::AbilityNumber;
 use core_data::types::{CardFacing, PlayerName};
+use display_data::battle_view::TargetingView;
 use display_data::card_view::{
-    CardActions, CardPrefab, CardView, DisplayImage, InfoZoomData, InfoZoomIcon, RevealedCardView,
+    CardActions, CardFrame, CardPrefab, CardView, DisplayImage, InfoZoomData, InfoZoomIcon,
+    RevealedCardView,
 };
+use display_data::command::{ArrowStyle, DisplayArrow};
 use masonry::flex_enums::FlexDirection;
 use masonry::flex_style::FlexStyle;
 use parser::serializer::ability_serializer;
@@ -39,8 +42,8 @@ use crate::display_actions::outcome_simulation;
 use crate::rendering::positions::ControllerAndZone;
 use crate::rendering::supplemental_card_info::SupplementalCardInfo;
 use crate::rendering::{
-    ability_help_text, apply_card_fx, card_display_state, modal_effect_prompt_rendering, positions,
-    rlf_helper,
+    ability_help_text, apply_card_fx, card_display_state, modal_effect_prompt_rendering,
+    playability_text, positions, rlf_helper, supplemental_card_info,
 };
 
 pub fn card_view(builder: &ResponseBuilder, context: &CardViewContext) -> CardView {
@@ -75,8 +78,15 @@ pub fn card_view(builder: &ResponseBuilder, context: &CardViewContext) -> CardVi
 }
 
 /// Returns the sprite address for a card's image.
+///
+/// Uses the image of the card's selected variant if it has one which exists
+/// in tabula, and otherwise the base card image.
 pub fn card_image(battle: &BattleState, card_id: CardId) -> SpriteAddress {
-    card::get_definition(battle, card_id).image.clone()
+    let definition = card::get_definition(battle, card_id);
+    definition
+        .variant
+        .and_then(|variant| battle.tabula.card_variant(definition.base_card_id, variant))
+        .map_or_else(|| definition.image.clone(), |variant| variant.image.clone())
 }
 
 /// Returns the displayed name for a card.
@@ -111,34 +121,41 @@ pub fn rules_text(_builder: &ResponseBuilder, battle: &BattleState, card_id: Car
         && let StackCardAdditionalCostsPaid::Energy(energy) = &stack_item.additional_costs_paid
     {
         return format!(
-            "{} <b><color=\"blue\">{}</color></b>",
-            formatted,
-            strings::card_rules_text_energy_paid(energy.0)
+            "{formatted} {}",
+            strings::card_rules_text_annotation(strings::card_rules_text_energy_paid(energy.0))
         );
     }
 
     if is_on_stack_from_void(battle, card_id) {
         return format!(
-            "{formatted} <b><color=\"blue\">{}</color></b>",
-            strings::card_rules_text_reclaimed()
+            "{formatted} {}",
+            strings::card_rules_text_annotation(strings::card_rules_text_reclaimed())
         );
     }
 
     if apply_card_fx::is_anchored(battle, card_id) {
         return format!(
-            "{formatted} <b><color=\"blue\">{}</color></b>",
-            strings::card_rules_text_anchored()
+            "{formatted} {}",
+            strings::card_rules_text_annotation(strings::card_rules_text_anchored())
         );
     }
 
     formatted
 }
 
-/// Builds info zoom data for a card including targeting icons and
-/// supplemental help text.
-pub fn build_info_zoom_data(battle: &BattleState, card_id: CardId) -> Option<InfoZoomData> {
+/// Builds info zoom data for a card including targeting icons, supplemental
+/// help text, and related cards.
+///
+/// If `unplayable_reason` is provided, it is shown before any keyword help
+/// text.
+pub fn build_info_zoom_data(
+    battle: &BattleState,
+    card_id: CardId,
+    unplayable_reason: Option<String>,
+) -> Option<InfoZoomData> {
     let targeting_icons = get_targeting_icons(battle, card_id);
-    let supplemental_texts = ability_help_text::help_texts(battle, card_id);
+    let mut supplemental_texts: Vec<String> = unplayable_reason.into_iter().collect();
+    supplemental_texts.extend(ability_help_text::help_texts(battle, card_id));
 
     let supplemental_info = if supplemental_texts.is_empty() {
         None
@@ -162,15 +179,55 @@ pub fn build_info_zoom_data(battle: &BattleState, card_id: CardId) -> Option<Inf
         }
     };
 
-    if targeting_icons.is_empty() && supplemental_info.is_none() {
+    let related_cards = supplemental_card_info::related_cards(battle, card_id);
+    if targeting_icons.is_empty() && supplemental_info.is_none() && related_cards.is_empty() {
         None
     } else {
-        Some(InfoZoomData { supplemental_card_info: supplemental_info, icons: targeting_icons })
+        Some(InfoZoomData {
+            supplemental_card_info: supplemental_info,
+            icons: targeting_icons,
+            related_cards,
+        })
     }
 }
 
+/// Returns the cards which are legal targets for the pending prompt, along
+/// with arrows from the prompt's source card to each target.
+///
+/// Returns None if the player is not currently choosing card targets.
+pub fn targeting_view(builder: &ResponseBuilder, battle: &BattleState) -> Option<TargetingView> {
+    let player = builder.act_for_player();
+    let targets = legal_targets(&legal_actions::compute(battle, player));
+    if targets.is_empty() {
+        return None;
+    }
+
+    let source = battle.prompts.front().and_then(|prompt| prompt.source.card_id());
+    let arrows = source
+        .map(|source| {
+            targets
+                .iter()
+                .map(|&target| DisplayArrow {
+                    source: adapter::card_game_object_id(source),
+                    target: adapter::card_game_object_id(target),
+                    color: if card_properties::controller(battle, target) == player {
+                        ArrowStyle::Green
+                    } else {
+                        ArrowStyle::Red
+                    },
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(TargetingView {
+        source: source.map(adapter::client_card_id),
+        legal_targets: targets.into_iter().map(adapter::client_card_id).collect(),
+        arrows,
+    })
+}
+
 /// Serializes abilities using the ability serializer and formats with RLF.
-fn serialize_abilities_text(abilities: &[Ability]) -> String {
+pub fn serialize_abilities_text(abilities: &[Ability]) -> String {
     let line_height_25 = "<line-height=25%>";
     let end_line_height = "</line-height>";
 
@@ -181,6 +238,23 @@ fn serialize_abilities_text(abilities: &[Ability]) -> String {
         .join(&format!("\n{line_height_25}\n{end_line_height}"))
 }
 
+/// Returns the displayed type line for a card definition, without a fast
+/// indicator.
+pub fn definition_card_type(definition: &CardDefinition) -> String {
+    if let Some(subtype) = definition.card_subtype {
+        strings::subtype(rlf_helper::subtype_phrase(subtype)).to_string()
+    } else {
+        match definition.card_type {
+            CardType::Character => strings::card_type_character(),
+            CardType::Event => strings::card_type_event(),
+            CardType::Dreamsign => strings::card_type_dreamsign(),
+            CardType::Dreamcaller => strings::card_type_dreamcaller(),
+            CardType::Dreamwell => strings::card_type_dreamwell(),
+        }
+        .to_string()
+    }
+}
+
 fn revealed_card_view(builder: &ResponseBuilder, context: &CardViewContext) -> RevealedCardView {
     let battle = context.battle();
     let card_id = context.card_id();
@@ -193,6 +267,12 @@ fn revealed_card_view(builder: &ResponseBuilder, context: &CardViewContext) -> R
     let can_play = play_action.is_some();
     let (selection_color, selection_action) =
         outline_and_selection_action(battle, &legal_actions, card_id, builder.act_for_player());
+    let selection_preview = match &selection_action {
+        Some(GameAction::BattleAction(action)) => {
+            outcome_simulation::prompt_option_preview(battle, builder.act_for_player(), *action)
+        }
+        _ => None,
+    };
     let ControllerAndZone { controller, .. } = positions::controller_and_zone(battle, card_id);
 
     // Get the cost to display
@@ -221,12 +301,18 @@ fn revealed_card_view(builder: &ResponseBuilder, context: &CardViewContext) -> R
             None if can_play => Some(display_color::GREEN),
             _ => None,
         },
-        info_zoom_data: build_info_zoom_data(battle, card_id),
+        info_zoom_data: build_info_zoom_data(
+            battle,
+            card_id,
+            playability_text::unplayable_reason_text(battle, builder.act_for_player(), card_id),
+        ),
         is_fast: false,
+        is_legal_target: is_legal_target(&legal_actions, card_id),
         actions: CardActions {
             can_play: play_action.map(GameAction::BattleAction),
             can_select_order: can_select_order_action(&legal_actions, card_id),
             on_click: selection_action,
+            on_click_effect_preview: selection_preview,
             play_effect_preview: play_action.map(|play_action| {
                 outcome_simulation::action_effect_preview(
                     battle,
@@ -237,6 +323,8 @@ fn revealed_card_view(builder: &ResponseBuilder, context: &CardViewContext) -> R
             ..Default::default()
         },
         effects: apply_card_fx::persistent_card_effects(battle, card_id),
+        stack_group: None,
+        frame: CardFrame::Standard,
     }
 }
 
@@ -303,25 +391,41 @@ fn outline_and_selection_action(
     (None, None)
 }
 
+/// Returns the cards which can be selected as targets for the pending prompt.
+fn legal_targets(legal_actions: &LegalActions) -> Vec<CardId> {
+    match legal_actions {
+        LegalActions::SelectCharacterPrompt { valid } => {
+            valid.iter().map(CardIdType::card_id).collect()
+        }
+        LegalActions::SelectStackCardPrompt { valid } => {
+            valid.iter().map(CardIdType::card_id).collect()
+        }
+        LegalActions::SelectVoidCardPrompt { valid, .. } => {
+            valid.iter().map(CardIdType::card_id).collect()
+        }
+        LegalActions::SelectHandCardPrompt { valid, .. } => {
+            valid.iter().map(CardIdType::card_id).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn is_legal_target(legal_actions: &LegalActions, card_id: CardId) -> bool {
+    match legal_actions {
+        LegalActions::SelectCharacterPrompt { valid } => valid.contains(CharacterId(card_id)),
+        LegalActions::SelectStackCardPrompt { valid } => valid.contains(StackCardId(card_id)),
+        LegalActions::SelectVoidCardPrompt { valid, .. } => valid.contains(VoidCardId(card_id)),
+        LegalActions::SelectHandCardPrompt { valid, .. } => valid.contains(HandCardId(card_id)),
+        _ => false,
+    }
+}
+
 fn can_select_order_action(legal_actions: &LegalActions, card_id: CardId) -> Option<CardId> {
     if let LegalActions::SelectDeckCardOrder { .. } = legal_actions { Some(card_id) } else { None }
 }
 
 fn card_type(battle: &BattleState, card_id: CardId) -> String {
-    let definition = card::get_definition(battle, card_id);
-    let result = if let Some(subtype) = definition.card_subtype {
-        strings::subtype(rlf_helper::subtype_phrase(subtype)).to_string()
-    } else {
-        match definition.card_type {
-            CardType::Character => strings::card_type_character(),
-            CardType::Event => strings::card_type_event(),
-            CardType::Dreamsign => strings::card_type_dreamsign(),
-            CardType::Dreamcaller => strings::card_type_dreamcaller(),
-            CardType::Dreamwell => strings::card_type_dreamwell(),
-        }
-        .to_string()
-    };
-
+    let result = definition_card_type(&card::get_definition(battle, card_id));
     if card_properties::is_fast(battle, card_id) { format!("\u{f0e7} {result}") } else { result }
 }
 
@@ -399,11 +503,14 @@ fn get_targeting_icons(battle: &BattleState, card_id: CardId) -> Vec<InfoZoomIco
                 };
 
                 for target_card_id in target_card_ids {
-                    icons.insert(target_card_id, InfoZoomIcon {
-                        card_id: adapter::client_card_id(target_card_id),
-                        icon: icon::CHEVRON_UP.to_string(),
-                        color: targeting_color(battle, current_player, target_card_id),
-                    });
+                    icons.insert(
+                        target_card_id,
+                        InfoZoomIcon {
+                            card_id: adapter::client_card_id(target_card_id),
+                            icon: icon::CHEVRON_UP.to_string(),
+                            color: targeting_color(battle, current_player, target_card_id),
+                        },
+                    );
                 }
             }
         }
@@ -438,21 +545,27 @@ fn get_targeting_icons(battle: &BattleState, card_id: CardId) -> Vec<InfoZoomIco
         };
 
         for target_card_id in target_card_ids {
-            icons.insert(target_card_id, InfoZoomIcon {
-                card_id: adapter::client_card_id(target_card_id),
-                icon: icon::CHEVRON_UP.to_string(),
-                color: targeting_color(battle, current_player, target_card_id),
-            });
+            icons.insert(
+                target_card_id,
+                InfoZoomIcon {
+                    card_id: adapter::client_card_id(target_card_id),
+                    icon: icon::CHEVRON_UP.to_string(),
+                    color: targeting_color(battle, current_player, target_card_id),
+                },
+            );
         }
     } else if let Some(stack_card) = battle.cards.stack_item(StackCardId(card_id))
         && stack_card.targets.is_some()
         && valid_target_queries::valid_targets(battle, stack_card.targets.as_ref()).is_none()
     {
-        icons.insert(card_id, InfoZoomIcon {
-            card_id: adapter::client_card_id(card_id),
-            icon: icon::XMARK.to_string(),
-            color: display_color::RED_500,
-        });
+        icons.insert(
+            card_id,
+            InfoZoomIcon {
+                card_id: adapter::client_card_id(card_id),
+                icon: icon::XMARK.to_string(),
+                color: display_color::RED_500,
+            },
+        );
     }
 
     icons.into_values().collect()
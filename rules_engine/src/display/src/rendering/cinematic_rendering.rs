@@ -0,0 +1,59 @@
+use battle_state::battle::battle_state::BattleState;
+use core_data::numerics::Points;
+use core_data::types::PlayerName;
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::{CinematicType, Command, DisplayCinematicCommand};
+use strings::strings;
+
+use crate::core::response_builder::ResponseBuilder;
+
+/// Returns a command to show the turn start banner for `player`.
+pub fn turn_start(builder: &ResponseBuilder, battle: &BattleState, player: PlayerName) -> Command {
+    let score = battle.players.player(player).points;
+    cinematic(builder, battle, CinematicType::TurnStart, player, Points(0), score)
+}
+
+/// Returns a command to show the judgment sweep for `player`, whose score
+/// changes to `new_score` if they gained points from the judgment.
+///
+/// `battle` should reflect the state before points were gained.
+pub fn judgment_sweep(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+    player: PlayerName,
+    new_score: Option<Points>,
+) -> Command {
+    let previous = battle.players.player(player).points;
+    let score = new_score.unwrap_or(previous);
+    let points_delta = Points(score.0.saturating_sub(previous.0));
+    cinematic(builder, battle, CinematicType::JudgmentSweep, player, points_delta, score)
+}
+
+/// Returns a command to show the victory cinematic for `winner`.
+pub fn victory(builder: &ResponseBuilder, battle: &BattleState, winner: PlayerName) -> Command {
+    let score = battle.players.player(winner).points;
+    cinematic(builder, battle, CinematicType::Victory, winner, Points(0), score)
+}
+
+fn cinematic(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+    cinematic: CinematicType,
+    player: PlayerName,
+    points_delta: Points,
+    score: Points,
+) -> Command {
+    let display_player = builder.to_display_player(player);
+    let player_name = match display_player {
+        DisplayPlayer::User => strings::cinematic_user_name(),
+        DisplayPlayer::Enemy => strings::cinematic_enemy_name(),
+    };
+    Command::DisplayCinematic(DisplayCinematicCommand {
+        cinematic,
+        player: display_player,
+        player_name: player_name.to_string(),
+        turn_number: battle.turn.turn_id,
+        points_delta,
+        score,
+    })
+}
@@ -0,0 +1,55 @@
+use database::collection::{Collection, UnlockSource};
+use display_data::card_view::DisplayImage;
+use display_data::collection_view::{CollectionCardView, CollectionView};
+use display_data::command::{Command, UpdateCollectionCommand};
+use strings::strings;
+use tabula_data::tabula::Tabula;
+
+/// Returns a command to display the given [Collection] in the collection
+/// browser.
+pub fn update_collection(tabula: &Tabula, collection: &Collection) -> Command {
+    Command::UpdateCollection(Box::new(UpdateCollectionCommand {
+        collection: collection_view(tabula, collection),
+    }))
+}
+
+/// Renders a [CollectionView] for the given [Collection].
+///
+/// Cards without a definition in the [Tabula] are omitted.
+pub fn collection_view(tabula: &Tabula, collection: &Collection) -> CollectionView {
+    let mut cards = collection
+        .cards
+        .iter()
+        .filter(|(_, owned)| owned.count > 0)
+        .filter_map(|(&card_id, owned)| {
+            let definition = tabula.cards.get(&card_id)?;
+            let variant = owned
+                .selected_variant
+                .and_then(|variant_id| tabula.card_variant(card_id, variant_id));
+            Some(CollectionCardView {
+                card_id,
+                name: definition.displayed_name.clone(),
+                image: DisplayImage::Sprite(
+                    variant.map_or_else(|| definition.image.clone(), |v| v.image.clone()),
+                ),
+                is_foil: variant.is_some_and(|v| v.is_foil),
+                count: owned.count,
+                unlocked_by: owned
+                    .unlock_sources
+                    .iter()
+                    .map(|&source| unlocked_by(source))
+                    .collect(),
+            })
+        })
+        .collect::<Vec<_>>();
+    cards.sort_by(|a, b| a.name.cmp(&b.name));
+    CollectionView { cards, total_cards: collection.total_cards() }
+}
+
+fn unlocked_by(source: UnlockSource) -> String {
+    match source {
+        UnlockSource::StartingCollection => strings::unlocked_starting_collection().to_string(),
+        UnlockSource::QuestReward(_) => strings::unlocked_quest_reward().to_string(),
+        UnlockSource::Debug => strings::unlocked_debug().to_string(),
+    }
+}
@@ -3,7 +3,7 @@ use battle_state::battle::battle_turn_phase::BattleTurnPhase;
 use battle_state::battle_cards::dreamwell_data::{BattleDreamwellCardId, DreamwellCard};
 use core_data::types::CardFacing;
 use display_data::card_view::{
-    CardActions, CardEffects, CardPrefab, CardView, DisplayImage, RevealedCardView,
+    CardActions, CardEffects, CardFrame, CardPrefab, CardView, DisplayImage, RevealedCardView,
 };
 use display_data::object_position::{ObjectPosition, Position};
 use parser::serializer::ability_serializer;
@@ -61,8 +61,11 @@ fn dreamwell_card_view(
             outline_color: None,
             info_zoom_data: None,
             is_fast: false,
+            is_legal_target: false,
             actions: CardActions::default(),
             effects: CardEffects::default(),
+            stack_group: None,
+            frame: CardFrame::Standard,
         }),
         revealed_to_opponents: true,
         card_facing: CardFacing::FaceUp,
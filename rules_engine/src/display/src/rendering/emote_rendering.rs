@@ -0,0 +1,28 @@
+use action_data::emote_data::Emote;
+use core_data::display_types::Milliseconds;
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::{Command, DisplayEmoteCommand};
+use strings::strings;
+
+/// Returns a command to show an emote sent by the given player.
+pub fn display_emote(player: DisplayPlayer, emote: Emote) -> Command {
+    Command::DisplayEmote(DisplayEmoteCommand {
+        player,
+        emote,
+        text: emote_text(emote),
+        show_duration: Milliseconds::new(3000),
+    })
+}
+
+/// Returns the localized phrase for an emote.
+pub fn emote_text(emote: Emote) -> String {
+    match emote {
+        Emote::Greetings => strings::emote_greetings(),
+        Emote::WellPlayed => strings::emote_well_played(),
+        Emote::Thanks => strings::emote_thanks(),
+        Emote::Oops => strings::emote_oops(),
+        Emote::Thinking => strings::emote_thinking(),
+        Emote::GoodGame => strings::emote_good_game(),
+    }
+    .to_string()
+}
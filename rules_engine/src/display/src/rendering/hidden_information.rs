@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CardId;
+use core_data::types::PlayerName;
+use display_data::battle_view::{BattlePreviewState, BattleView, InterfaceView};
+use display_data::card_view::CardActions;
+use display_data::object_position::Position;
+
+use crate::core::adapter;
+use crate::rendering::card_display_state;
+
+/// Identifies who a battle view is being rendered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Viewer {
+    /// A participant in the battle.
+    Player(PlayerName),
+
+    /// An observer of the battle, who can only see public information.
+    Spectator,
+}
+
+/// Removes information from a battle view which the viewer should not be able
+/// to see.
+///
+/// Card rendering already avoids revealing hidden cards, but this pass is
+/// applied to every battle view as a final check so that new rendering code
+/// cannot leak hidden information by accident. It:
+///
+/// - Removes the revealed face of cards which are hidden from the viewer.
+/// - Gives every hidden card in a deck the same sorting key, so the order of a
+///   shuffled deck cannot be inferred.
+/// - Removes targeting and previews for prompts the viewer is not answering.
+/// - Removes all interface controls and card actions for spectators.
+pub fn scrub(view: &mut BattleView, battle: &BattleState, viewer: Viewer) {
+    let hidden = battle
+        .cards
+        .all_cards()
+        .filter(|&card_id| !is_visible_to(battle, card_id, viewer))
+        .map(adapter::client_card_id)
+        .collect::<HashSet<_>>();
+    for card in view.cards.iter_mut().filter(|card| hidden.contains(&card.id)) {
+        card.revealed = None;
+        if matches!(card.position.position, Position::InDeck(_)) {
+            card.position.sorting_key = 0;
+        }
+    }
+
+    if !is_answering_prompt(battle, viewer) {
+        view.targeting = None;
+        if matches!(view.preview, BattlePreviewState::Active(_)) {
+            view.preview = BattlePreviewState::None;
+        }
+    }
+
+    if viewer == Viewer::Spectator {
+        view.interface = InterfaceView::default();
+        for revealed in view.cards.iter_mut().filter_map(|card| card.revealed.as_mut()) {
+            revealed.actions = CardActions::default();
+            revealed.is_legal_target = false;
+        }
+    }
+}
+
+/// Returns true if the card's face can be seen by the viewer.
+///
+/// Spectators can only see cards which are revealed to both players.
+pub fn is_visible_to(battle: &BattleState, card_id: CardId, viewer: Viewer) -> bool {
+    match viewer {
+        Viewer::Player(player) => card_display_state::is_revealed_to(battle, card_id, player),
+        Viewer::Spectator => [PlayerName::One, PlayerName::Two]
+            .into_iter()
+            .all(|player| card_display_state::is_revealed_to(battle, card_id, player)),
+    }
+}
+
+fn is_answering_prompt(battle: &BattleState, viewer: Viewer) -> bool {
+    match (battle.prompts.front(), viewer) {
+        (Some(prompt), Viewer::Player(player)) => prompt.player == player,
+        (None, Viewer::Player(_)) => true,
+        (_, Viewer::Spectator) => false,
+    }
+}
@@ -0,0 +1,123 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::game_action_data::GameAction;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_queries::legal_action_queries::legal_actions_data::ForPlayer;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CardIdType;
+use battle_state::battle_player::battle_player_state::PlayerType;
+use battle_state::battle_trace::decision_explanation::ScoredAction;
+use battle_state::prompt_types::prompt_data::PromptType;
+use masonry::dimension::SafeAreaInsets;
+use masonry::flex_enums::FlexPosition;
+use masonry::flex_style::FlexStyle;
+use strings::strings;
+use ui_components::box_component::BoxComponent;
+use ui_components::button_component::ButtonComponent;
+
+use crate::core::response_builder::ResponseBuilder;
+use crate::display_actions::display_state;
+use crate::rendering::interface_message::{AnchorPosition, InterfaceMessage};
+use crate::rendering::{card_rendering, labels, win_probability};
+
+/// Renders the button used to request a suggested action.
+///
+/// Hints are only offered in battles against an AI opponent, when the user
+/// has a choice between multiple legal actions.
+pub fn hint_button(builder: &ResponseBuilder, battle: &BattleState) -> Option<BoxComponent> {
+    let player = builder.act_for_player();
+    if !matches!(battle.players.player(player.opponent()).player_type, PlayerType::Agent(_))
+        || legal_actions::compute(battle, player).len() <= 1
+    {
+        return None;
+    }
+
+    let searching = display_state::get_hint(builder).is_some_and(|hint| hint.suggestion.is_none());
+    let (label, action) = if searching {
+        (strings::hint_button_searching().to_string(), GameAction::NoOp)
+    } else {
+        (strings::hint_button().to_string(), BattleDisplayAction::RequestHint.into())
+    };
+
+    Some(
+        BoxComponent::builder()
+            .name("Hint Button Container")
+            .style(
+                FlexStyle::builder()
+                    .position(FlexPosition::Absolute)
+                    .inset(SafeAreaInsets::builder().bottom(8).left(8).build())
+                    .build(),
+            )
+            .child(ButtonComponent::builder().label(label).action(action).build())
+            .build(),
+    )
+}
+
+/// Renders a one-line explanation of the most recently suggested action.
+///
+/// Returns None if no suggestion is available or if the suggested action is
+/// no longer legal.
+pub fn hint_message(builder: &ResponseBuilder, battle: &BattleState) -> Option<InterfaceMessage> {
+    let suggestion = display_state::get_hint(builder)?.suggestion?;
+    if !legal_actions::compute(battle, builder.act_for_player())
+        .contains(suggestion.action, ForPlayer::Human)
+    {
+        return None;
+    }
+
+    Some(
+        InterfaceMessage::builder()
+            .text(rationale(battle, suggestion))
+            .anchor_position(AnchorPosition::Bottom)
+            .temporary(false)
+            .build(),
+    )
+}
+
+fn rationale(battle: &BattleState, suggestion: ScoredAction) -> String {
+    let win_percent =
+        (win_probability::from_reward(suggestion.average_reward) * 100.0).round() as u32;
+    strings::hint_rationale(action_description(battle, suggestion.action), win_percent).to_string()
+}
+
+fn action_description(battle: &BattleState, action: BattleAction) -> String {
+    let description = match action {
+        BattleAction::PlayCardFromHand(card_id) => {
+            strings::hint_play_card(card_rendering::card_name(battle, card_id.card_id()))
+        }
+        BattleAction::PlayCardFromVoid(card_id) => {
+            strings::hint_play_card(card_rendering::card_name(battle, card_id.card_id()))
+        }
+        BattleAction::ActivateAbilityForCharacter(character_id) => strings::hint_activate_ability(
+            card_rendering::card_name(battle, character_id.card_id()),
+        ),
+        BattleAction::SelectCharacterTarget(character_id) => {
+            strings::hint_select_target(card_rendering::card_name(battle, character_id.card_id()))
+        }
+        BattleAction::SelectStackCardTarget(card_id) => {
+            strings::hint_select_target(card_rendering::card_name(battle, card_id.card_id()))
+        }
+        BattleAction::SelectVoidCardTarget(card_id) => {
+            strings::hint_select_target(card_rendering::card_name(battle, card_id.card_id()))
+        }
+        BattleAction::SelectHandCardTarget(card_id) => {
+            strings::hint_select_target(card_rendering::card_name(battle, card_id.card_id()))
+        }
+        BattleAction::SelectPromptChoice(index) => {
+            let choice = battle.prompts.front().and_then(|prompt| match &prompt.prompt_type {
+                PromptType::Choose { choices } => choices.get(index),
+                _ => None,
+            });
+            match choice {
+                Some(choice) => strings::hint_choose_option(labels::choice_label(choice.label)),
+                None => strings::hint_make_selection(),
+            }
+        }
+        BattleAction::SelectEnergyAdditionalCost(energy) => strings::hint_spend_energy(energy.0),
+        BattleAction::PassPriority => strings::hint_pass_priority(),
+        BattleAction::EndTurn => strings::hint_end_turn(),
+        BattleAction::StartNextTurn => strings::hint_start_next_turn(),
+        _ => strings::hint_make_selection(),
+    };
+    description.to_string()
+}
@@ -3,7 +3,7 @@ use core_data::display_types::PrefabAddress;
 use core_data::types::{CardFacing, PlayerName};
 use display_data::battle_view::DisplayPlayer;
 use display_data::card_view::{
-    CardActions, CardEffects, CardPrefab, CardView, DisplayImage, DisplayPrefabImage,
+    CardActions, CardEffects, CardFrame, CardPrefab, CardView, DisplayImage, DisplayPrefabImage,
     RevealedCardView,
 };
 use display_data::command::StudioType;
@@ -61,9 +61,12 @@ pub fn identity_card_view(
             },
             outline_color: None,
             is_fast: false,
+            is_legal_target: false,
             actions: CardActions::default(),
             effects: CardEffects::default(),
             info_zoom_data: None,
+            stack_group: None,
+            frame: CardFrame::Standard,
         }),
         revealed_to_opponents: true,
         card_facing: CardFacing::FaceUp,
@@ -8,10 +8,14 @@ use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::BattleState;
 use battle_state::battle::battle_turn_phase::BattleTurnPhase;
 use battle_state::prompt_types::prompt_data::PromptType;
+use core_data::display_types::Milliseconds;
 use core_data::numerics::Energy;
 use display_data::battle_view::{
-    ButtonView, CardBrowserView, CardOrderSelectorView, InterfaceView,
+    ButtonView, CardBrowserView, CardOrderSelectorView, InterfaceView, PendingPromptKind,
+    PendingPromptView,
 };
+use display_data::card_view::CardDetailView;
+use display_data::command::{Command, DisplayNotificationCommand, NotificationSeverity};
 use masonry::dimension::{FlexInsets, SafeAreaInsets};
 use masonry::flex_enums::{FlexAlign, FlexJustify, FlexPosition};
 use masonry::flex_style::FlexStyle;
@@ -24,7 +28,7 @@ use crate::core::response_builder::ResponseBuilder;
 use crate::display_actions::display_state;
 use crate::panels::panel_rendering;
 use crate::rendering::interface_message::{AnchorPosition, InterfaceMessage};
-use crate::rendering::labels;
+use crate::rendering::{hint_rendering, labels, supplemental_card_info, tutorial_rendering};
 
 pub fn interface_view(builder: &ResponseBuilder, battle: &BattleState) -> InterfaceView {
     let current_panel_address = display_state::get_current_panel_address(builder);
@@ -44,13 +48,17 @@ pub fn interface_view(builder: &ResponseBuilder, battle: &BattleState) -> Interf
                 action: None,
             }),
             undo_button: Some(ButtonView { label: strings::undo_icon().to_string(), action: None }),
+            card_detail: card_detail_view(builder, battle),
             ..Default::default()
         };
     }
 
     let overlay_builder = overlay_builder()
         .child(render_prompt_message(builder, battle))
+        .child(tutorial_rendering::tutorial_message(builder, battle))
         .child(render_show_battlefield_button(builder, battle))
+        .child(hint_rendering::hint_button(builder, battle))
+        .child(hint_rendering::hint_message(builder, battle))
         .child(
             current_panel_address
                 .map(|address| panel_rendering::render_panel(address, builder, battle)),
@@ -77,9 +85,79 @@ pub fn interface_view(builder: &ResponseBuilder, battle: &BattleState) -> Interf
         }),
         browser: card_browser_view(builder),
         card_order_selector: card_order_selector_view(builder, battle),
+        card_detail: card_detail_view(builder, battle),
     }
 }
 
+/// Returns commands to display transient notifications describing how game
+/// rules changed the result of the most recent battle action for the player
+/// being rendered, e.g. drawing a card with a full hand.
+pub fn action_notifications(builder: &ResponseBuilder, battle: &BattleState) -> Vec<Command> {
+    let history = battle.turn_history.current_action_history.player(builder.display_for_player());
+    let mut out = Vec::new();
+    if history.hand_size_limit_exceeded {
+        out.push(notification(
+            strings::notification_draw_converted_to_energy().to_string(),
+            NotificationSeverity::Warning,
+        ));
+    }
+    out
+}
+
+/// Returns a command to tell the user that their opponent has reconnected to
+/// the battle.
+pub fn opponent_reconnected_notification() -> Command {
+    notification(
+        strings::notification_opponent_reconnected().to_string(),
+        NotificationSeverity::Info,
+    )
+}
+
+/// Returns a command to display a transient notification, shown for a duration
+/// based on its severity.
+pub fn notification(text: String, severity: NotificationSeverity) -> Command {
+    let duration = match severity {
+        NotificationSeverity::Info => Milliseconds::new(3000),
+        NotificationSeverity::Warning => Milliseconds::new(5000),
+        NotificationSeverity::Error => Milliseconds::new(8000),
+    };
+    Command::DisplayNotification(DisplayNotificationCommand { text, severity, duration })
+}
+
+/// Returns the prompt the player being rendered for must respond to before
+/// the battle can continue, if any.
+pub fn pending_prompt(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+) -> Option<PendingPromptView> {
+    let prompt = battle.prompts.front()?;
+    if prompt.player != builder.act_for_player() {
+        return None;
+    }
+
+    let kind = match prompt.prompt_type {
+        PromptType::ChooseCharacter { .. } => PendingPromptKind::ChooseCharacter,
+        PromptType::ChooseStackCard { .. } => PendingPromptKind::ChooseStackCard,
+        PromptType::ChooseVoidCard(_) => PendingPromptKind::ChooseVoidCard,
+        PromptType::ChooseHandCards(_) => PendingPromptKind::ChooseHandCards,
+        PromptType::Choose { .. } => PendingPromptKind::Choose,
+        PromptType::ChooseEnergyValue { .. } => PendingPromptKind::ChooseEnergyValue,
+        PromptType::ModalEffect(_) => PendingPromptKind::ModalEffect,
+        PromptType::ChooseActivatedAbility { .. } => PendingPromptKind::ChooseActivatedAbility,
+        PromptType::SelectDeckCardOrder { .. } => PendingPromptKind::SelectDeckCardOrder,
+    };
+    Some(PendingPromptView {
+        kind,
+        description: prompt.prompt_description.clone(),
+        optional: prompt.configuration.optional,
+    })
+}
+
+fn card_detail_view(builder: &ResponseBuilder, battle: &BattleState) -> Option<CardDetailView> {
+    let card_id = display_state::get_inspected_card(builder)?;
+    supplemental_card_info::card_detail_view(builder, battle, card_id)
+}
+
 fn render_prompt_message(
     builder: &ResponseBuilder,
     battle: &BattleState,
@@ -1,18 +1,30 @@
 pub mod ability_help_text;
+pub mod animation_timeline;
 pub mod animations;
 pub mod apply_card_fx;
+pub mod battle_log_rendering;
 pub mod battle_rendering;
+pub mod battle_replay;
+pub mod battle_view_diff;
 pub mod card_display_state;
 pub mod card_rendering;
+pub mod cinematic_rendering;
+pub mod collection_rendering;
 pub mod dreamwell_card_rendering;
+pub mod emote_rendering;
+pub mod hidden_information;
+pub mod hint_rendering;
 pub mod identity_card_rendering;
 pub mod interface_message;
 pub mod interface_rendering;
 pub mod labels;
 pub mod modal_effect_prompt_rendering;
+pub mod playability_text;
 pub mod position_overrides;
 pub mod positions;
 pub mod renderer;
 pub mod rlf_helper;
 pub mod supplemental_card_info;
 pub mod token_rendering;
+pub mod tutorial_rendering;
+pub mod win_probability;
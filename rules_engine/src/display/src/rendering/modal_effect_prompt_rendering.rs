@@ -10,15 +10,16 @@ use battle_state::battle::card_id::CardId;
 use battle_state::prompt_types::prompt_data::PromptType;
 use core_data::display_color;
 use core_data::numerics::Energy;
-use display_data::card_view::{CardActions, CardView};
+use display_data::card_view::{CardActions, CardFrame, CardView};
 use display_data::object_position::{ObjectPosition, Position};
 use parser::serializer::ability_serializer;
 use strings::strings;
 
 use crate::core::adapter;
 use crate::core::response_builder::ResponseBuilder;
+use crate::display_actions::outcome_simulation;
+use crate::rendering::token_rendering;
 use crate::rendering::token_rendering::TokenCardView;
-use crate::rendering::{card_rendering, token_rendering};
 
 /// [CardView]s for cards representing the choices in an active modal effect
 /// prompt, if any.
@@ -78,7 +79,8 @@ fn modal_effect_card_view(
     let formatted = description.to_string();
     let view = TokenCardView::builder()
         .id(adapter::modal_effect_choice_client_id(card_id, index))
-        .image(card_rendering::card_image(battle, card_id))
+        .image(token_rendering::token_image(battle, card_id, CardFrame::AbilityToken))
+        .frame(CardFrame::AbilityToken)
         .name(strings::modal_effect_choice_card_name(index.value() + 1).to_string())
         .position(ObjectPosition { position: Position::Browser, sorting_key: index.value() as u32 })
         .create_position(ObjectPosition {
@@ -94,6 +96,13 @@ fn modal_effect_card_view(
         .outline_color(if can_select { display_color::GREEN } else { display_color::WHITE })
         .actions(CardActions {
             on_click: can_select.then_some(GameAction::BattleAction(select_action)),
+            on_click_effect_preview: can_select.then(|| {
+                outcome_simulation::action_effect_preview(
+                    battle,
+                    builder.act_for_player(),
+                    select_action,
+                )
+            }),
             ..CardActions::default()
         })
         .build();
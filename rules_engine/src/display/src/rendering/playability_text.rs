@@ -0,0 +1,38 @@
+use battle_queries::legal_action_queries::card_playability::{self, Playability, UnplayableReason};
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardId, HandCardId};
+use core_data::types::PlayerName;
+use strings::strings;
+
+/// Returns tooltip text explaining why a card in the given player's hand cannot
+/// currently be played.
+///
+/// Returns None if the card is playable or is not in this player's hand.
+pub fn unplayable_reason_text(
+    battle: &BattleState,
+    player: PlayerName,
+    card_id: CardId,
+) -> Option<String> {
+    let hand_card_id = HandCardId(card_id);
+    if !battle.cards.hand(player).contains(hand_card_id) {
+        return None;
+    }
+
+    let Playability::Unplayable(reason) = card_playability::for_card(battle, player, hand_card_id)
+    else {
+        return None;
+    };
+
+    Some(match reason {
+        UnplayableReason::WrongTiming => strings::unplayable_wrong_timing().to_string(),
+        UnplayableReason::NotFast => strings::unplayable_not_fast().to_string(),
+        UnplayableReason::InsufficientEnergy { cost, .. } => {
+            strings::unplayable_insufficient_energy(cost.0).to_string()
+        }
+        UnplayableReason::NoLegalTargets => strings::unplayable_no_legal_targets().to_string(),
+        UnplayableReason::CannotPayAdditionalCosts => {
+            strings::unplayable_additional_costs().to_string()
+        }
+        UnplayableReason::NoLegalModalChoices => strings::unplayable_no_modal_choices().to_string(),
+    })
+}
@@ -4,12 +4,15 @@ use battle_state::battle::card_id::{BattleDeckCardId, CardId, VoidCardId};
 use battle_state::battle_cards::stack_card_state::{EffectTargets, StandardEffectTarget};
 use battle_state::prompt_types::prompt_data::PromptType;
 use core_data::types::PlayerName;
-use display_data::object_position::{ObjectPosition, Position, StackType};
+use display_data::object_position::{BattlefieldPosition, ObjectPosition, Position, StackType};
 
 use crate::core::response_builder::ResponseBuilder;
 use crate::display_actions::{apply_battle_display_action, display_state};
 use crate::rendering::positions;
 
+/// Maximum number of characters displayed in a single battlefield row.
+const BATTLEFIELD_ROW_SIZE: u32 = 8;
+
 /// Returns an alternate object position for a card based on display logic, e.g.
 /// showing it in a browser.
 pub fn object_position(
@@ -18,7 +21,8 @@ pub fn object_position(
     card_id: CardId,
     base_object_position: ObjectPosition,
 ) -> ObjectPosition {
-    let position = for_prompt_source(builder, battle, card_id, base_object_position.position);
+    let position = for_battlefield_rows(base_object_position.position);
+    let position = for_prompt_source(builder, battle, card_id, position);
     let position = for_hidden_overlay(builder, position);
     let position = for_stack_during_prompt(battle, position);
     let object_position = for_top_of_deck(battle, card_id, ObjectPosition {
@@ -49,6 +53,22 @@ pub fn for_browser(builder: &ResponseBuilder, position: Position) -> Position {
     }
 }
 
+/// Splits the lanes of a large battlefield across multiple rows, so that no row
+/// holds more than `BATTLEFIELD_ROW_SIZE` characters. Additional rows are
+/// placed behind the first.
+fn for_battlefield_rows(position: Position) -> Position {
+    match position {
+        Position::OnBattlefield(BattlefieldPosition { player, row: Some(0), lane: Some(lane) }) => {
+            Position::OnBattlefield(BattlefieldPosition {
+                player,
+                row: Some(lane / BATTLEFIELD_ROW_SIZE),
+                lane: Some(lane % BATTLEFIELD_ROW_SIZE),
+            })
+        }
+        _ => position,
+    }
+}
+
 /// Returns the position for a card if it is the source of the current prompt.
 fn for_prompt_source(
     builder: &ResponseBuilder,
@@ -7,7 +7,7 @@ use battle_state::battle_cards::stack_card_state::{
 use battle_state::battle_cards::zone::Zone;
 use battle_state::prompt_types::prompt_data::PromptType;
 use core_data::types::PlayerName;
-use display_data::object_position::{ObjectPosition, Position, StackType};
+use display_data::object_position::{BattlefieldPosition, ObjectPosition, Position, StackType};
 
 use crate::core::response_builder::ResponseBuilder;
 use crate::rendering::position_overrides;
@@ -27,7 +27,11 @@ pub fn calculate(
     let position = match zone {
         Zone::Hand => Position::InHand(player),
         Zone::Deck => Position::InDeck(player),
-        Zone::Battlefield => Position::OnBattlefield(player),
+        Zone::Battlefield => Position::OnBattlefield(BattlefieldPosition {
+            player,
+            row: Some(0),
+            lane: Some(battlefield_lane(battle, controller, card_id)),
+        }),
         Zone::Stack => Position::OnStack(current_stack_type(builder, battle)),
         Zone::Void => Position::InVoid(player),
         Zone::Banished => Position::InBanished(player),
@@ -37,6 +41,18 @@ pub fn calculate(
     position_overrides::object_position(builder, battle, card_id, base_object_position)
 }
 
+/// Returns the index of a character among the characters on its controller's
+/// battlefield, in the order they entered play.
+pub fn battlefield_lane(battle: &BattleState, controller: PlayerName, card_id: CardId) -> u32 {
+    let object_id = card::get(battle, card_id).object_id;
+    battle
+        .cards
+        .battlefield(controller)
+        .iter()
+        .filter(|character_id| card::get(battle, *character_id).object_id < object_id)
+        .count() as u32
+}
+
 pub fn for_card(battle: &BattleState, card_id: CardId, position: Position) -> ObjectPosition {
     let object_id = card::get(battle, card_id).object_id;
     ObjectPosition { position, sorting_key: object_id.0 as u32 }
@@ -1,3 +1,4 @@
+use action_data::emote_data::Emote;
 use battle_queries::macros::write_tracing_event;
 use battle_queries::panic_with;
 use battle_state::battle::battle_state::BattleState;
@@ -5,11 +6,21 @@ use battle_state::battle::battle_status::BattleStatus;
 use battle_state::battle_player::battle_player_state::PlayerType;
 use core_data::identifiers::UserId;
 use core_data::types::PlayerName;
+use database::collection::Collection;
+use display_data::battle_view::{BattleView, DisplayPlayer, PendingPromptView};
 use display_data::command::CommandSequence;
-use state_provider::display_state_provider::DisplayStateProvider;
+use display_data::request_data::ClientCapability;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use state_provider::display_state_provider::{DisplayState, DisplayStateProvider};
+use strings::palette::Palette;
+use strings::{language, palette};
 
 use crate::core::response_builder::ResponseBuilder;
-use crate::rendering::{animations, battle_rendering};
+use crate::rendering::{
+    animations, battle_rendering, collection_rendering, emote_rendering, interface_rendering,
+};
 
 /// Returns a [CommandSequence] which fully describe the current state of the
 /// provided game
@@ -19,14 +30,28 @@ pub fn connect(
     provider: impl DisplayStateProvider + 'static,
     animate: bool,
 ) -> CommandSequence {
-    let mut builder = ResponseBuilder::with_state_provider(
-        player_name_for_user(battle, user_id),
-        user_id,
-        provider,
-        animate,
-    );
-    battle_rendering::run(&mut builder, battle);
-    builder.commands()
+    connect_for_player(battle, player_name_for_user(battle, user_id), user_id, provider, animate)
+}
+
+/// Equivalent to [connect], but renders the battle from the perspective of
+/// the given player instead of the player controlled by `user_id`.
+///
+/// Text is rendered in the language and palette `user_id` has selected.
+pub fn connect_for_player(
+    battle: &BattleState,
+    player: PlayerName,
+    user_id: UserId,
+    provider: impl DisplayStateProvider + 'static,
+    animate: bool,
+) -> CommandSequence {
+    let state = provider.get_display_state(user_id);
+    let commands = language::with_language(state.language.as_deref(), || {
+        let mut builder = ResponseBuilder::with_state_provider(player, user_id, provider, animate);
+        builder.clear_last_battle_view();
+        battle_rendering::run(&mut builder, battle);
+        builder.commands()
+    });
+    finish(commands, &state)
 }
 
 /// Returns a series of commands which contain animations for recent changes to
@@ -37,29 +62,96 @@ pub fn render_updates(
     user_id: UserId,
     provider: impl DisplayStateProvider + 'static,
 ) -> CommandSequence {
-    let mut builder = ResponseBuilder::with_state_provider(
-        player_name_for_user(battle, user_id),
-        user_id,
-        provider,
-        true,
+    render_updates_for_player(battle, player_name_for_user(battle, user_id), user_id, provider)
+}
+
+/// Equivalent to [render_updates], but renders the battle from the
+/// perspective of the given player instead of the player controlled by
+/// `user_id`.
+///
+/// Text is rendered in the language and palette `user_id` has selected.
+pub fn render_updates_for_player(
+    battle: &BattleState,
+    player: PlayerName,
+    user_id: UserId,
+    provider: impl DisplayStateProvider + 'static,
+) -> CommandSequence {
+    let state = provider.get_display_state(user_id);
+    let commands = language::with_language(state.language.as_deref(), || {
+        render_updates_in_current_language(battle, player, user_id, provider)
+    });
+    finish(commands, &state)
+}
+
+/// Returns a complete [BattleView] of the current state of the battle for
+/// `user_id`, along with the prompt they owe a response to, if any.
+///
+/// Used to resynchronize a client after a dropped connection. The next battle
+/// update sent to this user is a full snapshot.
+pub fn resync(
+    battle: &BattleState,
+    user_id: UserId,
+    provider: impl DisplayStateProvider + 'static,
+) -> (BattleView, Option<PendingPromptView>) {
+    let state = provider.get_display_state(user_id);
+    let player = player_name_for_user(battle, user_id);
+    let (view, prompt) = language::with_language(state.language.as_deref(), || {
+        let builder = ResponseBuilder::with_state_provider(player, user_id, provider, false);
+        builder.update_display_state(|display_state| display_state.last_battle_view = None);
+        (
+            battle_rendering::battle_view(&builder, battle),
+            interface_rendering::pending_prompt(&builder, battle),
+        )
+    });
+    (apply_palette(view, state.colorblind_mode), apply_palette(prompt, state.colorblind_mode))
+}
+
+/// Returns a [CommandSequence] telling `user_id` that their opponent has
+/// reconnected to the battle.
+///
+/// Text is rendered in the language and palette `user_id` has selected.
+pub fn opponent_reconnected(
+    user_id: UserId,
+    provider: impl DisplayStateProvider + 'static,
+) -> CommandSequence {
+    let state = provider.get_display_state(user_id);
+    let command = language::with_language(
+        state.language.as_deref(),
+        interface_rendering::opponent_reconnected_notification,
     );
-    builder.set_for_animation(true);
-    if let Some(animations) = &battle.animations {
-        if !animations.steps.is_empty() {
-            write_tracing_event::write_animations(battle, animations);
-        }
-        for step in &animations.steps {
-            animations::render(&mut builder, step.source, &step.animation, &step.snapshot, battle);
-            if matches!(step.snapshot.status, BattleStatus::GameOver { .. }) {
-                // Ignore future updates when GameOver state is detected
-                break;
-            }
-        }
-    }
+    finish(CommandSequence::from_command(command), &state)
+}
 
-    builder.set_for_animation(false);
-    battle_rendering::run(&mut builder, battle);
-    builder.commands()
+/// Returns a [CommandSequence] showing `user_id` an emote sent by `sender`.
+///
+/// Text is rendered in the language and palette `user_id` has selected.
+pub fn emote(
+    user_id: UserId,
+    sender: DisplayPlayer,
+    emote: Emote,
+    provider: impl DisplayStateProvider + 'static,
+) -> CommandSequence {
+    let state = provider.get_display_state(user_id);
+    let command = language::with_language(state.language.as_deref(), || {
+        emote_rendering::display_emote(sender, emote)
+    });
+    finish(CommandSequence::from_command(command), &state)
+}
+
+/// Returns a [CommandSequence] showing `user_id` their card [Collection] in the
+/// collection browser.
+///
+/// Text is rendered in the language and palette `user_id` has selected.
+pub fn collection(
+    user_id: UserId,
+    collection: &Collection,
+    provider: impl DisplayStateProvider + 'static,
+) -> CommandSequence {
+    let state = provider.get_display_state(user_id);
+    let command = language::with_language(state.language.as_deref(), || {
+        collection_rendering::update_collection(&provider.tabula(), collection)
+    });
+    finish(CommandSequence::from_command(command), &state)
 }
 
 /// Returns the name of the player for a given user ID, or panics if this user
@@ -89,3 +181,97 @@ pub fn player_name_for_user_optional(battle: &BattleState, user_id: UserId) -> O
 
     None
 }
+
+/// Removes commands which require a [ClientCapability] the client did not
+/// declare when connecting, dropping any command groups left empty.
+///
+/// Battle view deltas are already only produced for clients which support
+/// them, so older clients fall back to full snapshots and simply skip the
+/// animations they do not know how to play.
+pub fn remove_unsupported_commands(
+    commands: CommandSequence,
+    capabilities: &[ClientCapability],
+) -> CommandSequence {
+    let groups = commands
+        .groups
+        .into_iter()
+        .filter_map(|mut group| {
+            group.commands.retain(|command| {
+                command.required_capability().is_none_or(|c| capabilities.contains(&c))
+            });
+            (!group.commands.is_empty()).then_some(group)
+        })
+        .collect();
+    CommandSequence { groups }
+}
+
+fn render_updates_in_current_language(
+    battle: &BattleState,
+    player: PlayerName,
+    user_id: UserId,
+    provider: impl DisplayStateProvider + 'static,
+) -> CommandSequence {
+    let mut builder = ResponseBuilder::with_state_provider(player, user_id, provider, true);
+    builder.set_for_animation(true);
+    if let Some(animations) = &battle.animations {
+        if !animations.steps.is_empty() {
+            write_tracing_event::write_animations(battle, animations);
+        }
+        for step in &animations.steps {
+            animations::render(&mut builder, step.source, &step.animation, &step.snapshot, battle);
+            if matches!(step.snapshot.status, BattleStatus::GameOver { .. }) {
+                // Ignore future updates when GameOver state is detected
+                break;
+            }
+        }
+    }
+
+    builder.set_for_animation(false);
+    battle_rendering::run(&mut builder, battle);
+    for notification in interface_rendering::action_notifications(&builder, battle) {
+        builder.push(notification);
+    }
+    builder.commands()
+}
+
+/// Adapts rendered commands to the palette and protocol capabilities of the
+/// client they are being sent to.
+fn finish(commands: CommandSequence, state: &DisplayState) -> CommandSequence {
+    remove_unsupported_commands(
+        apply_palette(commands, state.colorblind_mode),
+        &state.client_capabilities,
+    )
+}
+
+/// Rewrites the colors of all rich text in `output` to the colorblind-safe
+/// palette if `colorblind_mode` is set.
+///
+/// Phrases are defined using the standard palette, so this is a no-op for
+/// users who have not enabled colorblind mode.
+fn apply_palette<T: Serialize + DeserializeOwned>(output: T, colorblind_mode: bool) -> T {
+    if !colorblind_mode {
+        return output;
+    }
+    let Ok(mut value) = serde_json::to_value(&output) else {
+        return output;
+    };
+    recolor_strings(&mut value, Palette::Colorblind);
+    serde_json::from_value(value).unwrap_or(output)
+}
+
+fn recolor_strings(value: &mut Value, palette: Palette) {
+    match value {
+        Value::String(text) => *text = palette::apply(palette, text),
+        Value::Array(values) => {
+            for value in values {
+                recolor_strings(value, palette);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                recolor_strings(value, palette);
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,18 +1,125 @@
+use ability_data::ability::Ability;
+use ability_data::cost::Cost;
+use ability_data::duration::Duration;
+use ability_data::named_ability::NamedAbility;
+use action_data::battle_display_action::BattleDisplayAction;
+use battle_queries::battle_card_queries::{card, card_properties};
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::{CardId, CharacterId};
+use battle_state::battle_cards::ability_state::TimedEffect;
 use bon::Builder;
 use core_data::display_color;
+use display_data::card_view::{CardDetailView, CardFrame, CardView, TemporaryEffectView};
+use display_data::object_position::{ObjectPosition, Position};
 use masonry::flex_enums::{TextAlign, WhiteSpace};
 use masonry::flex_style::FlexStyle;
+use strings::strings;
+use tabula_data::card_definition::CardDefinition;
+use tracing::warn;
 use ui_components::box_component::BoxComponent;
 use ui_components::component::Component;
+use ui_components::icon;
 use ui_components::text_component::TextComponent;
 use ui_components::typography::Typography;
 
+use crate::core::adapter;
+use crate::core::response_builder::ResponseBuilder;
+use crate::rendering::token_rendering::{self, TokenCardView};
+use crate::rendering::{ability_help_text, card_display_state, card_rendering};
+
 #[derive(Clone, Builder)]
 pub struct SupplementalCardInfo {
     #[builder(into)]
     pub text: String,
 }
 
+/// Returns the full details of a card for the card inspection screen.
+///
+/// Returns None if the card does not exist or is hidden from the player the
+/// view is being rendered for.
+pub fn card_detail_view(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+    card_id: CardId,
+) -> Option<CardDetailView> {
+    if !battle.cards.all_cards().any(|id| id == card_id)
+        || !card_display_state::is_revealed_to(battle, card_id, builder.display_for_player())
+    {
+        return None;
+    }
+
+    Some(CardDetailView {
+        card_id: adapter::client_card_id(card_id),
+        name: card_rendering::card_name(battle, card_id),
+        rules_text: card_rendering::rules_text(builder, battle, card_id),
+        reminder_text: ability_help_text::help_texts(battle, card_id),
+        modifiers: modifiers(battle, card_id),
+        // The rules engine does not currently have counters or attachments.
+        counters: Vec::new(),
+        attached_cards: Vec::new(),
+        temporary_effects: temporary_effects(builder, battle, card_id),
+        related_cards: related_cards(battle, card_id),
+        close_action: BattleDisplayAction::CloseCardInspection.into(),
+    })
+}
+
+/// Returns views of the cards related to a card, to display alongside it.
+///
+/// Includes the version of the card which can be played from the void if it
+/// has a reclaim ability, followed by each card it refers to in tabula, e.g.
+/// cards it creates. References to cards which are not in tabula are skipped.
+pub fn related_cards(battle: &BattleState, card_id: CardId) -> Vec<CardView> {
+    let definition = card::get_definition(battle, card_id);
+    let mut result = Vec::new();
+    if let Some(cost) = reclaim_cost(&definition) {
+        result.push(token_rendering::token_card_view(
+            TokenCardView::builder()
+                .id(adapter::related_card_client_id(card_id, result.len()))
+                .position(related_card_position(result.len()))
+                .image(token_rendering::token_image(battle, card_id, CardFrame::Copy))
+                .frame(CardFrame::Copy)
+                .name(definition.displayed_name.clone())
+                .card_type(format!("{} {}", icon::FAST, strings::token_type_reclaim_ability()))
+                .cost(cost)
+                .maybe_spark(definition.spark.map(|spark| spark.to_string()))
+                .rules_text(card_rendering::serialize_abilities_text(&definition.abilities))
+                .build(),
+        ));
+    }
+
+    for related_id in &definition.related_cards {
+        let Some(related) = battle.tabula.cards.get(related_id) else {
+            warn!(?related_id, "Related card not found in tabula");
+            continue;
+        };
+        let card_type = card_rendering::definition_card_type(related);
+        result.push(token_rendering::token_card_view(
+            TokenCardView::builder()
+                .id(adapter::related_card_client_id(card_id, result.len()))
+                .position(related_card_position(result.len()))
+                .image(related.image.clone())
+                .frame(CardFrame::Standard)
+                .name(related.displayed_name.clone())
+                .card_type(if related.is_fast {
+                    format!("{} {card_type}", icon::FAST)
+                } else {
+                    card_type
+                })
+                .cost(
+                    related.energy_cost.map_or_else(
+                        || strings::asterisk_icon().to_string(),
+                        |cost| cost.to_string(),
+                    ),
+                )
+                .maybe_spark(related.spark.map(|spark| spark.to_string()))
+                .rules_text(card_rendering::serialize_abilities_text(&related.abilities))
+                .is_fast(related.is_fast)
+                .build(),
+        ));
+    }
+    result
+}
+
 impl Component for SupplementalCardInfo {
     fn render(self) -> Option<impl Component> {
         Some(
@@ -38,3 +145,83 @@ impl Component for SupplementalCardInfo {
         )
     }
 }
+
+/// Returns the energy cost to display for playing a card with reclaim, or
+/// None if it does not have a reclaim ability.
+fn reclaim_cost(definition: &CardDefinition) -> Option<String> {
+    definition.abilities.iter().find_map(|ability| match ability {
+        Ability::Named(NamedAbility::Reclaim(energy)) => Some(
+            energy
+                .or(definition.energy_cost)
+                .map_or_else(|| icon::NON_NUMERIC.to_string(), |energy| energy.to_string()),
+        ),
+        Ability::Named(NamedAbility::ReclaimForCost(Cost::Energy(energy))) => {
+            Some(energy.to_string())
+        }
+        Ability::Named(NamedAbility::ReclaimForCost(_)) => Some(icon::NON_NUMERIC.to_string()),
+        _ => None,
+    })
+}
+
+fn related_card_position(index: usize) -> ObjectPosition {
+    ObjectPosition { position: Position::Default, sorting_key: index as u32 }
+}
+
+fn modifiers(battle: &BattleState, card_id: CardId) -> Vec<String> {
+    let mut out = Vec::new();
+    let controller = card_properties::controller(battle, card_id);
+    if let Some(spark) = card_properties::spark(battle, controller, CharacterId(card_id))
+        && let Some(base) = card_properties::base_spark(battle, card_id)
+        && spark != base
+    {
+        out.push(strings::card_detail_spark_modifier(spark.0, base.0).to_string());
+    }
+
+    if let Some(base) = card_properties::base_energy_cost(battle, card_id) {
+        let cost = card_properties::converted_energy_cost(battle, card_id);
+        if cost != base {
+            out.push(strings::card_detail_cost_modifier(cost.0, base.0).to_string());
+        }
+    }
+
+    if battle.ability_state.banish_when_leaves_play.contains(card_id) {
+        out.push(strings::card_detail_banish_when_leaves_play().to_string());
+    }
+
+    out
+}
+
+fn temporary_effects(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+    card_id: CardId,
+) -> Vec<TemporaryEffectView> {
+    battle
+        .ability_state
+        .prevent_dissolved
+        .iter()
+        .filter(|effect| effect.value.card_id.card_id() == card_id)
+        .map(|effect| TemporaryEffectView {
+            description: strings::card_detail_prevent_dissolved().to_string(),
+            controller: builder.to_display_player(effect.controller),
+            duration: duration_text(builder, effect),
+        })
+        .collect()
+}
+
+fn duration_text<T>(builder: &ResponseBuilder, effect: &TimedEffect<T>) -> String {
+    let is_user = effect.controller == builder.display_for_player();
+    match (effect.duration, is_user) {
+        (Duration::ThisTurn, _) => strings::card_detail_duration_this_turn(),
+        (Duration::UntilYourNextMain, true) => strings::card_detail_duration_until_your_next_main(),
+        (Duration::UntilYourNextMain, false) => {
+            strings::card_detail_duration_until_enemy_next_main()
+        }
+        (Duration::UntilYourNextTurn, true) => strings::card_detail_duration_until_your_next_turn(),
+        (Duration::UntilYourNextTurn, false) => {
+            strings::card_detail_duration_until_enemy_next_turn()
+        }
+        (Duration::WhileInPlay, _) => strings::card_detail_duration_while_in_play(),
+    }
+    .to_string()
+}
@@ -1,6 +1,7 @@
 use ability_data::cost::Cost;
 use action_data::game_action_data::GameAction;
 use battle_queries::battle_card_queries::{card, card_properties};
+use battle_queries::card_ability_queries::trigger_queries;
 use battle_queries::legal_action_queries::legal_actions_data::ForPlayer;
 use battle_queries::legal_action_queries::{can_play_cards, legal_actions};
 use battle_state::actions::battle_actions::BattleAction;
@@ -10,14 +11,15 @@ use battle_state::battle::card_id::{
     ActivatedAbilityId, CardId, CardIdType, CharacterId, VoidCardId,
 };
 use battle_state::battle_cards::stack_card_state::StackItemId;
+use battle_state::triggers::trigger::TriggerName;
 use bon::Builder;
 use core_data::display_color::{self, DisplayColor};
 use core_data::display_types::{AudioClipAddress, SpriteAddress};
 use core_data::types::CardFacing;
 use display_data::battle_view::DisplayPlayer;
 use display_data::card_view::{
-    CardActions, CardEffects, CardPrefab, CardView, ClientCardId, DisplayImage, InfoZoomData,
-    RevealedCardView,
+    CardActions, CardEffects, CardFrame, CardPrefab, CardView, ClientCardId, DisplayImage,
+    InfoZoomData, RevealedCardView, StackGroupView,
 };
 use display_data::object_position::{ObjectPosition, Position};
 use strings::strings;
@@ -34,6 +36,7 @@ pub struct TokenCardView {
     id: ClientCardId,
     position: ObjectPosition,
     image: SpriteAddress,
+    frame: CardFrame,
     name: String,
     cost: Option<String>,
     spark: Option<String>,
@@ -48,13 +51,20 @@ pub struct TokenCardView {
     actions: CardActions,
     outline_color: Option<DisplayColor>,
     info_zoom_data: Option<InfoZoomData>,
+    stack_group: Option<StackGroupView>,
 }
 
+/// Returns a view for a triggered ability which is awaiting resolution.
+///
+/// `index` is the position of `trigger` within `triggers`, the list of all
+/// currently active triggers. Triggers fired by the same event as other active
+/// triggers are given a shared [StackGroupView].
 pub fn trigger_card_view(
     builder: &ResponseBuilder,
     battle: &BattleState,
     index: usize,
     trigger: &TriggerAnimation,
+    triggers: &[TriggerAnimation],
 ) -> CardView {
     let current_stack = positions::current_stack_type(builder, battle);
     let stack_position = if display_state::is_battlefield_shown(builder) {
@@ -71,7 +81,8 @@ pub fn trigger_card_view(
                 position: stack_position,
                 sorting_key: (battle.cards.next_object_id_for_display().0 + index + 5) as u32,
             })
-            .image(card_rendering::card_image(battle, character_card_id))
+            .image(token_image(battle, character_card_id, CardFrame::AbilityToken))
+            .frame(CardFrame::AbilityToken)
             .name(card_rendering::card_name(battle, character_card_id))
             .card_type(strings::token_type_triggered_ability().to_string())
             .rules_text(card_rendering::ability_token_text(
@@ -89,6 +100,7 @@ pub fn trigger_card_view(
             })
             .create_sound(AudioClipAddress::new("Assets/ThirdParty/WowSound/RPG Magic Sound Effects Pack 3/UI, Pads, Enchantments and Misc/RPG3_Enchantment_Subtle01v2.wav"))
             .maybe_info_zoom_data(build_token_info_zoom_data(battle, character_card_id))
+            .maybe_stack_group(trigger_stack_group(battle, index, trigger, triggers))
             .build(),
     )
 }
@@ -227,9 +239,12 @@ pub fn token_card_view(view: TokenCardView) -> CardView {
             rules_text: view.rules_text,
             outline_color: view.outline_color,
             is_fast: view.is_fast,
+            is_legal_target: false,
             actions: view.actions,
             effects: CardEffects::default(),
             info_zoom_data: view.info_zoom_data,
+            stack_group: view.stack_group,
+            frame: view.frame,
         }),
         revealed_to_opponents: true,
         card_facing: CardFacing::FaceUp,
@@ -241,6 +256,22 @@ pub fn token_card_view(view: TokenCardView) -> CardView {
     }
 }
 
+/// Returns the image to display for a token of the given frame which was
+/// created from `card_id`.
+///
+/// Uses the card's art for that kind of token from tabula if it has any, and
+/// otherwise falls back to the card's own image.
+pub fn token_image(battle: &BattleState, card_id: CardId, frame: CardFrame) -> SpriteAddress {
+    let definition = card::get_definition(battle, card_id);
+    let image = match frame {
+        CardFrame::Standard => None,
+        CardFrame::AbilityToken => definition.token_image.as_ref(),
+        CardFrame::Copy => definition.copy_image.as_ref(),
+        CardFrame::Figment => definition.figment_image.as_ref(),
+    };
+    image.cloned().unwrap_or_else(|| card_rendering::card_image(battle, card_id))
+}
+
 fn activated_ability_card_view(
     builder: &ResponseBuilder,
     battle: &BattleState,
@@ -295,7 +326,8 @@ fn activated_ability_card_view(
         TokenCardView::builder()
             .id(adapter::stack_item_client_card_id(ability))
             .position(position)
-            .image(card_rendering::card_image(battle, character_card_id))
+            .image(token_image(battle, character_card_id, CardFrame::AbilityToken))
+            .frame(CardFrame::AbilityToken)
             .name(ability_name)
             .maybe_cost(cost.map(|cost| cost.to_string()))
             .card_type(format!(
@@ -363,7 +395,8 @@ fn void_card_token_view(
         TokenCardView::builder()
             .id(adapter::void_card_token_client_id(void_card_id))
             .position(ObjectPosition { position, sorting_key: hand_sorting_key })
-            .image(card_rendering::card_image(battle, card_id))
+            .image(token_image(battle, card_id, CardFrame::Copy))
+            .frame(CardFrame::Copy)
             .name(card_rendering::card_name(battle, card_id))
             .card_type(format!("{} {}", icon::FAST, strings::token_type_reclaim_ability()))
             .cost(
@@ -401,9 +434,44 @@ fn void_card_token_view(
     )
 }
 
+fn trigger_stack_group(
+    battle: &BattleState,
+    index: usize,
+    trigger: &TriggerAnimation,
+    triggers: &[TriggerAnimation],
+) -> Option<StackGroupView> {
+    let group = triggers
+        .iter()
+        .enumerate()
+        .filter(|(_, other)| other.trigger == trigger.trigger)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if group.len() < 2 {
+        return None;
+    }
+
+    let label = match trigger_queries::triggering_card_id(trigger.trigger) {
+        Some(card_id) => {
+            strings::stack_group_triggered_by_card(card_rendering::card_name(battle, card_id))
+        }
+        None => match trigger.trigger.name() {
+            TriggerName::EndOfTurn => strings::stack_group_end_of_turn(),
+            TriggerName::StartOfTurn => strings::stack_group_start_of_turn(),
+            TriggerName::Judgment => strings::stack_group_judgment(),
+            _ => strings::stack_group_triggered(),
+        },
+    };
+    Some(StackGroupView {
+        group_id: format!("TG{:?}", trigger.trigger),
+        index: group.iter().position(|&i| i == index).unwrap_or_default() as u32,
+        size: group.len() as u32,
+        label: label.to_string(),
+    })
+}
+
 fn build_token_info_zoom_data(
     battle: &BattleState,
     parent_card_id: CardId,
 ) -> Option<InfoZoomData> {
-    card_rendering::build_info_zoom_data(battle, parent_card_id)
+    card_rendering::build_info_zoom_data(battle, parent_card_id, None)
 }
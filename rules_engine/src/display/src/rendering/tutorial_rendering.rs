@@ -0,0 +1,116 @@
+use battle_queries::battle_card_queries::card_properties;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_queries::legal_action_queries::legal_actions_data::{
+    LegalActions, PrimaryLegalAction, StandardLegalActions,
+};
+use battle_state::battle::battle_state::BattleState;
+use core_data::types::PlayerName;
+use state_provider::display_state_provider::TutorialMessageId;
+use strings::strings;
+
+use crate::core::response_builder::ResponseBuilder;
+use crate::display_actions::display_state;
+use crate::rendering::interface_message::{AnchorPosition, InterfaceMessage};
+
+/// Tutorial messages in the order they should be shown.
+const TUTORIAL_STEPS: &[TutorialStep] = &[
+    TutorialStep {
+        id: TutorialMessageId::PlayCard,
+        after: None,
+        condition: can_play_card_in_main_phase,
+        text: || strings::tutorial_play_card().to_string(),
+    },
+    TutorialStep {
+        id: TutorialMessageId::EndTurn,
+        after: Some(TutorialMessageId::PlayCard),
+        condition: can_only_end_turn,
+        text: || strings::tutorial_end_turn().to_string(),
+    },
+    TutorialStep {
+        id: TutorialMessageId::FastCard,
+        after: None,
+        condition: can_respond_with_fast_card,
+        text: || strings::tutorial_fast_card().to_string(),
+    },
+];
+
+/// Renders the next scripted tutorial message for the user, if the tutorial
+/// is enabled.
+///
+/// The first step in `TUTORIAL_STEPS` which the user has not seen and whose
+/// condition currently holds is shown. It is recorded as the current tutorial
+/// message in the display state, and is marked as seen once the user takes a
+/// battle action.
+pub fn tutorial_message(
+    builder: &ResponseBuilder,
+    battle: &BattleState,
+) -> Option<InterfaceMessage> {
+    if !builder.get_display_state().tutorial {
+        return None;
+    }
+
+    let player = builder.act_for_player();
+    let step = TUTORIAL_STEPS.iter().find(|step| {
+        !display_state::has_seen_tutorial_message(builder, step.id)
+            && step
+                .after
+                .is_none_or(|after| display_state::has_seen_tutorial_message(builder, after))
+            && (step.condition)(battle, player)
+    });
+    display_state::set_tutorial_message(builder, step.map(|step| step.id));
+    let step = step?;
+
+    Some(
+        InterfaceMessage::builder()
+            .text((step.text)())
+            .anchor_position(AnchorPosition::Top)
+            .temporary(false)
+            .build(),
+    )
+}
+
+/// A scripted message in the tutorial sequence.
+struct TutorialStep {
+    id: TutorialMessageId,
+
+    /// Step which must have been seen before this one can be shown, if any.
+    after: Option<TutorialMessageId>,
+
+    /// Returns true if the game state calls for this message to be shown to
+    /// the given player.
+    condition: fn(&BattleState, PlayerName) -> bool,
+
+    text: fn() -> String,
+}
+
+fn can_play_card_in_main_phase(battle: &BattleState, player: PlayerName) -> bool {
+    standard_actions(battle, player).is_some_and(|actions| {
+        actions.primary == PrimaryLegalAction::EndTurn && !actions.play_card_from_hand.is_empty()
+    })
+}
+
+fn can_only_end_turn(battle: &BattleState, player: PlayerName) -> bool {
+    standard_actions(battle, player).is_some_and(|actions| {
+        actions.primary == PrimaryLegalAction::EndTurn
+            && actions.play_card_from_hand.is_empty()
+            && actions.play_card_from_void.is_empty()
+            && actions.activate_abilities_for_character.is_empty()
+    })
+}
+
+fn can_respond_with_fast_card(battle: &BattleState, player: PlayerName) -> bool {
+    standard_actions(battle, player).is_some_and(|actions| {
+        actions.primary != PrimaryLegalAction::EndTurn
+            && actions
+                .play_card_from_hand
+                .iter()
+                .any(|card_id| card_properties::is_fast(battle, card_id))
+    })
+}
+
+fn standard_actions(battle: &BattleState, player: PlayerName) -> Option<StandardLegalActions> {
+    match legal_actions::compute(battle, player) {
+        LegalActions::Standard { actions } => Some(actions),
+        _ => None,
+    }
+}
@@ -0,0 +1,52 @@
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle_trace::decision_explanation::ScoredAction;
+use core_data::types::PlayerName;
+
+use crate::core::response_builder::ResponseBuilder;
+use crate::display_actions::display_state;
+
+/// Number of imaginary search iterations with an even outcome added to every
+/// estimate, so that thinly-searched actions stay close to 50%.
+const PRIOR_VISITS: f64 = 10.0;
+
+/// Estimates the probability that the displayed player wins `battle`.
+///
+/// Reuses the statistics of the most recent tree search instead of searching
+/// again, so this is cheap enough to compute on every update. Prefers a hint
+/// suggested to the displayed player over the last AI decision, since hints
+/// are searched from a more recent state. Returns None if neither search has
+/// run.
+pub fn estimate(builder: &ResponseBuilder, battle: &BattleState) -> Option<f64> {
+    let player = builder.display_for_player();
+    if let BattleStatus::GameOver { winner } = battle.status {
+        return Some(if winner == Some(player) { 1.0 } else { 0.0 });
+    }
+
+    if let Some(suggestion) = display_state::get_hint(builder).and_then(|hint| hint.suggestion) {
+        return Some(calibrated(suggestion, builder.act_for_player(), player));
+    }
+
+    let decision = battle.last_ai_decision.as_ref()?;
+    let selected = decision
+        .alternatives
+        .iter()
+        .find(|alternative| alternative.action == decision.action)
+        .or_else(|| decision.alternatives.first())?;
+    Some(calibrated(*selected, decision.player, player))
+}
+
+/// Converts a search reward from -1.0 to 1.0 into a win probability from 0.0
+/// to 1.0.
+pub fn from_reward(average_reward: f64) -> f64 {
+    ((average_reward + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+/// Returns the win probability for `player` of an action searched on behalf
+/// of `searched_for`, shrinking it towards 50% when it received few visits.
+fn calibrated(action: ScoredAction, searched_for: PlayerName, player: PlayerName) -> f64 {
+    let visits = f64::from(action.visit_count);
+    let probability =
+        0.5 + (from_reward(action.average_reward) - 0.5) * visits / (visits + PRIOR_VISITS);
+    if searched_for == player { probability } else { 1.0 - probability }
+}
@@ -0,0 +1,40 @@
+use core_data::numerics::TurnId;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::battle_view::DisplayPlayer;
+use crate::card_view::ClientCardId;
+
+/// Record of the actions players have taken during a battle, for players to
+/// review what has happened.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BattleLogView {
+    /// Turns of the battle in which actions were taken, oldest first.
+    pub turns: Vec<BattleLogTurnView>,
+}
+
+/// Actions taken during a single turn of a battle.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BattleLogTurnView {
+    pub turn_number: TurnId,
+
+    /// Localized heading for this turn.
+    pub header: String,
+
+    /// Actions taken during this turn, in the order they were taken.
+    pub entries: Vec<BattleLogEntryView>,
+}
+
+/// A single action in the battle log.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BattleLogEntryView {
+    /// Player who took this action.
+    pub player: DisplayPlayer,
+
+    /// Localized, styled description of the action.
+    pub text: String,
+
+    /// Cards involved in this action, which the client can highlight when the
+    /// entry is selected.
+    pub cards: Vec<ClientCardId>,
+}
@@ -6,7 +6,7 @@ use masonry::flex_node::FlexNode;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::card_view::{CardPreviewView, CardView};
+use crate::card_view::{CardDetailView, CardPreviewView, CardView, ClientCardId};
 use crate::command::DisplayArrow;
 
 /// Represents the visual state of an ongoing dream battle
@@ -30,12 +30,61 @@ pub struct BattleView {
     /// Arrows to display between cards
     pub arrows: Vec<DisplayArrow>,
 
+    /// Legal targets for the pending prompt, if the user is currently
+    /// choosing targets.
+    pub targeting: Option<TargetingView>,
+
     /// Preview of the next state of the battle, used e.g. when confirming
     /// prompt choices.
     pub preview: BattlePreviewState,
 
     /// Current turn number
     pub turn_number: TurnId,
+
+    /// Estimated probability that the user wins this battle, from 0.0 to 1.0,
+    /// used to display an evaluation bar.
+    ///
+    /// None if no estimate is available.
+    pub win_probability: Option<f64>,
+}
+
+/// A prompt the user must respond to before the battle can continue.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PendingPromptView {
+    /// Kind of response the prompt is asking for.
+    pub kind: PendingPromptKind,
+
+    /// Description text for the prompt.
+    pub description: String,
+
+    /// Whether the user can decline to choose any option.
+    pub optional: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum PendingPromptKind {
+    ChooseCharacter,
+    ChooseStackCard,
+    ChooseVoidCard,
+    ChooseHandCards,
+    Choose,
+    ChooseEnergyValue,
+    ModalEffect,
+    ChooseActivatedAbility,
+    SelectDeckCardOrder,
+}
+
+/// Cards which can currently be chosen as targets for a prompt.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TargetingView {
+    /// Card which is choosing targets, if any.
+    pub source: Option<ClientCardId>,
+
+    /// Cards which are legal targets for the prompt.
+    pub legal_targets: Vec<ClientCardId>,
+
+    /// Arrows from the source to each legal target.
+    pub arrows: Vec<DisplayArrow>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -101,6 +150,9 @@ pub struct InterfaceView {
 
     /// Options for display of the card order selector
     pub card_order_selector: Option<CardOrderSelectorView>,
+
+    /// Details of the card the user is inspecting, if any.
+    pub card_detail: Option<CardDetailView>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema)]
@@ -179,6 +231,12 @@ pub struct PlayerPreviewView {
 
     /// New total spark for this player
     pub total_spark: Option<Spark>,
+
+    /// Number of cards this player will draw, if any
+    pub cards_drawn: Option<u32>,
+
+    /// Number of this player's characters which will be dissolved, if any
+    pub characters_dissolved: Option<u32>,
 }
 
 /// Represents a player within the context of the display layer.
@@ -0,0 +1,92 @@
+use core_data::identifiers::BattleId;
+use core_data::numerics::TurnId;
+use core_data::types::CardFacing;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::battle_view::{BattlePreviewState, InterfaceView, PlayerView, TargetingView};
+use crate::card_view::{CardView, ClientCardId, RevealedCardView};
+use crate::command::DisplayArrow;
+use crate::object_position::ObjectPosition;
+
+/// Changes to apply to the previously sent [crate::battle_view::BattleView]
+/// in order to produce the current one.
+///
+/// Fields which are None are unchanged from the previous view.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BattleViewDelta {
+    /// Battle this delta applies to
+    pub id: BattleId,
+
+    /// New state of the user, if changed
+    pub user: Option<PlayerView>,
+
+    /// New state of the enemy, if changed
+    pub enemy: Option<PlayerView>,
+
+    /// Cards which were not present in the previous view, or which changed
+    /// in ways not described by a [CardViewDelta]. Replaces the previous
+    /// state of the card.
+    pub cards: Vec<CardView>,
+
+    /// Changes to cards which were present in the previous view.
+    pub changed_cards: Vec<CardViewDelta>,
+
+    /// Cards which were present in the previous view and should be removed.
+    pub removed_cards: Vec<ClientCardId>,
+
+    /// New UI to display to the player, if changed
+    pub interface: Option<InterfaceView>,
+
+    /// New arrows to display between cards, if changed
+    pub arrows: Option<Vec<DisplayArrow>>,
+
+    /// New battle preview state, if changed
+    pub preview: Option<BattlePreviewState>,
+
+    /// Legal targets for the pending prompt
+    pub targeting: Option<TargetingView>,
+
+    /// Current turn number
+    pub turn_number: TurnId,
+
+    /// Estimated probability that the user wins this battle.
+    pub win_probability: Option<f64>,
+}
+
+/// Changes to a single card in a [BattleViewDelta].
+///
+/// Fields which are None are unchanged from the previous view.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CardViewDelta {
+    /// Identifier for this card
+    pub id: ClientCardId,
+
+    /// New position of this card, if changed
+    pub position: Option<ObjectPosition>,
+
+    /// New revealed state of this card, if changed
+    pub revealed: Option<RevealedCardDelta>,
+
+    /// New status of this card, if changed
+    pub status: Option<CardStatusView>,
+}
+
+/// A change to the revealed face of a card.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub enum RevealedCardDelta {
+    /// The card is no longer revealed to the viewer.
+    Hidden,
+
+    /// The card is revealed to the viewer with this face.
+    Revealed(Box<RevealedCardView>),
+}
+
+/// Visibility status of a card, see the fields of the same name on
+/// [CardView].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CardStatusView {
+    pub revealed_to_opponents: bool,
+    pub card_facing: CardFacing,
+    pub backless: bool,
+}
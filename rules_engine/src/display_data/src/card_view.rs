@@ -7,7 +7,7 @@ use masonry::flex_node::FlexNode;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::battle_view::{BattlePreviewView, ButtonView};
+use crate::battle_view::{BattlePreviewView, ButtonView, DisplayPlayer};
 use crate::command::{DissolveCardCommand, StudioType};
 use crate::object_position::ObjectPosition;
 
@@ -104,6 +104,10 @@ pub struct RevealedCardView {
     /// True if this card can be played during the opponent's turn
     pub is_fast: bool,
 
+    /// True if this card is currently a legal target for the pending prompt,
+    /// e.g. a character which can be chosen for a targeted effect.
+    pub is_legal_target: bool,
+
     /// Actions available for this card
     pub actions: CardActions,
 
@@ -113,6 +117,32 @@ pub struct RevealedCardView {
     /// Data providing supplemental information about this card on long
     /// press/hover.
     pub info_zoom_data: Option<InfoZoomData>,
+
+    /// If this card is one of several related items on the stack, identifies
+    /// the group it belongs to.
+    pub stack_group: Option<StackGroupView>,
+
+    /// Frame to display this card with.
+    pub frame: CardFrame,
+}
+
+/// Identifies a group of related items on the stack, such as several
+/// triggered abilities fired by the same event, which the client displays
+/// clustered together under a shared label.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StackGroupView {
+    /// Identifier shared by every item in the group.
+    pub group_id: String,
+
+    /// Position of this item within the group, in the order the items will
+    /// resolve.
+    pub index: u32,
+
+    /// Number of items in the group.
+    pub size: u32,
+
+    /// Label describing what caused this group of items.
+    pub label: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -146,6 +176,10 @@ pub struct CardActions {
     /// Preview of the battle state after this card is played.
     pub play_effect_preview: Option<BattlePreviewView>,
 
+    /// Preview of the battle state after this card is clicked, e.g. when it is
+    /// an option in a targeting or modal effect prompt.
+    pub on_click_effect_preview: Option<BattlePreviewView>,
+
     /// Button to display below this card.
     pub button_attachment: Option<ButtonView>,
 }
@@ -173,6 +207,10 @@ pub struct InfoZoomData {
     /// Icons to display on other cards during info zoom, e.g. indicating
     /// targets.
     pub icons: Vec<InfoZoomIcon>,
+
+    /// Cards to display alongside this card, e.g. cards its rules text refers
+    /// to or the version of it which can be played with reclaim.
+    pub related_cards: Vec<CardView>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -182,6 +220,76 @@ pub struct InfoZoomIcon {
     pub color: DisplayColor,
 }
 
+/// Full description of a card, shown on the zoomed card inspection screen.
+///
+/// Unlike [RevealedCardView], this is only produced for the card the user has
+/// requested to inspect.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CardDetailView {
+    /// Identifier for the inspected card
+    pub card_id: ClientCardId,
+
+    /// Name of the card
+    pub name: String,
+
+    /// Rules text of the card, including annotations such as "(Anchored)".
+    pub rules_text: String,
+
+    /// Reminder text explaining each keyword used in the card's rules text.
+    pub reminder_text: Vec<String>,
+
+    /// Descriptions of properties of this card which currently differ from
+    /// its printed values, e.g. a modified spark value.
+    pub modifiers: Vec<String>,
+
+    /// Counters currently placed on this card.
+    pub counters: Vec<CardCounterView>,
+
+    /// Cards currently attached to this card.
+    pub attached_cards: Vec<ClientCardId>,
+
+    /// Effects currently applied to this card which will expire.
+    pub temporary_effects: Vec<TemporaryEffectView>,
+
+    /// Cards to display alongside the inspected card, see
+    /// [InfoZoomData::related_cards].
+    pub related_cards: Vec<CardView>,
+
+    /// Action to perform to close the inspection screen.
+    pub close_action: GameAction,
+}
+
+/// A kind of counter placed on a card.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CardCounterView {
+    pub name: String,
+    pub count: u32,
+}
+
+/// An effect applied to a card for a limited time.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TemporaryEffectView {
+    /// Description of the effect, e.g. "Anchored".
+    pub description: String,
+
+    /// Player who controlled the ability which created this effect.
+    pub controller: DisplayPlayer,
+
+    /// Description of when this effect ends.
+    pub duration: String,
+}
+
+/// Frame to display around a card's image, distinguishing tokens and copies
+/// from the card they were created from.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum CardFrame {
+    #[default]
+    Standard,
+    AbilityToken,
+    Copy,
+    Figment,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum CardPrefab {
     Character,
@@ -0,0 +1,37 @@
+use core_data::identifiers::BaseCardId;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::card_view::DisplayImage;
+
+/// Represents the visual state of the collection browser.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CollectionView {
+    /// Cards owned by the player, sorted by name.
+    pub cards: Vec<CollectionCardView>,
+
+    /// Total number of cards owned, counting each copy.
+    pub total_cards: u32,
+}
+
+/// A card owned by the player within the collection browser.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CollectionCardView {
+    /// Identifies the card definition.
+    pub card_id: BaseCardId,
+
+    /// Name of this card.
+    pub name: String,
+
+    /// Image to display for this card, from its selected variant if any.
+    pub image: DisplayImage,
+
+    /// Whether this card is displayed with a foil treatment.
+    pub is_foil: bool,
+
+    /// Number of copies of this card which are owned.
+    pub count: u32,
+
+    /// Descriptions of how copies of this card were unlocked.
+    pub unlocked_by: Vec<String>,
+}
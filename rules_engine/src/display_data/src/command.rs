@@ -1,3 +1,4 @@
+use action_data::emote_data::Emote;
 use bon::Builder;
 use core_data::display_color::DisplayColor;
 use core_data::display_types::{
@@ -5,7 +6,7 @@ use core_data::display_types::{
     StudioAnimation,
 };
 use core_data::identifiers::SiteId;
-use core_data::numerics::{Energy, Points};
+use core_data::numerics::{Energy, Points, TurnId};
 use masonry::flex_node::FlexNode;
 use masonry::flex_style::FlexVector3;
 use schemars::JsonSchema;
@@ -13,9 +14,12 @@ use serde::{Deserialize, Serialize};
 use strum::EnumDiscriminants;
 
 use crate::battle_view::{BattleView, DisplayPlayer};
+use crate::battle_view_delta::BattleViewDelta;
 use crate::card_view::{CardView, ClientCardId};
+use crate::collection_view::CollectionView;
 use crate::object_position::{ObjectPosition, Position};
 use crate::quest_view::QuestView;
+use crate::request_data::ClientCapability;
 
 /// A list of [ParallelCommandGroup]s to execute sequentially.
 #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
@@ -34,7 +38,9 @@ pub struct ParallelCommandGroup {
 #[strum_discriminants()]
 pub enum Command {
     UpdateBattle(Box<UpdateBattleCommand>),
+    UpdateBattleDelta(Box<UpdateBattleDeltaCommand>),
     UpdateQuest(Box<UpdateQuestCommand>),
+    UpdateCollection(Box<UpdateCollectionCommand>),
     Wait(Milliseconds),
     FireProjectile(FireProjectileCommand),
     DissolveCard(DissolveCardCommand),
@@ -45,12 +51,16 @@ pub enum Command {
     DisplayJudgment(DisplayJudgmentCommand),
     DisplayDreamwellActivation(DisplayDreamwellActivationCommand),
     DisplayEnemyMessage(DisplayEnemyMessageCommand),
+    DisplayNotification(DisplayNotificationCommand),
     PlayStudioAnimation(PlayStudioAnimationCommand),
     PlayMecanimAnimation(PlayMecanimAnimationCommand),
     SetCardTrail(SetCardTrailCommand),
     ShuffleVoidIntoDeck(ShuffleVoidIntoDeckCommand),
     UpdateScreenOverlay(Box<UpdateScreenOverlayCommand>),
     AnchorToScreenPosition(Box<AnchorToScreenPositionCommand>),
+    DisplayCinematic(DisplayCinematicCommand),
+    WaitForInput(WaitForInputCommand),
+    DisplayEmote(DisplayEmoteCommand),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -62,6 +72,17 @@ pub struct UpdateBattleCommand {
     pub update_sound: Option<AudioClipAddress>,
 }
 
+/// Updates the battle by applying changes to the battle view sent in the
+/// previous [UpdateBattleCommand] or [UpdateBattleDeltaCommand].
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateBattleDeltaCommand {
+    /// Changes to apply to the battle.
+    pub delta: BattleViewDelta,
+
+    /// Sound to play when the battle is updated.
+    pub update_sound: Option<AudioClipAddress>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateQuestCommand {
     /// The quest to update.
@@ -71,6 +92,19 @@ pub struct UpdateQuestCommand {
     pub update_sound: Option<AudioClipAddress>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateCollectionCommand {
+    /// The collection to display in the collection browser.
+    pub collection: CollectionView,
+}
+
+/// Pauses command processing until the user clicks to continue.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WaitForInputCommand {
+    /// If provided, continue automatically after this duration.
+    pub timeout: Option<Milliseconds>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Builder)]
 pub struct FireProjectileCommand {
     // The source to fire the projectile from.
@@ -229,6 +263,42 @@ pub struct DisplayJudgmentCommand {
     pub new_score: Option<Points>,
 }
 
+/// Plays a full-screen cinematic marking a change in the flow of the battle.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayCinematicCommand {
+    /// The cinematic to play.
+    pub cinematic: CinematicType,
+
+    /// The player the cinematic is about: the player whose turn is starting,
+    /// the player being judged, or the winner of the battle.
+    pub player: DisplayPlayer,
+
+    /// Name to display for `player`.
+    pub player_name: String,
+
+    /// Turn number during which the cinematic is played.
+    pub turn_number: TurnId,
+
+    /// Points gained by `player` as a result of this event. Zero if no points
+    /// were gained.
+    pub points_delta: Points,
+
+    /// Score of `player` after this event.
+    pub score: Points,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum CinematicType {
+    /// Banner shown at the start of a player's turn.
+    TurnStart,
+
+    /// Sweep shown as a player's judgment phase resolves.
+    JudgmentSweep,
+
+    /// Shown when a player wins the battle.
+    Victory,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DisplayDreamwellActivationCommand {
     /// The player to display the dreamwell activation for.
@@ -273,6 +343,52 @@ pub struct DisplayEnemyMessageCommand {
     pub show_duration: Milliseconds,
 }
 
+/// Shows a transient notification to the user, e.g. "Opponent reconnected".
+///
+/// Notifications are dismissed automatically after [Self::duration] and do not
+/// persist in the battle view, unlike interface messages describing the
+/// current prompt.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayNotificationCommand {
+    /// Localized text of the notification.
+    pub text: String,
+
+    pub severity: NotificationSeverity,
+
+    /// How long to show the notification before dismissing it.
+    pub duration: Milliseconds,
+}
+
+/// Shows an emote sent by a player, e.g. as a speech bubble next to their
+/// identity card.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayEmoteCommand {
+    /// Player who sent the emote.
+    pub player: DisplayPlayer,
+
+    pub emote: Emote,
+
+    /// Localized text of the emote.
+    pub text: String,
+
+    pub show_duration: Milliseconds,
+}
+
+/// How important a notification is, used to select its visual style.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub enum NotificationSeverity {
+    /// Information about something which happened, such as a player
+    /// reconnecting.
+    Info,
+
+    /// Information about a game rule which changed the result of an action,
+    /// such as a draw being converted to energy.
+    Warning,
+
+    /// A problem the user should know about, such as a lost connection.
+    Error,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PlayStudioAnimationCommand {
     pub studio_type: StudioType,
@@ -391,6 +507,21 @@ impl CommandSequence {
     }
 }
 
+impl Command {
+    /// Returns the capability a client must declare when connecting in order
+    /// to receive this command, or None if all clients support it.
+    pub fn required_capability(&self) -> Option<ClientCapability> {
+        match self {
+            Command::UpdateBattleDelta(_) => Some(ClientCapability::BattleViewDeltas),
+            Command::WaitForInput(_) => Some(ClientCapability::InputBarriers),
+            Command::DisplayNotification(_) => Some(ClientCapability::Notifications),
+            Command::DisplayEmote(_) => Some(ClientCapability::Emotes),
+            Command::UpdateCollection(_) => Some(ClientCapability::CollectionBrowser),
+            _ => None,
+        }
+    }
+}
+
 impl UpdateBattleCommand {
     pub fn new(battle: BattleView) -> Self {
         Self { battle, update_sound: None }
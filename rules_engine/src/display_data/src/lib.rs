@@ -1,6 +1,9 @@
+pub mod battle_log_view;
 pub mod battle_view;
+pub mod battle_view_delta;
 pub mod card_view;
 pub mod client_log_request;
+pub mod collection_view;
 pub mod command;
 pub mod object_position;
 pub mod quest_view;
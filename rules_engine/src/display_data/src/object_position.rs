@@ -55,7 +55,7 @@ pub enum Position {
     InBanished(DisplayPlayer),
 
     /// Object is on the battlefield
-    OnBattlefield(DisplayPlayer),
+    OnBattlefield(BattlefieldPosition),
 
     /// Object is in a player's status zone
     InPlayerStatus(DisplayPlayer),
@@ -144,6 +144,27 @@ pub enum Position {
     StartBattleDisplay(StartBattleDisplayType),
 }
 
+/// Location of an object on a player's battlefield.
+///
+/// Objects with the same player but different rows or lanes are in different
+/// positions, so code which cares only about which battlefield an object is on
+/// should compare [Self::player].
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, Ord, PartialOrd, JsonSchema,
+)]
+pub struct BattlefieldPosition {
+    /// Player whose battlefield the object is on.
+    pub player: DisplayPlayer,
+
+    /// Row of the battlefield the object is in, where 0 is the row closest to
+    /// the center of the screen. None if the object has no assigned row.
+    pub row: Option<u32>,
+
+    /// Lane within the row the object is in, counting from the left edge of
+    /// the screen. None if the object has no assigned lane.
+    pub lane: Option<u32>,
+}
+
 #[derive(
     Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, Ord, PartialOrd, JsonSchema,
 )]
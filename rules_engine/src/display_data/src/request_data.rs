@@ -1,12 +1,15 @@
 use action_data::game_action_data::GameAction;
+use ai_data::personality::AiPersonality;
 use battle_state::battle_player::battle_player_state::{PlayerType, TestDeckName};
-use core_data::identifiers::{BattleId, UserId};
+use core_data::identifiers::{BattleId, SaveSlotId, UserId};
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use strum::EnumString;
 use tabula_generated::card_lists::DreamwellCardIdList;
 use ui_components::display_properties::DisplayProperties;
 use uuid::Uuid;
 
+use crate::battle_view::{BattleView, PendingPromptView};
 use crate::client_log_request::{ClientLogRequest, ClientLogResponse};
 use crate::command::CommandSequence;
 
@@ -14,6 +17,40 @@ pub type RequestId = Uuid;
 
 pub type IntegrationTestId = Uuid;
 
+/// Version of the display protocol implemented by the rules engine.
+///
+/// Incremented whenever the set of [ClientCapability] values changes, so that
+/// clients and servers shipped on different schedules can tell which features
+/// the other side knows about.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Optional display protocol features a client can declare support for when
+/// connecting.
+///
+/// Commands which require a capability the client has not declared are
+/// omitted from responses.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, EnumString,
+)]
+pub enum ClientCapability {
+    /// Client can apply [crate::command::UpdateBattleDeltaCommand] updates.
+    /// Otherwise, every battle update contains a full snapshot.
+    BattleViewDeltas,
+
+    /// Client can pause for a [crate::command::WaitForInputCommand].
+    InputBarriers,
+
+    /// Client can show [crate::command::DisplayNotificationCommand] toasts.
+    Notifications,
+
+    /// Client can show [crate::command::DisplayEmoteCommand] emotes.
+    Emotes,
+
+    /// Client can show [crate::command::UpdateCollectionCommand] in its
+    /// collection browser.
+    CollectionBrowser,
+}
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Metadata {
     /// ID of the user making the request.
@@ -62,6 +99,65 @@ pub struct ConnectRequest {
     /// If specified, the battle will be created with the given debug
     /// configuration.
     pub debug_configuration: Option<DebugConfiguration>,
+
+    /// True if the client can apply [crate::command::UpdateBattleDeltaCommand]
+    /// updates. Otherwise, every battle update contains a full snapshot.
+    #[serde(default)]
+    pub battle_view_deltas: bool,
+
+    /// Language to render text for this user in, e.g. "ru". If not specified,
+    /// text is rendered in the default language.
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// True if text for this user should be colored using a palette which
+    /// remains distinguishable with color vision deficiency.
+    #[serde(default)]
+    pub colorblind_mode: bool,
+
+    /// Version of the display protocol the client implements, see
+    /// [PROTOCOL_VERSION]. Clients from before protocol versioning omit this
+    /// and are treated as version 0.
+    #[serde(default)]
+    pub protocol_version: u32,
+
+    /// Optional protocol features the client supports. Capabilities this
+    /// version of the rules engine does not recognize are ignored.
+    #[serde(default, deserialize_with = "deserialize_capabilities")]
+    #[schemars(with = "Vec<ClientCapability>")]
+    pub capabilities: Vec<ClientCapability>,
+
+    /// True if scripted tutorial messages should be shown to this user, e.g.
+    /// in the first-time-user tutorial battle.
+    #[serde(default)]
+    pub tutorial: bool,
+
+    /// How this user would like animations to be played.
+    #[serde(default)]
+    pub animation_preferences: AnimationPreferences,
+
+    /// Save slot to load this user's quest from and save it to. Users can keep
+    /// an independent quest in each slot.
+    #[serde(default)]
+    pub save_slot: SaveSlotId,
+}
+
+/// Per-user preferences controlling the timing of animations sent to the
+/// client.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AnimationPreferences {
+    /// Factor by which to speed up animations, e.g. 2.0 plays them in half the
+    /// usual time. Values which are not positive are treated as 1.0.
+    pub speed_multiplier: f32,
+
+    /// True if purely decorative effects such as projectiles, particle effects
+    /// and cinematics should be omitted.
+    pub skip_non_essential_effects: bool,
+
+    /// True if animations which move objects across the screen should be
+    /// omitted or played without staggering.
+    pub reduce_motion: bool,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
@@ -69,6 +165,10 @@ pub struct DebugConfiguration {
     /// If specified, the enemy will be this player type.
     pub enemy: Option<PlayerType>,
 
+    /// If specified, the enemy's AI search will be biased toward this play
+    /// style.
+    pub enemy_personality: Option<AiPersonality>,
+
     /// If specified, the battle will be seeded with the given value. Otherwise
     /// a random seed will be used.
     pub seed: Option<u64>,
@@ -86,6 +186,11 @@ pub struct ConnectResponse {
     pub metadata: Metadata,
     pub commands: CommandSequence,
     pub response_version: Uuid,
+
+    /// Version of the display protocol implemented by the rules engine, see
+    /// [PROTOCOL_VERSION].
+    #[serde(default)]
+    pub protocol_version: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -108,6 +213,37 @@ pub struct PerformActionResponse {
     pub commands: CommandSequence,
 }
 
+/// Requests the complete current state of a battle, e.g. after a client
+/// reconnects following a dropped connection.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ResyncRequest {
+    /// Identifies the user and the battle they expect to be in. If
+    /// `battle_id` is specified, the request fails if the user's current
+    /// battle has a different ID.
+    pub metadata: Metadata,
+
+    /// If specified, reads the battle from the save file with this ID instead
+    /// of the user's own save file, as in [PerformActionRequest].
+    pub save_file_id: Option<UserId>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ResyncResponse {
+    pub metadata: Metadata,
+
+    /// Complete current state of the battle. None if the battle could not be
+    /// loaded, in which case `error` describes why.
+    pub battle: Option<BattleView>,
+
+    /// Prompt the user owes a response to, if any.
+    pub pending_prompt: Option<PendingPromptView>,
+
+    /// Version to send as `last_response_version` with the next action.
+    pub response_version: Uuid,
+
+    pub error: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PollRequest {
     pub metadata: Metadata,
@@ -136,6 +272,24 @@ pub struct SchemaTypes {
     pub perform_action_response: PerformActionResponse,
     pub poll_request: PollRequest,
     pub poll_response: PollResponse,
+    pub resync_request: ResyncRequest,
+    pub resync_response: ResyncResponse,
     pub client_log_request: ClientLogRequest,
     pub client_log_response: ClientLogResponse,
 }
+
+impl Default for AnimationPreferences {
+    fn default() -> Self {
+        Self { speed_multiplier: 1.0, skip_non_essential_effects: false, reduce_motion: false }
+    }
+}
+
+/// Deserializes a list of [ClientCapability] names, skipping any which are not
+/// recognized so that newer clients can still connect.
+fn deserialize_capabilities<'de, D>(deserializer: D) -> Result<Vec<ClientCapability>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let names = Vec::<String>::deserialize(deserializer)?;
+    Ok(names.iter().filter_map(|name| name.parse().ok()).collect())
+}
@@ -86,6 +86,7 @@ pub fn create_and_start(
         players: PlayerMap {
             one: BattlePlayerState {
                 player_type: player_one.player_type,
+                personality: player_one.personality,
                 points: Points(0),
                 spark_bonus: Spark(0),
                 current_energy: Energy(0),
@@ -96,6 +97,7 @@ pub fn create_and_start(
             },
             two: BattlePlayerState {
                 player_type: player_two.player_type,
+                personality: player_two.personality,
                 points: Points(0),
                 spark_bonus: Spark(0),
                 current_energy: Energy(0),
@@ -119,6 +121,7 @@ pub fn create_and_start(
         ability_state: AbilityState::default(),
         pending_effects: VecDeque::new(),
         tracing: None,
+        last_ai_decision: None,
         action_history: None,
         turn_history: TurnHistory::default(),
         request_context,
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use core_data::identifiers::UserId;
+
+use crate::server_messages::ClientBattleId;
+
+/// Tracks the battles hosted by the game server and the users connected to
+/// them.
+///
+/// Battle state is stored in the save file of the user who created the battle,
+/// its host. The second user to join plays against the host by loading the
+/// host's save file, which replaces the host's AI opponent.
+#[derive(Debug, Default)]
+pub struct BattleRegistry {
+    battles: HashMap<ClientBattleId, HostedBattle>,
+    connected: HashSet<UserId>,
+}
+
+/// Users playing in a battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostedBattle {
+    /// User who created the battle, whose save file stores it.
+    pub host: UserId,
+
+    /// User playing against the host, if they have joined.
+    pub guest: Option<UserId>,
+}
+
+/// Reason a user could not join a battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinError {
+    /// The user already has an open connection to the server.
+    AlreadyConnected,
+
+    /// Both players in the battle are other users.
+    BattleFull,
+}
+
+impl BattleRegistry {
+    /// Records that `user_id` has connected to `battle`, creating the battle
+    /// with `user_id` as its host if it does not exist.
+    ///
+    /// Users who are already playing in the battle may rejoin it after
+    /// disconnecting.
+    pub fn join(
+        &mut self,
+        battle: &ClientBattleId,
+        user_id: UserId,
+    ) -> Result<HostedBattle, JoinError> {
+        if self.connected.contains(&user_id) {
+            return Err(JoinError::AlreadyConnected);
+        }
+
+        let hosted = self
+            .battles
+            .entry(battle.clone())
+            .or_insert(HostedBattle { host: user_id, guest: None });
+        if hosted.host != user_id {
+            match hosted.guest {
+                None => hosted.guest = Some(user_id),
+                Some(guest) if guest == user_id => {}
+                Some(_) => return Err(JoinError::BattleFull),
+            }
+        }
+
+        self.connected.insert(user_id);
+        Ok(*hosted)
+    }
+
+    /// Records that `user_id` has disconnected.
+    ///
+    /// The user's battle is kept so that they can rejoin it.
+    pub fn leave(&mut self, user_id: UserId) {
+        self.connected.remove(&user_id);
+    }
+
+    /// Returns the users playing in a battle, if it exists.
+    pub fn battle(&self, battle: &ClientBattleId) -> Option<HostedBattle> {
+        self.battles.get(battle).copied()
+    }
+}
+
+impl HostedBattle {
+    /// Returns the user whose save file should be loaded to act in this battle
+    /// as `user_id`, or None if this is the user's own save file.
+    pub fn save_file_for(&self, user_id: UserId) -> Option<UserId> {
+        (user_id != self.host).then_some(self.host)
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::AlreadyConnected => write!(f, "User is already connected"),
+            JoinError::BattleFull => write!(f, "Battle already has two players"),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use axum::extract::ws::{Message, WebSocket};
+use battle_state::battle::battle_state::RequestContext;
+use core_data::identifiers::UserId;
+use display_data::request_data::{ConnectRequest, Metadata, PerformActionRequest};
+use rules_engine::engine;
+use tokio::{task, time};
+use tracing::{error, info};
+
+use crate::battle_registry::{BattleRegistry, HostedBattle};
+use crate::server_messages::{ClientBattleId, ClientMessage, ServerMessage};
+
+/// Interval at which the rules engine is checked for updates to send to a
+/// connected client.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// State shared by every connection to the game server.
+pub struct ServerState {
+    /// Directory in which battles are saved, used in place of the path
+    /// provided by clients.
+    pub persistent_data_path: String,
+
+    /// Directory containing game data, used in place of the path provided by
+    /// clients.
+    pub streaming_assets_path: String,
+
+    pub request_context: RequestContext,
+
+    pub registry: Mutex<BattleRegistry>,
+}
+
+/// Serves the connect/act protocol for a single client until it disconnects.
+///
+/// Updates for the connected user are forwarded as they become available,
+/// including updates caused by the opponent's actions.
+pub async fn run(mut socket: WebSocket, state: Arc<ServerState>) {
+    let mut session: Option<Session> = None;
+    let mut poll_interval = time::interval(POLL_INTERVAL);
+    'connection: loop {
+        tokio::select! {
+            message = socket.recv() => {
+                let Some(Ok(message)) = message else {
+                    break 'connection;
+                };
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                if let Some(response) = handle_message(&state, &mut session, text.as_str()).await
+                    && send(&mut socket, &response).await.is_err()
+                {
+                    break 'connection;
+                }
+            }
+            _ = poll_interval.tick() => {
+                let Some(session) = &session else {
+                    continue;
+                };
+                while let Some(update) = engine::poll(session.user_id, session.metadata) {
+                    if send(&mut socket, &ServerMessage::Update(Box::new(update))).await.is_err() {
+                        break 'connection;
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(session) = session {
+        info!(user_id = ?session.user_id, "Client disconnected");
+        registry(&state).leave(session.user_id);
+    }
+}
+
+/// User a connection is bound to and the battle they are playing in.
+struct Session {
+    user_id: UserId,
+    metadata: Metadata,
+    battle: HostedBattle,
+}
+
+async fn handle_message(
+    state: &ServerState,
+    session: &mut Option<Session>,
+    text: &str,
+) -> Option<ServerMessage> {
+    let message = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) => message,
+        Err(e) => return Some(ServerMessage::Error(format!("Invalid message: {e}"))),
+    };
+    match message {
+        ClientMessage::Connect { battle, request } => {
+            Some(connect(state, session, battle, *request).await)
+        }
+        ClientMessage::PerformAction(request) => {
+            perform_action(session.as_ref(), *request).err().map(ServerMessage::Error)
+        }
+    }
+}
+
+async fn connect(
+    state: &ServerState,
+    session: &mut Option<Session>,
+    battle: ClientBattleId,
+    mut request: ConnectRequest,
+) -> ServerMessage {
+    if session.is_some() {
+        return ServerMessage::Error("Connection has already joined a battle".to_string());
+    }
+
+    let user_id = request.metadata.user_id;
+    let hosted = match registry(state).join(&battle, user_id) {
+        Ok(hosted) => hosted,
+        Err(e) => return ServerMessage::Error(e.to_string()),
+    };
+    info!(?user_id, ?battle, host = ?hosted.host, "Client joined battle");
+
+    request.persistent_data_path = state.persistent_data_path.clone();
+    request.streaming_assets_path = state.streaming_assets_path.clone();
+    request.vs_opponent = hosted.save_file_for(user_id);
+    let metadata = request.metadata;
+    let request_context = state.request_context.clone();
+    match task::spawn_blocking(move || engine::connect(&request, request_context)).await {
+        Ok(response) => {
+            *session = Some(Session { user_id, metadata, battle: hosted });
+            ServerMessage::Connected(Box::new(response))
+        }
+        Err(e) => {
+            error!(?user_id, error.message = %e, "Connect request failed");
+            registry(state).leave(user_id);
+            ServerMessage::Error(format!("Connect request failed: {e}"))
+        }
+    }
+}
+
+fn perform_action(
+    session: Option<&Session>,
+    mut request: PerformActionRequest,
+) -> Result<(), String> {
+    let Some(session) = session else {
+        return Err("Must join a battle before performing actions".to_string());
+    };
+    if request.metadata.user_id != session.user_id {
+        return Err(format!("Connection is bound to user {:?}", session.user_id));
+    }
+
+    request.save_file_id = session.battle.save_file_for(session.user_id);
+    engine::perform_action(request);
+    Ok(())
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), ()> {
+    let json = serde_json::to_string(message).map_err(|e| {
+        error!(error.message = %e, "Failed to serialize server message");
+    })?;
+    socket.send(Message::Text(json.into())).await.map_err(|e| {
+        info!(error.message = %e, "Failed to send server message");
+    })
+}
+
+fn registry(state: &ServerState) -> MutexGuard<'_, BattleRegistry> {
+    state.registry.lock().unwrap_or_else(PoisonError::into_inner)
+}
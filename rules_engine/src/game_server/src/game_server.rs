@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+
+use axum::Router;
+use axum::extract::State;
+use axum::extract::ws::WebSocketUpgrade;
+use axum::response::Response;
+use axum::routing::get;
+use battle_state::battle::battle_state::{LoggingOptions, RequestContext};
+use clap::Parser;
+use game_server::battle_registry::BattleRegistry;
+use game_server::connection::{self, ServerState};
+use tracing::{error, info};
+
+#[derive(Parser)]
+#[command(version, about = "Hosts battles between human players over WebSockets")]
+struct Args {
+    #[arg(long, default_value = "26599", help = "Port to listen on")]
+    port: u16,
+
+    #[arg(
+        long,
+        default_value = "/tmp/dreamtides_server",
+        help = "Directory in which to store battle save files"
+    )]
+    persistent_data_path: String,
+
+    #[arg(
+        long,
+        help = "Directory containing Tabula game data (default: the client's StreamingAssets directory)"
+    )]
+    streaming_assets_path: Option<String>,
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    let request_context = RequestContext {
+        logging_options: LoggingOptions {
+            log_directory: logging::get_developer_mode_project_directory().ok(),
+            log_ai_search_diagram: false,
+            enable_action_legality_check: true,
+        },
+    };
+    logging::maybe_initialize(&request_context);
+
+    let state = Arc::new(ServerState {
+        persistent_data_path: args.persistent_data_path,
+        streaming_assets_path: args
+            .streaming_assets_path
+            .unwrap_or_else(logging::get_developer_mode_streaming_assets_path),
+        request_context,
+        registry: Mutex::new(BattleRegistry::default()),
+    });
+    let app = Router::new().route("/battle", get(battle)).with_state(state);
+
+    let address = format!("0.0.0.0:{}", args.port);
+    let listener = tokio::net::TcpListener::bind(&address).await.unwrap_or_else(|e| {
+        error!(error.message = %e, ?address, "Failed to bind game server");
+        panic!("Server initialization failed: {e}");
+    });
+
+    info!("Game server running on ws://{address}/battle");
+    axum::serve(listener, app).await.unwrap_or_else(|e| {
+        error!(error.message = %e, "Server error");
+        panic!("Server error: {e}");
+    });
+}
+
+async fn battle(socket: WebSocketUpgrade, State(state): State<Arc<ServerState>>) -> Response {
+    socket.on_upgrade(move |socket| connection::run(socket, state))
+}
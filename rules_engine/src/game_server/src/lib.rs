@@ -0,0 +1,3 @@
+pub mod battle_registry;
+pub mod connection;
+pub mod server_messages;
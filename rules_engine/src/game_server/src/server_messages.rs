@@ -0,0 +1,39 @@
+use display_data::request_data::{
+    ConnectRequest, ConnectResponse, PerformActionRequest, PollResponse,
+};
+use serde::{Deserialize, Serialize};
+
+/// Identifies a battle hosted by the game server.
+///
+/// Chosen by the client which creates the battle and shared with its opponent
+/// outside of the server, e.g. as a lobby code.
+pub type ClientBattleId = String;
+
+/// A message sent from a client to the game server over a WebSocket.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// Joins a battle, creating it if no battle with this ID exists.
+    ///
+    /// Must be the first message sent on a connection. The connection is
+    /// bound to the user ID in the request's metadata, and all later messages
+    /// on the connection are performed as that user.
+    Connect { battle: ClientBattleId, request: Box<ConnectRequest> },
+
+    /// Performs a game action in the connected battle.
+    PerformAction(Box<PerformActionRequest>),
+}
+
+/// A message sent from the game server to a client over a WebSocket.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// Response to [ClientMessage::Connect] containing the current state of
+    /// the battle.
+    Connected(Box<ConnectResponse>),
+
+    /// Commands to apply to the battle, sent whenever either player's actions
+    /// produce updates for this user.
+    Update(Box<PollResponse>),
+
+    /// A message from the client could not be handled.
+    Error(String),
+}
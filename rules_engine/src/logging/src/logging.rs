@@ -79,7 +79,11 @@ fn initialize(request_context: &RequestContext) {
     let env_filter =
         env::var("RUST_LOG").map(EnvFilter::new).unwrap_or_else(|_| EnvFilter::new("debug"));
 
-    match request_context.logging_options.log_directory.as_ref() {
+    // File logging is only available with the "native" feature, since there
+    // is no filesystem on WASM targets.
+    let log_directory =
+        request_context.logging_options.log_directory.as_ref().filter(|_| cfg!(feature = "native"));
+    match log_directory {
         Some(log_directory) => {
             // Set up dual output to stdout and file
             let log_path = log_directory.join("dreamtides.log");
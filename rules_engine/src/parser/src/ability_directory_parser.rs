@@ -3,16 +3,14 @@ use std::fs;
 use std::path::Path;
 
 use ability_data::ability::Ability;
-use chumsky::span::SimpleSpan;
-use chumsky::Parser as ChumskyParser;
 use toml::Value;
 
 use crate::error::parser_diagnostics;
 use crate::error::parser_errors::ParserError;
-use crate::lexer::lexer_tokenize;
 use crate::parser::ability_parser;
+use crate::rules_text_parser;
+use crate::rules_text_parser::ResolvedAbility;
 use crate::variables::parser_bindings::VariableBindings;
-use crate::variables::parser_substitutions::{self, ResolvedToken};
 
 /// Parses all TOML files in a directory and extracts abilities from entries
 /// containing `id`, `rules-text`, and `variables` fields.
@@ -66,91 +64,39 @@ pub fn parse_abilities_from_directory(
             }
         };
 
-        let ability_texts: Vec<&str> =
-            entry.rules_text.split("\n\n").map(str::trim).filter(|s| !s.is_empty()).collect();
-
-        let mut ability_resolved_tokens = Vec::new();
-        let mut had_error = false;
-
-        for ability_text in ability_texts {
-            let lex_result = match lexer_tokenize::lex(ability_text) {
-                Ok(r) => r,
-                Err(e) => {
-                    eprintln!(
-                        "Error lexing '{}' ({}): {}",
-                        entry.name,
-                        entry.id,
-                        parser_diagnostics::format_error(
-                            &ParserError::from(e),
-                            ability_text,
-                            &entry.name
-                        )
-                    );
-                    had_error = true;
-                    break;
-                }
-            };
-
-            let resolved =
-                match parser_substitutions::resolve_variables(&lex_result.tokens, &bindings) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        eprintln!(
-                            "Error resolving variables for '{}' ({}): {}",
-                            entry.name,
-                            entry.id,
-                            parser_diagnostics::format_error(
-                                &ParserError::from(e),
-                                ability_text,
-                                &entry.name
-                            )
-                        );
-                        had_error = true;
-                        break;
-                    }
-                };
-
-            ability_resolved_tokens.push((ability_text.to_string(), resolved));
-        }
-
-        if !had_error {
+        // Cards are only included if every ability parses, so that a game
+        // never uses a card with some of its abilities missing.
+        let resolved = rules_text_parser::resolve_rules_text(&entry.rules_text, &bindings);
+        if resolved.diagnostics.is_empty() {
             resolved_entries.push(ResolvedEntry {
                 id: entry.id,
                 name: entry.name,
-                resolved_abilities: ability_resolved_tokens,
+                resolved_abilities: resolved.abilities,
             });
+        } else {
+            eprintln!(
+                "Error resolving '{}' ({}):\n{}",
+                entry.name,
+                entry.id,
+                rules_text_parser::format_diagnostics(&resolved.diagnostics, &entry.name)
+            );
         }
     }
 
     let parser = ability_parser::ability_parser();
 
     for entry in &resolved_entries {
-        let mut abilities = Vec::new();
-        let mut had_error = false;
-
-        for (ability_text, resolved) in &entry.resolved_abilities {
-            match parser.parse(resolved).into_result() {
-                Ok(ability) => {
-                    abilities.push(ability);
-                }
-                Err(errors) => {
-                    let error_strs: Vec<String> =
-                        errors.iter().map(|e| format!("{:?}", e.reason())).collect();
-                    eprintln!(
-                        "Parse error in '{}' ({}): {} (text: \"{}\")",
-                        entry.name,
-                        entry.id,
-                        error_strs.join(", "),
-                        ability_text
-                    );
-                    had_error = true;
-                    break;
-                }
-            }
-        }
-
-        if !had_error && !abilities.is_empty() {
-            results.entry(entry.id.clone()).or_default().extend(abilities);
+        let parsed =
+            rules_text_parser::parse_resolved_abilities(&parser, &entry.resolved_abilities);
+        if !parsed.diagnostics.is_empty() {
+            eprintln!(
+                "Parse error in '{}' ({}):\n{}",
+                entry.name,
+                entry.id,
+                rules_text_parser::format_diagnostics(&parsed.diagnostics, &entry.name)
+            );
+        } else if !parsed.abilities.is_empty() {
+            results.entry(entry.id.clone()).or_default().extend(parsed.abilities);
         }
     }
 
@@ -167,7 +113,7 @@ struct EntryToParse {
 struct ResolvedEntry {
     id: String,
     name: String,
-    resolved_abilities: Vec<(String, Vec<(ResolvedToken, SimpleSpan)>)>,
+    resolved_abilities: Vec<ResolvedAbility>,
 }
 
 fn collect_entries_from_file(path: &Path) -> Result<Vec<EntryToParse>, Box<dyn std::error::Error>> {
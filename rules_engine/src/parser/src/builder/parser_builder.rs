@@ -39,6 +39,18 @@ pub fn build_spanned_ability(ability: &Ability, lex_result: &LexResult) -> Optio
                 name: SpannedText::new(lex_result.original.clone(), full_span),
             })
         }
+        Ability::WithAbilityWord(with_word) => {
+            let prefix_len = lex_result
+                .tokens
+                .iter()
+                .position(|(t, _)| matches!(t, Token::Word(w) if w == "--"))?
+                + 1;
+            let inner = LexResult {
+                tokens: lex_result.tokens[prefix_len..].to_vec(),
+                original: lex_result.original.clone(),
+            };
+            build_spanned_ability(&with_word.ability, &inner)
+        }
         Ability::Activated(_) => {
             let colon_idx =
                 lex_result.tokens.iter().position(|(t, _)| matches!(t, Token::Colon))?;
@@ -147,30 +159,16 @@ fn build_spanned_triggered(
 ) -> Option<SpannedAbility> {
     let is_once_per_turn =
         triggered.options.as_ref().map(|opts| opts.once_per_turn).unwrap_or(false);
-    let is_until_end_of_turn =
-        triggered.options.as_ref().map(|opts| opts.until_end_of_turn).unwrap_or(false);
-
-    let until_end_of_turn = if is_until_end_of_turn {
-        let until_span = SimpleSpan::new((), 0..17);
-        Some(SpannedText::new("Until end of turn".to_string(), until_span))
-    } else {
-        None
-    };
 
-    let once_per_turn = if is_once_per_turn && until_end_of_turn.is_none() {
+    let once_per_turn = if is_once_per_turn {
         let once_span = SimpleSpan::new((), 0..13);
         Some(SpannedText::new("Once per turn".to_string(), once_span))
     } else {
         None
     };
 
-    let trigger_start = if let Some(until_end_of_turn) = &until_end_of_turn {
-        until_end_of_turn.span.end() + 2
-    } else if let Some(once_per_turn) = &once_per_turn {
-        once_per_turn.span.end() + 2
-    } else {
-        0
-    };
+    let trigger_start =
+        if let Some(once_per_turn) = &once_per_turn { once_per_turn.span.end() + 2 } else { 0 };
 
     let is_keyword_trigger = matches!(triggered.trigger, TriggerEvent::Keywords(_));
 
@@ -196,7 +194,6 @@ fn build_spanned_triggered(
     let effect_span = SimpleSpan::new((), effect_start..effect_end);
 
     Some(SpannedAbility::Triggered(SpannedTriggeredAbility {
-        until_end_of_turn,
         once_per_turn,
         trigger: SpannedText::new(
             lex_result.original[trigger_span.into_range()].to_string(),
@@ -1,9 +1,9 @@
-use ariadne::{Color, Label, Report, ReportKind, Source};
-use chumsky::error::Rich;
+use ariadne::{CharSet, Color, Config, Label, Report, ReportKind, Source};
+use chumsky::error::{Rich, RichPattern};
 use chumsky::span::{SimpleSpan, Span};
 
 use crate::error::parser_error_suggestions;
-use crate::error::parser_errors::{LexError, ParserError};
+use crate::error::parser_errors::{LexError, ParseDiagnostic, ParserError};
 use crate::lexer::lexer_token::Token;
 use crate::variables::parser_substitutions::ResolvedToken;
 
@@ -54,14 +54,93 @@ pub fn format_error(error: &ParserError, source: &str, filename: &str) -> String
 
             report.finish().write((filename, Source::from(source)), &mut output).unwrap();
         }
-        ParserError::Parse { span, error } => {
-            format_parse_error(error, *span, source, filename, &mut output);
+        ParserError::Parse(diagnostic) => {
+            format_parse_error(diagnostic, source, filename, &mut output);
         }
     }
 
     String::from_utf8(output).unwrap()
 }
 
+/// Describes `error` in a single line without source context, for display
+/// alongside the text in an editor.
+pub fn error_summary(error: &ParserError) -> String {
+    match error {
+        ParserError::Lex(LexError::UnclosedBrace { .. }) => {
+            "Unclosed brace, expected closing '}'".to_string()
+        }
+        ParserError::Lex(LexError::EmptyDirective { .. }) => {
+            "Empty directive, directives cannot be empty".to_string()
+        }
+        ParserError::Lex(LexError::UnclosedParenthesis { .. }) => {
+            "Unclosed parenthesis, expected closing ')' after reminder text".to_string()
+        }
+        ParserError::VariableParse(parse_error) => format!("Variable parsing error: {parse_error}"),
+        ParserError::UnresolvedVariable(unresolved) => {
+            match parser_error_suggestions::suggest_variable(&unresolved.name) {
+                Some(suggestions) => format!(
+                    "Variable '{}' not found in bindings. Did you mean '{}'?",
+                    unresolved.name,
+                    suggestions.join("', '")
+                ),
+                None => format!("Variable '{}' not found in bindings", unresolved.name),
+            }
+        }
+        ParserError::Parse(diagnostic) => {
+            let found = match &diagnostic.found {
+                Some(token) => format!("Unexpected {}", describe_token(token)),
+                None => "Unexpected end of input".to_string(),
+            };
+            if diagnostic.expected.is_empty() {
+                found
+            } else {
+                format!("{found}. Expected one of: {}", diagnostic.expected.join(", "))
+            }
+        }
+    }
+}
+
+/// Converts a chumsky error from parsing `tokens` into a [ParseDiagnostic].
+///
+/// Chumsky reports spans as token indices, so these are mapped back to byte
+/// ranges of the source text using the span of each token.
+pub fn parse_diagnostic(
+    error: &Rich<'_, (ResolvedToken, SimpleSpan), SimpleSpan>,
+    tokens: &[(ResolvedToken, SimpleSpan)],
+) -> ParseDiagnostic {
+    let mut expected = Vec::new();
+    for description in error.expected().filter_map(describe_pattern) {
+        if !expected.contains(&description) {
+            expected.push(description);
+        }
+    }
+
+    ParseDiagnostic {
+        span: byte_span(*error.span(), tokens),
+        found: error.found().map(|(token, _)| token.clone()),
+        expected,
+        clauses: error.contexts().filter_map(|(pattern, _)| describe_pattern(pattern)).collect(),
+    }
+}
+
+/// Formats every error from parsing `tokens` as an ability, separated by
+/// blank lines.
+pub fn format_parse_errors(
+    errors: &[Rich<'_, (ResolvedToken, SimpleSpan), SimpleSpan>],
+    tokens: &[(ResolvedToken, SimpleSpan)],
+    source: &str,
+    filename: &str,
+) -> String {
+    errors
+        .iter()
+        .map(|error| {
+            let error = ParserError::Parse(parse_diagnostic(error, tokens));
+            format_error(&error, source, filename)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
 fn format_lex_error(error: &LexError, source: &str, filename: &str, output: &mut Vec<u8>) {
     match error {
         LexError::UnclosedBrace { span } => {
@@ -84,6 +163,16 @@ fn format_lex_error(error: &LexError, source: &str, filename: &str, output: &mut
                 output,
             );
         }
+        LexError::UnclosedParenthesis { span } => {
+            build_lex_error_report(
+                "Unclosed parenthesis",
+                "Expected closing ')' after reminder text",
+                *span,
+                source,
+                filename,
+                output,
+            );
+        }
     }
 }
 
@@ -108,14 +197,12 @@ fn build_lex_error_report(
 }
 
 fn format_parse_error(
-    error: &Rich<'_, (ResolvedToken, SimpleSpan), SimpleSpan>,
-    span: SimpleSpan,
+    diagnostic: &ParseDiagnostic,
     source: &str,
     filename: &str,
     output: &mut Vec<u8>,
 ) {
-    let found_token = error.found().map(|(token, _)| token);
-    let (message, label_message, note) = match found_token {
+    let (message, label_message, note) = match &diagnostic.found {
         Some(ResolvedToken::Token(Token::Word(word))) => {
             if let Some(suggestions) = parser_error_suggestions::suggest_word(word) {
                 (
@@ -145,25 +232,94 @@ fn format_parse_error(
                 )
             }
         }
-        _ => {
-            let message = format!("Parse error: {:?}", error.reason());
-            (message, "Failed to parse ability text here".to_string(), None)
+        Some(token) => {
+            let token = describe_token(token);
+            (format!("Unexpected {token}"), format!("Found {token} here"), None)
         }
+        None => ("Unexpected end of input".to_string(), "Text ends here".to_string(), None),
     };
 
-    let mut report =
-        Report::<(&str, std::ops::Range<usize>)>::build(ReportKind::Error, filename, span.start())
-            .with_message(&message);
-
-    let label = Label::new((filename, span.start()..span.end()))
-        .with_message(&label_message)
-        .with_color(Color::Red);
+    let expected = if diagnostic.expected.is_empty() {
+        None
+    } else {
+        Some(format!("Expected one of: {}", diagnostic.expected.join(", ")))
+    };
+    let note = match (expected, note) {
+        (Some(expected), Some(note)) => Some(format!("{expected}\n{note}")),
+        (expected, note) => expected.or(note),
+    };
 
-    report = report.with_label(label);
+    let range = char_range(diagnostic.span, source);
+    let mut report =
+        Report::<(&str, std::ops::Range<usize>)>::build(ReportKind::Error, filename, range.start)
+            .with_config(Config::default().with_char_set(CharSet::Ascii))
+            .with_message(format!(
+                "{message} at bytes {}..{}",
+                diagnostic.span.start(),
+                diagnostic.span.end()
+            ))
+            .with_label(
+                Label::new((filename, range)).with_message(&label_message).with_color(Color::Red),
+            );
 
     if let Some(note_text) = note {
         report = report.with_note(note_text);
     }
 
+    if !diagnostic.clauses.is_empty() {
+        report = report.with_help(format!("While parsing {}", diagnostic.clauses.join(" in ")));
+    }
+
     report.finish().write((filename, Source::from(source)), output).unwrap();
 }
+
+/// Maps a span of token indices to the byte range those tokens cover.
+///
+/// A span past the last token maps to the end of the last token.
+fn byte_span(span: SimpleSpan, tokens: &[(ResolvedToken, SimpleSpan)]) -> SimpleSpan {
+    let end_of_input = tokens.last().map_or(0, |(_, token_span)| token_span.end());
+    let start = tokens.get(span.start()).map_or(end_of_input, |(_, token_span)| token_span.start());
+    let end = if span.end() > span.start() {
+        tokens.get(span.end() - 1).map_or(end_of_input, |(_, token_span)| token_span.end())
+    } else {
+        start
+    };
+    SimpleSpan::new((), start..end)
+}
+
+/// Maps a byte range of `source` to the character range used by ariadne,
+/// widening an empty range at the end of the text to cover its final
+/// character so the caret remains visible.
+fn char_range(span: SimpleSpan, source: &str) -> std::ops::Range<usize> {
+    let chars = |byte: usize| source.get(..byte).map_or(byte, |prefix| prefix.chars().count());
+    let (start, end) = (chars(span.start()), chars(span.end()));
+    if start == end && start > 0 && span.end() == source.len() {
+        start - 1..end
+    } else {
+        start..end
+    }
+}
+
+/// Describes an expected pattern, returning None for patterns which carry no
+/// useful information such as "any token".
+fn describe_pattern(pattern: &RichPattern<'_, (ResolvedToken, SimpleSpan)>) -> Option<String> {
+    match pattern {
+        RichPattern::Token(token) => Some(describe_token(&token.0)),
+        RichPattern::Label(label) => Some(label.to_string()),
+        RichPattern::EndOfInput => Some("end of input".to_string()),
+        _ => None,
+    }
+}
+
+fn describe_token(token: &ResolvedToken) -> String {
+    match token {
+        ResolvedToken::Token(Token::Word(word)) => format!("'{word}'"),
+        ResolvedToken::Token(Token::Directive(name)) => format!("'{{{name}}}'"),
+        ResolvedToken::Token(Token::Period) => "'.'".to_string(),
+        ResolvedToken::Token(Token::Comma) => "','".to_string(),
+        ResolvedToken::Token(Token::Colon) => "':'".to_string(),
+        ResolvedToken::Token(Token::Newline) => "line break".to_string(),
+        ResolvedToken::Token(Token::ReminderText(text)) => format!("'({text})'"),
+        token => format!("{token:?}"),
+    }
+}
@@ -1,4 +1,3 @@
-use chumsky::error::Rich;
 use chumsky::span::SimpleSpan;
 
 use crate::variables::parser_bindings::ParseError as VariableParseError;
@@ -11,6 +10,27 @@ pub enum LexError {
 
     #[error("Empty directive at position {}", span.start)]
     EmptyDirective { span: SimpleSpan },
+
+    #[error("Unclosed parenthesis starting at position {}", span.start)]
+    UnclosedParenthesis { span: SimpleSpan },
+}
+
+/// A failure to parse resolved tokens into an ability, located in the
+/// original ability text.
+#[derive(Debug, Clone)]
+pub struct ParseDiagnostic {
+    /// Byte range of the ability text at which parsing failed.
+    pub span: SimpleSpan,
+
+    /// Token found at the failure, or None if the text ended early.
+    pub found: Option<ResolvedToken>,
+
+    /// Descriptions of the words, symbols, and clauses which would have been
+    /// accepted instead.
+    pub expected: Vec<String>,
+
+    /// Clauses being parsed when the failure occurred, innermost first.
+    pub clauses: Vec<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -24,6 +44,23 @@ pub enum ParserError {
     #[error("Unresolved variable: {0}")]
     UnresolvedVariable(#[from] UnresolvedVariable),
 
-    #[error("Parse error at position {}", span.start)]
-    Parse { span: SimpleSpan, error: Rich<'static, (ResolvedToken, SimpleSpan), SimpleSpan> },
+    #[error("Parse error at bytes {}..{}", .0.span.start, .0.span.end)]
+    Parse(ParseDiagnostic),
+}
+
+impl ParserError {
+    /// Returns the byte range of the ability text this error refers to, or
+    /// None if it applies to the ability as a whole.
+    pub fn span(&self) -> Option<SimpleSpan> {
+        match self {
+            ParserError::Lex(
+                LexError::UnclosedBrace { span }
+                | LexError::EmptyDirective { span }
+                | LexError::UnclosedParenthesis { span },
+            ) => Some(*span),
+            ParserError::VariableParse(_) => None,
+            ParserError::UnresolvedVariable(unresolved) => Some(unresolved.span),
+            ParserError::Parse(diagnostic) => Some(diagnostic.span),
+        }
+    }
 }
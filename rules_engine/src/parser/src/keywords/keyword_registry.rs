@@ -0,0 +1,163 @@
+use ability_data::trigger_event::TriggerKeyword;
+use rlf::Phrase;
+use strings::strings;
+
+/// Every keyword recognized in rules text.
+///
+/// Adding a simple keyword only requires registering it here, defining its
+/// phrases in the strings crate, and wiring up the effect it produces.
+pub const KEYWORDS: &[KeywordDefinition] = &[
+    KeywordDefinition {
+        name: "judgment",
+        kind: KeywordKind::Trigger {
+            keyword: TriggerKeyword::Judgment,
+            prefix: strings::judgment,
+            display_name: strings::judgment_keyword_name,
+        },
+        reminder_text: None,
+    },
+    KeywordDefinition {
+        name: "materialized",
+        kind: KeywordKind::Trigger {
+            keyword: TriggerKeyword::Materialized,
+            prefix: strings::materialized,
+            display_name: strings::materialized_keyword_name,
+        },
+        reminder_text: None,
+    },
+    KeywordDefinition {
+        name: "dissolved",
+        kind: KeywordKind::Trigger {
+            keyword: TriggerKeyword::Dissolved,
+            prefix: strings::dissolved,
+            display_name: strings::dissolved_keyword_name,
+        },
+        reminder_text: None,
+    },
+    KeywordDefinition { name: "fast", kind: KeywordKind::Static, reminder_text: None },
+    KeywordDefinition {
+        name: "discover",
+        kind: KeywordKind::Effect,
+        reminder_text: Some(KeywordReminderText::Fixed(strings::reminder_text_discover)),
+    },
+    KeywordDefinition {
+        name: "dissolve",
+        kind: KeywordKind::Effect,
+        reminder_text: Some(KeywordReminderText::Fixed(strings::reminder_text_dissolve)),
+    },
+    KeywordDefinition {
+        name: "foresee",
+        kind: KeywordKind::Effect,
+        reminder_text: Some(KeywordReminderText::Counted(strings::reminder_text_foresee)),
+    },
+    KeywordDefinition {
+        name: "kindle",
+        kind: KeywordKind::Effect,
+        reminder_text: Some(KeywordReminderText::Counted(strings::reminder_text_kindle)),
+    },
+    KeywordDefinition {
+        name: "prevent",
+        kind: KeywordKind::Effect,
+        reminder_text: Some(KeywordReminderText::Fixed(strings::reminder_text_prevent)),
+    },
+    KeywordDefinition {
+        name: "reclaim",
+        kind: KeywordKind::Cost,
+        reminder_text: Some(KeywordReminderText::Fixed(strings::reminder_text_reclaim)),
+    },
+];
+
+/// A keyword recognized in rules text.
+#[derive(Debug, Clone)]
+pub struct KeywordDefinition {
+    /// Directive name of this keyword in rules text, e.g. "judgment" for
+    /// "{Judgment}".
+    pub name: &'static str,
+
+    pub kind: KeywordKind,
+
+    /// Standard reminder text explaining this keyword, if any.
+    pub reminder_text: Option<KeywordReminderText>,
+}
+
+/// How a keyword is used in rules text.
+#[derive(Debug, Clone)]
+pub enum KeywordKind {
+    /// Begins a triggered ability, e.g. "{Judgment} Draw a card."
+    Trigger {
+        keyword: TriggerKeyword,
+
+        /// Renders this keyword as the prefix of a triggered ability.
+        prefix: fn() -> Phrase,
+
+        /// Renders the name of this keyword when joined with other trigger
+        /// keywords.
+        display_name: fn() -> Phrase,
+    },
+
+    /// A property of a card, e.g. "{Fast}".
+    Static,
+
+    /// Names an alternate cost to play a card, e.g. "{Reclaim}".
+    Cost,
+
+    /// Names an effect, e.g. "{Dissolve} an enemy."
+    Effect,
+}
+
+/// Reminder text for a keyword.
+#[derive(Debug, Clone, Copy)]
+pub enum KeywordReminderText {
+    Fixed(fn() -> Phrase),
+
+    /// Reminder text describing the numeric argument of a keyword, e.g.
+    /// "{Foresee(2)}".
+    Counted(fn(u32) -> Phrase),
+}
+
+/// Returns the registered keyword with the given directive name.
+pub fn keyword(name: &str) -> Option<&'static KeywordDefinition> {
+    KEYWORDS.iter().find(|definition| definition.name == name)
+}
+
+/// Returns the directive name and [TriggerKeyword] of every registered
+/// trigger keyword.
+pub fn trigger_keywords() -> impl Iterator<Item = (&'static str, TriggerKeyword)> {
+    KEYWORDS.iter().filter_map(|definition| match &definition.kind {
+        KeywordKind::Trigger { keyword, .. } => Some((definition.name, keyword.clone())),
+        _ => None,
+    })
+}
+
+/// Renders `keyword` as the prefix of a triggered ability.
+pub fn trigger_prefix(keyword: &TriggerKeyword) -> Phrase {
+    trigger_phrases(keyword).0()
+}
+
+/// Renders the name of `keyword` for joining with other trigger keywords.
+pub fn trigger_display_name(keyword: &TriggerKeyword) -> Phrase {
+    trigger_phrases(keyword).1()
+}
+
+/// Renders the reminder text for the keyword with the given directive name,
+/// using `count` as the keyword's numeric argument if it takes one.
+pub fn reminder_text(name: &str, count: u32) -> Option<Phrase> {
+    match keyword(name)?.reminder_text? {
+        KeywordReminderText::Fixed(phrase) => Some(phrase()),
+        KeywordReminderText::Counted(phrase) => Some(phrase(count)),
+    }
+}
+
+fn trigger_phrases(keyword: &TriggerKeyword) -> (fn() -> Phrase, fn() -> Phrase) {
+    KEYWORDS
+        .iter()
+        .find_map(|definition| match &definition.kind {
+            KeywordKind::Trigger { keyword: registered, prefix, display_name }
+                if registered == keyword =>
+            {
+                Some((*prefix, *display_name))
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Trigger keyword {keyword:?} is not registered"))
+}
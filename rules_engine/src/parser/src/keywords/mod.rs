@@ -0,0 +1 @@
+pub mod keyword_registry;
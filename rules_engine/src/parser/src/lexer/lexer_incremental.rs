@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+use chumsky::span::SimpleSpan;
+
+use crate::error::parser_errors::LexError;
+use crate::lexer::lexer_token::{Spanned, Token};
+use crate::lexer::lexer_tokenize::{self, LexResult};
+
+/// A change to lexed text: the bytes in `range` of the previous text are
+/// replaced with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+/// Lexes the result of applying `edit` to the text of `previous`.
+///
+/// Tokens which end before the edit are reused as-is, and tokens after it
+/// are reused with their spans shifted once lexing reaches a token boundary
+/// the edit cannot affect. Only the text in between is re-lexed. The result
+/// is identical to calling [lexer_tokenize::lex] on the edited text.
+pub fn relex(previous: &LexResult, edit: &TextEdit) -> Result<LexResult, LexError> {
+    let old = &previous.original;
+    let text = format!("{}{}{}", &old[..edit.range.start], edit.text, &old[edit.range.end..]);
+    let edit_end = edit.range.start + edit.text.len();
+    let shift = |offset: usize| offset - edit.range.end + edit_end;
+
+    let prefix_len =
+        previous.tokens.iter().take_while(|(_, span)| span.end < edit.range.start).count();
+    let restart = prefix_len.checked_sub(1).map_or(0, |last| previous.tokens[last].1.end);
+    let mut tokens = previous.tokens[..prefix_len].to_vec();
+
+    let resync = previous.tokens.iter().enumerate().skip(prefix_len).find(|(_, (token, span))| {
+        span.start >= edit.range.end && starts_new_token(&text, token, shift(span.start))
+    });
+    let window = resync.and_then(|(index, (_, span))| {
+        lex_range(&text, restart..shift(span.start)).ok().map(|window| (index, window))
+    });
+    match window {
+        Some((index, window)) => {
+            tokens.extend(window);
+            tokens.extend(previous.tokens[index..].iter().map(|(token, span)| {
+                (token.clone(), SimpleSpan::new((), shift(span.start)..shift(span.end)))
+            }));
+        }
+        None => tokens.extend(lex_range(&text, restart..text.len())?),
+    }
+
+    Ok(LexResult { tokens, original: text })
+}
+
+/// Returns true if lexing `text` up to `start` cannot extend into `token`,
+/// which begins at `start`.
+fn starts_new_token(text: &str, token: &Token, start: usize) -> bool {
+    !matches!(token, Token::Word(_))
+        || text[..start].chars().next_back().is_none_or(|c| !lexer_tokenize::is_word_char(c))
+}
+
+/// Lexes `range` of `text`, returning tokens and errors with spans relative
+/// to the start of `text`.
+fn lex_range(text: &str, range: Range<usize>) -> Result<Vec<Spanned<Token>>, LexError> {
+    let offset = range.start;
+    let shift = |span: SimpleSpan| SimpleSpan::new((), span.start + offset..span.end + offset);
+    match lexer_tokenize::lex(&text[range]) {
+        Ok(result) => {
+            Ok(result.tokens.into_iter().map(|(token, span)| (token, shift(span))).collect())
+        }
+        Err(LexError::UnclosedBrace { span }) => Err(LexError::UnclosedBrace { span: shift(span) }),
+        Err(LexError::EmptyDirective { span }) => {
+            Err(LexError::EmptyDirective { span: shift(span) })
+        }
+        Err(LexError::UnclosedParenthesis { span }) => {
+            Err(LexError::UnclosedParenthesis { span: shift(span) })
+        }
+    }
+}
@@ -7,6 +7,9 @@ pub type Spanned<T> = (T, SimpleSpan);
 pub enum Token {
     Word(String),
     Directive(String),
+    /// Parenthetical reminder text, without the parentheses and in its
+    /// original case. Reminder text has no rules meaning and is not parsed.
+    ReminderText(String),
     Period,
     Comma,
     Colon,
@@ -21,6 +21,7 @@ pub fn lex(input: &str) -> Result<LexResult, LexError> {
     while let Some((start, ch)) = chars.next() {
         match ch {
             '{' => tokens.push(lex_directive(&mut chars, start)?),
+            '(' => tokens.push(lex_reminder_text(&mut chars, start, &original)?),
             '.' => tokens.push((Token::Period, SimpleSpan::new((), start..start + 1))),
             ',' => tokens.push((Token::Comma, SimpleSpan::new((), start..start + 1))),
             ':' => tokens.push((Token::Colon, SimpleSpan::new((), start..start + 1))),
@@ -33,6 +34,23 @@ pub fn lex(input: &str) -> Result<LexResult, LexError> {
     Ok(LexResult { tokens, original })
 }
 
+/// Returns the reminder text in `lex_result` along with its spans.
+pub fn reminder_text(lex_result: &LexResult) -> Vec<Spanned<String>> {
+    lex_result
+        .tokens
+        .iter()
+        .filter_map(|(token, span)| match token {
+            Token::ReminderText(text) => Some((text.clone(), *span)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns true if `ch` continues a word token.
+pub fn is_word_char(ch: char) -> bool {
+    !ch.is_whitespace() && !matches!(ch, '{' | '}' | '(' | '.' | ',' | ':' | '\n')
+}
+
 fn lex_directive(
     chars: &mut Peekable<CharIndices<'_>>,
     start: usize,
@@ -62,6 +80,28 @@ fn lex_directive(
     }
 }
 
+fn lex_reminder_text(
+    chars: &mut Peekable<CharIndices<'_>>,
+    start: usize,
+    original: &str,
+) -> Result<Spanned<Token>, LexError> {
+    let mut depth = 1;
+    for (end, ch) in chars.by_ref() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    let text = original[start + 1..end].trim().to_string();
+                    return Ok((Token::ReminderText(text), SimpleSpan::new((), start..end + 1)));
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(LexError::UnclosedParenthesis { span: SimpleSpan::new((), start..original.len()) })
+}
+
 fn lex_word(
     chars: &mut Peekable<CharIndices<'_>>,
     start: usize,
@@ -83,7 +123,3 @@ fn lex_word(
 
     (Token::Word(word), SimpleSpan::new((), start..end))
 }
-
-fn is_word_char(ch: char) -> bool {
-    !ch.is_whitespace() && !matches!(ch, '{' | '}' | '.' | ',' | ':' | '\n')
-}
@@ -1,2 +1,3 @@
+pub mod lexer_incremental;
 pub mod lexer_token;
 pub mod lexer_tokenize;
@@ -1,7 +1,10 @@
+#[cfg(feature = "native")]
 pub mod ability_directory_parser;
 pub mod builder;
 pub mod error;
+pub mod keywords;
 pub mod lexer;
 pub mod parser;
+pub mod rules_text_parser;
 pub mod serializer;
 pub mod variables;
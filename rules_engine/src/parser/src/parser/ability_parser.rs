@@ -1,12 +1,29 @@
-use ability_data::ability::{Ability, EventAbility};
+use ability_data::ability::{Ability, AbilityWithWord, EventAbility};
 use chumsky::prelude::*;
 
-use crate::parser::parser_helpers::{ParserExtra, ParserInput};
+use crate::parser::parser_helpers::{ability_word, word, ParserExtra, ParserInput};
 use crate::parser::{
     activated_ability_parser, effect_parser, named_parser, static_ability_parser, triggered_parser,
 };
 
 pub fn ability_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone {
+    choice((ability_word_ability_parser(), unnamed_ability_parser())).boxed()
+}
+
+fn ability_word_ability_parser<'a>(
+) -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone {
+    ability_word()
+        .then_ignore(word("--"))
+        .then(unnamed_ability_parser())
+        .map(|(name, ability)| {
+            Ability::WithAbilityWord(AbilityWithWord { word: name, ability: Box::new(ability) })
+        })
+        .labelled("ability word")
+        .as_context()
+}
+
+fn unnamed_ability_parser<'a>(
+) -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone {
     choice((
         triggered_ability_parser(),
         activated_ability_parser(),
@@ -19,26 +36,37 @@ pub fn ability_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Ability, ParserE
 
 fn triggered_ability_parser<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone {
-    triggered_parser::triggered_ability_parser().map(Ability::Triggered)
+    triggered_parser::triggered_ability_parser()
+        .map(Ability::Triggered)
+        .labelled("triggered ability")
+        .as_context()
 }
 
 fn activated_ability_parser<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone {
-    activated_ability_parser::activated_ability_parser().map(Ability::Activated)
+    activated_ability_parser::activated_ability_parser()
+        .map(Ability::Activated)
+        .labelled("activated ability")
+        .as_context()
 }
 
 fn named_ability_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone
 {
-    named_parser::named_ability_parser().map(Ability::Named)
+    named_parser::named_ability_parser().map(Ability::Named).labelled("named ability").as_context()
 }
 
 fn static_ability_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone
 {
-    static_ability_parser::static_ability_parser().map(Ability::Static)
+    static_ability_parser::static_ability_parser()
+        .map(Ability::Static)
+        .labelled("static ability")
+        .as_context()
 }
 
 fn event_ability_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>> + Clone
 {
     effect_parser::effect_or_compound_parser()
         .map(|effect| Ability::Event(EventAbility { additional_cost: None, effect }))
+        .labelled("event ability")
+        .as_context()
 }
@@ -1,8 +1,9 @@
 use ability_data::activated_ability::{ActivatedAbility, ActivatedAbilityOptions};
+use ability_data::effect::{Effect, EffectWithOptions};
 use chumsky::prelude::*;
 
 use crate::parser::parser_helpers::{
-    colon, comma, directive, word, words, ParserExtra, ParserInput,
+    colon, comma, directive, newline, period, word, words, ParserExtra, ParserInput,
 };
 use crate::parser::{cost_parser, effect_parser};
 
@@ -22,8 +23,11 @@ pub fn activated_ability_parser<'a>(
             .collect::<Vec<_>>(),
         )
         .then_ignore(colon())
-        .then(effect_parser::effect_or_compound_parser())
-        .map(|((is_fast, costs_and_options), effect)| {
+        .then(choice((
+            effect_paragraphs_parser().map(|(effect, paragraphs)| (effect, Some(paragraphs))),
+            effect_parser::effect_or_compound_parser().map(|effect| (effect, None)),
+        )))
+        .map(|((is_fast, costs_and_options), (effect, paragraphs))| {
             let (costs, once_per_turn) = costs_and_options.into_iter().fold(
                 (Vec::new(), false),
                 |(mut costs, mut once_per_turn), cost| {
@@ -46,6 +50,28 @@ pub fn activated_ability_parser<'a>(
                 } else {
                     None
                 },
+                paragraphs,
             }
         })
 }
+
+/// Parses the effect paragraphs of a block layout activated ability, each
+/// written on its own line as "> effect.", returning the effects of every
+/// paragraph in order along with the number of effects in each paragraph.
+fn effect_paragraphs_parser<'a>(
+) -> impl Parser<'a, ParserInput<'a>, (Effect, Vec<usize>), ParserExtra<'a>> + Clone {
+    let paragraph = word(">").ignore_then(
+        effect_parser::single_effect_parser()
+            .then_ignore(period())
+            .repeated()
+            .at_least(1)
+            .collect::<Vec<_>>(),
+    );
+    newline()
+        .ignore_then(paragraph.separated_by(newline()).at_least(2).collect::<Vec<_>>())
+        .map(|paragraphs| {
+            let lengths = paragraphs.iter().map(Vec::len).collect();
+            let effects = paragraphs.into_iter().flatten().map(EffectWithOptions::new).collect();
+            (Effect::List(effects), lengths)
+        })
+}
@@ -1,3 +1,5 @@
+use std::iter;
+
 use ability_data::predicate::CardPredicate;
 use chumsky::prelude::*;
 use core_data::numerics::{Energy, Spark};
@@ -8,6 +10,7 @@ use crate::parser::predicate_suffix_parser;
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, CardPredicate, ParserExtra<'a>> + Clone {
     recursive(|parser| {
         let base = choice((
+            or_parser(),
             fast_parser(parser.clone()),
             subtype_parser(),
             character_parser(),
@@ -98,6 +101,14 @@ fn fast_parser<'a>(
         .map(|target| CardPredicate::Fast { target: Box::new(target) })
 }
 
+fn or_parser<'a>() -> impl Parser<'a, ParserInput<'a>, CardPredicate, ParserExtra<'a>> + Clone {
+    let alternative = choice((subtype_parser(), character_parser(), event_parser(), card_parser()));
+    alternative
+        .clone()
+        .then(word("or").ignore_then(alternative).repeated().at_least(1).collect::<Vec<_>>())
+        .map(|(first, rest)| CardPredicate::Or(iter::once(first).chain(rest).collect()))
+}
+
 fn subtype_parser<'a>() -> impl Parser<'a, ParserInput<'a>, CardPredicate, ParserExtra<'a>> + Clone
 {
     subtype().map(CardPredicate::CharacterType)
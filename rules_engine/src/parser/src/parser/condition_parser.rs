@@ -20,6 +20,8 @@ pub fn condition_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Condition, Par
         with_count_allied_subtype(),
         with_an_allied_subtype(),
     ))
+    .labelled("condition")
+    .as_context()
     .boxed()
 }
 
@@ -77,7 +79,8 @@ fn this_card_is_in_your_void<'a>(
 
 fn while_you_have_count_or_more_cards_in_your_void<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, Condition, ParserExtra<'a>> + Clone {
-    words(&["while", "you", "have"])
+    choice((word("while"), words(&["as", "long", "as"])))
+        .ignore_then(words(&["you", "have"]))
         .ignore_then(count())
         .then_ignore(words(&["or", "more", "cards", "in", "your", "void"]))
         .then_ignore(comma())
@@ -5,13 +5,13 @@ use chumsky::prelude::*;
 use core_data::numerics::Energy;
 
 use crate::parser::parser_helpers::{
-    article, cards, count, count_allies, directive, discards, energy, maximum_energy, word, words,
-    ParserExtra, ParserInput,
+    article, cards, count, count_allied_subtype, count_allies, directive, discards, energy,
+    maximum_energy, word, words, ParserExtra, ParserInput,
 };
 use crate::parser::{card_predicate_parser, predicate_parser};
 
 pub fn cost_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone {
-    choice((cost_choice(), single_cost_parser())).boxed()
+    choice((cost_choice(), single_cost_parser())).labelled("cost").as_context().boxed()
 }
 
 pub fn banish_from_hand_cost<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone
@@ -103,6 +103,7 @@ fn single_cost_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtr
         choice((
             spend_one_or_more_energy_cost(),
             energy_cost(),
+            variable_energy_cost(),
             abandon_this_character_cost(),
             abandon_cost(),
         ))
@@ -140,6 +141,10 @@ fn energy_cost<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>>
     energy().map(|n| Cost::Energy(Energy(n)))
 }
 
+fn variable_energy_cost<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone {
+    directive("x").to(Cost::VariableEnergy)
+}
+
 fn abandon_this_character_cost<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone {
     words(&["abandon", "this", "character"]).map(|_| Cost::AbandonCharactersCount {
@@ -162,12 +167,23 @@ fn abandon_any_number<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtr
         })
 }
 
+/// Parses abandoning a number of allies, optionally constrained by subtype or
+/// by a predicate suffix, e.g. "abandon 2 allied warriors" or "abandon 2
+/// allies with cost 3 or more".
 fn abandon_cost_with_count<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone
 {
-    word("abandon").ignore_then(count_allies()).map(|count| Cost::AbandonCharactersCount {
-        target: Predicate::Another(CardPredicate::Character),
-        count: CollectionExpression::Exactly(count),
-    })
+    word("abandon")
+        .ignore_then(choice((
+            count_allied_subtype()
+                .map(|(count, subtype)| (count, CardPredicate::CharacterType(subtype))),
+            count_allies().then(card_predicate_parser::parser().or_not()).map(
+                |(count, predicate)| (count, predicate.unwrap_or(CardPredicate::Character)),
+            ),
+        )))
+        .map(|(count, predicate)| Cost::AbandonCharactersCount {
+            target: Predicate::Another(predicate),
+            count: CollectionExpression::Exactly(count),
+        })
 }
 
 fn discard_hand_cost<'a>() -> impl Parser<'a, ParserInput<'a>, Cost, ParserExtra<'a>> + Clone {
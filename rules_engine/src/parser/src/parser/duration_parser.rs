@@ -0,0 +1,18 @@
+use ability_data::duration::Duration;
+use chumsky::prelude::*;
+
+use crate::parser::parser_helpers::{words, ParserExtra, ParserInput};
+
+/// Parses a phrase describing how long an effect lasts, such as "this turn" or
+/// "until your next main phase".
+pub fn duration_parser<'a>(
+) -> impl Parser<'a, ParserInput<'a>, Duration, ParserExtra<'a>> + Clone {
+    choice((
+        words(&["this", "turn"]).to(Duration::ThisTurn),
+        words(&["until", "end", "of", "turn"]).to(Duration::ThisTurn),
+        words(&["until", "your", "next", "main", "phase"]).to(Duration::UntilYourNextMain),
+        words(&["until", "your", "next", "turn"]).to(Duration::UntilYourNextTurn),
+        words(&["while", "this", "character", "is", "in", "play"]).to(Duration::WhileInPlay),
+    ))
+    .boxed()
+}
@@ -8,7 +8,9 @@ use crate::parser::parser_helpers::{
     article, cards, directive, discards, energy, period, points, reclaim_cost, subtype,
     up_to_n_events, word, words, ParserExtra, ParserInput,
 };
-use crate::parser::{card_predicate_parser, predicate_parser, quantity_expression_parser};
+use crate::parser::{
+    card_predicate_parser, duration_parser, predicate_parser, quantity_expression_parser,
+};
 
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     choice((
@@ -19,8 +21,14 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserEx
         ))
         .boxed(),
         choice((
-            choice((draw_cards_for_each(), draw_matching_card(), draw_cards(), discard_cards()))
-                .boxed(),
+            choice((
+                draw_cards_for_each(),
+                draw_x_cards(),
+                draw_matching_card(),
+                draw_cards(),
+                discard_cards(),
+            ))
+            .boxed(),
             choice((
                 gain_energy_equal_to_abandoned_cost(),
                 gain_energy_for_each(),
@@ -37,7 +45,7 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserEx
             ))
             .boxed(),
             choice((
-                gains_reclaim_for_cost_this_turn(),
+                gains_reclaim_for_duration(),
                 cards_in_void_gain_reclaim(),
                 return_from_void_to_hand(),
                 return_to_hand(),
@@ -193,27 +201,28 @@ pub fn each_player_abandons_characters<'a>(
         .map(|matching| StandardEffect::EachPlayerAbandonsCharacters { matching, count: 1 })
 }
 
-pub fn gains_reclaim_for_cost_this_turn<'a>(
+pub fn gains_reclaim_for_duration<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     choice((
         word("it")
             .ignore_then(word("gains"))
             .ignore_then(directive("reclaim"))
-            .ignore_then(words(&["equal", "to", "its", "cost", "this", "turn"]))
-            .to(StandardEffect::GainsReclaim {
+            .ignore_then(words(&["equal", "to", "its", "cost"]))
+            .ignore_then(duration_parser::duration_parser())
+            .map(|duration| StandardEffect::GainsReclaim {
                 target: Predicate::It,
                 count: CollectionExpression::Exactly(1),
-                this_turn: true,
+                duration: Some(duration),
                 cost: None,
             }),
         predicate_parser::predicate_parser()
             .then_ignore(word("gains"))
             .then(reclaim_cost())
-            .then_ignore(words(&["this", "turn"]))
-            .map(|(target, cost)| StandardEffect::GainsReclaim {
+            .then(duration_parser::duration_parser())
+            .map(|((target, cost), duration)| StandardEffect::GainsReclaim {
                 target,
                 count: CollectionExpression::Exactly(1),
-                this_turn: true,
+                duration: Some(duration),
                 cost: Some(Energy(cost)),
             }),
     ))
@@ -248,11 +257,11 @@ pub fn cards_in_void_gain_reclaim<'a>(
         .then_ignore(words(&["in", "your", "void"]))
         .then_ignore(choice((word("gain"), word("gains"))))
         .then(reclaim_type)
-        .then(words(&["this", "turn"]).or_not().map(|opt| opt.is_some()))
-        .map(|(((count, predicate), cost), until_end_of_turn)| StandardEffect::GainsReclaim {
+        .then(duration_parser::duration_parser().or_not())
+        .map(|(((count, predicate), cost), duration)| StandardEffect::GainsReclaim {
             target: Predicate::YourVoid(predicate),
             count,
-            this_turn: until_end_of_turn,
+            duration,
             cost,
         })
         .boxed()
@@ -267,6 +276,14 @@ fn draw_cards_for_each<'a>(
         .map(|(count, for_each)| StandardEffect::DrawCardsForEach { count, for_each })
 }
 
+fn draw_x_cards<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone
+{
+    word("draw")
+        .ignore_then(quantity_expression_parser::parser())
+        .then_ignore(word("cards"))
+        .map(|for_each| StandardEffect::DrawCardsForEach { count: 1, for_each })
+}
+
 fn discard_from_opponent_hand<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     choice((
@@ -2,7 +2,7 @@ use ability_data::standard_effect::StandardEffect;
 use chumsky::prelude::*;
 
 use crate::parser::parser_helpers::{article, word, words, ParserExtra, ParserInput};
-use crate::parser::predicate_parser;
+use crate::parser::{duration_parser, predicate_parser};
 
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     choice((disable_activated_abilities(), gain_control(), put_on_top_of_opponent_deck())).boxed()
@@ -30,6 +30,6 @@ pub fn disable_activated_abilities<'a>(
     words(&["disable", "the", "activated", "abilities", "of"])
         .ignore_then(article().or_not())
         .ignore_then(predicate_parser::predicate_parser())
-        .then_ignore(words(&["while", "this", "character", "is", "in", "play"]))
-        .map(|target| StandardEffect::DisableActivatedAbilitiesWhileInPlay { target })
+        .then(duration_parser::duration_parser())
+        .map(|(target, duration)| StandardEffect::DisableActivatedAbilities { target, duration })
 }
@@ -5,11 +5,12 @@ use ability_data::standard_effect::StandardEffect;
 use chumsky::prelude::*;
 
 use crate::parser::parser_helpers::{
-    article, cards, comma, directive, figment, figment_count, foresee_count, number, pronoun,
+    ability_word, article, cards, comma, directive, figment, figment_count, foresee_count, number, pronoun,
     this_turn_times, up_to_n_allies, word, words, ParserExtra, ParserInput,
 };
 use crate::parser::{
-    card_predicate_parser, cost_parser, predicate_parser, quantity_expression_parser,
+    card_predicate_parser, cost_parser, duration_parser, predicate_parser,
+    quantity_expression_parser,
 };
 
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
@@ -24,6 +25,7 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserEx
             discover(),
             counterspell_effects(),
             trigger_judgment_ability(),
+            trigger_this_cards_ability(),
         ))
         .boxed(),
         choice((
@@ -37,7 +39,7 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserEx
                 banish_up_to_n(),
                 banish_collection(),
                 banish_character_until_leaves_play(),
-                banish_until_next_main(),
+                banish_for_duration(),
                 banish_character(),
                 banish_enemy_void(),
             ))
@@ -161,13 +163,13 @@ pub fn banish_character_until_leaves_play<'a>(
         })
 }
 
-pub fn banish_until_next_main<'a>(
+pub fn banish_for_duration<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     directive("banish")
         .ignore_then(article())
         .ignore_then(predicate_parser::predicate_parser())
-        .then_ignore(words(&["until", "your", "next", "main", "phase"]))
-        .map(|target| StandardEffect::BanishUntilNextMain { target })
+        .then(duration_parser::duration_parser())
+        .map(|(target, duration)| StandardEffect::BanishForDuration { target, duration })
 }
 
 pub fn banish_collection<'a>(
@@ -322,6 +324,14 @@ pub fn trigger_judgment_ability<'a>(
         })
 }
 
+pub fn trigger_this_cards_ability<'a>(
+) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
+    words(&["trigger", "this", "card's"])
+        .ignore_then(ability_word())
+        .then_ignore(word("ability"))
+        .map(|word| StandardEffect::TriggerThisCardsAbility { word })
+}
+
 pub fn you_win_the_game<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     words(&["you", "win", "the", "game"]).to(StandardEffect::YouWinTheGame)
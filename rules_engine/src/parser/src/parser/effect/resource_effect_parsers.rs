@@ -3,6 +3,7 @@ use ability_data::static_ability::StandardStaticAbility;
 use chumsky::prelude::*;
 use core_data::numerics::Points;
 
+use crate::parser::duration_parser;
 use crate::parser::parser_helpers::{directive, number, points, words, ParserExtra, ParserInput};
 
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
@@ -35,11 +36,13 @@ pub fn multiply_energy_gain_from_card_effects<'a>(
     number()
         .then_ignore(words(&["the", "amount", "of"]))
         .then_ignore(directive("energy_symbol"))
-        .then_ignore(words(&["you", "gain", "from", "card", "effects", "this", "turn"]))
-        .map(|multiplier| StandardEffect::CreateStaticAbilityUntilEndOfTurn {
+        .then_ignore(words(&["you", "gain", "from", "card", "effects"]))
+        .then(duration_parser::duration_parser())
+        .map(|(multiplier, duration)| StandardEffect::CreateStaticAbility {
             ability: Box::new(StandardStaticAbility::MultiplyEnergyGainFromCardEffects {
                 multiplier,
             }),
+            duration,
         })
 }
 
@@ -47,13 +50,14 @@ pub fn multiply_card_draw_from_card_effects<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
     number()
         .then_ignore(words(&[
-            "the", "number", "of", "cards", "you", "draw", "from", "card", "effects", "this",
-            "turn",
+            "the", "number", "of", "cards", "you", "draw", "from", "card", "effects",
         ]))
-        .map(|multiplier| StandardEffect::CreateStaticAbilityUntilEndOfTurn {
+        .then(duration_parser::duration_parser())
+        .map(|(multiplier, duration)| StandardEffect::CreateStaticAbility {
             ability: Box::new(StandardStaticAbility::MultiplyCardDrawFromCardEffects {
                 multiplier,
             }),
+            duration,
         })
 }
 
@@ -5,7 +5,7 @@ use chumsky::prelude::*;
 use core_data::numerics::Spark;
 
 use crate::parser::parser_helpers::{
-    article, kindle_amount, spark, word, words, ParserExtra, ParserInput,
+    article, kindle_amount, spark, spark_counters, word, words, ParserExtra, ParserInput,
 };
 use crate::parser::{card_predicate_parser, predicate_parser, quantity_expression_parser};
 
@@ -17,6 +17,8 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserEx
         each_allied_gains_spark(),
         gains_spark_for_each(),
         gains_spark(),
+        put_spark_counters(),
+        remove_spark_counters(),
     ))
     .boxed()
 }
@@ -95,3 +97,23 @@ fn spark_becomes<'a>() -> impl Parser<'a, ParserInput<'a>, StandardEffect, Parse
             spark: Spark(spark_value),
         })
 }
+
+fn put_spark_counters<'a>(
+) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
+    word("put")
+        .ignore_then(spark_counters())
+        .then_ignore(word("on"))
+        .then_ignore(article().or_not())
+        .then(predicate_parser::predicate_parser())
+        .map(|(count, target)| StandardEffect::PutSparkCounters { target, count })
+}
+
+fn remove_spark_counters<'a>(
+) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
+    word("remove")
+        .ignore_then(spark_counters())
+        .then_ignore(word("from"))
+        .then_ignore(article().or_not())
+        .then(predicate_parser::predicate_parser())
+        .map(|(count, target)| StandardEffect::RemoveSparkCounters { target, count })
+}
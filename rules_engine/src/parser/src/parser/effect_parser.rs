@@ -1,7 +1,9 @@
 use ability_data::cost::Cost;
-use ability_data::effect::{Effect, EffectWithOptions, ListWithOptions, ModalEffectChoice};
+use ability_data::effect::{
+    Effect, EffectWithOptions, IfYouDoEffect, ListWithOptions, ModalEffectChoice,
+};
 use ability_data::standard_effect::StandardEffect;
-use ability_data::triggered_ability::{TriggeredAbility, TriggeredAbilityOptions};
+use ability_data::triggered_ability::TriggeredAbility;
 use chumsky::prelude::*;
 use core_data::numerics::Energy;
 
@@ -13,23 +15,26 @@ use crate::parser::parser_helpers::{
     colon, comma, directive, effect_separator, mode1_cost, mode2_cost, newline, period, word,
     words, ParserExtra, ParserInput,
 };
-use crate::parser::{condition_parser, cost_parser, trigger_parser};
+use crate::parser::{condition_parser, cost_parser, duration_parser, trigger_parser};
 
 pub fn single_effect_parser<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
-    choice((create_trigger_until_end_of_turn(), base_single_effect_parser())).boxed()
+    choice((create_trigger_for_duration(), base_single_effect_parser())).boxed()
 }
 
 pub fn effect_or_compound_parser<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, Effect, ParserExtra<'a>> + Clone {
     choice((
         modal_effect_parser(),
+        if_you_do_effect_parser(),
         optional_effect_with_trigger_cost_parser(),
         effect_with_trigger_cost_parser(),
         optional_effect_parser(),
         conditional_effect_parser(),
         standard_effect_parser(),
     ))
+    .labelled("effect")
+    .as_context()
     .boxed()
 }
 
@@ -92,21 +97,19 @@ fn base_single_effect_parser<'a>(
     .boxed()
 }
 
-fn create_trigger_until_end_of_turn<'a>(
+fn create_trigger_for_duration<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, StandardEffect, ParserExtra<'a>> + Clone {
-    words(&["until", "end", "of", "turn"])
-        .ignore_then(comma())
-        .ignore_then(trigger_parser::trigger_event_parser())
+    duration_parser::duration_parser()
+        .then_ignore(comma())
+        .then(trigger_parser::trigger_event_parser())
         .then(base_single_effect_parser())
-        .map(|(trigger, effect)| StandardEffect::CreateTriggerUntilEndOfTurn {
+        .map(|((duration, trigger), effect)| StandardEffect::CreateTrigger {
             trigger: Box::new(TriggeredAbility {
                 trigger,
                 effect: Effect::Effect(effect),
-                options: Some(TriggeredAbilityOptions {
-                    once_per_turn: false,
-                    until_end_of_turn: true,
-                }),
+                options: None,
             }),
+            duration,
         })
 }
 
@@ -213,6 +216,27 @@ fn conditional_effect_parser<'a>(
         })
 }
 
+fn if_you_do_effect_parser<'a>(
+) -> impl Parser<'a, ParserInput<'a>, Effect, ParserExtra<'a>> + Clone {
+    words(&["you", "may"])
+        .or_not()
+        .then(single_effect_parser())
+        .then_ignore(period())
+        .then_ignore(words(&["if", "you", "do"]))
+        .then_ignore(comma())
+        .then(
+            single_effect_parser().separated_by(effect_separator()).at_least(1).collect::<Vec<_>>(),
+        )
+        .then_ignore(period())
+        .map(|((optional, effect), follow_up)| {
+            Effect::IfYouDo(IfYouDoEffect {
+                effect,
+                optional: optional.is_some(),
+                follow_up: follow_up.into_iter().map(EffectWithOptions::new).collect(),
+            })
+        })
+}
+
 fn optional_effect_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Effect, ParserExtra<'a>> + Clone
 {
     words(&["you", "may"])
@@ -3,6 +3,7 @@ pub mod activated_ability_parser;
 pub mod card_predicate_parser;
 pub mod condition_parser;
 pub mod cost_parser;
+pub mod duration_parser;
 pub mod effect;
 pub mod effect_parser;
 pub mod named_parser;
@@ -1,3 +1,4 @@
+use ability_data::ability::AbilityWord;
 use chumsky::extra::Err;
 use chumsky::prelude::*;
 use core_data::card_types::CardSubtype;
@@ -16,6 +17,7 @@ pub fn word<'a>(
     select! {
         (ResolvedToken::Token(Token::Word(w)), _) if w == text => ()
     }
+    .labelled(text)
 }
 
 pub fn directive<'a>(
@@ -24,24 +26,36 @@ pub fn directive<'a>(
     select! {
         (ResolvedToken::Token(Token::Directive(d)), _) if d == name => ()
     }
+    .labelled(name)
+}
+
+pub fn ability_word<'a>() -> impl Parser<'a, ParserInput<'a>, AbilityWord, ParserExtra<'a>> + Clone
+{
+    select! {
+        (ResolvedToken::Token(Token::Directive(d)), _) if d == "resolve" => AbilityWord::Resolve,
+    }
+    .labelled("ability word")
 }
 
 pub fn period<'a>() -> impl Parser<'a, ParserInput<'a>, (), ParserExtra<'a>> + Clone {
     select! {
         (ResolvedToken::Token(Token::Period), _) => ()
     }
+    .labelled("period")
 }
 
 pub fn comma<'a>() -> impl Parser<'a, ParserInput<'a>, (), ParserExtra<'a>> + Clone {
     select! {
         (ResolvedToken::Token(Token::Comma), _) => ()
     }
+    .labelled("comma")
 }
 
 pub fn colon<'a>() -> impl Parser<'a, ParserInput<'a>, (), ParserExtra<'a>> + Clone {
     select! {
         (ResolvedToken::Token(Token::Colon), _) => ()
     }
+    .labelled("colon")
 }
 
 pub fn effect_separator<'a>() -> impl Parser<'a, ParserInput<'a>, (), ParserExtra<'a>> + Clone {
@@ -82,6 +96,12 @@ pub fn spark<'a>() -> impl Parser<'a, ParserInput<'a>, u32, ParserExtra<'a>> + C
     }
 }
 
+pub fn spark_counters<'a>() -> impl Parser<'a, ParserInput<'a>, u32, ParserExtra<'a>> + Clone {
+    select! {
+        (ResolvedToken::SparkCounters(v), _) => v,
+    }
+}
+
 pub fn subtype<'a>() -> impl Parser<'a, ParserInput<'a>, CardSubtype, ParserExtra<'a>> + Clone {
     select! {
         (ResolvedToken::Subtype(subtype), _) => subtype
@@ -199,4 +219,5 @@ pub fn newline<'a>() -> impl Parser<'a, ParserInput<'a>, (), ParserExtra<'a>> +
     select! {
         (ResolvedToken::Token(Token::Newline), _) => ()
     }
+    .labelled("line break")
 }
@@ -6,7 +6,7 @@ use crate::parser::parser_helpers::{directive, subtype, word, words, ParserExtra
 
 pub fn predicate_parser<'a>() -> impl Parser<'a, ParserInput<'a>, Predicate, ParserExtra<'a>> + Clone
 {
-    choice((specific_predicates(), general_predicates())).boxed()
+    choice((specific_predicates(), general_predicates())).labelled("target").as_context().boxed()
 }
 
 fn specific_predicates<'a>() -> impl Parser<'a, ParserInput<'a>, Predicate, ParserExtra<'a>> + Clone
@@ -2,7 +2,7 @@ use ability_data::predicate::CardPredicate;
 use ability_data::quantity_expression_data::QuantityExpression;
 use chumsky::prelude::*;
 
-use crate::parser::parser_helpers::{directive, word, words, ParserExtra, ParserInput};
+use crate::parser::parser_helpers::{article, directive, word, words, ParserExtra, ParserInput};
 use crate::parser::{card_predicate_parser, predicate_parser};
 
 pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, QuantityExpression, ParserExtra<'a>> + Clone
@@ -22,6 +22,7 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, QuantityExpression, Pars
         .or(directive("energy_symbol")
             .then_ignore(word("spent"))
             .to(QuantityExpression::ForEachEnergySpentOnThisCard))
+        .or(word("x").to(QuantityExpression::VariableEnergyPaid))
         .or(words(&["ally", "abandoned", "this", "turn"])
             .to(QuantityExpression::AbandonedThisTurn(CardPredicate::Character)))
         .or(words(&["ally", "abandoned"])
@@ -34,6 +35,10 @@ pub fn parser<'a>() -> impl Parser<'a, ParserInput<'a>, QuantityExpression, Pars
         .or(card_predicate_parser::parser()
             .then_ignore(word("returned"))
             .map(QuantityExpression::ReturnedToHandThisWay))
+        .or(words(&["+1", "spark", "counter", "on"])
+            .ignore_then(article().or_not())
+            .ignore_then(predicate_parser::predicate_parser())
+            .map(QuantityExpression::SparkCountersOn))
         .or(predicate_parser::predicate_parser().map(QuantityExpression::Matching))
         .boxed()
 }
@@ -5,11 +5,15 @@ use chumsky::prelude::*;
 use crate::parser::parser_helpers::{
     article, cards, comma, count_allies, directive, word, words, ParserExtra, ParserInput,
 };
+use crate::keywords::keyword_registry;
 use crate::parser::{card_predicate_parser, predicate_parser};
 
 pub fn trigger_event_parser<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, TriggerEvent, ParserExtra<'a>> + Clone {
-    choice((keyword_triggers(), standard_trigger().then_ignore(comma()))).boxed()
+    choice((keyword_triggers(), standard_trigger().then_ignore(comma())))
+        .labelled("trigger")
+        .as_context()
+        .boxed()
 }
 
 fn keyword_triggers<'a>() -> impl Parser<'a, ParserInput<'a>, TriggerEvent, ParserExtra<'a>> + Clone
@@ -19,11 +23,11 @@ fn keyword_triggers<'a>() -> impl Parser<'a, ParserInput<'a>, TriggerEvent, Pars
 
 fn keyword_trigger<'a>() -> impl Parser<'a, ParserInput<'a>, TriggerEvent, ParserExtra<'a>> + Clone
 {
-    choice((
-        directive("judgment").to(TriggerKeyword::Judgment),
-        directive("materialized").to(TriggerKeyword::Materialized),
-        directive("dissolved").to(TriggerKeyword::Dissolved),
-    ))
+    choice(
+        keyword_registry::trigger_keywords()
+            .map(|(name, keyword)| directive(name).to(keyword))
+            .collect::<Vec<_>>(),
+    )
     .map(|keyword| TriggerEvent::Keywords(vec![keyword]))
 }
 
@@ -83,6 +87,7 @@ fn timing_triggers<'a>() -> impl Parser<'a, ParserInput<'a>, TriggerEvent, Parse
         draw_cards_in_turn_trigger(),
         draw_all_cards_trigger(),
         end_of_turn_trigger(),
+        start_of_turn_trigger(),
         gain_energy_trigger(),
     ))
     .boxed()
@@ -249,6 +254,11 @@ fn end_of_turn_trigger<'a>(
     words(&["at", "the", "end", "of", "your", "turn"]).to(TriggerEvent::EndOfYourTurn)
 }
 
+fn start_of_turn_trigger<'a>(
+) -> impl Parser<'a, ParserInput<'a>, TriggerEvent, ParserExtra<'a>> + Clone {
+    words(&["at", "the", "start", "of", "your", "turn"]).to(TriggerEvent::StartOfYourTurn)
+}
+
 fn gain_energy_trigger<'a>(
 ) -> impl Parser<'a, ParserInput<'a>, TriggerEvent, ParserExtra<'a>> + Clone {
     words(&["when", "you", "gain", "energy"]).to(TriggerEvent::GainEnergy)
@@ -18,10 +18,7 @@ fn once_per_turn_triggered<'a>(
         .map(|(trigger, effect)| TriggeredAbility {
             trigger,
             effect,
-            options: Some(TriggeredAbilityOptions {
-                once_per_turn: true,
-                until_end_of_turn: false,
-            }),
+            options: Some(TriggeredAbilityOptions { once_per_turn: true }),
         })
 }
 
@@ -3,19 +3,17 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use ability_data::variable_value::VariableValue;
-use ariadne::{Color, Label, Report, ReportKind, Source};
-use chumsky::error::Rich;
-use chumsky::span::{SimpleSpan, Span};
 use chumsky::Parser as ChumskyParser;
 use clap::{Parser, Subcommand, ValueEnum};
 use core_data::card_types::CardSubtype;
 use core_data::figment_type::FigmentType;
 use parser::ability_directory_parser;
 use parser::error::parser_errors::ParserError;
-use parser::error::{parser_diagnostics, parser_error_suggestions};
+use parser::error::parser_diagnostics;
 use parser::lexer::lexer_token::Token;
 use parser::lexer::lexer_tokenize;
 use parser::parser::ability_parser;
+use parser::rules_text_parser;
 use parser::serializer::ability_serializer;
 use parser::variables::parser_bindings::VariableBindings;
 use parser::variables::parser_substitutions::{self, ResolvedToken};
@@ -24,7 +22,7 @@ use rlf::{Phrase, Value as RlfValue};
 use serde::{Deserialize, Serialize};
 use strings::strings;
 use toml::Value as TomlValue;
-use toml_edit::DocumentMut;
+use toml_edit::{DocumentMut, ImDocument};
 
 const CARDS_TOML_PATH: &str = "rules_engine/tabula/cards.toml";
 
@@ -61,6 +59,12 @@ const CARDS_TOML_PATH: &str = "rules_engine/tabula/cards.toml";
   # Verify all cards can be lexed and variables resolved
   parser verify tabula/cards.toml
 
+  # Verify every TOML file under a directory, printing a JSON summary
+  parser verify --dir tabula --format json
+
+  # Verify every ability renders identically after parsing and serializing
+  parser verify tabula/cards.toml --round-trip
+
   # Parse event with reclaim ability
   parser parse \"Draw {cards}. Discard {discards}.\\n\\n{ReclaimForCost}\" \\
     --vars \"cards: 2, discards: 2, reclaim: 2\" --stage lex --format json
@@ -91,6 +95,11 @@ enum Command {
 
         #[arg(short, long, default_value = "full")]
         stage: Stage,
+
+        /// Serialize the parsed ability and compare it with the input text
+        /// rendered directly
+        #[arg(long)]
+        round_trip: bool,
     },
 
     ParseFile {
@@ -118,7 +127,22 @@ enum Command {
     },
 
     Verify {
-        input: PathBuf,
+        /// TOML file of cards to verify
+        #[arg(required_unless_present = "dir")]
+        input: Option<PathBuf>,
+
+        /// Verify every TOML file in this directory and its subdirectories
+        #[arg(long, conflicts_with = "input")]
+        dir: Option<PathBuf>,
+
+        /// Print a machine-readable summary instead of per-card results
+        #[arg(short, long, default_value = "text")]
+        format: SummaryFormat,
+
+        /// Also parse and serialize every ability, comparing the result with
+        /// its rules text rendered directly
+        #[arg(long)]
+        round_trip: bool,
     },
 
     ExportCards {
@@ -145,6 +169,13 @@ enum OutputFormat {
     Debug,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum SummaryFormat {
+    Text,
+    Json,
+    Ron,
+}
+
 #[derive(Clone, ValueEnum)]
 enum Stage {
     Lex,
@@ -163,8 +194,8 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
     match cli.command {
-        Command::Parse { text, vars, format, stage } => {
-            parse_command(&text, vars.as_deref(), format, stage)?;
+        Command::Parse { text, vars, format, stage, round_trip } => {
+            parse_command(&text, vars.as_deref(), format, stage, round_trip)?;
         }
         Command::ParseFile { input, output } => {
             parse_file_command(&input, &output)?;
@@ -175,8 +206,13 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         Command::VerifyAbilities { directory, input } => {
             verify_abilities_command(&directory, &input)?;
         }
-        Command::Verify { input } => {
-            verify_command(&input)?;
+        Command::Verify { input, dir, format, round_trip } => {
+            let files = match (input, dir) {
+                (_, Some(dir)) => toml_files(&dir)?,
+                (Some(input), None) => vec![input],
+                (None, None) => unreachable!("clap requires input or --dir"),
+            };
+            verify_command(&files, format, round_trip)?;
         }
         Command::ExportCards { input, output } => {
             export_cards_command(&input, &output)?;
@@ -194,6 +230,7 @@ fn parse_command(
     vars: Option<&str>,
     format: OutputFormat,
     stage: Stage,
+    round_trip: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let bindings = if let Some(vars_str) = vars {
         VariableBindings::parse(vars_str).map_err(ParserError::from)?
@@ -235,12 +272,20 @@ fn parse_command(
             })?;
 
             let parser = ability_parser::ability_parser();
-            let parsed = parser
-                .parse(&resolved)
-                .into_result()
-                .map_err(|errors| format_parse_errors(errors, text))?;
+            let parsed = parser.parse(&resolved).into_result().map_err(|errors| {
+                parser_diagnostics::format_parse_errors(&errors, &resolved, text, "<input>")
+            })?;
 
             output_format(&parsed, format);
+
+            if round_trip {
+                strings::register_source_phrases();
+                let errors = round_trip_errors(text, &bindings, "<input>");
+                if !errors.is_empty() {
+                    return Err(errors.join("\n\n").into());
+                }
+                println!("✓ Round trip matches");
+            }
         }
     }
 
@@ -326,70 +371,166 @@ fn parse_file_command(input: &PathBuf, output: &PathBuf) -> Result<(), Box<dyn s
     Ok(())
 }
 
-fn verify_command(input: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(input)?;
-    let cards_file: CardsFile = toml::from_str(&content)?;
-
-    let mut success_count = 0;
-    let mut error_count = 0;
-    let mut skipped_count = 0;
-
-    for card in cards_file.cards {
-        let Some(rules_text) = &card.rules_text else {
-            skipped_count += 1;
-            continue;
-        };
-
-        let bindings = if let Some(vars) = &card.variables {
-            match VariableBindings::parse(vars) {
-                Ok(b) => b,
-                Err(e) => {
-                    let error = ParserError::from(e);
-                    let formatted =
-                        parser_diagnostics::format_error(&error, rules_text, &card.name);
-                    eprintln!("\n{formatted}");
-                    error_count += 1;
-                    continue;
-                }
-            }
-        } else {
-            VariableBindings::new()
-        };
+fn verify_command(
+    files: &[PathBuf],
+    format: SummaryFormat,
+    round_trip: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if round_trip {
+        strings::register_source_phrases();
+    }
 
-        match lexer_tokenize::lex(rules_text) {
-            Ok(lex_result) => {
-                match parser_substitutions::resolve_variables(&lex_result.tokens, &bindings) {
-                    Ok(_) => {
-                        println!("✓ {}", card.name);
-                        success_count += 1;
+    let mut summary = VerifySummary::default();
+    for file in files {
+        for entry in collect_verify_entries(file, &mut summary.skipped)? {
+            match verify_entry(&entry, round_trip) {
+                None => {
+                    if matches!(format, SummaryFormat::Text) {
+                        println!("✓ {}", entry.name);
                     }
-                    Err(e) => {
-                        let error = ParserError::from(e);
-                        let formatted =
-                            parser_diagnostics::format_error(&error, rules_text, &card.name);
-                        eprintln!("\n{formatted}");
-                        error_count += 1;
+                    summary.succeeded += 1;
+                }
+                Some(failure) => {
+                    if matches!(format, SummaryFormat::Text) {
+                        eprintln!(
+                            "\n✗ {}:{} {}\n{}",
+                            failure.file.display(),
+                            failure.line,
+                            failure.name,
+                            failure.report
+                        );
                     }
+                    summary.failed += 1;
+                    summary.failures.push(failure);
                 }
             }
-            Err(e) => {
-                let error = ParserError::from(e);
-                let formatted = parser_diagnostics::format_error(&error, rules_text, &card.name);
-                eprintln!("\n{formatted}");
-                error_count += 1;
-            }
         }
     }
 
-    println!("\nResults: {success_count} succeeded, {error_count} failed, {skipped_count} skipped");
+    match format {
+        SummaryFormat::Text => println!(
+            "\nResults: {} succeeded, {} failed, {} skipped",
+            summary.succeeded, summary.failed, summary.skipped
+        ),
+        SummaryFormat::Json => output_format(&summary, OutputFormat::Json),
+        SummaryFormat::Ron => output_format(&summary, OutputFormat::Ron),
+    }
 
-    if error_count > 0 {
+    if summary.failed > 0 {
         Err("Verification failed".into())
     } else {
         Ok(())
     }
 }
 
+/// Lexes and resolves the variables of `entry`, and also checks that each
+/// ability round trips if `round_trip` is set.
+///
+/// Returns a failure describing the first problem found, or None if the entry
+/// verified successfully.
+fn verify_entry(entry: &VerifyEntry, round_trip: bool) -> Option<VerifyFailure> {
+    let failure = |message: String, report: String| VerifyFailure {
+        file: entry.file.clone(),
+        line: entry.line,
+        name: entry.name.clone(),
+        message,
+        report,
+    };
+    let parser_failure = |error: ParserError| {
+        let report = parser_diagnostics::format_error(&error, &entry.rules_text, &entry.name);
+        failure(error.to_string(), report)
+    };
+
+    let bindings = match &entry.variables {
+        Some(vars) => match VariableBindings::parse(vars) {
+            Ok(bindings) => bindings,
+            Err(e) => return Some(parser_failure(ParserError::from(e))),
+        },
+        None => VariableBindings::new(),
+    };
+    let lex_result = match lexer_tokenize::lex(&entry.rules_text) {
+        Ok(lex_result) => lex_result,
+        Err(e) => return Some(parser_failure(ParserError::from(e))),
+    };
+    if let Err(e) = parser_substitutions::resolve_variables(&lex_result.tokens, &bindings) {
+        return Some(parser_failure(ParserError::from(e)));
+    }
+
+    if round_trip {
+        let errors = round_trip_errors(&entry.rules_text, &bindings, &entry.name);
+        if !errors.is_empty() {
+            let report = errors.join("\n\n");
+            return Some(failure(report.clone(), report));
+        }
+    }
+    None
+}
+
+/// Returns every TOML file in `directory` and its subdirectories, sorted by
+/// path.
+fn toml_files(directory: &Path) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(toml_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "toml") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Finds every entry with a `rules-text` field in the arrays of tables of the
+/// TOML file at `path`.
+///
+/// Other entries in an array which contains rules text are counted in
+/// `skipped`. Arrays without any rules text are ignored.
+fn collect_verify_entries(
+    path: &Path,
+    skipped: &mut usize,
+) -> Result<Vec<VerifyEntry>, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let document = ImDocument::parse(content.as_str())
+        .map_err(|e| format!("Failed to parse {}: {e}", path.display()))?;
+
+    let mut entries = Vec::new();
+    for (_name, item) in document.as_table().iter() {
+        let Some(array) = item.as_array_of_tables() else {
+            continue;
+        };
+        if !array.iter().any(|table| table.contains_key("rules-text")) {
+            continue;
+        }
+
+        for table in array.iter() {
+            let rules_text_item = table.get("rules-text");
+            let Some(rules_text) = rules_text_item.and_then(toml_edit::Item::as_str) else {
+                *skipped += 1;
+                continue;
+            };
+            let offset = rules_text_item.and_then(toml_edit::Item::span).map_or(0, |s| s.start);
+            entries.push(VerifyEntry {
+                file: path.to_path_buf(),
+                line: content[..offset].matches('\n').count() + 1,
+                name: table
+                    .get("name")
+                    .and_then(toml_edit::Item::as_str)
+                    .unwrap_or("<unknown>")
+                    .to_string(),
+                rules_text: rules_text.to_string(),
+                variables: table
+                    .get("variables")
+                    .and_then(toml_edit::Item::as_str)
+                    .filter(|vars| !vars.trim().is_empty())
+                    .map(str::to_string),
+            });
+        }
+    }
+    Ok(entries)
+}
+
 fn render_cards_toml_command(
     output: &PathBuf,
     strip_html: bool,
@@ -442,6 +583,41 @@ fn render_cards_toml_command(
     Ok(())
 }
 
+/// Parses and serializes each ability in `rules_text`, comparing the result
+/// with the ability's text rendered directly through RLF.
+///
+/// The serializer produces rendered text rather than parser input, so this
+/// compares rendered output instead of re-parsing it. Returns a description of
+/// each ability which fails to parse or does not match.
+fn round_trip_errors(rules_text: &str, bindings: &VariableBindings, filename: &str) -> Vec<String> {
+    let resolved = rules_text_parser::resolve_rules_text(rules_text, bindings);
+    let mut errors = Vec::new();
+    if !resolved.diagnostics.is_empty() {
+        errors.push(rules_text_parser::format_diagnostics(&resolved.diagnostics, filename));
+    }
+
+    let parser = ability_parser::ability_parser();
+    for ability in &resolved.abilities {
+        let parsed =
+            rules_text_parser::parse_resolved_abilities(&parser, std::slice::from_ref(ability));
+        if !parsed.diagnostics.is_empty() {
+            errors.push(rules_text_parser::format_diagnostics(&parsed.diagnostics, filename));
+        }
+        for parsed_ability in &parsed.abilities {
+            let serialized = ability_serializer::serialize_ability(parsed_ability).text;
+            match render_with_rlf(&ability.text, bindings) {
+                Ok(rendered) if rendered == serialized => {}
+                Ok(rendered) => errors.push(format!(
+                    "Round trip mismatch for {:?}:\n  serialized: {serialized:?}\n  rendered:   {rendered:?}",
+                    ability.text
+                )),
+                Err(e) => errors.push(format!("Failed to render {:?}: {e}", ability.text)),
+            }
+        }
+    }
+    errors
+}
+
 fn render_with_rlf(template: &str, bindings: &VariableBindings) -> Result<String, String> {
     let params = build_params(bindings);
     rlf::with_locale(|locale| {
@@ -520,79 +696,37 @@ fn output_format<T: serde::Serialize + std::fmt::Debug>(value: &T, format: Outpu
     }
 }
 
-fn format_parse_errors<'a>(
-    errors: Vec<Rich<'a, (ResolvedToken, SimpleSpan)>>,
-    text: &str,
-) -> String {
-    let error_strs: Vec<String> = errors
-        .into_iter()
-        .map(|e| {
-            let span = *e.span();
-            let mut output = Vec::new();
-
-            let found_token = e.found().map(|(token, _)| token);
-            let (message, label_message, note) = match found_token {
-                Some(ResolvedToken::Token(Token::Word(word))) => {
-                    if let Some(suggestions) = parser_error_suggestions::suggest_word(word) {
-                        (
-                            format!("Unexpected word '{word}'"),
-                            format!(
-                                "Found '{word}' here. Did you mean '{}'?",
-                                suggestions.join("', '")
-                            ),
-                            Some(format!("Similar words: {}", suggestions.join(", "))),
-                        )
-                    } else {
-                        (format!("Unexpected word '{word}'"), format!("Found '{word}' here"), None)
-                    }
-                }
-                Some(ResolvedToken::Token(Token::Directive(name))) => {
-                    if let Some(suggestions) = parser_error_suggestions::suggest_directive(name) {
-                        (
-                            format!("Unexpected directive '{{{name}}}'"),
-                            format!(
-                                "Found '{{{name}}}' here. Did you mean '{{{}}}'?",
-                                suggestions.join("', '{")
-                            ),
-                            Some(format!("Similar directives: {}", suggestions.join(", "))),
-                        )
-                    } else {
-                        (
-                            format!("Unexpected directive '{{{name}}}'"),
-                            format!("Found '{{{name}}}' here"),
-                            None,
-                        )
-                    }
-                }
-                _ => {
-                    let message = format!("Parse error: {:?}", e.reason());
-                    (message, "Failed to parse ability text here".to_string(), None)
-                }
-            };
-
-            let mut report = Report::<(&str, std::ops::Range<usize>)>::build(
-                ReportKind::Error,
-                "<input>",
-                span.start(),
-            )
-            .with_message(&message);
+/// Results of the `verify` command, printed with `--format json` or
+/// `--format ron` for use by validation scripts and editor tooling.
+#[derive(Debug, Default, Serialize)]
+struct VerifySummary {
+    succeeded: usize,
+    failed: usize,
+    skipped: usize,
+    failures: Vec<VerifyFailure>,
+}
 
-            let label = Label::new(("<input>", span.start()..span.end()))
-                .with_message(&label_message)
-                .with_color(Color::Red);
+#[derive(Debug, Serialize)]
+struct VerifyFailure {
+    file: PathBuf,
 
-            report = report.with_label(label);
+    /// Line of the entry's `rules-text` field, starting from 1.
+    line: usize,
 
-            if let Some(note_text) = note {
-                report = report.with_note(note_text);
-            }
+    name: String,
+    message: String,
 
-            report.finish().write(("<input>", Source::from(text)), &mut output).unwrap();
-            String::from_utf8(output).unwrap()
-        })
-        .collect();
+    /// Formatted diagnostic report, printed in text mode.
+    #[serde(skip)]
+    report: String,
+}
 
-    error_strs.join("\n\n")
+struct VerifyEntry {
+    file: PathBuf,
+    line: usize,
+    name: String,
+    rules_text: String,
+    variables: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -730,27 +864,19 @@ fn render_rules_text(
         VariableBindings::new()
     };
 
-    let mut rendered_parts = Vec::new();
-
-    for ability_block in rules_text.split("\n\n") {
-        let ability_block = ability_block.trim();
-        if ability_block.is_empty() {
-            continue;
-        }
-
-        let lex_result = lexer_tokenize::lex(ability_block)
-            .map_err(|e| format!("Lex error for '{}': {:?}", card.name, e))?;
-
-        let resolved = parser_substitutions::resolve_variables(&lex_result.tokens, &bindings)
-            .map_err(|e| format!("Resolve error for '{}': {}", card.name, e))?;
-
-        let parser = ability_parser::ability_parser();
-        let ability = parser
-            .parse(&resolved)
-            .into_result()
-            .map_err(|errors| format!("Parse error for '{}': {:?}", card.name, errors))?;
+    let parsed = rules_text_parser::parse_rules_text(rules_text, &bindings);
+    if !parsed.diagnostics.is_empty() {
+        return Err(format!(
+            "Error parsing '{}':\n{}",
+            card.name,
+            rules_text_parser::format_diagnostics(&parsed.diagnostics, &card.name)
+        )
+        .into());
+    }
 
-        let serialized = ability_serializer::serialize_ability(&ability);
+    let mut rendered_parts = Vec::new();
+    for ability in &parsed.abilities {
+        let serialized = ability_serializer::serialize_ability(ability);
         let stripped = tag_regex.replace_all(&serialized.text, "");
         rendered_parts.push(stripped.into_owned());
     }
@@ -0,0 +1,156 @@
+use ability_data::ability::Ability;
+use chumsky::span::SimpleSpan;
+use chumsky::Parser;
+
+use crate::error::parser_diagnostics;
+use crate::error::parser_errors::ParserError;
+use crate::lexer::lexer_tokenize;
+use crate::parser::ability_parser;
+use crate::parser::parser_helpers::{ParserExtra, ParserInput};
+use crate::variables::parser_bindings::VariableBindings;
+use crate::variables::parser_substitutions::{self, ResolvedToken};
+
+/// A single ability from a card's rules text, lexed and with its variables
+/// resolved.
+#[derive(Debug, Clone)]
+pub struct ResolvedAbility {
+    /// Position of this ability within the rules text, starting from 0.
+    pub index: usize,
+
+    /// Byte offset of `text` within the rules text.
+    pub offset: usize,
+
+    /// Text of this ability.
+    pub text: String,
+
+    /// Resolved tokens of this ability, with spans into `text`.
+    pub tokens: Vec<(ResolvedToken, SimpleSpan)>,
+
+    /// Parenthetical reminder text in this ability, with spans into `text`.
+    ///
+    /// Reminder text is preserved here but omitted from `tokens`, so it has no
+    /// effect on parsing.
+    pub reminder_text: Vec<(String, SimpleSpan)>,
+}
+
+/// An error encountered while lexing, resolving, or parsing one ability of a
+/// card's rules text.
+#[derive(Debug)]
+pub struct AbilityDiagnostic {
+    /// Position of the failing ability within the rules text, starting from
+    /// 0.
+    pub index: usize,
+
+    /// Byte offset of `text` within the rules text.
+    pub offset: usize,
+
+    /// Text of the failing ability, which spans in `error` refer to.
+    pub text: String,
+
+    pub error: ParserError,
+}
+
+/// Rules text split into abilities which are ready to parse, along with
+/// diagnostics for abilities which could not be lexed or resolved.
+#[derive(Debug, Default)]
+pub struct ResolvedRulesText {
+    pub abilities: Vec<ResolvedAbility>,
+    pub diagnostics: Vec<AbilityDiagnostic>,
+}
+
+/// Result of parsing a card's rules text.
+///
+/// Abilities are separated by blank lines, and each ability is parsed
+/// independently, so a failure in one ability does not prevent the others
+/// from being returned.
+#[derive(Debug, Default)]
+pub struct ParsedRulesText {
+    /// Abilities which parsed successfully, in the order they appear.
+    pub abilities: Vec<Ability>,
+
+    /// Errors for abilities which failed, ordered by ability index.
+    pub diagnostics: Vec<AbilityDiagnostic>,
+}
+
+/// Parses every ability in `rules_text`, recovering at each ability boundary
+/// so that all successfully parsed abilities are returned.
+pub fn parse_rules_text(rules_text: &str, bindings: &VariableBindings) -> ParsedRulesText {
+    let resolved = resolve_rules_text(rules_text, bindings);
+    let parser = ability_parser::ability_parser();
+    let mut parsed = parse_resolved_abilities(&parser, &resolved.abilities);
+    parsed.diagnostics.extend(resolved.diagnostics);
+    parsed.diagnostics.sort_by_key(|diagnostic| diagnostic.index);
+    parsed
+}
+
+/// Splits `rules_text` into abilities and resolves the variables of each one
+/// using `bindings`.
+pub fn resolve_rules_text(rules_text: &str, bindings: &VariableBindings) -> ResolvedRulesText {
+    let mut result = ResolvedRulesText::default();
+    let blocks = rules_text.split("\n\n").map(str::trim).filter(|block| !block.is_empty());
+    for (index, text) in blocks.enumerate() {
+        let offset = text.as_ptr() as usize - rules_text.as_ptr() as usize;
+        let resolved =
+            lexer_tokenize::lex(text).map_err(ParserError::from).and_then(|lex_result| {
+                let tokens = parser_substitutions::resolve_variables(&lex_result.tokens, bindings)
+                    .map_err(ParserError::from)?;
+                Ok((tokens, lexer_tokenize::reminder_text(&lex_result)))
+            });
+        match resolved {
+            Ok((tokens, reminder_text)) => result.abilities.push(ResolvedAbility {
+                index,
+                offset,
+                text: text.to_string(),
+                tokens,
+                reminder_text,
+            }),
+            Err(error) => result.diagnostics.push(AbilityDiagnostic {
+                index,
+                offset,
+                text: text.to_string(),
+                error,
+            }),
+        }
+    }
+    result
+}
+
+/// Parses each of `abilities` with `parser`, returning the abilities which
+/// parsed successfully and a diagnostic for each parse error.
+///
+/// Accepts a parser so that callers parsing many cards can construct it once.
+pub fn parse_resolved_abilities<'a>(
+    parser: &impl Parser<'a, ParserInput<'a>, Ability, ParserExtra<'a>>,
+    abilities: &'a [ResolvedAbility],
+) -> ParsedRulesText {
+    let mut result = ParsedRulesText::default();
+    for ability in abilities {
+        match parser.parse(&ability.tokens).into_result() {
+            Ok(parsed) => result.abilities.push(parsed),
+            Err(errors) => {
+                result.diagnostics.extend(errors.iter().map(|error| AbilityDiagnostic {
+                    index: ability.index,
+                    offset: ability.offset,
+                    text: ability.text.clone(),
+                    error: ParserError::Parse(parser_diagnostics::parse_diagnostic(
+                        error,
+                        &ability.tokens,
+                    )),
+                }));
+            }
+        }
+    }
+    result
+}
+
+/// Formats every diagnostic in `diagnostics` using the source text of its
+/// ability, separated by blank lines.
+pub fn format_diagnostics(diagnostics: &[AbilityDiagnostic], filename: &str) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            parser_diagnostics::format_error(&diagnostic.error, &diagnostic.text, filename)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
@@ -7,12 +7,12 @@ use ability_data::effect::{Effect, ModelEffectChoiceIndex};
 use ability_data::named_ability::NamedAbility;
 use ability_data::trigger_event::TriggerEvent;
 use ability_data::triggered_ability::TriggeredAbility;
-use rlf::Phrase;
-use strings::strings;
+use strings::{language, number_format, strings};
 
 use crate::serializer::effect_serializer::AbilityContext;
 use crate::serializer::{
-    cost_serializer, effect_serializer, static_ability_serializer, trigger_serializer,
+    cost_serializer, effect_serializer, reminder_text_serializer, serializer_utils,
+    static_ability_serializer, trigger_serializer,
 };
 
 /// Result of serializing an ability into displayable text.
@@ -21,21 +21,57 @@ pub struct SerializedAbility {
     pub text: String,
 }
 
+/// How much detail to include when serializing an ability, selected by the
+/// context the text is rendered in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SerializationStyle {
+    /// Compact oracle text, as printed in the card frame. Numbers are written
+    /// as digits and no reminder text is included.
+    #[default]
+    Oracle,
+
+    /// Verbose text for supplemental info panels. Standard reminder text is
+    /// appended for each keyword the ability uses, e.g. "(Look at the top two
+    /// cards of your deck...)", and small numbers are spelled out.
+    Verbose,
+}
+
+/// Options controlling how an ability is serialized.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerializerOptions {
+    /// Level of detail to serialize the ability with.
+    pub style: SerializationStyle,
+
+    /// Language to serialize the ability in, e.g. "ru". Phrases are selected
+    /// from that language's translations, including their gender and case
+    /// variants, and the previously active language is restored afterwards.
+    /// If None, the active language is used.
+    pub language: Option<&'static str>,
+}
+
 /// Serializes an ability into its rules text and variable bindings.
 pub fn serialize_ability(ability: &Ability) -> SerializedAbility {
-    let text = match ability {
-        Ability::Triggered(triggered) => serialize_triggered(triggered),
-        Ability::Event(event) => {
-            strings::capitalized_sentence(effect_serializer::serialize_effect(&event.effect))
-                .to_string()
+    serialize_ability_with_options(ability, SerializerOptions::default())
+}
+
+/// Serializes an ability into its rules text using the given `options`.
+pub fn serialize_ability_with_options(
+    ability: &Ability,
+    options: SerializerOptions,
+) -> SerializedAbility {
+    if let Some(language) = options.language {
+        return language::with_language(Some(language), || {
+            serialize_ability_with_options(ability, SerializerOptions { language: None, ..options })
+        });
+    }
+
+    let mut text = serialize_ability_text(ability);
+    if options.style == SerializationStyle::Verbose {
+        for reminder in reminder_text_serializer::serialize_reminder_text(ability) {
+            text = strings::ability_with_reminder_text(text, reminder).to_string();
         }
-        Ability::Activated(activated) => serialize_activated(activated),
-        Ability::Named(named) => serialize_named_ability(named),
-        Ability::Static(static_ability) => strings::capitalized_sentence(
-            static_ability_serializer::serialize_static_ability(static_ability),
-        )
-        .to_string(),
-    };
+        text = number_format::spell_out_numbers(&text);
+    }
     SerializedAbility { text }
 }
 
@@ -86,20 +122,39 @@ pub fn serialize_modal_choices(
     result
 }
 
+/// Serializes an ability into its rules text.
+fn serialize_ability_text(ability: &Ability) -> String {
+    match ability {
+        Ability::Triggered(triggered) => serialize_triggered(triggered),
+        Ability::Event(event) => {
+            strings::capitalized_sentence(effect_serializer::serialize_effect(&event.effect))
+                .to_string()
+        }
+        Ability::Activated(activated) => serialize_activated(activated),
+        Ability::Named(named) => serialize_named_ability(named),
+        Ability::Static(static_ability) => strings::capitalized_sentence(
+            static_ability_serializer::serialize_static_ability(static_ability),
+        )
+        .to_string(),
+        Ability::WithAbilityWord(with_word) => strings::ability_word_ability(
+            serializer_utils::ability_word_to_phrase(with_word.word),
+            serialize_ability_text(&with_word.ability),
+        )
+        .to_string(),
+    }
+}
+
 /// Assembles a triggered ability using phrase-based composition.
 fn serialize_triggered(triggered: &TriggeredAbility) -> String {
     let has_once_per_turn = triggered.options.as_ref().map(|o| o.once_per_turn).unwrap_or(false);
-    let has_until_end_of_turn =
-        triggered.options.as_ref().map(|o| o.until_end_of_turn).unwrap_or(false);
-    let has_prefix = has_once_per_turn || has_until_end_of_turn;
     let trigger = trigger_serializer::serialize_trigger_event(&triggered.trigger);
     let effect = effect_serializer::serialize_effect_with_context(
         &triggered.effect,
         AbilityContext::Triggered,
     );
     let is_keyword_trigger = matches!(triggered.trigger, TriggerEvent::Keywords(_));
-    let prefix = build_trigger_prefix(has_until_end_of_turn, has_once_per_turn);
-    match (has_prefix, is_keyword_trigger) {
+    let prefix = strings::once_per_turn_prefix();
+    match (has_once_per_turn, is_keyword_trigger) {
         (true, true) => {
             strings::prefixed_keyword_triggered_ability(prefix, trigger, effect).to_string()
         }
@@ -109,22 +164,14 @@ fn serialize_triggered(triggered: &TriggeredAbility) -> String {
     }
 }
 
-/// Builds the combined prefix phrase for trigger modifiers.
-fn build_trigger_prefix(has_until_end_of_turn: bool, has_once_per_turn: bool) -> Phrase {
-    let prefix = Phrase::empty();
-    let prefix = if has_until_end_of_turn { strings::until_end_of_turn_prefix() } else { prefix };
-    if has_once_per_turn {
-        prefix.map_text(|t| format!("{t}{}", strings::once_per_turn_prefix()))
-    } else {
-        prefix
-    }
-}
-
 /// Assembles an activated ability using phrase-based composition.
 fn serialize_activated(activated: &ActivatedAbility) -> String {
     let is_fast = activated.options.as_ref().is_some_and(|options| options.is_fast);
     let has_once_per_turn = activated.options.as_ref().is_some_and(|options| !options.is_multi);
     let costs = join_activated_costs(&activated.costs);
+    if let Some(paragraphs) = &activated.paragraphs {
+        return serialize_activated_paragraphs(activated, paragraphs, costs);
+    }
     let effect = effect_serializer::serialize_effect_with_context(
         &activated.effect,
         AbilityContext::Triggered,
@@ -137,6 +184,39 @@ fn serialize_activated(activated: &ActivatedAbility) -> String {
     }
 }
 
+/// Assembles a block layout activated ability, with its costs on their own
+/// line followed by one line per effect paragraph.
+fn serialize_activated_paragraphs(
+    activated: &ActivatedAbility,
+    paragraphs: &[usize],
+    costs: String,
+) -> String {
+    let mut cost_line = costs;
+    if activated.options.as_ref().is_some_and(|options| options.is_fast) {
+        cost_line = format!("{}{cost_line}", strings::fast_prefix());
+    }
+    if activated.options.as_ref().is_some_and(|options| !options.is_multi) {
+        cost_line = format!("{cost_line}{}", strings::once_per_turn_suffix());
+    }
+    let effects = match &activated.effect {
+        Effect::List(effects) => effects.as_slice(),
+        _ => &[],
+    };
+    let mut result = strings::activated_cost_line(cost_line).to_string();
+    let mut remaining = effects;
+    for &length in paragraphs {
+        let (paragraph, rest) = remaining.split_at(length.min(remaining.len()));
+        remaining = rest;
+        let effect = effect_serializer::serialize_effect_with_context(
+            &Effect::List(paragraph.to_vec()),
+            AbilityContext::Event,
+        );
+        result.push('\n');
+        result.push_str(&strings::effect_paragraph_line(effect).to_string());
+    }
+    result
+}
+
 /// Joins activated ability costs with correct separators and
 /// capitalization.
 ///
@@ -1,21 +1,34 @@
 use ability_data::collection_expression::CollectionExpression;
 use ability_data::cost::Cost;
+use ability_data::predicate::{CardPredicate, Predicate};
 use rlf::Phrase;
 use strings::strings;
 
-use crate::serializer::predicate_serializer;
+use crate::serializer::{predicate_serializer, serializer_utils};
 
 /// Serializes a cost to its template text representation.
 pub fn serialize_cost(cost: &Cost) -> Phrase {
     match cost {
-        Cost::AbandonCharactersCount { target, count } => match count {
-            CollectionExpression::AnyNumberOf => {
+        Cost::AbandonCharactersCount { target, count } => match (count, target) {
+            (CollectionExpression::AnyNumberOf, _) => {
                 strings::abandon_any_number_of(predicate_serializer::serialize_predicate(target))
             }
-            CollectionExpression::Exactly(1) => {
+            (CollectionExpression::Exactly(1), _) => {
                 strings::abandon_target(predicate_serializer::serialize_predicate(target))
             }
-            CollectionExpression::Exactly(n) => strings::abandon_count_allies(*n),
+            (CollectionExpression::Exactly(n), Predicate::Another(CardPredicate::Character)) => {
+                strings::abandon_count_allies(*n)
+            }
+            (
+                CollectionExpression::Exactly(n),
+                Predicate::Another(CardPredicate::CharacterType(subtype)),
+            ) => strings::abandon_count_allied_subtype(
+                *n,
+                serializer_utils::subtype_to_phrase(*subtype),
+            ),
+            (CollectionExpression::Exactly(n), _) => {
+                strings::abandon_count_target(*n, predicate_serializer::serialize_predicate(target))
+            }
             _ => strings::abandon_count_allies(0),
         },
         Cost::DiscardCards { count, .. } => strings::discard_cards_cost(*count),
@@ -72,6 +85,7 @@ pub fn serialize_cost(cost: &Cost) -> Phrase {
             ),
         },
         Cost::SpendOneOrMoreEnergy => strings::pay_one_or_more_energy_cost(),
+        Cost::VariableEnergy => strings::x_energy_cost(),
         Cost::BanishAllCardsFromYourVoid => strings::banish_your_void_cost(),
         Cost::CostList(costs) => {
             let joined = costs
@@ -88,7 +102,7 @@ pub fn serialize_cost(cost: &Cost) -> Phrase {
 /// "pay" prefix.
 pub fn serialize_trigger_cost(cost: &Cost) -> Phrase {
     match cost {
-        Cost::Energy(_) => strings::pay_prefix(serialize_cost(cost)),
+        Cost::Energy(_) | Cost::VariableEnergy => strings::pay_prefix(serialize_cost(cost)),
         _ => serialize_cost(cost),
     }
 }
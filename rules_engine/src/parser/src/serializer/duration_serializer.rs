@@ -0,0 +1,23 @@
+use ability_data::duration::Duration;
+use rlf::Phrase;
+use strings::strings;
+
+/// Serializes a [Duration] placed after the effect it modifies, e.g. "this
+/// turn".
+pub fn serialize_duration(duration: Duration) -> Phrase {
+    match duration {
+        Duration::ThisTurn => strings::duration_this_turn(),
+        Duration::UntilYourNextMain => strings::duration_until_your_next_main(),
+        Duration::UntilYourNextTurn => strings::duration_until_your_next_turn(),
+        Duration::WhileInPlay => strings::duration_while_in_play(),
+    }
+}
+
+/// Serializes a [Duration] placed before the effect it modifies, e.g. "until
+/// end of turn".
+pub fn serialize_duration_prefix(duration: Duration) -> Phrase {
+    match duration {
+        Duration::ThisTurn => strings::duration_until_end_of_turn(),
+        _ => serialize_duration(duration),
+    }
+}
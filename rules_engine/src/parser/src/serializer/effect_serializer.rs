@@ -1,4 +1,5 @@
 use ability_data::collection_expression::CollectionExpression;
+use ability_data::duration::Duration;
 use ability_data::effect::{Effect, EffectWithOptions};
 use ability_data::predicate::{CardPredicate, Predicate};
 use ability_data::quantity_expression_data::QuantityExpression;
@@ -9,8 +10,8 @@ use rlf::Phrase;
 use strings::strings;
 
 use crate::serializer::{
-    condition_serializer, cost_serializer, predicate_serializer, serializer_utils,
-    static_ability_serializer, trigger_serializer,
+    condition_serializer, cost_serializer, duration_serializer, predicate_serializer,
+    serializer_utils, static_ability_serializer, trigger_serializer,
 };
 
 /// Context for effect serialization to determine joining behavior.
@@ -27,24 +28,33 @@ pub enum AbilityContext {
 /// Assembly-level code adds the period.
 pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
     match effect {
-        StandardEffect::CreateStaticAbilityUntilEndOfTurn { ability } => {
-            static_ability_serializer::serialize_standard_static_ability(ability)
+        StandardEffect::CreateStaticAbility { ability, duration } => {
+            strings::create_static_ability_for_duration(
+                static_ability_serializer::serialize_standard_static_ability(ability),
+                duration_serializer::serialize_duration(*duration),
+            )
         }
-        StandardEffect::CreateTriggerUntilEndOfTurn { trigger } => {
+        StandardEffect::CreateTrigger { trigger, duration } => {
             let effect_fragment = serialize_effect_fragment(&trigger.effect);
             if matches!(trigger.trigger, TriggerEvent::Keywords(_)) {
-                strings::create_trigger_until_end_of_turn_keyword(
+                strings::create_trigger_for_duration_keyword(
+                    duration_serializer::serialize_duration_prefix(*duration),
                     trigger_serializer::serialize_trigger_event(&trigger.trigger),
                     effect_fragment,
                 )
             } else {
-                strings::create_trigger_until_end_of_turn(
+                strings::create_trigger_for_duration(
+                    duration_serializer::serialize_duration_prefix(*duration),
                     trigger_serializer::serialize_trigger_event(&trigger.trigger),
                     effect_fragment,
                 )
             }
         }
         StandardEffect::DrawCards { count } => strings::draw_cards_effect(*count),
+        StandardEffect::DrawCardsForEach {
+            count: 1,
+            for_each: QuantityExpression::VariableEnergyPaid,
+        } => strings::draw_x_cards(),
         StandardEffect::DrawCardsForEach { count, for_each } => {
             strings::draw_cards_for_each(*count, serialize_for_count_expression(for_each))
         }
@@ -83,12 +93,19 @@ pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
         StandardEffect::EnemyLosesPoints { count } => strings::opponent_loses_points_effect(*count),
         StandardEffect::Foresee { count } => strings::foresee_effect(*count),
         StandardEffect::Kindle { amount } => strings::kindle_effect(amount.0),
-        StandardEffect::GainsReclaim { target, count, this_turn, cost } => {
-            serialize_gains_reclaim(target, count, *this_turn, cost)
+        StandardEffect::GainsReclaim { target, count, duration, cost } => {
+            serialize_gains_reclaim(target, count, *duration, cost)
         }
         StandardEffect::GainsSpark { target, gains } => {
             strings::gains_spark(predicate_serializer::serialize_predicate(target), gains.0)
         }
+        StandardEffect::PutSparkCounters { target, count } => {
+            strings::put_spark_counters(*count, predicate_serializer::serialize_predicate(target))
+        }
+        StandardEffect::RemoveSparkCounters { target, count } => strings::remove_spark_counters(
+            *count,
+            predicate_serializer::serialize_predicate(target),
+        ),
         StandardEffect::EachMatchingGainsSpark { each, gains } => {
             strings::have_each_gain_spark(serialize_allied_card_predicate(each), gains.0)
         }
@@ -176,9 +193,10 @@ pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
                 predicate_serializer::predicate_base_phrase(until_leaves),
             )
         }
-        StandardEffect::BanishUntilNextMain { target } => {
-            strings::banish_until_next_main(predicate_serializer::serialize_predicate(target))
-        }
+        StandardEffect::BanishForDuration { target, duration } => strings::banish_for_duration(
+            predicate_serializer::serialize_predicate(target),
+            duration_serializer::serialize_duration(*duration),
+        ),
         StandardEffect::Discover { predicate } => strings::discover_target(
             predicate_serializer::serialize_card_predicate_phrase(predicate),
         ),
@@ -291,8 +309,11 @@ pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
         StandardEffect::Copy { target } => {
             strings::copy_target(predicate_serializer::serialize_predicate(target))
         }
-        StandardEffect::DisableActivatedAbilitiesWhileInPlay { target } => {
-            strings::disable_activated_abilities(predicate_serializer::serialize_predicate(target))
+        StandardEffect::DisableActivatedAbilities { target, duration } => {
+            strings::disable_activated_abilities(
+                predicate_serializer::serialize_predicate(target),
+                duration_serializer::serialize_duration(*duration),
+            )
         }
         StandardEffect::DrawMatchingCard { predicate } => strings::draw_matching_from_deck(
             predicate_serializer::serialize_card_predicate_phrase(predicate),
@@ -305,6 +326,9 @@ pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
                 collection, matching,
             )),
         },
+        StandardEffect::TriggerThisCardsAbility { word } => {
+            strings::trigger_this_cards_ability(serializer_utils::ability_word_to_phrase(*word))
+        }
         StandardEffect::TriggerAdditionalJudgmentPhaseAtEndOfTurn => {
             strings::judgment_phase_at_end_of_turn_effect()
         }
@@ -327,13 +351,15 @@ pub fn serialize_standard_effect(effect: &StandardEffect) -> Phrase {
                 serialize_for_count_expression(quantity),
             )
         }
-        StandardEffect::PreventDissolveThisTurn { target } => {
-            strings::prevent_dissolve_this_turn(predicate_serializer::serialize_predicate(target))
-        }
-        StandardEffect::GainsSparkUntilYourNextMainForEach { target, gains, for_each } => {
-            strings::gains_spark_until_next_main_for_each(
+        StandardEffect::PreventDissolve { target, duration } => strings::prevent_dissolve(
+            predicate_serializer::serialize_predicate(target),
+            duration_serializer::serialize_duration(*duration),
+        ),
+        StandardEffect::GainsSparkForEach { target, gains, for_each, duration } => {
+            strings::gains_spark_for_duration_for_each(
                 predicate_serializer::serialize_predicate(target),
                 gains.0,
+                duration_serializer::serialize_duration(*duration),
                 predicate_serializer::for_each_predicate_phrase(for_each),
             )
         }
@@ -503,6 +529,17 @@ pub fn serialize_effect_with_context(effect: &Effect, context: AbilityContext) -
                 with_period
             }
         }
+        Effect::IfYouDo(if_you_do) => {
+            let effect_text = serialize_standard_effect(&if_you_do.effect);
+            let body = if if_you_do.optional {
+                strings::optional_effect_body(effect_text)
+            } else {
+                effect_text
+            };
+            let then_join = strings::then_joiner().to_string();
+            let follow_up = join_effect_phrases(&if_you_do.follow_up, &then_join);
+            strings::effect_with_period(strings::if_you_do_effect_body(body, follow_up))
+        }
         Effect::Modal(choices) => {
             let mut result = strings::choose_one().to_string();
             for choice in choices {
@@ -569,6 +606,10 @@ pub fn serialize_for_count_expression(quantity_expression: &QuantityExpression)
             let base = predicate_serializer::base_card_phrase(predicate);
             strings::card_predicate_dissolved_this_turn(base)
         }
+        QuantityExpression::SparkCountersOn(target) => {
+            strings::spark_counter_on(predicate_serializer::serialize_predicate(target))
+        }
+        QuantityExpression::VariableEnergyPaid => strings::x_amount(),
     }
 }
 
@@ -630,52 +671,60 @@ fn prepend_condition_from_list(effects: &[EffectWithOptions], body: Phrase) -> P
 fn serialize_gains_reclaim(
     target: &Predicate,
     count: &CollectionExpression,
-    this_turn: bool,
+    duration: Option<Duration>,
     cost: &Option<Energy>,
 ) -> Phrase {
+    let duration = duration.map(duration_serializer::serialize_duration);
     match target {
         Predicate::It => {
             let antecedent = strings::pronoun_it();
-            if let Some(energy_cost) = cost {
-                if this_turn {
-                    strings::it_gains_reclaim_for_cost_this_turn(antecedent, energy_cost.0)
-                } else {
+            match (cost, duration) {
+                (Some(energy_cost), Some(duration)) => {
+                    strings::it_gains_reclaim_for_cost_with_duration(
+                        antecedent,
+                        energy_cost.0,
+                        duration,
+                    )
+                }
+                (Some(energy_cost), None) => {
                     strings::it_gains_reclaim_for_cost(antecedent, energy_cost.0)
                 }
-            } else if this_turn {
-                strings::it_gains_reclaim_equal_cost_this_turn(antecedent)
-            } else {
-                strings::it_gains_reclaim_equal_cost(antecedent)
-            }
-        }
-        Predicate::This => {
-            if let Some(energy_cost) = cost {
-                if this_turn {
-                    strings::this_card_gains_reclaim_for_cost_this_turn(energy_cost.0)
-                } else {
-                    strings::this_card_gains_reclaim_for_cost(energy_cost.0)
+                (None, Some(duration)) => {
+                    strings::it_gains_reclaim_equal_cost_with_duration(antecedent, duration)
                 }
-            } else if this_turn {
-                strings::this_card_gains_reclaim_equal_cost_this_turn()
-            } else {
-                strings::this_card_gains_reclaim_equal_cost()
+                (None, None) => strings::it_gains_reclaim_equal_cost(antecedent),
             }
         }
+        Predicate::This => match (cost, duration) {
+            (Some(energy_cost), Some(duration)) => {
+                strings::this_card_gains_reclaim_for_cost_with_duration(energy_cost.0, duration)
+            }
+            (Some(energy_cost), None) => strings::this_card_gains_reclaim_for_cost(energy_cost.0),
+            (None, Some(duration)) => {
+                strings::this_card_gains_reclaim_equal_cost_with_duration(duration)
+            }
+            (None, None) => strings::this_card_gains_reclaim_equal_cost(),
+        },
         Predicate::YourVoid(predicate) => {
-            serialize_void_gains_reclaim(count, predicate, this_turn, cost)
+            serialize_void_gains_reclaim(count, predicate, duration, cost)
         }
         _ => {
             let target = predicate_serializer::serialize_predicate(target);
-            if let Some(energy_cost) = cost {
-                if this_turn {
-                    strings::target_gains_reclaim_for_cost_this_turn(target, energy_cost.0)
-                } else {
+            match (cost, duration) {
+                (Some(energy_cost), Some(duration)) => {
+                    strings::target_gains_reclaim_for_cost_with_duration(
+                        target,
+                        energy_cost.0,
+                        duration,
+                    )
+                }
+                (Some(energy_cost), None) => {
                     strings::target_gains_reclaim_for_cost(target, energy_cost.0)
                 }
-            } else if this_turn {
-                strings::target_gains_reclaim_equal_cost_this_turn(target)
-            } else {
-                strings::target_gains_reclaim_equal_cost(target)
+                (None, Some(duration)) => {
+                    strings::target_gains_reclaim_equal_cost_with_duration(target, duration)
+                }
+                (None, None) => strings::target_gains_reclaim_equal_cost(target),
             }
         }
     }
@@ -684,11 +733,11 @@ fn serialize_gains_reclaim(
 fn serialize_void_gains_reclaim(
     count: &CollectionExpression,
     predicate: &CardPredicate,
-    this_turn: bool,
+    duration: Option<Phrase>,
     cost: &Option<Energy>,
 ) -> Phrase {
     let (subject, is_singular) = serialize_void_collection_subject(count, predicate);
-    assemble_void_reclaim_effect(subject, is_singular, this_turn, cost)
+    assemble_void_reclaim_effect(subject, is_singular, duration, cost)
 }
 
 /// Produces the subject phrase and singularity flag for a void collection
@@ -738,25 +787,37 @@ fn serialize_void_collection_subject(
 fn assemble_void_reclaim_effect(
     subject: Phrase,
     is_singular: bool,
-    this_turn: bool,
+    duration: Option<Phrase>,
     cost: &Option<Energy>,
 ) -> Phrase {
-    match (is_singular, this_turn, cost) {
-        (true, false, Some(energy_cost)) => {
+    match (is_singular, duration, cost) {
+        (true, None, Some(energy_cost)) => {
             strings::void_gains_reclaim_for_cost_singular(subject, energy_cost.0)
         }
-        (true, false, None) => strings::void_gains_reclaim_equal_cost_singular(subject),
-        (true, true, Some(energy_cost)) => {
-            strings::void_gains_reclaim_for_cost_singular_this_turn(subject, energy_cost.0)
+        (true, None, None) => strings::void_gains_reclaim_equal_cost_singular(subject),
+        (true, Some(duration), Some(energy_cost)) => {
+            strings::void_gains_reclaim_for_cost_singular_with_duration(
+                subject,
+                energy_cost.0,
+                duration,
+            )
+        }
+        (true, Some(duration), None) => {
+            strings::void_gains_reclaim_equal_cost_singular_with_duration(subject, duration)
         }
-        (true, true, None) => strings::void_gains_reclaim_equal_cost_singular_this_turn(subject),
-        (false, false, Some(energy_cost)) => {
+        (false, None, Some(energy_cost)) => {
             strings::void_gains_reclaim_for_cost_plural(subject, energy_cost.0)
         }
-        (false, false, None) => strings::void_gains_reclaim_equal_cost_plural(subject),
-        (false, true, Some(energy_cost)) => {
-            strings::void_gains_reclaim_for_cost_plural_this_turn(subject, energy_cost.0)
+        (false, None, None) => strings::void_gains_reclaim_equal_cost_plural(subject),
+        (false, Some(duration), Some(energy_cost)) => {
+            strings::void_gains_reclaim_for_cost_plural_with_duration(
+                subject,
+                energy_cost.0,
+                duration,
+            )
+        }
+        (false, Some(duration), None) => {
+            strings::void_gains_reclaim_equal_cost_plural_with_duration(subject, duration)
         }
-        (false, true, None) => strings::void_gains_reclaim_equal_cost_plural_this_turn(subject),
     }
 }
@@ -6,5 +6,7 @@ pub mod prompt_serializer;
 pub mod trigger_serializer;
 
 mod condition_serializer;
+mod duration_serializer;
+mod reminder_text_serializer;
 mod serializer_utils;
 mod static_ability_serializer;
@@ -128,7 +128,10 @@ pub(super) fn for_each_predicate_phrase(predicate: &Predicate) -> Phrase {
 /// Serializes a card predicate with an article.
 fn serialize_card_predicate(card_predicate: &CardPredicate) -> Phrase {
     match card_predicate {
-        CardPredicate::Card | CardPredicate::Character | CardPredicate::Event => {
+        CardPredicate::Card
+        | CardPredicate::Character
+        | CardPredicate::Event
+        | CardPredicate::Or(..) => {
             strings::predicate_with_indefinite_article(card_predicate_base_phrase(card_predicate))
         }
         CardPredicate::CharacterType(subtype) => strings::predicate_with_indefinite_article(
@@ -208,9 +211,10 @@ fn serialize_card_predicate(card_predicate: &CardPredicate) -> Phrase {
 /// Use this when the caller already provides an article, like "a random".
 fn serialize_card_predicate_without_article(card_predicate: &CardPredicate) -> Phrase {
     match card_predicate {
-        CardPredicate::Card | CardPredicate::Character | CardPredicate::Event => {
-            card_predicate_base_phrase(card_predicate)
-        }
+        CardPredicate::Card
+        | CardPredicate::Character
+        | CardPredicate::Event
+        | CardPredicate::Or(..) => card_predicate_base_phrase(card_predicate),
         CardPredicate::CharacterType(subtype) => {
             strings::subtype(serializer_utils::subtype_to_phrase(*subtype))
         }
@@ -302,6 +306,11 @@ fn card_predicate_base_phrase(predicate: &CardPredicate) -> Phrase {
         CardPredicate::NotCharacterType(subtype) => {
             strings::character_not_subtype(serializer_utils::subtype_to_phrase(*subtype))
         }
+        CardPredicate::Or(predicates) => predicates
+            .iter()
+            .map(card_predicate_base_phrase)
+            .reduce(strings::predicate_or)
+            .unwrap_or_else(strings::character),
         CardPredicate::Fast { target } => card_predicate_base_phrase(target),
         CardPredicate::CardWithCost { target, .. } => card_predicate_base_phrase(target),
         CardPredicate::CharacterWithSpark(..) => strings::character(),
@@ -354,6 +363,9 @@ fn your_predicate_formatted(card_predicate: &CardPredicate) -> Phrase {
         CardPredicate::NotCharacterType(subtype) => {
             strings::ally_not_subtype(serializer_utils::subtype_to_phrase(*subtype))
         }
+        CardPredicate::Or(..) => {
+            strings::allied_predicate(card_predicate_base_phrase(card_predicate))
+        }
         CardPredicate::CharacterWithSpark(spark, operator) => strings::pred_with_constraint(
             strings::ally(),
             strings::with_spark_constraint(serializer_utils::serialize_operator(operator), spark.0),
@@ -428,6 +440,9 @@ fn enemy_predicate_formatted(card_predicate: &CardPredicate) -> Phrase {
         CardPredicate::NotCharacterType(subtype) => {
             strings::non_subtype_enemy(serializer_utils::subtype_to_phrase(*subtype))
         }
+        CardPredicate::Or(..) => {
+            strings::enemy_predicate(card_predicate_base_phrase(card_predicate))
+        }
         CardPredicate::CharacterWithSpark(spark, operator) => strings::pred_with_constraint(
             strings::enemy(),
             strings::with_spark_constraint(serializer_utils::serialize_operator(operator), spark.0),
@@ -507,6 +522,9 @@ fn serialize_your_predicate_plural(card_predicate: &CardPredicate) -> Phrase {
         CardPredicate::NotCharacterType(subtype) => {
             strings::ally_not_subtype_plural(serializer_utils::subtype_to_phrase(*subtype))
         }
+        CardPredicate::Or(..) => {
+            strings::allied_predicate_plural(card_predicate_base_phrase(card_predicate))
+        }
         CardPredicate::CharacterWithSpark(spark, operator) => {
             strings::as_plural(strings::pred_with_constraint(
                 strings::ally(),
@@ -592,6 +610,9 @@ fn serialize_enemy_predicate_plural(card_predicate: &CardPredicate) -> Phrase {
         CardPredicate::NotCharacterType(subtype) => {
             strings::non_subtype_enemy_plural(serializer_utils::subtype_to_phrase(*subtype))
         }
+        CardPredicate::Or(..) => {
+            strings::enemy_predicate_plural(card_predicate_base_phrase(card_predicate))
+        }
         CardPredicate::Fast { target } => {
             strings::fast_predicate_plural(serialize_card_predicate_plural_text(target))
         }
@@ -0,0 +1,78 @@
+use ability_data::ability::Ability;
+use ability_data::ability_visitor::{self, AbilityVisitor};
+use ability_data::named_ability::NamedAbility;
+use ability_data::standard_effect::StandardEffect;
+use rlf::Phrase;
+
+use crate::keywords::keyword_registry;
+
+/// Returns the standard reminder text for each keyword used in `ability`, in
+/// the order the keywords first appear.
+pub fn serialize_reminder_text(ability: &Ability) -> Vec<Phrase> {
+    let mut collector = KeywordCollector::default();
+    collector.visit_ability(ability);
+    collector
+        .keywords
+        .into_iter()
+        .filter_map(|keyword| keyword_registry::reminder_text(keyword.name, keyword.count))
+        .collect()
+}
+
+/// A registered keyword used in an ability, along with its numeric argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReminderKeyword {
+    name: &'static str,
+    count: u32,
+}
+
+#[derive(Default)]
+struct KeywordCollector {
+    keywords: Vec<ReminderKeyword>,
+}
+
+impl AbilityVisitor for KeywordCollector {
+    fn visit_named_ability(&mut self, ability: &NamedAbility) {
+        self.add(ReminderKeyword::new("reclaim"));
+        ability_visitor::walk_named_ability(self, ability);
+    }
+
+    fn visit_standard_effect(&mut self, effect: &StandardEffect) {
+        if let Some(keyword) = standard_effect_keyword(effect) {
+            self.add(keyword);
+        }
+        ability_visitor::walk_standard_effect(self, effect);
+    }
+}
+
+impl ReminderKeyword {
+    fn new(name: &'static str) -> Self {
+        Self { name, count: 0 }
+    }
+}
+
+impl KeywordCollector {
+    fn add(&mut self, keyword: ReminderKeyword) {
+        if !self.keywords.contains(&keyword) {
+            self.keywords.push(keyword);
+        }
+    }
+}
+
+fn standard_effect_keyword(effect: &StandardEffect) -> Option<ReminderKeyword> {
+    let keyword = match effect {
+        StandardEffect::Discover { .. } | StandardEffect::DiscoverAndThenMaterialize { .. } => {
+            ReminderKeyword::new("discover")
+        }
+        StandardEffect::DissolveCharacter { .. }
+        | StandardEffect::DissolveCharactersCount { .. }
+        | StandardEffect::DissolveCharactersQuantity { .. } => ReminderKeyword::new("dissolve"),
+        StandardEffect::Foresee { count } => ReminderKeyword { name: "foresee", count: *count },
+        StandardEffect::Kindle { amount } => ReminderKeyword { name: "kindle", count: amount.0 },
+        StandardEffect::Counterspell { .. } | StandardEffect::CounterspellUnlessPaysCost { .. } => {
+            ReminderKeyword::new("prevent")
+        }
+        StandardEffect::GainsReclaim { .. } => ReminderKeyword::new("reclaim"),
+        _ => return None,
+    };
+    Some(keyword)
+}
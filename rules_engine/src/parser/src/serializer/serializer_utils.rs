@@ -1,3 +1,4 @@
+use ability_data::ability::AbilityWord;
 use ability_data::predicate::Operator;
 use core_data::card_types::CardSubtype;
 use core_data::figment_type::FigmentType;
@@ -30,6 +31,13 @@ pub fn figment_to_phrase(figment: FigmentType) -> Phrase {
     }
 }
 
+/// Converts an [AbilityWord] to its corresponding RLF phrase.
+pub fn ability_word_to_phrase(word: AbilityWord) -> Phrase {
+    match word {
+        AbilityWord::Resolve => strings::resolve(),
+    }
+}
+
 /// Converts a [CardSubtype] to its corresponding RLF phrase.
 pub fn subtype_to_phrase(subtype: CardSubtype) -> Phrase {
     match subtype {
@@ -2,6 +2,7 @@ use ability_data::trigger_event::{PlayerTurn, TriggerEvent, TriggerKeyword};
 use rlf::Phrase;
 use strings::strings;
 
+use crate::keywords::keyword_registry;
 use crate::serializer::predicate_serializer;
 
 /// Serializes a trigger event to its phrase representation.
@@ -54,6 +55,7 @@ pub fn serialize_trigger_event(trigger: &TriggerEvent) -> Phrase {
         ),
         TriggerEvent::DrawCardsInTurn(count) => strings::when_you_draw_in_turn_trigger(*count),
         TriggerEvent::EndOfYourTurn => strings::at_end_of_your_turn_trigger(),
+        TriggerEvent::StartOfYourTurn => strings::at_start_of_your_turn_trigger(),
         TriggerEvent::DrawAllCardsInCopyOfDeck => strings::when_deck_empty_trigger(),
         TriggerEvent::MaterializeNthThisTurn(predicate, count) => {
             strings::when_you_materialize_nth_in_turn_trigger(
@@ -68,28 +70,20 @@ pub fn serialize_trigger_event(trigger: &TriggerEvent) -> Phrase {
 /// Serializes a keyword trigger list to its phrase representation.
 fn serialize_keyword_trigger(keywords: &[TriggerKeyword]) -> Phrase {
     match keywords {
-        [TriggerKeyword::Judgment] => strings::judgment(),
-        [TriggerKeyword::Materialized] => strings::materialized(),
-        [TriggerKeyword::Dissolved] => strings::dissolved(),
         [TriggerKeyword::Materialized, TriggerKeyword::Judgment] => {
             strings::materialized_judgment()
         }
         [TriggerKeyword::Materialized, TriggerKeyword::Dissolved] => {
             strings::materialized_dissolved()
         }
+        [keyword] => keyword_registry::trigger_prefix(keyword),
         _ => {
-            let keyword_text =
-                keywords.iter().map(serialize_keyword_name).collect::<Vec<_>>().join(", ");
+            let keyword_text = keywords
+                .iter()
+                .map(|keyword| keyword_registry::trigger_display_name(keyword).to_string())
+                .collect::<Vec<_>>()
+                .join(&strings::trigger_keyword_separator().to_string());
             strings::trigger(keyword_text)
         }
     }
 }
-
-/// Returns the display name for a trigger keyword.
-fn serialize_keyword_name(keyword: &TriggerKeyword) -> String {
-    match keyword {
-        TriggerKeyword::Judgment => strings::judgment_keyword_name().to_string(),
-        TriggerKeyword::Materialized => strings::materialized_keyword_name().to_string(),
-        TriggerKeyword::Dissolved => strings::dissolved_keyword_name().to_string(),
-    }
-}
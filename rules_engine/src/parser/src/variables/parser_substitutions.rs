@@ -6,7 +6,7 @@ use serde::Serialize;
 use crate::lexer::lexer_token::{Spanned, Token};
 use crate::variables::parser_bindings::VariableBindings;
 
-static BARE_PHRASES: &[&str] = &["choose_one", "energy_symbol", "judgment_phase_name"];
+static BARE_PHRASES: &[&str] = &["choose_one", "energy_symbol", "judgment_phase_name", "x"];
 
 static FIGMENT_PHRASES: &[(&str, &str)] = &[("figment", "g"), ("figments", "g")];
 
@@ -25,6 +25,7 @@ static PHRASES: &[PhraseEntry] = &[
     ("points", "p", ResolvedToken::PointCount),
     ("reclaim_for_cost", "r", ResolvedToken::ReclaimCost),
     ("spark", "s", ResolvedToken::SparkAmount),
+    ("spark_counters", "n", ResolvedToken::SparkCounters),
     ("text_number", "n", ResolvedToken::TextNumber),
     ("this_turn_times", "n", ResolvedToken::ThisTurnTimes),
     ("top_n_cards", "v", ResolvedToken::CardCount),
@@ -51,6 +52,7 @@ pub enum ResolvedToken {
     DiscardCount(u32),
     PointCount(u32),
     SparkAmount(u32),
+    SparkCounters(u32),
     ForeseeCount(u32),
     KindleAmount(u32),
     MaximumEnergy(u32),
@@ -81,15 +83,17 @@ pub struct UnresolvedVariable {
 /// Resolves directive tokens using variable bindings.
 ///
 /// Display-only directives (like `{card:$c}` for RLF parameterized
-/// selection) are filtered out since they carry no semantic information
-/// for the parser.
+/// selection) and reminder text are filtered out since they carry no
+/// semantic information for the parser.
 pub fn resolve_variables(
     tokens: &[Spanned<Token>],
     bindings: &VariableBindings,
 ) -> Result<Vec<Spanned<ResolvedToken>>, UnresolvedVariable> {
     tokens
         .iter()
-        .filter(|(token, _)| !is_display_only_directive(token))
+        .filter(|(token, _)| {
+            !is_display_only_directive(token) && !matches!(token, Token::ReminderText(_))
+        })
         .map(|(token, span)| match token {
             Token::Directive(name) => {
                 resolve_directive(name, bindings, *span).map(|resolved| (resolved, *span))
@@ -111,6 +115,19 @@ pub fn phrase_names() -> impl Iterator<Item = &'static str> {
         .chain(std::iter::once("count_allied_subtype"))
 }
 
+/// Returns a directive template for each phrase which can appear in rules
+/// text, written with its default variable, e.g. "cards($c)".
+pub fn phrase_templates() -> impl Iterator<Item = String> {
+    PHRASES
+        .iter()
+        .filter(|(name, var_name, _)| name != var_name)
+        .map(|(name, var_name, _)| (*name, *var_name))
+        .chain(SUBTYPE_PHRASES.iter().copied())
+        .chain(FIGMENT_PHRASES.iter().copied())
+        .map(|(name, var_name)| format!("{name}(${var_name})"))
+        .chain(BARE_PHRASES.iter().map(|name| (*name).to_string()))
+}
+
 /// Returns all variable names for error suggestion matching.
 pub fn variable_names() -> impl Iterator<Item = &'static str> {
     PHRASES
@@ -1,4 +1,6 @@
-use core_data::identifiers::BaseCardId;
+use std::collections::BTreeMap;
+
+use core_data::identifiers::{BaseCardId, CardVariantId};
 use serde::{Deserialize, Serialize};
 use tabula_data::card_definition::CardDefinition;
 use tabula_data::tabula::Tabula;
@@ -36,6 +38,16 @@ impl Deck {
         }
     }
 
+    /// Sets the displayed variant of each card in the deck whose base card has
+    /// an entry in `variants`.
+    pub fn select_variants(&mut self, variants: &BTreeMap<BaseCardId, CardVariantId>) {
+        for card in &mut self.cards {
+            if let Some(&variant) = variants.get(&card.base_card_id) {
+                card.variant = Some(variant);
+            }
+        }
+    }
+
     pub fn push_card_and_get_id(&mut self, card: CardDefinition) -> QuestDeckCardId {
         let id = QuestDeckCardId(self.cards.len());
         self.cards.push(card);
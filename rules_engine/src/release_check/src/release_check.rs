@@ -0,0 +1,286 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Instant;
+
+use ability_data::ability::Ability;
+use ai_agents::agent_search;
+use ai_data::game_ai::GameAI;
+use battle_mutations::actions::apply_battle_action;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::battle::animation_data::AnimationData;
+use battle_state::battle::battle_state::RequestContext;
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle_cards::dreamwell_data::Dreamwell;
+use battle_state::battle_player::battle_player_state::{
+    CreateBattlePlayer, PlayerType, TestDeckName,
+};
+use clap::Parser;
+use core_data::identifiers::{BattleId, UserId};
+use core_data::numerics::TurnId;
+use core_data::types::PlayerName;
+use database::match_history::MatchHistory;
+use display::rendering::renderer;
+use game_creation::new_test_battle;
+use parser::ability_directory_parser;
+use state_provider::display_state_provider::{DisplayState, DisplayStateProvider, TutorialMessageId};
+use tabula_data::tabula::{Tabula, TabulaSource};
+use tabula_generated::card_lists::DreamwellCardIdList;
+use uuid::Uuid;
+
+/// Test decks to play, with the card data each deck is built from.
+const DECKS: [(TestDeckName, TabulaSource); 4] = [
+    (TestDeckName::Vanilla, TabulaSource::Test),
+    (TestDeckName::StartingFive, TabulaSource::Test),
+    (TestDeckName::Benchmark1, TabulaSource::Test),
+    (TestDeckName::Core11, TabulaSource::Production),
+];
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Smoke-test card data, battles and rendering as a final pre-release gate",
+    after_help = "Exits with a non-zero status if any check fails.\n\nEXAMPLE:\n    release_check --turns 20"
+)]
+struct Args {
+    #[arg(
+        long,
+        help = "Path to the Tabula directory. Defaults to the development client's StreamingAssets/Tabula"
+    )]
+    tabula_dir: Option<PathBuf>,
+
+    #[arg(long, default_value = "20", help = "Number of turns to play with each test deck")]
+    turns: u32,
+
+    #[arg(
+        long,
+        default_value = "{\"MonteCarlo\":1}",
+        help = "JSON serialized GameAI for both players"
+    )]
+    ai: String,
+
+    #[arg(long, default_value = "3141592653", help = "Random seed for each battle")]
+    seed: u64,
+}
+
+/// Display state provider which renders every battle with default display
+/// state and a fixed [Tabula].
+#[derive(Clone)]
+struct ReleaseCheckProvider {
+    tabula: Arc<Tabula>,
+}
+
+impl DisplayStateProvider for ReleaseCheckProvider {
+    fn get_display_state(&self, _user_id: UserId) -> DisplayState {
+        DisplayState::default()
+    }
+
+    fn set_display_state(&self, _user_id: UserId, _state: DisplayState) {}
+
+    fn tabula(&self) -> Arc<Tabula> {
+        self.tabula.clone()
+    }
+
+    fn can_undo(&self, _battle_id: BattleId, _player: PlayerName) -> bool {
+        false
+    }
+
+    fn has_seen_tutorial_message(&self, _user_id: UserId, _message: TutorialMessageId) -> bool {
+        false
+    }
+
+    fn mark_tutorial_message_seen(&self, _user_id: UserId, _message: TutorialMessageId) {}
+
+    fn match_history(&self, _user_id: UserId) -> MatchHistory {
+        MatchHistory::default()
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let ai: GameAI = match serde_json::from_str(&args.ai) {
+        Ok(ai) => ai,
+        Err(e) => {
+            eprintln!("Invalid --ai value: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let tabula_dir = args.tabula_dir.unwrap_or_else(|| {
+        PathBuf::from(logging::get_developer_mode_streaming_assets_path()).join("Tabula")
+    });
+
+    let mut failures = Vec::new();
+    let production = run_check("Load production tabula", &mut failures, || {
+        load_tabula(TabulaSource::Production, &tabula_dir)
+    });
+    let test = run_check("Load test tabula", &mut failures, || {
+        load_tabula(TabulaSource::Test, &tabula_dir)
+    });
+    let loaded = [&production, &test].into_iter().flatten().cloned().collect::<Vec<_>>();
+    run_check("Parse card abilities", &mut failures, || check_abilities(&tabula_dir, &loaded));
+
+    for (deck, source) in DECKS {
+        let tabula = match source {
+            TabulaSource::Production => &production,
+            TabulaSource::Test => &test,
+        };
+        let Some(tabula) = tabula else {
+            failures.push(format!("Play {deck:?}: card data failed to load"));
+            continue;
+        };
+        run_check(&format!("Play {deck:?}"), &mut failures, || {
+            play_battle(tabula.clone(), deck, source, ai, args.turns, args.seed)
+        });
+    }
+
+    if failures.is_empty() {
+        println!("\nAll release checks passed.");
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("\n{} release check(s) failed:", failures.len());
+        for failure in &failures {
+            eprintln!("  {failure}");
+        }
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs a single check, recording a failure if it returns an error or panics.
+fn run_check<T>(
+    name: &str,
+    failures: &mut Vec<String>,
+    check: impl FnOnce() -> Result<T, String>,
+) -> Option<T> {
+    let start_time = Instant::now();
+    println!("{name}...");
+    let result = panic::catch_unwind(AssertUnwindSafe(check))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload.as_ref()))));
+    match result {
+        Ok(value) => {
+            println!("  ok ({:.2?})", start_time.elapsed());
+            Some(value)
+        }
+        Err(message) => {
+            eprintln!("  FAILED: {message}");
+            failures.push(format!("{name}: {message}"));
+            None
+        }
+    }
+}
+
+fn load_tabula(source: TabulaSource, tabula_dir: &Path) -> Result<Arc<Tabula>, String> {
+    Tabula::load(source, tabula_dir)
+        .map(Arc::new)
+        .map_err(|errors| errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n    "))
+}
+
+/// Parses the rules text of every card and checks that the result matches
+/// the pre-parsed abilities loaded into each [Tabula].
+fn check_abilities(tabula_dir: &Path, tabulas: &[Arc<Tabula>]) -> Result<(), String> {
+    let parsed = ability_directory_parser::parse_abilities_from_directory(tabula_dir)
+        .map_err(|e| format!("Failed to parse abilities: {e}"))?;
+    let mut mismatched = Vec::new();
+    let mut check = |id: Uuid, name: &str, rules_text: &str, abilities: &[Ability]| {
+        let expected = parsed.get(&id.to_string()).map_or(&[][..], Vec::as_slice);
+        if expected != abilities || (expected.is_empty() && !rules_text.trim().is_empty()) {
+            mismatched.push(format!("{name} ({id})"));
+        }
+    };
+    for tabula in tabulas {
+        for card in tabula.cards.values() {
+            check(
+                card.base_card_id.0,
+                &card.displayed_name,
+                &card.displayed_rules_text,
+                &card.abilities,
+            );
+        }
+        for card in tabula.dreamwell_cards.values() {
+            check(
+                card.base_card_id.0,
+                &card.displayed_name,
+                &card.displayed_rules_text,
+                &card.abilities,
+            );
+        }
+    }
+
+    if mismatched.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Rules text does not parse to the abilities in parsed_abilities.json for {} card(s), \
+             run `tabula generate` to regenerate:\n    {}",
+            mismatched.len(),
+            mismatched.join("\n    ")
+        ))
+    }
+}
+
+/// Plays a battle between two AI-controlled copies of `deck` for `turns`
+/// turns, rendering every update for player one.
+fn play_battle(
+    tabula: Arc<Tabula>,
+    deck: TestDeckName,
+    source: TabulaSource,
+    ai: GameAI,
+    turns: u32,
+    seed: u64,
+) -> Result<(), String> {
+    let user_id = UserId(Uuid::new_v4());
+    let dreamwell = match source {
+        TabulaSource::Production => DreamwellCardIdList::DreamwellBasic5,
+        TabulaSource::Test => DreamwellCardIdList::TestDreamwellBasic5,
+    };
+    let mut battle = new_test_battle::create_and_start(
+        BattleId(Uuid::new_v4()),
+        tabula.clone(),
+        seed,
+        Dreamwell::from_card_list(&tabula, dreamwell),
+        CreateBattlePlayer {
+            player_type: PlayerType::User(user_id),
+            deck_name: deck,
+            personality: None,
+        },
+        CreateBattlePlayer {
+            player_type: PlayerType::Agent(ai),
+            deck_name: deck,
+            personality: None,
+        },
+        RequestContext::default(),
+    );
+    let provider = ReleaseCheckProvider { tabula };
+    renderer::connect(&battle, user_id, provider.clone(), false);
+
+    while battle.turn.turn_id < TurnId(turns) {
+        let Some(player) = legal_actions::next_to_act(&battle) else {
+            return match battle.status {
+                BattleStatus::GameOver { .. } => Ok(()),
+                _ => Err(format!("No player to act on turn {:?}", battle.turn.turn_id)),
+            };
+        };
+        let legal = legal_actions::compute(&battle, player);
+        let action = if legal.len() == 1 {
+            legal.all()[0]
+        } else {
+            agent_search::select_action_unchecked(&battle, player, &ai, None)
+        };
+        battle.animations = Some(AnimationData::default());
+        apply_battle_action::execute(&mut battle, player, action);
+        renderer::render_updates(&battle, user_id, provider.clone());
+        battle.animations = None;
+    }
+    Ok(())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -46,10 +46,12 @@ pub fn execute<P>(
                 CreateBattlePlayer {
                     player_type: battle.players.one.player_type.clone(),
                     deck_name: one,
+                    personality: battle.players.one.personality,
                 },
                 CreateBattlePlayer {
                     player_type: battle.players.two.player_type.clone(),
                     deck_name: two,
+                    personality: battle.players.two.personality,
                 },
                 battle.request_context.clone(),
             );
@@ -37,6 +37,7 @@ where
                 },
                 card_abilities::build_from_definition,
             ));
+            battle.ability_state.upgrade_saved_effects(|id| battle.cards[id].owner);
             legal_actions_cache::populate(&mut battle);
             battle_trace!("Loaded battle from save", &mut battle);
             Some((battle, quest_id))
@@ -5,6 +5,8 @@ use std::panic;
 use std::sync::{LazyLock, Mutex};
 use std::time::Instant;
 
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::emote_data::Emote;
 use action_data::game_action_data::GameAction;
 use ai_agents::agent_search;
 use ai_data::game_ai::GameAI;
@@ -20,12 +22,18 @@ use battle_state::battle_player::battle_player_state::{CreateBattlePlayer, Playe
 use core_data::identifiers::{BattleId, QuestId, UserId};
 use core_data::initialization_error::InitializationError;
 use core_data::types::PlayerName;
+use database::collection::{Collection, UnlockSource};
+use database::match_history::MatchHistory;
+use database::save_file::SaveFile;
+use database::save_file_io;
 use display::display_actions::apply_battle_display_action;
 use display::rendering::renderer;
+use display_data::battle_view::{BattleView, DisplayPlayer, PendingPromptView};
 use display_data::command::CommandSequence;
 use display_data::request_data::{
-    ConnectRequest, ConnectResponse, DebugConfiguration, Metadata, PerformActionRequest,
-    PollResponse, PollResponseType,
+    ClientCapability, ConnectRequest, ConnectResponse, DebugConfiguration, Metadata,
+    PROTOCOL_VERSION, PerformActionRequest, PollResponse, PollResponseType, ResyncRequest,
+    ResyncResponse,
 };
 use game_creation::new_battle;
 use rand::RngCore;
@@ -37,7 +45,8 @@ use ui_components::display_properties;
 use uuid::Uuid;
 
 use crate::{
-    debug_actions, deserialize_save_file, error_message, handle_battle_action, serialize_save_file,
+    debug_actions, deserialize_save_file, error_message, handle_battle_action, hint_search,
+    match_history_recording, serialize_save_file, user_collection,
 };
 
 static TEST_STATE_PROVIDERS: LazyLock<Mutex<HashMap<Uuid, TestStateProvider>>> =
@@ -87,7 +96,42 @@ pub fn connect_with_provider(
     };
     let response_version = Uuid::new_v4();
     provider.store_last_response_version(user_id, response_version);
-    ConnectResponse { metadata, commands, response_version }
+    ConnectResponse { metadata, commands, response_version, protocol_version: PROTOCOL_VERSION }
+}
+
+/// Returns the complete current state of a battle for a user whose client may
+/// have missed updates, e.g. after reconnecting.
+pub fn resync(request: &ResyncRequest) -> ResyncResponse {
+    if let Some(integration_test_id) = request.metadata.integration_test_id {
+        let provider = get_test_state_provider(integration_test_id);
+        resync_with_provider(provider, request)
+    } else {
+        resync_with_provider(DefaultStateProvider, request)
+    }
+}
+
+/// Resynchronizes a client with the specified [StateProvider].
+///
+/// Discards any poll results the client has not yet received, since the
+/// returned battle view already includes their effects, and starts a new
+/// response version for the client's next action.
+pub fn resync_with_provider(
+    provider: impl StateProvider + 'static,
+    request: &ResyncRequest,
+) -> ResyncResponse {
+    let metadata = request.metadata;
+    let user_id = metadata.user_id;
+    let result = catch_panic_conditionally(&provider, || resync_internal(&provider, request));
+    let (battle, pending_prompt, error) = match result {
+        Ok(Ok((battle, pending_prompt))) => (Some(battle), pending_prompt, None),
+        Ok(Err(error)) | Err(error) => (None, None, Some(error)),
+    };
+    if battle.is_some() && !provider.is_processing(user_id) {
+        while provider.take_next_poll_result(user_id).is_some() {}
+    }
+    let response_version = Uuid::new_v4();
+    provider.store_last_response_version(user_id, response_version);
+    ResyncResponse { metadata, battle, pending_prompt, response_version, error }
 }
 
 /// Returns true if there are any pending poll updates for any user.
@@ -227,42 +271,84 @@ fn connect_internal<P: StateProvider + 'static>(
     request_context: RequestContext,
 ) -> CommandSequence {
     let user_id = request.metadata.user_id;
-    let persistent_data_path = &request.persistent_data_path;
+    let persistent_data_path = if request.persistent_data_path.is_empty() {
+        save_file_io::default_save_dir()
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    } else {
+        request.persistent_data_path.clone()
+    };
     let streaming_assets_path = &request.streaming_assets_path;
     write_tracing_event::clear_log_file();
     if let Some(ref display_props) = request.display_properties {
         display_properties::store_display_properties(user_id, display_props.clone());
     }
+    if request.protocol_version > PROTOCOL_VERSION {
+        warn!(
+            client_version = request.protocol_version,
+            server_version = PROTOCOL_VERSION,
+            "Client is newer than the rules engine"
+        );
+    }
+    let mut capabilities = request.capabilities.clone();
+    if request.battle_view_deltas && !capabilities.contains(&ClientCapability::BattleViewDeltas) {
+        capabilities.push(ClientCapability::BattleViewDeltas);
+    }
+    let mut display_state = provider.get_display_state(user_id);
+    display_state.battle_view_deltas = capabilities.contains(&ClientCapability::BattleViewDeltas);
+    display_state.client_capabilities = capabilities;
+    display_state.language = request.language.clone();
+    display_state.colorblind_mode = request.colorblind_mode;
+    display_state.tutorial = request.tutorial;
+    display_state.animation_preferences = request.animation_preferences;
+    display_state.last_battle_view = None;
+    provider.set_display_state(user_id, display_state);
+    provider.select_save_slot(user_id, request.save_slot);
     debug!(">>> Initializing provider with persistent data path: {:?}", persistent_data_path);
-    if let Err(errors) = provider.initialize(persistent_data_path, streaming_assets_path) {
+    if let Err(errors) = provider.initialize(&persistent_data_path, streaming_assets_path) {
         return error_message::display_error_message(format_initialization_errors(&errors));
     }
     if let Some(vs_opponent) = request.vs_opponent {
-        return connect_for_multiplayer(provider, user_id, vs_opponent);
+        return connect_for_multiplayer(provider, user_id, vs_opponent, &request_context);
     } else {
         info!(?user_id, "Loading battle from database");
     }
     match load_battle_from_provider(
         provider,
         user_id,
-        request_context,
+        request_context.clone(),
         request.debug_configuration.as_ref(),
     ) {
         Ok(LoadBattleResult::ExistingBattle(battle, quest_id)) => {
-            if is_user_in_battle(&battle, user_id) {
+            let commands = if is_user_in_battle(&battle, user_id) {
+                notify_opponent_reconnected(provider, &battle, user_id, &request_context);
                 renderer::connect(&battle, user_id, (*provider).clone(), false)
             } else {
                 handle_user_not_in_battle(provider, user_id, battle, quest_id, None)
-            }
+            };
+            with_collection(provider, user_id, commands)
         }
         Ok(LoadBattleResult::NewBattle(mut battle)) => {
             run_initial_ai_turns(provider, &mut battle, user_id);
-            renderer::connect(&battle, user_id, (*provider).clone(), false)
+            let commands = renderer::connect(&battle, user_id, (*provider).clone(), false);
+            with_collection(provider, user_id, commands)
         }
         Err(error) => error_message::display_error_message(error),
     }
 }
 
+/// Appends commands showing the user's card collection, as read from their
+/// save file, to `commands`.
+fn with_collection<P: StateProvider + 'static>(
+    provider: &P,
+    user_id: UserId,
+    mut commands: CommandSequence,
+) -> CommandSequence {
+    let (collection, _) = saved_user_data(provider, user_id);
+    commands.groups.extend(renderer::collection(user_id, &collection, provider.clone()).groups);
+    commands
+}
+
 /// Handles a connection request for multiplayer games.
 ///
 /// Instead of loading the requesting user's save file, this loads the
@@ -271,12 +357,14 @@ fn connect_for_multiplayer<P: StateProvider + 'static>(
     provider: &P,
     user_id: UserId,
     vs_opponent: UserId,
+    request_context: &RequestContext,
 ) -> CommandSequence {
     info!(?user_id, ?vs_opponent, "Loading multiplayer battle from opponent's database");
     match provider.read_save_file(vs_opponent) {
         Ok(Some(save_file)) => match deserialize_save_file::battle(provider, &save_file) {
             Some((battle, quest_id)) => {
                 if is_user_in_battle(&battle, user_id) {
+                    notify_opponent_reconnected(provider, &battle, user_id, request_context);
                     return renderer::connect(&battle, user_id, (*provider).clone(), false);
                 }
                 handle_user_not_in_battle(provider, user_id, battle, quest_id, Some(vs_opponent))
@@ -304,7 +392,7 @@ fn load_battle_from_provider<P: StateProvider + 'static>(
     request_context: RequestContext,
     debug_configuration: Option<&DebugConfiguration>,
 ) -> Result<LoadBattleResult, String> {
-    match provider.read_save_file(user_id) {
+    match read_save_file_or_autosave(provider, user_id) {
         Ok(Some(save_file)) => match deserialize_save_file::battle(provider, &save_file) {
             Some((battle, quest_id)) => Ok(LoadBattleResult::ExistingBattle(battle, quest_id)),
             None => Err("No battle in save file".to_string()),
@@ -328,13 +416,43 @@ fn load_battle_from_provider<P: StateProvider + 'static>(
                         .dreamwell_override
                         .unwrap_or_else(|| provider.default_dreamwell_list()),
                 ),
-                CreateBattlePlayer { player_type: PlayerType::User(user_id), deck_name },
-                CreateBattlePlayer { player_type: enemy, deck_name },
+                CreateBattlePlayer {
+                    player_type: PlayerType::User(user_id),
+                    deck_name,
+                    personality: None,
+                },
+                CreateBattlePlayer {
+                    player_type: enemy,
+                    deck_name,
+                    personality: configuration.enemy_personality,
+                },
                 request_context,
             );
+            let mut collection = Collection::default();
+            user_collection::grant_deck(
+                &mut collection,
+                &provider.tabula(),
+                provider.default_deck_name(),
+                UnlockSource::StartingCollection,
+            );
+            if deck_name != provider.default_deck_name() {
+                user_collection::grant_deck(
+                    &mut collection,
+                    &provider.tabula(),
+                    deck_name,
+                    UnlockSource::Debug,
+                );
+            }
+            user_collection::validate_battle_deck(&collection, &new_battle, PlayerName::One)?;
             provider.clear_undo_stack(new_battle.id);
             let quest_id = QuestId(Uuid::new_v4());
-            let save_file = serialize_save_file::battle(user_id, quest_id, &new_battle);
+            let save_file = serialize_save_file::battle(
+                user_id,
+                quest_id,
+                &new_battle,
+                collection,
+                MatchHistory::default(),
+            );
             match provider.write_save_file(save_file) {
                 Ok(_) => Ok(LoadBattleResult::NewBattle(new_battle)),
                 Err(errors) => Err(format_initialization_errors(&errors)),
@@ -344,6 +462,29 @@ fn load_battle_from_provider<P: StateProvider + 'static>(
     }
 }
 
+/// Reads the user's save file, falling back to their most recent autosave if
+/// the save file cannot be read.
+fn read_save_file_or_autosave(
+    provider: &impl StateProvider,
+    user_id: UserId,
+) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+    let errors = match provider.read_save_file(user_id) {
+        Ok(save) => return Ok(save),
+        Err(errors) => errors,
+    };
+    match provider.read_latest_autosave(user_id) {
+        Ok(Some(autosave)) => {
+            warn!(
+                ?user_id,
+                "Failed to read save file, restoring from autosave: {}",
+                format_initialization_errors(&errors)
+            );
+            Ok(Some(autosave))
+        }
+        _ => Err(errors),
+    }
+}
+
 /// If the active player in a newly-created battle is an AI agent, runs the
 /// AI's turns inline so the connect response shows the state after the AI
 /// has acted.
@@ -370,13 +511,103 @@ fn run_initial_ai_turns<P: StateProvider + 'static>(
             apply_battle_action::execute(battle, next_player, action);
         } else {
             let quest_id = QuestId(Uuid::new_v4());
-            let save_file = serialize_save_file::battle(user_id, quest_id, battle);
+            let (collection, match_history) = saved_user_data(provider, user_id);
+            let save_file =
+                serialize_save_file::battle(user_id, quest_id, battle, collection, match_history);
             let _ = provider.write_save_file(save_file);
             return;
         }
     }
 }
 
+/// Tells the opponent of `user_id` that they have reconnected to the battle,
+/// if the opponent is another user.
+fn notify_opponent_reconnected<P: StateProvider + 'static>(
+    provider: &P,
+    battle: &BattleState,
+    user_id: UserId,
+    request_context: &RequestContext,
+) {
+    let player = renderer::player_name_for_user(battle, user_id);
+    if let PlayerType::User(opponent_id) = &battle.players.player(player.opponent()).player_type {
+        handle_battle_action::append_update(
+            provider,
+            *opponent_id,
+            renderer::opponent_reconnected(*opponent_id, provider.clone()),
+            request_context,
+            None,
+            PollResponseType::Final,
+        );
+    }
+}
+
+fn resync_internal<P: StateProvider + 'static>(
+    provider: &P,
+    request: &ResyncRequest,
+) -> Result<(BattleView, Option<PendingPromptView>), String> {
+    let user_id = request.metadata.user_id;
+    let battle = load_battle_for_user(provider, user_id, request.save_file_id)?;
+    if let Some(battle_id) = request.metadata.battle_id
+        && battle_id != battle.id
+    {
+        return Err(format!("Battle {battle_id:?} is no longer active"));
+    }
+    if !is_user_in_battle(&battle, user_id) {
+        return Err(format!("User {user_id:?} is not a player in this battle"));
+    }
+    info!(?user_id, battle_id = ?battle.id, "Resynchronizing client");
+    Ok(renderer::resync(&battle, user_id, provider.clone()))
+}
+
+/// Reads the battle from the save file with the given ID, or from the user's
+/// own save file if None.
+fn load_battle_for_user<P: StateProvider + 'static>(
+    provider: &P,
+    user_id: UserId,
+    save_file_id: Option<UserId>,
+) -> Result<BattleState, String> {
+    let save_file_id = save_file_id.unwrap_or(user_id);
+    match provider.read_save_file(save_file_id) {
+        Ok(Some(save)) => deserialize_save_file::battle(provider, &save)
+            .map(|(battle, _)| battle)
+            .ok_or_else(|| format!("No battle found for save_file_id: {save_file_id:?}")),
+        Ok(None) => Err(format!("No save file found for save_file_id: {save_file_id:?}")),
+        Err(errors) => Err(format_initialization_errors(&errors)),
+    }
+}
+
+/// Handles an action request whose request ID matches the last action
+/// accepted from this user, e.g. because the client resubmitted it after
+/// reconnecting without having received the response.
+///
+/// The action is not applied again. If it has finished processing, the
+/// client is sent a full snapshot of the battle in place of the response it
+/// missed.
+fn handle_duplicate_request<P: StateProvider + 'static>(
+    provider: &P,
+    request: &PerformActionRequest,
+) {
+    let user_id = request.metadata.user_id;
+    if provider.is_processing(user_id) {
+        return;
+    }
+    let request_context = provider
+        .get_request_context(user_id)
+        .unwrap_or(RequestContext { logging_options: Default::default() });
+    let commands = match load_battle_for_user(provider, user_id, request.save_file_id) {
+        Ok(battle) => renderer::connect(&battle, user_id, provider.clone(), false),
+        Err(error) => error_message::display_error_message(error),
+    };
+    handle_battle_action::append_update(
+        provider,
+        user_id,
+        commands,
+        &request_context,
+        request.metadata.request_id,
+        PollResponseType::Final,
+    );
+}
+
 fn is_user_in_battle(battle: &BattleState, user_id: UserId) -> bool {
     match &battle.players.one.player_type {
         PlayerType::User(id) if *id == user_id => true,
@@ -424,10 +655,21 @@ fn save_battle_to_provider(
     quest_id: QuestId,
     battle: &BattleState,
 ) -> Result<(), String> {
-    let save_file = serialize_save_file::battle(user_id, quest_id, battle);
+    let (collection, match_history) = saved_user_data(provider, user_id);
+    let save_file =
+        serialize_save_file::battle(user_id, quest_id, battle, collection, match_history);
     provider.write_save_file(save_file).map_err(|errors| format_initialization_errors(&errors))
 }
 
+/// Returns the card collection and match history from the user's existing save
+/// file, or empty values if none can be read.
+fn saved_user_data(provider: &impl StateProvider, user_id: UserId) -> (Collection, MatchHistory) {
+    match provider.read_save_file(user_id) {
+        Ok(Some(save)) => (save.collection().clone(), save.match_history().clone()),
+        _ => (Collection::default(), MatchHistory::default()),
+    }
+}
+
 #[instrument(skip_all, level = "debug")]
 fn perform_action_internal<P: StateProvider + 'static>(
     provider: &P,
@@ -436,6 +678,11 @@ fn perform_action_internal<P: StateProvider + 'static>(
     let metadata = request.metadata;
     let user_id = metadata.user_id;
     let request_id = metadata.request_id;
+    if request_id.is_some() && request_id == provider.get_last_request_id(user_id) {
+        warn!(?user_id, ?request_id, "Ignoring action: duplicate submission of a previous request");
+        handle_duplicate_request(provider, request);
+        return false;
+    }
     if let Some(last_response_version) = request.last_response_version {
         let stored_version = provider.get_last_response_version(user_id);
         if stored_version != Some(last_response_version) {
@@ -452,6 +699,9 @@ fn perform_action_internal<P: StateProvider + 'static>(
         warn!(?user_id, "Ignoring action: already processing another action for this user");
         return false;
     }
+    if let Some(request_id) = request_id {
+        provider.store_last_request_id(user_id, request_id);
+    }
     let span = tracing::span!(
         Level::DEBUG, "perform_action", ? request_id, ? request.last_response_version
     );
@@ -483,10 +733,35 @@ fn perform_action_internal<P: StateProvider + 'static>(
             return;
         };
         battle.animations = Some(AnimationData::default());
+        let was_game_over = battle.status.is_game_over();
+        let initial_phase = (battle.turn.turn_id, battle.phase);
         handle_request_action(provider, request, user_id, &mut battle, request_id);
-        if let Err(errors) =
-            provider.write_save_file(serialize_save_file::battle(save_file_id, quest_id, &battle))
+        let mut match_history = save.match_history().clone();
+        let mut collection = save.collection().clone();
+        if !was_game_over
+            && let Some(record) = match_history_recording::match_record(&battle, save_file_id)
+        {
+            match_history.record(record, provider.match_history_pruning());
+            collection.grant_quest_reward(
+                quest_id,
+                user_collection::victory_reward(&battle, save_file_id),
+            );
+        }
+        let save_file =
+            serialize_save_file::battle(save_file_id, quest_id, &battle, collection, match_history);
+        let phase_changed = initial_phase != (battle.turn.turn_id, battle.phase);
+        if provider
+            .autosave_policy()
+            .should_autosave(provider.count_autosave_action(save_file_id), phase_changed)
+            && let Err(errors) = provider.write_autosave(save_file.clone())
         {
+            warn!(
+                ?save_file_id,
+                "Failed to write autosave: {}",
+                format_initialization_errors(&errors)
+            );
+        }
+        if let Err(errors) = provider.write_save_file(save_file) {
             show_error_message(
                 provider,
                 user_id,
@@ -574,9 +849,13 @@ fn handle_request_action<P: StateProvider + 'static>(
             );
         }
         GameAction::BattleDisplayAction(action) => {
+            if *action == BattleDisplayAction::RequestHint {
+                hint_search::start(provider, battle, user_id, &request_context);
+            }
             let player = renderer::player_name_for_user(&*battle, user_id);
             let display_commands = apply_battle_display_action::execute(
                 provider.clone(),
+                battle,
                 action.clone(),
                 player,
                 user_id,
@@ -614,9 +893,45 @@ fn handle_request_action<P: StateProvider + 'static>(
                 );
             }
         }
+        GameAction::Emote(emote) => {
+            send_emote(provider, battle, user_id, *emote, &request_context, request_id);
+        }
     };
 }
 
+/// Shows an emote to the user who sent it and to their opponent, unless the
+/// opponent has muted emotes.
+fn send_emote<P: StateProvider + 'static>(
+    provider: &P,
+    battle: &BattleState,
+    user_id: UserId,
+    emote: Emote,
+    request_context: &RequestContext,
+    request_id: Option<Uuid>,
+) {
+    handle_battle_action::append_update(
+        provider,
+        user_id,
+        renderer::emote(user_id, DisplayPlayer::User, emote, provider.clone()),
+        request_context,
+        request_id,
+        PollResponseType::Final,
+    );
+    let player = renderer::player_name_for_user(battle, user_id);
+    if let PlayerType::User(opponent_id) = &battle.players.player(player.opponent()).player_type
+        && !provider.get_display_state(*opponent_id).opponent_emotes_muted
+    {
+        handle_battle_action::append_update(
+            provider,
+            *opponent_id,
+            renderer::emote(*opponent_id, DisplayPlayer::Enemy, emote, provider.clone()),
+            request_context,
+            None,
+            PollResponseType::Final,
+        );
+    }
+}
+
 /// Gets or creates a TestStateProvider for the given integration test ID.
 /// This ensures that all requests for the same integration test use the same
 /// provider instance.
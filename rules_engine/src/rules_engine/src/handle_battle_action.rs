@@ -1,3 +1,4 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 
 use ai_agents::agent_search;
@@ -13,16 +14,23 @@ use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::animation_data::AnimationData;
 use battle_state::battle::battle_state::{BattleState, RequestContext};
 use battle_state::battle_player::battle_player_state::PlayerType;
+use battle_state::battle_trace::decision_explanation::DecisionExplanation;
 use core_data::identifiers::UserId;
 use core_data::types::PlayerName;
 use display::rendering::renderer;
 use display_data::command::CommandSequence;
 use display_data::request_data::PollResponseType;
-use state_provider::state_provider::{PollResult, SpeculativeSearchState, StateProvider};
+use state_provider::state_provider::{
+    PollResult, SpeculativeResponse, SpeculativeSearchState, StateProvider,
+};
 use tokio::task;
 use tracing::{debug, instrument};
 use uuid::Uuid;
 
+/// Maximum number of likely opponent actions to search responses for while
+/// waiting for a human player to act.
+const PONDER_RESPONSES: usize = 3;
+
 pub fn poll(provider: &impl StateProvider, user_id: UserId) -> Option<PollResult> {
     provider.take_next_poll_result(user_id)
 }
@@ -101,11 +109,17 @@ pub fn execute(
             battle.animations = Some(AnimationData::default());
             battle_trace!("Selecting action for AI player", battle);
 
-            if let Some(action) = get_speculative_response_action(provider, battle, action) {
-                battle_trace!("[🌟] Speculative action hit", battle, action);
-                current_action = action;
+            let (selected, explanation) = if let Some((speculative, explanation)) =
+                get_speculative_response_action(provider, battle, action)
+            {
+                battle_trace!("[🌟] Speculative action hit", battle, speculative);
+                (speculative, explanation)
             } else {
-                current_action = agent_search::select_action(battle, next_player, &agent);
+                agent_search::select_action_with_explanation(battle, next_player, &agent)
+            };
+            current_action = selected;
+            if let Some(explanation) = explanation {
+                battle.last_ai_decision = Some(Arc::new(explanation));
             }
 
             current_player = next_player;
@@ -125,17 +139,7 @@ pub fn execute(
             if let PlayerType::Agent(agent) =
                 &battle.players.player(agent_player).player_type.clone()
             {
-                let legal = legal_actions::compute(battle, next_player);
-                if let LegalActions::Standard { actions } = legal {
-                    start_speculative_response_search(
-                        provider,
-                        battle,
-                        agent_player,
-                        agent,
-                        next_player,
-                        actions.primary,
-                    );
-                }
+                start_pondering(provider, battle, agent_player, agent, next_player);
             }
             return;
         }
@@ -171,87 +175,186 @@ pub fn should_auto_execute_action(legal_actions: &LegalActions) -> Option<Battle
     }
 }
 
-/// Begins a speculative search for an agent action.
+/// Begins searching for agent responses while waiting for the human player to
+/// act.
 ///
 /// In order to optimistically improve performance, we assume that the human
-/// player will respond to a battle state with their [PrimaryLegalAction]
-/// (resolve, end turn, etc). While we are waiting for a user response, we start
-/// computing a speculative response to this action in order to respond more
-/// quickly if it is selected.
-fn start_speculative_response_search(
+/// player will most likely respond to a battle state with their
+/// [PrimaryLegalAction] (resolve, end turn, etc), and search for an agent
+/// response to this action first. Afterwards, a short search from the human
+/// player's perspective predicts their other likely actions, and responses to
+/// these are searched in turn. When the human acts, a matching response is
+/// reused and all other pondering is cancelled.
+fn start_pondering(
     provider: &(impl StateProvider + 'static),
     battle: &mut BattleState,
     ai_player: PlayerName,
     agent: &GameAI,
     human_player: PlayerName,
-    opponent_action: PrimaryLegalAction,
 ) {
-    let assumed_action = match opponent_action {
-        PrimaryLegalAction::PassPriority => BattleAction::PassPriority,
-        PrimaryLegalAction::EndTurn => BattleAction::EndTurn,
-        PrimaryLegalAction::StartNextTurn => BattleAction::StartNextTurn,
-    };
-    let mut simulation = battle.logical_clone();
-    apply_battle_action::execute(&mut simulation, human_player, assumed_action);
-    while let Some(next_player) = legal_actions::next_to_act(&simulation) {
-        if let Some(auto) =
-            should_auto_execute_action(&legal_actions::compute(&simulation, next_player))
-        {
-            apply_battle_action::execute(&mut simulation, next_player, auto);
-            continue;
-        }
-        break;
+    if let Some(previous) = provider.take_speculative_search(battle.id) {
+        cancel_pondering(&previous, None);
     }
-    if legal_actions::next_to_act(&simulation) != Some(ai_player) {
-        battle_trace!(
-            "[🔮] Skipping speculation, ai_player is not next to act",
-            battle,
-            opponent_action
-        );
-        return;
+
+    let search = SpeculativeSearchState::default();
+    let primary = match legal_actions::compute(battle, human_player) {
+        LegalActions::Standard { actions } => Some(assumed_action(actions.primary)),
+        _ => None,
+    };
+    let primary_response = primary.and_then(|action| {
+        let simulation = simulate_response(battle, ai_player, human_player, action)?;
+        Some((register_response(&search, action)?, simulation))
+    });
+    if primary_response.is_none() {
+        battle_trace!("[🔮] Primary action does not lead to an agent response", battle, primary);
     }
-    let result = Arc::new((Mutex::new(None), Condvar::new()));
-    let result_clone = result.clone();
-    let agent_clone = *agent;
-    battle_trace!("[🔮] Starting speculative action search", battle, opponent_action);
 
+    let initial_battle = battle.logical_clone();
+    let task_search = search.clone();
+    let agent = *agent;
+    battle_trace!("[🔮] Starting to ponder opponent responses", battle, primary);
     task::spawn_blocking(move || {
-        let action = agent_search::select_action(&simulation, ai_player, &agent_clone);
-        if let Ok(mut guard) = result_clone.0.lock() {
-            *guard = Some(action);
-            result_clone.1.notify_all();
+        if let Some((response, simulation)) = primary_response {
+            search_response(&response, &simulation, ai_player, &agent);
+        }
+
+        let predicted = agent_search::predict_actions(
+            &initial_battle,
+            human_player,
+            PONDER_RESPONSES,
+            task_search.cancelled.clone(),
+        );
+        for action in predicted.into_iter().filter(|&action| Some(action) != primary) {
+            let Some(simulation) =
+                simulate_response(&initial_battle, ai_player, human_player, action)
+            else {
+                continue;
+            };
+            let Some(response) = register_response(&task_search, action) else {
+                return;
+            };
+            search_response(&response, &simulation, ai_player, &agent);
         }
     });
-    provider.set_speculative_search(battle.id, SpeculativeSearchState { assumed_action, result });
+    provider.set_speculative_search(battle.id, search);
 }
 
-/// Returns the computed speculative response action.
+/// Returns the computed speculative response action and its explanation.
 ///
-/// If the provided `action` matches the action we assumed the user would take
-/// when we started our speculative response search, this returns a computed
-/// agent response to that action. If the user took a different action, this
+/// If the provided `action` matches an action we assumed the user might take
+/// while pondering, this returns a computed agent response to that action and
+/// cancels all other pondering. If the user took a different action, this
 /// returns None.
 ///
 /// If the AI evaluation has not yet completed, but the `action` here matches
-/// the assumed action, this blocks until the evaluation is complete and returns
+/// an assumed action, this blocks until the evaluation is complete and returns
 /// its value.
 fn get_speculative_response_action(
     provider: &(impl StateProvider + 'static),
     battle: &BattleState,
     action: BattleAction,
-) -> Option<BattleAction> {
+) -> Option<(BattleAction, Option<DecisionExplanation>)> {
     let search = provider.take_speculative_search(battle.id)?;
-    if search.assumed_action != action {
-        let expected = search.assumed_action;
-        debug!(?action, ?expected, "[👿] Speculative Action miss");
+    let Some(response) = cancel_pondering(&search, Some(action)) else {
+        debug!(?action, "[👿] Speculative Action miss");
         return None;
-    }
-    let (lock, cvar) = &*search.result;
+    };
+    let (lock, cvar) = &*response.result;
     let mut guard = lock.lock().unwrap();
     while guard.is_none() {
         guard = cvar.wait(guard).unwrap();
     }
-    *guard
+    guard.take()
+}
+
+/// Stops pondering and cancels every response search except the one for
+/// `keep`, returning that response if it has been started.
+fn cancel_pondering(
+    search: &SpeculativeSearchState,
+    keep: Option<BattleAction>,
+) -> Option<SpeculativeResponse> {
+    let responses = search.responses.lock().unwrap();
+    search.cancelled.store(true, Ordering::Relaxed);
+    let mut kept = None;
+    for response in responses.iter() {
+        if Some(response.assumed_action) == keep {
+            kept = Some(response.clone());
+        } else {
+            response.cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+    kept
+}
+
+/// Adds a response to `assumed_action` to the set of searched responses,
+/// returning None if pondering has been cancelled.
+///
+/// Registration happens under the same lock used by [cancel_pondering], so
+/// every registered response is either searched to completion or cancelled.
+fn register_response(
+    search: &SpeculativeSearchState,
+    assumed_action: BattleAction,
+) -> Option<SpeculativeResponse> {
+    let mut responses = search.responses.lock().unwrap();
+    if search.cancelled.load(Ordering::Relaxed) {
+        return None;
+    }
+    let response = SpeculativeResponse {
+        assumed_action,
+        result: Arc::new((Mutex::new(None), Condvar::new())),
+        cancelled: Arc::new(AtomicBool::new(false)),
+    };
+    responses.push(response.clone());
+    Some(response)
+}
+
+fn search_response(
+    response: &SpeculativeResponse,
+    simulation: &BattleState,
+    ai_player: PlayerName,
+    agent: &GameAI,
+) {
+    let result = agent_search::select_action_cancellable(
+        simulation,
+        ai_player,
+        agent,
+        response.cancelled.clone(),
+    );
+    if let Ok(mut guard) = response.result.0.lock() {
+        *guard = Some(result);
+        response.result.1.notify_all();
+    }
+}
+
+/// Applies `assumed_action` for the human player along with any automatic
+/// actions which follow it, returning the resulting battle if the AI player
+/// is next to act.
+fn simulate_response(
+    battle: &BattleState,
+    ai_player: PlayerName,
+    human_player: PlayerName,
+    assumed_action: BattleAction,
+) -> Option<BattleState> {
+    let mut simulation = battle.logical_clone();
+    apply_battle_action::execute(&mut simulation, human_player, assumed_action);
+    while let Some(next_player) = legal_actions::next_to_act(&simulation) {
+        if let Some(auto) =
+            should_auto_execute_action(&legal_actions::compute(&simulation, next_player))
+        {
+            apply_battle_action::execute(&mut simulation, next_player, auto);
+            continue;
+        }
+        break;
+    }
+    (legal_actions::next_to_act(&simulation) == Some(ai_player)).then_some(simulation)
+}
+
+fn assumed_action(primary: PrimaryLegalAction) -> BattleAction {
+    match primary {
+        PrimaryLegalAction::PassPriority => BattleAction::PassPriority,
+        PrimaryLegalAction::EndTurn => BattleAction::EndTurn,
+        PrimaryLegalAction::StartNextTurn => BattleAction::StartNextTurn,
+    }
 }
 
 fn should_push_undo_entry(action: BattleAction) -> bool {
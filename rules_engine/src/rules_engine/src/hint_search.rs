@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ai_agents::agent_search;
+use battle_state::battle::battle_state::{BattleState, RequestContext};
+use core_data::identifiers::UserId;
+use display::rendering::renderer;
+use display_data::request_data::PollResponseType;
+use state_provider::display_state_provider::HintState;
+use state_provider::state_provider::StateProvider;
+use tokio::task;
+use tracing::debug;
+
+use crate::handle_battle_action;
+
+/// Starts searching for an action to suggest to the given user.
+///
+/// Search runs in the background, cancelling any previous search for this
+/// user. Once complete, the suggestion is stored in the user's display state
+/// and an updated battle view is sent to them. Taking any battle action
+/// before search completes cancels it without sending an update.
+pub fn start(
+    provider: &(impl StateProvider + 'static),
+    battle: &BattleState,
+    user_id: UserId,
+    context: &RequestContext,
+) {
+    let player = renderer::player_name_for_user(battle, user_id);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let mut state = provider.get_display_state(user_id);
+    let hint = HintState { cancelled: cancelled.clone(), suggestion: None };
+    if let Some(previous) = state.hint.replace(hint) {
+        previous.cancelled.store(true, Ordering::Relaxed);
+    }
+    provider.set_display_state(user_id, state);
+
+    let mut snapshot = battle.clone();
+    snapshot.animations = None;
+    snapshot.tracing = None;
+    let provider = provider.clone();
+    let context = context.clone();
+    task::spawn_blocking(move || {
+        let suggestion = agent_search::suggest_action(&snapshot, player, cancelled.clone());
+        let mut state = provider.get_display_state(user_id);
+        let is_current =
+            state.hint.as_ref().is_some_and(|hint| Arc::ptr_eq(&hint.cancelled, &cancelled));
+        if !is_current || cancelled.load(Ordering::Relaxed) {
+            debug!(?user_id, "Hint search cancelled");
+            return;
+        }
+
+        debug!(?user_id, ?suggestion, "Hint search complete");
+        state.hint = suggestion.map(|suggestion| HintState {
+            cancelled: cancelled.clone(),
+            suggestion: Some(suggestion),
+        });
+        provider.set_display_state(user_id, state);
+        handle_battle_action::append_update(
+            &provider,
+            user_id,
+            renderer::connect(&snapshot, user_id, provider.clone(), true),
+            &context,
+            None,
+            PollResponseType::Final,
+        );
+    });
+}
@@ -4,4 +4,7 @@ pub mod deserialize_save_file;
 pub mod engine;
 pub mod error_message;
 pub mod handle_battle_action;
+pub mod hint_search;
+pub mod match_history_recording;
 pub mod serialize_save_file;
+pub mod user_collection;
@@ -0,0 +1,36 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_status::BattleStatus;
+use core_data::identifiers::UserId;
+use database::match_history::{MatchRecord, MatchResult};
+use display::rendering::renderer;
+
+/// Returns a [MatchRecord] summarizing a completed battle from the perspective
+/// of the given user.
+///
+/// Returns None if the battle is not over or the user is not a participant.
+pub fn match_record(battle: &BattleState, user_id: UserId) -> Option<MatchRecord> {
+    let BattleStatus::GameOver { winner } = battle.status else {
+        return None;
+    };
+    let player = renderer::player_name_for_user_optional(battle, user_id)?;
+    let opponent = battle.players.player(player.opponent());
+    let result = match winner {
+        Some(winner) if winner == player => MatchResult::Victory,
+        Some(_) => MatchResult::Defeat,
+        None => MatchResult::Draw,
+    };
+    Some(MatchRecord {
+        battle_id: battle.id,
+        result,
+        user_deck: battle.players.player(player).deck_name,
+        opponent_deck: opponent.deck_name,
+        opponent: opponent.player_type.clone(),
+        turn_count: battle.turn.turn_id.0 + 1,
+        completed_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeSet;
+
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle_player::battle_player_state::TestDeckName;
+use core_data::identifiers::{BaseCardId, UserId};
+use core_data::types::PlayerName;
+use database::collection::{self, Collection, UnlockSource};
+use display::rendering::renderer;
+use game_creation::new_test_battle;
+use tabula_data::tabula::Tabula;
+
+/// Adds a copy of each card in the deck named `deck_name` to `collection`.
+///
+/// New users are granted their starting deck with
+/// [UnlockSource::StartingCollection].
+pub fn grant_deck(
+    collection: &mut Collection,
+    tabula: &Tabula,
+    deck_name: TestDeckName,
+    source: UnlockSource,
+) {
+    for card in &new_test_battle::create_quest_state(tabula, deck_name).deck.cards {
+        collection.grant(card.base_card_id, 1, source);
+    }
+}
+
+/// Checks that `collection` owns every card in the deck `player` is using in
+/// `battle`.
+///
+/// Returns an error message naming each card the deck has more copies of than
+/// are owned.
+pub fn validate_battle_deck(
+    collection: &Collection,
+    battle: &BattleState,
+    player: PlayerName,
+) -> Result<(), String> {
+    let deck = &battle.players.player(player).quest.deck;
+    let Err(error) =
+        collection::validate_deck(collection, deck.cards.iter().map(|card| card.base_card_id))
+    else {
+        return Ok(());
+    };
+    let missing = error
+        .missing
+        .iter()
+        .map(|card| {
+            let name = battle
+                .tabula
+                .cards
+                .get(&card.card_id)
+                .map_or_else(|| format!("{:?}", card.card_id), |c| c.displayed_name.clone());
+            format!("{name} ({} in deck, {} owned)", card.required, card.owned)
+        })
+        .collect::<Vec<_>>();
+    Err(format!("Deck contains cards which are not in the collection: {}", missing.join(", ")))
+}
+
+/// Returns the cards the given user is rewarded with for winning `battle`.
+///
+/// Winning a quest battle grants one copy of each distinct card in the
+/// defeated opponent's deck. Returns no cards if the battle is not over, the
+/// user did not win, or the user is not a participant.
+pub fn victory_reward(battle: &BattleState, user_id: UserId) -> Vec<BaseCardId> {
+    let BattleStatus::GameOver { winner: Some(winner) } = battle.status else {
+        return Vec::new();
+    };
+    let Some(player) = renderer::player_name_for_user_optional(battle, user_id) else {
+        return Vec::new();
+    };
+    if winner != player {
+        return Vec::new();
+    }
+    let opponent = &battle.players.player(player.opponent()).quest.deck;
+    opponent
+        .cards
+        .iter()
+        .map(|card| card.base_card_id)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,42 @@
+use lsp_types::{Position, Range};
+
+/// Converts a byte offset in `document` into an LSP position.
+///
+/// LSP positions count columns in UTF-16 code units.
+pub fn position(document: &str, offset: usize) -> Position {
+    let prefix = &document[..floor_char_boundary(document, offset)];
+    let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+    Position {
+        line: prefix.matches('\n').count() as u32,
+        character: prefix[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// Converts a byte range in `document` into an LSP range.
+pub fn range(document: &str, range: std::ops::Range<usize>) -> Range {
+    Range { start: position(document, range.start), end: position(document, range.end) }
+}
+
+/// Converts an LSP position into a byte offset in `document`.
+///
+/// Positions past the end of a line are clamped to the end of that line.
+pub fn offset(document: &str, position: Position) -> usize {
+    let line_start =
+        document.split_inclusive('\n').take(position.line as usize).map(str::len).sum::<usize>();
+    let line = document[line_start..].split('\n').next().unwrap_or_default();
+    let mut units = 0;
+    for (index, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + index;
+        }
+        units += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+fn floor_char_boundary(document: &str, offset: usize) -> usize {
+    (0..=offset.min(document.len()))
+        .rev()
+        .find(|&index| document.is_char_boundary(index))
+        .unwrap_or(0)
+}
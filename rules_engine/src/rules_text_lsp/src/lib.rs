@@ -0,0 +1,6 @@
+pub mod document_positions;
+pub mod rules_text_completion;
+pub mod rules_text_diagnostics;
+pub mod rules_text_hover;
+pub mod rules_text_server;
+pub mod toml_rules_text;
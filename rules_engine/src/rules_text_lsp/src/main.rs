@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use rules_text_lsp::rules_text_server;
+
+fn main() -> ExitCode {
+    match rules_text_server::run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
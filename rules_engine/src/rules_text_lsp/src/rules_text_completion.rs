@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+use parser::variables::parser_substitutions;
+
+use crate::toml_rules_text;
+
+/// Directives which do not take a variable.
+const KEYWORDS: &[&str] = &[
+    "banish",
+    "banished",
+    "bullet",
+    "discover",
+    "dissolve",
+    "dissolved",
+    "fast",
+    "judgment",
+    "materialize",
+    "materialized",
+    "materialized_dissolved",
+    "materialized_judgment",
+    "prevent",
+    "reclaim",
+    "resolve",
+];
+
+/// Templates which can complete a partially typed directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveCompletions {
+    /// Byte range in the document of the text typed since the opening `{`,
+    /// which a completion replaces.
+    pub range: Range<usize>,
+
+    /// Matching directive templates, e.g. "cards($c)".
+    pub templates: Vec<String>,
+}
+
+/// Returns the directive templates which can complete the directive being
+/// typed at byte `offset` in a Tabula TOML `document`.
+///
+/// Templates are matched against the text typed since the opening `{`, and
+/// are capitalized if that text is. Returns None if the offset is not within
+/// an unclosed directive in rules text.
+pub fn completions(document: &str, offset: usize) -> Option<DirectiveCompletions> {
+    let cards = toml_rules_text::rules_text_fields(document).ok()?;
+    let (rules_text, prefix) = cards.iter().find_map(|card| {
+        let value_offset = card.rules_text.value_offset(offset)?;
+        Some((&card.rules_text, directive_prefix(&card.rules_text.value[..value_offset])?))
+    })?;
+
+    let lowercase_prefix = prefix.to_lowercase();
+    let capitalize = prefix.starts_with(char::is_uppercase);
+    let templates = KEYWORDS
+        .iter()
+        .map(|keyword| (*keyword).to_string())
+        .chain(parser_substitutions::phrase_templates())
+        .filter(|template| template.starts_with(&lowercase_prefix))
+        .map(|template| if capitalize { capitalize_first(&template) } else { template })
+        .collect();
+    let prefix_start = prefix.as_ptr() as usize - rules_text.value.as_ptr() as usize;
+    Some(DirectiveCompletions {
+        range: rules_text.document_offset(prefix_start)..offset,
+        templates,
+    })
+}
+
+/// Returns the text typed after the last unclosed `{` in `text`, if any.
+fn directive_prefix(text: &str) -> Option<&str> {
+    let start = text.rfind('{')? + 1;
+    let prefix = &text[start..];
+    (!prefix.contains(['}', '\n'])).then_some(prefix)
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+use parser::error::parser_diagnostics;
+use parser::rules_text_parser;
+use parser::variables::parser_bindings::VariableBindings;
+
+use crate::toml_rules_text::{self, CardRulesText};
+
+/// A problem found in a document, located by byte range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RulesTextDiagnostic {
+    pub range: Range<usize>,
+    pub message: String,
+}
+
+/// Checks every card's rules text in a Tabula TOML `document`, returning a
+/// diagnostic for each TOML error, invalid variable definition, and ability
+/// which fails to parse.
+pub fn document_diagnostics(document: &str) -> Vec<RulesTextDiagnostic> {
+    match toml_rules_text::rules_text_fields(document) {
+        Ok(cards) => cards.iter().flat_map(card_diagnostics).collect(),
+        Err(error) => vec![RulesTextDiagnostic {
+            range: error.span().unwrap_or(0..0),
+            message: error.message().to_string(),
+        }],
+    }
+}
+
+fn card_diagnostics(card: &CardRulesText) -> Vec<RulesTextDiagnostic> {
+    let variables = card.variables.as_ref().map_or("", |variables| variables.value.as_str());
+    let bindings = match VariableBindings::parse(variables) {
+        Ok(bindings) => bindings,
+        Err(error) => {
+            let span = card
+                .variables
+                .as_ref()
+                .map_or(card.rules_text.span.clone(), |variables| variables.span.clone());
+            return vec![RulesTextDiagnostic {
+                range: span,
+                message: format!("Variable parsing error: {error}"),
+            }];
+        }
+    };
+
+    let rules_text = &card.rules_text;
+    rules_text_parser::parse_rules_text(&rules_text.value, &bindings)
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let span = diagnostic
+                .error
+                .span()
+                .map_or(0..diagnostic.text.len(), |span| span.start..span.end);
+            RulesTextDiagnostic {
+                range: rules_text.document_offset(diagnostic.offset + span.start)
+                    ..rules_text.document_offset(diagnostic.offset + span.end),
+                message: parser_diagnostics::error_summary(&diagnostic.error),
+            }
+        })
+        .collect()
+}
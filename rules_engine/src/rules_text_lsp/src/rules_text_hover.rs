@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+use ability_data::variable_value::VariableValue;
+use parser::lexer::lexer_token::Token;
+use parser::lexer::lexer_tokenize;
+use parser::variables::parser_bindings::VariableBindings;
+use rlf::Phrase;
+use strings::strings;
+
+use crate::toml_rules_text::{self, CardRulesText};
+
+/// Documentation for the directive under the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RulesTextHover {
+    /// Byte range of the directive in the document.
+    pub range: Range<usize>,
+
+    /// Markdown documentation for the directive.
+    pub contents: String,
+}
+
+/// Returns documentation for the rules text directive at byte `offset` in a
+/// Tabula TOML `document`.
+///
+/// Keywords show their standard reminder text and any variables referenced
+/// by the directive show their bound values. Returns None if the offset is
+/// not within a directive which has documentation.
+pub fn hover(document: &str, offset: usize) -> Option<RulesTextHover> {
+    let cards = toml_rules_text::rules_text_fields(document).ok()?;
+    let card = cards.iter().find(|card| card.rules_text.span.contains(&offset))?;
+    let value_offset = card.rules_text.value_offset(offset)?;
+    let lex_result = lexer_tokenize::lex(&card.rules_text.value).ok()?;
+    let (name, span) = lex_result.tokens.iter().find_map(|(token, span)| match token {
+        Token::Directive(name) if (span.start..span.end).contains(&value_offset) => {
+            Some((name, *span))
+        }
+        _ => None,
+    })?;
+
+    let bindings = bindings(card);
+    let variables = directive_variables(name);
+    let mut sections = Vec::new();
+    if let Some(reminder_text) = keyword_reminder_text(name, &variables, &bindings) {
+        sections.push(format!(
+            "`{}`: {}.",
+            &card.rules_text.value[span.start..span.end],
+            strings::capitalized_sentence(reminder_text)
+        ));
+    }
+    sections.extend(variables.iter().filter_map(|variable| {
+        let value = bindings.get(variable)?;
+        Some(format!("`${variable}` = {}", variable_value_text(value)))
+    }));
+    if sections.is_empty() {
+        return None;
+    }
+
+    Some(RulesTextHover {
+        range: card.rules_text.document_offset(span.start)
+            ..card.rules_text.document_offset(span.end),
+        contents: sections.join("\n\n"),
+    })
+}
+
+fn bindings(card: &CardRulesText) -> VariableBindings {
+    card.variables
+        .as_ref()
+        .and_then(|variables| VariableBindings::parse(&variables.value).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the phrase name of a directive, e.g. "foresee" for "foresee($f)".
+fn directive_name(directive: &str) -> &str {
+    let name = directive.split(['(', ':']).next().unwrap_or(directive);
+    name.rsplit(' ').next().unwrap_or(name).trim_start_matches('@')
+}
+
+/// Returns the names of the variables referenced in a directive, e.g. ["c"]
+/// for "card:$c".
+fn directive_variables(directive: &str) -> Vec<&str> {
+    directive
+        .split('$')
+        .skip(1)
+        .filter_map(|rest| {
+            let end = rest.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(rest.len());
+            (end > 0).then(|| &rest[..end])
+        })
+        .collect()
+}
+
+fn keyword_reminder_text(
+    directive: &str,
+    variables: &[&str],
+    bindings: &VariableBindings,
+) -> Option<Phrase> {
+    let integer =
+        |default: &str| bindings.get_integer(variables.first().copied().unwrap_or(default));
+    let reminder_text = match directive_name(directive) {
+        "discover" => strings::reminder_text_discover(),
+        "dissolve" | "dissolved" => strings::reminder_text_dissolve(),
+        "foresee" => strings::reminder_text_foresee(integer("f")?),
+        "kindle" => strings::reminder_text_kindle(integer("k")?),
+        "prevent" => strings::reminder_text_prevent(),
+        "reclaim" | "reclaim_for_cost" => strings::reminder_text_reclaim(),
+        _ => return None,
+    };
+    Some(reminder_text)
+}
+
+fn variable_value_text(value: &VariableValue) -> String {
+    match value {
+        VariableValue::Integer(value) => value.to_string(),
+        VariableValue::Subtype(subtype) => format!("{subtype:?}"),
+        VariableValue::Figment(figment) => format!("{figment:?}"),
+    }
+}
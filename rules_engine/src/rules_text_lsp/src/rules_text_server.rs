@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationType, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, HoverRequest, Request as RequestType};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse,
+    CompletionTextEdit, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, MarkupContent, MarkupKind, PublishDiagnosticsParams,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+};
+
+use crate::{document_positions, rules_text_completion, rules_text_diagnostics, rules_text_hover};
+
+const DIAGNOSTIC_SOURCE: &str = "rules_text";
+
+/// Runs the rules text language server over stdin and stdout until the client
+/// requests shutdown.
+pub fn run() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["{".to_string()]),
+            ..CompletionOptions::default()
+        }),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+    serve(&connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+fn serve(connection: &Connection) -> Result<()> {
+    let mut documents = HashMap::<Url, String>::new();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let response = handle_request(&documents, request)?;
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                handle_notification(connection, &mut documents, notification)?;
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(documents: &HashMap<Url, String>, request: Request) -> Result<Response> {
+    match request.method.as_str() {
+        HoverRequest::METHOD => {
+            let params: HoverParams = serde_json::from_value(request.params)?;
+            let position = params.text_document_position_params;
+            let result = documents.get(&position.text_document.uri).and_then(|document| {
+                let offset = document_positions::offset(document, position.position);
+                let hover = rules_text_hover::hover(document, offset)?;
+                Some(Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: hover.contents,
+                    }),
+                    range: Some(document_positions::range(document, hover.range)),
+                })
+            });
+            Ok(Response::new_ok(request.id, result))
+        }
+        Completion::METHOD => {
+            let params: CompletionParams = serde_json::from_value(request.params)?;
+            let position = params.text_document_position;
+            let result = documents.get(&position.text_document.uri).and_then(|document| {
+                let offset = document_positions::offset(document, position.position);
+                let completions = rules_text_completion::completions(document, offset)?;
+                let range = document_positions::range(document, completions.range);
+                let items = completions
+                    .templates
+                    .into_iter()
+                    .map(|template| CompletionItem {
+                        label: template.clone(),
+                        kind: Some(CompletionItemKind::KEYWORD),
+                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                            range,
+                            new_text: template,
+                        })),
+                        ..CompletionItem::default()
+                    })
+                    .collect();
+                Some(CompletionResponse::Array(items))
+            });
+            Ok(Response::new_ok(request.id, result))
+        }
+        _ => Ok(Response::new_err(
+            request.id,
+            ErrorCode::MethodNotFound as i32,
+            format!("Unsupported request: {}", request.method),
+        )),
+    }
+}
+
+fn handle_notification(
+    connection: &Connection,
+    documents: &mut HashMap<Url, String>,
+    notification: Notification,
+) -> Result<()> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+            let uri = params.text_document.uri;
+            publish_diagnostics(connection, &uri, &params.text_document.text)?;
+            documents.insert(uri, params.text_document.text);
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+            if let Some(change) = params.content_changes.into_iter().last() {
+                let uri = params.text_document.uri;
+                publish_diagnostics(connection, &uri, &change.text)?;
+                documents.insert(uri, change.text);
+            }
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+            documents.remove(&params.text_document.uri);
+            publish_diagnostics(connection, &params.text_document.uri, "")?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn publish_diagnostics(connection: &Connection, uri: &Url, document: &str) -> Result<()> {
+    let diagnostics = rules_text_diagnostics::document_diagnostics(document)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            range: document_positions::range(document, diagnostic.range),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            message: diagnostic.message,
+            ..Diagnostic::default()
+        })
+        .collect();
+    let params = PublishDiagnosticsParams { uri: uri.clone(), diagnostics, version: None };
+    connection.sender.send(Message::Notification(Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        params,
+    )))?;
+    Ok(())
+}
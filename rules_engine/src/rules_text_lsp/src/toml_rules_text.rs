@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use serde::Deserialize;
+use toml::de::Error;
+use toml::{Spanned, Table};
+
+/// A string value in a TOML document, decoded, along with the location of
+/// each of its bytes in the document.
+#[derive(Debug, Clone)]
+pub struct TomlString {
+    /// Decoded contents of the string.
+    pub value: String,
+
+    /// Byte range of the string in the document, including its delimiters.
+    pub span: Range<usize>,
+
+    /// Document offset of each byte of `value`, followed by the document
+    /// offset of the end of the value. Empty if the string could not be
+    /// mapped back to the document.
+    offsets: Vec<usize>,
+}
+
+/// The rules text of a single card row, along with its variables.
+#[derive(Debug, Clone)]
+pub struct CardRulesText {
+    pub rules_text: TomlString,
+    pub variables: Option<TomlString>,
+}
+
+/// Returns the rules text of every card row in a Tabula TOML `document`.
+///
+/// Returns an error if the document is not valid TOML. Documents which are
+/// valid TOML but do not contain card rows return no rules text.
+pub fn rules_text_fields(document: &str) -> Result<Vec<CardRulesText>, Error> {
+    toml::from_str::<Table>(document)?;
+    let Ok(tables) = toml::from_str::<BTreeMap<String, Vec<CardRow>>>(document) else {
+        return Ok(Vec::new());
+    };
+    Ok(tables
+        .into_values()
+        .flatten()
+        .filter_map(|row| {
+            Some(CardRulesText {
+                rules_text: TomlString::new(document, row.rules_text?),
+                variables: row.variables.map(|variables| TomlString::new(document, variables)),
+            })
+        })
+        .collect())
+}
+
+impl TomlString {
+    /// Returns the document offset of byte `offset` of the decoded value.
+    ///
+    /// Falls back to the start of the string if the value could not be
+    /// mapped back to the document.
+    pub fn document_offset(&self, offset: usize) -> usize {
+        self.offsets.get(offset).copied().unwrap_or(self.span.start)
+    }
+
+    /// Returns the byte offset into the decoded value for the given document
+    /// offset, or None if the offset is not within the value.
+    pub fn value_offset(&self, document_offset: usize) -> Option<usize> {
+        self.offsets.iter().position(|&offset| offset == document_offset)
+    }
+
+    fn new(document: &str, spanned: Spanned<String>) -> Self {
+        let span = spanned.span();
+        let value = spanned.into_inner();
+        let offsets = document
+            .get(span.clone())
+            .and_then(|raw| decode_string(raw, span.start))
+            .filter(|(decoded, _)| *decoded == value)
+            .map(|(_, offsets)| offsets)
+            .unwrap_or_default();
+        Self { value, span, offsets }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CardRow {
+    #[serde(rename = "rules-text")]
+    rules_text: Option<Spanned<String>>,
+    variables: Option<Spanned<String>>,
+}
+
+/// Decodes the raw TOML string literal `raw`, which starts at document offset
+/// `start`, returning its value and the document offset of each byte.
+fn decode_string(raw: &str, start: usize) -> Option<(String, Vec<usize>)> {
+    let (delimiter, is_literal) = ["\"\"\"", "'''", "\"", "'"]
+        .into_iter()
+        .find(|delimiter| raw.starts_with(delimiter))
+        .map(|delimiter| (delimiter, delimiter.starts_with('\'')))?;
+    let mut body = raw.strip_prefix(delimiter)?.strip_suffix(delimiter)?;
+    let mut body_start = start + delimiter.len();
+    let leading_newline = match delimiter.len() {
+        3 => ["\r\n", "\n"].into_iter().find(|newline| body.starts_with(newline)),
+        _ => None,
+    };
+    if let Some(newline) = leading_newline {
+        body = &body[newline.len()..];
+        body_start += newline.len();
+    }
+
+    let mut value = String::new();
+    let mut offsets = Vec::new();
+    let mut push = |c: char, offset: usize| {
+        value.push(c);
+        offsets.extend(std::iter::repeat_n(offset, c.len_utf8()));
+    };
+    let mut chars = body.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let offset = body_start + index;
+        if is_literal || c != '\\' {
+            push(c, offset);
+            continue;
+        }
+        let (_, escape) = chars.next()?;
+        let decoded = match escape {
+            'b' => '\u{8}',
+            't' => '\t',
+            'n' => '\n',
+            'f' => '\u{c}',
+            'r' => '\r',
+            '"' => '"',
+            '\\' => '\\',
+            'u' | 'U' => {
+                let length = if escape == 'u' { 4 } else { 8 };
+                let hex = (0..length)
+                    .map(|_| chars.next().map(|(_, c)| c))
+                    .collect::<Option<String>>()?;
+                char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+            }
+            c if c.is_whitespace() => {
+                while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                continue;
+            }
+            _ => return None,
+        };
+        push(decoded, offset);
+    }
+    offsets.push(body_start + body.len());
+    Some((value, offsets))
+}
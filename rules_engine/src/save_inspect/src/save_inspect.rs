@@ -0,0 +1,332 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+
+use battle_queries::battle_card_queries::{card, card_abilities};
+use battle_state::battle::battle_card_definitions::BattleCardDefinitions;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CardIdType;
+use battle_state::battle_cards::stack_card_state::StackItemId;
+use battle_state::battle_player::battle_player_state::BattlePlayerState;
+use clap::Parser;
+use core_data::initialization_error::InitializationError;
+use core_data::types::PlayerName;
+use database::save_file::SaveFile;
+use database::save_file_encoding::{self, SaveFileCompression};
+use database::save_file_io;
+use database::save_file_migration;
+use serde::Serialize;
+use serde_json::Value;
+use strum::IntoDiscriminant;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Print a human-readable summary of a save file, or compare two save files",
+    after_help = "EXAMPLE:\n    save_inspect save.json --diff save-autosave_0.json"
+)]
+struct Args {
+    #[arg(help = "Path to the save file to inspect")]
+    path: PathBuf,
+
+    #[arg(long, help = "Print the summary (or differences) as JSON")]
+    json: bool,
+
+    #[arg(long, value_name = "OTHER", help = "Print only the differences from another save file")]
+    diff: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct SaveSummary {
+    user_id: String,
+    format: String,
+    schema_version: Option<u32>,
+    collection_cards: usize,
+    match_history_entries: usize,
+    quest_id: Option<String>,
+    battle: Option<BattleSummary>,
+}
+
+#[derive(Serialize)]
+struct BattleSummary {
+    id: String,
+    status: String,
+    turn: u32,
+    active_player: String,
+    phase: String,
+    stack_priority: Option<String>,
+    players: Vec<PlayerSummary>,
+    stack: Vec<String>,
+    prompts: Vec<PromptSummary>,
+}
+
+#[derive(Serialize)]
+struct PlayerSummary {
+    player: String,
+    player_type: String,
+    points: u32,
+    current_energy: u32,
+    produced_energy: u32,
+    spark_bonus: u32,
+    deck: usize,
+    hand: Vec<String>,
+    battlefield: Vec<String>,
+    void: Vec<String>,
+    banished: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct PromptSummary {
+    player: String,
+    kind: String,
+}
+
+/// A value which differs between two save file summaries.
+#[derive(Serialize)]
+struct Difference {
+    path: String,
+    left: Value,
+    right: Value,
+}
+
+fn main() {
+    let args = Args::parse();
+    let summary = load_summary(&args.path);
+    let Some(other_path) = &args.diff else {
+        if args.json {
+            println!("{}", serde_json::to_string_pretty(&summary).expect("Serialize summary"));
+        } else {
+            print_summary(&summary);
+        }
+        return;
+    };
+
+    let mut differences = Vec::new();
+    diff_values(
+        "",
+        &serde_json::to_value(&summary).expect("Serialize summary"),
+        &serde_json::to_value(load_summary(other_path)).expect("Serialize summary"),
+        &mut differences,
+    );
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&differences).expect("Serialize differences"));
+    } else if differences.is_empty() {
+        println!("No differences");
+    } else {
+        for difference in &differences {
+            println!("{}: {} -> {}", difference.path, difference.left, difference.right);
+        }
+    }
+}
+
+/// Reads and summarizes a save file, exiting with an error message if it
+/// cannot be read.
+fn load_summary(path: &Path) -> SaveSummary {
+    match read_summary(path) {
+        Ok(summary) => summary,
+        Err(error) => {
+            eprintln!("Error reading {}: {error}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+fn read_summary(path: &Path) -> Result<SaveSummary, String> {
+    let data = fs::read(path).map_err(|e| e.to_string())?;
+    let format = match save_file_encoding::read_header(&data).map_err(|e| e.to_string())? {
+        Some(header) if header.compression == SaveFileCompression::Zstd => "zstd",
+        Some(_) => "uncompressed",
+        None => "legacy JSON",
+    };
+    let json = save_file_encoding::decode(&data).map_err(|e| e.to_string())?;
+    let schema_version = save_file_migration::read_version(&json).ok().flatten();
+    let save = save_file_io::parse_save(&data).map_err(|errors| {
+        errors.iter().map(InitializationError::format).collect::<Vec<_>>().join("\n")
+    })?;
+
+    let SaveFile::V1(v1) = save;
+    Ok(SaveSummary {
+        user_id: v1.id.0.to_string(),
+        format: format.to_string(),
+        schema_version,
+        collection_cards: v1.collection.cards.values().map(|card| card.count as usize).sum(),
+        match_history_entries: v1.match_history.entries.len(),
+        quest_id: v1.quest.as_ref().map(|quest| quest.id.0.to_string()),
+        battle: v1.quest.and_then(|quest| quest.battle).map(battle_summary),
+    })
+}
+
+fn battle_summary(mut battle: BattleState) -> BattleSummary {
+    battle.card_definitions = Arc::new(BattleCardDefinitions::rebuild(
+        &battle.card_definitions,
+        |quest_deck_card_id, owner| {
+            let definition = battle.players.player(owner).quest.deck.get_card(quest_deck_card_id);
+            Arc::new(definition.clone())
+        },
+        card_abilities::build_from_definition,
+    ));
+
+    BattleSummary {
+        id: battle.id.0.to_string(),
+        status: format!("{:?}", battle.status),
+        turn: battle.turn.turn_id.0,
+        active_player: format!("{:?}", battle.turn.active_player),
+        phase: format!("{:?}", battle.phase),
+        stack_priority: battle.stack_priority.map(|player| format!("{player:?}")),
+        players: vec![
+            player_summary(&battle, PlayerName::One, &battle.players.one),
+            player_summary(&battle, PlayerName::Two, &battle.players.two),
+        ],
+        stack: battle
+            .cards
+            .all_items_on_stack()
+            .iter()
+            .map(|item| {
+                let name = match item.id {
+                    StackItemId::Card(card_id) => card_name(&battle, card_id),
+                    StackItemId::ActivatedAbility(ability_id) => format!("{ability_id:?}"),
+                };
+                format!("{name} ({:?})", item.controller)
+            })
+            .collect(),
+        prompts: battle
+            .prompts
+            .iter()
+            .map(|prompt| PromptSummary {
+                player: format!("{:?}", prompt.player),
+                kind: format!("{:?}", prompt.prompt_type.discriminant()),
+            })
+            .collect(),
+    }
+}
+
+fn player_summary(
+    battle: &BattleState,
+    player: PlayerName,
+    state: &BattlePlayerState,
+) -> PlayerSummary {
+    PlayerSummary {
+        player: format!("{player:?}"),
+        player_type: format!("{:?}", state.player_type),
+        points: state.points.0,
+        current_energy: state.current_energy.0,
+        produced_energy: state.produced_energy.0,
+        spark_bonus: state.spark_bonus.0,
+        deck: battle.cards.all_deck_cards(player).count(),
+        hand: battle.cards.hand(player).iter().map(|id| card_name(battle, id)).collect(),
+        battlefield: battle
+            .cards
+            .battlefield(player)
+            .iter()
+            .map(|id| {
+                let spark = battle.cards.spark(player, id).map_or(0, |spark| spark.0);
+                format!("{} (spark {spark})", card_name(battle, id))
+            })
+            .collect(),
+        void: battle.cards.void(player).iter().map(|id| card_name(battle, id)).collect(),
+        banished: battle.cards.banished(player).iter().map(|id| card_name(battle, id)).collect(),
+    }
+}
+
+fn card_name(battle: &BattleState, card_id: impl CardIdType) -> String {
+    card::get_definition(battle, card_id).displayed_name.clone()
+}
+
+fn print_summary(summary: &SaveSummary) {
+    println!("Save file for user {}", summary.user_id);
+    let version = summary.schema_version.map_or("unknown".to_string(), |v| format!("v{v}"));
+    println!("  Format: {}, schema {version}", summary.format);
+    println!(
+        "  Collection: {} cards, match history: {} entries",
+        summary.collection_cards, summary.match_history_entries
+    );
+    let Some(quest_id) = &summary.quest_id else {
+        println!("  No active quest");
+        return;
+    };
+    println!("  Quest: {quest_id}");
+    let Some(battle) = &summary.battle else {
+        println!("  No active battle");
+        return;
+    };
+
+    println!();
+    println!("Battle {} ({})", battle.id, battle.status);
+    println!("  Turn {}, {} active, phase {}", battle.turn, battle.active_player, battle.phase);
+    if let Some(player) = &battle.stack_priority {
+        println!("  Stack priority: {player}");
+    }
+    for player in &battle.players {
+        println!();
+        println!("  Player {} ({})", player.player, player.player_type);
+        println!(
+            "    Points: {}, energy: {}/{}, spark bonus: {}",
+            player.points, player.current_energy, player.produced_energy, player.spark_bonus
+        );
+        println!("    Deck: {} cards", player.deck);
+        print_zone("Hand", &player.hand);
+        print_zone("Battlefield", &player.battlefield);
+        print_zone("Void", &player.void);
+        print_zone("Banished", &player.banished);
+    }
+
+    println!();
+    print_zone("Stack", &battle.stack);
+    let prompts = battle
+        .prompts
+        .iter()
+        .map(|prompt| format!("{} for {}", prompt.kind, prompt.player))
+        .collect::<Vec<_>>();
+    print_zone("Prompts", &prompts);
+}
+
+fn print_zone(name: &str, cards: &[String]) {
+    if cards.is_empty() {
+        println!("    {name}: (empty)");
+    } else {
+        println!("    {name}: {}", cards.join(", "));
+    }
+}
+
+/// Records every value which differs between two summaries.
+///
+/// Objects are compared field by field and lists of objects item by item;
+/// other values, including lists of card names, are compared as a whole.
+fn diff_values(path: &str, left: &Value, right: &Value, differences: &mut Vec<Difference>) {
+    match (left, right) {
+        (Value::Object(left_fields), Value::Object(right_fields)) => {
+            for (key, left_value) in left_fields {
+                let right_value = right_fields.get(key).unwrap_or(&Value::Null);
+                diff_values(&join_path(path, key), left_value, right_value, differences);
+            }
+            for (key, right_value) in right_fields {
+                if !left_fields.contains_key(key) {
+                    diff_values(&join_path(path, key), &Value::Null, right_value, differences);
+                }
+            }
+        }
+        (Value::Array(left_items), Value::Array(right_items))
+            if left_items.iter().chain(right_items).all(Value::is_object) =>
+        {
+            for index in 0..left_items.len().max(right_items.len()) {
+                diff_values(
+                    &format!("{path}[{index}]"),
+                    left_items.get(index).unwrap_or(&Value::Null),
+                    right_items.get(index).unwrap_or(&Value::Null),
+                    differences,
+                );
+            }
+        }
+        _ if left != right => differences.push(Difference {
+            path: path.to_string(),
+            left: left.clone(),
+            right: right.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_string() } else { format!("{path}.{key}") }
+}
@@ -0,0 +1,515 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+/// Prefix of `$ref` values which refer to a schema definition.
+const DEFINITIONS_PREFIX: &str = "#/definitions/";
+
+/// Generates C# source declaring a type for each definition in a JSON schema
+/// produced by schemars, along with a class for the root schema.
+///
+/// Types are serialized with System.Text.Json in the same representation
+/// serde uses:
+///
+/// - Structs are classes whose properties are keyed by field name.
+/// - Enums with no data in any variant are C# enums, serialized as strings.
+/// - Other enums are externally tagged: a string for variants without data and
+///   an object with a single key naming the variant otherwise. These become a
+///   class with one property per variant and a generated converter.
+///
+/// Definitions which are not objects or enums, such as newtypes wrapping a
+/// number, are replaced by the type they wrap.
+pub fn generate(schema: &Value, namespace: &str) -> String {
+    let definitions =
+        schema.get("definitions").and_then(Value::as_object).cloned().unwrap_or_default();
+    let root = schema.get("title").and_then(Value::as_str).unwrap_or("SchemaTypes").to_string();
+    let mut names = definitions.keys().cloned().collect::<BTreeSet<_>>();
+    names.insert(root.clone());
+    let mut generator = CsharpGenerator {
+        pending: vec![(root, schema.clone())],
+        names,
+        definitions,
+        body: String::new(),
+    };
+    generator.pending.extend(generator.definitions.clone());
+
+    let mut index = 0;
+    while index < generator.pending.len() {
+        let (name, definition) = generator.pending[index].clone();
+        generator.declare(&name, &definition);
+        index += 1;
+    }
+
+    format!(
+        "// <auto-generated />\n\
+         // Generated by schema_generator from the Rust display_data types. Do not edit.\n\
+         #nullable enable\n\
+         \n\
+         using System;\n\
+         using System.Collections.Generic;\n\
+         using System.Text.Json;\n\
+         using System.Text.Json.Serialization;\n\
+         \n\
+         namespace {namespace}\n\
+         {{\n\
+         {}\n\
+         }}\n",
+        generator.body.trim_end_matches('\n')
+    )
+}
+
+/// Converts a snake_case field name to a PascalCase C# identifier.
+pub fn pascal_case(name: &str) -> String {
+    let identifier: String = name
+        .split(|c: char| c == '_' || !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else {
+        identifier
+    }
+}
+
+struct CsharpGenerator {
+    definitions: Map<String, Value>,
+
+    /// Types to declare, in order. Inline object and enum schemas are added
+    /// to this list as they are encountered.
+    pending: Vec<(String, Value)>,
+
+    /// Names of all declared types, used to avoid collisions when naming
+    /// inline schemas.
+    names: BTreeSet<String>,
+
+    body: String,
+}
+
+/// A C# type used for a property or variant payload.
+struct CsType {
+    name: String,
+
+    /// True if this is a C# value type, which must be wrapped in `Nullable` to
+    /// hold null.
+    value_type: bool,
+
+    nullable: bool,
+}
+
+/// How a schema is represented in C#.
+enum Shape {
+    Class,
+    StringEnum(Vec<Variant>),
+    TaggedUnion(Vec<Variant>),
+    Alias,
+}
+
+/// A variant of a serde enum.
+struct Variant {
+    name: String,
+    description: Option<String>,
+
+    /// Schema for the data in this variant, or None for a unit variant.
+    payload: Option<Value>,
+}
+
+impl CsharpGenerator {
+    fn declare(&mut self, name: &str, schema: &Value) {
+        match shape(schema) {
+            Shape::Class => self.declare_class(name, schema),
+            Shape::StringEnum(variants) => self.declare_string_enum(name, schema, &variants),
+            Shape::TaggedUnion(variants) => self.declare_tagged_union(name, schema, &variants),
+            Shape::Alias => {}
+        }
+    }
+
+    fn declare_class(&mut self, name: &str, schema: &Value) {
+        let properties = schema.get("properties").and_then(Value::as_object).cloned();
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut members = String::new();
+        for (field, property) in properties.iter().flatten() {
+            let is_required = required.contains(field);
+            let mut ty = self.type_of(property, &format!("{name}{}", pascal_case(field)));
+            if !is_required && ty.name == "JsonElement" {
+                ty.nullable = true;
+            }
+            let mut identifier = pascal_case(field);
+            if identifier == name {
+                identifier.push_str("Value");
+            }
+
+            write_summary(&mut members, 8, description(property));
+            let _ = writeln!(members, "        [JsonPropertyName(\"{field}\")]");
+            if is_required {
+                let _ = writeln!(members, "        [JsonRequired]");
+            }
+            let initializer =
+                if is_required && !ty.value_type && !ty.nullable { " = default!;" } else { "" };
+            let _ = writeln!(
+                members,
+                "        public {} {identifier} {{ get; set; }}{initializer}\n",
+                ty.render()
+            );
+        }
+
+        write_summary(&mut self.body, 4, description(schema));
+        let _ = writeln!(self.body, "    public sealed partial class {name}\n    {{");
+        self.body.push_str(members.trim_end_matches('\n'));
+        if !members.is_empty() {
+            self.body.push('\n');
+        }
+        self.body.push_str("    }\n\n");
+    }
+
+    fn declare_string_enum(&mut self, name: &str, schema: &Value, variants: &[Variant]) {
+        write_summary(&mut self.body, 4, description(schema));
+        let _ = writeln!(
+            self.body,
+            "    [JsonConverter(typeof(JsonStringEnumConverter))]\n    public enum {name}\n    {{"
+        );
+        for variant in variants {
+            write_summary(&mut self.body, 8, variant.description.as_deref());
+            let _ = writeln!(self.body, "        {},", pascal_case(&variant.name));
+        }
+        self.body.push_str("    }\n\n");
+    }
+
+    fn declare_tagged_union(&mut self, name: &str, schema: &Value, variants: &[Variant]) {
+        let payloads = variants
+            .iter()
+            .map(|variant| {
+                variant.payload.as_ref().map(|payload| {
+                    let mut ty = self.type_of(payload, &format!("{name}{}", variant.name));
+                    ty.nullable = true;
+                    ty.render()
+                })
+            })
+            .collect::<Vec<_>>();
+        let identifiers = variants
+            .iter()
+            .map(|variant| {
+                let identifier = pascal_case(&variant.name);
+                if identifier == name { format!("{identifier}Value") } else { identifier }
+            })
+            .collect::<Vec<_>>();
+
+        write_summary(&mut self.body, 4, description(schema));
+        let _ = writeln!(
+            self.body,
+            "    [JsonConverter(typeof({name}Converter))]\n    public sealed partial class {name}\n    {{"
+        );
+        for ((variant, payload), identifier) in variants.iter().zip(&payloads).zip(&identifiers) {
+            write_summary(&mut self.body, 8, variant.description.as_deref());
+            let ty = payload.as_deref().unwrap_or("bool");
+            let _ = writeln!(self.body, "        public {ty} {identifier} {{ get; set; }}\n");
+        }
+        self.body.truncate(self.body.trim_end_matches('\n').len());
+        self.body.push_str("\n    }\n\n");
+
+        let _ = writeln!(
+            self.body,
+            "    public sealed class {name}Converter : JsonConverter<{name}>\n    {{\n        \
+             public override {name} Read(ref Utf8JsonReader reader, Type typeToConvert, \
+             JsonSerializerOptions options)\n        {{\n            var result = new {name}();"
+        );
+        if payloads.iter().any(Option::is_none) {
+            self.body.push_str(
+                "            if (reader.TokenType == JsonTokenType.String)\n            {\n                \
+                 switch (reader.GetString())\n                {\n",
+            );
+            for ((variant, payload), identifier) in variants.iter().zip(&payloads).zip(&identifiers)
+            {
+                if payload.is_none() {
+                    let _ = writeln!(
+                        self.body,
+                        "                    case \"{}\":\n                        \
+                         result.{identifier} = true;\n                        return result;",
+                        variant.name
+                    );
+                }
+            }
+            let _ = writeln!(
+                self.body,
+                "                }}\n                throw new JsonException($\"Unknown {name} \
+                 variant: {{reader.GetString()}}\");\n            }}"
+            );
+        }
+        let _ = writeln!(
+            self.body,
+            "            if (reader.TokenType != JsonTokenType.StartObject)\n            {{\n                \
+             throw new JsonException(\"Expected {name}\");\n            }}\n            \
+             reader.Read();\n            var variant = reader.GetString();\n            \
+             reader.Read();\n            switch (variant)\n            {{"
+        );
+        for ((variant, payload), identifier) in variants.iter().zip(&payloads).zip(&identifiers) {
+            if let Some(payload) = payload {
+                let _ = writeln!(
+                    self.body,
+                    "                case \"{}\":\n                    result.{identifier} = \
+                     JsonSerializer.Deserialize<{payload}>(ref reader, options);\n                    \
+                     break;",
+                    variant.name
+                );
+            }
+        }
+        let _ = writeln!(
+            self.body,
+            "                default:\n                    throw new JsonException($\"Unknown \
+             {name} variant: {{variant}}\");\n            }}\n            reader.Read();\n            \
+             return result;\n        }}\n"
+        );
+
+        let _ = writeln!(
+            self.body,
+            "        public override void Write(Utf8JsonWriter writer, {name} value, \
+             JsonSerializerOptions options)\n        {{"
+        );
+        for ((variant, payload), identifier) in variants.iter().zip(&payloads).zip(&identifiers) {
+            if payload.is_some() {
+                let _ = writeln!(
+                    self.body,
+                    "            if (value.{identifier} != null)\n            {{\n                \
+                     writer.WriteStartObject();\n                \
+                     writer.WritePropertyName(\"{}\");\n                \
+                     JsonSerializer.Serialize(writer, value.{identifier}, options);\n                \
+                     writer.WriteEndObject();\n                return;\n            }}",
+                    variant.name
+                );
+            } else {
+                let _ = writeln!(
+                    self.body,
+                    "            if (value.{identifier})\n            {{\n                \
+                     writer.WriteStringValue(\"{}\");\n                return;\n            }}",
+                    variant.name
+                );
+            }
+        }
+        let _ = writeln!(
+            self.body,
+            "            throw new JsonException(\"{name} has no variant set\");\n        }}\n    }}\n"
+        );
+    }
+
+    /// Returns the C# type for a schema, declaring a new type named `context`
+    /// if the schema is an inline object or enum.
+    fn type_of(&mut self, schema: &Value, context: &str) -> CsType {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches(DEFINITIONS_PREFIX).to_string();
+            let Some(definition) = self.definitions.get(&name).cloned() else {
+                return CsType::reference("JsonElement");
+            };
+            return match shape(&definition) {
+                Shape::Alias => self.type_of(&definition, &name),
+                Shape::StringEnum(_) => CsType::value(&name),
+                Shape::Class | Shape::TaggedUnion(_) => CsType::reference(&name),
+            };
+        }
+
+        for key in ["allOf", "anyOf", "oneOf"] {
+            if let Some(options) = schema.get(key).and_then(Value::as_array) {
+                let non_null = options.iter().filter(|o| !is_null(o)).collect::<Vec<_>>();
+                if non_null.len() == 1 {
+                    let mut ty = self.type_of(non_null[0], context);
+                    ty.nullable |= non_null.len() < options.len();
+                    return ty;
+                }
+            }
+        }
+
+        if let Some(types) = schema.get("type").and_then(Value::as_array) {
+            let non_null = types.iter().filter(|t| t.as_str() != Some("null")).collect::<Vec<_>>();
+            if non_null.len() == 1 {
+                let mut single = schema.clone();
+                single["type"] = non_null[0].clone();
+                let mut ty = self.type_of(&single, context);
+                ty.nullable |= non_null.len() < types.len();
+                return ty;
+            }
+            return CsType::value("JsonElement");
+        }
+
+        match shape(schema) {
+            Shape::Class | Shape::StringEnum(_) | Shape::TaggedUnion(_) => {
+                let name = self.declare_inline(context, schema);
+                return if matches!(shape(schema), Shape::StringEnum(_)) {
+                    CsType::value(&name)
+                } else {
+                    CsType::reference(&name)
+                };
+            }
+            Shape::Alias => {}
+        }
+
+        let format = schema.get("format").and_then(Value::as_str).unwrap_or_default();
+        match schema.get("type").and_then(Value::as_str) {
+            Some("string") if format == "uuid" => CsType::value("Guid"),
+            Some("string") => CsType::reference("string"),
+            Some("boolean") => CsType::value("bool"),
+            Some("integer") => CsType::value(match format {
+                "uint8" => "byte",
+                "int8" => "sbyte",
+                "uint16" => "ushort",
+                "int16" => "short",
+                "uint32" => "uint",
+                "int32" => "int",
+                "uint64" | "uint" => "ulong",
+                _ => "long",
+            }),
+            Some("number") if format == "float" => CsType::value("float"),
+            Some("number") => CsType::value("double"),
+            Some("array") => match schema.get("items") {
+                Some(items) if items.is_object() => {
+                    let item = self.type_of(items, &format!("{context}Item"));
+                    CsType::reference(&format!("List<{}>", item.render()))
+                }
+                _ => CsType::value("JsonElement"),
+            },
+            Some("object") => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    let value = self.type_of(values, &format!("{context}Value"));
+                    CsType::reference(&format!("Dictionary<string, {}>", value.render()))
+                }
+                _ => CsType::value("JsonElement"),
+            },
+            _ => CsType::value("JsonElement"),
+        }
+    }
+
+    /// Queues an inline schema to be declared as a new type, returning its
+    /// name.
+    fn declare_inline(&mut self, context: &str, schema: &Value) -> String {
+        let mut name = context.to_string();
+        let mut suffix = 2;
+        while self.names.contains(&name) {
+            name = format!("{context}{suffix}");
+            suffix += 1;
+        }
+        self.names.insert(name.clone());
+        self.pending.push((name.clone(), schema.clone()));
+        name
+    }
+}
+
+impl CsType {
+    fn value(name: &str) -> Self {
+        Self { name: name.to_string(), value_type: true, nullable: false }
+    }
+
+    fn reference(name: &str) -> Self {
+        Self { name: name.to_string(), value_type: false, nullable: false }
+    }
+
+    fn render(&self) -> String {
+        if self.nullable { format!("{}?", self.name) } else { self.name.clone() }
+    }
+}
+
+fn shape(schema: &Value) -> Shape {
+    if let Some(variants) = enum_variants(schema) {
+        return if variants.iter().all(|v| v.payload.is_none()) {
+            Shape::StringEnum(variants)
+        } else {
+            Shape::TaggedUnion(variants)
+        };
+    }
+    if schema.get("properties").is_some_and(Value::is_object)
+        || (schema.get("type").and_then(Value::as_str) == Some("object")
+            && schema.get("additionalProperties").is_none_or(|a| a == &Value::Bool(false)))
+    {
+        return Shape::Class;
+    }
+    Shape::Alias
+}
+
+/// Returns the variants of a schema generated for a serde enum, or None if
+/// this schema does not describe an enum.
+fn enum_variants(schema: &Value) -> Option<Vec<Variant>> {
+    if let Some(variants) = string_variants(schema) {
+        return Some(variants);
+    }
+
+    let options = schema.get("oneOf").or_else(|| schema.get("anyOf"))?.as_array()?;
+    if options.iter().any(is_null) {
+        return None;
+    }
+    let mut variants = Vec::new();
+    for option in options {
+        if let Some(units) = string_variants(option) {
+            variants.extend(units);
+            continue;
+        }
+        let properties = option.get("properties")?.as_object()?;
+        let required = option.get("required")?.as_array()?;
+        let [(name, payload)] = properties.iter().collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        if required.len() != 1 || required[0].as_str() != Some(name) {
+            return None;
+        }
+        variants.push(Variant {
+            name: name.clone(),
+            description: description(option).map(str::to_string),
+            payload: Some(payload.clone()),
+        });
+    }
+    Some(variants)
+}
+
+/// Returns the variants of a schema describing a fixed set of strings, such as
+/// the unit variants of an enum.
+fn string_variants(schema: &Value) -> Option<Vec<Variant>> {
+    if schema.get("type").and_then(Value::as_str) != Some("string") {
+        return None;
+    }
+    let description = description(schema).map(str::to_string);
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return Some(vec![Variant { name: value.to_string(), description, payload: None }]);
+    }
+    let values = schema.get("enum")?.as_array()?;
+    Some(
+        values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|value| Variant {
+                name: value.to_string(),
+                description: if values.len() == 1 { description.clone() } else { None },
+                payload: None,
+            })
+            .collect(),
+    )
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn description(schema: &Value) -> Option<&str> {
+    schema.get("description").and_then(Value::as_str)
+}
+
+/// Writes a `<summary>` doc comment at the given indentation.
+fn write_summary(out: &mut String, indent: usize, description: Option<&str>) {
+    let Some(description) = description else {
+        return;
+    };
+    let padding = " ".repeat(indent);
+    let _ = writeln!(out, "{padding}/// <summary>");
+    for line in description.lines() {
+        let escaped = line.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        let _ = writeln!(out, "{padding}/// {escaped}");
+    }
+    let _ = writeln!(out, "{padding}/// </summary>");
+}
@@ -0,0 +1,2 @@
+pub mod csharp_generator;
+pub mod schema_diff;
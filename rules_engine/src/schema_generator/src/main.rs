@@ -1,9 +1,64 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
 use display_data::request_data::SchemaTypes;
+use schema_generator::{csharp_generator, schema_diff};
 use schemars::generate::SchemaSettings;
+use serde_json::Value;
+
+#[derive(Parser)]
+#[command(about = "Prints a JSON schema describing the types sent to the client")]
+struct Args {
+    #[arg(long, help = "Also write C# classes for the schema types to this path")]
+    csharp: Option<String>,
 
-fn main() {
+    #[arg(long, default_value = "Dreamtides.Schema", help = "Namespace for generated C# classes")]
+    namespace: String,
+
+    #[arg(
+        long,
+        help = "Compare against a previously generated schema instead of printing it, exiting \
+                with an error if there are breaking changes"
+    )]
+    compare: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
     let settings = SchemaSettings::draft07();
     let mut generator = settings.into_generator();
     let schema = generator.root_schema_for::<SchemaTypes>();
-    println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+    let json = serde_json::to_value(&schema).unwrap();
+    if let Some(path) = args.csharp {
+        fs::write(&path, csharp_generator::generate(&json, &args.namespace)).unwrap();
+    }
+
+    let Some(old_path) = args.compare else {
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return ExitCode::SUCCESS;
+    };
+    let old = match fs::read_to_string(&old_path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<Value>(&text).map_err(|e| e.to_string()))
+    {
+        Ok(old) => old,
+        Err(err) => {
+            eprintln!("Failed to read schema from {}: {err}", old_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let changes = schema_diff::compare(&old, &json);
+    for change in &changes {
+        println!("{change}");
+    }
+    if schema_diff::has_breaking_changes(&changes) {
+        eprintln!("Schema has breaking changes relative to {}", old_path.display());
+        ExitCode::FAILURE
+    } else {
+        println!("Schema is compatible with {} ({} changes)", old_path.display(), changes.len());
+        ExitCode::SUCCESS
+    }
 }
@@ -0,0 +1,315 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// Whether a schema change can be handled by a client built against the old
+/// schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    Additive,
+    Breaking,
+}
+
+/// A single difference between two versions of a schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub kind: ChangeKind,
+
+    /// JSON pointer to the changed location in the schema, e.g.
+    /// `/definitions/CardView/properties/revealed`.
+    pub path: String,
+
+    pub description: String,
+}
+
+/// Structurally compares two JSON schemas produced by schemars, returning the
+/// changes needed to go from `old` to `new`.
+///
+/// Messages are deserialized in both directions, so a change is only additive
+/// if data written against either version still reads correctly with the
+/// other:
+///
+/// - New definitions, optional properties, and enum variants are additive.
+/// - Removed definitions, properties, and enum variants are breaking.
+/// - New required properties, changes between optional and required, and
+///   changes to the type, format, or nullability of a value are breaking.
+///
+/// Descriptions and other annotations are ignored.
+pub fn compare(old: &Value, new: &Value) -> Vec<SchemaChange> {
+    let mut diff = SchemaDiff { changes: Vec::new() };
+    diff.compare_node("", old, new);
+
+    let empty = Map::new();
+    let old_definitions = old.get("definitions").and_then(Value::as_object).unwrap_or(&empty);
+    let new_definitions = new.get("definitions").and_then(Value::as_object).unwrap_or(&empty);
+    for (name, old_definition) in old_definitions {
+        let path = format!("/definitions/{name}");
+        match new_definitions.get(name) {
+            Some(new_definition) => diff.compare_node(&path, old_definition, new_definition),
+            None => diff.push(ChangeKind::Breaking, &path, "definition removed"),
+        }
+    }
+    for name in new_definitions.keys().filter(|name| !old_definitions.contains_key(*name)) {
+        diff.push(ChangeKind::Additive, &format!("/definitions/{name}"), "definition added");
+    }
+
+    diff.changes.sort_by(|a, b| b.kind.cmp(&a.kind).then_with(|| a.path.cmp(&b.path)));
+    diff.changes
+}
+
+/// Returns true if any of the given changes is breaking.
+pub fn has_breaking_changes(changes: &[SchemaChange]) -> bool {
+    changes.iter().any(|change| change.kind == ChangeKind::Breaking)
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            ChangeKind::Additive => "additive",
+            ChangeKind::Breaking => "breaking",
+        };
+        write!(f, "{kind}: {}: {}", self.path, self.description)
+    }
+}
+
+struct SchemaDiff {
+    changes: Vec<SchemaChange>,
+}
+
+/// A schema with `Option` and description wrappers removed.
+struct Normalized<'a> {
+    schema: &'a Value,
+    nullable: bool,
+}
+
+impl SchemaDiff {
+    fn push(&mut self, kind: ChangeKind, path: &str, description: impl Into<String>) {
+        let path = if path.is_empty() { "/".to_string() } else { path.to_string() };
+        self.changes.push(SchemaChange { kind, path, description: description.into() });
+    }
+
+    fn compare_node(&mut self, path: &str, old: &Value, new: &Value) {
+        let old = normalize(old);
+        let new = normalize(new);
+        if old.nullable != new.nullable {
+            let description = if new.nullable { "became nullable" } else { "no longer nullable" };
+            self.push(ChangeKind::Breaking, path, description);
+        }
+        let (old, new) = (old.schema, new.schema);
+
+        let old_ref = old.get("$ref").and_then(Value::as_str);
+        let new_ref = new.get("$ref").and_then(Value::as_str);
+        if old_ref.is_some() || new_ref.is_some() {
+            // Referenced definitions are compared separately, by name.
+            if old_ref != new_ref {
+                self.push(
+                    ChangeKind::Breaking,
+                    path,
+                    format!("type changed from {} to {}", describe(old), describe(new)),
+                );
+            }
+            return;
+        }
+
+        let old_type = types(old);
+        let new_type = types(new);
+        if old_type != new_type {
+            self.push(
+                ChangeKind::Breaking,
+                path,
+                format!("type changed from {} to {}", describe(old), describe(new)),
+            );
+            return;
+        }
+
+        let old_format = old.get("format").and_then(Value::as_str);
+        let new_format = new.get("format").and_then(Value::as_str);
+        if old_format != new_format {
+            self.push(
+                ChangeKind::Breaking,
+                path,
+                format!(
+                    "format changed from {} to {}",
+                    old_format.unwrap_or("none"),
+                    new_format.unwrap_or("none")
+                ),
+            );
+        }
+
+        self.compare_variants(path, old, new);
+        self.compare_properties(path, old, new);
+        for key in ["items", "additionalProperties"] {
+            if let (Some(old_child), Some(new_child)) = (old.get(key), new.get(key))
+                && old_child.is_object()
+                && new_child.is_object()
+            {
+                self.compare_node(&format!("{path}/{key}"), old_child, new_child);
+            }
+        }
+    }
+
+    fn compare_properties(&mut self, path: &str, old: &Value, new: &Value) {
+        let empty = Map::new();
+        let old_properties = old.get("properties").and_then(Value::as_object).unwrap_or(&empty);
+        let new_properties = new.get("properties").and_then(Value::as_object).unwrap_or(&empty);
+        let old_required = required(old);
+        let new_required = required(new);
+
+        for (name, old_property) in old_properties {
+            let property_path = format!("{path}/properties/{name}");
+            let Some(new_property) = new_properties.get(name) else {
+                self.push(ChangeKind::Breaking, &property_path, "property removed");
+                continue;
+            };
+            match (old_required.contains(name.as_str()), new_required.contains(name.as_str())) {
+                (false, true) => {
+                    self.push(ChangeKind::Breaking, &property_path, "property became required")
+                }
+                (true, false) => {
+                    self.push(ChangeKind::Breaking, &property_path, "property became optional")
+                }
+                _ => {}
+            }
+            self.compare_node(&property_path, old_property, new_property);
+        }
+
+        for name in new_properties.keys().filter(|name| !old_properties.contains_key(*name)) {
+            let property_path = format!("{path}/properties/{name}");
+            if new_required.contains(name.as_str()) {
+                self.push(ChangeKind::Breaking, &property_path, "required property added");
+            } else {
+                self.push(ChangeKind::Additive, &property_path, "optional property added");
+            }
+        }
+    }
+
+    fn compare_variants(&mut self, path: &str, old: &Value, new: &Value) {
+        let old_variants = variants(old);
+        let new_variants = variants(new);
+        for (name, old_payload) in &old_variants {
+            let variant_path = format!("{path}/variants/{name}");
+            match (old_payload, new_variants.get(name)) {
+                (_, None) => self.push(ChangeKind::Breaking, &variant_path, "variant removed"),
+                (Some(_), Some(None)) => {
+                    self.push(ChangeKind::Breaking, &variant_path, "variant data removed")
+                }
+                (None, Some(Some(_))) => {
+                    self.push(ChangeKind::Breaking, &variant_path, "variant data added")
+                }
+                (Some(old_payload), Some(Some(new_payload))) => {
+                    self.compare_node(&variant_path, old_payload, new_payload)
+                }
+                (None, Some(None)) => {}
+            }
+        }
+        for name in new_variants.keys().filter(|name| !old_variants.contains_key(*name)) {
+            self.push(ChangeKind::Additive, &format!("{path}/variants/{name}"), "variant added");
+        }
+    }
+}
+
+/// Strips the wrappers schemars adds around a schema, such as `allOf` with a
+/// single entry for a described reference and `anyOf` with `null` for an
+/// `Option`.
+fn normalize(schema: &Value) -> Normalized<'_> {
+    let mut nullable = false;
+    let mut current = schema;
+    loop {
+        if let Some([inner]) = current.get("allOf").and_then(Value::as_array).map(Vec::as_slice) {
+            current = inner;
+            continue;
+        }
+        let options = current.get("anyOf").or_else(|| current.get("oneOf"));
+        let Some(options) = options.and_then(Value::as_array) else {
+            break;
+        };
+        let non_null = options.iter().filter(|option| !is_null(option)).collect::<Vec<_>>();
+        if non_null.len() != 1 || non_null.len() == options.len() {
+            break;
+        }
+        nullable = true;
+        current = non_null[0];
+    }
+
+    if let Some(types) = current.get("type").and_then(Value::as_array)
+        && types.iter().any(|t| t.as_str() == Some("null"))
+    {
+        nullable = true;
+    }
+    Normalized { schema: current, nullable }
+}
+
+/// Returns the non-null JSON types a schema accepts.
+fn types(schema: &Value) -> BTreeSet<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => BTreeSet::from([t.as_str()]),
+        Some(Value::Array(types)) => {
+            types.iter().filter_map(Value::as_str).filter(|t| *t != "null").collect()
+        }
+        _ => BTreeSet::new(),
+    }
+}
+
+fn required(schema: &Value) -> BTreeSet<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the variants of a schema generated for a serde enum, keyed by name,
+/// with the schema for each variant's data or None for unit variants.
+///
+/// Options which do not look like externally tagged variants are keyed by
+/// their position.
+fn variants(schema: &Value) -> BTreeMap<String, Option<&Value>> {
+    let mut result = BTreeMap::new();
+    for value in string_values(schema) {
+        result.insert(value, None);
+    }
+    let options = schema.get("oneOf").or_else(|| schema.get("anyOf")).and_then(Value::as_array);
+    for (index, option) in options.into_iter().flatten().enumerate() {
+        let values = string_values(option);
+        if !values.is_empty() {
+            result.extend(values.into_iter().map(|value| (value, None)));
+            continue;
+        }
+        let properties = option.get("properties").and_then(Value::as_object);
+        match properties.map(|p| p.iter().collect::<Vec<_>>()).as_deref() {
+            Some([(name, payload)]) => result.insert((*name).clone(), Some(*payload)),
+            _ => result.insert(format!("#{index}"), Some(option)),
+        };
+    }
+    result
+}
+
+/// Returns the fixed strings a schema accepts via `enum` or `const`.
+fn string_values(schema: &Value) -> Vec<String> {
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return vec![value.to_string()];
+    }
+    schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+/// Short description of a schema's type for change messages.
+fn describe(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference.trim_start_matches("#/definitions/").to_string();
+    }
+    let types = types(schema);
+    if types.is_empty() {
+        "any".to_string()
+    } else {
+        types.into_iter().collect::<Vec<_>>().join(" | ")
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use ai_agents::agent_search;
+use ai_data::game_ai::GameAI;
+use battle_mutations::actions::apply_battle_action;
+use battle_queries::battle_card_queries::card;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_history::BattleHistory;
+use battle_state::battle::battle_state::{BattleState, LoggingOptions, RequestContext};
+use battle_state::battle::battle_status::BattleStatus;
+use battle_state::battle_cards::dreamwell_data::Dreamwell;
+use battle_state::battle_player::battle_player_state::{
+    CreateBattlePlayer, PlayerType, TestDeckName,
+};
+use clap::{Parser, ValueEnum};
+use core_data::identifiers::BattleId;
+use core_data::types::PlayerName;
+use game_creation::new_test_battle;
+use serde::Serialize;
+use state_provider::state_provider::StateProvider;
+use state_provider::test_state_provider::TestStateProvider;
+use tabula_generated::card_lists::DreamwellCardIdList;
+use tracing::subscriber;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer};
+use uuid::Uuid;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Run seeded AI-vs-AI battles to collect deck and card balance statistics",
+    after_help = "EXAMPLE:\n    self_play --pair StartingFive:Core11 --games 100 --output balance.json"
+)]
+struct Args {
+    #[arg(
+        long = "pair",
+        value_parser = parse_deck_pair,
+        default_value = "StartingFive:StartingFive",
+        help = "Deck pair to play, as DECK_ONE:DECK_TWO. May be repeated"
+    )]
+    pairs: Vec<(TestDeckName, TestDeckName)>,
+
+    #[arg(
+        long,
+        default_value = "{\"MonteCarlo\":1}",
+        help = "JSON serialized GameAI for both players"
+    )]
+    ai: String,
+
+    #[arg(long, default_value = "100", help = "Number of battles to run per deck pair")]
+    games: usize,
+
+    #[arg(
+        long,
+        default_value = "3141592653",
+        help = "Base random seed. Battle N of each pair uses seed + N"
+    )]
+    seed: u64,
+
+    #[arg(long, help = "Path to write results to. Prints JSON to stdout if omitted")]
+    output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "json",
+        help = "Output format. CSV output contains one row per deck and card"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct SelfPlayReport {
+    ai: GameAI,
+    seed: u64,
+    games_per_pair: usize,
+    matchups: Vec<MatchupReport>,
+}
+
+#[derive(Serialize)]
+struct MatchupReport {
+    deck_one: TestDeckName,
+    deck_two: TestDeckName,
+    games: usize,
+    deck_one_wins: usize,
+    deck_two_wins: usize,
+    draws: usize,
+    deck_one_win_rate: f64,
+    average_turns: f64,
+    average_actions: f64,
+    cards: Vec<CardReport>,
+}
+
+/// Play statistics for a card, attributed to the deck which played it.
+#[derive(Serialize)]
+struct CardReport {
+    deck: TestDeckName,
+    card: String,
+    times_played: usize,
+    games_played: usize,
+    wins_when_played: usize,
+    win_rate_when_played: f64,
+}
+
+#[derive(Default)]
+struct CardTotals {
+    times_played: usize,
+    games_played: usize,
+    wins_when_played: usize,
+}
+
+/// Result of a single self-play battle.
+struct GameResult {
+    winner: Option<PlayerName>,
+    turns: usize,
+    actions: usize,
+    /// Number of times each card was played, keyed by player and card name.
+    plays: BTreeMap<(PlayerName, String), usize>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    if args.games == 0 {
+        return Err("Number of games must be greater than 0".into());
+    }
+    let ai: GameAI = serde_json::from_str(&args.ai)?;
+
+    let provider = TestStateProvider::new();
+    let streaming_assets_path = logging::get_developer_mode_streaming_assets_path();
+    let _ = provider.initialize("/tmp/test", &streaming_assets_path);
+
+    let filter = EnvFilter::new("warn");
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_subscriber::fmt::layer().with_filter(filter));
+    let matchups = subscriber::with_default(subscriber, || {
+        args.pairs
+            .iter()
+            .map(|&(deck_one, deck_two)| {
+                run_matchup(&provider, ai, deck_one, deck_two, args.games, args.seed)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let report = SelfPlayReport { ai, seed: args.seed, games_per_pair: args.games, matchups };
+    let output = match args.format {
+        OutputFormat::Json => serde_json::to_string_pretty(&report)?,
+        OutputFormat::Csv => csv_report(&report),
+    };
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output)?;
+            eprintln!("Wrote self-play results to {}", path.display());
+        }
+        None => println!("{output}"),
+    }
+    Ok(())
+}
+
+/// Runs `games` battles between two decks, alternating which deck plays
+/// first.
+fn run_matchup(
+    provider: &TestStateProvider,
+    ai: GameAI,
+    deck_one: TestDeckName,
+    deck_two: TestDeckName,
+    games: usize,
+    seed: u64,
+) -> MatchupReport {
+    let start_time = Instant::now();
+    let mut deck_one_wins = 0;
+    let mut deck_two_wins = 0;
+    let mut draws = 0;
+    let mut total_turns = 0;
+    let mut total_actions = 0;
+    let mut cards: BTreeMap<(String, String), (TestDeckName, CardTotals)> = BTreeMap::new();
+
+    for game in 0..games {
+        let swap_positions = game % 2 == 1;
+        let (player_one_deck, player_two_deck) =
+            if swap_positions { (deck_two, deck_one) } else { (deck_one, deck_two) };
+        let result = run_game(
+            provider,
+            ai,
+            player_one_deck,
+            player_two_deck,
+            seed.wrapping_add(game as u64),
+        );
+
+        let deck_for = |player: PlayerName| match player {
+            PlayerName::One => player_one_deck,
+            PlayerName::Two => player_two_deck,
+        };
+        match result.winner {
+            Some(PlayerName::One) if !swap_positions => deck_one_wins += 1,
+            Some(PlayerName::Two) if swap_positions => deck_one_wins += 1,
+            Some(_) => deck_two_wins += 1,
+            None => draws += 1,
+        }
+        total_turns += result.turns;
+        total_actions += result.actions;
+
+        for ((player, card), count) in result.plays {
+            let deck = deck_for(player);
+            let (_, totals) = cards
+                .entry((format!("{deck:?}"), card))
+                .or_insert_with(|| (deck, CardTotals::default()));
+            totals.times_played += count;
+            totals.games_played += 1;
+            if result.winner == Some(player) {
+                totals.wins_when_played += 1;
+            }
+        }
+
+        eprint!("\r\x1B[2K{deck_one:?} vs {deck_two:?}: game {}/{games}", game + 1);
+    }
+    eprintln!(" ({:.2?})", start_time.elapsed());
+
+    let decided = deck_one_wins + deck_two_wins;
+    MatchupReport {
+        deck_one,
+        deck_two,
+        games,
+        deck_one_wins,
+        deck_two_wins,
+        draws,
+        deck_one_win_rate: ratio(deck_one_wins, decided),
+        average_turns: ratio(total_turns, games),
+        average_actions: ratio(total_actions, games),
+        cards: cards
+            .into_iter()
+            .map(|((_, card), (deck, totals))| CardReport {
+                deck,
+                card,
+                times_played: totals.times_played,
+                games_played: totals.games_played,
+                wins_when_played: totals.wins_when_played,
+                win_rate_when_played: ratio(totals.wins_when_played, totals.games_played),
+            })
+            .collect(),
+    }
+}
+
+/// Plays a single battle to completion and collects statistics from its
+/// action history.
+fn run_game(
+    provider: &TestStateProvider,
+    ai: GameAI,
+    player_one_deck: TestDeckName,
+    player_two_deck: TestDeckName,
+    seed: u64,
+) -> GameResult {
+    let mut battle = new_test_battle::create_and_start(
+        BattleId(Uuid::new_v4()),
+        provider.tabula(),
+        seed,
+        Dreamwell::from_card_list(
+            &provider.tabula(),
+            DreamwellCardIdList::TestDreamwellNoAbilities,
+        ),
+        CreateBattlePlayer {
+            player_type: PlayerType::Agent(ai),
+            deck_name: player_one_deck,
+            personality: None,
+        },
+        CreateBattlePlayer {
+            player_type: PlayerType::Agent(ai),
+            deck_name: player_two_deck,
+            personality: None,
+        },
+        RequestContext { logging_options: LoggingOptions::default() },
+    );
+    battle.action_history = Some(BattleHistory::default());
+
+    while let Some(player) = legal_actions::next_to_act(&battle) {
+        let legal = legal_actions::compute(&battle, player);
+        let action = if legal.len() == 1 {
+            legal.all()[0]
+        } else {
+            agent_search::select_action_unchecked(&battle, player, &ai, None)
+        };
+        apply_battle_action::execute(&mut battle, player, action);
+    }
+
+    let BattleStatus::GameOver { winner } = battle.status else {
+        panic!("Battle ended without reaching game over");
+    };
+    GameResult {
+        winner,
+        turns: battle.turn.turn_id.0 as usize,
+        actions: battle.action_history.as_ref().map_or(0, |history| history.actions.len()),
+        plays: card_plays(&battle),
+    }
+}
+
+/// Counts cards played from hand by each player in the battle's action
+/// history.
+fn card_plays(battle: &BattleState) -> BTreeMap<(PlayerName, String), usize> {
+    let mut plays = BTreeMap::new();
+    let Some(history) = &battle.action_history else {
+        return plays;
+    };
+    for entry in &history.actions {
+        if let BattleAction::PlayCardFromHand(card_id) = entry.action {
+            let name = card::get_definition(battle, card_id).displayed_name.clone();
+            *plays.entry((entry.player, name)).or_insert(0) += 1;
+        }
+    }
+    plays
+}
+
+fn csv_report(report: &SelfPlayReport) -> String {
+    let mut result = "deck_one,deck_two,deck_one_win_rate,deck,card,times_played,games_played,\
+                      wins_when_played,win_rate_when_played"
+        .to_string();
+    for matchup in &report.matchups {
+        for card in &matchup.cards {
+            result.push('\n');
+            result.push_str(&format!(
+                "{:?},{:?},{:.3},{:?},{},{},{},{},{:.3}",
+                matchup.deck_one,
+                matchup.deck_two,
+                matchup.deck_one_win_rate,
+                card.deck,
+                csv_field(&card.card),
+                card.times_played,
+                card.games_played,
+                card.wins_when_played,
+                card.win_rate_when_played
+            ));
+        }
+    }
+    result
+}
+
+/// Quotes a CSV field if it contains a comma or quote.
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 { 0.0 } else { numerator as f64 / denominator as f64 }
+}
+
+fn parse_deck_pair(value: &str) -> Result<(TestDeckName, TestDeckName), String> {
+    let Some((one, two)) = value.split_once(':') else {
+        return Err(format!("Expected DECK_ONE:DECK_TWO, got '{value}'"));
+    };
+    Ok((parse_deck(one)?, parse_deck(two)?))
+}
+
+fn parse_deck(name: &str) -> Result<TestDeckName, String> {
+    serde_json::from_str(&format!("\"{name}\"")).map_err(|_| format!("Unknown deck '{name}'"))
+}
@@ -1,10 +1,16 @@
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 use action_data::panel_address::PanelAddress;
+use battle_state::battle::card_id::CardId;
+use battle_state::battle_trace::decision_explanation::ScoredAction;
 use core_data::identifiers::{BattleId, UserId};
 use core_data::numerics::Energy;
 use core_data::types::PlayerName;
+use database::match_history::MatchHistory;
+use display_data::battle_view::BattleView;
 use display_data::object_position::Position;
+use display_data::request_data::{AnimationPreferences, ClientCapability};
 use tabula_data::tabula::Tabula;
 
 pub trait DisplayStateProvider: Send + Sync {
@@ -15,6 +21,17 @@ pub trait DisplayStateProvider: Send + Sync {
     fn tabula(&self) -> Arc<Tabula>;
 
     fn can_undo(&self, battle_id: BattleId, player: PlayerName) -> bool;
+
+    /// Returns true if the user has already been shown the given tutorial
+    /// message.
+    fn has_seen_tutorial_message(&self, user_id: UserId, message: TutorialMessageId) -> bool;
+
+    /// Records that the user has been shown the given tutorial message, so it
+    /// is not shown to them again.
+    fn mark_tutorial_message_seen(&self, user_id: UserId, message: TutorialMessageId);
+
+    /// Returns the battles the user has completed, most recent first.
+    fn match_history(&self, user_id: UserId) -> MatchHistory;
 }
 
 #[derive(Debug, Clone, Default)]
@@ -23,4 +40,60 @@ pub struct DisplayState {
     pub selected_energy_additional_cost: Option<Energy>,
     pub current_panel_address: Option<PanelAddress>,
     pub overlay_hidden: bool,
+    pub hint: Option<HintState>,
+
+    /// Card the user is viewing on the card inspection screen, if any.
+    pub inspected_card: Option<CardId>,
+
+    /// True if the client has requested battle view deltas instead of full
+    /// snapshots.
+    pub battle_view_deltas: bool,
+
+    /// Language the client has requested text be rendered in, or None to use
+    /// the default language.
+    pub language: Option<String>,
+
+    /// True if the client has requested text be colored using the
+    /// colorblind-safe palette.
+    pub colorblind_mode: bool,
+
+    /// Optional display protocol features the client declared support for
+    /// when connecting.
+    pub client_capabilities: Vec<ClientCapability>,
+
+    /// The most recent battle view sent to the client, used as the base for
+    /// computing battle view deltas.
+    pub last_battle_view: Option<Arc<BattleView>>,
+
+    /// True if scripted tutorial messages should be shown to the user.
+    pub tutorial: bool,
+
+    /// Tutorial message currently shown to the user, which is marked as seen
+    /// once the user takes a battle action.
+    pub tutorial_message: Option<TutorialMessageId>,
+
+    /// True if emotes sent by the user's opponent should not be shown.
+    pub opponent_emotes_muted: bool,
+
+    /// How the user has requested animations be played.
+    pub animation_preferences: AnimationPreferences,
+}
+
+/// An action suggestion requested by the user.
+#[derive(Debug, Clone)]
+pub struct HintState {
+    /// Set to stop searching for this suggestion once it is no longer needed,
+    /// e.g. because the user has taken an action.
+    pub cancelled: Arc<AtomicBool>,
+
+    /// Suggested action, or None while search is still running.
+    pub suggestion: Option<ScoredAction>,
+}
+
+/// Identifies a step in the scripted tutorial message sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TutorialMessageId {
+    PlayCard,
+    EndTurn,
+    FastCard,
 }
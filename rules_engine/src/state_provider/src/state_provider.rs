@@ -1,17 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, LazyLock, Mutex, RwLock};
 use std::time::Instant;
 
 use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::{BattleState, RequestContext};
 use battle_state::battle_player::battle_player_state::TestDeckName;
-use core_data::identifiers::{BattleId, UserId};
+use battle_state::battle_trace::decision_explanation::DecisionExplanation;
+use core_data::identifiers::{BattleId, SaveSlotId, UserId};
 use core_data::initialization_error::{ErrorCode, InitializationError};
 use core_data::types::PlayerName;
+use database::autosave::{self, AutosavePolicy};
+use database::match_history::{MatchHistory, MatchHistoryPruning};
 use database::save_file::SaveFile;
+use database::save_file_encoding::SaveFileOptions;
 use database::save_file_io;
 use display_data::command::CommandSequence;
 use display_data::request_data::{PollResponseType, RequestId};
@@ -20,7 +24,7 @@ use tabula_generated::card_lists::DreamwellCardIdList;
 use tracing::instrument;
 use uuid::Uuid;
 
-use crate::display_state_provider::{DisplayState, DisplayStateProvider};
+use crate::display_state_provider::{DisplayState, DisplayStateProvider, TutorialMessageId};
 
 static REQUEST_CONTEXTS: LazyLock<Mutex<HashMap<UserId, RequestContext>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
@@ -31,6 +35,9 @@ static REQUEST_TIMESTAMPS: LazyLock<Mutex<HashMap<Option<Uuid>, Instant>>> =
 static LAST_RESPONSE_VERSIONS: LazyLock<Mutex<HashMap<UserId, Uuid>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+static LAST_REQUEST_IDS: LazyLock<Mutex<HashMap<UserId, RequestId>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 static INITIALIZATION_ERROR: LazyLock<Mutex<Option<String>>> = LazyLock::new(|| Mutex::new(None));
 
 static UNDO_STACKS: LazyLock<Mutex<HashMap<BattleId, Vec<UndoEntry>>>> =
@@ -51,6 +58,9 @@ static TOTAL_PENDING_UPDATES: LazyLock<AtomicUsize> = LazyLock::new(|| AtomicUsi
 static DISPLAY_STATES: LazyLock<Mutex<HashMap<UserId, DisplayState>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+static SEEN_TUTORIAL_MESSAGES: LazyLock<Mutex<HashMap<UserId, HashSet<TutorialMessageId>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 static SPECULATIVE_SEARCHES: LazyLock<Mutex<HashMap<BattleId, SpeculativeSearchState>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
@@ -58,6 +68,12 @@ static TABULA_DATA: LazyLock<RwLock<Option<Arc<Tabula>>>> = LazyLock::new(|| RwL
 
 static PERSISTENT_DATA_DIR: LazyLock<Mutex<Option<PathBuf>>> = LazyLock::new(|| Mutex::new(None));
 
+static SAVE_SLOTS: LazyLock<Mutex<HashMap<UserId, SaveSlotId>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static AUTOSAVE_ACTION_COUNTS: LazyLock<Mutex<HashMap<UserId, u32>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 pub trait StateProvider:
     Clone + RefUnwindSafe + UnwindSafe + Send + Sync + DisplayStateProvider
 {
@@ -72,6 +88,28 @@ pub trait StateProvider:
 
     fn write_save_file(&self, save: SaveFile) -> Result<(), Vec<InitializationError>>;
 
+    /// Selects the save slot which [Self::read_save_file] and
+    /// [Self::write_save_file] use for this user.
+    fn select_save_slot(&self, user_id: UserId, slot: SaveSlotId);
+
+    /// Returns the save slot currently selected for this user.
+    fn save_slot(&self, user_id: UserId) -> SaveSlotId;
+
+    /// Records that an action was performed for this user and returns the
+    /// number of actions since their last autosave, including this one.
+    fn count_autosave_action(&self, user_id: UserId) -> u32;
+
+    /// Writes an autosave for the user's selected save slot, replacing their
+    /// oldest autosave if needed, and resets their autosave action count.
+    fn write_autosave(&self, save: SaveFile) -> Result<(), Vec<InitializationError>>;
+
+    /// Reads the most recent readable autosave for the user's selected save
+    /// slot.
+    fn read_latest_autosave(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<SaveFile>, Vec<InitializationError>>;
+
     fn store_request_context(&self, user_id: UserId, context: RequestContext);
 
     fn get_request_context(&self, user_id: UserId) -> Option<RequestContext>;
@@ -84,6 +122,12 @@ pub trait StateProvider:
 
     fn get_last_response_version(&self, user_id: UserId) -> Option<Uuid>;
 
+    /// Records the ID of the most recent action request accepted for
+    /// processing from this user, used to detect duplicate submissions.
+    fn store_last_request_id(&self, user_id: UserId, request_id: RequestId);
+
+    fn get_last_request_id(&self, user_id: UserId) -> Option<RequestId>;
+
     fn start_processing(&self, user_id: UserId) -> bool;
 
     fn finish_processing(&self, user_id: UserId);
@@ -123,6 +167,17 @@ pub trait StateProvider:
     fn default_deck_name(&self) -> TestDeckName {
         TestDeckName::Core11
     }
+
+    /// Returns the limits on how many completed battles are kept in a user's
+    /// match history.
+    fn match_history_pruning(&self) -> MatchHistoryPruning {
+        MatchHistoryPruning::default()
+    }
+
+    /// Returns how often autosaves are written during a battle.
+    fn autosave_policy(&self) -> AutosavePolicy {
+        AutosavePolicy::default()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -132,10 +187,27 @@ pub struct PollResult {
     pub response_type: PollResponseType,
 }
 
-#[derive(Clone)]
+/// Agent responses being searched in the background while waiting for a
+/// human player to act.
+#[derive(Clone, Default)]
 pub struct SpeculativeSearchState {
+    /// Set to stop pondering any further opponent actions.
+    pub cancelled: Arc<AtomicBool>,
+
+    /// Responses which have started searching, most likely opponent action
+    /// first.
+    pub responses: Arc<Mutex<Vec<SpeculativeResponse>>>,
+}
+
+/// A background search for an agent's response to a single assumed opponent
+/// action.
+#[derive(Clone)]
+pub struct SpeculativeResponse {
     pub assumed_action: BattleAction,
-    pub result: Arc<(Mutex<Option<BattleAction>>, Condvar)>,
+    pub result: Arc<(Mutex<Option<(BattleAction, Option<DecisionExplanation>)>>, Condvar)>,
+
+    /// Set to stop searching for this response.
+    pub cancelled: Arc<AtomicBool>,
 }
 
 #[derive(Clone)]
@@ -197,30 +269,61 @@ impl StateProvider for DefaultStateProvider {
         &self,
         user_id: UserId,
     ) -> Result<Option<SaveFile>, Vec<InitializationError>> {
-        let dir = {
-            let guard = PERSISTENT_DATA_DIR.lock().unwrap();
-            guard.clone().ok_or_else(|| {
-                vec![InitializationError::with_name(
-                    ErrorCode::NotInitializedError,
-                    "Data directory not initialized. Call initialize() first.".to_string(),
-                )]
-            })?
-        };
-        save_file_io::read_save_from_dir(&dir, user_id)
+        let dir = persistent_data_dir()?;
+        save_file_io::read_save_from_slot(&dir, user_id, self.save_slot(user_id))
     }
 
     #[instrument(skip_all, level = "debug")]
     fn write_save_file(&self, save: SaveFile) -> Result<(), Vec<InitializationError>> {
-        let dir = {
-            let guard = PERSISTENT_DATA_DIR.lock().unwrap();
-            guard.clone().ok_or_else(|| {
-                vec![InitializationError::with_name(
-                    ErrorCode::NotInitializedError,
-                    "Data directory not initialized. Call initialize() first.".to_string(),
-                )]
-            })?
-        };
-        save_file_io::write_save_to_dir(&dir, &save)
+        let dir = persistent_data_dir()?;
+        let slot = self.save_slot(save.id());
+        save_file_io::write_save_to_slot(&dir, &save, slot, SaveFileOptions::default())
+    }
+
+    fn select_save_slot(&self, user_id: UserId, slot: SaveSlotId) {
+        let mut slots = SAVE_SLOTS.lock().unwrap();
+        slots.insert(user_id, slot);
+    }
+
+    fn save_slot(&self, user_id: UserId) -> SaveSlotId {
+        let slots = SAVE_SLOTS.lock().unwrap();
+        slots.get(&user_id).copied().unwrap_or_default()
+    }
+
+    fn count_autosave_action(&self, user_id: UserId) -> u32 {
+        let mut counts = AUTOSAVE_ACTION_COUNTS.lock().unwrap();
+        let count = counts.entry(user_id).or_default();
+        *count += 1;
+        *count
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    fn write_autosave(&self, save: SaveFile) -> Result<(), Vec<InitializationError>> {
+        let dir = persistent_data_dir()?;
+        let user_id = save.id();
+        autosave::write_autosave(
+            &dir,
+            &save,
+            self.save_slot(user_id),
+            self.autosave_policy(),
+            SaveFileOptions::default(),
+        )?;
+        AUTOSAVE_ACTION_COUNTS.lock().unwrap().remove(&user_id);
+        Ok(())
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    fn read_latest_autosave(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+        let dir = persistent_data_dir()?;
+        autosave::read_latest_autosave(
+            &dir,
+            user_id,
+            self.save_slot(user_id),
+            self.autosave_policy(),
+        )
     }
 
     fn store_request_context(&self, user_id: UserId, context: RequestContext) {
@@ -265,6 +368,16 @@ impl StateProvider for DefaultStateProvider {
         versions.get(&user_id).copied()
     }
 
+    fn store_last_request_id(&self, user_id: UserId, request_id: RequestId) {
+        let mut request_ids = LAST_REQUEST_IDS.lock().unwrap();
+        request_ids.insert(user_id, request_id);
+    }
+
+    fn get_last_request_id(&self, user_id: UserId) -> Option<RequestId> {
+        let request_ids = LAST_REQUEST_IDS.lock().unwrap();
+        request_ids.get(&user_id).copied()
+    }
+
     fn start_processing(&self, user_id: UserId) -> bool {
         let mut processing = PROCESSING_USERS.lock().unwrap();
         if processing.get(&user_id).copied().unwrap_or(false) {
@@ -356,6 +469,16 @@ impl StateProvider for DefaultStateProvider {
     }
 }
 
+fn persistent_data_dir() -> Result<PathBuf, Vec<InitializationError>> {
+    let guard = PERSISTENT_DATA_DIR.lock().unwrap();
+    guard.clone().ok_or_else(|| {
+        vec![InitializationError::with_name(
+            ErrorCode::NotInitializedError,
+            "Data directory not initialized. Call initialize() first.".to_string(),
+        )]
+    })
+}
+
 impl DisplayStateProvider for DefaultStateProvider {
     fn get_display_state(&self, user_id: UserId) -> DisplayState {
         let states = DISPLAY_STATES.lock().unwrap();
@@ -379,4 +502,21 @@ impl DisplayStateProvider for DefaultStateProvider {
             .map(|stack| stack.iter().any(|entry| entry.player == player))
             .unwrap_or(false)
     }
+
+    fn has_seen_tutorial_message(&self, user_id: UserId, message: TutorialMessageId) -> bool {
+        let seen = SEEN_TUTORIAL_MESSAGES.lock().unwrap();
+        seen.get(&user_id).is_some_and(|messages| messages.contains(&message))
+    }
+
+    fn mark_tutorial_message_seen(&self, user_id: UserId, message: TutorialMessageId) {
+        let mut seen = SEEN_TUTORIAL_MESSAGES.lock().unwrap();
+        seen.entry(user_id).or_default().insert(message);
+    }
+
+    fn match_history(&self, user_id: UserId) -> MatchHistory {
+        match self.read_save_file(user_id) {
+            Ok(Some(save)) => save.match_history().clone(),
+            _ => MatchHistory::default(),
+        }
+    }
 }
@@ -1,19 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::Path;
 use std::sync::{Arc, Mutex, OnceLock, RwLock};
 use std::time::Instant;
 
 use battle_state::battle::battle_state::{BattleState, RequestContext};
 use battle_state::battle_player::battle_player_state::TestDeckName;
-use core_data::identifiers::{BattleId, UserId};
+use core_data::identifiers::{BattleId, SaveSlotId, UserId};
 use core_data::initialization_error::{ErrorCode, InitializationError};
 use core_data::types::PlayerName;
+use database::match_history::MatchHistory;
 use database::save_file::SaveFile;
+use display_data::request_data::RequestId;
 use tabula_data::tabula::{Tabula, TabulaSource};
 use tabula_generated::card_lists::DreamwellCardIdList;
 use uuid::Uuid;
 
-use crate::display_state_provider::{DisplayState, DisplayStateProvider};
+use crate::display_state_provider::{DisplayState, DisplayStateProvider, TutorialMessageId};
 use crate::state_provider::{PollResult, SpeculativeSearchState, StateProvider};
 
 #[derive(Clone)]
@@ -22,13 +24,18 @@ pub struct TestStateProvider {
 }
 
 struct TestStateProviderInner {
-    save_files: Mutex<HashMap<UserId, SaveFile>>,
+    save_files: Mutex<HashMap<(UserId, SaveSlotId), SaveFile>>,
+    save_slots: Mutex<HashMap<UserId, SaveSlotId>>,
+    autosaves: Mutex<HashMap<(UserId, SaveSlotId), VecDeque<SaveFile>>>,
+    autosave_action_counts: Mutex<HashMap<UserId, u32>>,
     request_contexts: Mutex<HashMap<UserId, RequestContext>>,
     request_timestamps: Mutex<HashMap<Option<Uuid>, Instant>>,
     last_response_versions: Mutex<HashMap<UserId, Uuid>>,
+    last_request_ids: Mutex<HashMap<UserId, RequestId>>,
     processing_users: Mutex<HashMap<UserId, bool>>,
     pending_updates: Mutex<HashMap<UserId, Vec<PollResult>>>,
     display_states: Mutex<HashMap<UserId, DisplayState>>,
+    seen_tutorial_messages: Mutex<HashMap<UserId, HashSet<TutorialMessageId>>>,
     tabula: RwLock<Option<Arc<Tabula>>>,
     undo_stacks: Mutex<HashMap<BattleId, Vec<(PlayerName, BattleState)>>>,
     speculative_searches: Mutex<HashMap<BattleId, SpeculativeSearchState>>,
@@ -39,12 +46,17 @@ impl TestStateProvider {
         Self {
             inner: Arc::new(TestStateProviderInner {
                 save_files: Mutex::new(HashMap::new()),
+                save_slots: Mutex::new(HashMap::new()),
+                autosaves: Mutex::new(HashMap::new()),
+                autosave_action_counts: Mutex::new(HashMap::new()),
                 request_contexts: Mutex::new(HashMap::new()),
                 request_timestamps: Mutex::new(HashMap::new()),
                 last_response_versions: Mutex::new(HashMap::new()),
+                last_request_ids: Mutex::new(HashMap::new()),
                 processing_users: Mutex::new(HashMap::new()),
                 pending_updates: Mutex::new(HashMap::new()),
                 display_states: Mutex::new(HashMap::new()),
+                seen_tutorial_messages: Mutex::new(HashMap::new()),
                 undo_stacks: Mutex::new(HashMap::new()),
                 speculative_searches: Mutex::new(HashMap::new()),
                 tabula: RwLock::new(None),
@@ -104,6 +116,7 @@ impl StateProvider for TestStateProvider {
         &self,
         user_id: UserId,
     ) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+        let slot = self.save_slot(user_id);
         Ok(self
             .inner
             .save_files
@@ -115,11 +128,12 @@ impl StateProvider for TestStateProvider {
                     e.to_string(),
                 )]
             })?
-            .get(&user_id)
+            .get(&(user_id, slot))
             .cloned())
     }
 
     fn write_save_file(&self, save: SaveFile) -> Result<(), Vec<InitializationError>> {
+        let slot = self.save_slot(save.id());
         self.inner
             .save_files
             .lock()
@@ -130,10 +144,51 @@ impl StateProvider for TestStateProvider {
                     e.to_string(),
                 )]
             })?
-            .insert(save.id(), save);
+            .insert((save.id(), slot), save);
         Ok(())
     }
 
+    fn select_save_slot(&self, user_id: UserId, slot: SaveSlotId) {
+        let mut slots = self.inner.save_slots.lock().unwrap();
+        slots.insert(user_id, slot);
+    }
+
+    fn save_slot(&self, user_id: UserId) -> SaveSlotId {
+        let slots = self.inner.save_slots.lock().unwrap();
+        slots.get(&user_id).copied().unwrap_or_default()
+    }
+
+    fn count_autosave_action(&self, user_id: UserId) -> u32 {
+        let mut counts = self.inner.autosave_action_counts.lock().unwrap();
+        let count = counts.entry(user_id).or_default();
+        *count += 1;
+        *count
+    }
+
+    fn write_autosave(&self, save: SaveFile) -> Result<(), Vec<InitializationError>> {
+        let user_id = save.id();
+        let rotation_size = self.autosave_policy().rotation_size as usize;
+        let mut autosaves = self.inner.autosaves.lock().unwrap();
+        let rotation = autosaves.entry((user_id, self.save_slot(user_id))).or_default();
+        rotation.push_back(save);
+        while rotation.len() > rotation_size {
+            rotation.pop_front();
+        }
+        self.inner.autosave_action_counts.lock().unwrap().remove(&user_id);
+        Ok(())
+    }
+
+    fn read_latest_autosave(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<SaveFile>, Vec<InitializationError>> {
+        let autosaves = self.inner.autosaves.lock().unwrap();
+        Ok(autosaves
+            .get(&(user_id, self.save_slot(user_id)))
+            .and_then(|rotation| rotation.back())
+            .cloned())
+    }
+
     fn store_request_context(&self, user_id: UserId, context: RequestContext) {
         if let Ok(mut contexts) = self.inner.request_contexts.lock() {
             contexts.insert(user_id, context);
@@ -185,6 +240,20 @@ impl StateProvider for TestStateProvider {
         }
     }
 
+    fn store_last_request_id(&self, user_id: UserId, request_id: RequestId) {
+        if let Ok(mut request_ids) = self.inner.last_request_ids.lock() {
+            request_ids.insert(user_id, request_id);
+        }
+    }
+
+    fn get_last_request_id(&self, user_id: UserId) -> Option<RequestId> {
+        if let Ok(request_ids) = self.inner.last_request_ids.lock() {
+            request_ids.get(&user_id).copied()
+        } else {
+            None
+        }
+    }
+
     fn start_processing(&self, user_id: UserId) -> bool {
         if let Ok(mut processing) = self.inner.processing_users.lock() {
             if processing.get(&user_id).copied().unwrap_or(false) {
@@ -311,4 +380,21 @@ impl DisplayStateProvider for TestStateProvider {
             .map(|stack| stack.iter().any(|(entry_player, _)| *entry_player == player))
             .unwrap_or(false)
     }
+
+    fn has_seen_tutorial_message(&self, user_id: UserId, message: TutorialMessageId) -> bool {
+        let seen = self.inner.seen_tutorial_messages.lock().unwrap();
+        seen.get(&user_id).is_some_and(|messages| messages.contains(&message))
+    }
+
+    fn mark_tutorial_message_seen(&self, user_id: UserId, message: TutorialMessageId) {
+        let mut seen = self.inner.seen_tutorial_messages.lock().unwrap();
+        seen.entry(user_id).or_default().insert(message);
+    }
+
+    fn match_history(&self, user_id: UserId) -> MatchHistory {
+        match self.read_save_file(user_id) {
+            Ok(Some(save)) => save.match_history().clone(),
+            _ => MatchHistory::default(),
+        }
+    }
 }
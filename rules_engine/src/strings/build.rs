@@ -0,0 +1,65 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+/// Phrase definitions compiled by the `rlf!` macro.
+const PHRASES_PATH: &str = "src/strings.rlf.rs";
+
+/// Generates a wrapper for every parameterized phrase in [PHRASES_PATH].
+///
+/// Each wrapper passes its arguments through `number_format::substitute`
+/// before calling the function generated by `rlf!`, so numbers are formatted
+/// for the active language as they are substituted into phrases.
+fn main() {
+    println!("cargo::rerun-if-changed={PHRASES_PATH}");
+    let source = fs::read_to_string(PHRASES_PATH).expect("Error reading phrase definitions");
+
+    let mut output = String::new();
+    let mut comments = Vec::new();
+    for line in source.lines().map(str::trim) {
+        if let Some(comment) = line.strip_prefix("//") {
+            comments.push(comment.trim());
+            continue;
+        }
+        if let Some((name, parameters)) = parameterized_phrase(line) {
+            write_wrapper(&mut output, &comments, name, &parameters);
+        }
+        comments.clear();
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set for build scripts");
+    fs::write(Path::new(&out_dir).join("localized_phrases.rs"), output)
+        .expect("Error writing localized phrases");
+}
+
+/// Returns the name and parameter names of a phrase definition line such as
+/// `cards($n) = :match($n) { 1: "a card", *other: "{$n} cards" };`.
+fn parameterized_phrase(line: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, rest) = line.split_once("($")?;
+    if name.is_empty()
+        || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return None;
+    }
+    let (parameters, rest) = rest.split_once(')')?;
+    if !rest.trim_start().starts_with('=') {
+        return None;
+    }
+    Some((name, parameters.split(',').map(|p| p.trim().trim_start_matches('$')).collect()))
+}
+
+fn write_wrapper(output: &mut String, comments: &[&str], name: &str, parameters: &[&str]) {
+    for comment in comments {
+        writeln!(output, "/// {comment}").unwrap();
+    }
+    let arguments =
+        parameters.iter().map(|p| format!("{p}: impl Into<Value>")).collect::<Vec<_>>().join(", ");
+    let values = parameters
+        .iter()
+        .map(|p| format!("number_format::substitute({p}.into())"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(output, "pub fn {name}({arguments}) -> Phrase {{").unwrap();
+    writeln!(output, "    generated::{name}({values})").unwrap();
+    writeln!(output, "}}\n").unwrap();
+}
@@ -0,0 +1,64 @@
+use std::cell::Cell;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Held while text is being produced in a specific language.
+///
+/// The active RLF language is global to the process, so text for users with
+/// different languages must not be produced concurrently.
+static LANGUAGE_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// True while this thread holds `LANGUAGE_LOCK`.
+    static HOLDS_LANGUAGE_LOCK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` with `language` (e.g. "ru") as the active RLF language, restoring
+/// the previously active language afterwards.
+///
+/// If `language` is None, `f` runs in the process's current language. In both
+/// cases other threads cannot change the active language via this function
+/// until `f` returns, so every phrase produced by `f` comes from the same
+/// locale. Calls may be nested on the same thread. Phrases not translated
+/// for `language` fall back to their English text.
+pub fn with_language<T>(language: Option<&str>, f: impl FnOnce() -> T) -> T {
+    let _lock = if HOLDS_LANGUAGE_LOCK.get() {
+        None
+    } else {
+        let guard = LANGUAGE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        HOLDS_LANGUAGE_LOCK.set(true);
+        Some(LockGuard { _guard: guard })
+    };
+    let _language = language.map(LanguageGuard::activate);
+    f()
+}
+
+/// Releases `LANGUAGE_LOCK` when dropped.
+struct LockGuard {
+    _guard: MutexGuard<'static, ()>,
+}
+
+/// Activates a language for the lifetime of the guard, restoring the
+/// previously active language when dropped.
+struct LanguageGuard {
+    previous_language: String,
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        HOLDS_LANGUAGE_LOCK.set(false);
+    }
+}
+
+impl LanguageGuard {
+    fn activate(language: &str) -> Self {
+        let previous_language = rlf::with_locale(|locale| locale.language().to_string());
+        rlf::with_locale_mut(|locale| locale.set_language(language));
+        Self { previous_language }
+    }
+}
+
+impl Drop for LanguageGuard {
+    fn drop(&mut self) {
+        rlf::with_locale_mut(|locale| locale.set_language(&self.previous_language));
+    }
+}
@@ -1,2 +1,6 @@
-#[path = "strings.rlf.rs"]
+pub mod language;
+pub mod number_format;
+pub mod palette;
 pub mod strings;
+#[path = "strings.rlf.rs"]
+mod strings_generated;
@@ -0,0 +1,256 @@
+use rlf::{Phrase, Value};
+
+use crate::{strings, strings_generated};
+
+/// Largest number which [spell_out_numbers] writes as a word.
+const MAX_SPELLED_OUT_NUMBER: u32 = 10;
+
+/// Digit glyphs used to render numbers in a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitShaping {
+    /// ASCII digits 0-9.
+    Latin,
+    /// Arabic-Indic digits (U+0660 to U+0669).
+    EasternArabic,
+    /// Extended Arabic-Indic digits used for Persian and Urdu (U+06F0 to
+    /// U+06F9).
+    Persian,
+}
+
+/// Rules for rendering integer values in a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Separator inserted between groups of three digits, if any.
+    pub grouping_separator: Option<char>,
+
+    /// Minimum number of digits a number must have before grouping applies.
+    pub minimum_grouping_digits: usize,
+
+    /// Digit glyphs to use.
+    pub digits: DigitShaping,
+}
+
+/// Returns the number format registered for a language tag such as "ru" or
+/// "ar-EG".
+///
+/// Unknown languages, including English and test locales, leave numbers
+/// unchanged.
+pub fn for_language(language: &str) -> NumberFormat {
+    match primary_subtag(language) {
+        "ru" | "uk" | "fr" | "pl" | "cs" => NumberFormat {
+            grouping_separator: Some('\u{00A0}'),
+            minimum_grouping_digits: 5,
+            digits: DigitShaping::Latin,
+        },
+        "de" | "es" | "it" | "pt" | "tr" => NumberFormat {
+            grouping_separator: Some('.'),
+            minimum_grouping_digits: 5,
+            digits: DigitShaping::Latin,
+        },
+        "ar" => NumberFormat {
+            grouping_separator: Some('\u{066C}'),
+            minimum_grouping_digits: 4,
+            digits: DigitShaping::EasternArabic,
+        },
+        "fa" | "ur" => NumberFormat {
+            grouping_separator: Some('\u{066C}'),
+            minimum_grouping_digits: 4,
+            digits: DigitShaping::Persian,
+        },
+        _ => NumberFormat::PLAIN,
+    }
+}
+
+/// Returns the number format for the currently active RLF locale.
+pub fn current() -> NumberFormat {
+    rlf::with_locale(|locale| for_language(locale.language()))
+}
+
+/// Formats an integer using the currently active RLF locale.
+pub fn format_integer(value: i64) -> String {
+    format_integer_with(current(), value)
+}
+
+/// Formats an integer using the given number format.
+pub fn format_integer_with(format: NumberFormat, value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    format!("{sign}{}", format_digits(format, &digits))
+}
+
+/// Returns the value to substitute into a phrase for an argument, formatting
+/// numbers for the currently active RLF language.
+///
+/// Every argument to a parameterized phrase function in [strings] passes
+/// through this function, so callers pass game quantities as plain integers.
+/// Numbers which the language's format leaves unchanged stay [Value::Number],
+/// so every kind of variant selection applies to them. Other numbers become a
+/// phrase containing the formatted text, tagged with the number's plural
+/// category. Phrases can select plural variants for these, as in `{card:$n}`
+/// or a `one:` branch of `:match($n)`, but branches for exact values such as
+/// `1:` do not match them. Values which are not numbers are returned
+/// unchanged.
+pub fn substitute(value: Value) -> Value {
+    let Value::Number(number) = value else {
+        return value;
+    };
+    let language = rlf::with_locale(|locale| locale.language().to_string());
+    number_with(for_language(&language), &language, number)
+}
+
+/// Returns the value to substitute into a phrase for an integer, using the
+/// given number format and the plural rules of `language`.
+pub fn number_with(format: NumberFormat, language: &str, value: i64) -> Value {
+    let text = format_integer_with(format, value);
+    if text == value.to_string() {
+        return Value::Number(value);
+    }
+    let tags = plural_category_phrase(plural_category(language, value)).tags;
+    Value::Phrase(Phrase::builder().text(text).tags(tags).build())
+}
+
+/// Returns the CLDR plural category of an integer in a language with a
+/// registered number format, e.g. "few" for 3 in Russian.
+///
+/// Languages without registered plural rules use "one" for 1 and "other" for
+/// every other number.
+pub fn plural_category(language: &str, value: i64) -> &'static str {
+    let n = value.unsigned_abs();
+    let (n10, n100) = (n % 10, n % 100);
+    let is_million = n != 0 && n % 1_000_000 == 0;
+    match primary_subtag(language) {
+        "ru" | "uk" => match n10 {
+            1 if n100 != 11 => "one",
+            2..=4 if !(12..=14).contains(&n100) => "few",
+            _ => "many",
+        },
+        "pl" => match n10 {
+            _ if n == 1 => "one",
+            2..=4 if !(12..=14).contains(&n100) => "few",
+            _ => "many",
+        },
+        "cs" => match n {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other",
+        },
+        "ar" => match (n, n100) {
+            (0, _) => "zero",
+            (1, _) => "one",
+            (2, _) => "two",
+            (_, 3..=10) => "few",
+            (_, 11..=99) => "many",
+            _ => "other",
+        },
+        "fa" | "fr" | "pt" if n <= 1 => "one",
+        "es" | "fr" | "it" | "pt" if is_million => "many",
+        _ if n == 1 => "one",
+        _ => "other",
+    }
+}
+
+/// Spells out small numbers in rendered rules text as words using the
+/// currently active RLF locale, e.g. "Draw 2 cards." becomes "Draw two
+/// cards.".
+///
+/// Only whole numbers up to ten which appear outside rich text markup are
+/// rewritten, so symbol amounts such as `<color=#00838F>2●</color>` and
+/// modifiers such as "+2" keep their digits.
+pub fn spell_out_numbers(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut markup_depth = 0usize;
+    let mut index = 0;
+    while let Some(c) = text[index..].chars().next() {
+        let rest = &text[index..];
+        if c == '<' {
+            let tag = &rest[..rest.find('>').map_or(rest.len(), |end| end + 1)];
+            if tag.starts_with("</") {
+                markup_depth = markup_depth.saturating_sub(1);
+            } else if !tag.ends_with("/>") {
+                markup_depth += 1;
+            }
+            result.push_str(tag);
+            index += tag.len();
+            continue;
+        }
+        if c.is_ascii_digit() {
+            let digits = &rest[..rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len())];
+            let is_standalone = !result
+                .ends_with(|c: char| c.is_alphanumeric() || c == '+' || c == '-')
+                && !rest[digits.len()..].starts_with(char::is_alphanumeric);
+            match digits.parse::<u32>() {
+                Ok(number)
+                    if markup_depth == 0 && is_standalone && number <= MAX_SPELLED_OUT_NUMBER =>
+                {
+                    // The number selects a word rather than being substituted
+                    // as text, so it is passed to the phrase unformatted.
+                    result.push_str(&strings_generated::number_word(number).to_string());
+                }
+                _ => result.push_str(digits),
+            }
+            index += digits.len();
+            continue;
+        }
+        result.push(c);
+        index += c.len_utf8();
+    }
+    result
+}
+
+impl NumberFormat {
+    /// Format which leaves numbers unchanged.
+    pub const PLAIN: NumberFormat = NumberFormat {
+        grouping_separator: None,
+        minimum_grouping_digits: 0,
+        digits: DigitShaping::Latin,
+    };
+
+    /// Returns true if this format never changes its input.
+    pub fn is_plain(&self) -> bool {
+        self.grouping_separator.is_none() && self.digits == DigitShaping::Latin
+    }
+}
+
+/// Returns the primary subtag of a language tag, e.g. "ar" for "ar-EG".
+fn primary_subtag(language: &str) -> &str {
+    language.split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Returns a phrase tagged with the given plural category.
+fn plural_category_phrase(category: &str) -> Phrase {
+    match category {
+        "zero" => strings::plural_zero(),
+        "one" => strings::plural_one(),
+        "two" => strings::plural_two(),
+        "few" => strings::plural_few(),
+        "many" => strings::plural_many(),
+        _ => strings::plural_other(),
+    }
+}
+
+/// Formats a string of ASCII digits.
+fn format_digits(format: NumberFormat, digits: &str) -> String {
+    let group =
+        format.grouping_separator.filter(|_| digits.len() >= format.minimum_grouping_digits);
+    let mut result = String::with_capacity(digits.len() * 2);
+    for (i, c) in digits.chars().enumerate() {
+        if let Some(separator) = group
+            && i > 0
+            && (digits.len() - i) % 3 == 0
+        {
+            result.push(separator);
+        }
+        result.push(shape_digit(format.digits, c));
+    }
+    result
+}
+
+fn shape_digit(shaping: DigitShaping, c: char) -> char {
+    let offset = c as u32 - '0' as u32;
+    let base = match shaping {
+        DigitShaping::Latin => return c,
+        DigitShaping::EasternArabic => 0x0660,
+        DigitShaping::Persian => 0x06F0,
+    };
+    char::from_u32(base + offset).unwrap_or(c)
+}
@@ -0,0 +1,79 @@
+/// Every [PaletteColor].
+pub const ALL_COLORS: [PaletteColor; 5] = [
+    PaletteColor::Energy,
+    PaletteColor::Points,
+    PaletteColor::Keyword,
+    PaletteColor::Subtype,
+    PaletteColor::Annotation,
+];
+
+/// Set of colors used to highlight game concepts in rich text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Palette {
+    /// Colors used in phrase definitions.
+    #[default]
+    Standard,
+    /// Colors chosen to remain distinguishable under common forms of color
+    /// vision deficiency, based on the Okabe-Ito palette.
+    Colorblind,
+}
+
+/// A color used for a category of game concept in rich text.
+///
+/// Phrase definitions in `strings.rlf.rs` and locale files may only use the
+/// [Palette::Standard] value of one of these colors, which are rewritten to
+/// the user's palette when text is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteColor {
+    /// Energy amounts and symbols.
+    Energy,
+    /// Points amounts and symbols, and figments.
+    Points,
+    /// Keywords such as dissolve and reclaim.
+    Keyword,
+    /// Character subtypes.
+    Subtype,
+    /// Annotations appended to rules text, such as "(Reclaimed)".
+    Annotation,
+}
+
+/// Returns the palette color whose [Palette::Standard] value is `hex`, if
+/// any.
+pub fn from_standard_hex(hex: &str) -> Option<PaletteColor> {
+    ALL_COLORS.into_iter().find(|color| color.hex(Palette::Standard).eq_ignore_ascii_case(hex))
+}
+
+/// Rewrites the color of every rich text color tag in `text` from the
+/// standard palette to `palette`.
+///
+/// Tags using colors which are not part of the palette are left unchanged.
+pub fn apply(palette: Palette, text: &str) -> String {
+    if palette == Palette::Standard {
+        return text.to_string();
+    }
+
+    ALL_COLORS.into_iter().fold(text.to_string(), |result, color| {
+        result.replace(
+            &format!("<color={}>", color.hex(Palette::Standard)),
+            &format!("<color={}>", color.hex(palette)),
+        )
+    })
+}
+
+impl PaletteColor {
+    /// Returns the hex value of this color in a palette, e.g. "#00838F".
+    pub fn hex(self, palette: Palette) -> &'static str {
+        match (self, palette) {
+            (PaletteColor::Energy, Palette::Standard) => "#00838F",
+            (PaletteColor::Energy, Palette::Colorblind) => "#0072B2",
+            (PaletteColor::Points, Palette::Standard) => "#F57F17",
+            (PaletteColor::Points, Palette::Colorblind) => "#E69F00",
+            (PaletteColor::Keyword, Palette::Standard) => "#AA00FF",
+            (PaletteColor::Keyword, Palette::Colorblind) => "#CC79A7",
+            (PaletteColor::Subtype, Palette::Standard) => "#2E7D32",
+            (PaletteColor::Subtype, Palette::Colorblind) => "#009E73",
+            (PaletteColor::Annotation, Palette::Standard) => "#0000FF",
+            (PaletteColor::Annotation, Palette::Colorblind) => "#56B4E9",
+        }
+    }
+}
@@ -18,6 +18,8 @@ rlf::rlf! {
     energy($e) = "<color=#00838F>{$e}\u{25CF}</color>";
     // Points amount with colored symbol (e.g., "3[points]").
     points($p) = "<color=#F57F17>{$p}\u{234F}</color>";
+    // Variable energy amount X with colored symbol (e.g., "X[energy]").
+    x = "<color=#00838F>X\u{25CF}</color>";
     // Maximum energy display (e.g., "5 maximum [energy]").
     maximum_energy($max) = "{$max} maximum {energy_symbol}";
 
@@ -45,6 +47,8 @@ rlf::rlf! {
     materialized_keyword_name = "Materialized";
     // Bare keyword name for Dissolved (used in multi-keyword fallback).
     dissolved_keyword_name = "Dissolved";
+    // Separator between keyword names in the multi-keyword fallback.
+    trigger_keyword_separator = ", ";
 
     // =========================================================================
     // Keywords
@@ -82,6 +86,8 @@ rlf::rlf! {
     fast = "<b>\u{21AF}fast</b>";
     // Reclaim with energy cost.
     reclaim_for_cost($r) = "<color=#AA00FF>reclaim</color> <color=#00838F>{$r}\u{25CF}</color>";
+    // Resolve ability word.
+    resolve = "<color=#AA00FF>resolve</color>";
 
     // =========================================================================
     // Modal card formatting
@@ -102,6 +108,14 @@ rlf::rlf! {
     cards($n) = :match($n) { 1: "a card", *other: "{$n} cards" };
     // Top N cards of deck (e.g., "top card" or "top 3 cards").
     top_n_cards($n) = :match($n) { 1: "top card", *other: "top {$n} {card:$n}" };
+    // Empty phrases tagged with each CLDR plural category. Numbers substituted
+    // as formatted text carry these tags (see number_format::number).
+    plural_zero = :zero "";
+    plural_one = :one "";
+    plural_two = :two "";
+    plural_few = :few "";
+    plural_many = :many "";
+    plural_other = :other "";
 
     // =========================================================================
     // Spark and generic count
@@ -246,6 +260,11 @@ rlf::rlf! {
 
     // Copy count with article (e.g., "a copy" or "two copies").
     copies($n) = :match($n) { 1: "a copy", *other: "{text_number($n)} copies" };
+    // Spark counter count with article (e.g., "a +1 spark counter" or "two +1 spark counters").
+    spark_counters($n) = :match($n) {
+        1: "a +1 spark counter",
+        *other: "{text_number($n)} +1 spark counters",
+    };
 
     // =========================================================================
     // Random character targeting
@@ -307,6 +326,8 @@ rlf::rlf! {
     prompt_pay_or_decline_description = "Choose whether to pay the cost.";
     // Prompt to choose an activated ability.
     prompt_choose_activated_ability_description = "Choose an activated ability.";
+    // Prompt to choose whether to perform an optional effect.
+    prompt_optional_effect_description = "Choose whether to perform this effect.";
 
     // =========================================================================
     // Buttons
@@ -316,6 +337,8 @@ rlf::rlf! {
     dev_menu_button = "{bug_icon} Dev";
     // Decline to take the action associated with a prompt.
     decline_prompt_button = "Decline";
+    // Accept the action associated with a prompt.
+    accept_prompt_button = "Accept";
     // Choose to pay energy to take a prompt action.
     pay_energy_prompt_button($e) = "Spend {energy($e)}";
     // Confirm the amount of energy to pay as an additional cost.
@@ -344,6 +367,10 @@ rlf::rlf! {
     show_battlefield_button = "{eye_icon}";
     // Hide the battlefield.
     hide_battlefield_button = "{eye_slash_icon}";
+    // Request a suggested action.
+    hint_button = "Hint";
+    // Hint button label while a suggested action is being searched for.
+    hint_button_searching = "Thinking...";
 
     // =========================================================================
     // Card rules text annotations
@@ -355,6 +382,8 @@ rlf::rlf! {
     card_rules_text_reclaimed = "(Reclaimed)";
     // Anchored annotation.
     card_rules_text_anchored = "(Anchored)";
+    // Bold blue formatting for an annotation appended to rules text.
+    card_rules_text_annotation($a) = "<b><color=#0000FF>{$a}</color></b>";
 
     // =========================================================================
     // Card naming
@@ -365,6 +394,222 @@ rlf::rlf! {
     // Card name for a character ability.
     character_ability_card_name($character_name) = "{$character_name} Ability";
 
+    // =========================================================================
+    // Stack groups
+    // =========================================================================
+
+    // Label for triggered abilities fired by an event involving a card.
+    stack_group_triggered_by_card($name) = "Triggered by {$name}";
+    // Label for triggered abilities fired at the end of a turn.
+    stack_group_end_of_turn = "End of turn";
+    // Label for triggered abilities fired at the start of a turn.
+    stack_group_start_of_turn = "Start of turn";
+    // Label for triggered abilities fired during the judgment phase.
+    stack_group_judgment = "{judgment_phase_name}";
+    // Label for triggered abilities fired by any other event.
+    stack_group_triggered = "Triggered together";
+
+    // =========================================================================
+    // Hints
+    // =========================================================================
+
+    // Suggested action with the share of simulated games it won.
+    hint_rationale($action, $percent) = "Suggested: {$action}, winning {$percent}% of simulated games.";
+    // Suggested action to play a card.
+    hint_play_card($name) = "play {$name}";
+    // Suggested action to activate a character's ability.
+    hint_activate_ability($name) = "activate {$name}";
+    // Suggested action to target a card.
+    hint_select_target($name) = "target {$name}";
+    // Suggested action to choose a prompt option.
+    hint_choose_option($option) = "choose {$option}";
+    // Suggested action to pay energy as an additional cost.
+    hint_spend_energy($e) = "spend {energy($e)}";
+    // Suggested action to pass priority.
+    hint_pass_priority = "pass";
+    // Suggested action to end the turn.
+    hint_end_turn = "end your turn";
+    // Suggested action to start the next turn.
+    hint_start_next_turn = "start the next turn";
+    // Suggested action for any other decision.
+    hint_make_selection = "make a selection";
+
+    // =========================================================================
+    // Tutorial
+    // =========================================================================
+
+    // Tutorial message shown the first time the user can play a card.
+    tutorial_play_card = "You can play a card from your hand by spending the {energy_symbol} it costs.";
+    // Tutorial message shown the first time the user has nothing left to play.
+    tutorial_end_turn = "You have nothing left to play. End your turn to let your opponent act.";
+    // Tutorial message shown the first time the user can respond with a fast card.
+    tutorial_fast_card = "Fast cards can be played in response to your opponent, even during their turn.";
+
+    // =========================================================================
+    // Notifications
+    // =========================================================================
+
+    // Notification shown when a card drawn with a full hand becomes energy.
+    notification_draw_converted_to_energy = "Draw converted to {energy_symbol}: hand full";
+    // Notification shown when the opponent reconnects to a multiplayer battle.
+    notification_opponent_reconnected = "Opponent reconnected";
+
+    // =========================================================================
+    // Card inspection
+    // =========================================================================
+
+    // Modified spark value of a character compared to its printed value.
+    card_detail_spark_modifier($spark, $base) = "Spark {$spark} (printed {$base})";
+    // Modified energy cost of a card compared to its printed value.
+    card_detail_cost_modifier($e, $base) = "Cost {energy($e)} (printed {energy($base)})";
+    // Modifier for a card which will be banished when it leaves play.
+    card_detail_banish_when_leaves_play = "Banished if it leaves play";
+    // Temporary effect preventing a character from being dissolved.
+    card_detail_prevent_dissolved = "Cannot be dissolved";
+    // Duration of an effect which ends at the end of the turn.
+    card_detail_duration_this_turn = "Until end of turn";
+    // Duration of an effect which ends at the user's next main phase.
+    card_detail_duration_until_your_next_main = "Until your next main phase";
+    // Duration of an effect which ends at the opponent's next main phase.
+    card_detail_duration_until_enemy_next_main = "Until your opponent's next main phase";
+    // Duration of an effect which ends at the start of the user's next turn.
+    card_detail_duration_until_your_next_turn = "Until your next turn";
+    // Duration of an effect which ends at the start of the opponent's next turn.
+    card_detail_duration_until_enemy_next_turn = "Until your opponent's next turn";
+    // Duration of an effect which lasts while its source remains in play.
+    card_detail_duration_while_in_play = "While its source remains in play";
+
+    // =========================================================================
+    // Battle log
+    // =========================================================================
+
+    // Title for the panel displaying the battle log.
+    battle_log_panel_title = "Battle Log";
+    // Message shown in the battle log before any actions have been taken.
+    battle_log_empty = "Nothing has happened yet.";
+    // Heading for the actions taken during a turn.
+    battle_log_turn_header($turn) = "<b>Turn {$turn}</b>";
+    // Name of the user in battle log entries.
+    battle_log_user_name = "You";
+    // Name of the user's opponent in battle log entries.
+    battle_log_enemy_name = "Opponent";
+    // Card name in battle log entries.
+    battle_log_card_name($name) = "<b>{$name}</b>";
+    // Card which is hidden from the user, in battle log entries.
+    battle_log_hidden_card = "a hidden card";
+    // Log entry for playing a card.
+    battle_log_played_card($player, $card) = "{$player} played {$card}.";
+    // Log entry for playing a card from the void.
+    battle_log_played_card_from_void($player, $card) = "{$player} played {$card} from the void.";
+    // Log entry for activating a character's ability.
+    battle_log_activated_ability($player, $card) = "{$player} activated {$card}.";
+    // Log entry for choosing a card as a target.
+    battle_log_targeted($player, $card) = "{$player} targeted {$card}.";
+    // Log entry for paying energy as an additional cost.
+    battle_log_paid_energy($player, $e) = "{$player} paid {energy($e)}.";
+    // Log entry for ending the turn.
+    battle_log_ended_turn($player) = "{$player} ended the turn.";
+
+    // =========================================================================
+    // Void browser
+    // =========================================================================
+
+    // Title for the panel listing the cards in the user's void.
+    void_browser_user_title = "Your Void";
+    // Title for the panel listing the cards in the opponent's void.
+    void_browser_enemy_title = "Opponent's Void";
+    // Message shown in the void browser when no cards match the filter.
+    void_browser_empty = "No matching cards.";
+    // Filter showing every card in the void browser.
+    void_browser_filter_all = "All";
+    // Filter showing only characters in the void browser.
+    void_browser_filter_characters = "Characters";
+    // Filter showing only events in the void browser.
+    void_browser_filter_events = "Events";
+    // Filter showing only cards which can be played from the void.
+    void_browser_filter_reclaimable = "Reclaimable";
+    // Sort the void browser by the order cards were put into the void.
+    void_browser_sort_recent = "Recent";
+    // Sort the void browser by energy cost.
+    void_browser_sort_cost = "Cost";
+    // Sort the void browser by card name.
+    void_browser_sort_name = "Name";
+    // Entry for a card in the void browser.
+    void_browser_entry($name, $e) = "{$name} {energy($e)}";
+
+    // =========================================================================
+    // Deck list
+    // =========================================================================
+
+    // Title for the panel listing the cards remaining in the user's deck.
+    deck_list_panel_title = "Your Deck";
+    // Heading for the cards the user knows to be on top of their deck.
+    deck_list_top_of_deck_header = "<b>Top of Deck</b>";
+    // Entry for a known card on top of the deck, by position from the top.
+    deck_list_top_of_deck_entry($position, $name) = "{$position}. {$name}";
+    // Heading for the cards remaining in the deck.
+    deck_list_contents_header($n) = "<b>Remaining Cards ({$n})</b>";
+    // Message shown in the deck list when no cards remain in the deck.
+    deck_list_empty = "Your deck is empty.";
+    // Entry for the number of copies of a card remaining in the deck.
+    deck_list_entry($count, $name) = "{$count}x {$name}";
+
+    // =========================================================================
+    // Emotes
+    // =========================================================================
+
+    // Title for the panel listing emotes the user can send.
+    emote_panel_title = "Emotes";
+    // Emote greeting the opponent.
+    emote_greetings = "Hello!";
+    // Emote complimenting the opponent's play.
+    emote_well_played = "Well played!";
+    // Emote thanking the opponent.
+    emote_thanks = "Thanks!";
+    // Emote acknowledging a mistake.
+    emote_oops = "Oops!";
+    // Emote indicating the player is thinking about their next move.
+    emote_thinking = "Hmm...";
+    // Emote sent at the end of a battle.
+    emote_good_game = "Good game!";
+    // Button to hide emotes sent by the opponent.
+    emote_mute_opponent = "Mute Opponent";
+    // Button to show emotes sent by the opponent again.
+    emote_unmute_opponent = "Unmute Opponent";
+
+    // =========================================================================
+    // Match history
+    // =========================================================================
+
+    // Title for the panel listing the user's recently completed battles.
+    match_history_panel_title = "Match History";
+    // Message shown in the match history panel before any battles are played.
+    match_history_empty = "No battles completed yet.";
+    // Result of a battle the user won.
+    match_history_victory = "Victory";
+    // Result of a battle the user lost.
+    match_history_defeat = "Defeat";
+    // Result of a battle with no winner.
+    match_history_draw = "Draw";
+    // Name for an AI opponent in the match history.
+    match_history_ai_opponent = "AI";
+    // Name for another user as the opponent in the match history.
+    match_history_user_opponent = "Player";
+    // Number of turns a battle lasted.
+    match_history_turns($n) = :match($n) { 1: "1 turn", *other: "{$n} turns" };
+    // Entry for a completed battle in the match history.
+    match_history_entry($result, $opponent, $deck, $opponent_deck, $turns) =
+        "<b>{$result}</b> vs {$opponent}: {$deck} vs {$opponent_deck}, {match_history_turns($turns)}";
+
+    // =========================================================================
+    // Cinematics
+    // =========================================================================
+
+    // Name of the user in battle cinematics.
+    cinematic_user_name = "You";
+    // Name of the user's opponent in battle cinematics.
+    cinematic_enemy_name = "Opponent";
+
     // =========================================================================
     // Limit warning messages
     // =========================================================================
@@ -419,6 +664,69 @@ rlf::rlf! {
     // Help text for reclaim with energy cost.
     help_text_reclaim_with_cost($e) = "{@cap reclaim} {energy($e)}: You may play this card from your void for {energy($e)}, then banish it.";
 
+    // =========================================================================
+    // Reminder text
+    // =========================================================================
+
+    // Reminder text for discover.
+    reminder_text_discover = "look at 3 matching cards from your deck and put one into your hand";
+    // Reminder text for dissolve.
+    reminder_text_dissolve = "send a character to the void";
+    // Reminder text for foresee with card count.
+    reminder_text_foresee($n) = :match($n) {
+        1: "look at the top card of your deck. You may put it into your void",
+        *other: "look at the top {$n} cards of your deck. You may put them into your void or put them back in any order",
+    };
+    // Reminder text for kindle with spark amount.
+    reminder_text_kindle($k) = "add {$k} spark to your character with the highest spark";
+    // Reminder text for prevent.
+    reminder_text_prevent = "send a card to the void in response to it being played";
+    // Reminder text for reclaim.
+    reminder_text_reclaim = "you may play this card from your void, then banish it when it leaves play";
+    // Number spelled out as a word in verbose rules text.
+    number_word($n) = :match($n) {
+        0: "zero",
+        1: "one",
+        2: "two",
+        3: "three",
+        4: "four",
+        5: "five",
+        6: "six",
+        7: "seven",
+        8: "eight",
+        9: "nine",
+        10: "ten",
+        *other: "{$n}",
+    };
+
+    // =========================================================================
+    // Unplayable card reasons
+    // =========================================================================
+
+    // Reason a card in hand cannot be played at this point in the turn.
+    unplayable_wrong_timing = "Cannot be played right now.";
+    // Reason a non-fast card in hand cannot be played in response.
+    unplayable_not_fast = "Only {fast} cards can be played right now.";
+    // Reason a card in hand costs more energy than is available.
+    unplayable_insufficient_energy($e) = "Requires {energy($e)} to play.";
+    // Reason a card in hand has no valid targets.
+    unplayable_no_legal_targets = "No valid targets.";
+    // Reason a card in hand has additional costs which cannot be paid.
+    unplayable_additional_costs = "Additional costs cannot be paid.";
+    // Reason none of a modal card's choices can be selected.
+    unplayable_no_modal_choices = "No choices can be selected.";
+
+    // =========================================================================
+    // Collection unlock sources
+    // =========================================================================
+
+    // Card was part of the player's starting collection.
+    unlocked_starting_collection = "Starting collection";
+    // Card was granted as a quest reward.
+    unlocked_quest_reward = "Quest reward";
+    // Card was granted via a debug action.
+    unlocked_debug = "Debug";
+
     // =========================================================================
     // Token types
     // =========================================================================
@@ -472,10 +780,16 @@ rlf::rlf! {
 
     // Abandon a count of allies.
     abandon_count_allies($a) = "abandon {count_allies($a)}";
+    // Abandon a count of allies of a character subtype.
+    abandon_count_allied_subtype($a, $t) = "abandon {count_allied_subtype($a, $t)}";
+    // Abandon a count of targets (uses plural variant).
+    abandon_count_target($n, $target) = :from($target) "abandon {$n} {$target:other}";
     // Discard a count of cards.
     discard_cards_cost($d) = "discard {cards($d)}";
     // Energy cost value.
     energy_cost_value($e) = "{energy($e)}";
+    // Variable X energy cost value.
+    x_energy_cost = "{x}";
     // Lose maximum energy cost.
     lose_max_energy_cost($m) = "lose {maximum_energy($m)}";
     // Banish your entire void.
@@ -497,6 +811,7 @@ rlf::rlf! {
 
     // End of your turn trigger.
     at_end_of_your_turn_trigger = "at the end of your turn, ";
+    at_start_of_your_turn_trigger = "at the start of your turn, ";
     // Empty deck trigger.
     when_deck_empty_trigger = "when you have no cards in your deck, ";
     // Gain energy trigger.
@@ -591,6 +906,8 @@ rlf::rlf! {
 
     // Draw cards effect fragment (no trailing period).
     draw_cards_effect($c) = "draw {cards($c)}";
+    // Draw X cards effect fragment (no trailing period).
+    draw_x_cards = "draw X cards";
     // Discard cards effect fragment (no trailing period).
     discard_cards_effect($d) = "discard {cards($d)}";
     // Gain energy effect fragment (no trailing period).
@@ -666,11 +983,13 @@ rlf::rlf! {
     // Copy the next card you play this turn.
     copy_next_played($target, $times) = :from($target)
         "copy the next {$target} you play {this_turn_times($times)}";
-    // Create a trigger until end of turn with keyword trigger.
-    create_trigger_until_end_of_turn_keyword($trig, $eff) =
-        "until end of turn, {$trig} {@cap $eff}";
-    // Create a trigger until end of turn.
-    create_trigger_until_end_of_turn($trig, $eff) = "until end of turn, {$trig}{$eff}";
+    // Create a trigger for a duration with keyword trigger.
+    create_trigger_for_duration_keyword($duration, $trig, $eff) =
+        "{$duration}, {$trig} {@cap $eff}";
+    // Create a trigger for a duration.
+    create_trigger_for_duration($duration, $trig, $eff) = "{$duration}, {$trig}{$eff}";
+    // Create a static ability for a duration.
+    create_static_ability_for_duration($ability, $duration) = "{$ability} {$duration}";
     // Dissolve a target.
     dissolve_target($target) = :from($target) "{dissolve} {$target}";
     // Banish a target.
@@ -678,9 +997,8 @@ rlf::rlf! {
     // Banish a target until another leaves play.
     banish_until_leaves($target, $until) = :from($target)
         "{banish} {$target} until {$until} leaves play";
-    // Banish a target until your next main phase.
-    banish_until_next_main($target) = :from($target)
-        "{banish} {$target} until your next main phase";
+    // Banish a target for a duration.
+    banish_for_duration($target, $duration) = :from($target) "{banish} {$target} {$duration}";
     // Banish a target when it leaves play.
     banish_when_leaves_play($target) = :from($target) "{banish} {$target} when it leaves play";
     // Gain control of a target.
@@ -715,9 +1033,9 @@ rlf::rlf! {
         "put {$target} on top of the opponent's deck";
     // Copy a target.
     copy_target($target) = :from($target) "copy {$target}";
-    // Disable activated abilities of a target while this is in play.
-    disable_activated_abilities($target) = :from($target)
-        "disable the activated abilities of {$target} while this character is in play";
+    // Disable activated abilities of a target for a duration.
+    disable_activated_abilities($target, $duration) = :from($target)
+        "disable the activated abilities of {$target} {$duration}";
     // Draw a matching card from your deck.
     draw_matching_from_deck($target) = :from($target) "draw {$target} from your deck";
     // Abandon a target and gain energy for its spark.
@@ -727,9 +1045,9 @@ rlf::rlf! {
     abandon_at_end_of_turn($target) = :from($target) "abandon {$target} at end of turn";
     // Each player abandons a matching card.
     each_player_abandons($target) = :from($target) "each player abandons {$target}";
-    // Target cannot be dissolved this turn.
-    prevent_dissolve_this_turn($target) = :from($target)
-        "{$target} cannot be {dissolved} this turn";
+    // Target cannot be dissolved for a duration.
+    prevent_dissolve($target, $duration) = :from($target)
+        "{$target} cannot be {dissolved} {$duration}";
     // Prevent a played target.
     prevent_played_target($target) = :from($target) "{prevent} a played {$target}";
     // Prevent a played target unless opponent pays cost.
@@ -740,6 +1058,10 @@ rlf::rlf! {
         "gain {energy_symbol} equal to {$target}'s cost";
     // Target gains spark.
     gains_spark($target, $s) = :from($target) "{$target} gains +{$s} spark";
+    // Put spark counters on a target.
+    put_spark_counters($n, $target) = "put {spark_counters($n)} on {$target}";
+    // Remove spark counters from a target.
+    remove_spark_counters($n, $target) = "remove {spark_counters($n)} from {$target}";
     // Opponent pays a cost.
     opponent_pays_cost($cost) = "the opponent pays {$cost}";
     // Pay a cost.
@@ -759,6 +1081,21 @@ rlf::rlf! {
     // No effect.
     no_effect = "";
 
+    // =========================================================================
+    // Duration phrases
+    // =========================================================================
+
+    // Effect lasts until the end of the current turn, placed after the effect.
+    duration_this_turn = "this turn";
+    // Effect lasts until the end of the current turn, placed before the effect.
+    duration_until_end_of_turn = "until end of turn";
+    // Effect lasts until the start of its controller's next main phase.
+    duration_until_your_next_main = "until your next main phase";
+    // Effect lasts until the start of its controller's next turn.
+    duration_until_your_next_turn = "until your next turn";
+    // Effect lasts while its source character remains in play.
+    duration_while_in_play = "while this character is in play";
+
     // =========================================================================
     // Structural phrases
     // =========================================================================
@@ -767,8 +1104,6 @@ rlf::rlf! {
     you_may_prefix = "you may ";
     // Connects a cost to its effect in activated abilities (e.g., "{cost} to {effect}").
     cost_to_connector($cost) = "{$cost} to ";
-    // Prefix for effects that last until the end of the current turn.
-    until_end_of_turn_prefix = "Until end of turn, ";
     // Prefix for abilities that can only be used once per turn.
     once_per_turn_prefix = "Once per turn, ";
     // Suffix for abilities that can only be used once per turn.
@@ -944,6 +1279,19 @@ rlf::rlf! {
     non_subtype_enemy($s) = :a "non-{subtype($s)} enemy";
     // Enemies that are not a subtype (plural).
     non_subtype_enemy_plural($s) = :from($s) "non-{subtype($s):other} enemies";
+    // Either of two predicate nouns, e.g. "Warrior or Mage".
+    predicate_or($a, $b) = :from($a) {
+        *one: "{$a} or {$b}",
+        other: "{$a:other} or {$b:other}",
+    };
+    // Allied predicate noun.
+    allied_predicate($p) = :an "allied {$p}";
+    // Allied predicate plural noun.
+    allied_predicate_plural($p) = :from($p) "allied {$p:other}";
+    // Enemy predicate noun.
+    enemy_predicate($p) = :an "enemy {$p}";
+    // Enemy predicate plural noun.
+    enemy_predicate_plural($p) = :from($p) "enemy {$p:other}";
 
     // =========================================================================
     // For-each predicate phrases
@@ -1016,6 +1364,8 @@ rlf::rlf! {
     card_predicate_returned($base) = :from($base) "{$base} returned";
     // Energy spent for count expressions.
     energy_spent = "{energy_symbol} spent";
+    // Variable X energy paid for count expressions.
+    x_amount = "X";
     // Card predicate played this turn for count expressions.
     card_predicate_played_this_turn($base) = :from($base) "{$base} you have played this turn";
     // Card predicate drawn this turn for count expressions.
@@ -1028,6 +1378,8 @@ rlf::rlf! {
     card_predicate_abandoned_this_turn($base) = :from($base) "{$base} abandoned this turn";
     // Generic card predicate abandoned for count expressions.
     card_predicate_abandoned($base) = :from($base) "{$base} abandoned";
+    // Spark counter on a target for count expressions.
+    spark_counter_on($target) = "+1 spark counter on {$target}";
 
     // =========================================================================
     // For-each effect phrases
@@ -1042,9 +1394,9 @@ rlf::rlf! {
     // Target gains spark for each matching quantity.
     gains_spark_for_each($target, $s, $quantity) = :from($target)
         "{$target} gains +{$s} spark for each {$quantity}";
-    // Target gains spark until next main phase for each matching predicate.
-    gains_spark_until_next_main_for_each($target, $s, $for_each) = :from($target)
-        "{$target} gains +{$s} spark until your next main phase for each {$for_each}";
+    // Target gains spark for a duration for each matching predicate.
+    gains_spark_for_duration_for_each($target, $s, $duration, $for_each) = :from($target)
+        "{$target} gains +{$s} spark {$duration} for each {$for_each}";
     // Each matching gains spark equal to count of another group.
     each_gains_spark_equal_to($each, $count_of) = :from($each)
         "each {$each} gains spark equal to the number of {$count_of:other}";
@@ -1103,6 +1455,8 @@ rlf::rlf! {
     // Trigger judgment ability of each matching target.
     trigger_judgment_of_each($target) = :from($target)
         "trigger the {Judgment} ability of each {$target}";
+    // Trigger the ability of this card named by an ability word.
+    trigger_this_cards_ability($w) = "trigger this card's {@cap $w} ability";
 
     // =========================================================================
     // Materialize figment quantity phrases
@@ -1162,25 +1516,26 @@ rlf::rlf! {
     target_gains_reclaim_equal_cost($target) = :from($target)
         "{$target} gains {reclaim} equal to its cost";
 
-    // It gains reclaim with cost this turn.
+    // It gains reclaim with cost for a duration.
     // Accepts antecedent $target for gendered pronoun agreement in translations.
-    it_gains_reclaim_for_cost_this_turn($target, $r) = "it gains {reclaim_for_cost($r)} this turn";
-    // It gains reclaim equal to its cost this turn.
+    it_gains_reclaim_for_cost_with_duration($target, $r, $duration) =
+        "it gains {reclaim_for_cost($r)} {$duration}";
+    // It gains reclaim equal to its cost for a duration.
     // Accepts antecedent $target for gendered pronoun agreement in translations.
-    it_gains_reclaim_equal_cost_this_turn($target) =
-        "it gains {reclaim} equal to its cost this turn";
-    // This card gains reclaim with cost this turn.
-    this_card_gains_reclaim_for_cost_this_turn($r) =
-        "this card gains {reclaim_for_cost($r)} this turn";
-    // This card gains reclaim equal to its cost this turn.
-    this_card_gains_reclaim_equal_cost_this_turn =
-        "this card gains {reclaim} equal to its cost this turn";
-    // Target gains reclaim with cost this turn.
-    target_gains_reclaim_for_cost_this_turn($target, $r) = :from($target)
-        "{$target} gains {reclaim_for_cost($r)} this turn";
-    // Target gains reclaim equal to its cost this turn.
-    target_gains_reclaim_equal_cost_this_turn($target) = :from($target)
-        "{$target} gains {reclaim} equal to its cost this turn";
+    it_gains_reclaim_equal_cost_with_duration($target, $duration) =
+        "it gains {reclaim} equal to its cost {$duration}";
+    // This card gains reclaim with cost for a duration.
+    this_card_gains_reclaim_for_cost_with_duration($r, $duration) =
+        "this card gains {reclaim_for_cost($r)} {$duration}";
+    // This card gains reclaim equal to its cost for a duration.
+    this_card_gains_reclaim_equal_cost_with_duration($duration) =
+        "this card gains {reclaim} equal to its cost {$duration}";
+    // Target gains reclaim with cost for a duration.
+    target_gains_reclaim_for_cost_with_duration($target, $r, $duration) = :from($target)
+        "{$target} gains {reclaim_for_cost($r)} {$duration}";
+    // Target gains reclaim equal to its cost for a duration.
+    target_gains_reclaim_equal_cost_with_duration($target, $duration) = :from($target)
+        "{$target} gains {reclaim} equal to its cost {$duration}";
 
     // =========================================================================
     // Void collection subject phrases
@@ -1219,18 +1574,18 @@ rlf::rlf! {
     // Plural subject gains reclaim equal to their cost.
     void_gains_reclaim_equal_cost_plural($subject) = :from($subject)
         "{$subject} gain {reclaim} equal to their cost";
-    // Singular subject gains reclaim for a specific cost this turn.
-    void_gains_reclaim_for_cost_singular_this_turn($subject, $r) = :from($subject)
-        "{$subject} gains {reclaim_for_cost($r)} this turn";
-    // Singular subject gains reclaim equal to its cost this turn.
-    void_gains_reclaim_equal_cost_singular_this_turn($subject) = :from($subject)
-        "{$subject} gains {reclaim} equal to its cost this turn";
-    // Plural subject gains reclaim for a specific cost this turn.
-    void_gains_reclaim_for_cost_plural_this_turn($subject, $r) = :from($subject)
-        "{$subject} gain {reclaim_for_cost($r)} this turn";
-    // Plural subject gains reclaim equal to their cost this turn.
-    void_gains_reclaim_equal_cost_plural_this_turn($subject) = :from($subject)
-        "{$subject} gain {reclaim} equal to their cost this turn";
+    // Singular subject gains reclaim for a specific cost for a duration.
+    void_gains_reclaim_for_cost_singular_with_duration($subject, $r, $duration) = :from($subject)
+        "{$subject} gains {reclaim_for_cost($r)} {$duration}";
+    // Singular subject gains reclaim equal to its cost for a duration.
+    void_gains_reclaim_equal_cost_singular_with_duration($subject, $duration) = :from($subject)
+        "{$subject} gains {reclaim} equal to its cost {$duration}";
+    // Plural subject gains reclaim for a specific cost for a duration.
+    void_gains_reclaim_for_cost_plural_with_duration($subject, $r, $duration) = :from($subject)
+        "{$subject} gain {reclaim_for_cost($r)} {$duration}";
+    // Plural subject gains reclaim equal to their cost for a duration.
+    void_gains_reclaim_equal_cost_plural_with_duration($subject, $duration) = :from($subject)
+        "{$subject} gain {reclaim} equal to their cost {$duration}";
 
     // =========================================================================
     // Static ability serializer phrases
@@ -1266,10 +1621,10 @@ rlf::rlf! {
     has_all_character_types = "has all character types";
     // Multiply the amount of energy gained from card effects.
     multiply_energy_gain($n) =
-        "{multiply_by($n)} the amount of {energy_symbol} you gain from card effects this turn";
+        "{multiply_by($n)} the amount of {energy_symbol} you gain from card effects";
     // Multiply the number of cards drawn from card effects.
     multiply_card_draw($n) =
-        "{multiply_by($n)} the number of cards you draw from card effects this turn";
+        "{multiply_by($n)} the number of cards you draw from card effects";
     // Once per turn, play a matching card from your void.
     once_per_turn_play_from_void($matching) = :from($matching)
         "once per turn, you may play {$matching} from your void";
@@ -1330,6 +1685,10 @@ rlf::rlf! {
     capitalized_sentence($s) = "{@cap $s}";
     // Reclaim with dash-separated cost text.
     reclaim_with_cost($cost) = "{Reclaim} -- {$cost}";
+    // Ability prefixed with a dash-separated ability word.
+    ability_word_ability($w, $ability) = "{@cap $w} -- {$ability}";
+    // Ability followed by parenthesized reminder text.
+    ability_with_reminder_text($ability, $reminder) = "{$ability} ({@cap $reminder}.)";
 
     // =========================================================================
     // Ability assembly phrases
@@ -1353,6 +1712,11 @@ rlf::rlf! {
     // Fast activated ability with once-per-turn suffix.
     fast_activated_ability_once_per_turn($c, $eff) =
         "{fast_prefix}{$c}{once_per_turn_suffix}{cost_effect_separator}{@cap $eff}";
+    // Cost line of a block layout activated ability, followed by one effect
+    // paragraph line per paragraph.
+    activated_cost_line($c) = "{$c}:";
+    // Single effect paragraph line in a block layout activated ability.
+    effect_paragraph_line($eff) = "> {@cap $eff}";
     // Activated ability cost separator for joining multiple costs.
     activated_cost_separator = ", ";
     // Separator between non-energy costs in activated abilities.
@@ -1374,6 +1738,9 @@ rlf::rlf! {
     optional_effect_body($body) = "{you_may_prefix}{$body}";
     // Optional prefix with cost and effect body: "you may {cost} to {body}".
     optional_cost_effect_body($cost, $body) = "{you_may_prefix}{cost_to_connector($cost)}{$body}";
+    // Effect followed by effects which occur only if it was performed:
+    // "{effect}. If you do, {follow_up}".
+    if_you_do_effect_body($effect, $follow_up) = "{$effect}{period_suffix} If you do, {$follow_up}";
     // Mandatory cost with effect body: "{cost} to {body}".
     cost_effect_body($cost, $body) = "{cost_to_connector($cost)}{$body}";
     // Per-effect optional prefix: "you may {body}".
@@ -0,0 +1,11 @@
+use rlf::{Phrase, Value};
+
+use crate::number_format;
+use crate::strings_generated as generated;
+
+pub use crate::strings_generated::*;
+
+// Wrappers for each parameterized phrase, generated by build.rs. These shadow
+// the functions re-exported above and format numeric arguments for the active
+// language before substituting them.
+include!(concat!(env!("OUT_DIR"), "/localized_phrases.rs"));
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::violation::{StyleViolation, ViolationKind};
+
+/// Module defining the colors which may be used in rich text.
+const PALETTE_PATH: &str = "src/strings/src/palette.rs";
+
+/// Phrase definitions, which may use colors defined in the palette.
+const PHRASES_PATH: &str = "src/strings/src/strings.rlf.rs";
+
+const COLOR_TAG: &str = "<color=";
+
+/// Checks for rich text color tags hardcoded outside of the palette module.
+///
+/// Colored text must come from a phrase in `strings.rlf.rs` using one of the
+/// standard colors defined in the palette module, so that it can be rewritten
+/// for users who have selected another palette. Comments and files under
+/// `tests/` are not checked.
+pub fn check_file(file: &Path, rules_engine_path: &Path) -> Result<Vec<StyleViolation>> {
+    let relative_path = file
+        .strip_prefix(rules_engine_path)
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+    if relative_path.starts_with("tests/") || relative_path == PALETTE_PATH {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+    if !content.contains(COLOR_TAG) {
+        return Ok(Vec::new());
+    }
+    let palette = if relative_path == PHRASES_PATH {
+        let path = rules_engine_path.join(PALETTE_PATH);
+        Some(
+            fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read palette: {}", path.display()))?,
+        )
+    } else {
+        None
+    };
+
+    let mut violations = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("//") {
+            continue;
+        }
+        // Tags are matched as they appear in Rust string literals, e.g.
+        // `<color=#00838F>` or `<color=\"blue\">`.
+        for (column, _) in line.match_indices(COLOR_TAG) {
+            let color =
+                line[column + COLOR_TAG.len()..].split('>').next().unwrap_or_default().to_string();
+            if !color.starts_with('#') && !color.starts_with("\\\"") {
+                continue;
+            }
+            let in_palette =
+                palette.as_ref().is_some_and(|palette| palette.contains(&format!("\"{color}\"")));
+            if !in_palette {
+                violations.push(StyleViolation {
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                    column: column + 1,
+                    kind: ViolationKind::HardcodedColor,
+                    path_str: color,
+                });
+            }
+        }
+    }
+    Ok(violations)
+}
@@ -7,9 +7,11 @@ mod code_order;
 mod direct_function_imports;
 mod doc_comment_links;
 mod file_scanner;
+mod hardcoded_colors;
 mod inline_tests;
 mod inline_use_statements;
 mod mod_lib_files;
+mod panicking_calls;
 mod pub_use;
 mod qualified_imports;
 mod super_self_imports;
@@ -126,6 +128,15 @@ fn main() -> Result<()> {
             }
         }
 
+        match hardcoded_colors::check_file(file, rules_engine_path) {
+            Ok(violations) => {
+                all_violations.extend(violations);
+            }
+            Err(e) => {
+                eprintln!("Error checking {}: {}", file.display(), e);
+            }
+        }
+
         if check_code_order {
             match code_order::check_file(file) {
                 Ok(violations) => {
@@ -154,6 +165,22 @@ fn main() -> Result<()> {
         }
     }
 
+    // Check for panicking calls in production crates
+    let mut panic_counts = None;
+    match panicking_calls::check_all_files(
+        &rust_files,
+        rules_engine_path,
+        &style_validator_path.join("panic_allowlist.toml"),
+    ) {
+        Ok((violations, counts)) => {
+            all_violations.extend(violations);
+            panic_counts = Some(counts);
+        }
+        Err(e) => {
+            eprintln!("Error checking panicking calls: {e}");
+        }
+    }
+
     // Check Cargo.toml files
     let cargo_toml_files = find_cargo_toml_files(rules_engine_path);
 
@@ -215,6 +242,13 @@ fn main() -> Result<()> {
         }
     }
 
+    if let Some(counts) = &panic_counts {
+        println!("\nPanicking calls in production crates:");
+        for (crate_name, count) in counts {
+            println!("  {crate_name}: {} ({} allowlisted)", count.found, count.allowlisted);
+        }
+    }
+
     if all_violations.is_empty() {
         println!("\n✓ No style violations found!");
         Ok(())
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{ExprMethodCall, Macro};
+
+use crate::violation::{StyleViolation, ViolationKind};
+
+/// Crates in which panicking calls crash live games.
+const PRODUCTION_CRATES: &[&str] = &["battle_mutations", "battle_state", "display"];
+
+const PANICKING_METHODS: &[&str] = &["unwrap", "expect"];
+
+const PANICKING_MACROS: &[&str] = &["panic", "todo"];
+
+/// Number of panicking calls found in a production crate.
+#[derive(Default)]
+pub struct CrateCount {
+    pub found: usize,
+    pub allowlisted: usize,
+}
+
+struct PanickingCallsChecker {
+    file_path: PathBuf,
+    calls: Vec<StyleViolation>,
+}
+
+impl PanickingCallsChecker {
+    fn add_call<T: Spanned>(&mut self, node: &T, path_str: String) {
+        let span = node.span();
+        self.calls.push(StyleViolation {
+            file: self.file_path.clone(),
+            line: span.start().line,
+            column: span.start().column + 1,
+            kind: ViolationKind::PanickingCall,
+            path_str,
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for PanickingCallsChecker {
+    fn visit_expr_method_call(&mut self, i: &'ast ExprMethodCall) {
+        let method = i.method.to_string();
+        if PANICKING_METHODS.contains(&method.as_str()) {
+            self.add_call(&i.method, format!(".{method}()"));
+        }
+        syn::visit::visit_expr_method_call(self, i);
+    }
+
+    fn visit_macro(&mut self, i: &'ast Macro) {
+        if let Some(segment) = i.path.segments.last() {
+            let name = segment.ident.to_string();
+            if PANICKING_MACROS.contains(&name.as_str()) {
+                self.add_call(&i.path, format!("{name}!()"));
+            }
+        }
+        syn::visit::visit_macro(self, i);
+    }
+}
+
+/// Checks production crates for `unwrap()`, `expect()`, `panic!` and `todo!`.
+///
+/// Each file may contain at most the number of panicking calls listed for it
+/// in `panic_allowlist.toml`. Every call in a file over its limit is reported.
+/// Returns the violations along with per-crate counts for the report.
+pub fn check_all_files(
+    rust_files: &[PathBuf],
+    rules_engine_path: &Path,
+    allowlist_path: &Path,
+) -> Result<(Vec<StyleViolation>, BTreeMap<&'static str, CrateCount>)> {
+    let allowlist = load_allowlist(allowlist_path)?;
+    let mut violations = Vec::new();
+    let mut counts = BTreeMap::new();
+
+    for file in rust_files {
+        let relative_path = file
+            .strip_prefix(rules_engine_path)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        let Some(crate_name) = PRODUCTION_CRATES
+            .iter()
+            .find(|name| relative_path.starts_with(&format!("src/{name}/")))
+        else {
+            continue;
+        };
+
+        let calls = find_calls(file)?;
+        let allowed = allowlist.get(&relative_path).copied().unwrap_or_default();
+        let count: &mut CrateCount = counts.entry(*crate_name).or_default();
+        count.found += calls.len();
+        count.allowlisted += calls.len().min(allowed);
+        if calls.len() > allowed {
+            violations.extend(calls);
+        }
+    }
+
+    Ok((violations, counts))
+}
+
+fn find_calls(path: &Path) -> Result<Vec<StyleViolation>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let syntax = syn::parse_file(&content)
+        .with_context(|| format!("Failed to parse file: {}", path.display()))?;
+    let mut checker = PanickingCallsChecker { file_path: path.to_path_buf(), calls: Vec::new() };
+    checker.visit_file(&syntax);
+    Ok(checker.calls)
+}
+
+/// Loads allowlisted panicking call counts, keyed by path relative to
+/// `rules_engine/`.
+fn load_allowlist(path: &Path) -> Result<BTreeMap<String, usize>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read allowlist: {}", path.display()))?;
+    let toml_value: toml::Value = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse allowlist: {}", path.display()))?;
+    let Some(files) = toml_value.get("files").and_then(|f| f.as_table()) else {
+        return Ok(BTreeMap::new());
+    };
+    files
+        .iter()
+        .map(|(file, count)| {
+            let count = count
+                .as_integer()
+                .and_then(|c| usize::try_from(c).ok())
+                .with_context(|| format!("Invalid allowlist count for {file}"))?;
+            Ok((file.clone(), count))
+        })
+        .collect()
+}
@@ -28,6 +28,8 @@ pub enum ViolationKind {
     TestFileNamingConvention,
     SuperOrSelfImport,
     CodeSpacing,
+    PanickingCall,
+    HardcodedColor,
 }
 
 impl ViolationKind {
@@ -81,6 +83,12 @@ impl ViolationKind {
             ViolationKind::CodeSpacing => {
                 "incorrect spacing between code elements (should have exactly one blank line between items, except consecutive constants)"
             }
+            ViolationKind::PanickingCall => {
+                "unwrap/expect/panic!/todo! not permitted in production crates (return an error or add the file to style_validator/panic_allowlist.toml)"
+            }
+            ViolationKind::HardcodedColor => {
+                "hardcoded rich text color (define colored text in strings.rlf.rs using a color from strings/src/palette.rs)"
+            }
         }
     }
 }
@@ -0,0 +1,95 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use ability_data::ability::Ability;
+use anyhow::{Context, Result};
+use tabula_data::tabula::{Tabula, TabulaSource};
+
+use crate::commands::generate;
+
+/// Card ID written in coverage records for effects with no source card.
+const NO_SOURCE_CARD: &str = "-";
+
+/// Effect coverage records read from a coverage directory.
+#[derive(Debug, Default)]
+pub struct CoverageRecords {
+    /// IDs of cards which were the source of at least one applied effect.
+    pub cards: BTreeSet<String>,
+
+    /// Number of distinct source cards each effect variant was applied for.
+    pub effects: BTreeMap<String, usize>,
+}
+
+/// Reports which effect variants were applied and which cards never had an
+/// ability resolve, based on effect coverage records.
+///
+/// Records are written to `coverage_dir` by builds with the `effect_coverage`
+/// feature of battle_mutations when the `EFFECT_COVERAGE_DIR` environment
+/// variable is set. Cards with only static abilities are not reported, since
+/// static abilities never resolve.
+pub fn coverage(coverage_dir: &Path) -> Result<ExitCode> {
+    let records = read_records(coverage_dir)?;
+    let tabula_dir = generate::tabula_source_dir();
+
+    println!("Effect variants applied ({}):", records.effects.len());
+    for (effect, cards) in &records.effects {
+        println!("  {effect}: {cards} source card(s)");
+    }
+
+    for (source, label) in
+        [(TabulaSource::Test, "Test cards"), (TabulaSource::Production, "Production cards")]
+    {
+        let (tabula, _) = Tabula::load_lenient(source, &tabula_dir)
+            .map_err(|e| anyhow::anyhow!("Failed to load tabula: {e:?}"))?;
+        let resolvable = tabula
+            .cards
+            .values()
+            .filter(|card| card.abilities.iter().any(|a| !matches!(a, Ability::Static(_))))
+            .collect::<Vec<_>>();
+        let uncovered = resolvable
+            .iter()
+            .filter(|card| !records.cards.contains(&card.base_card_id.0.to_string()))
+            .collect::<Vec<_>>();
+
+        println!(
+            "\n{label} whose abilities never resolved ({} of {}):",
+            uncovered.len(),
+            resolvable.len()
+        );
+        for card in uncovered {
+            println!("  {} ({})", card.displayed_name, card.base_card_id.0);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reads and merges every coverage record file in `coverage_dir`.
+pub fn read_records(coverage_dir: &Path) -> Result<CoverageRecords> {
+    let mut effect_cards: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut records = CoverageRecords::default();
+    let entries = fs::read_dir(coverage_dir)
+        .with_context(|| format!("Failed to read {}", coverage_dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "tsv") {
+            continue;
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        for line in contents.lines() {
+            let Some((card, effect)) = line.split_once('\t') else {
+                continue;
+            };
+            if card != NO_SOURCE_CARD {
+                records.cards.insert(card.to_string());
+            }
+            effect_cards.entry(effect.to_string()).or_default().insert(card.to_string());
+        }
+    }
+    records.effects =
+        effect_cards.into_iter().map(|(effect, cards)| (effect, cards.len())).collect();
+    Ok(records)
+}
@@ -1,3 +1,4 @@
 pub mod check;
+pub mod coverage;
 pub mod generate;
 pub mod watch;
@@ -3,7 +3,7 @@ use std::process::ExitCode;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
-use tabula_cli::commands::{check, generate, watch};
+use tabula_cli::commands::{check, coverage, generate, watch};
 
 #[derive(Parser)]
 #[command(name = "tabula")]
@@ -27,6 +27,11 @@ enum Commands {
     },
     #[command(about = "Check that generated files are up to date")]
     Check,
+    #[command(about = "Report cards whose abilities were never resolved in recorded runs")]
+    Coverage {
+        #[arg(help = "Directory containing effect coverage records")]
+        coverage_dir: PathBuf,
+    },
 }
 
 fn main() -> ExitCode {
@@ -52,5 +57,6 @@ fn run() -> Result<ExitCode> {
             Ok(ExitCode::SUCCESS)
         }
         Commands::Check => check::check(),
+        Commands::Coverage { coverage_dir } => coverage::coverage(&coverage_dir),
     }
 }
@@ -2,7 +2,7 @@ use ability_data::ability::Ability;
 use core_data::card_property_data::Rarity;
 use core_data::card_types::{CardSubtype, CardType};
 use core_data::display_types::SpriteAddress;
-use core_data::identifiers::BaseCardId;
+use core_data::identifiers::{BaseCardId, CardVariantId};
 use core_data::numerics::{Energy, Spark};
 use serde::{Deserialize, Serialize};
 
@@ -44,4 +44,24 @@ pub struct CardDefinition {
     pub rarity: Option<Rarity>,
     /// Image to display for this card.
     pub image: SpriteAddress,
+    /// Image to display for ability tokens created by this card, if it has
+    /// distinct token art.
+    #[serde(default)]
+    pub token_image: Option<SpriteAddress>,
+    /// Image to display for copies of this card, if it has distinct copy art.
+    #[serde(default)]
+    pub copy_image: Option<SpriteAddress>,
+    /// Image to display for figments created by this card, if it has distinct
+    /// figment art.
+    #[serde(default)]
+    pub figment_image: Option<SpriteAddress>,
+    /// Other cards this card refers to, which are displayed alongside it when
+    /// it is inspected.
+    #[serde(default)]
+    pub related_cards: Vec<BaseCardId>,
+    /// Variant selected to display this card with, if any.
+    ///
+    /// Variants never change a card's rules. See `Tabula::card_variant`.
+    #[serde(default)]
+    pub variant: Option<CardVariantId>,
 }
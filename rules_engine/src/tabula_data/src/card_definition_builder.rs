@@ -36,6 +36,11 @@ pub fn build_card(
         spark: parse_spark(raw, file, Some(card_id))?,
         rarity: parse_rarity(raw, file, Some(card_id))?,
         image: build_sprite_address(image_number),
+        token_image: raw.token_image_number.map(build_sprite_address),
+        copy_image: raw.copy_image_number.map(build_sprite_address),
+        figment_image: raw.figment_image_number.map(build_sprite_address),
+        related_cards: raw.related_cards.iter().flatten().map(|&id| BaseCardId(id)).collect(),
+        variant: None,
     })
 }
 
@@ -63,6 +68,13 @@ pub fn build_dreamwell(
     })
 }
 
+/// Returns the address of the standard card image with the given number.
+pub fn build_sprite_address(image_number: i64) -> SpriteAddress {
+    SpriteAddress::new(format!(
+        "Assets/ThirdParty/GameAssets/CardImages/Standard/shutterstock_{image_number}.png"
+    ))
+}
+
 fn require_field<T: Clone>(
     value: Option<T>,
     field: &'static str,
@@ -191,12 +203,6 @@ fn parse_phase(
     Ok(phase as DreamwellCardPhase)
 }
 
-fn build_sprite_address(image_number: i64) -> SpriteAddress {
-    SpriteAddress::new(format!(
-        "Assets/ThirdParty/GameAssets/CardImages/Standard/shutterstock_{image_number}.png"
-    ))
-}
-
 fn build_dreamwell_sprite_address(image_number: i64) -> SpriteAddress {
     SpriteAddress::new(format!(
         "Assets/ThirdParty/GameAssets/CardImages/Dreamwell/shutterstock_{image_number}.png"
@@ -31,6 +31,14 @@ pub struct CardDefinitionRaw {
     pub variables: Option<String>,
     /// Image asset identifier.
     pub image_number: Option<i64>,
+    /// Image asset identifier for ability tokens created by this card.
+    pub token_image_number: Option<i64>,
+    /// Image asset identifier for copies of this card.
+    pub copy_image_number: Option<i64>,
+    /// Image asset identifier for figments created by this card.
+    pub figment_image_number: Option<i64>,
+    /// IDs of other cards this card refers to, e.g. a card it creates.
+    pub related_cards: Option<Vec<Uuid>>,
     /// Card rarity (e.g., "Common", "Rare").
     pub rarity: Option<String>,
     /// Energy produced when this dreamwell card is drawn.
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use core_data::display_color::DisplayColor;
@@ -10,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 use uuid::Uuid;
 
+use crate::fx_definition_row::FxDefinitionRow;
 use crate::tabula_error::TabulaError;
 use crate::toml_loader::CardEffectRowRaw;
 
@@ -61,6 +63,9 @@ pub struct CardEffectRow {
     pub effect_type: CardEffectRowType,
     /// The game event that triggers this effect.
     pub effect_trigger: CardEffectRowTrigger,
+    /// Name of the effect from the FX table supplying this row's assets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fx_name: Option<String>,
     /// Source location for projectile effects.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub projectile_source: Option<CardEffectRowObjectPredicate>,
@@ -124,6 +129,7 @@ pub fn build_card_effect_row(
         card_id: BaseCardId(card_id),
         effect_type,
         effect_trigger,
+        fx_name: raw.fx_name.clone(),
         projectile_source: parse_object_predicate_opt(&raw.projectile_source, file, card_id)?,
         projectile_target: parse_object_predicate_opt(&raw.projectile_target, file, card_id)?,
         projectile_address: parse_string_wrapper_opt(&raw.projectile_address),
@@ -147,6 +153,52 @@ pub fn build_card_effect_row(
     })
 }
 
+/// Fills in each asset which a card effect row does not specify from the
+/// named effect it references via `fx-name`, if any.
+///
+/// Returns an error if the referenced effect does not exist.
+pub fn resolve_fx_name(
+    row: CardEffectRow,
+    fx_definitions: &BTreeMap<String, FxDefinitionRow>,
+    file: &Path,
+) -> Result<CardEffectRow, TabulaError> {
+    let Some(name) = &row.fx_name else {
+        return Ok(row);
+    };
+    let Some(fx) = fx_definitions.get(name) else {
+        return Err(TabulaError::InvalidField {
+            file: file.to_path_buf(),
+            card_id: Some(row.card_id.0),
+            field: "fx-name",
+            message: format!("unknown fx name '{name}'"),
+        });
+    };
+
+    Ok(CardEffectRow {
+        projectile_address: row.projectile_address.or_else(|| fx.projectile_address.clone()),
+        projectile_fire_sound: row
+            .projectile_fire_sound
+            .or_else(|| fx.projectile_fire_sound.clone()),
+        projectile_impact_sound: row
+            .projectile_impact_sound
+            .or_else(|| fx.projectile_impact_sound.clone()),
+        dissolve_material: row.dissolve_material.or_else(|| fx.dissolve_material.clone()),
+        dissolve_color: row.dissolve_color.or(fx.dissolve_color),
+        dissolve_sound: row.dissolve_sound.or_else(|| fx.dissolve_sound.clone()),
+        effect_address: row.effect_address.or_else(|| fx.effect_address.clone()),
+        effect_duration_milliseconds: row
+            .effect_duration_milliseconds
+            .or(fx.effect_duration_milliseconds),
+        effect_scale: row.effect_scale.or(fx.effect_scale),
+        effect_sound: row.effect_sound.or_else(|| fx.effect_sound.clone()),
+        card_trail_address: row.card_trail_address.or_else(|| fx.card_trail_address.clone()),
+        trail_duration_milliseconds: row
+            .trail_duration_milliseconds
+            .or(fx.trail_duration_milliseconds),
+        ..row
+    })
+}
+
 fn parse_uuid(s: &str, file: &Path, field: &'static str) -> Result<Uuid, TabulaError> {
     Uuid::parse_str(s).map_err(|e| TabulaError::InvalidField {
         file: file.to_path_buf(),
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use core_data::display_types::SpriteAddress;
+use core_data::identifiers::{BaseCardId, CardVariantId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::card_definition_builder;
+use crate::tabula_error::TabulaError;
+use crate::toml_loader::CardVariantRowRaw;
+
+/// A row from the card variants table defining an alternate presentation of a
+/// card, such as promotional art or a foil treatment.
+///
+/// Variants reference their base card instead of duplicating its rules, so
+/// every variant of a card plays identically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardVariantRow {
+    /// Identifies this variant.
+    pub variant_id: CardVariantId,
+    /// The base card this is a variant of.
+    pub card_id: BaseCardId,
+    /// Display name of this variant.
+    pub variant_name: String,
+    /// Image to display in place of the base card's image.
+    pub image: SpriteAddress,
+    /// Whether this variant is displayed with a foil treatment.
+    pub is_foil: bool,
+}
+
+/// Builds a [CardVariantRow] from raw TOML data.
+pub fn build_card_variant_row(
+    raw: &CardVariantRowRaw,
+    file: &Path,
+) -> Result<CardVariantRow, TabulaError> {
+    let variant_id = parse_uuid(&raw.id, file, None, "id")?;
+    let card_id = parse_uuid(&raw.card_id, file, Some(variant_id), "card-id")?;
+
+    Ok(CardVariantRow {
+        variant_id: CardVariantId(variant_id),
+        card_id: BaseCardId(card_id),
+        variant_name: raw.variant_name.clone(),
+        image: card_definition_builder::build_sprite_address(raw.image_number),
+        is_foil: raw.is_foil.unwrap_or(false),
+    })
+}
+
+fn parse_uuid(
+    s: &str,
+    file: &Path,
+    variant_id: Option<Uuid>,
+    field: &'static str,
+) -> Result<Uuid, TabulaError> {
+    Uuid::parse_str(s).map_err(|e| TabulaError::InvalidField {
+        file: file.to_path_buf(),
+        card_id: variant_id,
+        field,
+        message: e.to_string(),
+    })
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use core_data::display_color::DisplayColor;
+use core_data::display_types::{
+    AudioClipAddress, EffectAddress, MaterialAddress, Milliseconds, ProjectileAddress,
+    StringWrapper,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::tabula_error::TabulaError;
+use crate::toml_loader::FxDefinitionRowRaw;
+
+/// A row from the FX table defining a named visual effect.
+///
+/// Card effect rows reference these by `fx-name`, so an effect can be attached
+/// to many cards without repeating its assets. Any asset a card effect row
+/// specifies directly takes precedence over the named effect's value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxDefinitionRow {
+    /// Name used to reference this effect.
+    pub name: String,
+    /// Asset path for the projectile prefab.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projectile_address: Option<ProjectileAddress>,
+    /// Sound to play when firing the projectile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projectile_fire_sound: Option<AudioClipAddress>,
+    /// Sound to play on projectile impact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projectile_impact_sound: Option<AudioClipAddress>,
+    /// Material for dissolve shader effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dissolve_material: Option<MaterialAddress>,
+    /// Color for dissolve shader effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dissolve_color: Option<DisplayColor>,
+    /// Sound for dissolve effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dissolve_sound: Option<AudioClipAddress>,
+    /// Asset path for display effect prefab.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_address: Option<EffectAddress>,
+    /// Duration of the display effect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_duration_milliseconds: Option<Milliseconds>,
+    /// Scale multiplier for display effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_scale: Option<f64>,
+    /// Sound for display effects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_sound: Option<AudioClipAddress>,
+    /// Asset path for card trail prefab.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_trail_address: Option<ProjectileAddress>,
+    /// Duration of the card trail effect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trail_duration_milliseconds: Option<Milliseconds>,
+}
+
+/// Builds a [FxDefinitionRow] from raw TOML data.
+pub fn build_fx_definition_row(
+    raw: &FxDefinitionRowRaw,
+    file: &Path,
+) -> Result<FxDefinitionRow, TabulaError> {
+    if raw.name.trim().is_empty() {
+        return Err(TabulaError::InvalidField {
+            file: file.to_path_buf(),
+            card_id: None,
+            field: "name",
+            message: "fx name must not be empty".to_string(),
+        });
+    }
+
+    Ok(FxDefinitionRow {
+        name: raw.name.clone(),
+        projectile_address: parse_string_wrapper_opt(&raw.projectile_address),
+        projectile_fire_sound: parse_string_wrapper_opt(&raw.projectile_fire_sound),
+        projectile_impact_sound: parse_string_wrapper_opt(&raw.projectile_impact_sound),
+        dissolve_material: parse_string_wrapper_opt(&raw.dissolve_material),
+        dissolve_color: parse_display_color_opt(&raw.dissolve_color, file)?,
+        dissolve_sound: parse_string_wrapper_opt(&raw.dissolve_sound),
+        effect_address: parse_string_wrapper_opt(&raw.effect_address),
+        effect_duration_milliseconds: raw
+            .effect_duration_milliseconds
+            .map(|ms| Milliseconds::new(ms as u32)),
+        effect_scale: raw.effect_scale,
+        effect_sound: parse_string_wrapper_opt(&raw.effect_sound),
+        card_trail_address: parse_string_wrapper_opt(&raw.card_trail_address),
+        trail_duration_milliseconds: raw
+            .trail_duration_milliseconds
+            .map(|ms| Milliseconds::new(ms as u32)),
+    })
+}
+
+fn parse_string_wrapper_opt<T: StringWrapper>(opt: &Option<String>) -> Option<T> {
+    opt.as_ref().and_then(|s| T::from_string_value(s).ok())
+}
+
+fn parse_display_color_opt(
+    opt: &Option<String>,
+    file: &Path,
+) -> Result<Option<DisplayColor>, TabulaError> {
+    match opt {
+        Some(s) => {
+            DisplayColor::from_string_value(s).map(Some).map_err(|e| TabulaError::InvalidField {
+                file: file.to_path_buf(),
+                card_id: None,
+                field: "dissolve-color",
+                message: e,
+            })
+        }
+        None => Ok(None),
+    }
+}
@@ -4,7 +4,9 @@ pub mod card_definition_builder;
 pub mod card_definition_raw;
 pub mod card_effect_row;
 pub mod card_list_row;
+pub mod card_variant_row;
 pub mod dreamwell_definition;
+pub mod fx_definition_row;
 pub mod tabula;
 pub mod tabula_error;
 pub mod toml_loader;
@@ -2,17 +2,19 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use ability_data::ability::Ability;
-use core_data::identifiers::{BaseCardId, DreamwellCardId};
+use core_data::identifiers::{BaseCardId, CardVariantId, DreamwellCardId};
 use uuid::Uuid;
 
 use crate::card_definition::CardDefinition;
 use crate::card_effect_row::{self, CardEffectRow};
 use crate::card_list_row::{self, CardListRow};
+use crate::card_variant_row::{self, CardVariantRow};
 use crate::dreamwell_definition::DreamwellCardDefinition;
+use crate::fx_definition_row::{self, FxDefinitionRow};
 use crate::tabula_error::TabulaError;
 use crate::toml_loader::{
-    self, CardEffectsFile, CardListsFile, CardsFile, DreamwellFile, TestCardsFile,
-    TestDreamwellFile,
+    self, CardEffectsFile, CardListsFile, CardVariantsFile, CardsFile, DreamwellFile,
+    FxDefinitionRowRaw, FxFile, TestCardsFile, TestDreamwellFile,
 };
 use crate::{ability_parser, card_definition_builder};
 
@@ -39,6 +41,10 @@ pub struct Tabula {
     pub card_lists: Vec<CardListRow>,
     /// Card effect rows defining visual effects.
     pub card_effects: Vec<CardEffectRow>,
+    /// Named visual effects indexed by name.
+    pub fx_definitions: BTreeMap<String, FxDefinitionRow>,
+    /// Alternate presentations of cards indexed by variant ID.
+    pub card_variants: BTreeMap<CardVariantId, CardVariantRow>,
 }
 
 impl Tabula {
@@ -52,10 +58,19 @@ impl Tabula {
         let cards = load_cards_strict(source, path, &abilities, &mut errors);
         let dreamwell_cards = load_dreamwell_cards_strict(source, path, &abilities, &mut errors);
         let card_lists = load_card_lists_strict(path, &mut errors);
-        let card_effects = load_card_effects_strict(path, &mut errors);
+        let fx_definitions = load_fx_definitions_strict(path, &mut errors);
+        let card_effects = load_card_effects_strict(path, &fx_definitions, &mut errors);
+        let card_variants = load_card_variants_strict(path, &mut errors);
 
         if errors.is_empty() {
-            Ok(Self { cards, dreamwell_cards, card_lists, card_effects })
+            Ok(Self {
+                cards,
+                dreamwell_cards,
+                card_lists,
+                card_effects,
+                fx_definitions,
+                card_variants,
+            })
         } else {
             Err(errors)
         }
@@ -80,14 +95,36 @@ impl Tabula {
         let dreamwell_cards =
             load_dreamwell_cards_lenient(source, path, &abilities, &mut errors, &mut warnings);
         let card_lists = load_card_lists_lenient(path, &mut errors, &mut warnings);
-        let card_effects = load_card_effects_lenient(path, &mut errors, &mut warnings);
+        let fx_definitions = load_fx_definitions_lenient(path, &mut errors, &mut warnings);
+        let card_effects =
+            load_card_effects_lenient(path, &fx_definitions, &mut errors, &mut warnings);
+        let card_variants = load_card_variants_lenient(path, &mut errors, &mut warnings);
 
         if errors.is_empty() {
-            Ok((Self { cards, dreamwell_cards, card_lists, card_effects }, warnings))
+            Ok((
+                Self {
+                    cards,
+                    dreamwell_cards,
+                    card_lists,
+                    card_effects,
+                    fx_definitions,
+                    card_variants,
+                },
+                warnings,
+            ))
         } else {
             Err(errors)
         }
     }
+
+    /// Returns the variant with the given ID if it is a variant of `card_id`.
+    pub fn card_variant(
+        &self,
+        card_id: BaseCardId,
+        variant_id: CardVariantId,
+    ) -> Option<&CardVariantRow> {
+        self.card_variants.get(&variant_id).filter(|variant| variant.card_id == card_id)
+    }
 }
 
 /// Loads pre-parsed abilities from the parsed_abilities.json file.
@@ -340,15 +377,22 @@ fn load_card_lists_lenient(
     card_lists
 }
 
-/// Loads card effect definitions, failing on any row build error.
-fn load_card_effects_strict(path: &Path, errors: &mut Vec<TabulaError>) -> Vec<CardEffectRow> {
+/// Loads card effect definitions, failing on any row build error or reference
+/// to an unknown named effect.
+fn load_card_effects_strict(
+    path: &Path,
+    fx_definitions: &BTreeMap<String, FxDefinitionRow>,
+    errors: &mut Vec<TabulaError>,
+) -> Vec<CardEffectRow> {
     let mut card_effects = Vec::new();
     let card_fx_path = path.join("card-fx.toml");
 
     match toml_loader::load_toml::<CardEffectsFile>(&card_fx_path) {
         Ok(file) => {
             for raw in &file.card_fx {
-                match card_effect_row::build_card_effect_row(raw, &card_fx_path) {
+                match card_effect_row::build_card_effect_row(raw, &card_fx_path).and_then(|row| {
+                    card_effect_row::resolve_fx_name(row, fx_definitions, &card_fx_path)
+                }) {
                     Ok(row) => {
                         card_effects.push(row);
                     }
@@ -364,9 +408,11 @@ fn load_card_effects_strict(path: &Path, errors: &mut Vec<TabulaError>) -> Vec<C
     card_effects
 }
 
-/// Loads card effect definitions, collecting row build errors as warnings.
+/// Loads card effect definitions, collecting row build errors and references
+/// to unknown named effects as warnings.
 fn load_card_effects_lenient(
     path: &Path,
+    fx_definitions: &BTreeMap<String, FxDefinitionRow>,
     errors: &mut Vec<TabulaError>,
     warnings: &mut Vec<TabulaError>,
 ) -> Vec<CardEffectRow> {
@@ -376,7 +422,9 @@ fn load_card_effects_lenient(
     match toml_loader::load_toml::<CardEffectsFile>(&card_fx_path) {
         Ok(file) => {
             for raw in &file.card_fx {
-                match card_effect_row::build_card_effect_row(raw, &card_fx_path) {
+                match card_effect_row::build_card_effect_row(raw, &card_fx_path).and_then(|row| {
+                    card_effect_row::resolve_fx_name(row, fx_definitions, &card_fx_path)
+                }) {
                     Ok(row) => {
                         card_effects.push(row);
                     }
@@ -391,3 +439,134 @@ fn load_card_effects_lenient(
 
     card_effects
 }
+
+/// Loads named effect definitions, failing on any row build error or
+/// duplicate name.
+fn load_fx_definitions_strict(
+    path: &Path,
+    errors: &mut Vec<TabulaError>,
+) -> BTreeMap<String, FxDefinitionRow> {
+    let mut fx_definitions = BTreeMap::new();
+    let fx_path = path.join("fx.toml");
+
+    match toml_loader::load_toml::<FxFile>(&fx_path) {
+        Ok(file) => {
+            for raw in &file.fx {
+                match build_fx_definition(raw, &fx_path, &fx_definitions) {
+                    Ok(row) => {
+                        fx_definitions.insert(row.name.clone(), row);
+                    }
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+        Err(e) => {
+            errors.push(e);
+        }
+    }
+
+    fx_definitions
+}
+
+/// Loads named effect definitions, collecting row build errors and duplicate
+/// names as warnings.
+fn load_fx_definitions_lenient(
+    path: &Path,
+    errors: &mut Vec<TabulaError>,
+    warnings: &mut Vec<TabulaError>,
+) -> BTreeMap<String, FxDefinitionRow> {
+    let mut fx_definitions = BTreeMap::new();
+    let fx_path = path.join("fx.toml");
+
+    match toml_loader::load_toml::<FxFile>(&fx_path) {
+        Ok(file) => {
+            for raw in &file.fx {
+                match build_fx_definition(raw, &fx_path, &fx_definitions) {
+                    Ok(row) => {
+                        fx_definitions.insert(row.name.clone(), row);
+                    }
+                    Err(e) => warnings.push(e),
+                }
+            }
+        }
+        Err(e) => {
+            errors.push(e);
+        }
+    }
+
+    fx_definitions
+}
+
+/// Builds a named effect definition, returning an error if an effect with the
+/// same name has already been loaded.
+fn build_fx_definition(
+    raw: &FxDefinitionRowRaw,
+    file: &Path,
+    fx_definitions: &BTreeMap<String, FxDefinitionRow>,
+) -> Result<FxDefinitionRow, TabulaError> {
+    let row = fx_definition_row::build_fx_definition_row(raw, file)?;
+    if fx_definitions.contains_key(&row.name) {
+        return Err(TabulaError::InvalidField {
+            file: file.to_path_buf(),
+            card_id: None,
+            field: "name",
+            message: format!("duplicate fx name '{}'", row.name),
+        });
+    }
+    Ok(row)
+}
+
+/// Loads card variant definitions, failing on any row build error.
+fn load_card_variants_strict(
+    path: &Path,
+    errors: &mut Vec<TabulaError>,
+) -> BTreeMap<CardVariantId, CardVariantRow> {
+    let mut card_variants = BTreeMap::new();
+    let card_variants_path = path.join("card-variants.toml");
+
+    match toml_loader::load_toml::<CardVariantsFile>(&card_variants_path) {
+        Ok(file) => {
+            for raw in &file.card_variants {
+                match card_variant_row::build_card_variant_row(raw, &card_variants_path) {
+                    Ok(row) => {
+                        card_variants.insert(row.variant_id, row);
+                    }
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+        Err(e) => {
+            errors.push(e);
+        }
+    }
+
+    card_variants
+}
+
+/// Loads card variant definitions, collecting row build errors as warnings.
+fn load_card_variants_lenient(
+    path: &Path,
+    errors: &mut Vec<TabulaError>,
+    warnings: &mut Vec<TabulaError>,
+) -> BTreeMap<CardVariantId, CardVariantRow> {
+    let mut card_variants = BTreeMap::new();
+    let card_variants_path = path.join("card-variants.toml");
+
+    match toml_loader::load_toml::<CardVariantsFile>(&card_variants_path) {
+        Ok(file) => {
+            for raw in &file.card_variants {
+                match card_variant_row::build_card_variant_row(raw, &card_variants_path) {
+                    Ok(row) => {
+                        card_variants.insert(row.variant_id, row);
+                    }
+                    Err(e) => warnings.push(e),
+                }
+            }
+        }
+        Err(e) => {
+            errors.push(e);
+        }
+    }
+
+    card_variants
+}
@@ -55,6 +55,8 @@ pub struct CardEffectRowRaw {
     pub effect_type: String,
     /// The trigger condition for the effect.
     pub effect_trigger: String,
+    /// Name of an effect from the FX table supplying this row's assets.
+    pub fx_name: Option<String>,
     /// Source of the projectile (for projectile effects).
     pub projectile_source: Option<String>,
     /// Target of the projectile (for projectile effects).
@@ -98,6 +100,47 @@ pub struct CardEffectsFile {
     pub card_fx: Vec<CardEffectRowRaw>,
 }
 
+/// Raw representation of a named effect row from TOML.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FxDefinitionRowRaw {
+    /// Name used to reference this effect from card effect rows.
+    pub name: String,
+    /// Asset path for the projectile prefab.
+    pub projectile_address: Option<String>,
+    /// Sound to play when firing the projectile.
+    pub projectile_fire_sound: Option<String>,
+    /// Sound to play on projectile impact.
+    pub projectile_impact_sound: Option<String>,
+    /// Material for dissolve effects.
+    pub dissolve_material: Option<String>,
+    /// Color for dissolve effects.
+    pub dissolve_color: Option<String>,
+    /// Sound for dissolve effects.
+    pub dissolve_sound: Option<String>,
+    /// Asset path for display effect prefab.
+    pub effect_address: Option<String>,
+    /// Duration of the display effect in milliseconds.
+    pub effect_duration_milliseconds: Option<i64>,
+    /// Scale multiplier for display effects.
+    pub effect_scale: Option<f64>,
+    /// Sound for display effects.
+    pub effect_sound: Option<String>,
+    /// Asset path for card trail prefab.
+    pub card_trail_address: Option<String>,
+    /// Duration of the card trail in milliseconds.
+    pub trail_duration_milliseconds: Option<i64>,
+}
+
+/// Wrapper for deserializing named effect arrays from TOML files using
+/// `[[fx]]` syntax.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FxFile {
+    /// The array of named effect rows.
+    pub fx: Vec<FxDefinitionRowRaw>,
+}
+
 /// Raw representation of a card list row from TOML.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -121,6 +164,31 @@ pub struct CardListsFile {
     pub card_lists: Vec<CardListRowRaw>,
 }
 
+/// Raw representation of a card variant row from TOML.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CardVariantRowRaw {
+    /// Unique identifier for this variant.
+    pub id: String,
+    /// The base card this is a variant of.
+    pub card_id: String,
+    /// Display name of the variant (e.g., "Promotional").
+    pub variant_name: String,
+    /// Image number for the variant's art.
+    pub image_number: i64,
+    /// Whether the variant is displayed with a foil treatment.
+    pub is_foil: Option<bool>,
+}
+
+/// Wrapper for deserializing card variant arrays from TOML files using
+/// `[[card-variants]]` syntax.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CardVariantsFile {
+    /// The array of card variant rows.
+    pub card_variants: Vec<CardVariantRowRaw>,
+}
+
 /// Loads and parses a TOML file from the filesystem into the specified type.
 ///
 /// On Android, this function automatically handles loading from APK assets
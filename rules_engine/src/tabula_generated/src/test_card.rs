@@ -74,6 +74,15 @@ pub const TEST_DISCARD: BaseCardId = BaseCardId(uuid!("6e76f193-dcf0-4faf-b1f7-5
 pub const TEST_DISCARD_TWO: BaseCardId = BaseCardId(uuid!("ef6d55f9-49ba-4637-af50-91068cb3a2b2"));
 /// {Materialized} Draw {cards($c)}.
 pub const TEST_MATERIALIZED_DRAW_CARD: BaseCardId = BaseCardId(uuid!("f62670ae-73ad-4645-8de4-4cd8fb58b920"));
+/// At the start of your turn, this character gains +{$s} spark.
+pub const TEST_START_OF_TURN_GAIN_SPARK: BaseCardId = BaseCardId(uuid!("3d6a1c0c-76bd-4165-a8f4-b972442d56a8"));
+/// You may discard {cards($d)}. If you do, draw {cards($c)}.
+pub const TEST_DISCARD_IF_YOU_DO_DRAW: BaseCardId = BaseCardId(uuid!("5b2f8e41-9c7d-4a36-b1e0-7d4c2a9f6e13"));
+pub const TEST_RELATED_CARD_CHARACTER: BaseCardId = BaseCardId(uuid!("9d4a7c2e-3f81-4b6a-a5d0-2e8c61f4b937"));
+/// With an allied {subtype($t)}, you may play this card from your hand or void for {energy($e)}.
+pub const TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY: BaseCardId = BaseCardId(uuid!("c6e1f3a8-5b27-4d9e-8f40-3a7d2b9c1e65"));
+/// While you have {count($n)} or more cards in your void, allied {@plural subtype($t)} have +{$s} spark.
+pub const TEST_SPARK_BONUS_WITH_CARDS_IN_VOID: BaseCardId = BaseCardId(uuid!("7e3b9d14-6a2f-4c85-b0d1-9f4e2a6c8b53"));
 pub const DREAMWELL_PRODUCE_0: DreamwellCardId = DreamwellCardId(uuid!("146ae27e-a8ac-4f3c-aef2-cf2211e4bcfe"));
 pub const DREAMWELL_PRODUCE_1: DreamwellCardId = DreamwellCardId(uuid!("ee7b0367-f7c3-46c3-94db-b29cfd8dc2d2"));
 pub const DREAMWELL_PRODUCE_2_STARTER: DreamwellCardId = DreamwellCardId(uuid!("308fd4c0-ca98-4bfa-a9be-c29b36a145fd"));
@@ -125,6 +134,11 @@ pub const ALL_TEST_CARD_IDS: &[BaseCardId] = &[
     TEST_DISCARD,
     TEST_DISCARD_TWO,
     TEST_MATERIALIZED_DRAW_CARD,
+    TEST_START_OF_TURN_GAIN_SPARK,
+    TEST_DISCARD_IF_YOU_DO_DRAW,
+    TEST_RELATED_CARD_CHARACTER,
+    TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY,
+    TEST_SPARK_BONUS_WITH_CARDS_IN_VOID,
 ];
 
 pub const ALL_TEST_DREAMWELL_CARD_IDS: &[DreamwellCardId] = &[
@@ -3,18 +3,23 @@ use std::sync::Arc;
 
 use ai_data::game_ai::GameAI;
 use battle_mutations::card_mutations::move_card;
+use battle_mutations::play_cards::play_card;
+use battle_queries::battle_card_queries::card_properties;
 use battle_state::battle::battle_state::{BattleState, RequestContext};
-use battle_state::battle::card_id::BattleDeckCardId;
+use battle_state::battle::card_id::{BattleDeckCardId, CardIdType};
 use battle_state::battle_cards::dreamwell_data::Dreamwell;
 use battle_state::battle_player::battle_player_state::{
     CreateBattlePlayer, PlayerType, TestDeckName,
 };
 use battle_state::core::effect_source::EffectSource;
-use core_data::identifiers::{BattleId, QuestId, UserId};
-use core_data::numerics::Energy;
+use core_data::identifiers::{BattleId, QuestId, SaveSlotId, UserId};
+use core_data::numerics::{Energy, TurnId};
 use core_data::types::PlayerName;
+use database::collection::Collection;
+use database::match_history::MatchHistory;
 use database::quest_save_file::QuestSaveFile;
 use database::save_file::{SaveFile, SaveFileV1};
+use database::save_file_encoding::SaveFileOptions;
 use database::save_file_io;
 use game_creation::new_test_battle;
 use rand::RngCore;
@@ -22,6 +27,8 @@ use tabula_data::tabula::{Tabula, TabulaSource};
 use tabula_generated::card_lists::DreamwellCardIdList;
 use uuid::Uuid;
 
+mod scenario;
+
 /// User ID used by the Unity client in development mode.
 const UNITY_USER_ID: Uuid = uuid::uuid!("d2da9785-f20e-4879-bed5-35b2e1926faf");
 
@@ -30,7 +37,14 @@ fn main() {
     let args: Vec<String> = std::env::args().collect();
     let mut energy: Option<u32> = None;
     let mut card_names: Vec<String> = Vec::new();
+    let mut enemy_energy: Option<u32> = None;
+    let mut enemy_card_names: Vec<String> = Vec::new();
+    let mut enemy_battlefield_names: Vec<String> = Vec::new();
+    let mut turn: Option<u32> = None;
+    let mut stack_cards: Vec<(PlayerName, String)> = Vec::new();
     let mut save_dir: Option<PathBuf> = None;
+    let mut slot = SaveSlotId::default();
+    let mut scenario_path: Option<PathBuf> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -43,10 +57,42 @@ fn main() {
                 i += 1;
                 card_names.push(args[i].clone());
             }
+            "--enemy-energy" => {
+                i += 1;
+                enemy_energy = Some(args[i].parse().expect("Invalid energy value"));
+            }
+            "--enemy-card" => {
+                i += 1;
+                enemy_card_names.push(args[i].clone());
+            }
+            "--enemy-battlefield" => {
+                i += 1;
+                enemy_battlefield_names.push(args[i].clone());
+            }
+            "--stack-card" => {
+                i += 1;
+                stack_cards.push((PlayerName::One, args[i].clone()));
+            }
+            "--enemy-stack-card" => {
+                i += 1;
+                stack_cards.push((PlayerName::Two, args[i].clone()));
+            }
+            "--turn" => {
+                i += 1;
+                turn = Some(args[i].parse().expect("Invalid turn number"));
+            }
             "--save-dir" => {
                 i += 1;
                 save_dir = Some(PathBuf::from(&args[i]));
             }
+            "--slot" => {
+                i += 1;
+                slot = SaveSlotId(args[i].parse().expect("Invalid save slot"));
+            }
+            "--scenario" => {
+                i += 1;
+                scenario_path = Some(PathBuf::from(&args[i]));
+            }
             "--help" | "-h" => {
                 print_usage();
                 return;
@@ -64,20 +110,53 @@ fn main() {
         i += 1;
     }
 
-    let save_dir = save_dir.unwrap_or_else(default_save_dir);
+    let Some(save_dir) = save_dir.or_else(save_file_io::default_save_dir) else {
+        eprintln!(
+            "Error: Cannot determine save directory. Pass --save-dir or set {}",
+            save_file_io::SAVE_DIR_ENV_VAR
+        );
+        std::process::exit(1);
+    };
     let tabula = load_tabula();
     let mut battle = create_battle(tabula.clone());
 
+    if let Some(path) = &scenario_path {
+        scenario::apply(&mut battle, &tabula, &scenario::load(path));
+        eprintln!("Applied scenario {}", path.display());
+    }
+
+    if let Some(t) = turn {
+        set_turn(&mut battle, TurnId(t));
+        eprintln!("Set turn to {t}");
+    }
+
     if let Some(e) = energy {
-        set_energy(&mut battle, Energy(e));
+        set_energy(&mut battle, PlayerName::One, Energy(e));
         eprintln!("Set energy to {e}");
     }
 
+    if let Some(e) = enemy_energy {
+        set_energy(&mut battle, PlayerName::Two, Energy(e));
+        eprintln!("Set enemy energy to {e}");
+    }
+
     for name in &card_names {
-        move_card_to_hand(&mut battle, &tabula, name);
+        move_card_to_hand(&mut battle, &tabula, PlayerName::One, name);
+    }
+
+    for name in &enemy_card_names {
+        move_card_to_hand(&mut battle, &tabula, PlayerName::Two, name);
+    }
+
+    for name in &enemy_battlefield_names {
+        move_card_to_battlefield(&mut battle, &tabula, PlayerName::Two, name);
+    }
+
+    for (player, name) in &stack_cards {
+        play_card_to_stack(&mut battle, &tabula, *player, name);
     }
 
-    write_save(&battle, &save_dir);
+    write_save(&battle, &save_dir, slot);
     eprintln!("Save file written to {}", save_dir.display());
 }
 
@@ -88,18 +167,21 @@ fn print_usage() {
     eprintln!("Options:");
     eprintln!("  --energy <N>        Set player energy to N");
     eprintln!("  --card <NAME>       Add a card to player's hand (can be repeated)");
+    eprintln!("  --enemy-energy <N>  Set enemy energy to N");
+    eprintln!("  --enemy-card <NAME> Add a card to enemy's hand (can be repeated)");
+    eprintln!("  --enemy-battlefield <NAME>");
+    eprintln!("                      Put a card onto enemy's battlefield (can be repeated)");
+    eprintln!("  --stack-card <NAME> Play a card to the stack for player (can be repeated)");
+    eprintln!("  --enemy-stack-card <NAME>");
+    eprintln!("                      Play a card to the stack for enemy (can be repeated)");
+    eprintln!("  --turn <N>          Set the turn number, alternating the active player");
     eprintln!("  --save-dir <DIR>    Override save file directory");
+    eprintln!("  --slot <N>          Write to save slot N instead of the default slot");
+    eprintln!("  --scenario <FILE>   Set up the battle from a TOML scenario file");
     eprintln!("  --list-cards        List all available card names");
     eprintln!("  --help              Show this help message");
 }
 
-/// Returns the default macOS save directory.
-fn default_save_dir() -> PathBuf {
-    dirs::home_dir()
-        .expect("Cannot determine home directory")
-        .join("Library/Application Support/Dreamtides/Dreamtides")
-}
-
 /// Loads the Tabula card database from the local tabula/ directory.
 fn load_tabula() -> Arc<Tabula> {
     let tabula_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tabula");
@@ -121,51 +203,141 @@ fn create_battle(tabula: Arc<Tabula>) -> BattleState {
         CreateBattlePlayer {
             player_type: PlayerType::User(user_id),
             deck_name: TestDeckName::Core11,
+            personality: None,
         },
         CreateBattlePlayer {
             player_type: PlayerType::Agent(GameAI::MonteCarlo(5)),
             deck_name: TestDeckName::Core11,
+            personality: None,
         },
         RequestContext::default(),
     )
 }
 
-/// Sets both current and produced energy for player one.
-fn set_energy(battle: &mut BattleState, amount: Energy) {
-    battle.players.one.current_energy = amount;
-    battle.players.one.produced_energy = amount;
+/// Sets the turn number.
+///
+/// Players alternate turns, so the active player is switched if the new turn
+/// number has a different parity from the current one.
+fn set_turn(battle: &mut BattleState, turn_id: TurnId) {
+    if turn_id.0 % 2 != battle.turn.turn_id.0 % 2 {
+        battle.turn.active_player = battle.turn.active_player.opponent();
+    }
+    battle.turn.turn_id = turn_id;
 }
 
-/// Finds a card by name in the player's deck and moves it to their hand.
-fn move_card_to_hand(battle: &mut BattleState, tabula: &Tabula, card_name: &str) {
-    let target_name_lower = card_name.to_lowercase();
+/// Sets both current and produced energy for a player.
+fn set_energy(battle: &mut BattleState, player: PlayerName, amount: Energy) {
+    let state = battle.players.player_mut(player);
+    state.current_energy = amount;
+    state.produced_energy = amount;
+}
 
-    // Find all deck cards for player one (both shuffled and top-of-deck)
-    let deck_cards: Vec<BattleDeckCardId> = battle.cards.all_deck_cards(PlayerName::One).collect();
-
-    for deck_card_id in deck_cards {
-        let identity = battle.cards[deck_card_id].identity;
-        let definition = battle.card_definitions.get_definition(identity);
-        if definition.displayed_name.to_lowercase() == target_name_lower {
-            move_card::from_deck_to_hand(
-                battle,
-                EffectSource::Game { controller: PlayerName::One },
-                PlayerName::One,
-                deck_card_id,
-            );
-            eprintln!("Moved '{}' to hand", definition.displayed_name);
-            return;
-        }
-    }
+/// Finds a card by name in a player's deck and moves it to their hand.
+fn move_card_to_hand(
+    battle: &mut BattleState,
+    tabula: &Tabula,
+    player: PlayerName,
+    card_name: &str,
+) {
+    let Some(deck_card_id) = find_deck_card(battle, player, card_name) else {
+        report_missing_card(tabula, card_name);
+        return;
+    };
+    move_card::from_deck_to_hand(
+        battle,
+        EffectSource::Game { controller: player },
+        player,
+        deck_card_id,
+    );
+    let definition = battle.card_definitions.get_definition(battle.cards[deck_card_id].identity);
+    eprintln!("Moved '{}' to {player:?}'s hand", definition.displayed_name);
+}
+
+/// Finds a card by name in a player's deck and moves it to their battlefield.
+fn move_card_to_battlefield(
+    battle: &mut BattleState,
+    tabula: &Tabula,
+    player: PlayerName,
+    card_name: &str,
+) {
+    let Some(deck_card_id) = find_deck_card(battle, player, card_name) else {
+        report_missing_card(tabula, card_name);
+        return;
+    };
+    move_card::from_deck_to_battlefield(
+        battle,
+        EffectSource::Game { controller: player },
+        player,
+        deck_card_id,
+    );
+    let definition = battle.card_definitions.get_definition(battle.cards[deck_card_id].identity);
+    eprintln!("Moved '{}' to {player:?}'s battlefield", definition.displayed_name);
+}
+
+/// Plays a card from a player's deck to the stack, as if it had been drawn
+/// and then played from hand.
+///
+/// The card's targeting prompts and "played" triggers are added as usual.
+/// Its cost is granted before it is played, so the player's energy is
+/// unchanged.
+fn play_card_to_stack(
+    battle: &mut BattleState,
+    tabula: &Tabula,
+    player: PlayerName,
+    card_name: &str,
+) {
+    let Some(deck_card_id) = find_deck_card(battle, player, card_name) else {
+        report_missing_card(tabula, card_name);
+        return;
+    };
+    let hand_card_id = move_card::from_deck_to_hand(
+        battle,
+        EffectSource::Game { controller: player },
+        player,
+        deck_card_id,
+    );
+    let cost = card_properties::converted_energy_cost(battle, hand_card_id);
+    battle.players.player_mut(player).current_energy += cost;
+    play_card::from_hand(battle, player, hand_card_id);
+    let definition = battle.card_definitions.get_definition(battle.cards[deck_card_id].identity);
+    eprintln!("Played '{}' to the stack for {player:?}", definition.displayed_name);
+}
 
-    // Card not in deck, check if it's in tabula at all
+/// Finds a card by name in a player's deck (both shuffled and top-of-deck).
+fn find_deck_card(
+    battle: &BattleState,
+    player: PlayerName,
+    card_name: &str,
+) -> Option<BattleDeckCardId> {
+    find_card(battle, battle.cards.all_deck_cards(player), card_name)
+}
+
+/// Finds a card by name among the given cards.
+fn find_card<T: CardIdType>(
+    battle: &BattleState,
+    cards: impl IntoIterator<Item = T>,
+    card_name: &str,
+) -> Option<T> {
+    let target_name_lower = card_name.to_lowercase();
+    cards.into_iter().find(|&card_id| {
+        let definition = battle.card_definitions.get_definition(battle.cards[card_id].identity);
+        definition.displayed_name.to_lowercase() == target_name_lower
+    })
+}
+
+/// Reports a card which could not be found in a player's deck.
+///
+/// Exits with an error and a suggested card name if the card does not exist
+/// at all.
+fn report_missing_card(tabula: &Tabula, card_name: &str) {
+    let target_name_lower = card_name.to_lowercase();
     let exists_in_tabula =
         tabula.cards.values().any(|def| def.displayed_name.to_lowercase() == target_name_lower);
 
     if exists_in_tabula {
         eprintln!(
             "Warning: '{card_name}' exists but is not in the player's deck. \
-             It may already be in hand."
+             It may already be in another zone."
         );
     } else {
         let mut closest: Option<(usize, &str)> = None;
@@ -195,14 +367,17 @@ fn list_cards() {
 }
 
 /// Writes the battle state as a save file.
-fn write_save(battle: &BattleState, save_dir: &Path) {
+fn write_save(battle: &BattleState, save_dir: &Path, slot: SaveSlotId) {
     let user_id = UserId(UNITY_USER_ID);
     let quest_id = QuestId(Uuid::new_v4());
     let save = SaveFile::V1(Box::new(SaveFileV1 {
         id: user_id,
         quest: Some(QuestSaveFile { id: quest_id, battle: Some(battle.clone()) }),
+        collection: Collection::default(),
+        match_history: MatchHistory::default(),
     }));
-    save_file_io::write_save_to_dir(save_dir, &save).expect("Failed to write save file");
+    save_file_io::write_save_to_slot(save_dir, &save, slot, SaveFileOptions::default())
+        .expect("Failed to write save file");
 }
 
 /// Computes the Levenshtein distance between two strings.
@@ -0,0 +1,260 @@
+use std::fs;
+use std::path::Path;
+
+use battle_mutations::card_mutations::move_card;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_turn_phase::BattleTurnPhase;
+use battle_state::battle::card_id::CardIdType;
+use battle_state::core::effect_source::EffectSource;
+use battle_state::triggers::trigger::{Trigger, TriggerName};
+use core_data::numerics::{Energy, Points, TurnId};
+use core_data::types::PlayerName;
+use serde::Deserialize;
+use tabula_data::tabula::Tabula;
+
+/// Battle setup described by a TOML scenario file.
+///
+/// Every field is optional; anything left out keeps the value from a newly
+/// started battle.
+///
+/// ```toml
+/// turn = 4
+/// active_player = "One"
+/// phase = "Main"
+///
+/// [player_one]
+/// energy = 5
+/// points = 10
+/// hand = ["Card Name"]
+/// battlefield = ["Other Card Name"]
+///
+/// [player_two]
+/// void = ["Card Name"]
+///
+/// [[stack]]
+/// player = "Two"
+/// card = "Card Name"
+///
+/// [[triggers]]
+/// event = "Materialized"
+/// player = "One"
+/// card = "Other Card Name"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub turn: Option<u32>,
+    pub active_player: Option<PlayerName>,
+    pub phase: Option<BattleTurnPhase>,
+    #[serde(default)]
+    pub player_one: PlayerScenario,
+    #[serde(default)]
+    pub player_two: PlayerScenario,
+
+    /// Cards played to the stack in order, after both players are set up.
+    #[serde(default)]
+    pub stack: Vec<StackScenario>,
+
+    /// Trigger events queued after the stack is set up. They fire before the
+    /// next action is taken, as if they had just happened.
+    #[serde(default)]
+    pub triggers: Vec<TriggerScenario>,
+}
+
+/// Setup for one player in a [Scenario].
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerScenario {
+    /// Sets both current and produced energy, unless `produced_energy` is
+    /// also given.
+    pub energy: Option<u32>,
+    pub produced_energy: Option<u32>,
+    pub points: Option<u32>,
+
+    /// Replaces the player's opening hand. Cards already in hand are shuffled
+    /// back into the deck first.
+    pub hand: Option<Vec<String>>,
+
+    /// Cards moved from the player's deck to their battlefield.
+    #[serde(default)]
+    pub battlefield: Vec<String>,
+
+    /// Cards moved from the player's deck to their void.
+    #[serde(default)]
+    pub void: Vec<String>,
+}
+
+/// A card played to the stack in a [Scenario].
+///
+/// The card is moved from the player's deck to their hand and then played
+/// normally, so its targeting prompts and "played" triggers are added. Its
+/// cost is granted first, so the player's energy is unchanged.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StackScenario {
+    pub player: PlayerName,
+    pub card: String,
+}
+
+/// A trigger event queued in a [Scenario].
+///
+/// Events with no listening cards in play have no effect.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerScenario {
+    pub event: TriggerName,
+    pub player: PlayerName,
+
+    /// Card the event happened to, for events about a card. Materialized
+    /// looks for the card on the player's battlefield, played events on the
+    /// stack, and the remaining card events in the player's void.
+    pub card: Option<String>,
+
+    /// Amount of energy gained, for GainedEnergy events.
+    pub energy: Option<u32>,
+}
+
+/// Reads a scenario file, exiting with an error message if it is invalid.
+pub fn load(path: &Path) -> Scenario {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Error: Cannot read scenario file {}: {e}", path.display());
+        std::process::exit(1);
+    });
+    toml::from_str(&text).unwrap_or_else(|e| {
+        eprintln!("Error: Invalid scenario file {}: {e}", path.display());
+        std::process::exit(1);
+    })
+}
+
+/// Applies a scenario to a newly started battle.
+pub fn apply(battle: &mut BattleState, tabula: &Tabula, scenario: &Scenario) {
+    apply_player(battle, tabula, PlayerName::One, &scenario.player_one);
+    apply_player(battle, tabula, PlayerName::Two, &scenario.player_two);
+    if let Some(turn) = scenario.turn {
+        battle.turn.turn_id = TurnId(turn);
+    }
+    if let Some(player) = scenario.active_player {
+        battle.turn.active_player = player;
+    }
+    if let Some(phase) = scenario.phase {
+        battle.phase = phase;
+    }
+    for entry in &scenario.stack {
+        crate::play_card_to_stack(battle, tabula, entry.player, &entry.card);
+    }
+    for entry in &scenario.triggers {
+        let trigger = trigger(battle, entry);
+        battle.triggers.push(EffectSource::Game { controller: entry.player }, trigger);
+        eprintln!("Queued trigger {trigger:?}");
+    }
+}
+
+fn apply_player(
+    battle: &mut BattleState,
+    tabula: &Tabula,
+    player: PlayerName,
+    scenario: &PlayerScenario,
+) {
+    let source = EffectSource::Game { controller: player };
+    if let Some(hand) = &scenario.hand {
+        let current = battle.cards.hand(player).iter().collect::<Vec<_>>();
+        for card_id in current {
+            move_card::from_hand_to_deck(battle, source, player, card_id);
+        }
+        for name in hand {
+            crate::move_card_to_hand(battle, tabula, player, name);
+        }
+    }
+    for name in &scenario.battlefield {
+        crate::move_card_to_battlefield(battle, tabula, player, name);
+    }
+    for name in &scenario.void {
+        if let Some(card_id) = crate::find_deck_card(battle, player, name) {
+            move_card::from_deck_to_void(battle, source, player, card_id);
+        } else {
+            crate::report_missing_card(tabula, name);
+        }
+    }
+
+    let state = battle.players.player_mut(player);
+    if let Some(energy) = scenario.energy {
+        state.current_energy = Energy(energy);
+        state.produced_energy = Energy(energy);
+    }
+    if let Some(produced) = scenario.produced_energy {
+        state.produced_energy = Energy(produced);
+    }
+    if let Some(points) = scenario.points {
+        state.points = Points(points);
+    }
+}
+
+fn trigger(battle: &BattleState, entry: &TriggerScenario) -> Trigger {
+    let player = entry.player;
+    match entry.event {
+        TriggerName::Abandonded => {
+            Trigger::Abandonded(trigger_card(battle, entry, "void", battle.cards.void(player)))
+        }
+        TriggerName::Banished => {
+            Trigger::Banished(trigger_card(battle, entry, "void", battle.cards.void(player)))
+        }
+        TriggerName::Discarded => {
+            Trigger::Discarded(trigger_card(battle, entry, "void", battle.cards.void(player)))
+        }
+        TriggerName::Dissolved => {
+            Trigger::Dissolved(trigger_card(battle, entry, "void", battle.cards.void(player)))
+        }
+        TriggerName::PutIntoVoid => {
+            Trigger::PutIntoVoid(trigger_card(battle, entry, "void", battle.cards.void(player)))
+        }
+        TriggerName::DrewAllCardsInCopyOfDeck => Trigger::DrewAllCardsInCopyOfDeck(player),
+        TriggerName::EndOfTurn => Trigger::EndOfTurn(player),
+        TriggerName::GainedEnergy => {
+            Trigger::GainedEnergy(player, Energy(entry.energy.unwrap_or_default()))
+        }
+        TriggerName::Judgment => Trigger::Judgment(player),
+        TriggerName::Materialized => Trigger::Materialized(trigger_card(
+            battle,
+            entry,
+            "battlefield",
+            battle.cards.battlefield(player),
+        )),
+        TriggerName::PlayedCard => Trigger::PlayedCard(trigger_card(
+            battle,
+            entry,
+            "stack",
+            battle.cards.stack_set(player),
+        )),
+        TriggerName::PlayedCardFromHand => Trigger::PlayedCardFromHand(trigger_card(
+            battle,
+            entry,
+            "stack",
+            battle.cards.stack_set(player),
+        )),
+        TriggerName::PlayedCardFromVoid => Trigger::PlayedCardFromVoid(trigger_card(
+            battle,
+            entry,
+            "stack",
+            battle.cards.stack_set(player),
+        )),
+        TriggerName::StartOfTurn => Trigger::StartOfTurn(player),
+    }
+}
+
+/// Finds the card named by a trigger event in one of the player's zones,
+/// exiting with an error message if it is missing.
+fn trigger_card<T: CardIdType>(
+    battle: &BattleState,
+    entry: &TriggerScenario,
+    zone: &str,
+    cards: impl IntoIterator<Item = T>,
+) -> T {
+    let Some(name) = &entry.card else {
+        eprintln!("Error: {:?} trigger requires a card", entry.event);
+        std::process::exit(1);
+    };
+    crate::find_card(battle, cards, name).unwrap_or_else(|| {
+        eprintln!("Error: '{name}' is not in {:?}'s {zone}", entry.player);
+        std::process::exit(1);
+    })
+}
@@ -1,2 +1,3 @@
 pub mod test_battle;
+pub mod test_battle_state;
 pub mod test_player;
@@ -1,9 +1,11 @@
 use ai_data::game_ai::GameAI;
+use ai_data::personality::AiPersonality;
 use battle_state::actions::battle_actions::BattleAction;
 use battle_state::actions::debug_battle_action::DebugBattleAction;
 use battle_state::battle_player::battle_player_state::PlayerType;
-use core_data::identifiers::BattleId;
+use core_data::identifiers::{BattleId, SaveSlotId};
 use core_data::types::PlayerName;
+use display_data::request_data::{AnimationPreferences, ClientCapability};
 use tabula_generated::card_lists::DreamwellCardIdList;
 use uuid::Uuid;
 
@@ -62,6 +64,12 @@ impl TestBattle {
         self
     }
 
+    /// Sets the play style biasing the enemy AI agent's search.
+    pub fn enemy_personality(mut self, personality: AiPersonality) -> Self {
+        self.session.enemy_personality = Some(personality);
+        self
+    }
+
     /// Sets the seed for deterministic random number generation.
     pub fn seed(mut self, seed: u64) -> Self {
         self.session.seed = Some(seed);
@@ -74,6 +82,49 @@ impl TestBattle {
         self
     }
 
+    /// Requests battle view deltas instead of full snapshots.
+    pub fn battle_view_deltas(mut self) -> Self {
+        self.session = self.session.with_battle_view_deltas();
+        self
+    }
+
+    /// Sets the optional protocol features the client declares support for.
+    pub fn capabilities(mut self, capabilities: Vec<ClientCapability>) -> Self {
+        self.session = self.session.with_capabilities(capabilities);
+        self
+    }
+
+    /// Requests that text for the user be rendered in the given language.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.session = self.session.with_language(language);
+        self
+    }
+
+    /// Requests that text for the user be colored using the colorblind-safe
+    /// palette.
+    pub fn colorblind_mode(mut self) -> Self {
+        self.session = self.session.with_colorblind_mode();
+        self
+    }
+
+    /// Requests that scripted tutorial messages be shown to the user.
+    pub fn tutorial(mut self) -> Self {
+        self.session = self.session.with_tutorial();
+        self
+    }
+
+    /// Sets how animations should be played for the user.
+    pub fn animation_preferences(mut self, preferences: AnimationPreferences) -> Self {
+        self.session = self.session.with_animation_preferences(preferences);
+        self
+    }
+
+    /// Sets the save slot the user's battle is created in.
+    pub fn save_slot(mut self, slot: SaveSlotId) -> Self {
+        self.session = self.session.with_save_slot(slot);
+        self
+    }
+
     /// Connects to the rules engine, returning the session struct. Moves all
     /// player hands into their decks.
     ///
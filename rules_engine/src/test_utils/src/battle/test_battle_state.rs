@@ -0,0 +1,39 @@
+use ai_data::game_ai::GameAI;
+use battle_state::battle::battle_state::{BattleState, RequestContext};
+use battle_state::battle_cards::dreamwell_data::Dreamwell;
+use battle_state::battle_player::battle_player_state::{
+    CreateBattlePlayer, PlayerType, TestDeckName,
+};
+use core_data::identifiers::BattleId;
+use game_creation::new_test_battle;
+use state_provider::display_state_provider::DisplayStateProvider;
+use state_provider::test_state_provider::TestStateProvider;
+use tabula_generated::card_lists::DreamwellCardIdList;
+use uuid::Uuid;
+
+/// Creates and starts a battle between two agents playing the Vanilla test
+/// deck, without connecting a client.
+///
+/// Used by tests which operate directly on a [BattleState], such as tests of
+/// AI search. Use [crate::battle::test_battle::TestBattle] to test the battle
+/// through the rules engine instead.
+pub fn create(seed: u64) -> BattleState {
+    let provider = TestStateProvider::new();
+    let agent = || CreateBattlePlayer {
+        player_type: PlayerType::Agent(GameAI::FirstAvailableAction),
+        deck_name: TestDeckName::Vanilla,
+        personality: None,
+    };
+    new_test_battle::create_and_start(
+        BattleId(Uuid::new_v4()),
+        provider.tabula(),
+        seed,
+        Dreamwell::from_card_list(
+            &provider.tabula(),
+            DreamwellCardIdList::TestDreamwellNoAbilities,
+        ),
+        agent(),
+        agent(),
+        RequestContext::default(),
+    )
+}
@@ -1,13 +1,15 @@
 use action_data::game_action_data::GameAction;
 use core_data::display_types::AudioClipAddress;
 use core_data::identifiers::BattleId;
+use display::rendering::battle_view_diff;
 use display_data::battle_view::{
-    BattlePreviewState, BattlePreviewView, ButtonView, DisplayPlayer, InterfaceView,
+    BattlePreviewState, BattlePreviewView, ButtonView, DisplayPlayer, InterfaceView, TargetingView,
 };
 use display_data::card_view::CardView;
+use display_data::collection_view::CollectionView;
 use display_data::command::{
     Command, CommandSequence, DisplayArrow, GameMessageType, UpdateBattleCommand,
-    UpdateQuestCommand,
+    UpdateBattleDeltaCommand, UpdateQuestCommand,
 };
 
 use crate::client::test_client_cards::{TestClientCard, TestClientCards};
@@ -30,12 +32,18 @@ pub struct TestClient {
     pub arrows: Vec<DisplayArrow>,
     /// Current battle preview state
     pub preview: Option<BattlePreviewState>,
+    /// Current legal targets for the pending prompt
+    pub targeting: Option<TargetingView>,
+    /// Current estimated probability that this player wins the battle
+    pub win_probability: Option<f64>,
     /// Last played audio clip
     pub last_audio_clip: Option<AudioClipAddress>,
     /// Last displayed game message
     pub last_game_message: Option<GameMessageType>,
     /// Cards drawn by the user during the last draw command
     pub last_drawn_cards: Vec<CardView>,
+    /// Last displayed card collection
+    pub collection: Option<CollectionView>,
 }
 
 impl TestClient {
@@ -48,7 +56,11 @@ impl TestClient {
             for command in group.commands {
                 match command {
                     Command::UpdateBattle(update) => self.handle_update_battle(*update),
+                    Command::UpdateBattleDelta(update) => self.handle_update_battle_delta(*update),
                     Command::UpdateQuest(update) => self.handle_update_quest(*update),
+                    Command::UpdateCollection(update) => {
+                        self.collection = Some(update.collection);
+                    }
                     Command::Wait(_) => {}
                     Command::FireProjectile(_) => {}
                     Command::DissolveCard(_) => {}
@@ -101,6 +113,7 @@ impl TestClient {
                         }
                     },
                     Command::DisplayEnemyMessage(_) => {}
+                    Command::DisplayNotification(_) => {}
                     Command::PlayStudioAnimation(_) => {}
                     Command::PlayMecanimAnimation(_) => {}
                     Command::SetCardTrail(_) => {}
@@ -111,6 +124,9 @@ impl TestClient {
                         }
                     }
                     Command::AnchorToScreenPosition(..) => {}
+                    Command::DisplayCinematic(_) => {}
+                    Command::WaitForInput(_) => {}
+                    Command::DisplayEmote(_) => {}
                 }
             }
         }
@@ -135,8 +151,56 @@ impl TestClient {
 
         self.arrows = battle.arrows;
 
+        self.targeting = battle.targeting;
+
         self.preview = Some(battle.preview);
 
+        self.win_probability = battle.win_probability;
+
+        if let Some(sound) = update.update_sound {
+            self.last_audio_clip = Some(sound);
+        }
+    }
+
+    fn handle_update_battle_delta(&mut self, update: UpdateBattleDeltaCommand) {
+        let delta = update.delta;
+
+        self.battle_id = Some(delta.id);
+
+        if let Some(user) = delta.user {
+            self.me.view = Some(user);
+        }
+        if let Some(enemy) = delta.enemy {
+            self.opponent.view = Some(enemy);
+        }
+
+        for id in &delta.removed_cards {
+            self.cards.card_map.remove(id);
+        }
+        for change in delta.changed_cards {
+            if let Some(card) = self.cards.card_map.get_mut(&change.id) {
+                battle_view_diff::apply_card_delta(&mut card.view, change);
+            }
+        }
+        for card in delta.cards {
+            self.cards
+                .card_map
+                .insert(card.id.clone(), TestClientCard { id: card.id.clone(), view: card });
+        }
+
+        if let Some(interface) = delta.interface {
+            self.interface = TestInterfaceView::new(Some(interface));
+        }
+        if let Some(arrows) = delta.arrows {
+            self.arrows = arrows;
+        }
+        if let Some(preview) = delta.preview {
+            self.preview = Some(preview);
+        }
+        self.targeting = delta.targeting;
+
+        self.win_probability = delta.win_probability;
+
         if let Some(sound) = update.update_sound {
             self.last_audio_clip = Some(sound);
         }
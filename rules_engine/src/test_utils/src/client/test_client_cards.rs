@@ -53,14 +53,34 @@ impl TestClientCards {
         self.cards_at_position(&Position::InHand(DisplayPlayer::Enemy))
     }
 
-    /// Get all cards on the user's battlefield
+    /// Get all cards on the user's battlefield, in any row or lane
     pub fn user_battlefield(&self) -> TestClientCardList<'_> {
-        self.cards_at_position(&Position::OnBattlefield(DisplayPlayer::User))
+        let cards = self
+            .card_map
+            .values()
+            .filter(|card| {
+                matches!(
+                    &card.view.position.position,
+                    Position::OnBattlefield(battlefield) if battlefield.player == DisplayPlayer::User
+                )
+            })
+            .collect();
+        TestClientCardList::new(cards)
     }
 
-    /// Get all cards on the enemy's battlefield
+    /// Get all cards on the enemy's battlefield, in any row or lane
     pub fn enemy_battlefield(&self) -> TestClientCardList<'_> {
-        self.cards_at_position(&Position::OnBattlefield(DisplayPlayer::Enemy))
+        let cards = self
+            .card_map
+            .values()
+            .filter(|card| {
+                matches!(
+                    &card.view.position.position,
+                    Position::OnBattlefield(battlefield) if battlefield.player == DisplayPlayer::Enemy
+                )
+            })
+            .collect();
+        TestClientCardList::new(cards)
     }
 
     /// Get all cards in the user's void
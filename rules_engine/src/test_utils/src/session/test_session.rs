@@ -1,12 +1,14 @@
 use action_data::game_action_data::GameAction;
+use ai_data::personality::AiPersonality;
 use battle_state::battle::battle_state::{LoggingOptions, RequestContext};
 use battle_state::battle_player::battle_player_state::{PlayerType, TestDeckName};
-use core_data::identifiers::{BattleId, UserId};
+use core_data::identifiers::{BattleId, SaveSlotId, UserId};
 use core_data::types::PlayerName;
 use display_data::battle_view::DisplayPlayer;
-use display_data::command::CommandSequence;
+use display_data::command::{Command, CommandSequence, UpdateBattleCommand};
 use display_data::request_data::{
-    ConnectRequest, ConnectResponse, DebugConfiguration, Metadata, PerformActionRequest,
+    AnimationPreferences, ClientCapability, ConnectRequest, ConnectResponse, DebugConfiguration,
+    Metadata, PROTOCOL_VERSION, PerformActionRequest, ResyncRequest, ResyncResponse,
 };
 use rules_engine::engine;
 use state_provider::test_state_provider::TestStateProvider;
@@ -28,6 +30,14 @@ pub struct TestSession {
     pub last_user_commands: Option<CommandSequence>,
     pub last_enemy_commands: Option<CommandSequence>,
     pub dreamwell_list: Option<DreamwellCardIdList>,
+    pub enemy_personality: Option<AiPersonality>,
+    pub battle_view_deltas: bool,
+    pub language: Option<String>,
+    pub colorblind_mode: bool,
+    pub capabilities: Vec<ClientCapability>,
+    pub tutorial: bool,
+    pub animation_preferences: AnimationPreferences,
+    pub save_slot: SaveSlotId,
 }
 
 impl Default for TestSession {
@@ -51,6 +61,19 @@ impl TestSession {
             last_user_commands: None,
             last_enemy_commands: None,
             dreamwell_list: None,
+            enemy_personality: None,
+            battle_view_deltas: false,
+            language: None,
+            colorblind_mode: false,
+            capabilities: vec![
+                ClientCapability::InputBarriers,
+                ClientCapability::Notifications,
+                ClientCapability::Emotes,
+                ClientCapability::CollectionBrowser,
+            ],
+            tutorial: false,
+            animation_preferences: AnimationPreferences::default(),
+            save_slot: SaveSlotId::default(),
         }
     }
 
@@ -73,6 +96,53 @@ impl TestSession {
         self
     }
 
+    /// Requests battle view deltas instead of full snapshots when connecting
+    pub fn with_battle_view_deltas(mut self) -> Self {
+        self.battle_view_deltas = true;
+        self
+    }
+
+    /// Requests that text for the user be rendered in the given language when
+    /// connecting
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Requests that text for the user be colored using the colorblind-safe
+    /// palette when connecting
+    pub fn with_colorblind_mode(mut self) -> Self {
+        self.colorblind_mode = true;
+        self
+    }
+
+    /// Sets the optional protocol features the client declares support for
+    /// when connecting
+    pub fn with_capabilities(mut self, capabilities: Vec<ClientCapability>) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Requests that scripted tutorial messages be shown to the user when
+    /// connecting
+    pub fn with_tutorial(mut self) -> Self {
+        self.tutorial = true;
+        self
+    }
+
+    /// Sets how animations should be played for the user when connecting
+    pub fn with_animation_preferences(mut self, preferences: AnimationPreferences) -> Self {
+        self.animation_preferences = preferences;
+        self
+    }
+
+    /// Sets the save slot to load and store the user's battle in when
+    /// connecting
+    pub fn with_save_slot(mut self, slot: SaveSlotId) -> Self {
+        self.save_slot = slot;
+        self
+    }
+
     /// Connects to the rules engine and applies the commands to the client.
     pub fn connect(&mut self) -> ConnectResponse {
         self.connect_with_opponent(Some(PlayerType::User(self.enemy_id)))
@@ -98,6 +168,7 @@ impl TestSession {
                 display_properties: None,
                 debug_configuration: Some(DebugConfiguration {
                     enemy: opponent.clone(),
+                    enemy_personality: self.enemy_personality,
                     seed: self.seed.or(Some(314159265358979323)),
                     deck_override: Some(TestDeckName::Vanilla),
                     dreamwell_override: Some(
@@ -107,6 +178,14 @@ impl TestSession {
                             .clone(),
                     ),
                 }),
+                battle_view_deltas: self.battle_view_deltas,
+                language: self.language.clone(),
+                colorblind_mode: self.colorblind_mode,
+                protocol_version: PROTOCOL_VERSION,
+                capabilities: self.capabilities.clone(),
+                tutorial: self.tutorial,
+                animation_preferences: self.animation_preferences,
+                save_slot: self.save_slot,
             },
             self.request_context(),
         );
@@ -133,6 +212,14 @@ impl TestSession {
                     vs_opponent: Some(self.user_id),
                     display_properties: None,
                     debug_configuration: None,
+                    battle_view_deltas: self.battle_view_deltas,
+                    language: None,
+                    colorblind_mode: false,
+                    protocol_version: PROTOCOL_VERSION,
+                    capabilities: self.capabilities.clone(),
+                    tutorial: false,
+                    animation_preferences: AnimationPreferences::default(),
+                    save_slot: SaveSlotId::default(),
                 },
                 self.request_context(),
             );
@@ -144,6 +231,22 @@ impl TestSession {
         response
     }
 
+    /// Requests the complete current battle state for the user, as a client
+    /// does after reconnecting, and applies it to the user's client.
+    pub fn resync(&mut self) -> ResyncResponse {
+        let response = engine::resync_with_provider(self.state_provider.clone(), &ResyncRequest {
+            metadata: self.metadata(),
+            save_file_id: Some(self.user_id),
+        });
+        self.last_user_response_version = Some(response.response_version);
+        if let Some(battle) = &response.battle {
+            self.user_client.apply_commands(CommandSequence::from_command(Command::UpdateBattle(
+                Box::new(UpdateBattleCommand::new(battle.clone())),
+            )));
+        }
+        response
+    }
+
     /// Performs a Game Action as the user player.
     ///
     /// This function will call the rules engine to execute the provided action
@@ -1,4 +1,5 @@
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::toml::metadata_types::ColumnConfig;
 use crate::toml::{metadata, metadata_serializer};
 use crate::traits::TvConfig;
@@ -6,6 +7,7 @@ use crate::traits::TvConfig;
 /// Tauri command to get the column configurations for a TOML file.
 #[tauri::command]
 pub fn get_column_configs(file_path: String) -> Result<Vec<ColumnConfig>, TvError> {
+    let _timer = command_timer::start("get_column_configs");
     tracing::debug!(
         component = "tv.commands.column",
         file_path = %file_path,
@@ -31,6 +33,7 @@ pub fn set_column_width(
     column_key: String,
     width: u32,
 ) -> Result<(), TvError> {
+    let _timer = command_timer::start("set_column_width");
     tracing::info!(
         component = "tv.commands.column",
         file_path = %file_path,
@@ -51,6 +54,7 @@ pub fn set_derived_column_width(
     column_name: String,
     width: u32,
 ) -> Result<(), TvError> {
+    let _timer = command_timer::start("set_derived_column_width");
     tracing::info!(
         component = "tv.commands.column",
         file_path = %file_path,
@@ -0,0 +1,59 @@
+use tauri::AppHandle;
+
+use crate::error::error_types::TvError;
+use crate::logging::command_timer;
+use crate::sync::state_machine;
+use crate::toml::cell_comments::{self, NewComment};
+use crate::toml::metadata_types::CommentThread;
+use crate::traits::TvConfig;
+
+/// Tauri command to add a comment to a cell, starting a new thread or
+/// replying to an existing one.
+#[tauri::command]
+pub fn add_comment(
+    app_handle: AppHandle,
+    file_path: String,
+    table_name: String,
+    comment: NewComment,
+) -> Result<CommentThread, TvError> {
+    let _timer = command_timer::start("add_comment");
+    tracing::debug!(
+        component = "tv.commands.comment",
+        file_path = %file_path,
+        row_index = comment.row_index,
+        column = %comment.column,
+        "Adding comment"
+    );
+    state_machine::begin_save(&app_handle, &file_path)?;
+
+    let result =
+        cell_comments::add_comment(&TvConfig::default(), &file_path, &table_name, &comment);
+    let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
+
+    result
+}
+
+/// Tauri command to resolve or reopen a comment thread.
+#[tauri::command]
+pub fn resolve_comment_thread(
+    app_handle: AppHandle,
+    file_path: String,
+    thread_id: String,
+    resolved: bool,
+) -> Result<CommentThread, TvError> {
+    let _timer = command_timer::start("resolve_comment_thread");
+    tracing::debug!(
+        component = "tv.commands.comment",
+        file_path = %file_path,
+        thread_id = %thread_id,
+        resolved = resolved,
+        "Setting comment thread resolution"
+    );
+    state_machine::begin_save(&app_handle, &file_path)?;
+
+    let result =
+        cell_comments::set_thread_resolved(&TvConfig::default(), &file_path, &thread_id, resolved);
+    let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
+
+    result
+}
@@ -7,6 +7,7 @@ use crate::derived::compute_executor::{ComputationRequest, ComputeExecutorState}
 use crate::derived::derived_types::{LookupContext, RowData};
 use crate::derived::generation_tracker::RowKey;
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::toml::metadata;
 
 /// Request to compute a derived column value.
@@ -50,6 +51,7 @@ pub fn compute_derived(
     executor_state: State<ComputeExecutorState>,
     request: ComputeDerivedRequest,
 ) -> Result<(), TvError> {
+    let _timer = command_timer::start("compute_derived");
     // Get the current generation for this row
     let generation = executor_state
         .with_executor(|executor| {
@@ -93,6 +95,7 @@ pub fn compute_derived_batch(
     executor_state: State<ComputeExecutorState>,
     batch: ComputeDerivedBatchRequest,
 ) -> Result<(), TvError> {
+    let _timer = command_timer::start("compute_derived_batch");
     let requests: Vec<ComputationRequest> = batch
         .requests
         .into_iter()
@@ -138,6 +141,7 @@ pub fn update_lookup_context(
     executor_state: State<ComputeExecutorState>,
     request: UpdateLookupContextRequest,
 ) -> Result<(), TvError> {
+    let _timer = command_timer::start("update_lookup_context");
     let mut context = LookupContext::new();
     let mut table_count = 0;
     let mut row_count = 0;
@@ -174,6 +178,7 @@ pub fn increment_row_generation(
     table_name: String,
     row_index: usize,
 ) -> Result<u64, TvError> {
+    let _timer = command_timer::start("increment_row_generation");
     let generation = executor_state
         .with_executor(|executor| {
             executor
@@ -202,6 +207,7 @@ pub fn increment_row_generation(
 /// Useful when switching files or tables to avoid processing stale requests.
 #[tauri::command]
 pub fn clear_computation_queue(executor_state: State<ComputeExecutorState>) -> Result<(), TvError> {
+    let _timer = command_timer::start("clear_computation_queue");
     executor_state.with_executor(|executor| {
         executor.clear_queue();
     });
@@ -217,6 +223,7 @@ pub fn clear_computation_queue(executor_state: State<ComputeExecutorState>) -> R
 /// Tauri command to get the current computation queue length.
 #[tauri::command]
 pub fn get_computation_queue_length(executor_state: State<ComputeExecutorState>) -> Result<usize, TvError> {
+    let _timer = command_timer::start("get_computation_queue_length");
     let length = executor_state.with_executor(|executor| executor.queue_len()).unwrap_or(0);
 
     Ok(length)
@@ -244,6 +251,7 @@ pub struct DerivedColumnInfo {
 /// Tauri command to get derived column configurations from a file's metadata.
 #[tauri::command]
 pub fn get_derived_columns_config(file_path: String) -> Result<Vec<DerivedColumnInfo>, TvError> {
+    let _timer = command_timer::start("get_derived_columns_config");
     let command_start = std::time::Instant::now();
 
     let parse_start = std::time::Instant::now();
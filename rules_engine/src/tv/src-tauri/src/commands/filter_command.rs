@@ -2,6 +2,7 @@ use tauri::State;
 
 use crate::filter::filter_state::FilterStateManager;
 use crate::filter::filter_types::FilterState;
+use crate::logging::command_timer;
 use crate::toml::metadata_types::{ColumnFilter, FilterCondition};
 
 #[derive(serde::Deserialize)]
@@ -50,6 +51,7 @@ pub fn get_filter_state(
     file_path: String,
     table_name: String,
 ) -> FilterStateResponse {
+    let _timer = command_timer::start("get_filter_state");
     let filter_state = state.get_filter_state(&file_path, &table_name);
     tracing::debug!(
         component = "tv.commands.filter",
@@ -68,6 +70,7 @@ pub fn set_filter_state(
     table_name: String,
     filter: Option<SetFilterRequest>,
 ) -> FilterStateResponse {
+    let _timer = command_timer::start("set_filter_state");
     let new_state = filter.map(|f| {
         let filters = f
             .filters
@@ -96,6 +99,7 @@ pub fn clear_filter_state(
     file_path: String,
     table_name: String,
 ) -> FilterStateResponse {
+    let _timer = command_timer::start("clear_filter_state");
     tracing::info!(
         component = "tv.commands.filter",
         file_path = %file_path,
@@ -112,6 +116,7 @@ pub fn get_filter_visibility(
     file_path: String,
     table_name: String,
 ) -> Vec<bool> {
+    let _timer = command_timer::start("get_filter_visibility");
     let visibility = state.get_visibility(&file_path, &table_name);
     tracing::debug!(
         component = "tv.commands.filter",
@@ -130,6 +135,7 @@ pub fn is_row_visible(
     table_name: String,
     row_index: usize,
 ) -> bool {
+    let _timer = command_timer::start("is_row_visible");
     state.is_row_visible(&file_path, &table_name, row_index)
 }
 
@@ -140,6 +146,7 @@ pub fn set_hidden_rows(
     table_name: String,
     hidden_rows: Vec<usize>,
 ) {
+    let _timer = command_timer::start("set_hidden_rows");
     tracing::debug!(
         component = "tv.commands.filter",
         file_path = %file_path,
@@ -156,6 +163,7 @@ pub fn get_hidden_rows(
     file_path: String,
     table_name: String,
 ) -> Vec<usize> {
+    let _timer = command_timer::start("get_hidden_rows");
     state.get_hidden_rows(&file_path, &table_name)
 }
 
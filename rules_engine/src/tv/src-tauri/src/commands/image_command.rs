@@ -2,6 +2,7 @@ use tauri::State;
 
 use crate::error::error_types::TvError;
 use crate::images::image_fetcher::ImageFetcherState;
+use crate::logging::command_timer;
 
 /// Tauri command to fetch an image by URL.
 ///
@@ -12,6 +13,7 @@ pub async fn fetch_image(
     fetcher_state: State<'_, ImageFetcherState>,
     url: String,
 ) -> Result<String, TvError> {
+    let _timer = command_timer::start("fetch_image");
     let fetcher = fetcher_state.get().ok_or_else(|| TvError::ImageFetchError {
         url: url.clone(),
         message: "Image fetcher not initialized".to_string(),
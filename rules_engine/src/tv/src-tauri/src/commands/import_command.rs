@@ -0,0 +1,48 @@
+use tauri::AppHandle;
+
+use crate::error::error_types::TvError;
+use crate::import::csv_import::{self, CsvImportOptions, CsvImportResult};
+use crate::logging::command_timer;
+use crate::sync::state_machine;
+use crate::toml::document_writer::JournalOperation;
+use crate::traits::TvConfig;
+use crate::validation::card_editor_state;
+
+/// Tauri command to import rows from a CSV file into an existing table.
+///
+/// With `options.dry_run` set, returns a preview of the import without
+/// modifying the file.
+#[tauri::command]
+pub fn import_csv(
+    app_handle: AppHandle,
+    file_path: String,
+    table_name: String,
+    csv_path: String,
+    options: CsvImportOptions,
+) -> Result<CsvImportResult, TvError> {
+    let _timer = command_timer::start("import_csv");
+    tracing::debug!(
+        component = "tv.commands.import",
+        file_path = %file_path,
+        csv_path = %csv_path,
+        dry_run = options.dry_run,
+        "CSV import requested"
+    );
+    let config = TvConfig::default();
+    if options.dry_run {
+        return csv_import::import_csv_file(&config, &file_path, &table_name, &csv_path, &options);
+    }
+
+    state_machine::begin_save(&app_handle, &file_path)?;
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::ImportCsv,
+        || csv_import::import_csv_file(&config, &file_path, &table_name, &csv_path, &options),
+    );
+    let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
+
+    result
+}
@@ -4,10 +4,12 @@ use crate::error::error_types::TvError;
 use crate::error::permission_recovery;
 use crate::filter::filter_state::FilterStateManager;
 use crate::filter::filter_types::{ColumnFilterState, FilterConditionState, FilterState};
+use crate::logging::command_timer;
 use crate::sort::sort_state::SortStateManager;
 use crate::sort::sort_types::{SortDirection, SortState};
 use crate::sync::state_machine;
-use crate::toml::document_loader::{self, TomlTableData};
+use crate::toml::cell_comments;
+use crate::toml::document_loader::{self, LoadedTable};
 use crate::toml::metadata;
 use crate::toml::metadata_types::FilterCondition;
 use crate::traits::TvConfig;
@@ -20,7 +22,8 @@ pub fn load_toml_table(
     filter_state_manager: State<FilterStateManager>,
     file_path: String,
     table_name: String,
-) -> Result<TomlTableData, TvError> {
+) -> Result<LoadedTable, TvError> {
+    let _timer = command_timer::start("load_toml_table");
     if let Err(e) = state_machine::begin_load(&app_handle, &file_path) {
         tracing::warn!(
             component = "tv.commands.load",
@@ -57,7 +60,18 @@ pub fn load_toml_table(
     // Treat skipped files as successful for state machine purposes so we
     // transition to Idle (not Error) and don't show an error indicator.
     state_machine::end_load(&app_handle, &file_path, result.is_ok() || is_skipped);
-    result
+    let data = result?;
+    let comments = cell_comments::cell_comments(&TvConfig::default(), &file_path, &data)
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                component = "tv.commands.load",
+                file_path = %file_path,
+                error = %e,
+                "Failed to load comment threads, ignoring"
+            );
+            Vec::new()
+        });
+    Ok(LoadedTable { data, comments })
 }
 
 fn restore_sort_state_from_metadata(
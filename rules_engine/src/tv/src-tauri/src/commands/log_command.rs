@@ -1,8 +1,10 @@
+use crate::logging::command_timer;
 use crate::logging::log_aggregator::{self, FrontendLogMessage, FrontendPerfLogEntry};
 
 /// Tauri command to receive a log message from the frontend.
 #[tauri::command]
 pub fn log_message(message: FrontendLogMessage) {
+    let _timer = command_timer::start("log_message");
     log_aggregator::ingest(&message);
 }
 
@@ -10,5 +12,6 @@ pub fn log_message(message: FrontendLogMessage) {
 /// Performance entries are written to a dedicated log file for focused analysis.
 #[tauri::command]
 pub fn log_perf(entry: FrontendPerfLogEntry) {
+    let _timer = command_timer::start("log_perf");
     log_aggregator::ingest_perf(&entry);
 }
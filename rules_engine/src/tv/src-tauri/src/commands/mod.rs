@@ -1,16 +1,22 @@
 pub mod column_command;
+pub mod comment_command;
 pub mod derived_command;
 pub mod filter_command;
 pub mod image_command;
+pub mod import_command;
 pub mod load_command;
 pub mod log_command;
+pub mod performance_command;
 pub mod permission_command;
+pub mod report_command;
 pub mod row_command;
 pub mod save_command;
+pub mod search_command;
 pub mod sheet_order_command;
 pub mod sort_command;
 pub mod statistics_command;
 pub mod style_command;
+pub mod undo_command;
 pub mod validation_command;
 pub mod view_state_command;
 pub mod watch_command;
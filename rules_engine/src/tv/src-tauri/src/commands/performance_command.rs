@@ -0,0 +1,7 @@
+use crate::logging::command_timer::{self, PerformanceReport};
+
+/// Tauri command to summarize command latencies for the current session.
+#[tauri::command]
+pub fn get_performance_report() -> PerformanceReport {
+    command_timer::global_tracker().report()
+}
@@ -4,16 +4,19 @@ use crate::error::error_types::TvError;
 use crate::error::permission_recovery::{
     self, PermissionState, PermissionStateChangedPayload,
 };
+use crate::logging::command_timer;
 
 /// Tauri command to get the current permission state for a file.
 #[tauri::command]
 pub fn get_permission_state(app_handle: AppHandle, file_path: String) -> PermissionState {
+    let _timer = command_timer::start("get_permission_state");
     permission_recovery::get_permission_state(&app_handle, &file_path)
 }
 
 /// Tauri command to get the number of pending updates for a file.
 #[tauri::command]
 pub fn get_pending_update_count(app_handle: AppHandle, file_path: String) -> usize {
+    let _timer = command_timer::start("get_pending_update_count");
     permission_recovery::get_pending_update_count(&app_handle, &file_path)
 }
 
@@ -23,6 +26,7 @@ pub fn retry_pending_updates(
     app_handle: AppHandle,
     file_path: String,
 ) -> Result<usize, TvError> {
+    let _timer = command_timer::start("retry_pending_updates");
     // First, check if permissions have been restored
     let current_state = permission_recovery::detect_permission_state(std::path::Path::new(&file_path));
 
@@ -62,6 +66,7 @@ pub fn check_permission_state(
     app_handle: AppHandle,
     file_path: String,
 ) -> PermissionStateChangedPayload {
+    let _timer = command_timer::start("check_permission_state");
     let current_state = permission_recovery::detect_permission_state(std::path::Path::new(&file_path));
     let message = permission_recovery::get_permission_error_message(current_state, &file_path);
     permission_recovery::set_permission_state(&app_handle, &file_path, current_state, &message);
@@ -79,5 +84,6 @@ pub fn check_permission_state(
 /// Tauri command to clear permission state when a file is no longer being watched.
 #[tauri::command]
 pub fn clear_permission_state(app_handle: AppHandle, file_path: String) {
+    let _timer = command_timer::start("clear_permission_state");
     permission_recovery::clear_permission_state(&app_handle, &file_path);
 }
@@ -0,0 +1,57 @@
+use tauri::State;
+
+use crate::error::error_types::TvError;
+use crate::filter::filter_state::FilterStateManager;
+use crate::logging::command_timer;
+use crate::report::html_report;
+use crate::sort::sort_state::SortStateManager;
+use crate::toml::document_loader::TomlTableData;
+use crate::toml::{conditional_formatting, metadata};
+use crate::traits::TvConfig;
+
+/// Tauri command to export the current view of a table as a standalone HTML
+/// report.
+///
+/// Rows are expected in TOML order with derived column values already
+/// included, the active sort and filter are applied here. Returns the path
+/// of the written report.
+#[tauri::command]
+pub fn export_report(
+    sort_state: State<SortStateManager>,
+    filter_state: State<FilterStateManager>,
+    file_path: String,
+    table_name: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<serde_json::Value>>,
+) -> Result<String, TvError> {
+    let _timer = command_timer::start("export_report");
+    tracing::debug!(
+        component = "tv.commands.report",
+        file_path = %file_path,
+        table_name = %table_name,
+        row_count = rows.len(),
+        "Exporting report"
+    );
+
+    let sort = sort_state.get_sort_state(&file_path, &table_name);
+    let filter = filter_state.get_filter_state(&file_path, &table_name);
+    let data =
+        html_report::view_data(TomlTableData { headers, rows }, sort.as_ref(), filter.as_ref());
+
+    let style = metadata::parse_table_style_from_file(&file_path)?;
+    let rules = metadata::parse_conditional_formatting_from_file(&file_path)?;
+    let formats = conditional_formatting::evaluate_rules(&rules, &data.headers, &data.rows);
+
+    let html = html_report::render_html(&table_name, &data, style.as_ref(), &formats);
+    let output_path = html_report::default_report_path(&file_path).to_string_lossy().into_owned();
+    html_report::write_report(&TvConfig::default(), &output_path, &html)?;
+
+    tracing::info!(
+        component = "tv.commands.report",
+        file_path = %file_path,
+        output_path = %output_path,
+        row_count = data.rows.len(),
+        "Report exported"
+    );
+    Ok(output_path)
+}
@@ -1,10 +1,20 @@
+use tauri::{AppHandle, State};
+
 use crate::error::error_types::TvError;
+use crate::filter::filter_state::FilterStateManager;
+use crate::logging::command_timer;
+use crate::sort::sort_state::SortStateManager;
+use crate::sync::state_machine;
+use crate::toml::document_writer::{self, DuplicateRowResult, JournalOperation, MoveRowResult};
 use crate::toml::metadata;
 use crate::toml::metadata_types::RowConfig;
+use crate::traits::TvConfig;
+use crate::validation::card_editor_state;
 
 /// Tauri command to get the row configuration for a TOML file.
 #[tauri::command]
 pub fn get_row_config(file_path: String) -> Result<Option<RowConfig>, TvError> {
+    let _timer = command_timer::start("get_row_config");
     tracing::debug!(
         component = "tv.commands.row",
         file_path = %file_path,
@@ -22,3 +32,76 @@ pub fn get_row_config(file_path: String) -> Result<Option<RowConfig>, TvError> {
 
     Ok(row_config)
 }
+
+/// Tauri command to insert a copy of a row directly after it.
+#[tauri::command]
+pub fn duplicate_row(
+    app_handle: AppHandle,
+    sort_state_manager: State<SortStateManager>,
+    filter_state_manager: State<FilterStateManager>,
+    file_path: String,
+    table_name: String,
+    row_index: usize,
+) -> Result<DuplicateRowResult, TvError> {
+    let _timer = command_timer::start("duplicate_row");
+    state_machine::begin_save(&app_handle, &file_path)?;
+
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::DuplicateRow,
+        || document_writer::duplicate_row(&config, &file_path, &table_name, row_index),
+    );
+    let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
+
+    if let Ok(duplicated) = &result {
+        sort_state_manager.on_row_duplicated(
+            &file_path,
+            &table_name,
+            duplicated.source_index,
+            duplicated.row_index,
+        );
+        filter_state_manager.on_row_duplicated(
+            &file_path,
+            &table_name,
+            duplicated.source_index,
+            duplicated.row_index,
+        );
+    }
+    result
+}
+
+/// Tauri command to move a row to a new position in the TOML array-of-tables.
+#[tauri::command]
+pub fn move_row(
+    app_handle: AppHandle,
+    sort_state_manager: State<SortStateManager>,
+    filter_state_manager: State<FilterStateManager>,
+    file_path: String,
+    table_name: String,
+    from_index: usize,
+    to_index: usize,
+) -> Result<MoveRowResult, TvError> {
+    let _timer = command_timer::start("move_row");
+    state_machine::begin_save(&app_handle, &file_path)?;
+
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::MoveRow,
+        || document_writer::move_row(&config, &file_path, &table_name, from_index, to_index),
+    );
+    let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
+
+    if result.is_ok() {
+        sort_state_manager.on_row_moved(&file_path, &table_name, from_index, to_index);
+        filter_state_manager.on_row_moved(&file_path, &table_name, from_index, to_index);
+    }
+    result
+}
@@ -4,13 +4,15 @@ use tauri::AppHandle;
 
 use crate::error::error_types::TvError;
 use crate::error::permission_recovery::{self, PermissionState};
+use crate::logging::command_timer;
 use crate::sync::state_machine;
 use crate::toml::document_loader::TomlTableData;
 use crate::toml::document_writer::{
-    self, AddRowResult, CellUpdate, DeleteRowResult, SaveBatchResult, SaveCellResult,
-    SaveTableResult,
+    self, AddRowResult, CellUpdate, DeleteRowResult, JournalOperation, SaveBatchResult,
+    SaveCellResult, SaveTableResult,
 };
 use crate::traits::TvConfig;
+use crate::validation::card_editor_state;
 
 /// Tauri command to save spreadsheet data back to a TOML file.
 #[tauri::command]
@@ -20,12 +22,26 @@ pub fn save_toml_table(
     table_name: String,
     data: TomlTableData,
 ) -> Result<SaveTableResult, TvError> {
+    let _timer = command_timer::start("save_toml_table");
     state_machine::begin_save(&app_handle, &file_path)?;
 
-    let result = document_writer::save_toml_document(&TvConfig::default(), &file_path, &table_name, &data);
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::SaveTable,
+        || document_writer::save_toml_document(&config, &file_path, &table_name, &data),
+    );
     let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
 
-    result
+    match result {
+        Err(TvError::CardValidationFailed { errors, .. }) => {
+            Ok(SaveTableResult { uuids_generated: false, validation_errors: errors })
+        }
+        result => result,
+    }
 }
 
 /// Tauri command to save a single cell update to a TOML file.
@@ -38,6 +54,7 @@ pub fn save_cell(
     column_key: String,
     value: serde_json::Value,
 ) -> Result<SaveCellResult, TvError> {
+    let _timer = command_timer::start("save_cell");
     // Check if file is in read-only mode due to permission issues
     let permission_state = permission_recovery::get_permission_state(&app_handle, &file_path);
     if permission_state == PermissionState::ReadOnly {
@@ -62,7 +79,15 @@ pub fn save_cell(
     state_machine::begin_save(&app_handle, &file_path)?;
 
     let update = CellUpdate { row_index, column_key: column_key.clone(), value: value.clone() };
-    let result = document_writer::save_cell(&TvConfig::default(), &file_path, &table_name, &update);
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::SaveCell,
+        || document_writer::save_cell(&config, &file_path, &table_name, &update),
+    );
 
     // Handle permission errors by updating state and queueing the update
     if let Err(ref e) = result {
@@ -94,6 +119,7 @@ pub fn save_batch(
     table_name: String,
     updates: Vec<CellUpdate>,
 ) -> Result<SaveBatchResult, TvError> {
+    let _timer = command_timer::start("save_batch");
     // Check if file is in read-only mode due to permission issues
     let permission_state = permission_recovery::get_permission_state(&app_handle, &file_path);
     if permission_state == PermissionState::ReadOnly {
@@ -122,7 +148,15 @@ pub fn save_batch(
 
     state_machine::begin_save(&app_handle, &file_path)?;
 
-    let result = document_writer::save_batch(&TvConfig::default(), &file_path, &table_name, &updates);
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::SaveBatch,
+        || document_writer::save_batch(&config, &file_path, &table_name, &updates),
+    );
 
     // Handle permission errors by updating state and queueing updates
     if let Err(ref e) = result {
@@ -155,9 +189,18 @@ pub fn add_row(
     position: Option<usize>,
     initial_values: Option<HashMap<String, serde_json::Value>>,
 ) -> Result<AddRowResult, TvError> {
+    let _timer = command_timer::start("add_row");
     state_machine::begin_save(&app_handle, &file_path)?;
 
-    let result = document_writer::add_row(&TvConfig::default(), &file_path, &table_name, position, initial_values);
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::AddRow,
+        || document_writer::add_row(&config, &file_path, &table_name, position, initial_values),
+    );
     let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
 
     result
@@ -171,9 +214,18 @@ pub fn delete_row(
     table_name: String,
     row_index: usize,
 ) -> Result<DeleteRowResult, TvError> {
+    let _timer = command_timer::start("delete_row");
     state_machine::begin_save(&app_handle, &file_path)?;
 
-    let result = document_writer::delete_row(&TvConfig::default(), &file_path, &table_name, row_index);
+    let config = TvConfig::default();
+    let result = card_editor_state::record_write(
+        &app_handle,
+        &config,
+        &file_path,
+        &table_name,
+        JournalOperation::DeleteRow,
+        || document_writer::delete_row(&config, &file_path, &table_name, row_index),
+    );
     let _ = state_machine::end_save(&app_handle, &file_path, result.is_ok());
 
     result
@@ -0,0 +1,66 @@
+use tauri::AppHandle;
+
+use crate::error::error_types::TvError;
+use crate::logging::command_timer;
+use crate::search::find_replace::{self, FindOptions, FindResult, ReplaceResult, TableRef};
+use crate::sync::state_machine;
+use crate::toml::document_writer::JournalOperation;
+use crate::traits::TvConfig;
+use crate::validation::card_editor_state;
+
+/// Tauri command to find matching cells across the given tables.
+#[tauri::command]
+pub fn find_in_tables(tables: Vec<TableRef>, options: FindOptions) -> Result<FindResult, TvError> {
+    let _timer = command_timer::start("find_in_tables");
+    tracing::debug!(
+        component = "tv.commands.search",
+        table_count = tables.len(),
+        regex = options.regex,
+        "Find requested"
+    );
+    find_replace::find(&TvConfig::default(), &tables, &options)
+}
+
+/// Tauri command to replace matches across the given tables.
+///
+/// Every table is validated before any is written, so the replace changes
+/// either all of the tables or none of them. It is recorded as one operation,
+/// which is undone in every changed file at once.
+#[tauri::command]
+pub fn replace_in_tables(
+    app_handle: AppHandle,
+    tables: Vec<TableRef>,
+    options: FindOptions,
+    replacement: String,
+) -> Result<Vec<ReplaceResult>, TvError> {
+    let _timer = command_timer::start("replace_in_tables");
+    tracing::debug!(
+        component = "tv.commands.search",
+        table_count = tables.len(),
+        regex = options.regex,
+        "Replace requested"
+    );
+
+    let config = TvConfig::default();
+    let plan = find_replace::plan_replace(&config, &tables, &options, &replacement)?;
+    let changed_tables = plan.changed_tables();
+    if plan.has_failures() || changed_tables.is_empty() {
+        return plan.apply(&config);
+    }
+
+    let mut file_paths = Vec::new();
+    for (file_path, _) in &changed_tables {
+        if !file_paths.iter().any(|path| path == file_path) {
+            file_paths.push(file_path.to_string());
+        }
+    }
+    state_machine::save_files(&app_handle, &file_paths, || {
+        card_editor_state::record_tables_write(
+            &app_handle,
+            &config,
+            &changed_tables,
+            JournalOperation::Replace,
+            || plan.apply(&config),
+        )
+    })
+}
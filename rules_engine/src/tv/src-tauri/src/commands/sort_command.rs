@@ -1,5 +1,6 @@
 use tauri::State;
 
+use crate::logging::command_timer;
 use crate::sort::sort_state::SortStateManager;
 use crate::sort::sort_types::{SortDirection, SortState};
 
@@ -30,6 +31,7 @@ pub fn get_sort_state(
     file_path: String,
     table_name: String,
 ) -> SortStateResponse {
+    let _timer = command_timer::start("get_sort_state");
     let sort_state = state.get_sort_state(&file_path, &table_name);
     tracing::debug!(
         component = "tv.commands.sort",
@@ -48,6 +50,7 @@ pub fn set_sort_state(
     table_name: String,
     sort: Option<SetSortRequest>,
 ) -> SortStateResponse {
+    let _timer = command_timer::start("set_sort_state");
     let new_state = sort.map(|s| SortState::new(s.column, s.direction));
 
     tracing::info!(
@@ -69,6 +72,7 @@ pub fn clear_sort_state(
     file_path: String,
     table_name: String,
 ) -> SortStateResponse {
+    let _timer = command_timer::start("clear_sort_state");
     tracing::info!(
         component = "tv.commands.sort",
         file_path = %file_path,
@@ -85,6 +89,7 @@ pub fn get_sort_row_mapping(
     file_path: String,
     table_name: String,
 ) -> Vec<usize> {
+    let _timer = command_timer::start("get_sort_row_mapping");
     let mapping = state.get_row_mapping(&file_path, &table_name);
     tracing::debug!(
         component = "tv.commands.sort",
@@ -103,6 +108,7 @@ pub fn translate_row_index(
     table_name: String,
     display_index: usize,
 ) -> usize {
+    let _timer = command_timer::start("translate_row_index");
     state.display_to_original(&file_path, &table_name, display_index)
 }
 
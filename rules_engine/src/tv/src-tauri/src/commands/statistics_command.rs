@@ -1,9 +1,11 @@
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::toml::metadata;
 use crate::toml::metadata_types::StatisticConfig;
 
 #[tauri::command]
 pub fn get_statistics_config(file_path: String) -> Result<Vec<StatisticConfig>, TvError> {
+    let _timer = command_timer::start("get_statistics_config");
     tracing::debug!(
         component = "tv.commands.statistics",
         file_path = %file_path,
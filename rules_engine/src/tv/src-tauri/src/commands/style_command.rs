@@ -1,4 +1,5 @@
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::toml::color_schemes::{self, ColorPalette};
 use crate::toml::conditional_formatting::{self, CellFormatResult};
 use crate::toml::metadata;
@@ -17,6 +18,7 @@ pub struct ResolvedTableStyle {
 /// Tauri command to get the resolved table style for a TOML file.
 #[tauri::command]
 pub fn get_table_style(file_path: String) -> Result<Option<ResolvedTableStyle>, TvError> {
+    let _timer = command_timer::start("get_table_style");
     tracing::debug!(
         component = "tv.commands.style",
         file_path = %file_path,
@@ -56,6 +58,7 @@ pub fn get_table_style(file_path: String) -> Result<Option<ResolvedTableStyle>,
 /// Tauri command to get available color scheme names.
 #[tauri::command]
 pub fn get_available_color_schemes() -> Vec<String> {
+    let _timer = command_timer::start("get_available_color_schemes");
     color_schemes::available_schemes().into_iter().map(String::from).collect()
 }
 
@@ -66,6 +69,7 @@ pub fn get_conditional_formatting(
     headers: Vec<String>,
     rows: Vec<Vec<serde_json::Value>>,
 ) -> Result<Vec<CellFormatResult>, TvError> {
+    let _timer = command_timer::start("get_conditional_formatting");
     tracing::debug!(
         component = "tv.commands.style",
         file_path = %file_path,
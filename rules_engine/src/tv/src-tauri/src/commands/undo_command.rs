@@ -0,0 +1,35 @@
+use tauri::{AppHandle, Manager};
+
+use crate::error::error_types::TvError;
+use crate::logging::command_timer;
+use crate::sync::state_machine;
+use crate::toml::document_writer::{OperationJournal, UndoRedoResult};
+use crate::traits::TvConfig;
+
+/// Tauri command to undo the most recent write to a TOML file.
+///
+/// An operation which also changed other files is undone in all of them.
+#[tauri::command]
+pub fn undo(app_handle: AppHandle, file_path: String) -> Result<UndoRedoResult, TvError> {
+    let _timer = command_timer::start("undo");
+    tracing::debug!(component = "tv.commands.undo", file_path = %file_path, "Undo requested");
+    let journal = app_handle.state::<OperationJournal>();
+    let file_paths = journal.undo_files(&file_path);
+    state_machine::save_files(&app_handle, &file_paths, || {
+        journal.undo(&TvConfig::default(), &file_path)
+    })
+}
+
+/// Tauri command to reapply the most recently undone write to a TOML file.
+///
+/// An operation which also changed other files is redone in all of them.
+#[tauri::command]
+pub fn redo(app_handle: AppHandle, file_path: String) -> Result<UndoRedoResult, TvError> {
+    let _timer = command_timer::start("redo");
+    tracing::debug!(component = "tv.commands.undo", file_path = %file_path, "Redo requested");
+    let journal = app_handle.state::<OperationJournal>();
+    let file_paths = journal.redo_files(&file_path);
+    state_machine::save_files(&app_handle, &file_paths, || {
+        journal.redo(&TvConfig::default(), &file_path)
+    })
+}
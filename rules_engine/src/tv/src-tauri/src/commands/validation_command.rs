@@ -1,9 +1,11 @@
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::toml::metadata;
 use crate::validation::validation_rules::ValidationRule;
 
 #[tauri::command]
 pub fn get_validation_rules(file_path: String) -> Result<Vec<ValidationRule>, TvError> {
+    let _timer = command_timer::start("get_validation_rules");
     tracing::debug!(
         component = "tv.commands.validation",
         file_path = %file_path,
@@ -24,6 +26,7 @@ pub fn get_validation_rules(file_path: String) -> Result<Vec<ValidationRule>, Tv
 
 #[tauri::command]
 pub fn get_enum_validation_rules(file_path: String) -> Result<Vec<EnumValidationInfo>, TvError> {
+    let _timer = command_timer::start("get_enum_validation_rules");
     tracing::debug!(
         component = "tv.commands.validation",
         file_path = %file_path,
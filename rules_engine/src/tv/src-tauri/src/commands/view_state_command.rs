@@ -1,11 +1,13 @@
 use tauri::State;
 
 use crate::cli::AppPaths;
+use crate::logging::command_timer;
 use crate::view_state::view_state_types::{self, ViewState};
 
 /// Loads the persisted view state from disk.
 #[tauri::command]
 pub fn load_view_state(state: State<AppPaths>) -> ViewState {
+    let _timer = command_timer::start("load_view_state");
     view_state_types::load_view_state(&state)
 }
 
@@ -16,6 +18,7 @@ pub fn save_view_state(
     active_sheet_path: Option<String>,
     statistics_visible: Option<bool>,
 ) {
+    let _timer = command_timer::start("save_view_state");
     view_state_types::save_view_state(
         &state,
         &ViewState {
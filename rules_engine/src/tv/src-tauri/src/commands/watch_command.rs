@@ -1,16 +1,19 @@
 use tauri::AppHandle;
 
 use crate::error::error_types::TvError;
+use crate::logging::command_timer;
 use crate::sync::file_watcher;
 
 /// Tauri command to start a file watcher for the given file path.
 #[tauri::command]
 pub fn start_file_watcher(app_handle: AppHandle, file_path: String) -> Result<(), TvError> {
+    let _timer = command_timer::start("start_file_watcher");
     file_watcher::start_watcher(app_handle, file_path)
 }
 
 /// Tauri command to stop a file watcher for the given file path.
 #[tauri::command]
 pub fn stop_file_watcher(app_handle: AppHandle, file_path: String) -> Result<(), TvError> {
+    let _timer = command_timer::start("stop_file_watcher");
     file_watcher::stop_watcher(app_handle, file_path)
 }
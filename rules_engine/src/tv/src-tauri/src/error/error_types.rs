@@ -2,6 +2,8 @@ use std::io::ErrorKind;
 
 use serde::Serialize;
 
+use crate::validation::tabula_validation::CardValidationError;
+
 /// Comprehensive error type for TV application covering all failure modes.
 #[derive(Debug, thiserror::Error)]
 pub enum TvError {
@@ -85,6 +87,27 @@ pub enum TvError {
 
     #[error("Failed to emit event: {message}")]
     EventEmitFailed { message: String },
+
+    #[error("Cannot undo or redo changes to {path}: the table was modified outside TV")]
+    JournalConflict { path: String },
+
+    #[error("CSV parse error in {path} at line {line:?}: {message}")]
+    CsvParseError { path: String, line: Option<usize>, message: String },
+
+    #[error("Invalid search pattern '{pattern}': {message}")]
+    InvalidSearchPattern { pattern: String, message: String },
+
+    #[error("Cannot comment on row {row_index} of table '{table_name}': {message}")]
+    InvalidCommentTarget { table_name: String, row_index: usize, message: String },
+
+    #[error("Comment thread not found: {thread_id}")]
+    CommentThreadNotFound { thread_id: String },
+
+    #[error(
+        "Card validation failed in table '{table_name}' of {path}: {}",
+        describe_card_errors(.errors)
+    )]
+    CardValidationFailed { path: String, table_name: String, errors: Vec<CardValidationError> },
 }
 
 impl TvError {
@@ -128,6 +151,12 @@ impl TvError {
             TvError::WatcherCreationFailed { .. } => "WatcherCreationFailed",
             TvError::WatchPathFailed { .. } => "WatchPathFailed",
             TvError::EventEmitFailed { .. } => "EventEmitFailed",
+            TvError::JournalConflict { .. } => "JournalConflict",
+            TvError::CsvParseError { .. } => "CsvParseError",
+            TvError::InvalidSearchPattern { .. } => "InvalidSearchPattern",
+            TvError::InvalidCommentTarget { .. } => "InvalidCommentTarget",
+            TvError::CommentThreadNotFound { .. } => "CommentThreadNotFound",
+            TvError::CardValidationFailed { .. } => "CardValidationFailed",
         }
     }
 
@@ -145,7 +174,10 @@ impl TvError {
             | TvError::AtomicWriteFailed { path, .. }
             | TvError::FileTooLarge { path, .. }
             | TvError::WatcherError { path, .. }
-            | TvError::WatchPathFailed { path, .. } => Some(path),
+            | TvError::WatchPathFailed { path, .. }
+            | TvError::JournalConflict { path }
+            | TvError::CsvParseError { path, .. }
+            | TvError::CardValidationFailed { path, .. } => Some(path),
             TvError::InvalidStateTransition { file_path, .. } => Some(file_path),
             TvError::AtomicRenameFailed { target_path, .. } => Some(target_path),
             _ => None,
@@ -165,6 +197,12 @@ impl TvError {
                 | TvError::TableNotFound { .. }
                 | TvError::NotAnArrayOfTables { .. }
                 | TvError::RowNotFound { .. }
+                | TvError::JournalConflict { .. }
+                | TvError::CsvParseError { .. }
+                | TvError::InvalidSearchPattern { .. }
+                | TvError::InvalidCommentTarget { .. }
+                | TvError::CommentThreadNotFound { .. }
+                | TvError::CardValidationFailed { .. }
         )
     }
 }
@@ -253,3 +291,14 @@ impl Serialize for TvError {
         serializer.serialize_str(&self.to_string())
     }
 }
+
+fn describe_card_errors(errors: &[CardValidationError]) -> String {
+    errors
+        .iter()
+        .map(|error| match &error.column {
+            Some(column) => format!("row {} column '{column}': {}", error.row, error.message),
+            None => format!("row {}: {}", error.row, error.message),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -6,8 +6,9 @@ use serde::Serialize;
 use tauri::{AppHandle, Emitter, Manager};
 
 use crate::error::error_types::TvError;
-use crate::toml::document_writer::CellUpdate;
+use crate::toml::document_writer::{self, CellUpdate, JournalOperation};
 use crate::traits::TvConfig;
+use crate::validation::card_editor_state;
 
 /// Tracks the permission state for a file (readable and writable).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -206,12 +207,22 @@ pub fn retry_pending_updates(
     let mut success_count = 0;
     let mut failed_updates = Vec::new();
 
+    let config = TvConfig::default();
     for pending_update in pending {
-        let result = crate::toml::document_writer::save_cell(
-            &TvConfig::default(),
+        let result = card_editor_state::record_write(
+            app_handle,
+            &config,
             file_path,
             &pending_update.table_name,
-            &pending_update.update,
+            JournalOperation::SaveCell,
+            || {
+                document_writer::save_cell(
+                    &config,
+                    file_path,
+                    &pending_update.table_name,
+                    &pending_update.update,
+                )
+            },
         );
 
         match result {
@@ -234,7 +245,10 @@ pub fn retry_pending_updates(
                     error = %e,
                     "Failed to apply pending update"
                 );
-                failed_updates.push(pending_update);
+                // Retrying an update which failed validation cannot succeed
+                if !matches!(e, TvError::CardValidationFailed { .. }) {
+                    failed_updates.push(pending_update);
+                }
             }
         }
     }
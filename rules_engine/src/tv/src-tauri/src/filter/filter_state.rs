@@ -5,6 +5,7 @@ use crate::filter::filter_types::{matches_condition, ColumnFilterState, FilterCo
 use crate::sort::sort_types::CellValue;
 use crate::toml::document_loader::TomlTableData;
 use crate::toml::metadata_types::ColumnFilter;
+use crate::toml::row_operations;
 
 pub struct FilterStateManager {
     states: RwLock<HashMap<String, FilterState>>,
@@ -129,6 +130,61 @@ impl FilterStateManager {
         let key = format!("{file_path}::{table_name}");
         self.hidden_rows.read().ok().and_then(|h| h.get(&key).cloned()).unwrap_or_default()
     }
+
+    /// Updates row visibility after a copy of `source_index` is inserted at
+    /// `row_index`. The copy has the same values, so it is hidden exactly when
+    /// its source is.
+    pub fn on_row_duplicated(
+        &self,
+        file_path: &str,
+        table_name: &str,
+        source_index: usize,
+        row_index: usize,
+    ) {
+        let key = format!("{file_path}::{table_name}");
+        if let Ok(mut vis) = self.visibility.write() {
+            if let Some(visible) = vis.get_mut(&key) {
+                if source_index < visible.len() && row_index <= visible.len() {
+                    visible.insert(row_index, visible[source_index]);
+                }
+            }
+        }
+        if let Ok(mut hidden) = self.hidden_rows.write() {
+            if let Some(rows) = hidden.get_mut(&key) {
+                let source_hidden = rows.contains(&source_index);
+                for row in rows.iter_mut() {
+                    if *row >= row_index {
+                        *row += 1;
+                    }
+                }
+                if source_hidden {
+                    rows.push(row_index);
+                    rows.sort_unstable();
+                }
+            }
+        }
+    }
+
+    /// Updates row visibility after a row is moved.
+    pub fn on_row_moved(&self, file_path: &str, table_name: &str, from_index: usize, to_index: usize) {
+        let key = format!("{file_path}::{table_name}");
+        if let Ok(mut vis) = self.visibility.write() {
+            if let Some(visible) = vis.get_mut(&key) {
+                if from_index < visible.len() && to_index < visible.len() {
+                    let moved = visible.remove(from_index);
+                    visible.insert(to_index, moved);
+                }
+            }
+        }
+        if let Ok(mut hidden) = self.hidden_rows.write() {
+            if let Some(rows) = hidden.get_mut(&key) {
+                for row in rows.iter_mut() {
+                    *row = row_operations::index_after_move(*row, from_index, to_index);
+                }
+                rows.sort_unstable();
+            }
+        }
+    }
 }
 
 impl Default for FilterStateManager {
@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::error_types::{map_io_error_for_read, TvError};
+use crate::toml::array_columns;
+use crate::toml::document_loader::{self, TomlTableData};
+use crate::toml::document_writer::{self, CellUpdate};
+use crate::toml::metadata;
+use crate::traits::TvConfig;
+use crate::validation::validation_rules::{ValidationRule, ValueType};
+use crate::validation::validators;
+
+/// Options controlling how a CSV file is imported into a table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvImportOptions {
+    /// Maps CSV header names to table column names. CSV columns which are not
+    /// listed are matched to the table column with the same name, ignoring
+    /// case and treating spaces, hyphens and underscores as equivalent.
+    #[serde(default)]
+    pub column_mapping: HashMap<String, String>,
+
+    /// When true, the import is only previewed and the file is not modified.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Table column chosen for one CSV column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvColumnMapping {
+    pub csv_column: String,
+    /// None if the CSV column does not match any table column and is ignored.
+    pub table_column: Option<String>,
+}
+
+/// A CSV value which was converted to the type of its table column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeCoercion {
+    /// Zero-based index of the data row in the CSV file, excluding the header.
+    pub row: usize,
+    pub column: String,
+    pub original: String,
+    pub value: Value,
+}
+
+/// A CSV value which could not be imported. Rows with failures are skipped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFailure {
+    /// Zero-based index of the data row in the CSV file, excluding the header.
+    pub row: usize,
+    pub column: String,
+    pub value: String,
+    pub reason: String,
+}
+
+/// Result of previewing or applying a CSV import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvImportResult {
+    pub dry_run: bool,
+    pub column_mapping: Vec<CsvColumnMapping>,
+    /// Number of data rows in the CSV file.
+    pub row_count: usize,
+    /// Number of rows without failures, which are added to the table.
+    pub importable_count: usize,
+    /// Number of rows actually added. Always zero for a dry run.
+    pub imported_count: usize,
+    pub coercions: Vec<TypeCoercion>,
+    pub failures: Vec<ImportFailure>,
+}
+
+/// Reads a CSV file and imports its rows into a table, see [import_csv].
+pub fn import_csv_file(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    csv_path: &str,
+    options: &CsvImportOptions,
+) -> Result<CsvImportResult, TvError> {
+    let csv_content = config
+        .fs()
+        .read_to_string(Path::new(csv_path))
+        .map_err(|e| map_io_error_for_read(&e, csv_path))?;
+    import_csv(config, file_path, table_name, csv_path, &csv_content, options)
+}
+
+/// Imports CSV rows into an existing table, appending them after the existing
+/// rows.
+///
+/// Each value is converted to the type of its column, taken from a `type`
+/// validation rule or otherwise from the existing values in the column, and
+/// then checked against the table's validation rules. Rows with any failure
+/// are skipped. Unless `options.dry_run` is set, the remaining rows are added
+/// with [document_writer::add_row] and filled in with a single
+/// [document_writer::save_batch].
+pub fn import_csv(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    csv_path: &str,
+    csv_content: &str,
+    options: &CsvImportOptions,
+) -> Result<CsvImportResult, TvError> {
+    let table = document_loader::load_toml_document(config, file_path, table_name)?;
+    let content = config
+        .fs()
+        .read_to_string(Path::new(file_path))
+        .map_err(|e| map_io_error_for_read(&e, file_path))?;
+    let rules = metadata::parse_validation_rules_from_content(&content, file_path)?;
+
+    let (csv_headers, records) = parse_csv(csv_content, csv_path)?;
+    let column_mapping = map_columns(&csv_headers, &table.headers, &options.column_mapping);
+    let column_types = column_types(&table, &rules);
+
+    let mut coercions = Vec::new();
+    let mut failures = Vec::new();
+    let mut importable_rows = Vec::new();
+    for (row, record) in records.iter().enumerate() {
+        let failure_count = failures.len();
+        let mut values = Vec::new();
+        for (mapping, raw) in column_mapping.iter().zip(record) {
+            let Some(column) = &mapping.table_column else {
+                continue;
+            };
+            let raw = raw.trim();
+            if raw.is_empty() {
+                continue;
+            }
+            let value_type = column_types.get(column.as_str()).copied();
+            match coerce(raw, value_type) {
+                Ok(value) => {
+                    if !value.is_string() {
+                        coercions.push(TypeCoercion {
+                            row,
+                            column: column.clone(),
+                            original: raw.to_string(),
+                            value: value.clone(),
+                        });
+                    }
+                    values.push((column.clone(), raw, value));
+                }
+                Err(reason) => failures.push(ImportFailure {
+                    row,
+                    column: column.clone(),
+                    value: raw.to_string(),
+                    reason,
+                }),
+            }
+        }
+
+        for (column, raw, value) in &values {
+            let results = validators::validate_all(&rules, validation_column(column), value);
+            if let Some(error) = validators::first_error(&results) {
+                failures.push(ImportFailure {
+                    row,
+                    column: column.clone(),
+                    value: raw.to_string(),
+                    reason: error
+                        .error_message
+                        .clone()
+                        .unwrap_or_else(|| "Validation failed".to_string()),
+                });
+            }
+        }
+        for rule in rules.iter().filter(|rule| matches!(rule, ValidationRule::Required { .. })) {
+            let present =
+                values.iter().any(|(column, _, _)| validation_column(column) == rule.column());
+            if !present {
+                let result = validators::validate(rule, &Value::Null);
+                failures.push(ImportFailure {
+                    row,
+                    column: rule.column().to_string(),
+                    value: String::new(),
+                    reason: result.error_message.unwrap_or_else(|| rule.describe()),
+                });
+            }
+        }
+
+        if failures.len() == failure_count {
+            importable_rows.push(values);
+        }
+    }
+
+    let importable_count = importable_rows.len();
+    let imported_count = if options.dry_run || importable_rows.is_empty() {
+        0
+    } else {
+        let rows = importable_rows
+            .into_iter()
+            .map(|values| {
+                values.into_iter().map(|(column, _, value)| (column, value)).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        apply_rows(config, file_path, table_name, table.rows.len(), &rows)?
+    };
+
+    tracing::info!(
+        component = "tv.import.csv",
+        file_path = %file_path,
+        csv_path = %csv_path,
+        dry_run = options.dry_run,
+        row_count = records.len(),
+        importable_count = importable_count,
+        imported_count = imported_count,
+        failure_count = failures.len(),
+        "CSV import completed"
+    );
+
+    Ok(CsvImportResult {
+        dry_run: options.dry_run,
+        column_mapping,
+        row_count: records.len(),
+        importable_count,
+        imported_count,
+        coercions,
+        failures,
+    })
+}
+
+/// Parses CSV content into its header row and data rows.
+fn parse_csv(content: &str, csv_path: &str) -> Result<(Vec<String>, Vec<Vec<String>>), TvError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut reader =
+        csv::ReaderBuilder::new().trim(csv::Trim::Headers).from_reader(content.as_bytes());
+    let to_error = |e: csv::Error| TvError::CsvParseError {
+        path: csv_path.to_string(),
+        line: e.position().map(|position| position.line() as usize),
+        message: e.to_string(),
+    };
+
+    let headers = reader.headers().map_err(to_error)?.iter().map(str::to_string).collect();
+    let records = reader
+        .records()
+        .map(|record| record.map(|r| r.iter().map(str::to_string).collect()))
+        .collect::<Result<Vec<Vec<String>>, _>>()
+        .map_err(to_error)?;
+    Ok((headers, records))
+}
+
+/// Chooses the table column for each CSV column.
+fn map_columns(
+    csv_headers: &[String],
+    table_headers: &[String],
+    explicit: &HashMap<String, String>,
+) -> Vec<CsvColumnMapping> {
+    csv_headers
+        .iter()
+        .map(|csv_column| {
+            let table_column = match explicit.get(csv_column) {
+                Some(target) => table_headers.iter().find(|h| *h == target).cloned(),
+                None => table_headers
+                    .iter()
+                    .find(|h| normalize_column_name(h) == normalize_column_name(csv_column))
+                    .cloned(),
+            };
+            CsvColumnMapping { csv_column: csv_column.clone(), table_column }
+        })
+        .collect()
+}
+
+fn normalize_column_name(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// Determines the value type of each table column, preferring `type`
+/// validation rules over the types of existing values.
+fn column_types<'a>(
+    table: &'a TomlTableData,
+    rules: &[ValidationRule],
+) -> HashMap<&'a str, ValueType> {
+    let mut types = HashMap::new();
+    for (index, header) in table.headers.iter().enumerate() {
+        let from_rule = rules.iter().find_map(|rule| match rule {
+            ValidationRule::Type { column, value_type, .. }
+                if column == validation_column(header) =>
+            {
+                Some(*value_type)
+            }
+            _ => None,
+        });
+        let from_data = || {
+            table.rows.iter().find_map(|row| match row.get(index)? {
+                Value::Bool(_) => Some(ValueType::Boolean),
+                Value::Number(n) if n.is_i64() || n.is_u64() => Some(ValueType::Integer),
+                Value::Number(_) => Some(ValueType::Float),
+                Value::String(_) => Some(ValueType::String),
+                _ => None,
+            })
+        };
+        if let Some(value_type) = from_rule.or_else(from_data) {
+            types.insert(header.as_str(), value_type);
+        }
+    }
+    types
+}
+
+/// Converts a CSV value to a column's type. Values in columns of unknown type
+/// are kept as strings.
+fn coerce(raw: &str, value_type: Option<ValueType>) -> Result<Value, String> {
+    match value_type {
+        None | Some(ValueType::String) => Ok(Value::String(raw.to_string())),
+        Some(ValueType::Integer) => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("Expected an integer, got '{raw}'")),
+        Some(ValueType::Float) => raw
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| format!("Expected a number, got '{raw}'")),
+        Some(ValueType::Boolean) => match raw.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("Expected true or false, got '{raw}'")),
+        },
+    }
+}
+
+/// Returns the column name validation rules use for a table column, which is
+/// the base key for expanded array columns.
+fn validation_column(column: &str) -> &str {
+    array_columns::parse_array_column_key(column).map_or(column, |(base, _)| base)
+}
+
+/// Appends rows to the table, returning the number of rows added.
+///
+/// Added rows are removed again if their values cannot be saved.
+fn apply_rows(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    first_row: usize,
+    rows: &[Vec<(String, Value)>],
+) -> Result<usize, TvError> {
+    for _ in rows {
+        document_writer::add_row(config, file_path, table_name, None, None)?;
+    }
+
+    let updates = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(offset, values)| {
+            values.iter().map(move |(column, value)| CellUpdate {
+                row_index: first_row + offset,
+                column_key: column.clone(),
+                value: value.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let result = document_writer::save_batch(config, file_path, table_name, &updates)?;
+    if result.success {
+        return Ok(rows.len());
+    }
+
+    for row_index in (first_row..first_row + rows.len()).rev() {
+        document_writer::delete_row(config, file_path, table_name, row_index)?;
+    }
+    let failure = result.failed_updates.into_iter().next();
+    Err(TvError::ValidationFailed {
+        column: failure.as_ref().map(|f| f.column_key.clone()).unwrap_or_default(),
+        row: failure.as_ref().map_or(first_row, |f| f.row_index),
+        message: failure.map_or_else(|| "Batch save failed".to_string(), |f| f.reason),
+    })
+}
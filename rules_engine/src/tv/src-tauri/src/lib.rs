@@ -7,13 +7,19 @@ use crate::derived::compute_executor::ComputeExecutorState;
 use crate::error::permission_recovery::PermissionRecoveryState;
 use crate::filter::filter_state::FilterStateManager;
 use crate::images::image_fetcher::ImageFetcherState;
+use crate::logging::command_timer;
+use crate::toml::operation_journal::OperationJournal;
+use crate::validation::card_editor_state::{self, CardEditorState};
 pub mod cli;
 mod commands;
 pub mod derived;
 pub mod error;
 pub mod filter;
 pub mod images;
+pub mod import;
 pub mod logging;
+pub mod report;
+pub mod search;
 pub mod sort;
 mod sync;
 pub mod toml;
@@ -24,6 +30,7 @@ pub mod view_state;
 
 #[tauri::command]
 fn get_app_paths(state: tauri::State<cli::AppPaths>) -> Vec<String> {
+    let _timer = command_timer::start("get_app_paths");
     state.files.iter().map(|p| p.to_string_lossy().to_string()).collect()
 }
 
@@ -119,6 +126,21 @@ pub fn run(paths: cli::AppPaths) {
                 false,
                 None::<&str>,
             )?;
+            let export_report = MenuItem::with_id(
+                app_handle,
+                "export_report",
+                "Export Report",
+                true,
+                Some("CmdOrCtrl+Shift+E"),
+            )?;
+            let card_editor_mode = CheckMenuItem::with_id(
+                app_handle,
+                "card_editor_mode",
+                "Card Editor Mode",
+                true,
+                false,
+                None::<&str>,
+            )?;
             let show_statistics = CheckMenuItem::with_id(
                 app_handle,
                 "show_statistics",
@@ -130,8 +152,11 @@ pub fn run(paths: cli::AppPaths) {
             for item in menu.items()? {
                 if let MenuItemKind::Submenu(ref submenu) = item {
                     let text = submenu.text().unwrap_or_default();
-                    if text == "Edit" {
+                    if text == "File" {
+                        submenu.append(&export_report)?;
+                    } else if text == "Edit" {
                         submenu.append(&find_in_sheet)?;
+                        submenu.append(&card_editor_mode)?;
                     } else if text == "View" {
                         submenu.append(&dev_tools)?;
                         submenu.append(&disable_autosave)?;
@@ -150,6 +175,14 @@ pub fn run(paths: cli::AppPaths) {
                         "Failed to emit open-find-dialog event"
                     );
                 }
+            } else if event.id() == "export_report" {
+                if let Err(e) = app_handle.emit("export-report-requested", ()) {
+                    tracing::error!(
+                        component = "tv.menu",
+                        error = %e,
+                        "Failed to emit export-report-requested event"
+                    );
+                }
             } else if event.id() == "dev_tools" {
                 if let Some(window) = app_handle.get_webview_window("main") {
                     window.open_devtools();
@@ -166,6 +199,15 @@ pub fn run(paths: cli::AppPaths) {
                         "Failed to emit statistics-overlay-toggled event"
                     );
                 }
+            } else if event.id() == "card_editor_mode" {
+                if let Some(window) = app_handle.get_webview_window("main") {
+                    if let Some(menu) = window.menu() {
+                        if let Some(MenuItemKind::Check(check_item)) = menu.get("card_editor_mode") {
+                            let enabled = check_item.is_checked().unwrap_or(false);
+                            card_editor_state::set_enabled(app_handle, enabled);
+                        }
+                    }
+                }
             } else if event.id() == "disable_autosave" {
                 // CheckMenuItem automatically toggles its checked state on click.
                 // Read the current state and emit it to the frontend.
@@ -198,6 +240,8 @@ pub fn run(paths: cli::AppPaths) {
         .manage(executor_state)
         .manage(ImageFetcherState::new())
         .manage(PermissionRecoveryState::new())
+        .manage(CardEditorState::new())
+        .manage(OperationJournal::new())
         .invoke_handler(tauri::generate_handler![
             commands::load_command::load_toml_table,
             commands::save_command::save_toml_table,
@@ -205,6 +249,13 @@ pub fn run(paths: cli::AppPaths) {
             commands::save_command::save_batch,
             commands::save_command::add_row,
             commands::save_command::delete_row,
+            commands::row_command::duplicate_row,
+            commands::row_command::move_row,
+            commands::undo_command::undo,
+            commands::undo_command::redo,
+            commands::import_command::import_csv,
+            commands::search_command::find_in_tables,
+            commands::search_command::replace_in_tables,
             commands::sort_command::get_sort_state,
             commands::sort_command::set_sort_state,
             commands::sort_command::clear_sort_state,
@@ -233,6 +284,8 @@ pub fn run(paths: cli::AppPaths) {
             commands::style_command::get_conditional_formatting,
             commands::image_command::fetch_image,
             commands::row_command::get_row_config,
+            commands::comment_command::add_comment,
+            commands::comment_command::resolve_comment_thread,
             commands::column_command::get_column_configs,
             commands::column_command::set_column_width,
             commands::column_command::set_derived_column_width,
@@ -248,6 +301,8 @@ pub fn run(paths: cli::AppPaths) {
             commands::permission_command::check_permission_state,
             commands::permission_command::clear_permission_state,
             commands::statistics_command::get_statistics_config,
+            commands::performance_command::get_performance_report,
+            commands::report_command::export_report,
             get_app_paths,
         ])
         .setup(|app| {
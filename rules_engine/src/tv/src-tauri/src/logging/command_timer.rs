@@ -0,0 +1,183 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use chrono_tz::America::Los_Angeles;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// Maximum number of duration samples retained per command for percentile
+/// calculations.
+const MAX_SAMPLES_PER_COMMAND: usize = 500;
+
+/// Maximum number of recent command invocations retained across all commands.
+const MAX_RECENT_TIMINGS: usize = 200;
+
+/// Number of slowest recent invocations included in a performance report.
+const SLOWEST_RECENT_COUNT: usize = 10;
+
+/// Commands taking longer than this are logged as warnings.
+const SLOW_COMMAND_THRESHOLD: Duration = Duration::from_millis(250);
+
+static GLOBAL_TRACKER: OnceLock<CommandTimingTracker> = OnceLock::new();
+
+/// Records how long each Tauri command takes to execute for the current
+/// session.
+pub struct CommandTimingTracker {
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    samples: HashMap<&'static str, CommandSamples>,
+    recent: VecDeque<CommandTiming>,
+}
+
+#[derive(Default)]
+struct CommandSamples {
+    /// Total number of invocations, including those whose samples have been
+    /// evicted.
+    count: u64,
+    durations_ms: VecDeque<f64>,
+}
+
+/// A single timed command invocation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandTiming {
+    pub command: String,
+    /// Time the command finished, in ISO 8601 format in Pacific time.
+    pub ts: String,
+    pub duration_ms: f64,
+}
+
+/// Latency percentiles for a single command.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandLatencySummary {
+    pub command: String,
+    pub count: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Summary of command latencies for the current session.
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceReport {
+    /// Per-command latency summaries, slowest p95 first.
+    pub commands: Vec<CommandLatencySummary>,
+    /// Slowest recent command invocations, slowest first.
+    pub slowest_recent: Vec<CommandTiming>,
+}
+
+/// Guard which records the elapsed time of a command when dropped.
+#[must_use = "the command is timed until this guard is dropped"]
+pub struct CommandTimer {
+    command: &'static str,
+    started: Instant,
+}
+
+impl CommandTimingTracker {
+    pub fn new() -> Self {
+        Self { state: Mutex::new(TrackerState::default()) }
+    }
+
+    /// Records a completed invocation of a command.
+    pub fn record(&self, command: &'static str, duration: Duration) {
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        let mut state = self.state.lock();
+
+        let samples = state.samples.entry(command).or_default();
+        samples.count += 1;
+        if samples.durations_ms.len() == MAX_SAMPLES_PER_COMMAND {
+            samples.durations_ms.pop_front();
+        }
+        samples.durations_ms.push_back(duration_ms);
+
+        if state.recent.len() == MAX_RECENT_TIMINGS {
+            state.recent.pop_front();
+        }
+        let ts = Utc::now().with_timezone(&Los_Angeles).format("%Y-%m-%dT%H:%M:%S%.3f%:z");
+        state.recent.push_back(CommandTiming {
+            command: command.to_string(),
+            ts: ts.to_string(),
+            duration_ms,
+        });
+    }
+
+    /// Builds a report of latency percentiles and the slowest recent
+    /// invocations.
+    pub fn report(&self) -> PerformanceReport {
+        let state = self.state.lock();
+
+        let mut commands: Vec<CommandLatencySummary> = state
+            .samples
+            .iter()
+            .map(|(command, samples)| {
+                let mut sorted: Vec<f64> = samples.durations_ms.iter().copied().collect();
+                sorted.sort_by(f64::total_cmp);
+                CommandLatencySummary {
+                    command: command.to_string(),
+                    count: samples.count,
+                    p50_ms: percentile(&sorted, 50),
+                    p95_ms: percentile(&sorted, 95),
+                    max_ms: sorted.last().copied().unwrap_or_default(),
+                }
+            })
+            .collect();
+        commands
+            .sort_by(|a, b| b.p95_ms.total_cmp(&a.p95_ms).then_with(|| a.command.cmp(&b.command)));
+
+        let mut slowest_recent: Vec<CommandTiming> = state.recent.iter().cloned().collect();
+        slowest_recent.sort_by(|a, b| b.duration_ms.total_cmp(&a.duration_ms));
+        slowest_recent.truncate(SLOWEST_RECENT_COUNT);
+
+        PerformanceReport { commands, slowest_recent }
+    }
+
+    /// Discards all recorded timings.
+    pub fn clear(&self) {
+        *self.state.lock() = TrackerState::default();
+    }
+}
+
+impl Default for CommandTimingTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for CommandTimer {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        if elapsed >= SLOW_COMMAND_THRESHOLD {
+            tracing::warn!(
+                component = "tv.ipc.command",
+                command = self.command,
+                duration_ms = elapsed.as_millis() as u64,
+                "Slow command"
+            );
+        }
+        global_tracker().record(self.command, elapsed);
+    }
+}
+
+/// Returns the session-wide command timing tracker.
+pub fn global_tracker() -> &'static CommandTimingTracker {
+    GLOBAL_TRACKER.get_or_init(CommandTimingTracker::new)
+}
+
+/// Starts timing a command. The elapsed time is recorded in the global tracker
+/// when the returned guard is dropped.
+pub fn start(command: &'static str) -> CommandTimer {
+    CommandTimer { command, started: Instant::now() }
+}
+
+/// Returns the nearest-rank percentile of an ascending list of samples.
+fn percentile(sorted: &[f64], percentile: usize) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
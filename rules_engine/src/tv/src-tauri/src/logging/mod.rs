@@ -1,2 +1,3 @@
+pub mod command_timer;
 pub mod json_logger;
 pub mod log_aggregator;
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::error::error_types::TvError;
+use crate::filter::filter_state;
+use crate::filter::filter_types::FilterState;
+use crate::sort::sort_state;
+use crate::sort::sort_types::SortState;
+use crate::toml::cell_writer::map_atomic_write_error;
+use crate::toml::color_schemes::{self, ColorPalette};
+use crate::toml::conditional_formatting::CellFormatResult;
+use crate::toml::document_loader::TomlTableData;
+use crate::toml::metadata_types::{FormatStyle, TableStyle};
+use crate::traits::TvConfig;
+
+/// Page-level CSS embedded in every report so it renders without TV.
+const REPORT_STYLESHEET: &str = "<style>
+body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", sans-serif; margin: 24px; color: #1F2328; }
+h1 { font-size: 20px; margin-bottom: 4px; }
+p.summary { color: #656D76; margin-top: 0; }
+table { border-collapse: collapse; font-size: 13px; }
+th, td { border: 1px solid #D0D7DE; padding: 4px 8px; text-align: left; vertical-align: top; white-space: pre-wrap; }
+</style>
+";
+
+/// Applies the active sort and filter to `data`, returning the rows in the
+/// order they are displayed in the spreadsheet view.
+pub fn view_data(
+    data: TomlTableData,
+    sort: Option<&SortState>,
+    filter: Option<&FilterState>,
+) -> TomlTableData {
+    let sorted = sort_state::apply_sort_to_data(data, sort);
+    filter_state::apply_filter_to_data(sorted, filter)
+}
+
+/// Renders `data` as a self-contained HTML document.
+///
+/// Table style colors and conditional formatting are written as inline
+/// styles, with conditional formatting taking precedence as it does in the
+/// spreadsheet view. `formats` must be evaluated against the rows of `data`.
+pub fn render_html(
+    title: &str,
+    data: &TomlTableData,
+    style: Option<&TableStyle>,
+    formats: &[CellFormatResult],
+) -> String {
+    let default_style = TableStyle::default();
+    let style = style.unwrap_or(&default_style);
+    let palette = style.color_scheme.as_deref().and_then(color_schemes::resolve_color_scheme);
+
+    let mut cell_formats: HashMap<(usize, usize), Vec<&FormatStyle>> = HashMap::new();
+    for result in formats {
+        cell_formats.entry((result.row, result.col_index)).or_default().push(&result.style);
+    }
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape_html(title)));
+    html.push_str(REPORT_STYLESHEET);
+    html.push_str("</head>\n<body>\n");
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+    html.push_str(&format!("<p class=\"summary\">{} rows</p>\n", data.rows.len()));
+    html.push_str("<table>\n<thead>\n<tr>");
+
+    let header_css = header_declarations(style, palette.as_ref());
+    for header in &data.headers {
+        html.push_str(&format!("<th{}>{}</th>", style_attribute(&header_css), escape_html(header)));
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for (row_index, row) in data.rows.iter().enumerate() {
+        html.push_str("<tr>");
+        for col_index in 0..data.headers.len() {
+            let mut css = cell_declarations(style, palette.as_ref(), row_index, col_index);
+            for format in cell_formats.get(&(row_index, col_index)).into_iter().flatten() {
+                css.extend(format_declarations(format));
+            }
+            let text = row.get(col_index).map(display_value).unwrap_or_default();
+            html.push_str(&format!("<td{}>{}</td>", style_attribute(&css), escape_html(&text)));
+        }
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+    html
+}
+
+/// Returns the default report location for a TOML file: an HTML file next to
+/// it named after the file, e.g. `cards_report.html` for `cards.toml`.
+pub fn default_report_path(file_path: &str) -> PathBuf {
+    let path = Path::new(file_path);
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    path.with_file_name(format!("{stem}_report.html"))
+}
+
+/// Atomically writes a rendered report to `output_path`.
+pub fn write_report(config: &TvConfig, output_path: &str, html: &str) -> Result<(), TvError> {
+    config
+        .fs()
+        .write_atomic(Path::new(output_path), html)
+        .map_err(|e| map_atomic_write_error(e, output_path))?;
+
+    tracing::info!(
+        component = "tv.report",
+        output_path = %output_path,
+        bytes = html.len(),
+        "Report written"
+    );
+    Ok(())
+}
+
+/// Escapes text for inclusion in HTML element content or attribute values.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => {
+            items.iter().map(display_value).collect::<Vec<_>>().join(", ")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn header_declarations(style: &TableStyle, palette: Option<&ColorPalette>) -> Vec<String> {
+    let mut css =
+        vec![format!("font-weight: {}", if style.header_bold { "bold" } else { "normal" })];
+    if let Some(background) =
+        style.header_background.as_ref().or(palette.map(|p| &p.header_background))
+    {
+        css.push(format!("background-color: {background}"));
+    }
+    if let Some(palette) = palette {
+        css.push(format!("color: {}", palette.header_font_color));
+    }
+    css
+}
+
+fn cell_declarations(
+    style: &TableStyle,
+    palette: Option<&ColorPalette>,
+    row_index: usize,
+    col_index: usize,
+) -> Vec<String> {
+    let mut css = Vec::new();
+    let Some(palette) = palette else {
+        return css;
+    };
+    if style.show_row_stripes {
+        let background = if row_index % 2 == 0 {
+            &palette.row_even_background
+        } else {
+            &palette.row_odd_background
+        };
+        css.push(format!("background-color: {background}"));
+        css.push(format!("color: {}", palette.row_font_color));
+    }
+    if style.show_column_stripes && col_index % 2 == 1 {
+        css.push(format!("background-color: {}33", palette.accent_color));
+    }
+    css
+}
+
+fn format_declarations(format: &FormatStyle) -> Vec<String> {
+    let mut css = Vec::new();
+    if let Some(background) = &format.background_color {
+        css.push(format!("background-color: {background}"));
+    }
+    if let Some(font_color) = &format.font_color {
+        css.push(format!("color: {font_color}"));
+    }
+    if format.bold == Some(true) {
+        css.push("font-weight: bold".to_string());
+    }
+    if format.italic == Some(true) {
+        css.push("font-style: italic".to_string());
+    }
+    if format.underline == Some(true) {
+        css.push("text-decoration: underline".to_string());
+    }
+    css
+}
+
+fn style_attribute(declarations: &[String]) -> String {
+    if declarations.is_empty() {
+        String::new()
+    } else {
+        format!(" style=\"{}\"", escape_html(&declarations.join("; ")))
+    }
+}
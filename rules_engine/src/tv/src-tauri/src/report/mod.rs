@@ -0,0 +1 @@
+pub mod html_report;
@@ -0,0 +1,361 @@
+//! Find and replace across the cells of one or more tables.
+//!
+//! Cells are matched on their displayed text, so numbers and booleans can be
+//! found as well as strings. Only string cells are changed by a replace, which
+//! keeps the TOML value types of the other cells intact.
+//!
+//! A replace across several tables is computed and validated for every table
+//! before any file is written, so it either changes every table or none.
+
+use regex::{NoExpand, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::error_types::TvError;
+use crate::toml::array_columns;
+use crate::toml::document_loader;
+use crate::toml::document_writer::{self, CellUpdate, FailedUpdate};
+use crate::traits::TvConfig;
+
+/// Maximum number of hits returned by a single find.
+pub const MAX_FIND_HITS: usize = 10_000;
+
+/// A table to search, identified the same way as for loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRef {
+    pub file_path: String,
+    pub table_name: String,
+}
+
+/// What to search for.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindOptions {
+    pub pattern: String,
+    /// Treats `pattern` as a regular expression instead of literal text.
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Restricts the search to these columns. Naming an array column matches
+    /// all of its expanded `key[N]` columns. Empty searches every column.
+    #[serde(default)]
+    pub columns: Vec<String>,
+}
+
+/// A cell containing at least one match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindHit {
+    pub file_path: String,
+    pub table_name: String,
+    pub row_index: usize,
+    pub column_key: String,
+    pub value: Value,
+    pub match_count: usize,
+}
+
+/// A table which could not be searched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedTable {
+    pub file_path: String,
+    pub table_name: String,
+    pub reason: String,
+}
+
+/// Result of a find across tables.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindResult {
+    pub hits: Vec<FindHit>,
+    /// True if the search stopped after [MAX_FIND_HITS] hits.
+    pub truncated: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed_tables: Vec<FailedTable>,
+}
+
+/// Result of a replace in one table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceResult {
+    pub file_path: String,
+    pub table_name: String,
+    /// Number of cells changed. Zero if any update in any of the replaced
+    /// tables failed validation, since every table is then left unchanged.
+    pub replaced_count: usize,
+    /// Number of matching cells which were not strings and so were left as is.
+    pub skipped_count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed_updates: Vec<FailedUpdate>,
+}
+
+/// The changes a replace would make to a set of tables, computed and validated
+/// without writing any file.
+#[derive(Debug, Clone)]
+pub struct ReplacePlan {
+    tables: Vec<PlannedTable>,
+}
+
+#[derive(Debug, Clone)]
+struct PlannedTable {
+    result: ReplaceResult,
+    updates: Vec<CellUpdate>,
+}
+
+impl ReplacePlan {
+    /// True if an update to any table failed validation, in which case
+    /// [Self::apply] writes nothing.
+    pub fn has_failures(&self) -> bool {
+        self.tables.iter().any(|table| !table.result.failed_updates.is_empty())
+    }
+
+    /// Returns the file path and table name of each table the plan changes.
+    pub fn changed_tables(&self) -> Vec<(&str, &str)> {
+        self.tables
+            .iter()
+            .filter(|table| !table.updates.is_empty())
+            .map(|table| (table.result.file_path.as_str(), table.result.table_name.as_str()))
+            .collect()
+    }
+
+    /// Writes the planned changes to every table.
+    ///
+    /// Nothing is written if any update failed validation. Each table is
+    /// written with a single [document_writer::save_batch], so a write which
+    /// fails partway through can leave earlier tables changed; run this
+    /// through [OperationJournal::record_tables] to restore them.
+    ///
+    /// [OperationJournal::record_tables]: crate::toml::document_writer::OperationJournal::record_tables
+    pub fn apply(&self, config: &TvConfig) -> Result<Vec<ReplaceResult>, TvError> {
+        let mut results = self.tables.iter().map(|table| table.result.clone()).collect::<Vec<_>>();
+        if self.has_failures() {
+            tracing::warn!(
+                component = "tv.search",
+                table_count = self.tables.len(),
+                "Replace rejected due to validation failure"
+            );
+            return Ok(results);
+        }
+
+        for (table, result) in self.tables.iter().zip(&mut results) {
+            if table.updates.is_empty() {
+                continue;
+            }
+            let batch = document_writer::save_batch(
+                config,
+                &result.file_path,
+                &result.table_name,
+                &table.updates,
+            )?;
+            if let Some(failed) = batch.failed_updates.first() {
+                return Err(TvError::ValidationFailed {
+                    column: failed.column_key.clone(),
+                    row: failed.row_index,
+                    message: failed.reason.clone(),
+                });
+            }
+            result.replaced_count = batch.applied_count;
+
+            tracing::info!(
+                component = "tv.search",
+                file_path = %result.file_path,
+                table_name = %result.table_name,
+                replaced_count = result.replaced_count,
+                skipped_count = result.skipped_count,
+                "Replace completed"
+            );
+        }
+        Ok(results)
+    }
+}
+
+/// Compiles the search pattern, escaping it unless it is a regular expression.
+pub fn compile_pattern(options: &FindOptions) -> Result<Regex, TvError> {
+    if options.pattern.is_empty() {
+        return Err(TvError::InvalidSearchPattern {
+            pattern: String::new(),
+            message: "Search pattern is empty".to_string(),
+        });
+    }
+    let pattern =
+        if options.regex { options.pattern.clone() } else { regex::escape(&options.pattern) };
+    RegexBuilder::new(&pattern).case_insensitive(!options.case_sensitive).build().map_err(|e| {
+        TvError::InvalidSearchPattern { pattern: options.pattern.clone(), message: e.to_string() }
+    })
+}
+
+/// Finds every cell matching `options` in the given tables.
+///
+/// Tables which cannot be loaded are listed in [FindResult::failed_tables]
+/// instead of failing the whole search.
+pub fn find(
+    config: &TvConfig,
+    tables: &[TableRef],
+    options: &FindOptions,
+) -> Result<FindResult, TvError> {
+    let regex = compile_pattern(options)?;
+    let mut result = FindResult { hits: Vec::new(), truncated: false, failed_tables: Vec::new() };
+
+    for table_ref in tables {
+        let table = match document_loader::load_toml_document(
+            config,
+            &table_ref.file_path,
+            &table_ref.table_name,
+        ) {
+            Ok(table) => table,
+            Err(e) => {
+                tracing::warn!(
+                    component = "tv.search",
+                    file_path = %table_ref.file_path,
+                    table_name = %table_ref.table_name,
+                    error = %e,
+                    "Skipping table in find"
+                );
+                result.failed_tables.push(FailedTable {
+                    file_path: table_ref.file_path.clone(),
+                    table_name: table_ref.table_name.clone(),
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let columns = searched_columns(&table.headers, &options.columns);
+        for (row_index, row) in table.rows.iter().enumerate() {
+            for &(column_index, column_key) in &columns {
+                let Some(value) = row.get(column_index) else {
+                    continue;
+                };
+                let Some(text) = cell_text(value) else {
+                    continue;
+                };
+                let match_count = regex.find_iter(&text).count();
+                if match_count == 0 {
+                    continue;
+                }
+                if result.hits.len() == MAX_FIND_HITS {
+                    result.truncated = true;
+                    return Ok(result);
+                }
+                result.hits.push(FindHit {
+                    file_path: table_ref.file_path.clone(),
+                    table_name: table_ref.table_name.clone(),
+                    row_index,
+                    column_key: column_key.to_string(),
+                    value: value.clone(),
+                    match_count,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Computes the replacement of every match of `options` in the string cells
+/// of the given tables, and validates each new value without writing it.
+///
+/// For regular expression searches, `replacement` may refer to capture groups
+/// as `$1` or `${name}`.
+pub fn plan_replace(
+    config: &TvConfig,
+    tables: &[TableRef],
+    options: &FindOptions,
+    replacement: &str,
+) -> Result<ReplacePlan, TvError> {
+    let regex = compile_pattern(options)?;
+    let mut plan = ReplacePlan { tables: Vec::new() };
+    for table_ref in tables {
+        let file_path = &table_ref.file_path;
+        let table_name = &table_ref.table_name;
+        let table = document_loader::load_toml_document(config, file_path, table_name)?;
+        let columns = searched_columns(&table.headers, &options.columns);
+
+        let mut updates = Vec::new();
+        let mut skipped_count = 0;
+        for (row_index, row) in table.rows.iter().enumerate() {
+            for &(column_index, column_key) in &columns {
+                match row.get(column_index) {
+                    Some(Value::String(text)) if regex.is_match(text) => {
+                        let replaced = if options.regex {
+                            regex.replace_all(text, replacement)
+                        } else {
+                            regex.replace_all(text, NoExpand(replacement))
+                        };
+                        if replaced != *text {
+                            updates.push(CellUpdate {
+                                row_index,
+                                column_key: column_key.to_string(),
+                                value: Value::String(replaced.into_owned()),
+                            });
+                        }
+                    }
+                    Some(value) if cell_text(value).is_some_and(|text| regex.is_match(&text)) => {
+                        skipped_count += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let failed_updates = document_writer::check_batch(config, file_path, table_name, &updates)?;
+        plan.tables.push(PlannedTable {
+            result: ReplaceResult {
+                file_path: file_path.clone(),
+                table_name: table_name.clone(),
+                replaced_count: 0,
+                skipped_count,
+                failed_updates,
+            },
+            updates,
+        });
+    }
+    Ok(plan)
+}
+
+/// Replaces every match of `options` in the string cells of one table.
+///
+/// Either every cell is updated or, if any new value fails validation, none
+/// are. See [plan_replace].
+pub fn replace_in_table(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    options: &FindOptions,
+    replacement: &str,
+) -> Result<ReplaceResult, TvError> {
+    let table = TableRef { file_path: file_path.to_string(), table_name: table_name.to_string() };
+    let plan = plan_replace(config, &[table], options, replacement)?;
+    let mut results = plan.apply(config)?;
+    Ok(results.remove(0))
+}
+
+/// Returns the index and key of each column included in the search.
+fn searched_columns<'a>(headers: &'a [String], columns: &[String]) -> Vec<(usize, &'a str)> {
+    headers
+        .iter()
+        .enumerate()
+        .filter(|(_, header)| {
+            columns.is_empty()
+                || columns.iter().any(|column| {
+                    column == *header
+                        || array_columns::parse_array_column_key(header)
+                            .is_some_and(|(base, _)| column == base)
+                })
+        })
+        .map(|(index, header)| (index, header.as_str()))
+        .collect()
+}
+
+/// Returns the text a cell is displayed with, or None for empty cells and
+/// nested values.
+fn cell_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::RwLock;
 
 use crate::sort::sort_types::{CellValue, SortDirection, SortState};
 use crate::toml::document_loader::TomlTableData;
+use crate::toml::row_operations;
 
 pub struct SortStateManager {
     states: RwLock<HashMap<String, SortState>>,
@@ -102,6 +103,47 @@ impl SortStateManager {
             .and_then(|mappings| mappings.get(&key).and_then(|m| m.get(display_index).copied()))
             .unwrap_or(display_index)
     }
+
+    /// Updates the row mapping after a copy of `source_index` is inserted at
+    /// `row_index`. The copy is displayed directly after its source.
+    pub fn on_row_duplicated(
+        &self,
+        file_path: &str,
+        table_name: &str,
+        source_index: usize,
+        row_index: usize,
+    ) {
+        let key = format!("{file_path}::{table_name}");
+        let Ok(mut mappings) = self.row_mappings.write() else {
+            return;
+        };
+        let Some(mapping) = mappings.get_mut(&key) else {
+            return;
+        };
+        for original in mapping.iter_mut() {
+            if *original >= row_index {
+                *original += 1;
+            }
+        }
+        let display_index = mapping
+            .iter()
+            .position(|&original| original == source_index)
+            .map_or(mapping.len(), |i| i + 1);
+        mapping.insert(display_index, row_index);
+    }
+
+    /// Updates the row mapping after a row is moved. Display order is kept,
+    /// since it is determined by the active sort.
+    pub fn on_row_moved(&self, file_path: &str, table_name: &str, from_index: usize, to_index: usize) {
+        let key = format!("{file_path}::{table_name}");
+        if let Ok(mut mappings) = self.row_mappings.write() {
+            if let Some(mapping) = mappings.get_mut(&key) {
+                for original in mapping.iter_mut() {
+                    *original = row_operations::index_after_move(*original, from_index, to_index);
+                }
+            }
+        }
+    }
 }
 
 impl Default for SortStateManager {
@@ -12,6 +12,7 @@ use tauri::{AppHandle, Emitter, Manager};
 use crate::error::error_types::TvError;
 use crate::error::permission_recovery::{self, PermissionState};
 use crate::sync::state_machine;
+use crate::toml::operation_journal::OperationJournal;
 
 #[derive(Clone, Serialize)]
 pub struct FileChangedPayload {
@@ -261,6 +262,12 @@ fn run_watcher(
                 "File watcher event will be emitted"
             );
 
+            // The file was changed outside TV, so recorded operations can no
+            // longer be undone safely.
+            if let Some(journal) = app_handle.try_state::<OperationJournal>() {
+                journal.clear(&file_path);
+            }
+
             if !path.exists() {
                 // Check if file was previously marked as deleted - if so, it's still
                 // missing, no need to emit another event
@@ -244,6 +244,32 @@ pub fn end_save(
     Ok(false)
 }
 
+/// Runs a write which changes several files, holding each of them in the
+/// Saving state for its duration.
+///
+/// If any file cannot begin saving, the files which already began are returned
+/// to Idle and the write is not run.
+pub fn save_files<T>(
+    app_handle: &AppHandle,
+    file_paths: &[String],
+    write: impl FnOnce() -> Result<T, TvError>,
+) -> Result<T, TvError> {
+    for (index, file_path) in file_paths.iter().enumerate() {
+        if let Err(e) = begin_save(app_handle, file_path) {
+            for started in &file_paths[..index] {
+                let _ = end_save(app_handle, started, true);
+            }
+            return Err(e);
+        }
+    }
+
+    let result = write();
+    for file_path in file_paths {
+        let _ = end_save(app_handle, file_path, result.is_ok());
+    }
+    result
+}
+
 /// Attempts to transition to the Loading state from Idle or Error.
 pub fn begin_load(app_handle: &AppHandle, file_path: &str) -> Result<(), TvError> {
     let state = app_handle.state::<SyncStateMachineState>();
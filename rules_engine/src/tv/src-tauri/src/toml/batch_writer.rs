@@ -78,53 +78,7 @@ pub fn save_batch_with_rules(
         .and_then(|v| v.as_array_of_tables_mut())
         .ok_or_else(|| TvError::TableNotFound { table_name: table_name.to_string() })?;
 
-    let array_len = array.len();
-    let mut failed_updates = Vec::new();
-    for update in updates {
-        if update.row_index >= array_len {
-            failed_updates.push(FailedUpdate {
-                row_index: update.row_index,
-                column_key: update.column_key.clone(),
-                reason: format!(
-                    "Row index {} out of bounds (max: {})",
-                    update.row_index,
-                    array_len.saturating_sub(1)
-                ),
-            });
-        } else if !update.value.is_null()
-            && value_converter::json_to_toml_edit(&update.value).is_none()
-        {
-            failed_updates.push(FailedUpdate {
-                row_index: update.row_index,
-                column_key: update.column_key.clone(),
-                reason: "Unsupported value type".to_string(),
-            });
-        } else {
-            let validation_column = array_columns::parse_array_column_key(&update.column_key)
-                .map_or(update.column_key.as_str(), |(base, _)| base);
-            let results =
-                validators::validate_all(&validation_rules, validation_column, &update.value);
-            if let Some(error) = validators::first_error(&results) {
-                tracing::warn!(
-                    component = "tv.toml.validation",
-                    column = %update.column_key,
-                    row = update.row_index,
-                    rule_type = %error.rule_type,
-                    error = ?error.error_message,
-                    "Validation failed in batch"
-                );
-                failed_updates.push(FailedUpdate {
-                    row_index: update.row_index,
-                    column_key: update.column_key.clone(),
-                    reason: error
-                        .error_message
-                        .clone()
-                        .unwrap_or_else(|| "Validation failed".to_string()),
-                });
-            }
-        }
-    }
-
+    let failed_updates = failed_updates(array.len(), &validation_rules, updates);
     if !failed_updates.is_empty() {
         tracing::warn!(
             component = "tv.toml",
@@ -187,3 +141,93 @@ pub fn save_batch_with_rules(
         failed_updates: Vec::new(),
     })
 }
+
+/// Checks a set of cell updates the same way as [save_batch], without writing
+/// the file.
+///
+/// Returns the updates which would be rejected. Used to validate a change
+/// which spans several tables before writing any of them.
+pub fn check_batch(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    updates: &[CellUpdate],
+) -> Result<Vec<FailedUpdate>, TvError> {
+    if updates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let content = config
+        .fs()
+        .read_to_string(Path::new(file_path))
+        .map_err(|e| map_io_error_for_read(&e, file_path))?;
+    let validation_rules =
+        metadata::parse_validation_rules_from_content(&content, file_path).unwrap_or_default();
+    let doc: toml_edit::DocumentMut = content.parse().map_err(|e: toml_edit::TomlError| {
+        TvError::TomlParseError { path: file_path.to_string(), line: None, message: e.to_string() }
+    })?;
+
+    let key = table_key::resolve_key_name(&doc, table_name, file_path, "Batch check failed")?;
+    let array = doc
+        .get(&key)
+        .and_then(|v| v.as_array_of_tables())
+        .ok_or_else(|| TvError::TableNotFound { table_name: table_name.to_string() })?;
+    Ok(failed_updates(array.len(), &validation_rules, updates))
+}
+
+/// Returns the updates which cannot be applied to a table with `array_len`
+/// rows, either because they are out of bounds, have an unsupported value
+/// type or fail one of the validation rules.
+fn failed_updates(
+    array_len: usize,
+    validation_rules: &[ValidationRule],
+    updates: &[CellUpdate],
+) -> Vec<FailedUpdate> {
+    let mut failed_updates = Vec::new();
+    for update in updates {
+        if update.row_index >= array_len {
+            failed_updates.push(FailedUpdate {
+                row_index: update.row_index,
+                column_key: update.column_key.clone(),
+                reason: format!(
+                    "Row index {} out of bounds (max: {})",
+                    update.row_index,
+                    array_len.saturating_sub(1)
+                ),
+            });
+        } else if !update.value.is_null()
+            && value_converter::json_to_toml_edit(&update.value).is_none()
+        {
+            failed_updates.push(FailedUpdate {
+                row_index: update.row_index,
+                column_key: update.column_key.clone(),
+                reason: "Unsupported value type".to_string(),
+            });
+        } else {
+            let validation_column = array_columns::parse_array_column_key(&update.column_key)
+                .map_or(update.column_key.as_str(), |(base, _)| base);
+            let results =
+                validators::validate_all(validation_rules, validation_column, &update.value);
+            if let Some(error) = validators::first_error(&results) {
+                tracing::warn!(
+                    component = "tv.toml.validation",
+                    column = %update.column_key,
+                    row = update.row_index,
+                    rule_type = %error.rule_type,
+                    error = ?error.error_message,
+                    "Validation failed in batch"
+                );
+                failed_updates.push(FailedUpdate {
+                    row_index: update.row_index,
+                    column_key: update.column_key.clone(),
+                    reason: error
+                        .error_message
+                        .clone()
+                        .unwrap_or_else(|| "Validation failed".to_string()),
+                });
+            }
+        }
+    }
+
+    failed_updates
+}
@@ -0,0 +1,174 @@
+//! Review comment threads attached to table cells.
+//!
+//! Threads are stored in the `[[metadata.comments]]` section of the TOML file
+//! and refer to their row by its "id" value, see [CommentThread]. They are
+//! matched back to row indices whenever the table is loaded.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::error_types::{map_io_error_for_read, TvError};
+use crate::toml::document_loader::{self, TomlTableData};
+use crate::toml::metadata;
+use crate::toml::metadata_serializer;
+use crate::toml::metadata_types::{CommentMessage, CommentThread};
+use crate::traits::TvConfig;
+
+/// A comment to add to a cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewComment {
+    pub row_index: usize,
+    pub column: String,
+    pub author: String,
+    pub text: String,
+    /// Thread to reply to. Starts a new thread on the cell if None.
+    #[serde(default)]
+    pub thread_id: Option<String>,
+}
+
+/// A comment thread together with the current index of its row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CellCommentThread {
+    pub row_index: usize,
+    #[serde(flatten)]
+    pub thread: CommentThread,
+}
+
+/// Adds a comment to a cell, either as a new thread or as a reply.
+///
+/// Replying to a resolved thread reopens it. Returns the updated thread.
+pub fn add_comment(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    comment: &NewComment,
+) -> Result<CommentThread, TvError> {
+    let table = document_loader::load_toml_document(config, file_path, table_name)?;
+    let invalid_target = |message: &str| TvError::InvalidCommentTarget {
+        table_name: table_name.to_string(),
+        row_index: comment.row_index,
+        message: message.to_string(),
+    };
+    if !table.headers.contains(&comment.column) {
+        return Err(invalid_target("column does not exist"));
+    }
+    let row_key = row_keys(&table)
+        .get(comment.row_index)
+        .cloned()
+        .ok_or_else(|| invalid_target("row does not exist"))?
+        .ok_or_else(|| invalid_target("row has no id"))?;
+
+    let mut threads = read_threads(config, file_path)?;
+    let message = CommentMessage {
+        author: comment.author.clone(),
+        text: comment.text.clone(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let thread = match &comment.thread_id {
+        Some(thread_id) => {
+            let thread = find_thread(&mut threads, thread_id)?;
+            thread.messages.push(message);
+            thread.resolved = false;
+            thread.clone()
+        }
+        None => {
+            let thread = CommentThread {
+                id: uuid::Uuid::new_v4().to_string(),
+                row_key,
+                column: comment.column.clone(),
+                resolved: false,
+                messages: vec![message],
+            };
+            threads.push(thread.clone());
+            thread
+        }
+    };
+    metadata_serializer::update_comments(config, file_path, &threads)?;
+
+    tracing::info!(
+        component = "tv.toml.comments",
+        file_path = %file_path,
+        thread_id = %thread.id,
+        message_count = thread.messages.len(),
+        "Comment added"
+    );
+    Ok(thread)
+}
+
+/// Marks a comment thread as resolved, or reopens it.
+pub fn set_thread_resolved(
+    config: &TvConfig,
+    file_path: &str,
+    thread_id: &str,
+    resolved: bool,
+) -> Result<CommentThread, TvError> {
+    let mut threads = read_threads(config, file_path)?;
+    let thread = find_thread(&mut threads, thread_id)?;
+    thread.resolved = resolved;
+    let thread = thread.clone();
+    metadata_serializer::update_comments(config, file_path, &threads)?;
+
+    tracing::info!(
+        component = "tv.toml.comments",
+        file_path = %file_path,
+        thread_id = %thread_id,
+        resolved = resolved,
+        "Comment thread resolution changed"
+    );
+    Ok(thread)
+}
+
+/// Returns the comment threads for a loaded table with their row indices.
+///
+/// Threads whose row no longer exists are left out.
+pub fn cell_comments(
+    config: &TvConfig,
+    file_path: &str,
+    table: &TomlTableData,
+) -> Result<Vec<CellCommentThread>, TvError> {
+    let keys = row_keys(table);
+    let threads = read_threads(config, file_path)?;
+    Ok(threads
+        .into_iter()
+        .filter_map(|thread| {
+            let row_index =
+                keys.iter().position(|key| key.as_deref() == Some(thread.row_key.as_str()))?;
+            Some(CellCommentThread { row_index, thread })
+        })
+        .collect())
+}
+
+fn read_threads(config: &TvConfig, file_path: &str) -> Result<Vec<CommentThread>, TvError> {
+    let content = config
+        .fs()
+        .read_to_string(Path::new(file_path))
+        .map_err(|e| map_io_error_for_read(&e, file_path))?;
+    metadata::parse_comments_from_content(&content, file_path)
+}
+
+fn find_thread<'a>(
+    threads: &'a mut [CommentThread],
+    thread_id: &str,
+) -> Result<&'a mut CommentThread, TvError> {
+    threads
+        .iter_mut()
+        .find(|thread| thread.id == thread_id)
+        .ok_or_else(|| TvError::CommentThreadNotFound { thread_id: thread_id.to_string() })
+}
+
+/// Returns the "id" value of each row, or None for rows without one.
+fn row_keys(table: &TomlTableData) -> Vec<Option<String>> {
+    let id_index = table.headers.iter().position(|h| h.eq_ignore_ascii_case("id"));
+    table
+        .rows
+        .iter()
+        .map(|row| match id_index.and_then(|i| row.get(i))? {
+            serde_json::Value::String(s) if !s.trim().is_empty() => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .collect()
+}
@@ -6,6 +6,7 @@ use std::time::Instant;
 use serde::{Deserialize, Serialize};
 
 use crate::error::error_types::TvError;
+use crate::toml::cell_comments::CellCommentThread;
 use crate::toml::{array_columns, value_converter};
 use crate::traits::TvConfig;
 
@@ -15,6 +16,16 @@ pub struct TomlTableData {
     pub rows: Vec<Vec<serde_json::Value>>,
 }
 
+/// Table data returned to the frontend on load, together with the comment
+/// threads attached to its cells.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoadedTable {
+    #[serde(flatten)]
+    pub data: TomlTableData,
+    #[serde(default)]
+    pub comments: Vec<CellCommentThread>,
+}
+
 /// Loads a TOML file and extracts the specified table as spreadsheet data.
 pub fn load_toml_document(
     config: &TvConfig,
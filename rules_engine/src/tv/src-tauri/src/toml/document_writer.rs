@@ -4,7 +4,8 @@
 //! For more granular operations, see:
 //! - [`cell_writer`]: Single cell updates
 //! - [`batch_writer`]: Multiple cell updates in a single atomic write
-//! - [`row_operations`]: Adding and deleting rows
+//! - [`row_operations`]: Adding, deleting, duplicating and moving rows
+//! - [`operation_journal`]: Undo and redo history for the operations above
 //! - [`temp_cleanup`]: Orphaned temp file cleanup
 
 use std::path::Path;
@@ -20,13 +21,14 @@ use crate::traits::TvConfig;
 use crate::uuid::uuid_generator;
 
 // Re-export types and functions for backwards compatibility
-pub use crate::toml::batch_writer::{save_batch, save_batch_with_rules};
+pub use crate::toml::batch_writer::{check_batch, save_batch, save_batch_with_rules};
 pub use crate::toml::cell_writer::{save_cell, save_cell_with_rules};
-pub use crate::toml::row_operations::{add_row, delete_row};
+pub use crate::toml::operation_journal::{JournalOperation, OperationJournal, UndoRedoResult};
+pub use crate::toml::row_operations::{add_row, delete_row, duplicate_row, move_row};
 pub use crate::toml::temp_cleanup::cleanup_orphaned_temp_files;
 pub use crate::toml::writer_types::{
-    AddRowResult, CellUpdate, DeleteRowResult, FailedUpdate, SaveBatchResult, SaveCellResult,
-    SaveTableResult,
+    AddRowResult, CellUpdate, DeleteRowResult, DuplicateRowResult, FailedUpdate, MoveRowResult,
+    SaveBatchResult, SaveCellResult, SaveTableResult,
 };
 
 /// Saves spreadsheet data back to a TOML file, preserving formatting.
@@ -195,7 +197,7 @@ pub fn save_toml_document(
         "File saved"
     );
 
-    Ok(SaveTableResult { uuids_generated })
+    Ok(SaveTableResult { uuids_generated, validation_errors: Vec::new() })
 }
 
 /// Collects non-null values from an array group's columns and writes the
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use crate::error::error_types::TvError;
+use crate::toml::metadata::helpers::parse_toml_content;
+use crate::toml::metadata_types::CommentThread;
+use crate::traits::{FileSystem, RealFileSystem};
+
+/// Parses the metadata.comments section from a TOML file using the real filesystem.
+pub fn parse_comments_from_file(file_path: &str) -> Result<Vec<CommentThread>, TvError> {
+    parse_comments_with_fs(&RealFileSystem, file_path)
+}
+
+/// Parses the metadata.comments section from a TOML file.
+pub fn parse_comments_with_fs(
+    fs: &dyn FileSystem,
+    file_path: &str,
+) -> Result<Vec<CommentThread>, TvError> {
+    let content = fs
+        .read_to_string(Path::new(file_path))
+        .map_err(|_| TvError::FileNotFound { path: file_path.to_string() })?;
+
+    parse_comments_from_content(&content, file_path)
+}
+
+/// Parses comment threads from TOML content string.
+pub fn parse_comments_from_content(
+    content: &str,
+    file_path: &str,
+) -> Result<Vec<CommentThread>, TvError> {
+    let value = parse_toml_content(content, file_path)?;
+
+    let Some(metadata) = value.get("metadata") else {
+        return Ok(Vec::new());
+    };
+
+    let Some(comments_value) = metadata.get("comments") else {
+        return Ok(Vec::new());
+    };
+
+    let Some(comments_array) = comments_value.as_array() else {
+        tracing::warn!(
+            component = "tv.toml.metadata",
+            file_path = %file_path,
+            "metadata.comments is not an array"
+        );
+        return Ok(Vec::new());
+    };
+
+    let mut threads = Vec::new();
+    for (idx, thread_value) in comments_array.iter().enumerate() {
+        match thread_value.clone().try_into::<CommentThread>() {
+            Ok(thread) => threads.push(thread),
+            Err(e) => {
+                tracing::warn!(
+                    component = "tv.toml.metadata",
+                    file_path = %file_path,
+                    thread_index = idx,
+                    error = %e,
+                    "Failed to parse comment thread, skipping"
+                );
+            }
+        }
+    }
+
+    tracing::debug!(
+        component = "tv.toml.metadata",
+        file_path = %file_path,
+        thread_count = threads.len(),
+        "Parsed comment threads from metadata"
+    );
+
+    Ok(threads)
+}
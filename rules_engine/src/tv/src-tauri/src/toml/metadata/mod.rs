@@ -1,4 +1,5 @@
 pub mod column_parser;
+pub mod comment_parser;
 pub mod derived_parser;
 pub mod filter_parser;
 pub mod formatting_parser;
@@ -13,6 +14,9 @@ pub use column_parser::{
     parse_column_configs_from_content, parse_column_configs_from_file,
     parse_column_configs_with_fs,
 };
+pub use comment_parser::{
+    parse_comments_from_content, parse_comments_from_file, parse_comments_with_fs,
+};
 pub use derived_parser::{
     parse_derived_columns_from_content, parse_derived_columns_from_file,
     parse_derived_columns_with_fs,
@@ -4,9 +4,9 @@ use toml_edit::{value, Array, ArrayOfTables, DocumentMut, InlineTable, Item, Tab
 
 use crate::error::error_types::{map_io_error_for_read, TvError};
 use crate::toml::metadata_types::{
-    Alignment, AppSettings, ColumnConfig, ColumnFilter, ConditionalFormatRule, DerivedColumnConfig,
-    FilterCondition, FilterConfig, FormatCondition, FormatStyle, Metadata, RowConfig, RowHeight,
-    ScrollPosition, SortConfig, TableStyle,
+    Alignment, AppSettings, ColumnConfig, ColumnFilter, CommentThread, ConditionalFormatRule,
+    DerivedColumnConfig, FilterCondition, FilterConfig, FormatCondition, FormatStyle, Metadata,
+    RowConfig, RowHeight, ScrollPosition, SortConfig, TableStyle,
 };
 use crate::traits::{AtomicWriteError, TvConfig};
 use crate::validation::validation_rules::{ValidationRule, ValueType};
@@ -125,6 +125,60 @@ pub fn update_filter_config(
     Ok(())
 }
 
+/// Replaces the comment threads in the metadata section of a TOML file.
+pub fn update_comments(
+    config: &TvConfig,
+    file_path: &str,
+    threads: &[CommentThread],
+) -> Result<(), TvError> {
+    let content = config.fs().read_to_string(Path::new(file_path)).map_err(|e| {
+        tracing::error!(
+            component = "tv.toml.metadata",
+            file_path = %file_path,
+            error = %e,
+            "Read failed during comments update"
+        );
+        map_io_error_for_read(&e, file_path)
+    })?;
+
+    let mut doc: DocumentMut = content.parse().map_err(|e: toml_edit::TomlError| {
+        tracing::error!(
+            component = "tv.toml.metadata",
+            file_path = %file_path,
+            error = %e,
+            "TOML parse failed during comments update"
+        );
+        TvError::TomlParseError { path: file_path.to_string(), line: None, message: e.to_string() }
+    })?;
+
+    let metadata_table = doc.entry("metadata").or_insert_with(|| {
+        let mut table = Table::new();
+        table.insert("schema_version", value(1i64));
+        Item::Table(table)
+    });
+
+    if let Some(table) = metadata_table.as_table_mut() {
+        if threads.is_empty() {
+            table.remove("comments");
+        } else {
+            table.insert("comments", Item::ArrayOfTables(serialize_comment_threads(threads)));
+        }
+    }
+
+    config.fs().write_atomic(Path::new(file_path), &doc.to_string()).map_err(|e| {
+        map_atomic_write_error(e, file_path)
+    })?;
+
+    tracing::debug!(
+        component = "tv.toml.metadata",
+        file_path = %file_path,
+        thread_count = threads.len(),
+        "Comments updated in metadata"
+    );
+
+    Ok(())
+}
+
 /// Updates the width of a single column in the metadata.columns array.
 pub fn update_column_width(
     config: &TvConfig,
@@ -695,6 +749,32 @@ fn serialize_sort_config(sort: &SortConfig) -> Table {
     table
 }
 
+fn serialize_comment_threads(threads: &[CommentThread]) -> ArrayOfTables {
+    let mut array = ArrayOfTables::new();
+    for thread in threads {
+        let mut table = Table::new();
+        table.insert("id", value(&thread.id));
+        table.insert("row_key", value(&thread.row_key));
+        table.insert("column", value(&thread.column));
+        if thread.resolved {
+            table.insert("resolved", value(true));
+        }
+        let mut messages = Array::new();
+        for message in &thread.messages {
+            let mut inline = InlineTable::new();
+            inline.insert("author", Value::from(message.author.as_str()));
+            inline.insert("text", Value::from(message.text.as_str()));
+            inline.insert("created_at", Value::from(message.created_at.as_str()));
+            messages.push_formatted(Value::InlineTable(inline).decorated("\n    ", ""));
+        }
+        messages.set_trailing_comma(true);
+        messages.set_trailing("\n");
+        table.insert("messages", value(messages));
+        array.push(table);
+    }
+    array
+}
+
 fn serialize_filter_config(filter: &FilterConfig) -> Table {
     let mut table = Table::new();
     if !filter.filters.is_empty() {
@@ -63,6 +63,10 @@ pub struct Metadata {
     /// Statistics overlay configurations.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub statistics: Vec<StatisticConfig>,
+
+    /// Review comment threads attached to individual cells.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<CommentThread>,
 }
 
 impl Metadata {
@@ -580,3 +584,33 @@ pub enum StatisticType {
     #[default]
     ValueCounts,
 }
+
+/// A thread of review comments attached to one cell.
+///
+/// The cell is identified by the row's "id" value rather than its index, so
+/// threads stay with their row when rows are sorted, moved or deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentThread {
+    /// Unique identifier for the thread.
+    pub id: String,
+    /// Value of the "id" column of the row this thread is attached to.
+    pub row_key: String,
+    /// Column key this thread is attached to.
+    pub column: String,
+    /// Whether the thread has been resolved. Resolved threads are kept for
+    /// reference but no longer shown as open.
+    #[serde(default)]
+    pub resolved: bool,
+    /// Comments in the order they were added.
+    #[serde(default)]
+    pub messages: Vec<CommentMessage>,
+}
+
+/// A single comment within a [CommentThread].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentMessage {
+    pub author: String,
+    pub text: String,
+    /// Time the comment was added, in RFC 3339 format.
+    pub created_at: String,
+}
@@ -1,5 +1,6 @@
 pub mod array_columns;
 pub mod batch_writer;
+pub mod cell_comments;
 pub mod cell_writer;
 pub mod color_schemes;
 pub mod conditional_formatting;
@@ -8,6 +9,7 @@ pub mod document_writer;
 pub mod metadata;
 pub mod metadata_serializer;
 pub mod metadata_types;
+pub mod operation_journal;
 pub mod row_operations;
 pub mod table_key;
 pub mod temp_cleanup;
@@ -0,0 +1,426 @@
+//! Undo and redo history for TOML write operations.
+//!
+//! Each write which changes a file records the document text before and after
+//! the operation. Undoing an operation restores only the affected table, so
+//! edits elsewhere in the file (such as the metadata section) are kept. If the
+//! table no longer matches the journal, for example because the file was
+//! edited in another program, the file's history is discarded instead of
+//! overwriting the external change.
+//!
+//! An operation which changes several files, such as a replace across tables,
+//! is recorded in the history of each file. Undoing or redoing it from any of
+//! those files applies it to all of them.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::error::error_types::{map_io_error_for_read, TvError};
+use crate::toml::cell_writer::map_atomic_write_error;
+use crate::toml::table_key;
+use crate::traits::TvConfig;
+
+/// Maximum number of operations kept in the undo history of each file.
+pub const MAX_JOURNAL_ENTRIES: usize = 100;
+
+/// A write operation recorded in the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JournalOperation {
+    SaveTable,
+    SaveCell,
+    SaveBatch,
+    AddRow,
+    DeleteRow,
+    DuplicateRow,
+    MoveRow,
+    ImportCsv,
+    Replace,
+}
+
+/// Result of an undo or redo operation.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoRedoResult {
+    /// The operation which was undone or redone, or None if there was nothing
+    /// to undo or redo.
+    pub operation: Option<JournalOperation>,
+    pub undo_count: usize,
+    pub redo_count: usize,
+    /// Every file changed by the undo or redo.
+    pub file_paths: Vec<String>,
+}
+
+/// A recorded operation with the document text on either side of it.
+struct JournalEntry {
+    operation: JournalOperation,
+    /// Tables in this file changed by the operation.
+    table_names: Vec<String>,
+    /// Shared by the entries recorded in each file for an operation which
+    /// changed several files.
+    group: Option<u64>,
+    before: String,
+    after: String,
+}
+
+#[derive(Default)]
+struct FileJournal {
+    undo: Vec<JournalEntry>,
+    redo: Vec<JournalEntry>,
+}
+
+impl FileJournal {
+    fn result(&self, operation: Option<JournalOperation>) -> UndoRedoResult {
+        UndoRedoResult {
+            operation,
+            undo_count: self.undo.len(),
+            redo_count: self.redo.len(),
+            file_paths: Vec::new(),
+        }
+    }
+
+    fn stack(&mut self, direction: Direction) -> &mut Vec<JournalEntry> {
+        match direction {
+            Direction::Undo => &mut self.undo,
+            Direction::Redo => &mut self.redo,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Undo,
+    Redo,
+}
+
+/// Undo and redo history for every file written during this session.
+pub struct OperationJournal {
+    files: Mutex<HashMap<String, FileJournal>>,
+    next_group: AtomicU64,
+}
+
+impl Default for OperationJournal {
+    fn default() -> Self {
+        Self { files: Mutex::new(HashMap::new()), next_group: AtomicU64::new(0) }
+    }
+}
+
+impl OperationJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs a write operation on a table, recording it in the journal if it
+    /// changed the file.
+    ///
+    /// Recording an operation discards the file's redo history.
+    pub fn record<T>(
+        &self,
+        config: &TvConfig,
+        file_path: &str,
+        table_name: &str,
+        operation: JournalOperation,
+        write: impl FnOnce() -> Result<T, TvError>,
+    ) -> Result<T, TvError> {
+        self.record_tables(config, &[(file_path, table_name)], operation, write)
+    }
+
+    /// Runs a write operation on several tables, given as pairs of file path
+    /// and table name, recording it as a single operation.
+    ///
+    /// If the write fails, every file it changed is restored to its previous
+    /// contents, so an operation which fails partway through has no effect.
+    pub fn record_tables<T>(
+        &self,
+        config: &TvConfig,
+        tables: &[(&str, &str)],
+        operation: JournalOperation,
+        write: impl FnOnce() -> Result<T, TvError>,
+    ) -> Result<T, TvError> {
+        let mut snapshots: Vec<(&str, Option<String>)> = Vec::new();
+        for &(file_path, _) in tables {
+            if !snapshots.iter().any(|(path, _)| *path == file_path) {
+                snapshots.push((file_path, config.fs().read_to_string(Path::new(file_path)).ok()));
+            }
+        }
+
+        let result = match write() {
+            Ok(result) => result,
+            Err(e) => {
+                restore_snapshots(config, &snapshots);
+                return Err(e);
+            }
+        };
+
+        let changed = snapshots
+            .into_iter()
+            .filter_map(|(file_path, before)| {
+                let after = config.fs().read_to_string(Path::new(file_path)).ok()?;
+                let before = before?;
+                (before != after).then_some((file_path, before, after))
+            })
+            .collect::<Vec<_>>();
+        let group = (changed.len() > 1).then(|| self.next_group.fetch_add(1, Ordering::Relaxed));
+
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        for (file_path, before, after) in changed {
+            let table_names = tables
+                .iter()
+                .filter(|(path, _)| *path == file_path)
+                .map(|(_, table_name)| table_name.to_string())
+                .collect();
+            let journal = files.entry(file_path.to_string()).or_default();
+            journal.redo.clear();
+            journal.undo.push(JournalEntry { operation, table_names, group, before, after });
+            if journal.undo.len() > MAX_JOURNAL_ENTRIES {
+                journal.undo.remove(0);
+            }
+
+            tracing::debug!(
+                component = "tv.toml.journal",
+                file_path = %file_path,
+                operation = ?operation,
+                undo_count = journal.undo.len(),
+                "Operation recorded"
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Reverts the most recent operation on a file.
+    pub fn undo(&self, config: &TvConfig, file_path: &str) -> Result<UndoRedoResult, TvError> {
+        self.apply(config, file_path, Direction::Undo)
+    }
+
+    /// Reapplies the most recently undone operation on a file.
+    pub fn redo(&self, config: &TvConfig, file_path: &str) -> Result<UndoRedoResult, TvError> {
+        self.apply(config, file_path, Direction::Redo)
+    }
+
+    /// Returns every file changed by undoing the most recent operation on
+    /// `file_path`, including `file_path` itself.
+    pub fn undo_files(&self, file_path: &str) -> Vec<String> {
+        self.linked_files(file_path, Direction::Undo)
+    }
+
+    /// Returns every file changed by redoing the most recently undone
+    /// operation on `file_path`, including `file_path` itself.
+    pub fn redo_files(&self, file_path: &str) -> Vec<String> {
+        self.linked_files(file_path, Direction::Redo)
+    }
+
+    /// Returns the number of operations which can be undone and redone for a
+    /// file.
+    pub fn status(&self, file_path: &str) -> UndoRedoResult {
+        let files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        files.get(file_path).map(|journal| journal.result(None)).unwrap_or_default()
+    }
+
+    /// Discards the undo and redo history for a file.
+    pub fn clear(&self, file_path: &str) {
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        if files.remove(file_path).is_some() {
+            tracing::debug!(
+                component = "tv.toml.journal",
+                file_path = %file_path,
+                "Journal cleared"
+            );
+        }
+    }
+
+    fn linked_files(&self, file_path: &str, direction: Direction) -> Vec<String> {
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        let group = files
+            .get_mut(file_path)
+            .and_then(|journal| journal.stack(direction).last())
+            .and_then(|entry| entry.group);
+        let mut result = vec![file_path.to_string()];
+        if let Some(group) = group {
+            for (path, journal) in files.iter_mut() {
+                if path != file_path
+                    && journal.stack(direction).last().is_some_and(|e| e.group == Some(group))
+                {
+                    result.push(path.clone());
+                }
+            }
+        }
+        result
+    }
+
+    fn apply(
+        &self,
+        config: &TvConfig,
+        file_path: &str,
+        direction: Direction,
+    ) -> Result<UndoRedoResult, TvError> {
+        let start = Instant::now();
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(journal) = files.get_mut(file_path) else {
+            return Ok(UndoRedoResult::default());
+        };
+        let Some(entry) = journal.stack(direction).pop() else {
+            return Ok(journal.result(None));
+        };
+
+        let operation = entry.operation;
+        let group = entry.group;
+        let mut entries = vec![(file_path.to_string(), entry)];
+        if let Some(group) = group {
+            for (path, journal) in files.iter_mut() {
+                if path != file_path
+                    && journal.stack(direction).last().is_some_and(|e| e.group == Some(group))
+                {
+                    let linked = journal.stack(direction).pop().expect("Entry should exist");
+                    entries.push((path.clone(), linked));
+                }
+            }
+        }
+
+        let restored = entries
+            .iter()
+            .map(|(path, entry)| {
+                let (expected, target) = match direction {
+                    Direction::Undo => (&entry.after, &entry.before),
+                    Direction::Redo => (&entry.before, &entry.after),
+                };
+                restored_document(config, path, &entry.table_names, expected, target)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|documents| {
+                for ((path, _), document) in entries.iter().zip(documents) {
+                    config
+                        .fs()
+                        .write_atomic(Path::new(path), &document)
+                        .map_err(|e| map_atomic_write_error(e, path))?;
+                }
+                Ok(())
+            });
+
+        if let Err(e) = restored {
+            let conflict = match &e {
+                TvError::JournalConflict { path } => Some(path.clone()),
+                _ => None,
+            };
+            for (path, entry) in entries {
+                if conflict.as_ref() == Some(&path) {
+                    tracing::warn!(
+                        component = "tv.toml.journal",
+                        file_path = %path,
+                        direction = ?direction,
+                        "Table changed outside TV, discarding journal"
+                    );
+                    files.remove(&path);
+                } else if let Some(journal) = files.get_mut(&path) {
+                    journal.stack(direction).push(entry);
+                }
+            }
+            return Err(e);
+        }
+
+        let file_paths = entries.iter().map(|(path, _)| path.clone()).collect();
+        let opposite = match direction {
+            Direction::Undo => Direction::Redo,
+            Direction::Redo => Direction::Undo,
+        };
+        for (path, entry) in entries {
+            if let Some(journal) = files.get_mut(&path) {
+                journal.stack(opposite).push(entry);
+            }
+        }
+
+        let duration_ms = start.elapsed().as_millis() as u64;
+        tracing::debug!(
+            component = "tv.toml.journal",
+            file_path = %file_path,
+            direction = ?direction,
+            operation = ?operation,
+            duration_ms = duration_ms,
+            "Operation applied"
+        );
+
+        let result = files
+            .get(file_path)
+            .map_or_else(UndoRedoResult::default, |journal| journal.result(Some(operation)));
+        Ok(UndoRedoResult { operation: Some(operation), file_paths, ..result })
+    }
+}
+
+/// Writes back the contents of each file from before a failed write which
+/// changed it.
+fn restore_snapshots(config: &TvConfig, snapshots: &[(&str, Option<String>)]) {
+    for (file_path, snapshot) in snapshots {
+        let Some(snapshot) = snapshot else {
+            continue;
+        };
+        let current = config.fs().read_to_string(Path::new(file_path)).ok();
+        if current.as_ref() == Some(snapshot) {
+            continue;
+        }
+        match config
+            .fs()
+            .write_atomic(Path::new(file_path), snapshot)
+            .map_err(|e| map_atomic_write_error(e, file_path))
+        {
+            Ok(()) => tracing::info!(
+                component = "tv.toml.journal",
+                file_path = %file_path,
+                "Restored file after failed operation"
+            ),
+            Err(e) => tracing::error!(
+                component = "tv.toml.journal",
+                file_path = %file_path,
+                error = %e,
+                "Failed to restore file after failed operation"
+            ),
+        }
+    }
+}
+
+/// Returns the file's contents with each table replaced by its contents from
+/// `target`, provided every table currently matches its contents in
+/// `expected`.
+fn restored_document(
+    config: &TvConfig,
+    file_path: &str,
+    table_names: &[String],
+    expected: &str,
+    target: &str,
+) -> Result<String, TvError> {
+    let content = config
+        .fs()
+        .read_to_string(Path::new(file_path))
+        .map_err(|e| map_io_error_for_read(&e, file_path))?;
+    let mut doc = parse_document(&content, file_path)?;
+    let expected_doc = parse_document(expected, file_path)?;
+    let target_doc = parse_document(target, file_path)?;
+
+    for table_name in table_names {
+        let key = table_key::resolve_key_name(&expected_doc, table_name, file_path, "Undo failed")?;
+        let current_table = doc.get(&key).map(|item| item.to_string());
+        let expected_table = expected_doc.get(&key).map(|item| item.to_string());
+        if current_table != expected_table {
+            return Err(TvError::JournalConflict { path: file_path.to_string() });
+        }
+
+        match target_doc.get(&key) {
+            Some(item) => doc[&key] = item.clone(),
+            None => {
+                doc.remove(&key);
+            }
+        }
+    }
+    Ok(doc.to_string())
+}
+
+fn parse_document(content: &str, file_path: &str) -> Result<toml_edit::DocumentMut, TvError> {
+    content.parse().map_err(|e: toml_edit::TomlError| TvError::TomlParseError {
+        path: file_path.to_string(),
+        line: None,
+        message: e.to_string(),
+    })
+}
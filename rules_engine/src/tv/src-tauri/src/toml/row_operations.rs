@@ -5,8 +5,9 @@ use crate::error::error_types::{map_io_error_for_read, TvError};
 use crate::toml::cell_writer::map_atomic_write_error;
 use crate::toml::table_key;
 use crate::toml::value_converter;
-use crate::toml::writer_types::{AddRowResult, DeleteRowResult};
+use crate::toml::writer_types::{AddRowResult, DeleteRowResult, DuplicateRowResult, MoveRowResult};
 use crate::traits::TvConfig;
+use crate::uuid::uuid_generator;
 
 /// Adds a new row to the TOML array at the specified position.
 /// If position is None, appends to the end.
@@ -183,3 +184,187 @@ pub fn delete_row(
 
     Ok(DeleteRowResult { success: true, deleted_index: row_index })
 }
+
+/// Inserts a copy of a row directly after it, keeping the row's formatting.
+///
+/// If the row has a string "id" value, the copy is given a new UUID.
+pub fn duplicate_row(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    row_index: usize,
+) -> Result<DuplicateRowResult, TvError> {
+    let start = Instant::now();
+    let mut doc = read_document(config, file_path, "duplicate row")?;
+
+    let key = table_key::resolve_key_name(&doc, table_name, file_path, "Duplicate row failed")?;
+    let array = doc
+        .get_mut(&key)
+        .and_then(|v| v.as_array_of_tables_mut())
+        .ok_or_else(|| TvError::TableNotFound { table_name: table_name.to_string() })?;
+
+    let Some(source) = array.get(row_index) else {
+        tracing::error!(
+            component = "tv.toml",
+            file_path = %file_path,
+            table_name = %table_name,
+            row_index = row_index,
+            array_len = array.len(),
+            error = "Row index out of bounds",
+            "Duplicate row failed"
+        );
+        return Err(TvError::RowNotFound { table_name: table_name.to_string(), row_index });
+    };
+    let mut copy = source.clone();
+    let generated_id = uuid_generator::regenerate_uuid(&mut copy);
+    if has_blank_prefix(copy.decor()) {
+        let separator = array
+            .iter()
+            .skip(1)
+            .map(|t| t.decor())
+            .find(|decor| has_blank_prefix(decor))
+            .and_then(|decor| decor.prefix().cloned())
+            .unwrap_or_else(|| "\n".into());
+        copy.decor_mut().set_prefix(separator);
+    }
+
+    let mut new_array = toml_edit::ArrayOfTables::new();
+    for (i, existing_table) in array.iter().enumerate() {
+        new_array.push(existing_table.clone());
+        if i == row_index {
+            new_array.push(copy.clone());
+        }
+    }
+    doc[&key] = toml_edit::Item::ArrayOfTables(new_array);
+
+    config.fs().write_atomic(Path::new(file_path), &doc.to_string())
+        .map_err(|e| map_atomic_write_error(e, file_path))?;
+
+    let duration_ms = start.elapsed().as_millis() as u64;
+    tracing::debug!(
+        component = "tv.toml",
+        file_path = %file_path,
+        source_index = row_index,
+        row_index = row_index + 1,
+        duration_ms = duration_ms,
+        "Row duplicated"
+    );
+
+    Ok(DuplicateRowResult {
+        success: true,
+        source_index: row_index,
+        row_index: row_index + 1,
+        generated_id,
+    })
+}
+
+/// Moves a row so that it ends up at `to_index`, keeping the row's formatting.
+pub fn move_row(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    from_index: usize,
+    to_index: usize,
+) -> Result<MoveRowResult, TvError> {
+    let start = Instant::now();
+    let mut doc = read_document(config, file_path, "move row")?;
+
+    let key = table_key::resolve_key_name(&doc, table_name, file_path, "Move row failed")?;
+    let array = doc
+        .get_mut(&key)
+        .and_then(|v| v.as_array_of_tables_mut())
+        .ok_or_else(|| TvError::TableNotFound { table_name: table_name.to_string() })?;
+
+    let array_len = array.len();
+    if let Some(row_index) = [from_index, to_index].into_iter().find(|&i| i >= array_len) {
+        tracing::error!(
+            component = "tv.toml",
+            file_path = %file_path,
+            table_name = %table_name,
+            row_index = row_index,
+            array_len = array_len,
+            error = "Row index out of bounds",
+            "Move row failed"
+        );
+        return Err(TvError::RowNotFound { table_name: table_name.to_string(), row_index });
+    }
+
+    // Tables are written in document position order, so each slot keeps its
+    // original position. Comments move with their row, while blank line
+    // separators stay with the slot.
+    let slots = array.iter().map(|t| (t.position(), t.decor().clone())).collect::<Vec<_>>();
+    let mut tables = array.iter().cloned().collect::<Vec<_>>();
+    let moved = tables.remove(from_index);
+    tables.insert(to_index, moved);
+    let mut new_array = toml_edit::ArrayOfTables::new();
+    for (mut table, (position, decor)) in tables.into_iter().zip(slots) {
+        if let Some(position) = position {
+            table.set_position(position);
+        }
+        if has_blank_prefix(table.decor()) && has_blank_prefix(&decor) {
+            *table.decor_mut() = decor;
+        }
+        new_array.push(table);
+    }
+    doc[&key] = toml_edit::Item::ArrayOfTables(new_array);
+
+    config.fs().write_atomic(Path::new(file_path), &doc.to_string())
+        .map_err(|e| map_atomic_write_error(e, file_path))?;
+
+    let duration_ms = start.elapsed().as_millis() as u64;
+    tracing::debug!(
+        component = "tv.toml",
+        file_path = %file_path,
+        from_index = from_index,
+        to_index = to_index,
+        duration_ms = duration_ms,
+        "Row moved"
+    );
+
+    Ok(MoveRowResult { success: true, from_index, to_index })
+}
+
+/// Returns the new index of the row at `index` after the row at `from_index`
+/// is moved to `to_index`.
+pub fn index_after_move(index: usize, from_index: usize, to_index: usize) -> usize {
+    if index == from_index {
+        to_index
+    } else if from_index < index && index <= to_index {
+        index - 1
+    } else if to_index <= index && index < from_index {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// Returns true if a table has only whitespace before its header.
+fn has_blank_prefix(decor: &toml_edit::Decor) -> bool {
+    decor.prefix().and_then(|prefix| prefix.as_str()).unwrap_or_default().trim().is_empty()
+}
+
+fn read_document(
+    config: &TvConfig,
+    file_path: &str,
+    operation: &str,
+) -> Result<toml_edit::DocumentMut, TvError> {
+    let content = config.fs().read_to_string(Path::new(file_path)).map_err(|e| {
+        tracing::error!(
+            component = "tv.toml",
+            file_path = %file_path,
+            error = %e,
+            "Read failed during {operation}"
+        );
+        map_io_error_for_read(&e, file_path)
+    })?;
+
+    content.parse().map_err(|e: toml_edit::TomlError| {
+        tracing::error!(
+            component = "tv.toml",
+            file_path = %file_path,
+            error = %e,
+            "TOML parse failed during {operation}"
+        );
+        TvError::TomlParseError { path: file_path.to_string(), line: None, message: e.to_string() }
+    })
+}
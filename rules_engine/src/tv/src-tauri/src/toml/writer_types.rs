@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::validation::tabula_validation::CardValidationError;
+
 /// Represents a single cell update request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +16,9 @@ pub struct CellUpdate {
 #[serde(rename_all = "camelCase")]
 pub struct SaveTableResult {
     pub uuids_generated: bool,
+    /// Card validation errors which prevented the table from being saved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_errors: Vec<CardValidationError>,
 }
 
 /// Result of a cell save operation.
@@ -60,3 +65,25 @@ pub struct DeleteRowResult {
     pub success: bool,
     pub deleted_index: usize,
 }
+
+/// Result of a row duplicate operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateRowResult {
+    pub success: bool,
+    pub source_index: usize,
+    /// Index of the new row, directly after the source row.
+    pub row_index: usize,
+    /// New id given to the copy, if the source row had one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_id: Option<String>,
+}
+
+/// Result of a row move operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveRowResult {
+    pub success: bool,
+    pub from_index: usize,
+    pub to_index: usize,
+}
@@ -33,6 +33,30 @@ pub fn ensure_uuids(array: &mut toml_edit::ArrayOfTables, headers: &[String]) ->
     generated
 }
 
+/// Replaces a row's "id" value with a new UUID, keeping its formatting.
+///
+/// Used when copying a row, so the copy does not share the original's id.
+/// Returns the new UUID, or None if the row has no string id.
+pub fn regenerate_uuid(table: &mut toml_edit::Table) -> Option<String> {
+    let id_key =
+        table.iter().map(|(key, _)| key.to_string()).find(|key| key.eq_ignore_ascii_case("id"))?;
+    let value = table.get_mut(&id_key)?.as_value_mut()?;
+    if !value.is_str() {
+        return None;
+    }
+
+    let uuid = Uuid::new_v4().to_string();
+    let decor = value.decor().clone();
+    *value = toml_edit::Value::from(uuid.as_str());
+    *value.decor_mut() = decor;
+    tracing::info!(
+        component = "tv.uuid",
+        uuid = %uuid,
+        "Regenerated UUID for copied row"
+    );
+    Some(uuid)
+}
+
 fn find_id_column(headers: &[String]) -> Option<&str> {
     headers.iter().find(|h| h.eq_ignore_ascii_case("id")).map(|s| s.as_str())
 }
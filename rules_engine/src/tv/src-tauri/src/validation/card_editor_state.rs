@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::{AppHandle, Manager};
+
+use crate::error::error_types::TvError;
+use crate::toml::document_writer::{JournalOperation, OperationJournal};
+use crate::traits::TvConfig;
+use crate::validation::tabula_validation;
+
+/// Tracks whether card editor mode is enabled.
+///
+/// In card editor mode, every write to a card table runs Tabula validation on
+/// the rows it adds or changes and is rejected if any row would fail to load
+/// in the game.
+#[derive(Debug, Default)]
+pub struct CardEditorState {
+    enabled: AtomicBool,
+}
+
+impl CardEditorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Returns true if card editor mode is enabled.
+pub fn is_enabled(app_handle: &AppHandle) -> bool {
+    app_handle
+        .try_state::<CardEditorState>()
+        .is_some_and(|state| state.enabled.load(Ordering::Relaxed))
+}
+
+/// Enables or disables card editor mode.
+pub fn set_enabled(app_handle: &AppHandle, enabled: bool) {
+    if let Some(state) = app_handle.try_state::<CardEditorState>() {
+        state.enabled.store(enabled, Ordering::Relaxed);
+    }
+    tracing::info!(
+        component = "tv.validation.card_editor",
+        enabled = enabled,
+        "Card editor mode changed"
+    );
+}
+
+/// Runs a write to a table and records it in the [OperationJournal].
+///
+/// Every command which writes table rows goes through this function, so that
+/// card editor mode validates the write no matter how the rows were edited.
+pub fn record_write<T>(
+    app_handle: &AppHandle,
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    operation: JournalOperation,
+    write: impl FnOnce() -> Result<T, TvError>,
+) -> Result<T, TvError> {
+    record_tables_write(app_handle, config, &[(file_path, table_name)], operation, write)
+}
+
+/// Equivalent to [record_write] for a write to several tables, given as pairs
+/// of file path and table name, which is recorded as a single operation.
+pub fn record_tables_write<T>(
+    app_handle: &AppHandle,
+    config: &TvConfig,
+    tables: &[(&str, &str)],
+    operation: JournalOperation,
+    write: impl FnOnce() -> Result<T, TvError>,
+) -> Result<T, TvError> {
+    let validate = is_enabled(app_handle);
+    app_handle.state::<OperationJournal>().record_tables(config, tables, operation, || {
+        if validate {
+            tabula_validation::validated_write_tables(config, tables, write)
+        } else {
+            write()
+        }
+    })
+}
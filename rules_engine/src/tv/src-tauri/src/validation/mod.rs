@@ -1,2 +1,4 @@
+pub mod card_editor_state;
+pub mod tabula_validation;
 pub mod validation_rules;
 pub mod validators;
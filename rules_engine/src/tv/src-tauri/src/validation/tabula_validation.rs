@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use parser::error::parser_errors::ParserError;
+use parser::rules_text_parser::{self, AbilityDiagnostic};
+use parser::variables::parser_bindings::VariableBindings;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tabula_data::card_definition_builder;
+use tabula_data::card_definition_raw::CardDefinitionRaw;
+use tabula_data::tabula_error::TabulaError;
+use uuid::Uuid;
+
+use crate::error::error_types::{map_io_error_for_read, TvError};
+use crate::toml::cell_writer::map_atomic_write_error;
+use crate::toml::document_loader::{self, TomlTableData};
+use crate::traits::TvConfig;
+
+/// The kind of card stored in a Tabula table, which determines the fields
+/// its rows must provide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardTableKind {
+    Card,
+    Dreamwell,
+}
+
+/// An error which prevents a card row from being saved in card editor mode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardValidationError {
+    /// Index of the row containing the error.
+    pub row: usize,
+    /// Column containing the error, or None if the error applies to the row
+    /// as a whole.
+    pub column: Option<String>,
+    pub message: String,
+}
+
+/// Returns the kind of card stored in the table named `table_name`, or None
+/// if the table does not contain card definitions.
+pub fn card_table_kind(table_name: &str) -> Option<CardTableKind> {
+    match table_name {
+        "cards" | "test-cards" => Some(CardTableKind::Card),
+        "dreamwell" | "test-dreamwell" => Some(CardTableKind::Dreamwell),
+        _ => None,
+    }
+}
+
+/// Runs a write to a table, rejecting it if any card row the write adds or
+/// changes fails validation.
+///
+/// Rejected writes are undone by restoring the file's previous contents, and
+/// fail with [TvError::CardValidationFailed]. Writes to tables which do not
+/// contain card definitions are not validated.
+pub fn validated_write<T>(
+    config: &TvConfig,
+    file_path: &str,
+    table_name: &str,
+    write: impl FnOnce() -> Result<T, TvError>,
+) -> Result<T, TvError> {
+    validated_write_tables(config, &[(file_path, table_name)], write)
+}
+
+/// Equivalent to [validated_write] for a write to several tables, given as
+/// pairs of file path and table name.
+///
+/// If any card table fails validation, every file containing a card table is
+/// restored.
+pub fn validated_write_tables<T>(
+    config: &TvConfig,
+    tables: &[(&str, &str)],
+    write: impl FnOnce() -> Result<T, TvError>,
+) -> Result<T, TvError> {
+    let card_tables = tables
+        .iter()
+        .copied()
+        .filter(|(_, table_name)| card_table_kind(table_name).is_some())
+        .collect::<Vec<_>>();
+    if card_tables.is_empty() {
+        return write();
+    }
+
+    let mut originals: Vec<(&str, String)> = Vec::new();
+    for &(file_path, _) in &card_tables {
+        if originals.iter().all(|(path, _)| *path != file_path) {
+            let original = config
+                .fs()
+                .read_to_string(Path::new(file_path))
+                .map_err(|e| map_io_error_for_read(&e, file_path))?;
+            originals.push((file_path, original));
+        }
+    }
+    let previous = card_tables
+        .iter()
+        .map(|(file_path, table_name)| {
+            document_loader::load_toml_document(config, file_path, table_name)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let result = write()?;
+
+    for ((file_path, table_name), previous) in card_tables.iter().zip(&previous) {
+        let data = document_loader::load_toml_document(config, file_path, table_name)?;
+        let errors = validate_changed_rows(table_name, previous, &data);
+        if errors.is_empty() {
+            continue;
+        }
+        tracing::warn!(
+            component = "tv.validation.tabula",
+            file_path = %file_path,
+            table_name = %table_name,
+            error_count = errors.len(),
+            "Write rejected by card validation"
+        );
+        for (path, original) in &originals {
+            config
+                .fs()
+                .write_atomic(Path::new(path), original)
+                .map_err(|e| map_atomic_write_error(e, path))?;
+        }
+        return Err(TvError::CardValidationFailed {
+            path: file_path.to_string(),
+            table_name: table_name.to_string(),
+            errors,
+        });
+    }
+    Ok(result)
+}
+
+/// Runs Tabula validation on each row of `data` which does not appear in
+/// `previous`.
+///
+/// Only edited rows are validated so that existing problems elsewhere in the
+/// file do not block unrelated edits. Rows which were only moved are not
+/// validated again, but each row in `previous` can only account for a single
+/// identical row in `data`, so copies of an existing row are validated.
+pub fn validate_changed_rows(
+    table_name: &str,
+    previous: &TomlTableData,
+    data: &TomlTableData,
+) -> Vec<CardValidationError> {
+    let Some(kind) = card_table_kind(table_name) else {
+        return Vec::new();
+    };
+    let mut unmatched = row_maps(previous);
+    let rows = row_maps(data);
+    let changed = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| match unmatched.iter().position(|previous| previous == *row) {
+            Some(position) => {
+                unmatched.swap_remove(position);
+                false
+            }
+            None => true,
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    changed.into_iter().flat_map(|index| validate_card_row(kind, &rows, index)).collect()
+}
+
+/// Validates the row at `index` of `rows` the same way Tabula does when
+/// loading card data.
+///
+/// Checks that the row builds into a card definition, that its rules text
+/// parses with its variables, and that its id is not used by another row.
+/// Blank rows are not validated, and a missing id is allowed because one is
+/// generated when the row is saved.
+pub fn validate_card_row(
+    kind: CardTableKind,
+    rows: &[Map<String, Value>],
+    index: usize,
+) -> Vec<CardValidationError> {
+    let row = &rows[index];
+    if row.values().all(is_blank) {
+        return Vec::new();
+    }
+
+    let mut errors = Vec::new();
+    let mut error = |column: Option<&str>, message: String| {
+        errors.push(CardValidationError { row: index, column: column.map(String::from), message });
+    };
+
+    let mut fields = row.clone();
+    fields.retain(|key, value| !value.is_null() && !(key == "id" && is_blank(value)));
+    match serde_json::from_value::<CardDefinitionRaw>(Value::Object(fields)) {
+        Ok(mut raw) => {
+            raw.id.get_or_insert_with(Uuid::nil);
+            let file = Path::new(row_file_name(kind));
+            let built = match kind {
+                CardTableKind::Card => {
+                    card_definition_builder::build_card(&raw, Vec::new(), file).map(|_| ())
+                }
+                CardTableKind::Dreamwell => {
+                    card_definition_builder::build_dreamwell(&raw, Vec::new(), file).map(|_| ())
+                }
+            };
+            if let Err(e) = built {
+                let (column, message) = describe_tabula_error(&e);
+                error(column, message);
+            }
+        }
+        Err(e) => error(None, format!("Invalid card definition: {e}")),
+    }
+
+    let rules_text = row.get("rules-text").and_then(Value::as_str).unwrap_or_default();
+    if !rules_text.trim().is_empty() {
+        let variables = row.get("variables").and_then(Value::as_str).unwrap_or_default();
+        match VariableBindings::parse(variables) {
+            Ok(bindings) => {
+                let parsed = rules_text_parser::parse_rules_text(rules_text, &bindings);
+                for diagnostic in &parsed.diagnostics {
+                    error(Some("rules-text"), describe_diagnostic(diagnostic));
+                }
+            }
+            Err(e) => error(Some("variables"), e.to_string()),
+        }
+    }
+
+    if let Some(id) = row.get("id").and_then(Value::as_str).filter(|id| !id.trim().is_empty()) {
+        let duplicate = rows.iter().enumerate().find(|(other, other_row)| {
+            *other != index && other_row.get("id").and_then(Value::as_str) == Some(id)
+        });
+        if let Some((_, other_row)) = duplicate {
+            let name = other_row.get("name").and_then(Value::as_str).unwrap_or("another card");
+            error(Some("id"), format!("Id {id} is already used by '{name}'"));
+        }
+    }
+
+    errors
+}
+
+fn row_maps(data: &TomlTableData) -> Vec<Map<String, Value>> {
+    data.rows
+        .iter()
+        .map(|row| data.headers.iter().cloned().zip(row.iter().cloned()).collect())
+        .collect()
+}
+
+fn row_file_name(kind: CardTableKind) -> &'static str {
+    match kind {
+        CardTableKind::Card => "cards.toml",
+        CardTableKind::Dreamwell => "dreamwell.toml",
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn describe_tabula_error(error: &TabulaError) -> (Option<&str>, String) {
+    match error {
+        TabulaError::MissingField { field, .. } => {
+            (Some(*field), format!("Missing required field '{field}'"))
+        }
+        TabulaError::InvalidField { field, message, .. } => (Some(*field), message.clone()),
+        TabulaError::UnexpectedField { field, .. } => {
+            (Some(field.as_str()), format!("Unexpected field '{field}'"))
+        }
+        TabulaError::AbilityParse { message, .. } => (Some("rules-text"), message.clone()),
+        TabulaError::TomlParse { message, .. } => (None, message.clone()),
+    }
+}
+
+fn describe_diagnostic(diagnostic: &AbilityDiagnostic) -> String {
+    let ability = diagnostic.index + 1;
+    match &diagnostic.error {
+        ParserError::Parse(parse) => {
+            let location = match diagnostic.text.get(parse.span.start..parse.span.end) {
+                Some(found) if !found.is_empty() => format!("at '{found}'"),
+                _ => "at end of text".to_string(),
+            };
+            if parse.expected.is_empty() {
+                format!("Ability {ability}: unexpected text {location}")
+            } else {
+                format!("Ability {ability}: expected {} {location}", parse.expected.join(", "))
+            }
+        }
+        other => format!("Ability {ability}: {other}"),
+    }
+}
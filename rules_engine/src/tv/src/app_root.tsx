@@ -8,6 +8,7 @@ import * as ipc from "./ipc_bridge";
 import type { TomlTableData, DerivedValuePayload, RowConfig, ColumnConfig, PermissionState, PermissionStateChangedPayload, StatisticConfig, EnumValidationInfo } from "./ipc_bridge";
 import type { MultiSheetData, SheetData, DerivedColumnState } from "./spreadsheet_types";
 import { createLogger } from "./logger_frontend";
+import { mergeDerivedColumns } from "./derived_column_utils";
 
 export type { TomlTableData };
 
@@ -453,6 +454,24 @@ export function AppRoot() {
         const saveResult = await ipc.saveTomlTable(sheetInfo.path, sheetInfo.tableName, newData);
         saveTimer.stop({ uuidsGenerated: saveResult.uuidsGenerated });
 
+        // In card editor mode the backend rejects saves which would produce
+        // invalid card data. Leave the last known data unchanged so the next
+        // edit retries the save.
+        if (saveResult.validationErrors && saveResult.validationErrors.length > 0) {
+          const messages = saveResult.validationErrors.map((e) =>
+            e.column
+              ? `Row ${e.row + 1} (${e.column}): ${e.message}`
+              : `Row ${e.row + 1}: ${e.message}`
+          );
+          logger.warn("Save blocked by card validation", {
+            sheetId,
+            errorCount: messages.length,
+          });
+          setError(`Save blocked by card validation: ${messages.join("; ")}`);
+          totalTimer.stop({ blockedByValidation: true });
+          return;
+        }
+
         // Record save time so file watcher can suppress self-triggered reloads
         lastSaveTimeRef.current[sheetId] = Date.now();
 
@@ -880,6 +899,112 @@ export function AppRoot() {
     };
   }, [sheets]);
 
+  useEffect(() => {
+    const exportSub = ipc.onExportReportRequested(() => {
+      const sheetId = activeSheetIdRef.current;
+      const sheetInfo = sheetId ? sheets.find((s) => s.id === sheetId) : undefined;
+      const sheetData = sheetId ? lastKnownDataRef.current[sheetId] : undefined;
+      if (!sheetId || !sheetInfo || !sheetData) {
+        logger.warn("No active sheet to export");
+        return;
+      }
+      const report = mergeDerivedColumns(
+        sheetData,
+        derivedColumnState.configs[sheetId],
+        derivedColumnState.values[sheetId],
+      );
+      ipc.exportReport(sheetInfo.path, sheetInfo.tableName, report.headers, report.rows)
+        .then((outputPath) => logger.info("Report exported", { outputPath }))
+        .catch((e) => {
+          logger.error("Failed to export report", { error: String(e) });
+          setError(`Failed to export report: ${String(e)}`);
+        });
+    });
+    return () => {
+      exportSub.dispose();
+    };
+  }, [sheets, derivedColumnState]);
+
+  useEffect(() => {
+    // Ctrl+Z / Ctrl+Shift+Z (Cmd on macOS) undo and redo writes to the active
+    // sheet's file. The event is captured before Univer sees it so that its
+    // in-memory undo stack never diverges from the file on disk. Keys pressed
+    // while editing a cell are left to the cell editor.
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (!(event.ctrlKey || event.metaKey) || event.altKey) return;
+      const key = event.key.toLowerCase();
+      const isUndo = key === "z" && !event.shiftKey;
+      const isRedo = (key === "z" && event.shiftKey) || key === "y";
+      if (!isUndo && !isRedo) return;
+
+      const target = event.target;
+      if (
+        target instanceof HTMLInputElement ||
+        target instanceof HTMLTextAreaElement ||
+        (target instanceof HTMLElement && target.isContentEditable)
+      ) {
+        return;
+      }
+
+      const sheetId = activeSheetIdRef.current;
+      const sheetInfo = sheetId ? sheets.find((s) => s.id === sheetId) : undefined;
+      if (!sheetId || !sheetInfo) return;
+      event.preventDefault();
+      event.stopPropagation();
+
+      // An edit still waiting for its debounced save has not reached the
+      // file yet, so undoing it only requires reloading the sheet.
+      if (isUndo && saveTimeoutRef.current) {
+        clearTimeout(saveTimeoutRef.current);
+        saveTimeoutRef.current = null;
+        logger.info("Discarding unsaved edit", { sheetId });
+        void reloadSheet(sheetId);
+        return;
+      }
+
+      if (isSavingRef.current[sheetId]) {
+        logger.debug("Ignoring undo/redo during save", { sheetId });
+        return;
+      }
+
+      const action = isUndo ? "undo" : "redo";
+      const request = isUndo ? ipc.undo(sheetInfo.path) : ipc.redo(sheetInfo.path);
+      request
+        .then(async (result) => {
+          if (!result.operation) {
+            logger.debug(`Nothing to ${action}`, { sheetId });
+            return;
+          }
+          // An operation such as a replace across tables can change other
+          // files too, so every sheet backed by a changed file is reloaded.
+          const changedSheetIds = sheets
+            .filter((s) => s.id === sheetId || result.filePaths.includes(s.path))
+            .map((s) => s.id);
+          logger.info(`Applied ${action}`, {
+            sheetId,
+            operation: result.operation,
+            undoCount: result.undoCount,
+            redoCount: result.redoCount,
+            changedSheetIds,
+          });
+          for (const changedSheetId of changedSheetIds) {
+            // Record the write so the file watcher event it triggers is ignored
+            lastSaveTimeRef.current[changedSheetId] = Date.now();
+          }
+          await Promise.all(changedSheetIds.map((id) => reloadSheet(id)));
+        })
+        .catch((e) => {
+          logger.error(`Failed to ${action}`, { sheetId, error: String(e) });
+          setError(`Failed to ${action}: ${String(e)}`);
+        });
+    };
+
+    window.addEventListener("keydown", handleKeyDown, true);
+    return () => {
+      window.removeEventListener("keydown", handleKeyDown, true);
+    };
+  }, [sheets, reloadSheet]);
+
   useEffect(() => {
     return () => {
       if (saveTimeoutRef.current) {
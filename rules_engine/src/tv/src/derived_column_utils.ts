@@ -6,6 +6,7 @@ import type {
   DerivedResultValue,
   TextRun,
   TextStyle,
+  TomlTableData,
 } from "./ipc_bridge";
 import { derivedResultToCellData } from "./rich_text_utils";
 import { createLogger } from "./logger_frontend";
@@ -168,6 +169,66 @@ export function applyDerivedResultToCell(
   }
 }
 
+/**
+ * Merges computed derived column values into table data at their visual
+ * column positions, producing plain cell values for exporting a report.
+ * Rows keep their TOML order, rows without a computed value are left empty.
+ */
+export function mergeDerivedColumns(
+  data: TomlTableData,
+  configs: DerivedColumnInfo[] | undefined,
+  values: Record<number, Record<string, DerivedResultValue>> | undefined,
+): TomlTableData {
+  if (!configs || configs.length === 0) return data;
+
+  const mapping = buildColumnMapping(configs, data.headers.length);
+  const derivedAt = new Map<number, DerivedColumnInfo>();
+  for (const config of configs) {
+    derivedAt.set(getDerivedColumnIndex(config, configs, mapping), config);
+  }
+  const columnCount = Math.max(
+    mapping.totalVisualColumns,
+    ...Array.from(derivedAt.keys()).map((col) => col + 1),
+  );
+
+  const headers: string[] = [];
+  for (let col = 0; col < columnCount; col++) {
+    const dataIdx = mapping.visualToData.get(col);
+    headers.push(dataIdx !== undefined ? data.headers[dataIdx] : (derivedAt.get(col)?.name ?? ""));
+  }
+
+  const rows = data.rows.map((row, rowIdx) => {
+    const merged: unknown[] = [];
+    for (let col = 0; col < columnCount; col++) {
+      const dataIdx = mapping.visualToData.get(col);
+      if (dataIdx !== undefined) {
+        merged.push(row[dataIdx] ?? null);
+        continue;
+      }
+      const config = derivedAt.get(col);
+      const result = config ? values?.[rowIdx]?.[config.function] : undefined;
+      merged.push(result ? derivedResultToPlainValue(result) : null);
+    }
+    return merged;
+  });
+
+  return { headers, rows };
+}
+
+function derivedResultToPlainValue(result: DerivedResultValue): unknown {
+  switch (result.type) {
+    case "text":
+    case "number":
+    case "boolean":
+    case "image":
+      return result.value;
+    case "richText":
+      return result.value.p.map((p) => p.ts.map((run) => run.t).join("")).join("\n");
+    case "error":
+      return `Error: ${result.value}`;
+  }
+}
+
 function toUniverTextStyle(style: TextStyle): ITextStyle | undefined {
   if (!style.bl && !style.it && !style.ul && !style.cl) return undefined;
   const result: ITextStyle = {};
@@ -8,6 +8,8 @@ import { listen, UnlistenFn } from "@tauri-apps/api/event";
 export interface TomlTableData {
   headers: string[];
   rows: (string | number | boolean | null)[][];
+  /** Comment threads on the table's cells, present in load responses. */
+  comments?: CellCommentThread[];
 }
 
 // ============ Metadata Schema Types ============
@@ -42,6 +44,8 @@ export interface Metadata {
   rows?: RowConfig;
   /** Application settings stored with the file. */
   app_settings?: AppSettings;
+  /** Review comment threads attached to individual cells. */
+  comments?: CommentThread[];
 }
 
 /**
@@ -275,6 +279,33 @@ export interface ScrollPosition {
   column: number;
 }
 
+/**
+ * A thread of review comments attached to one cell. The row is identified by
+ * its "id" value so threads follow their row when rows are reordered.
+ */
+export interface CommentThread {
+  /** Unique identifier for the thread. */
+  id: string;
+  /** Value of the "id" column of the row this thread is attached to. */
+  row_key: string;
+  /** Column key this thread is attached to. */
+  column: string;
+  /** Whether the thread has been resolved. Defaults to false. */
+  resolved?: boolean;
+  /** Comments in the order they were added. */
+  messages: CommentMessage[];
+}
+
+/**
+ * A single comment within a comment thread.
+ */
+export interface CommentMessage {
+  author: string;
+  text: string;
+  /** Time the comment was added, in RFC 3339 format. */
+  created_at: string;
+}
+
 // Legacy interface for backwards compatibility
 /** @deprecated Use Metadata instead */
 export interface TomlMetadata {
@@ -443,8 +474,15 @@ export async function loadTomlTable(
   return invoke<TomlTableData>("load_toml_table", { filePath, tableName });
 }
 
+export interface CardValidationError {
+  row: number;
+  column: string | null;
+  message: string;
+}
+
 export interface SaveTableResult {
   uuidsGenerated: boolean;
+  validationErrors?: CardValidationError[];
 }
 
 export async function saveTomlTable(
@@ -519,6 +557,180 @@ export async function deleteRow(
   });
 }
 
+export interface DuplicateRowResult {
+  success: boolean;
+  sourceIndex: number;
+  rowIndex: number;
+  generatedId?: string;
+}
+
+export async function duplicateRow(
+  filePath: string,
+  tableName: string,
+  rowIndex: number,
+): Promise<DuplicateRowResult> {
+  return invoke<DuplicateRowResult>("duplicate_row", {
+    filePath,
+    tableName,
+    rowIndex,
+  });
+}
+
+export interface MoveRowResult {
+  success: boolean;
+  fromIndex: number;
+  toIndex: number;
+}
+
+export async function moveRow(
+  filePath: string,
+  tableName: string,
+  fromIndex: number,
+  toIndex: number,
+): Promise<MoveRowResult> {
+  return invoke<MoveRowResult>("move_row", {
+    filePath,
+    tableName,
+    fromIndex,
+    toIndex,
+  });
+}
+
+export type JournalOperation =
+  | "saveTable"
+  | "saveCell"
+  | "saveBatch"
+  | "addRow"
+  | "deleteRow"
+  | "duplicateRow"
+  | "moveRow"
+  | "importCsv"
+  | "replace";
+
+export interface UndoRedoResult {
+  operation: JournalOperation | null;
+  undoCount: number;
+  redoCount: number;
+  /** Every file changed by the undo or redo. */
+  filePaths: string[];
+}
+
+export async function undo(filePath: string): Promise<UndoRedoResult> {
+  return invoke<UndoRedoResult>("undo", { filePath });
+}
+
+export async function redo(filePath: string): Promise<UndoRedoResult> {
+  return invoke<UndoRedoResult>("redo", { filePath });
+}
+
+export interface CsvImportOptions {
+  columnMapping?: Record<string, string>;
+  dryRun?: boolean;
+}
+
+export interface CsvColumnMapping {
+  csvColumn: string;
+  tableColumn: string | null;
+}
+
+export interface TypeCoercion {
+  row: number;
+  column: string;
+  original: string;
+  value: unknown;
+}
+
+export interface ImportFailure {
+  row: number;
+  column: string;
+  value: string;
+  reason: string;
+}
+
+export interface CsvImportResult {
+  dryRun: boolean;
+  columnMapping: CsvColumnMapping[];
+  rowCount: number;
+  importableCount: number;
+  importedCount: number;
+  coercions: TypeCoercion[];
+  failures: ImportFailure[];
+}
+
+export async function importCsv(
+  filePath: string,
+  tableName: string,
+  csvPath: string,
+  options: CsvImportOptions,
+): Promise<CsvImportResult> {
+  return invoke<CsvImportResult>("import_csv", {
+    filePath,
+    tableName,
+    csvPath,
+    options,
+  });
+}
+
+export interface TableRef {
+  filePath: string;
+  tableName: string;
+}
+
+export interface FindOptions {
+  pattern: string;
+  regex?: boolean;
+  caseSensitive?: boolean;
+  columns?: string[];
+}
+
+export interface FindHit {
+  filePath: string;
+  tableName: string;
+  rowIndex: number;
+  columnKey: string;
+  value: unknown;
+  matchCount: number;
+}
+
+export interface FailedTable {
+  filePath: string;
+  tableName: string;
+  reason: string;
+}
+
+export interface FindResult {
+  hits: FindHit[];
+  truncated: boolean;
+  failedTables?: FailedTable[];
+}
+
+export interface ReplaceResult {
+  filePath: string;
+  tableName: string;
+  replacedCount: number;
+  skippedCount: number;
+  failedUpdates?: FailedUpdate[];
+}
+
+export async function findInTables(
+  tables: TableRef[],
+  options: FindOptions,
+): Promise<FindResult> {
+  return invoke<FindResult>("find_in_tables", { tables, options });
+}
+
+export async function replaceInTables(
+  tables: TableRef[],
+  options: FindOptions,
+  replacement: string,
+): Promise<ReplaceResult[]> {
+  return invoke<ReplaceResult[]>("replace_in_tables", {
+    tables,
+    options,
+    replacement,
+  });
+}
+
 export async function startFileWatcher(filePath: string): Promise<void> {
   return invoke("start_file_watcher", { filePath });
 }
@@ -798,6 +1010,45 @@ export async function getStatisticsConfig(
   return invoke<StatisticConfig[]>("get_statistics_config", { filePath });
 }
 
+// ============ Comment Commands ============
+
+export interface CellCommentThread extends CommentThread {
+  rowIndex: number;
+}
+
+export interface NewComment {
+  rowIndex: number;
+  column: string;
+  author: string;
+  text: string;
+  /** Thread to reply to. Starts a new thread on the cell if omitted. */
+  threadId?: string;
+}
+
+export async function addComment(
+  filePath: string,
+  tableName: string,
+  comment: NewComment,
+): Promise<CommentThread> {
+  return invoke<CommentThread>("add_comment", {
+    filePath,
+    tableName,
+    comment,
+  });
+}
+
+export async function resolveCommentThread(
+  filePath: string,
+  threadId: string,
+  resolved: boolean,
+): Promise<CommentThread> {
+  return invoke<CommentThread>("resolve_comment_thread", {
+    filePath,
+    threadId,
+    resolved,
+  });
+}
+
 // ============ Validation Commands ============
 
 export interface EnumValidationInfo {
@@ -862,6 +1113,22 @@ export async function getConditionalFormatting(
   });
 }
 
+// ============ Report Commands ============
+
+export async function exportReport(
+  filePath: string,
+  tableName: string,
+  headers: string[],
+  rows: unknown[][],
+): Promise<string> {
+  return invoke<string>("export_report", {
+    filePath,
+    tableName,
+    headers,
+    rows,
+  });
+}
+
 // ============ Logging Commands ============
 
 export interface FrontendLogMessage {
@@ -888,6 +1155,31 @@ export async function logPerf(entry: FrontendPerfLogEntry): Promise<void> {
   return invoke("log_perf", { entry });
 }
 
+// ============ Performance Commands ============
+
+export interface CommandLatencySummary {
+  command: string;
+  count: number;
+  p50_ms: number;
+  p95_ms: number;
+  max_ms: number;
+}
+
+export interface CommandTiming {
+  command: string;
+  ts: string;
+  duration_ms: number;
+}
+
+export interface PerformanceReport {
+  commands: CommandLatencySummary[];
+  slowest_recent: CommandTiming[];
+}
+
+export async function getPerformanceReport(): Promise<PerformanceReport> {
+  return invoke<PerformanceReport>("get_performance_report");
+}
+
 // ============ View State Commands ============
 
 export interface ViewState {
@@ -1038,3 +1330,6 @@ export const onOpenFindDialog =
 
 export const onStatisticsOverlayToggled =
   createVoidEventListener("statistics-overlay-toggled");
+
+export const onExportReportRequested =
+  createVoidEventListener("export-report-requested");
@@ -0,0 +1,137 @@
+use ai_uct::action_pruning::{self, PruningRule};
+use battle_mutations::actions::{apply_battle_action, apply_debug_battle_action};
+use battle_mutations::card_mutations::move_card;
+use battle_mutations::effects::discard_cards;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::HandCardId;
+use battle_state::core::effect_source::EffectSource;
+use core_data::identifiers::BaseCardId;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+use enumset::EnumSet;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle_state;
+
+const PLAYER: PlayerName = PlayerName::One;
+
+#[test]
+fn duplicate_hand_cards_are_pruned_to_one_play() {
+    let battle = battle_with_hand(&[
+        test_card::TEST_VANILLA_CHARACTER,
+        test_card::TEST_VANILLA_CHARACTER,
+        test_card::TEST_DRAW_ONE,
+    ]);
+    let actions = legal_actions::compute(&battle, PLAYER).all();
+
+    let pruned = action_pruning::prune(
+        &battle,
+        actions.clone(),
+        EnumSet::only(PruningRule::DuplicateHandCard),
+    );
+    assert_eq!(pruned.len(), actions.len() - 1, "one copy of the duplicate card should be pruned");
+    assert_eq!(
+        action_pruning::prune(&battle, actions.clone(), EnumSet::empty()),
+        actions,
+        "no actions should be pruned with every rule disabled"
+    );
+}
+
+#[test]
+fn discarding_card_returned_from_void_is_pruned() {
+    let mut battle = battle_with_hand(&[test_card::TEST_DRAW_ONE]);
+    let returned = return_from_void(&mut battle, test_card::TEST_VANILLA_CHARACTER);
+    let kept = only_hand_card_other_than(&battle, returned);
+    discard_cards::execute(&mut battle, EffectSource::Game { controller: PLAYER }, PLAYER, 1);
+    let actions = legal_actions::compute(&battle, PLAYER).all();
+
+    assert_eq!(
+        action_pruning::prune(
+            &battle,
+            actions.clone(),
+            EnumSet::only(PruningRule::DiscardReturnedCard)
+        ),
+        vec![BattleAction::SelectHandCardTarget(kept)],
+        "the card returned to hand this turn should not be considered for discard"
+    );
+    assert_eq!(
+        action_pruning::prune(&battle, actions.clone(), EnumSet::empty()),
+        actions,
+        "every card may be discarded with the rule disabled"
+    );
+}
+
+#[test]
+fn discarding_card_returned_from_void_is_kept_without_alternative() {
+    let mut battle = battle_with_hand(&[test_card::TEST_DRAW_ONE]);
+    let first = return_from_void(&mut battle, test_card::TEST_VANILLA_CHARACTER);
+    let second = return_from_void(&mut battle, test_card::TEST_VANILLA_CHARACTER);
+    let other = hand_cards(&battle)
+        .into_iter()
+        .find(|&id| id != first && id != second)
+        .expect("hand should contain a card which was not returned");
+    discard_cards::execute(&mut battle, EffectSource::Game { controller: PLAYER }, PLAYER, 2);
+    apply_battle_action::execute(&mut battle, PLAYER, BattleAction::SelectHandCardTarget(other));
+    let actions = legal_actions::compute(&battle, PLAYER).all();
+
+    assert_eq!(
+        action_pruning::prune(
+            &battle,
+            actions.clone(),
+            EnumSet::only(PruningRule::DiscardReturnedCard)
+        ),
+        actions,
+        "returned cards must still be discarded once no other card can be"
+    );
+}
+
+/// Returns a battle in which player one's hand contains exactly `cards`.
+fn battle_with_hand(cards: &[BaseCardId]) -> BattleState {
+    let mut battle = test_battle_state::create(314159);
+    apply_debug_battle_action::execute(
+        &mut battle,
+        PLAYER,
+        DebugBattleAction::MoveHandToDeck { player: PLAYER },
+    );
+    apply_debug_battle_action::execute(
+        &mut battle,
+        PLAYER,
+        DebugBattleAction::SetEnergy { player: PLAYER, energy: Energy(99) },
+    );
+    for &card in cards {
+        apply_debug_battle_action::execute(
+            &mut battle,
+            PLAYER,
+            DebugBattleAction::AddCardToHand { player: PLAYER, card },
+        );
+    }
+    battle
+}
+
+/// Adds `card` to player one's void and returns it to their hand.
+fn return_from_void(battle: &mut BattleState, card: BaseCardId) -> HandCardId {
+    apply_debug_battle_action::execute(
+        battle,
+        PLAYER,
+        DebugBattleAction::AddCardToVoid { player: PLAYER, card },
+    );
+    let void_card = battle.cards.void(PLAYER).iter().last().expect("card should be in void");
+    move_card::from_void_to_hand(
+        battle,
+        EffectSource::Game { controller: PLAYER },
+        PLAYER,
+        void_card,
+    )
+}
+
+fn hand_cards(battle: &BattleState) -> Vec<HandCardId> {
+    battle.cards.hand(PLAYER).iter().collect()
+}
+
+fn only_hand_card_other_than(battle: &BattleState, returned: HandCardId) -> HandCardId {
+    let others = hand_cards(battle).into_iter().filter(|&id| id != returned).collect::<Vec<_>>();
+    assert_eq!(others.len(), 1, "hand should contain one other card");
+    others[0]
+}
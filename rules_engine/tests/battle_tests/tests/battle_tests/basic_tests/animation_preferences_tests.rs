@@ -0,0 +1,95 @@
+use battle_state::actions::battle_actions::BattleAction;
+use core_data::numerics::Spark;
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::Command;
+use display_data::request_data::AnimationPreferences;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::battle::test_player::TestPlayer;
+use test_utils::session::test_session::TestSession;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn default_preferences_pause_after_enemy_plays_card() {
+    let mut s = TestBattle::builder().connect();
+    s.end_turn_remove_opponent_hand(DisplayPlayer::User);
+    s.create_and_play(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(waits(&s).contains(&1000), "user should see a pause after the enemy plays a card");
+}
+
+#[test]
+fn speed_multiplier_shortens_waits() {
+    let mut s = TestBattle::builder()
+        .animation_preferences(AnimationPreferences {
+            speed_multiplier: 2.0,
+            ..AnimationPreferences::default()
+        })
+        .connect();
+    s.end_turn_remove_opponent_hand(DisplayPlayer::User);
+    s.create_and_play(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+
+    let waits = waits(&s);
+    assert!(waits.contains(&500), "pause should be played at double speed");
+    assert!(!waits.contains(&1000), "pause should not use the standard duration");
+}
+
+#[test]
+fn skip_non_essential_effects_omits_display_effects() {
+    let mut s = TestBattle::builder()
+        .user(TestPlayer::builder().energy(99).build())
+        .animation_preferences(AnimationPreferences {
+            skip_non_essential_effects: true,
+            ..AnimationPreferences::default()
+        })
+        .connect();
+    let trigger_character_id = s.create_and_play(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(
+        s.find_all_commands(DisplayPlayer::User, |command| match command {
+            Command::DisplayEffect(effect) => Some(effect),
+            _ => None,
+        })
+        .is_empty(),
+        "decorative effects should be skipped"
+    );
+    assert_eq!(
+        s.user_client.cards.get_revealed(&trigger_character_id).numeric_spark(),
+        Some(Spark(6)),
+        "game state should still update"
+    );
+}
+
+#[test]
+fn reduce_motion_omits_cinematics() {
+    let mut s = TestBattle::builder()
+        .animation_preferences(AnimationPreferences {
+            reduce_motion: true,
+            ..AnimationPreferences::default()
+        })
+        .connect();
+    s.perform_user_action(BattleAction::EndTurn);
+
+    assert!(
+        s.find_all_commands(DisplayPlayer::User, |command| match command {
+            Command::DisplayCinematic(cinematic) => Some(cinematic),
+            _ => None,
+        })
+        .is_empty(),
+        "turn start cinematic should be skipped"
+    );
+}
+
+fn waits(s: &TestSession) -> Vec<u32> {
+    s.find_all_commands(DisplayPlayer::User, |command| match command {
+        Command::Wait(duration) => Some(duration),
+        _ => None,
+    })
+    .into_iter()
+    .map(|duration| duration.milliseconds_value)
+    .collect()
+}
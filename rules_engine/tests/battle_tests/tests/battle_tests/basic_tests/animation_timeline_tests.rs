@@ -0,0 +1,61 @@
+use core_data::display_types::Milliseconds;
+use display::rendering::animation_timeline::{AnimationStep, AnimationTimeline};
+use display_data::command::{Command, CommandSequence, GameMessageType};
+
+#[test]
+fn parallel_tracks_share_a_group() {
+    let mut timeline = AnimationTimeline::default();
+    timeline.push(AnimationStep::parallel(vec![
+        Command::DisplayGameMessage(GameMessageType::YourTurn),
+        Command::Wait(Milliseconds::new(100)),
+    ]));
+    timeline.push(AnimationStep::single(Command::Wait(Milliseconds::new(200))));
+
+    assert_eq!(group_sizes(&timeline.commands()), vec![2, 1]);
+}
+
+#[test]
+fn step_duration_adds_wait_track() {
+    let mut timeline = AnimationTimeline::default();
+    timeline.push(
+        AnimationStep::single(Command::DisplayGameMessage(GameMessageType::EnemyTurn))
+            .with_duration(Milliseconds::new(500)),
+    );
+
+    let commands = timeline.commands();
+    assert_eq!(group_sizes(&commands), vec![2]);
+    assert!(matches!(
+        commands.groups[0].commands[1],
+        Command::Wait(duration) if duration.milliseconds_value == 500
+    ));
+}
+
+#[test]
+fn wait_for_input_adds_barrier_group() {
+    let mut timeline = AnimationTimeline::default();
+    timeline.push(
+        AnimationStep::single(Command::DisplayGameMessage(GameMessageType::YourTurn))
+            .wait_for_input(None),
+    );
+    timeline.push(AnimationStep::single(Command::Wait(Milliseconds::new(100))));
+
+    let commands = timeline.commands();
+    assert_eq!(group_sizes(&commands), vec![1, 1, 1]);
+    assert!(matches!(commands.groups[1].commands[0], Command::WaitForInput(_)));
+}
+
+#[test]
+fn extend_preserves_command_groups() {
+    let mut timeline = AnimationTimeline::default();
+    timeline.extend(CommandSequence::from_vecs(vec![
+        vec![Command::Wait(Milliseconds::new(100)), Command::Wait(Milliseconds::new(200))],
+        vec![Command::Wait(Milliseconds::new(300))],
+    ]));
+
+    assert_eq!(timeline.steps().len(), 2);
+    assert_eq!(group_sizes(&timeline.commands()), vec![2, 1]);
+}
+
+fn group_sizes(commands: &CommandSequence) -> Vec<usize> {
+    commands.groups.iter().map(|group| group.commands.len()).collect()
+}
@@ -0,0 +1,33 @@
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use database::save_file::SaveFile;
+use state_provider::state_provider::StateProvider;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session::TestSession;
+
+#[test]
+fn no_autosave_before_first_action() {
+    let s = TestBattle::builder().connect();
+    assert!(autosaved_battle(&s).is_none(), "connecting should not write an autosave");
+}
+
+#[test]
+fn ending_turn_writes_autosave() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(BattleAction::EndTurn);
+
+    let saved = s.state_provider.read_save_file(s.user_id).expect("read save").expect("save");
+    let autosaved = autosaved_battle(&s).expect("end of turn should write an autosave");
+    assert_eq!(autosaved.id, battle(saved).expect("battle").id);
+    assert_eq!(s.state_provider.count_autosave_action(s.user_id), 1);
+}
+
+fn autosaved_battle(s: &TestSession) -> Option<BattleState> {
+    battle(s.state_provider.read_latest_autosave(s.user_id).expect("read autosave")?)
+}
+
+fn battle(save: SaveFile) -> Option<BattleState> {
+    match save {
+        SaveFile::V1(v1) => v1.quest?.battle,
+    }
+}
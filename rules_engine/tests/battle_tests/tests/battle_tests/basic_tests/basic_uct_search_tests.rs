@@ -1,7 +1,14 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::panel_address::PanelAddress;
+use ai_data::difficulty::AiDifficulty;
 use ai_data::game_ai::GameAI;
+use ai_data::personality::AiPersonality;
 use battle_state::actions::battle_actions::BattleAction;
+use display_data::battle_view::DisplayPlayer;
 use display_data::command::GameMessageType;
+use tabula_generated::test_card;
 use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
 
 #[tokio::test]
 async fn test_monte_carlo_agent_basic_game() {
@@ -13,3 +20,115 @@ async fn test_monte_carlo_agent_basic_game() {
         "Enemy should have completed their turn"
     );
 }
+
+#[tokio::test]
+async fn test_easy_difficulty_agent_basic_game() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::Difficulty(AiDifficulty::Easy)).connect();
+    s.perform_user_action(BattleAction::EndTurn);
+    assert_eq!(
+        s.user_client.last_game_message,
+        Some(GameMessageType::YourTurn),
+        "Enemy should have completed their turn"
+    );
+}
+
+#[tokio::test]
+async fn test_personality_agents_basic_game() {
+    for personality in [AiPersonality::Aggro, AiPersonality::Control, AiPersonality::Combo] {
+        let mut s = TestBattle::builder()
+            .enemy_agent(GameAI::MonteCarlo(1))
+            .enemy_personality(personality)
+            .connect();
+        s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+        s.perform_user_action(BattleAction::EndTurn);
+        assert_eq!(
+            s.user_client.last_game_message,
+            Some(GameMessageType::YourTurn),
+            "{personality:?} enemy should have completed their turn"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_ai_decision_panel_explains_search() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::AiDecision));
+    let text = s.user_client.interface.screen_overlay_text();
+    assert!(text.contains("Principal variation"), "Panel should explain AI decision: {text}");
+    assert!(text.contains("Top candidates"), "Panel should list scored candidates: {text}");
+}
+
+#[tokio::test]
+async fn test_monte_carlo_agent_after_non_primary_action() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.perform_user_action(BattleAction::EndTurn);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    assert_eq!(
+        s.user_client.last_game_message,
+        Some(GameMessageType::YourTurn),
+        "Enemy should respond after pondering a different action"
+    );
+}
+
+#[tokio::test]
+async fn test_monte_carlo_agent_with_duplicate_cards_in_hand() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    assert_eq!(
+        s.user_client.last_game_message,
+        Some(GameMessageType::YourTurn),
+        "Enemy should complete their turn with duplicate cards in hand"
+    );
+}
+
+#[tokio::test]
+async fn test_win_probability_estimated_after_ai_decision() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    let probability =
+        s.user_client.win_probability.expect("Win probability should be estimated from search");
+    assert!((0.0..=1.0).contains(&probability), "Probability out of range: {probability}");
+}
+
+#[test]
+fn test_win_probability_absent_without_search() {
+    let s = TestBattle::builder().connect();
+    assert_eq!(s.user_client.win_probability, None, "No search has been run to estimate from");
+}
+
+#[tokio::test]
+async fn test_hint_button_shown_against_agent() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Hint"),
+        "Hint button should be offered against an AI opponent"
+    );
+}
+
+#[test]
+fn test_hint_button_not_shown_against_user() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert!(
+        !s.user_client.interface.screen_overlay_contains("Hint"),
+        "Hint button should not be offered against another user"
+    );
+}
+
+#[tokio::test]
+async fn test_request_hint_shows_searching_button() {
+    let mut s = TestBattle::builder().enemy_agent(GameAI::MonteCarlo(1)).connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleDisplayAction::RequestHint);
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Thinking"),
+        "Hint button should show that a suggestion is being searched for"
+    );
+}
@@ -1,7 +1,10 @@
 use action_data::battle_display_action::{BattleDisplayAction, CardBrowserType};
-use battle_state::actions::battle_actions::BattleAction;
+use action_data::panel_address::{PanelAddress, VoidBrowserFilter, VoidBrowserSort};
+use battle_state::actions::battle_actions::{BattleAction, CardOrderSelectionTarget};
 use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::card_id::CardId;
 use core_data::types::PlayerName;
+use display::display_actions::apply_battle_display_action::MAX_VOID_BROWSER_CARDS;
 use display_data::battle_view::DisplayPlayer;
 use display_data::card_view::CardPrefab;
 use display_data::object_position::Position;
@@ -570,3 +573,213 @@ fn toggle_battlefield_visibility_hides_and_shows_dreamwell_card() {
         "on screen storage should be empty after showing dreamwell card again"
     );
 }
+
+#[test]
+fn inspect_card_shows_modified_spark() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+    let character_id = s.create_and_play(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    s.perform_user_action(BattleDisplayAction::InspectCard(CardId(
+        character_id.parse().expect("numeric card id"),
+    )));
+
+    let detail = s.user_client.interface().card_detail.as_ref().expect("card detail shown");
+    assert_eq!(detail.card_id, character_id, "detail is for the inspected card");
+    assert!(
+        detail.modifiers.iter().any(|m| m.contains('6') && m.contains('5')),
+        "detail shows current and printed spark: {:?}",
+        detail.modifiers
+    );
+    assert!(s.enemy_client.interface().card_detail.is_none(), "enemy is not inspecting a card");
+
+    s.perform_user_action(BattleDisplayAction::CloseCardInspection);
+
+    assert!(s.user_client.interface().card_detail.is_none(), "card detail closed");
+}
+
+#[test]
+fn inspect_hidden_card_shows_no_details() {
+    let mut s = TestBattle::builder().connect();
+    let enemy_card_id = s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+
+    s.perform_user_action(BattleDisplayAction::InspectCard(CardId(
+        enemy_card_id.parse().expect("numeric card id"),
+    )));
+
+    assert!(
+        s.user_client.interface().card_detail.is_none(),
+        "cards in the enemy's hand cannot be inspected"
+    );
+}
+
+#[test]
+fn inspect_card_shows_related_cards() {
+    let mut s = TestBattle::builder().connect();
+    let card_id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_RELATED_CARD_CHARACTER);
+
+    s.perform_user_action(BattleDisplayAction::InspectCard(CardId(
+        card_id.parse().expect("numeric card id"),
+    )));
+
+    let detail = s.user_client.interface().card_detail.as_ref().expect("card detail shown");
+    let names = detail
+        .related_cards
+        .iter()
+        .filter_map(|card| card.revealed.as_ref().map(|revealed| revealed.name.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Test Vanilla Character"], "referenced card is shown");
+}
+
+#[test]
+fn info_zoom_shows_reclaim_version_of_card() {
+    let mut s = TestBattle::builder().connect();
+    let card_id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_DRAW_ONE_RECLAIM);
+
+    let related = &s
+        .user_client
+        .cards
+        .get_revealed(&card_id)
+        .info_zoom_data
+        .as_ref()
+        .expect("info zoom data")
+        .related_cards;
+    assert_eq!(related.len(), 1, "reclaim version is shown");
+    let reclaim = related[0].revealed.as_ref().expect("related card is revealed");
+    assert_eq!(reclaim.name, "Test Draw One Reclaim");
+    assert!(reclaim.card_type.contains("Reclaim"), "type line: {}", reclaim.card_type);
+    assert_eq!(related[0].prefab, CardPrefab::Token);
+}
+
+#[test]
+fn browse_large_void_opens_void_browser_panel() {
+    let mut s = TestBattle::builder().connect();
+    for _ in 0..=MAX_VOID_BROWSER_CARDS {
+        s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    }
+
+    s.perform_user_action(BattleDisplayAction::BrowseCards(CardBrowserType::UserVoid));
+
+    assert!(s.user_client.interface().has_open_panels, "void browser panel should be open");
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Reclaimable"),
+        "large void should be listed in the void browser panel"
+    );
+    assert_eq!(
+        s.user_client.cards.cards_at_position(&Position::Browser).len(),
+        0,
+        "cards should not be moved to the card browser"
+    );
+    assert_eq!(
+        s.user_client.cards.user_void().len(),
+        MAX_VOID_BROWSER_CARDS + 1,
+        "cards should remain in the void"
+    );
+}
+
+#[test]
+fn void_browser_panel_filters_cards() {
+    let mut s = TestBattle::builder().connect();
+    let character_id = s.add_to_void(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    let event_id = s.add_to_void(DisplayPlayer::Enemy, test_card::TEST_DISSOLVE);
+    let character_name = s.user_client.cards.get_revealed(&character_id).name.clone();
+    let event_name = s.user_client.cards.get_revealed(&event_id).name.clone();
+
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::VoidBrowser {
+        player: PlayerName::Two,
+        filter: VoidBrowserFilter::Events,
+        sort: VoidBrowserSort::Recent,
+    }));
+
+    assert!(s.user_client.interface().has_open_panels, "void browser panel should be open");
+    assert!(
+        s.user_client.interface.screen_overlay_contains(&event_name),
+        "events filter should include {event_name}"
+    );
+    assert!(
+        !s.user_client.interface.screen_overlay_contains(&character_name),
+        "events filter should exclude {character_name}"
+    );
+}
+
+#[test]
+fn void_browser_panel_sorts_cards() {
+    let mut s = TestBattle::builder().connect();
+    let first_id = s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    let second_id = s.add_to_void(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+    let first_name = s.user_client.cards.get_revealed(&first_id).name.clone();
+    let second_name = s.user_client.cards.get_revealed(&second_id).name.clone();
+    let open_sorted = |sort| {
+        BattleDisplayAction::OpenPanel(PanelAddress::VoidBrowser {
+            player: PlayerName::One,
+            filter: VoidBrowserFilter::All,
+            sort,
+        })
+    };
+
+    s.perform_user_action(open_sorted(VoidBrowserSort::Recent));
+    let text = s.user_client.interface.screen_overlay_text();
+    assert!(
+        text.find(&second_name) < text.find(&first_name),
+        "most recently added card should be listed first: {text}"
+    );
+
+    s.perform_user_action(open_sorted(VoidBrowserSort::Name));
+    let text = s.user_client.interface.screen_overlay_text();
+    assert_eq!(
+        text.find(&first_name) < text.find(&second_name),
+        first_name < second_name,
+        "cards should be listed by name: {text}"
+    );
+}
+
+#[test]
+fn deck_list_panel_shows_remaining_card_count() {
+    let mut s = TestBattle::builder().connect();
+    let deck_count = s.user_client.cards.user_deck().len();
+
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::DeckList));
+
+    assert!(s.user_client.interface().has_open_panels, "deck list panel should be open");
+    assert!(
+        s.user_client.interface.screen_overlay_contains(&format!("Remaining Cards ({deck_count})")),
+        "deck list should show the number of cards remaining in the deck"
+    );
+    assert!(
+        !s.user_client.interface.screen_overlay_contains("Top of Deck"),
+        "no cards should be known on top of the deck"
+    );
+}
+
+#[test]
+fn deck_list_panel_shows_known_top_of_deck() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_FORESEE_ONE);
+    let card_id = s
+        .user_client
+        .cards
+        .card_map
+        .values()
+        .find(|card| matches!(card.view.position.position, Position::CardOrderSelector(_)))
+        .expect("foresee should show a card")
+        .id
+        .clone();
+    let name = s.user_client.cards.get_revealed(&card_id).name.clone();
+    s.select_card_order(DisplayPlayer::User, &card_id, CardOrderSelectionTarget::Deck(0));
+    s.click_primary_button(DisplayPlayer::User, "Submit");
+
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::DeckList));
+
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Top of Deck"),
+        "deck list should list cards known to be on top of the deck"
+    );
+    assert!(
+        s.user_client.interface.screen_overlay_contains(&format!("1. {name}")),
+        "foreseen card {name} should be listed as the top card"
+    );
+}
@@ -2,7 +2,7 @@ use battle_state::actions::debug_battle_action::DebugBattleAction;
 use core_data::numerics::Energy;
 use core_data::types::PlayerName;
 use display_data::battle_view::DisplayPlayer;
-use display_data::command::GameMessageType;
+use display_data::command::{Command, GameMessageType, NotificationSeverity};
 use tabula_generated::test_card;
 use test_utils::battle::test_battle::TestBattle;
 use test_utils::session::test_session_prelude::*;
@@ -38,6 +38,38 @@ fn hand_size_limit_exceeded_gains_energy() {
     );
 }
 
+#[test]
+fn hand_size_limit_exceeded_displays_notification() {
+    let mut s = TestBattle::builder().connect();
+    for _ in 0..9 {
+        s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    }
+    let draw_id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VARIABLE_ENERGY_DRAW);
+
+    s.play_card_from_hand(DisplayPlayer::User, &draw_id);
+    s.click_increment_button(DisplayPlayer::User);
+    s.click_primary_button(DisplayPlayer::User, "Spend");
+
+    let notification = s.find_command(DisplayPlayer::User, |command| match command {
+        Command::DisplayNotification(notification) => Some(notification),
+        _ => None,
+    });
+    assert_eq!(notification.severity, NotificationSeverity::Warning);
+    assert!(
+        notification.text.contains("hand full"),
+        "notification should explain the draw was converted, got: '{}'",
+        notification.text
+    );
+    assert!(
+        s.find_all_commands(DisplayPlayer::Enemy, |command| match command {
+            Command::DisplayNotification(notification) => Some(notification),
+            _ => None,
+        })
+        .is_empty(),
+        "enemy should not be notified about the user's hand"
+    );
+}
+
 #[test]
 fn character_limit_exceeded_abandons_character() {
     let mut s = TestBattle::builder().connect();
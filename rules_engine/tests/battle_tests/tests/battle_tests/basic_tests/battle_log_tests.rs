@@ -0,0 +1,40 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::panel_address::PanelAddress;
+use battle_state::actions::battle_actions::BattleAction;
+use display_data::battle_view::DisplayPlayer;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn battle_log_empty_before_any_actions() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::BattleLog));
+    let text = s.user_client.interface.screen_overlay_text();
+    assert!(text.contains("Nothing has happened yet"), "Log should be empty: {text}");
+}
+
+#[test]
+fn battle_log_lists_actions_by_turn() {
+    let mut s = TestBattle::builder().connect();
+    let card_id = s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    let name = s.user_client.cards.get_revealed(&card_id).name.clone();
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::BattleLog));
+
+    let text = s.user_client.interface.screen_overlay_text();
+    assert!(text.contains("Turn 1"), "Log should group entries by turn: {text}");
+    assert!(text.contains("You played"), "Log should include the played card: {text}");
+    assert!(text.contains(&name), "Log should name the played card: {text}");
+    assert!(text.contains("You ended the turn"), "Log should include ending the turn: {text}");
+}
+
+#[test]
+fn battle_log_uses_opponent_perspective() {
+    let mut s = TestBattle::builder().connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_enemy_action(BattleDisplayAction::OpenPanel(PanelAddress::BattleLog));
+
+    let text = s.enemy_client.interface.screen_overlay_text();
+    assert!(text.contains("Opponent played"), "Enemy should see the user as opponent: {text}");
+}
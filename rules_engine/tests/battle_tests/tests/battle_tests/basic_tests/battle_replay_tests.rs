@@ -0,0 +1,97 @@
+use battle_mutations::actions::apply_battle_action;
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::battle::battle_history::BattleHistory;
+use battle_state::battle::battle_state::BattleState;
+use core_data::identifiers::UserId;
+use core_data::types::PlayerName;
+use display::rendering::battle_replay::BattleReplay;
+use display_data::command::{Command, CommandSequence};
+use state_provider::test_state_provider::TestStateProvider;
+use test_utils::battle::test_battle_state;
+use uuid::Uuid;
+
+const RECORDED_ACTIONS: usize = 40;
+
+#[test]
+fn replay_seek_matches_recorded_states() {
+    let provider = TestStateProvider::new();
+    let (initial, history, states) = record_battle();
+    let user_id = UserId(Uuid::new_v4());
+    let mut replay = BattleReplay::new(initial, history);
+    assert_eq!(replay.len(), states.len() - 1, "replay should contain every recorded action");
+
+    for index in [replay.len(), 3, 17, 16, 0, 33, 34] {
+        let commands = replay.seek(index, PlayerName::One, user_id, provider.clone());
+        assert_eq!(replay.index(), index, "replay should move to the requested index");
+        assert_eq!(
+            fingerprint(replay.battle()),
+            states[index],
+            "replayed state should match recorded state at index {index}"
+        );
+        assert!(has_battle_view(&commands), "seeking should render a battle view");
+    }
+}
+
+#[test]
+fn replay_steps_forward_and_backward() {
+    let provider = TestStateProvider::new();
+    let (initial, history, states) = record_battle();
+    let user_id = UserId(Uuid::new_v4());
+    let mut replay = BattleReplay::new(initial, history);
+
+    assert!(
+        replay.step_backward(PlayerName::One, user_id, provider.clone()).is_none(),
+        "cannot step backward from the start of the log"
+    );
+
+    replay.seek(10, PlayerName::One, user_id, provider.clone());
+    let commands = replay
+        .step_forward(PlayerName::One, user_id, provider.clone())
+        .expect("should step forward");
+    assert_eq!(replay.index(), 11, "stepping forward should apply one action");
+    assert_eq!(fingerprint(replay.battle()), states[11], "state after stepping forward");
+    assert!(has_battle_view(&commands), "stepping forward should render a battle view");
+
+    replay.step_backward(PlayerName::One, user_id, provider.clone()).expect("should step back");
+    assert_eq!(replay.index(), 10, "stepping backward should undo one action");
+    assert_eq!(fingerprint(replay.battle()), states[10], "state after stepping backward");
+
+    replay.seek(replay.len(), PlayerName::One, user_id, provider.clone());
+    assert!(
+        replay.step_forward(PlayerName::One, user_id, provider).is_none(),
+        "cannot step forward from the end of the log"
+    );
+}
+
+/// Plays a battle between two agents, returning its initial state, its action
+/// log, and a fingerprint of the battle state after each action.
+fn record_battle() -> (BattleState, BattleHistory, Vec<String>) {
+    let initial = test_battle_state::create(314159);
+
+    let mut battle = initial.clone();
+    battle.action_history = Some(BattleHistory::default());
+    let mut states = vec![fingerprint(&battle)];
+    while states.len() <= RECORDED_ACTIONS
+        && let Some(player) = legal_actions::next_to_act(&battle)
+    {
+        let action = legal_actions::compute(&battle, player).all()[0];
+        apply_battle_action::execute(&mut battle, player, action);
+        states.push(fingerprint(&battle));
+    }
+
+    let history = battle.action_history.take().expect("action history should be recorded");
+    (initial, history, states)
+}
+
+fn fingerprint(battle: &BattleState) -> String {
+    serde_json::to_string(&(&battle.cards, &battle.players, &battle.turn, &battle.prompts))
+        .expect("Failed to serialize battle state")
+}
+
+fn has_battle_view(commands: &CommandSequence) -> bool {
+    commands
+        .groups
+        .iter()
+        .flat_map(|group| &group.commands)
+        .any(|command| matches!(command, Command::UpdateBattle(_) | Command::UpdateBattleDelta(_)))
+}
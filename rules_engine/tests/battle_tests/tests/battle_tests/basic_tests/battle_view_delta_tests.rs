@@ -0,0 +1,71 @@
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::Command;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session::TestSession;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn battle_view_deltas_sent_when_requested() {
+    let mut s = TestBattle::builder().battle_view_deltas().connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let commands = s.last_user_commands.as_ref().expect("No commands found");
+    let all_commands = commands.groups.iter().flat_map(|group| &group.commands);
+    assert!(
+        all_commands.clone().any(|command| matches!(command, Command::UpdateBattleDelta(_))),
+        "battle updates should be sent as deltas"
+    );
+    assert!(
+        !all_commands.clone().any(|command| matches!(command, Command::UpdateBattle(_))),
+        "full snapshots should not be sent for small changes"
+    );
+}
+
+#[test]
+fn battle_view_deltas_not_sent_by_default() {
+    let mut s = TestBattle::builder().connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let commands = s.last_user_commands.as_ref().expect("No commands found");
+    assert!(
+        !commands
+            .groups
+            .iter()
+            .flat_map(|group| &group.commands)
+            .any(|command| matches!(command, Command::UpdateBattleDelta(_))),
+        "battle updates should be full snapshots unless deltas are requested"
+    );
+}
+
+#[test]
+fn battle_view_deltas_match_full_snapshots() {
+    let mut full = TestBattle::builder().connect();
+    let mut delta = TestBattle::builder().battle_view_deltas().connect();
+    for s in [&mut full, &mut delta] {
+        s.create_and_play(DisplayPlayer::User, test_card::TEST_VARIABLE_ENERGY_DRAW);
+        s.click_increment_button(DisplayPlayer::User);
+        s.click_primary_button(DisplayPlayer::User, "Spend");
+        s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    }
+
+    assert_eq!(full.user_client.me.energy(), delta.user_client.me.energy());
+    assert_eq!(card_summary(&full), card_summary(&delta));
+}
+
+fn card_summary(s: &TestSession) -> Vec<String> {
+    let mut cards = s
+        .user_client
+        .cards
+        .card_map
+        .values()
+        .map(|card| {
+            format!(
+                "{} {:?} {:?} {:?}",
+                card.id, card.view.position, card.view.card_facing, card.view.revealed
+            )
+        })
+        .collect::<Vec<_>>();
+    cards.sort();
+    cards
+}
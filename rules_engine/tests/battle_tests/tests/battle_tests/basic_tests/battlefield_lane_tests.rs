@@ -0,0 +1,65 @@
+use display_data::battle_view::DisplayPlayer;
+use display_data::object_position::{BattlefieldPosition, Position};
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn characters_are_assigned_lanes_in_order_of_entry() {
+    let mut s = TestBattle::builder().connect();
+    let first = s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    let second = s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert_eq!(
+        s.user_client.cards.get(&first).view.position.position,
+        Position::OnBattlefield(BattlefieldPosition {
+            player: DisplayPlayer::User,
+            row: Some(0),
+            lane: Some(0)
+        })
+    );
+    assert_eq!(
+        s.user_client.cards.get(&second).view.position.position,
+        Position::OnBattlefield(BattlefieldPosition {
+            player: DisplayPlayer::User,
+            row: Some(0),
+            lane: Some(1)
+        })
+    );
+    assert_eq!(
+        s.enemy_client.cards.get(&second).view.position.position,
+        Position::OnBattlefield(BattlefieldPosition {
+            player: DisplayPlayer::Enemy,
+            row: Some(0),
+            lane: Some(1)
+        }),
+        "Opponent should see the same lane on the other battlefield"
+    );
+}
+
+#[test]
+fn large_battlefield_uses_additional_rows() {
+    let mut s = TestBattle::builder().connect();
+    let ids = (0..9)
+        .map(|_| s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER))
+        .collect::<Vec<_>>();
+
+    assert_eq!(s.user_client.cards.user_battlefield().len(), 9);
+    assert_eq!(
+        s.user_client.cards.get(&ids[7]).view.position.position,
+        Position::OnBattlefield(BattlefieldPosition {
+            player: DisplayPlayer::User,
+            row: Some(0),
+            lane: Some(7)
+        })
+    );
+    assert_eq!(
+        s.user_client.cards.get(&ids[8]).view.position.position,
+        Position::OnBattlefield(BattlefieldPosition {
+            player: DisplayPlayer::User,
+            row: Some(1),
+            lane: Some(0)
+        }),
+        "Ninth character should start a second row"
+    );
+}
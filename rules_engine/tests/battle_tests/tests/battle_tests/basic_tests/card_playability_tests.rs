@@ -0,0 +1,49 @@
+use display_data::battle_view::DisplayPlayer;
+use display_data::card_view::ClientCardId;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::battle::test_player::TestPlayer;
+use test_utils::client::test_interface_view;
+use test_utils::session::test_session::TestSession;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn playable_card_has_no_unplayable_reason() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(10).build()).connect();
+    let id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert!(s.user_client.cards.get(&id).revealed().actions.can_play.is_some());
+    assert_eq!(supplemental_text(&s, &id), "", "playable card should have no tooltip");
+}
+
+#[test]
+fn insufficient_energy_shows_required_energy() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(0).build()).connect();
+    let id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert!(s.user_client.cards.get(&id).revealed().actions.can_play.is_none());
+    assert!(
+        supplemental_text(&s, &id).contains("Requires"),
+        "tooltip should explain that more energy is required"
+    );
+}
+
+#[test]
+fn no_legal_targets_shows_reason() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(10).build()).connect();
+    let id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+    assert!(s.user_client.cards.get(&id).revealed().actions.can_play.is_none());
+    assert!(
+        supplemental_text(&s, &id).contains("No valid targets"),
+        "tooltip should explain that there are no targets"
+    );
+}
+
+fn supplemental_text(s: &TestSession, id: &ClientCardId) -> String {
+    s.user_client
+        .cards
+        .get_revealed(id)
+        .info_zoom_data
+        .as_ref()
+        .and_then(|data| data.supplemental_card_info.as_ref())
+        .map(test_interface_view::extract_text_from_node)
+        .unwrap_or_default()
+}
@@ -0,0 +1,93 @@
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle_player::battle_player_state::TestDeckName;
+use core_data::types::PlayerName;
+use database::collection::{Collection, UnlockSource};
+use display_data::battle_view::DisplayPlayer;
+use rules_engine::user_collection;
+use state_provider::state_provider::StateProvider;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::battle::test_battle_state;
+use test_utils::battle::test_player::TestPlayer;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn connect_displays_starting_collection() {
+    let s = TestBattle::builder().connect();
+    let collection = s.user_client.collection.as_ref().expect("Collection should be displayed");
+    let card = collection
+        .cards
+        .iter()
+        .find(|card| card.card_id == test_card::TEST_VANILLA_CHARACTER)
+        .expect("Starting deck cards should be in the collection");
+    assert_eq!(card.count, 30, "every copy in the starting deck should be owned");
+    assert_eq!(card.unlocked_by, vec!["Starting collection".to_string()]);
+    assert_eq!(collection.total_cards, 30);
+}
+
+#[test]
+fn legacy_client_does_not_receive_collection() {
+    let s = TestBattle::builder().capabilities(vec![]).connect();
+    assert!(
+        s.user_client.collection.is_none(),
+        "clients without the collection browser capability should not receive the collection"
+    );
+}
+
+#[test]
+fn winning_battle_grants_quest_reward() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(20).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+
+    let save = s.state_provider.read_save_file(s.user_id).expect("read save").expect("save");
+    let owned = &save.collection().cards[&test_card::TEST_VANILLA_CHARACTER];
+    assert_eq!(owned.count, 31, "winning should grant a copy of each card in the enemy's deck");
+    assert!(
+        owned.unlock_sources.iter().any(|source| matches!(source, UnlockSource::QuestReward(_))),
+        "reward should be recorded as a quest reward"
+    );
+
+    s.connect();
+    let collection = s.user_client.collection.as_ref().expect("Collection should be displayed");
+    assert_eq!(collection.total_cards, 31, "reconnecting should show the rewarded card");
+}
+
+#[test]
+fn losing_battle_grants_no_reward() {
+    let mut s =
+        TestBattle::builder().enemy(TestPlayer::builder().energy(99).points(20).build()).connect();
+    s.end_turn_remove_opponent_hand(DisplayPlayer::User);
+    s.create_and_play(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_enemy_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleAction::EndTurn);
+
+    let save = s.state_provider.read_save_file(s.user_id).expect("read save").expect("save");
+    assert_eq!(save.collection().total_cards(), 30, "losing should not grant any cards");
+}
+
+#[test]
+fn battle_deck_must_be_owned() {
+    let battle = test_battle_state::create(314159);
+    let mut collection = Collection::default();
+    collection.grant(test_card::TEST_VANILLA_CHARACTER, 29, UnlockSource::Debug);
+    let error = user_collection::validate_battle_deck(&collection, &battle, PlayerName::One)
+        .expect_err("deck with an unowned copy should be rejected");
+    assert!(
+        error.contains("30 in deck, 29 owned"),
+        "error should describe missing copies: {error}"
+    );
+
+    user_collection::grant_deck(
+        &mut collection,
+        &battle.tabula,
+        TestDeckName::Vanilla,
+        UnlockSource::StartingCollection,
+    );
+    assert_eq!(
+        user_collection::validate_battle_deck(&collection, &battle, PlayerName::One),
+        Ok(())
+    );
+}
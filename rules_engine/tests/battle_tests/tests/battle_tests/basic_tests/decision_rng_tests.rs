@@ -0,0 +1,42 @@
+use ai_agents::agent_search;
+use ai_data::game_ai::GameAI;
+use ai_uct::uct_search;
+use battle_mutations::actions::apply_debug_battle_action;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::battle_state::BattleState;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+use rand::Rng;
+use test_utils::battle::test_battle_state;
+
+#[test]
+fn test_decision_rng_is_seeded_by_battle() {
+    let battle = test_battle_state::create(314159);
+    let draw = |battle: &BattleState| uct_search::decision_rng(battle).random::<u64>();
+    assert_eq!(draw(&battle), draw(&battle.clone()), "Same battle state should draw same values");
+    assert_ne!(
+        draw(&battle),
+        draw(&test_battle_state::create(271828)),
+        "Battle seed should change draws"
+    );
+
+    let mut changed = battle.clone();
+    apply_debug_battle_action::execute(
+        &mut changed,
+        PlayerName::One,
+        DebugBattleAction::SetEnergy { player: PlayerName::One, energy: Energy(7) },
+    );
+    assert_ne!(draw(&battle), draw(&changed), "Successive decisions should draw different values");
+}
+
+#[test]
+fn test_random_agent_is_deterministic_for_battle() {
+    let battle = test_battle_state::create(314159);
+    let select = || {
+        agent_search::select_action_unchecked(&battle, PlayerName::One, &GameAI::RandomAction, None)
+    };
+    let first = select();
+    for _ in 0..5 {
+        assert_eq!(select(), first, "Random choices should come from the battle's seeded RNG");
+    }
+}
@@ -1,8 +1,10 @@
 use action_data::game_action_data::GameAction;
 use battle_state::actions::battle_actions::BattleAction;
 use battle_state::battle::battle_state::{LoggingOptions, RequestContext};
-use core_data::identifiers::UserId;
-use display_data::request_data::{ConnectRequest, Metadata, PerformActionRequest};
+use core_data::identifiers::{SaveSlotId, UserId};
+use display_data::request_data::{
+    AnimationPreferences, ConnectRequest, Metadata, PerformActionRequest,
+};
 use rules_engine::engine;
 use state_provider::state_provider::StateProvider;
 use state_provider::test_state_provider::TestStateProvider;
@@ -143,6 +145,14 @@ fn action_without_version_is_processed() {
         vs_opponent: None,
         display_properties: None,
         debug_configuration: None,
+        battle_view_deltas: false,
+        language: None,
+        colorblind_mode: false,
+        protocol_version: 0,
+        capabilities: vec![],
+        tutorial: false,
+        animation_preferences: AnimationPreferences::default(),
+        save_slot: SaveSlotId::default(),
     };
     let _ = engine::connect_with_provider(provider.clone(), &connect_request, RequestContext {
         logging_options: LoggingOptions::default(),
@@ -191,6 +201,14 @@ fn response_version_tracking_in_poll() {
         vs_opponent: None,
         display_properties: None,
         debug_configuration: None,
+        battle_view_deltas: false,
+        language: None,
+        colorblind_mode: false,
+        protocol_version: 0,
+        capabilities: vec![],
+        tutorial: false,
+        animation_preferences: AnimationPreferences::default(),
+        save_slot: SaveSlotId::default(),
     };
 
     let connect_response =
@@ -0,0 +1,80 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::emote_data::Emote;
+use action_data::game_action_data::GameAction;
+use action_data::panel_address::PanelAddress;
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::Command;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn emote_shown_to_both_players() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(GameAction::Emote(Emote::WellPlayed));
+
+    let user_emote = s.find_command(DisplayPlayer::User, |command| match command {
+        Command::DisplayEmote(emote) => Some(emote),
+        _ => None,
+    });
+    assert_eq!(user_emote.player, DisplayPlayer::User, "user should see their own emote");
+    assert_eq!(user_emote.text, "Well played!");
+
+    let enemy_emote = s.find_command(DisplayPlayer::Enemy, |command| match command {
+        Command::DisplayEmote(emote) => Some(emote),
+        _ => None,
+    });
+    assert_eq!(
+        enemy_emote.player,
+        DisplayPlayer::Enemy,
+        "enemy should see the emote as sent by their opponent"
+    );
+    assert_eq!(enemy_emote.emote, Emote::WellPlayed);
+}
+
+#[test]
+fn muted_emotes_not_shown_to_opponent() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_enemy_action(BattleDisplayAction::SetOpponentEmotesMuted(true));
+    s.perform_user_action(GameAction::Emote(Emote::Greetings));
+
+    assert!(
+        s.find_all_commands(DisplayPlayer::Enemy, |command| match command {
+            Command::DisplayEmote(emote) => Some(emote),
+            _ => None,
+        })
+        .is_empty(),
+        "enemy has muted emotes from their opponent"
+    );
+    assert_eq!(
+        s.find_all_commands(DisplayPlayer::User, |command| match command {
+            Command::DisplayEmote(emote) => Some(emote),
+            _ => None,
+        })
+        .len(),
+        1,
+        "user should still see their own emote"
+    );
+}
+
+#[test]
+fn emote_panel_lists_emotes_and_mute_button() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::Emotes));
+
+    assert!(s.user_client.interface().has_open_panels, "emote panel should be open");
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Good game!"),
+        "emote panel should list emotes"
+    );
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Mute Opponent"),
+        "emote panel should offer to mute the opponent"
+    );
+
+    s.perform_user_action(BattleDisplayAction::SetOpponentEmotesMuted(true));
+
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Unmute Opponent"),
+        "emote panel should offer to unmute the opponent once muted"
+    );
+}
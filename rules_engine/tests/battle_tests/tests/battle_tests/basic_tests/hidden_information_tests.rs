@@ -0,0 +1,59 @@
+use display_data::battle_view::DisplayPlayer;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn enemy_hand_cards_not_revealed_to_user() {
+    let mut s = TestBattle::builder().connect();
+    let card_id = s.add_to_hand(DisplayPlayer::Enemy, test_card::TEST_DRAW_ONE);
+
+    assert!(
+        s.user_client.cards.get(&card_id).view.revealed.is_none(),
+        "enemy hand card should not be revealed to the user"
+    );
+    let name = s.enemy_client.cards.get_revealed(&card_id).name.clone();
+    let commands = serde_json::to_string(s.last_user_commands.as_ref().expect("No commands found"))
+        .expect("Failed to serialize commands");
+    assert!(!commands.contains(&name), "enemy hand card name should not be sent to the user");
+}
+
+#[test]
+fn deck_cards_not_revealed() {
+    let s = TestBattle::builder().connect();
+
+    for client in [&s.user_client, &s.enemy_client] {
+        for deck in [client.cards.user_deck(), client.cards.enemy_deck()] {
+            assert!(!deck.is_empty(), "decks should contain cards");
+            assert!(
+                deck.iter().all(|card| card.view.revealed.is_none()),
+                "deck cards should not be revealed"
+            );
+            assert!(
+                deck.iter().all(|card| card.view.position.sorting_key == 0),
+                "deck cards should not reveal deck order"
+            );
+        }
+    }
+}
+
+#[test]
+fn prompt_targeting_not_shown_to_opponent() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+
+    assert!(s.user_client.targeting.is_some(), "user should see their own targeting prompt");
+    assert!(s.enemy_client.targeting.is_none(), "enemy should not see the user's targeting prompt");
+    assert!(
+        s.enemy_client
+            .cards
+            .card_map
+            .values()
+            .filter_map(|card| card.view.revealed.as_ref())
+            .all(|revealed| !revealed.is_legal_target),
+        "enemy should not see the legal targets of the user's prompt"
+    );
+}
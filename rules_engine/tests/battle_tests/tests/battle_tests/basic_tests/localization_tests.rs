@@ -0,0 +1,59 @@
+use display_data::battle_view::DisplayPlayer;
+use strings::palette::{Palette, PaletteColor};
+use strings::{language, strings};
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+const BRACKET_LANGUAGE: &str = "en-x-bracket";
+const BRACKET_LOCALE_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../src/strings/locales/bracket.rlf");
+
+#[test]
+fn players_see_battle_in_their_own_language() {
+    register_bracket_locale();
+    let mut s = TestBattle::builder().language(BRACKET_LANGUAGE).connect();
+    let card_id =
+        s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_ACTIVATED_ABILITY_DRAW_CARD);
+
+    let user_text = s.user_client.cards.get_revealed(&card_id).rules_text.clone();
+    let enemy_text = s.enemy_client.cards.get_revealed(&card_id).rules_text.clone();
+    assert!(user_text.contains('['), "User should see bracket rules text: {user_text}");
+    assert!(!enemy_text.contains('['), "Enemy should see English rules text: {enemy_text}");
+
+    let button = s.user_client.interface.primary_action_button_text();
+    assert!(button.starts_with('['), "User should see a bracket button label: {button}");
+}
+
+#[test]
+fn rendering_in_another_language_keeps_default_language() {
+    register_bracket_locale();
+    let mut s = TestBattle::builder().language(BRACKET_LANGUAGE).connect();
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_ACTIVATED_ABILITY_DRAW_CARD);
+
+    language::with_language(None, || {
+        assert_eq!(rlf::with_locale(|locale| locale.language().to_string()), "en");
+        assert_eq!(strings::hint_button().to_string(), "Hint");
+    });
+}
+
+#[test]
+fn colorblind_mode_recolors_text_for_that_user_only() {
+    let mut s = TestBattle::builder().colorblind_mode().connect();
+    let card_id =
+        s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_ACTIVATED_ABILITY_DRAW_CARD);
+
+    let standard = PaletteColor::Energy.hex(Palette::Standard);
+    let colorblind = PaletteColor::Energy.hex(Palette::Colorblind);
+    let user_text = s.user_client.cards.get_revealed(&card_id).rules_text.clone();
+    let enemy_text = s.enemy_client.cards.get_revealed(&card_id).rules_text.clone();
+    assert!(user_text.contains(colorblind), "User should see colorblind palette: {user_text}");
+    assert!(!user_text.contains(standard), "User should not see standard palette: {user_text}");
+    assert!(enemy_text.contains(standard), "Enemy should see standard palette: {enemy_text}");
+}
+
+fn register_bracket_locale() {
+    strings::register_source_phrases();
+    rlf::with_locale_mut(|locale| locale.load_translations(BRACKET_LANGUAGE, BRACKET_LOCALE_PATH))
+        .expect("Bracket locale should load");
+}
@@ -0,0 +1,87 @@
+use action_data::battle_display_action::BattleDisplayAction;
+use action_data::panel_address::PanelAddress;
+use battle_state::actions::battle_actions::BattleAction;
+use database::match_history::MatchResult;
+use display_data::battle_view::DisplayPlayer;
+use state_provider::display_state_provider::DisplayStateProvider;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::battle::test_player::TestPlayer;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn winning_battle_records_victory() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(20).build()).connect();
+    assert!(
+        s.state_provider.match_history(s.user_id).entries.is_empty(),
+        "no battles should be recorded before the battle ends"
+    );
+
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+
+    let history = s.state_provider.match_history(s.user_id);
+    assert_eq!(history.entries.len(), 1, "completed battle should be recorded");
+    assert_eq!(history.entries[0].result, MatchResult::Victory, "user won the battle");
+    assert!(history.entries[0].turn_count > 1, "turn count should be recorded");
+}
+
+#[test]
+fn losing_battle_records_defeat() {
+    let mut s =
+        TestBattle::builder().enemy(TestPlayer::builder().energy(99).points(20).build()).connect();
+    s.end_turn_remove_opponent_hand(DisplayPlayer::User);
+    s.create_and_play(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_enemy_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleAction::EndTurn);
+
+    let history = s.state_provider.match_history(s.user_id);
+    assert_eq!(history.entries.len(), 1, "completed battle should be recorded");
+    assert_eq!(history.entries[0].result, MatchResult::Defeat, "enemy won the battle");
+}
+
+#[test]
+fn actions_after_game_over_do_not_duplicate_record() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(20).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::MatchHistory));
+    s.perform_user_action(BattleDisplayAction::CloseCurrentPanel);
+
+    assert_eq!(
+        s.state_provider.match_history(s.user_id).entries.len(),
+        1,
+        "battle should only be recorded once"
+    );
+}
+
+#[test]
+fn match_history_panel_shows_empty_message() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::MatchHistory));
+
+    assert!(s.user_client.interface().has_open_panels, "match history panel should be open");
+    assert!(
+        s.user_client.interface.screen_overlay_contains("No battles completed yet"),
+        "empty match history should show a placeholder message"
+    );
+}
+
+#[test]
+fn match_history_panel_lists_completed_battles() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(20).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+    s.perform_user_action(BattleDisplayAction::OpenPanel(PanelAddress::MatchHistory));
+
+    assert!(
+        s.user_client.interface.screen_overlay_contains("Victory"),
+        "match history panel should list the completed battle"
+    );
+}
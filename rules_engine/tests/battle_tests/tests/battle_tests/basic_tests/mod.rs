@@ -1,17 +1,39 @@
+mod action_pruning_tests;
 mod activated_ability_tests;
+mod animation_preferences_tests;
+mod animation_timeline_tests;
+mod autosave_tests;
 mod basic_battle_actions_tests;
 mod basic_uct_search_tests;
 mod battle_display_action_tests;
 mod battle_limits_tests;
+mod battle_log_tests;
+mod battle_replay_tests;
+mod battle_view_delta_tests;
+mod battlefield_lane_tests;
+mod card_playability_tests;
+mod collection_tests;
+mod decision_rng_tests;
 pub mod dreamwell_tests;
 mod duplicate_action_prevention_tests;
+mod emote_tests;
 mod enemy_message_tests;
+mod hidden_information_tests;
+mod localization_tests;
+mod match_history_tests;
 mod modal_effect_tests;
+mod opening_book_tests;
 mod outcome_simulation_tests;
+mod prompt_cache_tests;
 mod prompt_message_tests;
+mod protocol_version_tests;
+mod resync_tests;
+mod save_slot_tests;
+mod search_time_limit_tests;
 mod stack_interaction_tests;
 mod test_helpers;
 mod text_utils_tests;
 mod triggered_ability_tests;
+mod tutorial_tests;
 mod turn_sequence_tests;
 mod undo_tests;
@@ -0,0 +1,93 @@
+use ai_agents::opening_book::{self, OpeningAction, OpeningBook, OpeningSignature};
+use battle_mutations::actions::apply_debug_battle_action;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle_player::battle_player_state::TestDeckName;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle_state;
+
+#[test]
+fn test_opening_book_plays_book_move_in_known_position() {
+    let battle = opening_position();
+    let book =
+        book_with_results(&[(play_vanilla_character(), 17, 24), (OpeningAction::EndTurn, 8, 24)]);
+    let card_id = battle.cards.hand(PlayerName::One).iter().next().expect("Card should be in hand");
+    assert_eq!(
+        book.select(&battle, PlayerName::One),
+        Some(BattleAction::PlayCardFromHand(card_id)),
+        "Book should prefer the move with the higher win rate"
+    );
+}
+
+#[test]
+fn test_opening_book_signature_identifies_known_position() {
+    let battle = opening_position();
+    assert_eq!(opening_book::signature(&battle, PlayerName::One), Some(vanilla_signature()));
+    assert_eq!(
+        opening_book::to_opening_action(&battle, BattleAction::EndTurn),
+        Some(OpeningAction::EndTurn)
+    );
+}
+
+#[test]
+fn test_opening_book_ignores_entries_with_too_few_games() {
+    let battle = opening_position();
+    let games = opening_book::MIN_BOOK_GAMES - 1;
+    let book = book_with_results(&[(OpeningAction::EndTurn, games, games)]);
+    assert_eq!(
+        book.select(&battle, PlayerName::One),
+        None,
+        "Entries with fewer than the minimum number of games should not be trusted"
+    );
+}
+
+#[test]
+fn test_opening_book_not_consulted_for_inactive_player() {
+    let battle = opening_position();
+    let book = book_with_results(&[(OpeningAction::EndTurn, 10, 10)]);
+    assert_eq!(opening_book::signature(&battle, PlayerName::Two), None);
+    assert_eq!(book.select(&battle, PlayerName::Two), None);
+}
+
+/// Returns a battle on the first turn in which player one has 2 energy and
+/// only a Test Vanilla Character in hand.
+fn opening_position() -> BattleState {
+    let mut battle = test_battle_state::create(314159);
+    let player = PlayerName::One;
+    for action in [
+        DebugBattleAction::MoveHandToDeck { player },
+        DebugBattleAction::SetEnergy { player, energy: Energy(2) },
+        DebugBattleAction::AddCardToHand { player, card: test_card::TEST_VANILLA_CHARACTER },
+    ] {
+        apply_debug_battle_action::execute(&mut battle, player, action);
+    }
+    battle
+}
+
+fn vanilla_signature() -> OpeningSignature {
+    OpeningSignature {
+        deck: TestDeckName::Vanilla,
+        turn: 1,
+        energy: Energy(2),
+        hand: vec![test_card::TEST_VANILLA_CHARACTER],
+    }
+}
+
+fn play_vanilla_character() -> OpeningAction {
+    OpeningAction::PlayCardFromHand(test_card::TEST_VANILLA_CHARACTER)
+}
+
+/// Builds a book for the [opening_position] recording `(action, wins, games)`
+/// for each provided result.
+fn book_with_results(results: &[(OpeningAction, u32, u32)]) -> OpeningBook {
+    let mut book = OpeningBook::default();
+    for &(action, wins, games) in results {
+        for game in 0..games {
+            book.record(vanilla_signature(), action, game < wins);
+        }
+    }
+    book
+}
@@ -144,3 +144,42 @@ fn character_play_effect_preview_shows_spark_increase() {
         "play effect preview should show spark increase for test character"
     );
 }
+
+#[test]
+fn modal_choice_previews_show_cards_drawn() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_MODAL_DRAW_ONE_OR_DRAW_TWO);
+
+    let browser_cards = s.user_client.cards.browser_cards();
+    let draw_one = browser_cards.cards[0].revealed().actions.on_click_effect_preview.as_ref();
+    let draw_two = browser_cards.cards[1].revealed().actions.on_click_effect_preview.as_ref();
+    assert_eq!(
+        draw_one.expect("first choice should have a preview").user.cards_drawn,
+        Some(1),
+        "first choice preview should draw 1 card"
+    );
+    assert_eq!(
+        draw_two.expect("second choice should have a preview").user.cards_drawn,
+        Some(2),
+        "second choice preview should draw 2 cards"
+    );
+}
+
+#[test]
+fn character_target_preview_shows_dissolve() {
+    let mut s = TestBattle::builder().connect();
+    let target_id = s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+
+    let preview = s
+        .user_client
+        .cards
+        .get_revealed(&target_id)
+        .actions
+        .on_click_effect_preview
+        .as_ref()
+        .expect("target should have a preview");
+    assert_eq!(preview.enemy.characters_dissolved, Some(1), "preview should dissolve target");
+    assert_eq!(preview.user.characters_dissolved, None, "preview should not dissolve allies");
+}
@@ -0,0 +1,109 @@
+use ai_uct::prompt_cache::{self, PromptCache};
+use ai_uct::uct_search;
+use ai_uct::uct_tree::SearchGraph;
+use battle_mutations::actions::{apply_battle_action, apply_debug_battle_action};
+use battle_queries::legal_action_queries::legal_actions;
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::battle_state::BattleState;
+use core_data::numerics::Energy;
+use core_data::types::PlayerName;
+use petgraph::visit::EdgeRef;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle_state;
+
+#[test]
+fn test_cached_prompt_response_is_added_to_search_tree() {
+    let battle = targeting_position();
+    let play = play_dissolve_action(&battle);
+    let cache = PromptCache::default();
+    let response = cache_first_response(&battle, play, &cache);
+
+    let (graph, root) =
+        uct_search::search_candidate_tree_for_testing(&battle, PlayerName::One, play, 200, &cache);
+    let children = graph.edges(root).map(|edge| edge.weight().action).collect::<Vec<_>>();
+    assert_eq!(children, vec![response], "Prompt node should only follow the cached response");
+    assert!(cache.hits() > 0, "Search should answer the prompt from the cache");
+    assert_tree_consistent(&graph);
+}
+
+#[test]
+fn test_search_tree_stays_consistent_when_prompt_is_resolved_during_search() {
+    let battle = targeting_position();
+    let play = play_dissolve_action(&battle);
+    let cache = PromptCache::default();
+
+    let (graph, _) =
+        uct_search::search_candidate_tree_for_testing(&battle, PlayerName::One, play, 300, &cache);
+    assert!(cache.hits() > 0, "Prompt should be resolved and cached during search");
+    assert_tree_consistent(&graph);
+}
+
+/// Returns a battle in which player one has Test Dissolve in hand and player
+/// two has two characters it could target.
+fn targeting_position() -> BattleState {
+    let mut battle = test_battle_state::create(314159);
+    let (player, opponent) = (PlayerName::One, PlayerName::Two);
+    for action in [
+        DebugBattleAction::MoveHandToDeck { player },
+        DebugBattleAction::MoveHandToDeck { player: opponent },
+        DebugBattleAction::SetEnergy { player, energy: Energy(99) },
+        DebugBattleAction::AddCardToHand { player, card: test_card::TEST_DISSOLVE },
+        DebugBattleAction::AddCardToBattlefield {
+            player: opponent,
+            card: test_card::TEST_VANILLA_CHARACTER,
+        },
+        DebugBattleAction::AddCardToBattlefield {
+            player: opponent,
+            card: test_card::TEST_VANILLA_CHARACTER,
+        },
+    ] {
+        apply_debug_battle_action::execute(&mut battle, player, action);
+    }
+    battle
+}
+
+fn play_dissolve_action(battle: &BattleState) -> BattleAction {
+    let card_id =
+        battle.cards.hand(PlayerName::One).iter().next().expect("Test Dissolve should be in hand");
+    BattleAction::PlayCardFromHand(card_id)
+}
+
+/// Caches the first legal response to the targeting prompt created by
+/// `play`, returning that response.
+fn cache_first_response(
+    battle: &BattleState,
+    play: BattleAction,
+    cache: &PromptCache,
+) -> BattleAction {
+    let mut prompted = battle.clone();
+    apply_battle_action::execute(&mut prompted, PlayerName::One, play);
+    let key = prompt_cache::prompt_key(&prompted).expect("Playing Test Dissolve should prompt");
+    let player = legal_actions::next_to_act(&prompted).expect("A player should be prompted");
+    let response = legal_actions::compute(&prompted, player).all()[0];
+    cache.insert(key, response);
+    response
+}
+
+/// Asserts that every node's children come from a single decision: each
+/// child was created by the same player, by a distinct action, and the
+/// node's tried actions match its outgoing edges.
+fn assert_tree_consistent(graph: &SearchGraph) {
+    for node in graph.node_indices() {
+        let mut actions = graph.edges(node).map(|edge| edge.weight().action).collect::<Vec<_>>();
+        let mut tried = graph[node].tried.clone();
+        actions.sort();
+        tried.sort();
+        assert_eq!(actions, tried, "Tried actions should match the node's children");
+        actions.dedup();
+        assert_eq!(actions.len(), tried.len(), "Each action should have a single child");
+
+        let mut players = graph.edges(node).map(|edge| graph[edge.target()].player);
+        if let Some(first) = players.next() {
+            assert!(
+                players.all(|player| player == first),
+                "All children of a node should be created by the same player"
+            );
+        }
+    }
+}
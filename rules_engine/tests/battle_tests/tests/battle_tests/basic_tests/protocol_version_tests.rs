@@ -0,0 +1,121 @@
+use core_data::display_types::Milliseconds;
+use display::rendering::renderer;
+use display_data::battle_view::DisplayPlayer;
+use display_data::command::{Command, CommandSequence, WaitForInputCommand};
+use display_data::request_data::{ClientCapability, ConnectRequest, PROTOCOL_VERSION};
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn connect_response_reports_protocol_version() {
+    let mut session = TestBattle::builder().session;
+    let response = session.connect();
+    assert_eq!(response.protocol_version, PROTOCOL_VERSION);
+}
+
+#[test]
+fn battle_view_deltas_capability_enables_deltas() {
+    let mut s =
+        TestBattle::builder().capabilities(vec![ClientCapability::BattleViewDeltas]).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let commands = s.last_user_commands.as_ref().expect("No commands found");
+    assert!(
+        commands
+            .groups
+            .iter()
+            .flat_map(|group| &group.commands)
+            .any(|command| matches!(command, Command::UpdateBattleDelta(_))),
+        "battle updates should be sent as deltas"
+    );
+}
+
+#[test]
+fn legacy_client_does_not_receive_notifications() {
+    let mut s = TestBattle::builder().capabilities(vec![]).connect();
+    for _ in 0..9 {
+        s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    }
+    let draw_id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VARIABLE_ENERGY_DRAW);
+
+    s.play_card_from_hand(DisplayPlayer::User, &draw_id);
+    s.click_increment_button(DisplayPlayer::User);
+    s.click_primary_button(DisplayPlayer::User, "Spend");
+
+    assert!(
+        s.find_all_commands(DisplayPlayer::User, |command| match command {
+            Command::DisplayNotification(notification) => Some(notification),
+            _ => None,
+        })
+        .is_empty(),
+        "clients without the notifications capability should not receive toasts"
+    );
+    assert_eq!(s.user_client.cards.user_hand().len(), 10, "game state should still update");
+}
+
+#[test]
+fn unsupported_commands_are_removed() {
+    let commands = CommandSequence::from_vecs(vec![
+        vec![
+            Command::Wait(Milliseconds::new(100)),
+            Command::WaitForInput(WaitForInputCommand { timeout: None }),
+        ],
+        vec![Command::WaitForInput(WaitForInputCommand { timeout: None })],
+        vec![Command::Wait(Milliseconds::new(200))],
+    ]);
+
+    let legacy = renderer::remove_unsupported_commands(commands.clone(), &[]);
+    assert_eq!(group_sizes(&legacy), vec![1, 1]);
+
+    let current =
+        renderer::remove_unsupported_commands(commands, &[ClientCapability::InputBarriers]);
+    assert_eq!(group_sizes(&current), vec![2, 1, 1]);
+}
+
+#[test]
+fn unknown_capabilities_are_ignored() {
+    let request: ConnectRequest = serde_json::from_value(serde_json::json!({
+        "metadata": {
+            "user_id": "00000000-0000-0000-0000-000000000001",
+            "battle_id": null,
+            "request_id": null,
+            "integration_test_id": null
+        },
+        "persistent_data_path": "",
+        "streaming_assets_path": "",
+        "vs_opponent": null,
+        "display_properties": null,
+        "debug_configuration": null,
+        "protocol_version": PROTOCOL_VERSION + 1,
+        "capabilities": ["Notifications", "HolographicCards"]
+    }))
+    .expect("Failed to deserialize connect request");
+
+    assert_eq!(request.capabilities, vec![ClientCapability::Notifications]);
+}
+
+#[test]
+fn legacy_connect_request_has_no_capabilities() {
+    let request: ConnectRequest = serde_json::from_value(serde_json::json!({
+        "metadata": {
+            "user_id": "00000000-0000-0000-0000-000000000001",
+            "battle_id": null,
+            "request_id": null,
+            "integration_test_id": null
+        },
+        "persistent_data_path": "",
+        "streaming_assets_path": "",
+        "vs_opponent": null,
+        "display_properties": null,
+        "debug_configuration": null
+    }))
+    .expect("Failed to deserialize connect request");
+
+    assert_eq!(request.protocol_version, 0);
+    assert!(request.capabilities.is_empty());
+}
+
+fn group_sizes(commands: &CommandSequence) -> Vec<usize> {
+    commands.groups.iter().map(|group| group.commands.len()).collect()
+}
@@ -0,0 +1,86 @@
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use core_data::identifiers::BattleId;
+use core_data::types::PlayerName;
+use display_data::battle_view::{DisplayPlayer, PendingPromptKind};
+use display_data::request_data::{Metadata, PerformActionRequest, ResyncRequest};
+use rules_engine::engine;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session::TestSession;
+use test_utils::session::test_session_prelude::*;
+use uuid::Uuid;
+
+#[test]
+fn resync_returns_current_battle() {
+    let mut s = TestBattle::builder().connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let response = s.resync();
+
+    assert!(response.error.is_none(), "unexpected error: {:?}", response.error);
+    let battle = response.battle.expect("resync should return the battle");
+    assert_eq!(Some(battle.id), s.battle_id);
+    assert!(response.pending_prompt.is_none());
+    assert_eq!(s.user_client.cards.user_battlefield().len(), 1);
+}
+
+#[test]
+fn resync_returns_pending_prompt() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+
+    let prompt = s.resync().pending_prompt.expect("resync should return the pending prompt");
+
+    assert_eq!(prompt.kind, PendingPromptKind::ChooseCharacter);
+    assert!(prompt.description.contains("an enemy."), "got: '{}'", prompt.description);
+}
+
+#[test]
+fn resync_rejects_different_battle() {
+    let s = TestBattle::builder().connect();
+
+    let response = engine::resync_with_provider(s.state_provider.clone(), &ResyncRequest {
+        metadata: Metadata { battle_id: Some(BattleId(Uuid::new_v4())), ..metadata(&s, None) },
+        save_file_id: Some(s.user_id),
+    });
+
+    assert!(response.battle.is_none());
+    assert!(response.error.is_some());
+}
+
+#[test]
+fn actions_accepted_after_resync() {
+    let mut s = TestBattle::builder().connect();
+    s.resync();
+
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert_eq!(s.user_client.cards.user_battlefield().len(), 1);
+}
+
+#[test]
+fn duplicate_action_request_is_not_applied_twice() {
+    let mut s = TestBattle::builder().connect();
+    let request = PerformActionRequest {
+        metadata: metadata(&s, Some(Uuid::new_v4())),
+        action: DebugBattleAction::DrawCard { player: PlayerName::One }.into(),
+        save_file_id: Some(s.user_id),
+        last_response_version: s.last_user_response_version,
+    };
+
+    engine::perform_action_blocking(s.state_provider.clone(), request.clone(), None);
+    let duplicate = engine::perform_action_blocking(s.state_provider.clone(), request, None);
+
+    assert!(
+        !duplicate.user_poll_results.is_empty(),
+        "duplicate request should be answered with the current battle state"
+    );
+    s.resync();
+    assert_eq!(s.user_client.cards.user_hand().len(), 1, "card should only be drawn once");
+}
+
+fn metadata(s: &TestSession, request_id: Option<Uuid>) -> Metadata {
+    Metadata { user_id: s.user_id, battle_id: s.battle_id, request_id, integration_test_id: None }
+}
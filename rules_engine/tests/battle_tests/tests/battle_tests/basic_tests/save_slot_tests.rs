@@ -0,0 +1,46 @@
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::battle::battle_state::BattleState;
+use core_data::identifiers::SaveSlotId;
+use database::save_file::SaveFile;
+use state_provider::state_provider::StateProvider;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session::TestSession;
+
+#[test]
+fn battle_is_created_in_selected_save_slot() {
+    let s = TestBattle::builder().save_slot(SaveSlotId(2)).connect();
+    assert_eq!(s.state_provider.save_slot(s.user_id), SaveSlotId(2));
+    assert!(saved_battle(&s).is_some(), "battle should be saved in the selected slot");
+
+    s.state_provider.select_save_slot(s.user_id, SaveSlotId::default());
+    assert!(saved_battle(&s).is_none(), "default slot should be unused");
+}
+
+#[test]
+fn actions_are_saved_to_selected_save_slot() {
+    let mut s = TestBattle::builder().save_slot(SaveSlotId(1)).connect();
+    let turn_before = saved_battle(&s).expect("battle").turn.turn_id;
+    s.perform_user_action(BattleAction::EndTurn);
+
+    let turn_after = saved_battle(&s).expect("battle").turn.turn_id;
+    assert_ne!(turn_before, turn_after, "end of turn should be saved in the selected slot");
+}
+
+#[test]
+fn save_slots_hold_independent_battles() {
+    let s = TestBattle::builder().save_slot(SaveSlotId(1)).connect();
+    let battle_id = saved_battle(&s).expect("battle").id;
+
+    s.state_provider.select_save_slot(s.user_id, SaveSlotId(2));
+    assert!(saved_battle(&s).is_none(), "a new slot should not contain the first battle");
+
+    s.state_provider.select_save_slot(s.user_id, SaveSlotId(1));
+    assert_eq!(saved_battle(&s).expect("battle").id, battle_id);
+}
+
+fn saved_battle(s: &TestSession) -> Option<BattleState> {
+    let save = s.state_provider.read_save_file(s.user_id).expect("read save")?;
+    match save {
+        SaveFile::V1(v1) => v1.quest?.battle,
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use ai_uct::uct_config::{SearchProgress, SearchStop, SearchTimeLimits, UctConfig};
+use ai_uct::uct_search;
+use battle_queries::legal_action_queries::legal_actions;
+use enumset::EnumSet;
+use test_utils::battle::test_battle_state;
+
+const LIMITS: SearchTimeLimits =
+    SearchTimeLimits { soft: Duration::from_millis(500), hard: Duration::from_millis(1500) };
+
+/// Progress of an unstable search which has not yet reached the soft limit or
+/// its iteration budget.
+fn in_progress() -> SearchProgress {
+    SearchProgress {
+        elapsed: Duration::from_millis(100),
+        completed: 100,
+        iteration_budget: 1000,
+        stable: false,
+        dominant: false,
+        cancelled: false,
+    }
+}
+
+#[test]
+fn test_search_continues_before_limits() {
+    assert_eq!(LIMITS.stop_reason(in_progress()), None);
+    assert_eq!(LIMITS.stop_reason(SearchProgress { stable: true, ..in_progress() }), None);
+}
+
+#[test]
+fn test_search_stops_at_soft_limit_when_stable() {
+    let progress =
+        SearchProgress { elapsed: Duration::from_millis(600), stable: true, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), Some(SearchStop::SoftLimit));
+}
+
+#[test]
+fn test_search_stops_when_one_action_dominates() {
+    let progress = SearchProgress { dominant: true, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), Some(SearchStop::Dominant));
+}
+
+#[test]
+fn test_search_stops_when_cancelled() {
+    let progress = SearchProgress { cancelled: true, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), Some(SearchStop::Cancelled));
+}
+
+#[test]
+fn test_search_stops_at_hard_limit_when_unstable() {
+    let progress =
+        SearchProgress { elapsed: Duration::from_millis(1500), completed: 5000, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), Some(SearchStop::HardLimit));
+}
+
+#[test]
+fn test_search_stops_at_iteration_budget_when_stable() {
+    let progress = SearchProgress { completed: 1000, stable: true, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), Some(SearchStop::IterationBudget));
+}
+
+#[test]
+fn test_unstable_search_extends_past_soft_limit() {
+    let progress = SearchProgress { elapsed: Duration::from_millis(600), ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), None);
+}
+
+#[test]
+fn test_unstable_search_extends_past_iteration_budget() {
+    let progress = SearchProgress { completed: 1200, ..in_progress() };
+    assert_eq!(LIMITS.stop_reason(progress), None);
+}
+
+#[test]
+fn test_time_managed_search_evaluates_every_candidate() {
+    let battle = test_battle_state::create(314159);
+    let player = legal_actions::next_to_act(&battle).expect("A player should be next to act");
+    let config = UctConfig {
+        max_iterations_per_action: 100,
+        max_total_actions_multiplier: 6,
+        iteration_multiplier_override: Some(1.0),
+        single_threaded: true,
+        time_limits: Some(SearchTimeLimits { soft: Duration::ZERO, hard: Duration::ZERO }),
+        max_tree_depth: None,
+        evaluation_noise: 0.0,
+        cancellation: None,
+        pruning_rules: EnumSet::empty(),
+        personality: None,
+    };
+
+    let explanation = uct_search::search_with_explanation(&battle, player, &config);
+    assert!(
+        legal_actions::compute(&battle, player).all().contains(&explanation.action),
+        "Search should select a legal action"
+    );
+    assert!(
+        explanation.alternatives.iter().all(|scored| scored.visit_count >= 1),
+        "Every candidate should be evaluated before the hard limit stops search"
+    );
+}
@@ -1,6 +1,7 @@
-use core_data::numerics::Spark;
+use battle_state::actions::battle_actions::BattleAction;
+use core_data::numerics::{Points, Spark};
 use display_data::battle_view::DisplayPlayer;
-use display_data::card_view::{CardPrefab, CardView};
+use display_data::card_view::{CardFrame, CardPrefab, CardView, DisplayImage};
 use display_data::command::{Command, GameObjectId};
 use display_data::object_position::Position;
 use tabula_generated::test_card;
@@ -256,6 +257,118 @@ fn triggered_ability_token_cards_appear_and_disappear_on_stack() {
     test_helpers::assert_clients_identical(&s);
 }
 
+#[test]
+fn triggered_abilities_from_same_event_are_grouped_on_stack() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+    s.add_to_battlefield(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    s.add_to_battlefield(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    let vanilla_id = s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    let vanilla_name = s.user_client.cards.get_revealed(&vanilla_id).name.clone();
+
+    let groups = s
+        .find_all_commands(DisplayPlayer::User, |command| {
+            if let Command::UpdateBattle(update_cmd) = command { Some(update_cmd) } else { None }
+        })
+        .iter()
+        .flat_map(|update_cmd| &update_cmd.battle.cards)
+        .filter(|card| card.prefab == CardPrefab::Token)
+        .filter_map(|card| card.revealed.as_ref()?.stack_group.clone())
+        .collect::<Vec<_>>();
+
+    assert!(!groups.is_empty(), "Simultaneous triggers should be grouped on the stack");
+    for group in &groups {
+        assert_eq!(group.size, 2, "Group should contain both triggers");
+        assert!(group.index < 2, "Group index should be within the group");
+        assert_eq!(group.group_id, groups[0].group_id, "Triggers should share a group");
+        assert!(
+            group.label.contains(&vanilla_name),
+            "Group label should name the materialized character: {}",
+            group.label
+        );
+    }
+    assert!(
+        groups.iter().any(|group| group.index == 0) && groups.iter().any(|group| group.index == 1),
+        "Each trigger should have its own position in the group"
+    );
+}
+
+#[test]
+fn single_triggered_ability_is_not_grouped() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+    s.add_to_battlefield(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let token_cards = s
+        .find_all_commands(DisplayPlayer::User, |command| {
+            if let Command::UpdateBattle(update_cmd) = command { Some(update_cmd) } else { None }
+        })
+        .iter()
+        .flat_map(|update_cmd| &update_cmd.battle.cards)
+        .filter(|card| card.prefab == CardPrefab::Token)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert!(!token_cards.is_empty(), "Trigger should appear on the stack");
+    assert!(
+        token_cards.iter().all(|card| card
+            .revealed
+            .as_ref()
+            .is_some_and(|revealed| revealed.stack_group.is_none())),
+        "A lone trigger should not be grouped"
+    );
+}
+
+#[test]
+fn triggered_ability_token_uses_ability_token_frame() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+    let trigger_character_id = s.add_to_battlefield(
+        DisplayPlayer::User,
+        test_card::TEST_TRIGGER_GAIN_SPARK_WHEN_MATERIALIZE_ANOTHER_CHARACTER,
+    );
+    let DisplayImage::Sprite(character_image) =
+        s.user_client.cards.get_revealed(&trigger_character_id).image.clone()
+    else {
+        panic!("Character should have a sprite image");
+    };
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let token_cards = s
+        .find_all_commands(DisplayPlayer::User, |command| {
+            if let Command::UpdateBattle(update_cmd) = command { Some(update_cmd) } else { None }
+        })
+        .iter()
+        .flat_map(|update_cmd| &update_cmd.battle.cards)
+        .filter(|card| card.prefab == CardPrefab::Token)
+        .filter_map(|card| card.revealed.clone())
+        .collect::<Vec<_>>();
+
+    assert!(!token_cards.is_empty(), "Trigger should appear on the stack");
+    for token in &token_cards {
+        assert_eq!(token.frame, CardFrame::AbilityToken, "Trigger should use the token frame");
+        let DisplayImage::Sprite(image) = &token.image else {
+            panic!("Trigger should have a sprite image");
+        };
+        assert_eq!(
+            image.sprite, character_image.sprite,
+            "Card without token art should fall back to its own image"
+        );
+    }
+    assert_eq!(
+        s.user_client.cards.get_revealed(&trigger_character_id).frame,
+        CardFrame::Standard,
+        "Source card should keep the standard frame"
+    );
+}
+
 #[test]
 fn triggered_ability_display_effect_command_applied_to_spark_gaining_character() {
     let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
@@ -749,6 +862,11 @@ fn triggered_ability_triggers_on_reclaim_during_enemy_turn() {
 
     assert_eq!(s.user_client.cards.user_void().len(), 1, "reclaim card in void");
     assert_eq!(s.user_client.cards.user_hand().len(), 2, "drew card plus reclaim token");
+    assert!(
+        s.user_client.cards.user_hand().iter().any(|card| card.view.prefab == CardPrefab::Token
+            && card.revealed().frame == CardFrame::Copy),
+        "reclaim token uses the copy frame"
+    );
 
     s.end_turn_remove_opponent_hand(DisplayPlayer::User);
 
@@ -774,3 +892,33 @@ fn triggered_ability_triggers_on_reclaim_during_enemy_turn() {
 
     test_helpers::assert_clients_identical(&s);
 }
+
+#[test]
+fn start_of_turn_trigger_resolves_before_judgment() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+
+    let trigger_character_id =
+        s.create_and_play(DisplayPlayer::User, test_card::TEST_START_OF_TURN_GAIN_SPARK);
+
+    s.perform_user_action(BattleAction::EndTurn);
+
+    let spark_on_enemy_turn =
+        s.user_client.cards.get_revealed(&trigger_character_id).numeric_spark();
+    assert_eq!(
+        spark_on_enemy_turn,
+        Some(Spark(1)),
+        "trigger does not fire at the start of the opponent's turn"
+    );
+
+    s.perform_enemy_action(BattleAction::EndTurn);
+
+    let final_spark = s.user_client.cards.get_revealed(&trigger_character_id).numeric_spark();
+    assert_eq!(final_spark, Some(Spark(2)), "trigger character gained +1 spark");
+    assert_eq!(
+        s.user_client.me.score(),
+        Points(2),
+        "judgment scores the spark gained at the start of the turn"
+    );
+
+    test_helpers::assert_clients_identical(&s);
+}
@@ -1,10 +1,11 @@
 use battle_state::actions::battle_actions::BattleAction;
 use core_data::numerics::{Points, Spark};
 use display_data::battle_view::{DisplayPlayer, DisplayedTurnIndicator};
-use display_data::command::Command;
+use display_data::command::{CinematicType, Command, DisplayCinematicCommand};
 use tabula_generated::test_card;
 use test_utils::battle::test_battle::TestBattle;
 use test_utils::battle::test_player::TestPlayer;
+use test_utils::session::test_session::TestSession;
 use test_utils::session::test_session_prelude::*;
 
 #[test]
@@ -215,6 +216,62 @@ fn judgment_command_shows_total_score_not_points_gained() {
     );
 }
 
+#[test]
+fn judgment_sweep_cinematic_shows_points_gained() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(10).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+
+    let sweep = cinematics(&s)
+        .into_iter()
+        .find(|c| c.cinematic == CinematicType::JudgmentSweep && c.player == DisplayPlayer::User)
+        .expect("Judgment sweep cinematic should be displayed for user");
+    assert_eq!(sweep.points_delta, Points(5), "sweep should show points gained");
+    assert_eq!(sweep.score, Points(15), "sweep should show new total score");
+    assert_eq!(sweep.player_name, "You");
+}
+
+#[test]
+fn turn_start_cinematic_fired_for_each_turn() {
+    let mut s = TestBattle::builder().connect();
+
+    s.perform_user_action(BattleAction::EndTurn);
+    let enemy_turn = cinematics(&s)
+        .into_iter()
+        .find(|c| c.cinematic == CinematicType::TurnStart)
+        .expect("Turn start cinematic should be displayed for enemy turn");
+    assert_eq!(enemy_turn.player, DisplayPlayer::Enemy);
+    assert_eq!(enemy_turn.player_name, "Opponent");
+
+    s.perform_enemy_action(BattleAction::EndTurn);
+    let user_turn = cinematics(&s)
+        .into_iter()
+        .find(|c| c.cinematic == CinematicType::TurnStart)
+        .expect("Turn start cinematic should be displayed for user turn");
+    assert_eq!(user_turn.player, DisplayPlayer::User);
+    assert!(user_turn.turn_number > enemy_turn.turn_number, "turn number should advance");
+}
+
+#[test]
+fn victory_cinematic_fired_when_user_wins() {
+    let mut s =
+        TestBattle::builder().user(TestPlayer::builder().energy(99).points(10).build()).connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    s.perform_user_action(BattleAction::EndTurn);
+    s.perform_enemy_action(BattleAction::EndTurn);
+
+    let victory = cinematics(&s)
+        .into_iter()
+        .find(|c| c.cinematic == CinematicType::Victory)
+        .expect("Victory cinematic should be displayed");
+    assert_eq!(victory.player, DisplayPlayer::User);
+    assert_eq!(victory.score, Points(15));
+}
+
 #[test]
 fn turn_indicator_displays_correct_phase() {
     let mut s = TestBattle::builder().connect();
@@ -271,3 +328,17 @@ fn turn_indicator_displays_correct_phase() {
         "Enemy should have Right indicator during ending phase"
     );
 }
+
+fn cinematics(s: &TestSession) -> Vec<&DisplayCinematicCommand> {
+    s.last_user_commands
+        .as_ref()
+        .expect("No commands found")
+        .groups
+        .iter()
+        .flat_map(|group| &group.commands)
+        .filter_map(|command| match command {
+            Command::DisplayCinematic(cinematic) => Some(cinematic),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,80 @@
+use display_data::battle_view::DisplayPlayer;
+use state_provider::display_state_provider::{DisplayStateProvider, TutorialMessageId};
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+const PLAY_CARD_TEXT: &str = "You can play a card from your hand";
+const END_TURN_TEXT: &str = "You have nothing left to play";
+const FAST_CARD_TEXT: &str = "Fast cards can be played in response";
+
+#[test]
+fn tutorial_messages_not_shown_by_default() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(
+        !s.user_client.interface.screen_overlay_contains(PLAY_CARD_TEXT),
+        "Tutorial messages should only be shown when the tutorial is enabled"
+    );
+}
+
+#[test]
+fn play_card_message_shown_when_card_playable() {
+    let mut s = TestBattle::builder().tutorial().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(
+        s.user_client.interface.screen_overlay_contains(PLAY_CARD_TEXT),
+        "Play card message should be shown when the user can play a card"
+    );
+    assert!(
+        !s.enemy_client.interface.screen_overlay_contains(PLAY_CARD_TEXT),
+        "Enemy did not request the tutorial"
+    );
+}
+
+#[test]
+fn tutorial_messages_shown_in_sequence() {
+    let mut s = TestBattle::builder().tutorial().connect();
+    let card_id = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.play_card_from_hand(DisplayPlayer::User, &card_id);
+
+    assert!(
+        !s.user_client.interface.screen_overlay_contains(PLAY_CARD_TEXT),
+        "Play card message should be dismissed once the user acts"
+    );
+    assert!(
+        s.user_client.interface.screen_overlay_contains(END_TURN_TEXT),
+        "End turn message should follow once the user has nothing left to play"
+    );
+}
+
+#[test]
+fn seen_tutorial_message_not_shown_again() {
+    let mut s = TestBattle::builder().tutorial().connect();
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(
+        s.state_provider.has_seen_tutorial_message(s.user_id, TutorialMessageId::PlayCard),
+        "Play card message should be recorded as seen"
+    );
+    assert!(
+        !s.user_client.interface.screen_overlay_contains(PLAY_CARD_TEXT),
+        "Play card message should not be shown a second time"
+    );
+}
+
+#[test]
+fn fast_card_message_shown_when_user_can_respond() {
+    let mut s = TestBattle::builder().tutorial().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_COUNTERSPELL);
+    s.end_turn_remove_opponent_hand(DisplayPlayer::User);
+    s.create_and_play(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+
+    assert!(
+        s.user_client.interface.screen_overlay_contains(FAST_CARD_TEXT),
+        "Fast card message should be shown when the user can respond with a fast card"
+    );
+}
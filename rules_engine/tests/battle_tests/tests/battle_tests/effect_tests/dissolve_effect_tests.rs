@@ -86,6 +86,35 @@ fn dissolve_with_multiple_targets() {
     assert!(s.user_client.cards.enemy_battlefield().contains(&target2_id), "other target remains");
 }
 
+#[test]
+fn dissolve_prompt_displays_legal_targets() {
+    let mut s = TestBattle::builder().connect();
+    let target1_id = s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    let target2_id = s.add_to_battlefield(DisplayPlayer::Enemy, test_card::TEST_VANILLA_CHARACTER);
+    let ally_id = s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+
+    let dissolve_id = s.create_and_play(DisplayPlayer::User, test_card::TEST_DISSOLVE);
+
+    let targeting = s.user_client.targeting.as_ref().expect("targeting should be displayed");
+    assert_eq!(targeting.source, Some(dissolve_id), "test dissolve should be the source");
+    assert!(targeting.legal_targets.contains(&target1_id), "first enemy is a legal target");
+    assert!(targeting.legal_targets.contains(&target2_id), "second enemy is a legal target");
+    assert!(!targeting.legal_targets.contains(&ally_id), "ally is not a legal target");
+    assert_eq!(targeting.arrows.len(), 2, "arrow to each legal target");
+    assert!(
+        s.user_client.cards.get_revealed(&target1_id).is_legal_target,
+        "enemy card should be marked as a legal target"
+    );
+    assert!(
+        !s.user_client.cards.get_revealed(&ally_id).is_legal_target,
+        "ally card should not be marked as a legal target"
+    );
+
+    s.click_card(DisplayPlayer::User, &target1_id);
+
+    assert!(s.user_client.targeting.is_none(), "targeting cleared after choosing a target");
+}
+
 #[test]
 fn dissolve_card_command() {
     let mut s = TestBattle::builder().connect();
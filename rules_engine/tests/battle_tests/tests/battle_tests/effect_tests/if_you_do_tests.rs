@@ -0,0 +1,74 @@
+use battle_state::actions::battle_actions::BattleAction;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use core_data::types::PlayerName;
+use display_data::battle_view::DisplayPlayer;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn if_you_do_accept_applies_follow_up() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISCARD_IF_YOU_DO_DRAW);
+
+    s.click_primary_button(DisplayPlayer::User, "Accept");
+
+    assert_eq!(
+        s.user_client.cards.user_void().len(),
+        2,
+        "void should contain the discarded card and the event"
+    );
+    assert_eq!(s.user_client.cards.user_hand().len(), 2, "user should draw 2 cards");
+}
+
+#[test]
+fn if_you_do_decline_skips_follow_up() {
+    let mut s = TestBattle::builder().connect();
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISCARD_IF_YOU_DO_DRAW);
+
+    s.click_secondary_button(DisplayPlayer::User, "Decline");
+
+    assert_eq!(s.user_client.cards.user_void().len(), 1, "only the event should be in the void");
+    assert_eq!(s.user_client.cards.user_hand().len(), 1, "user should keep their card");
+}
+
+#[test]
+fn if_you_do_follow_up_waits_for_discard_prompt() {
+    let mut s = TestBattle::builder().connect();
+    let card1 = s.add_to_hand(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_hand(DisplayPlayer::User, test_card::TEST_FAST_ACTIVATED_ABILITY_DRAW_CARD_CHARACTER);
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISCARD_IF_YOU_DO_DRAW);
+
+    s.click_primary_button(DisplayPlayer::User, "Accept");
+    assert_eq!(
+        s.user_client.cards.user_hand().len(),
+        2,
+        "follow-up should not resolve before a card is discarded"
+    );
+
+    s.click_card(DisplayPlayer::User, &card1);
+    s.click_primary_button(DisplayPlayer::User, "Submit");
+
+    assert!(!s.user_client.cards.user_hand().contains(&card1), "card1 should be discarded");
+    assert_eq!(s.user_client.cards.user_hand().len(), 3, "user should draw 2 cards");
+}
+
+#[test]
+fn if_you_do_accept_with_nothing_to_discard_skips_follow_up() {
+    let mut s = TestBattle::builder().connect();
+    s.perform_user_action(BattleAction::Debug(DebugBattleAction::MoveHandToDeck {
+        player: PlayerName::One,
+    }));
+    s.create_and_play(DisplayPlayer::User, test_card::TEST_DISCARD_IF_YOU_DO_DRAW);
+
+    s.click_primary_button(DisplayPlayer::User, "Accept");
+
+    assert_eq!(
+        s.user_client.cards.user_hand().len(),
+        0,
+        "user should not draw without discarding a card"
+    );
+    assert_eq!(s.user_client.cards.user_void().len(), 1);
+}
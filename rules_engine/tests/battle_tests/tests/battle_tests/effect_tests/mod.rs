@@ -5,6 +5,8 @@ mod draw_card_effect_tests;
 mod foresee_tests;
 mod gain_energy_effect_tests;
 mod gain_points_effect_tests;
+mod if_you_do_tests;
+mod prevent_dissolve_tests;
 mod put_cards_from_deck_into_void_tests;
 mod return_from_void_to_hand_tests;
 mod return_to_hand_effect_tests;
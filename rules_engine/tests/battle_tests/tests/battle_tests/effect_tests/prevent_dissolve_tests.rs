@@ -0,0 +1,75 @@
+use ability_data::duration::Duration;
+use battle_mutations::actions::apply_debug_battle_action;
+use battle_mutations::card_mutations::move_card;
+use battle_queries::battle_card_queries::card;
+use battle_queries::card_ability_queries::effect_queries;
+use battle_state::actions::debug_battle_action::DebugBattleAction;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::card_id::CharacterId;
+use battle_state::battle_cards::ability_state::TimedEffect;
+use battle_state::battle_cards::battle_card_state::{CardObjectId, ObjectId};
+use battle_state::core::effect_source::EffectSource;
+use core_data::types::PlayerName;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle_state;
+
+const PLAYER: PlayerName = PlayerName::One;
+
+#[test]
+fn while_in_play_protection_is_removed_when_character_leaves_play() {
+    let mut battle = test_battle_state::create(314159);
+    let id = add_character(&mut battle);
+    prevent_dissolve(&mut battle, id, card::get(&battle, id).object_id);
+    assert!(effect_queries::prevent_dissolved_set(&battle).contains(id));
+
+    move_card::from_battlefield_to_void(
+        &mut battle,
+        EffectSource::Game { controller: PLAYER },
+        PLAYER,
+        id,
+    );
+
+    assert!(
+        battle.ability_state.prevent_dissolved.is_empty(),
+        "protection should be removed once the character leaves play"
+    );
+}
+
+#[test]
+fn protection_does_not_apply_to_a_new_object_for_the_same_card() {
+    let mut battle = test_battle_state::create(314159);
+    let id = add_character(&mut battle);
+    let old_object_id = card::get(&battle, id).object_id;
+    let source = EffectSource::Game { controller: PLAYER };
+    let void_id = move_card::from_battlefield_to_void(&mut battle, source, PLAYER, id);
+    let stack_id = move_card::from_void_to_stack(&mut battle, source, PLAYER, void_id);
+    let id = move_card::from_stack_to_battlefield(&mut battle, source, PLAYER, stack_id);
+    prevent_dissolve(&mut battle, id, old_object_id);
+
+    assert!(
+        !effect_queries::prevent_dissolved_set(&battle).contains(id),
+        "protection for the previous object should not apply"
+    );
+    assert!(!effect_queries::should_prevent_dissolve(&battle, id));
+}
+
+/// Adds a character to player one's battlefield and returns its ID.
+fn add_character(battle: &mut BattleState) -> CharacterId {
+    apply_debug_battle_action::execute(
+        battle,
+        PLAYER,
+        DebugBattleAction::AddCardToBattlefield {
+            player: PLAYER,
+            card: test_card::TEST_VANILLA_CHARACTER,
+        },
+    );
+    battle.cards.battlefield(PLAYER).iter().last().expect("character should be on battlefield")
+}
+
+fn prevent_dissolve(battle: &mut BattleState, id: CharacterId, object_id: ObjectId) {
+    battle.ability_state.prevent_dissolved.push(TimedEffect {
+        value: CardObjectId { card_id: id, object_id },
+        duration: Duration::WhileInPlay,
+        controller: PLAYER,
+    });
+}
@@ -0,0 +1,63 @@
+use core_data::numerics::{Energy, Spark};
+use display_data::battle_view::DisplayPlayer;
+use tabula_generated::test_card;
+use test_utils::battle::test_battle::TestBattle;
+use test_utils::battle::test_player::TestPlayer;
+use test_utils::session::test_session_prelude::*;
+
+#[test]
+fn conditional_play_from_void_not_playable_without_ally() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+
+    let card_id =
+        s.add_to_void(DisplayPlayer::User, test_card::TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY);
+    assert!(
+        !s.user_client.cards.user_hand().contains(&format!("V{card_id}")),
+        "card should not be playable from the void without an allied Survivor"
+    );
+}
+
+#[test]
+fn conditional_play_from_void_playable_with_ally() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+
+    let card_id =
+        s.add_to_void(DisplayPlayer::User, test_card::TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY);
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY);
+
+    s.play_card_from_void(DisplayPlayer::User, &card_id);
+
+    assert!(!s.user_client.cards.user_void().contains(&card_id), "card left the void");
+    assert_eq!(s.user_client.cards.user_battlefield().len(), 2, "card played from the void");
+    assert_eq!(s.user_client.me.energy(), Energy(98), "paid the ability's cost");
+}
+
+#[test]
+fn conditional_spark_bonus_applies_once_void_count_is_met() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_SPARK_BONUS_WITH_CARDS_IN_VOID);
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_PLAY_FROM_HAND_OR_VOID_WITH_ALLY);
+    s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert_eq!(s.user_client.me.total_spark(), Spark(3), "no bonus with 2 cards in void");
+
+    s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    assert_eq!(
+        s.user_client.me.total_spark(),
+        Spark(5),
+        "allied Survivor gains +2 spark once 3 cards are in the void"
+    );
+}
+
+#[test]
+fn conditional_spark_bonus_does_not_apply_to_other_character_types() {
+    let mut s = TestBattle::builder().user(TestPlayer::builder().energy(99).build()).connect();
+
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_SPARK_BONUS_WITH_CARDS_IN_VOID);
+    s.add_to_battlefield(DisplayPlayer::User, test_card::TEST_SPARK_BONUS_WITH_CARDS_IN_VOID);
+    for _ in 0..3 {
+        s.add_to_void(DisplayPlayer::User, test_card::TEST_VANILLA_CHARACTER);
+    }
+    assert_eq!(s.user_client.me.total_spark(), Spark(2), "Warriors do not gain the bonus");
+}
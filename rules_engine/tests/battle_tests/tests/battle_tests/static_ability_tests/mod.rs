@@ -1 +1,2 @@
+pub mod conditional_static_ability_tests;
 pub mod reclaim_tests;
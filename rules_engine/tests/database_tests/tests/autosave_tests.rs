@@ -0,0 +1,135 @@
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use core_data::identifiers::{BaseCardId, SaveSlotId, UserId};
+use database::autosave::{self, AutosavePolicy};
+use database::collection::{Collection, OwnedCard};
+use database::match_history::MatchHistory;
+use database::save_file::{SaveFile, SaveFileV1};
+use database::save_file_encoding::SaveFileOptions;
+use database::save_slots;
+use uuid::Uuid;
+
+#[test]
+fn policy_triggers_on_interval_or_phase_change() {
+    let policy = AutosavePolicy { action_interval: 3, on_phase_change: true, rotation_size: 2 };
+    assert!(!policy.should_autosave(1, false));
+    assert!(policy.should_autosave(1, true));
+    assert!(policy.should_autosave(3, false));
+
+    let phase_only = AutosavePolicy { action_interval: 0, ..policy };
+    assert!(!phase_only.should_autosave(100, false));
+    assert!(phase_only.should_autosave(1, true));
+
+    let disabled = AutosavePolicy { rotation_size: 0, ..policy };
+    assert!(!disabled.should_autosave(3, true));
+}
+
+#[test]
+fn autosaves_rotate_through_slots() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let policy = AutosavePolicy { rotation_size: 3, ..AutosavePolicy::default() };
+    let indices = (1..=4).map(|tag| write_at(dir.path(), tag, policy)).collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 1, 2, 0]);
+
+    for index in 0..3 {
+        assert!(autosave::autosave_path(dir.path(), user_id(), slot(), index).exists());
+    }
+    assert!(!autosave::autosave_path(dir.path(), user_id(), slot(), 3).exists());
+    assert_eq!(latest_tag(dir.path(), policy), Some(4));
+}
+
+#[test]
+fn read_latest_falls_back_when_newest_is_corrupted() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let policy = AutosavePolicy { rotation_size: 3, ..AutosavePolicy::default() };
+    write_at(dir.path(), 1, policy);
+    let newest = write_at(dir.path(), 2, policy);
+
+    let path = autosave::autosave_path(dir.path(), user_id(), slot(), newest);
+    let data = fs::read(&path).expect("read autosave");
+    fs::write(&path, &data[..20]).expect("truncate autosave");
+    set_modified(dir.path(), newest, 2);
+
+    assert_eq!(latest_tag(dir.path(), policy), Some(1));
+}
+
+#[test]
+fn read_latest_reports_error_when_all_autosaves_are_corrupted() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let policy = AutosavePolicy { rotation_size: 3, ..AutosavePolicy::default() };
+    let index = write_at(dir.path(), 1, policy);
+    fs::write(autosave::autosave_path(dir.path(), user_id(), slot(), index), b"DTSV")
+        .expect("corrupt autosave");
+
+    let errors = autosave::read_latest_autosave(dir.path(), user_id(), slot(), policy)
+        .expect_err("corrupted autosave should fail to load");
+    assert_eq!(errors[0].name, "Save file is corrupted");
+}
+
+#[test]
+fn read_latest_returns_none_without_autosaves() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    assert_eq!(latest_tag(dir.path(), AutosavePolicy::default()), None);
+}
+
+#[test]
+fn autosaves_are_not_listed_as_save_slots() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_at(dir.path(), 1, AutosavePolicy::default());
+    assert!(save_slots::list_profiles(dir.path()).expect("list profiles").is_empty());
+}
+
+fn user_id() -> UserId {
+    UserId(Uuid::from_u128(1))
+}
+
+fn slot() -> SaveSlotId {
+    SaveSlotId(1)
+}
+
+/// Writes an autosave identified by `tag` and marks it as modified `tag`
+/// seconds after the epoch, so rotation order does not depend on file system
+/// timestamp resolution.
+fn write_at(dir: &Path, tag: u32, policy: AutosavePolicy) -> u32 {
+    let index = autosave::write_autosave(
+        dir,
+        &tagged_save(tag),
+        slot(),
+        policy,
+        SaveFileOptions::default(),
+    )
+    .expect("write autosave");
+    set_modified(dir, index, tag);
+    index
+}
+
+fn set_modified(dir: &Path, index: u32, seconds: u32) {
+    File::options()
+        .write(true)
+        .open(autosave::autosave_path(dir, user_id(), slot(), index))
+        .expect("open autosave")
+        .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(u64::from(seconds)))
+        .expect("set modified time");
+}
+
+fn latest_tag(dir: &Path, policy: AutosavePolicy) -> Option<u32> {
+    let save =
+        autosave::read_latest_autosave(dir, user_id(), slot(), policy).expect("read autosave")?;
+    let SaveFile::V1(v1) = save;
+    v1.collection.cards.values().next().map(|card| card.count)
+}
+
+fn tagged_save(tag: u32) -> SaveFile {
+    let mut collection = Collection::default();
+    collection
+        .cards
+        .insert(BaseCardId(Uuid::from_u128(1)), OwnedCard { count: tag, ..OwnedCard::default() });
+    SaveFile::V1(Box::new(SaveFileV1 {
+        id: user_id(),
+        quest: None,
+        collection,
+        match_history: MatchHistory::default(),
+    }))
+}
@@ -0,0 +1,5 @@
+mod autosave_tests;
+mod save_file_encoding_tests;
+mod save_file_migration_tests;
+mod save_slots_tests;
+mod save_sync_tests;
@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use core_data::identifiers::UserId;
+use database::save_file::SaveFile;
+use database::save_file_encoding::{
+    self, ENVELOPE_PREFIX, HEADER_LENGTH, SaveFileCompression, SaveFileEncodingError,
+    SaveFileOptions,
+};
+use database::save_file_io;
+use uuid::Uuid;
+
+const FIXTURE_USER_ID: &str = "6f1c2a9e-4b7d-4e0a-9c3f-8d2b5e1a7c40";
+
+#[test]
+fn compressed_save_round_trips() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let save = fixture_save();
+    write_save(dir.path(), &save, SaveFileCompression::Zstd);
+
+    let data = fs::read(save_file_io::save_path(dir.path(), save.id())).expect("read file");
+    let header = save_file_encoding::read_header(&data).expect("read header").expect("header");
+    assert_eq!(header.compression, SaveFileCompression::Zstd);
+    assert_eq!(read_save(dir.path()).id(), save.id());
+}
+
+#[test]
+fn uncompressed_save_round_trips() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let save = fixture_save();
+    write_save(dir.path(), &save, SaveFileCompression::None);
+
+    let data = fs::read(save_file_io::save_path(dir.path(), save.id())).expect("read file");
+    let header = save_file_encoding::read_header(&data).expect("read header").expect("header");
+    assert_eq!(header.compression, SaveFileCompression::None);
+    assert_eq!(read_save(dir.path()).id(), save.id());
+}
+
+#[test]
+fn uncompressed_save_is_valid_json() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let save = fixture_save();
+    write_save(dir.path(), &save, SaveFileCompression::None);
+
+    let data = fs::read(save_file_io::save_path(dir.path(), save.id())).expect("read file");
+    let envelope: serde_json::Value = serde_json::from_slice(&data).expect("valid JSON");
+    let checksum = envelope["crc32"].as_str().expect("checksum");
+    assert_eq!(u32::from_str_radix(checksum, 16), Ok(header_checksum(&data)));
+    assert!(envelope["save"]["V1"].is_object(), "save should be embedded as JSON");
+}
+
+#[test]
+fn saves_are_uncompressed_by_default() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let save = fixture_save();
+    save_file_io::write_save_to_dir(dir.path(), &save).expect("write save");
+
+    let data = fs::read(save_file_io::save_path(dir.path(), save.id())).expect("read file");
+    let header = save_file_encoding::read_header(&data).expect("read header").expect("header");
+    assert_eq!(header.compression, SaveFileCompression::None);
+    assert!(data.starts_with(ENVELOPE_PREFIX.as_bytes()), "save should use a JSON envelope");
+}
+
+#[test]
+fn compression_reduces_save_size() {
+    let json = fs::read(fixture_path()).expect("read fixture");
+    let repeated = format!("[{}]", vec![String::from_utf8_lossy(&json); 50].join(","));
+    let compressed = save_file_encoding::encode(
+        repeated.as_bytes(),
+        SaveFileOptions { compression: SaveFileCompression::Zstd },
+    )
+    .expect("encode");
+    assert!(compressed.len() * 10 < repeated.len(), "compressed size {}", compressed.len());
+    assert_eq!(save_file_encoding::decode(&compressed).expect("decode"), repeated.as_bytes());
+}
+
+#[test]
+fn legacy_json_save_loads() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = save_file_io::save_path(dir.path(), fixture_user_id());
+    fs::copy(fixture_path(), &path).expect("copy fixture");
+    assert_eq!(save_file_encoding::read_header(&fs::read(&path).expect("read")), Ok(None));
+    assert_eq!(read_save(dir.path()).id(), fixture_user_id());
+}
+
+#[test]
+fn corrupted_payload_returns_error() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_save(dir.path(), &fixture_save(), SaveFileCompression::Zstd);
+    let path = save_file_io::save_path(dir.path(), fixture_user_id());
+    let mut data = fs::read(&path).expect("read file");
+    data[HEADER_LENGTH + 4] ^= 0xFF;
+    fs::write(&path, data).expect("write file");
+
+    let errors = save_file_io::read_save_from_dir(dir.path(), fixture_user_id())
+        .expect_err("corrupted save should fail to load");
+    assert_eq!(errors[0].name, "Save file is corrupted");
+}
+
+#[test]
+fn truncated_save_returns_error() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_save(dir.path(), &fixture_save(), SaveFileCompression::None);
+    let path = save_file_io::save_path(dir.path(), fixture_user_id());
+    let data = fs::read(&path).expect("read file");
+    fs::write(&path, &data[..data.len() / 2]).expect("write file");
+
+    let errors = save_file_io::read_save_from_dir(dir.path(), fixture_user_id())
+        .expect_err("truncated save should fail to load");
+    assert_eq!(errors[0].name, "Save file is corrupted");
+}
+
+#[test]
+fn decode_reports_checksum_mismatch() {
+    let mut data = save_file_encoding::encode(
+        br#"{"V1": {}}"#,
+        SaveFileOptions { compression: SaveFileCompression::None },
+    )
+    .expect("encode");
+    let last_payload_byte = data.len() - 2;
+    data[last_payload_byte] = b']';
+    assert!(matches!(
+        save_file_encoding::decode(&data),
+        Err(SaveFileEncodingError::ChecksumMismatch { .. })
+    ));
+}
+
+#[test]
+fn decode_reports_malformed_envelope() {
+    let data = format!(r#"{ENVELOPE_PREFIX}1,"crc32":"00000000","save":{{}}}}"#);
+    assert_eq!(
+        save_file_encoding::decode(data.as_bytes()),
+        Err(SaveFileEncodingError::MalformedEnvelope)
+    );
+}
+
+#[test]
+fn decode_reports_unknown_compression() {
+    let mut data = save_file_encoding::encode(
+        br#"{"V1": {}}"#,
+        SaveFileOptions { compression: SaveFileCompression::Zstd },
+    )
+    .expect("encode");
+    data[5] = 9;
+    assert_eq!(
+        save_file_encoding::decode(&data),
+        Err(SaveFileEncodingError::UnknownCompression(9))
+    );
+}
+
+fn fixture_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join("save_v1.json")
+}
+
+fn header_checksum(data: &[u8]) -> u32 {
+    save_file_encoding::read_header(data).expect("read header").expect("header").checksum
+}
+
+fn fixture_user_id() -> UserId {
+    UserId(Uuid::parse_str(FIXTURE_USER_ID).expect("uuid"))
+}
+
+fn fixture_save() -> SaveFile {
+    save_file_io::parse_save(&fs::read(fixture_path()).expect("read fixture")).expect("parse")
+}
+
+fn write_save(dir: &Path, save: &SaveFile, compression: SaveFileCompression) {
+    save_file_io::write_save_to_dir_with_options(dir, save, SaveFileOptions { compression })
+        .expect("write save");
+}
+
+fn read_save(dir: &Path) -> SaveFile {
+    save_file_io::read_save_from_dir(dir, fixture_user_id())
+        .expect("read save")
+        .expect("save should exist")
+}
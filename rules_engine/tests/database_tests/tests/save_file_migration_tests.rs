@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ability_data::ability::Ability;
+use ability_data::duration::Duration;
+use ability_data::effect::Effect;
+use ability_data::standard_effect::StandardEffect;
+use battle_state::battle::battle_state::BattleState;
+use battle_state::battle::battle_turn_phase::BattleTurnPhase;
+use battle_state::battle::card_id::{CardId, CharacterId};
+use core_data::identifiers::{BaseCardId, UserId};
+use core_data::numerics::TurnId;
+use core_data::types::PlayerName;
+use database::save_file::SaveFile;
+use database::save_file_encoding;
+use database::save_file_io;
+use database::save_file_migration::{
+    self, CURRENT_VERSION, SaveFileMigration, SaveFileMigrationError,
+};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+const FIXTURE_USER_ID: &str = "6f1c2a9e-4b7d-4e0a-9c3f-8d2b5e1a7c40";
+const FIXTURE_CARD_ID: &str = "253ee0ca-f973-4d9f-ad37-abe548bc674f";
+
+#[test]
+fn fixture_exists_for_every_version() {
+    for version in 1..=CURRENT_VERSION {
+        assert!(fixture_path(version).exists(), "missing fixture save for version {version}");
+        assert!(
+            battle_fixture_path(version).exists(),
+            "missing fixture battle save for version {version}"
+        );
+    }
+}
+
+#[test]
+fn every_fixture_version_loads() {
+    for version in 1..=CURRENT_VERSION {
+        let save = save_file_io::parse_save(&read_fixture(version))
+            .unwrap_or_else(|e| panic!("failed to load version {version} fixture: {e:?}"));
+        assert_fixture_contents(&save);
+    }
+}
+
+#[test]
+fn every_fixture_version_round_trips() {
+    for version in 1..=CURRENT_VERSION {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let save = save_file_io::parse_save(&read_fixture(version)).expect("parse fixture");
+        save_file_io::write_save_to_dir(dir.path(), &save).expect("write save");
+        let reloaded = save_file_io::read_save_from_dir(dir.path(), save.id())
+            .expect("read save")
+            .expect("save should exist");
+        assert_fixture_contents(&reloaded);
+
+        let written = fs::read(save_file_io::save_path(dir.path(), save.id())).expect("read file");
+        let json = save_file_encoding::decode(&written).expect("decode");
+        assert_eq!(
+            save_file_migration::read_version(&json).expect("read version"),
+            Some(CURRENT_VERSION),
+            "saves should always be written at the current version"
+        );
+    }
+}
+
+#[test]
+fn every_battle_fixture_version_loads() {
+    for version in 1..=CURRENT_VERSION {
+        let save = save_file_io::parse_save(&read_battle_fixture(version))
+            .unwrap_or_else(|e| panic!("failed to load version {version} battle fixture: {e:?}"));
+        assert_fixture_contents(&save);
+        let battle = fixture_battle(save);
+        assert_eq!(battle.turn.turn_id, TurnId(4));
+        assert_eq!(battle.turn.active_player, PlayerName::One);
+        assert_eq!(battle.phase, BattleTurnPhase::Main);
+    }
+}
+
+#[test]
+fn v1_battle_fixture_reads_effects_without_duration() {
+    let battle = fixture_battle(
+        save_file_io::parse_save(&read_battle_fixture(1)).expect("parse battle fixture"),
+    );
+    let prevent_dissolve = battle
+        .players
+        .player(PlayerName::One)
+        .quest
+        .deck
+        .cards
+        .iter()
+        .flat_map(|card| &card.abilities)
+        .find_map(|ability| match ability {
+            Ability::Event(event) => match &event.effect {
+                Effect::Effect(effect @ StandardEffect::PreventDissolve { .. }) => Some(effect),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("fixture deck should contain a prevent dissolve event");
+    assert!(matches!(prevent_dissolve, StandardEffect::PreventDissolve {
+        duration: Duration::ThisTurn,
+        ..
+    }));
+}
+
+#[test]
+fn v1_battle_fixture_upgrades_prevent_dissolved_this_turn() {
+    let mut battle = fixture_battle(
+        save_file_io::parse_save(&read_battle_fixture(1)).expect("parse battle fixture"),
+    );
+    assert!(battle.ability_state.prevent_dissolved.is_empty());
+
+    battle.ability_state.upgrade_saved_effects(|id| battle.cards[id].owner);
+    let [effect] = battle.ability_state.prevent_dissolved.as_slice() else {
+        panic!("expected one prevent dissolved effect");
+    };
+    assert_eq!(effect.value.card_id, CharacterId(CardId(14)));
+    assert_eq!(effect.duration, Duration::ThisTurn);
+    assert_eq!(effect.controller, PlayerName::One);
+    assert!(battle.ability_state.until_end_of_turn.saved_prevent_dissolved.is_empty());
+}
+
+#[test]
+fn gains_reclaim_reads_this_turn_flag() {
+    let gains_reclaim = |this_turn: bool| {
+        serde_json::from_value::<StandardEffect>(json!({"GainsReclaim": {
+            "target": "This",
+            "count": "All",
+            "this_turn": this_turn,
+            "cost": null
+        }}))
+        .expect("deserialize effect")
+    };
+    assert!(matches!(gains_reclaim(true), StandardEffect::GainsReclaim {
+        duration: Some(Duration::ThisTurn),
+        ..
+    }));
+    assert!(matches!(gains_reclaim(false), StandardEffect::GainsReclaim { duration: None, .. }));
+}
+
+#[test]
+fn read_version_returns_tagged_version() {
+    assert_eq!(save_file_migration::read_version(&read_fixture(1)).expect("read version"), Some(1));
+    assert_eq!(save_file_migration::read_version(br#"{"V7": {}}"#).expect("read version"), Some(7));
+    assert_eq!(save_file_migration::read_version(br#"{"id": 1}"#).expect("read version"), None);
+}
+
+#[test]
+fn migrate_current_version_is_unchanged() {
+    let save: Value = serde_json::from_slice(&read_fixture(CURRENT_VERSION)).expect("parse");
+    let migrated = save_file_migration::migrate(save.clone()).expect("migrate");
+    assert_eq!(migrated, save);
+}
+
+#[test]
+fn migrate_to_chains_migrations_in_order() {
+    let migrations = [
+        SaveFileMigration { from_version: 2, migrate: rename_collection },
+        SaveFileMigration { from_version: 1, migrate: add_settings },
+    ];
+    let migrated =
+        save_file_migration::migrate_to(json!({"V1": {"collection": {}}}), &migrations, 3)
+            .expect("migrate");
+    assert_eq!(migrated, json!({"V3": {"owned_cards": {}, "settings": {"volume": 100}}}));
+}
+
+#[test]
+fn migrate_to_starts_from_intermediate_version() {
+    let migrations = [
+        SaveFileMigration { from_version: 1, migrate: add_settings },
+        SaveFileMigration { from_version: 2, migrate: rename_collection },
+    ];
+    let migrated = save_file_migration::migrate_to(
+        json!({"V2": {"collection": {}, "settings": {"volume": 50}}}),
+        &migrations,
+        3,
+    )
+    .expect("migrate");
+    assert_eq!(migrated, json!({"V3": {"owned_cards": {}, "settings": {"volume": 50}}}));
+}
+
+#[test]
+fn migrate_to_reports_missing_migration() {
+    let migrations = [SaveFileMigration { from_version: 1, migrate: add_settings }];
+    let result = save_file_migration::migrate_to(json!({"V1": {}}), &migrations, 3);
+    assert_eq!(result, Err(SaveFileMigrationError::MissingMigration { from_version: 2 }));
+}
+
+#[test]
+fn migrate_to_reports_failed_migration() {
+    let migrations = [SaveFileMigration { from_version: 1, migrate: add_settings }];
+    let result = save_file_migration::migrate_to(json!({"V1": []}), &migrations, 2);
+    assert_eq!(
+        result,
+        Err(SaveFileMigrationError::MigrationFailed {
+            from_version: 1,
+            message: "expected object".to_string()
+        })
+    );
+}
+
+#[test]
+fn migrate_rejects_newer_version() {
+    let result = save_file_migration::migrate(json!({"V99": {}}));
+    assert_eq!(result, Err(SaveFileMigrationError::UnsupportedVersion { version: 99 }));
+}
+
+#[test]
+fn migrate_rejects_untagged_save() {
+    assert_eq!(
+        save_file_migration::migrate(json!({"id": FIXTURE_USER_ID})),
+        Err(SaveFileMigrationError::MissingVersion)
+    );
+    assert_eq!(
+        save_file_migration::migrate(json!({"V1": {}, "V2": {}})),
+        Err(SaveFileMigrationError::MissingVersion)
+    );
+}
+
+#[test]
+fn loading_newer_version_returns_error() {
+    let errors = save_file_io::parse_save(br#"{"V99": {}}"#).expect_err("should fail");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].name, "Failed to migrate save file");
+}
+
+fn fixture_path(version: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(format!("save_v{version}.json"))
+}
+
+fn battle_fixture_path(version: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(format!("save_v{version}_battle.json"))
+}
+
+fn read_fixture(version: u32) -> Vec<u8> {
+    fs::read(fixture_path(version)).expect("read fixture")
+}
+
+fn read_battle_fixture(version: u32) -> Vec<u8> {
+    fs::read(battle_fixture_path(version)).expect("read battle fixture")
+}
+
+fn fixture_battle(save: SaveFile) -> BattleState {
+    let SaveFile::V1(v1) = save;
+    v1.quest.and_then(|quest| quest.battle).expect("fixture should contain a battle")
+}
+
+fn assert_fixture_contents(save: &SaveFile) {
+    assert_eq!(save.id(), UserId(Uuid::parse_str(FIXTURE_USER_ID).expect("uuid")));
+    assert_eq!(
+        save.collection().count(BaseCardId(Uuid::parse_str(FIXTURE_CARD_ID).expect("uuid"))),
+        2
+    );
+    assert_eq!(save.match_history().entries.len(), 1);
+}
+
+fn add_settings(mut save: Value) -> Result<Value, String> {
+    let Some(object) = save.as_object_mut() else {
+        return Err("expected object".to_string());
+    };
+    object.insert("settings".to_string(), json!({"volume": 100}));
+    Ok(save)
+}
+
+fn rename_collection(mut save: Value) -> Result<Value, String> {
+    let Some(object) = save.as_object_mut() else {
+        return Err("expected object".to_string());
+    };
+    let collection = object.remove("collection").ok_or("missing collection")?;
+    object.insert("owned_cards".to_string(), collection);
+    Ok(save)
+}
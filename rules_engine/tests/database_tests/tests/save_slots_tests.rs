@@ -0,0 +1,101 @@
+use std::fs;
+
+use core_data::identifiers::{SaveSlotId, UserId};
+use database::save_file_io;
+use database::save_slots;
+use uuid::Uuid;
+
+#[test]
+fn create_profile_adds_default_slot() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_id = save_slots::create_profile(dir.path()).expect("create profile");
+
+    assert_eq!(save_slots::list_profiles(dir.path()).expect("list profiles"), vec![user_id]);
+    assert_eq!(
+        save_slots::list_slots(dir.path(), user_id).expect("list slots"),
+        vec![SaveSlotId::default()]
+    );
+    assert!(save_file_io::read_save_from_dir(dir.path(), user_id).expect("read").is_some());
+}
+
+#[test]
+fn create_slot_uses_lowest_unused_slot() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_id = save_slots::create_profile(dir.path()).expect("create profile");
+    assert_eq!(save_slots::create_slot(dir.path(), user_id).expect("create"), SaveSlotId(1));
+    assert_eq!(save_slots::create_slot(dir.path(), user_id).expect("create"), SaveSlotId(2));
+
+    save_slots::delete_slot(dir.path(), user_id, SaveSlotId(1)).expect("delete");
+    assert_eq!(
+        save_slots::list_slots(dir.path(), user_id).expect("list slots"),
+        vec![SaveSlotId(0), SaveSlotId(2)]
+    );
+    assert_eq!(save_slots::create_slot(dir.path(), user_id).expect("create"), SaveSlotId(1));
+}
+
+#[test]
+fn slots_are_listed_per_profile() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let first = save_slots::create_profile(dir.path()).expect("create profile");
+    let second = save_slots::create_profile(dir.path()).expect("create profile");
+    save_slots::create_slot(dir.path(), second).expect("create slot");
+
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(save_slots::list_profiles(dir.path()).expect("list profiles"), expected);
+    assert_eq!(save_slots::list_slots(dir.path(), first).expect("list slots").len(), 1);
+    assert_eq!(save_slots::list_slots(dir.path(), second).expect("list slots").len(), 2);
+}
+
+#[test]
+fn copy_slot_duplicates_save() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_id = save_slots::create_profile(dir.path()).expect("create profile");
+    save_slots::copy_slot(dir.path(), user_id, SaveSlotId(0), SaveSlotId(3)).expect("copy");
+
+    let copy = save_file_io::read_save_from_slot(dir.path(), user_id, SaveSlotId(3))
+        .expect("read")
+        .expect("copied save should exist");
+    assert_eq!(copy.id(), user_id);
+}
+
+#[test]
+fn copy_slot_rejects_used_destination_and_empty_source() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_id = save_slots::create_profile(dir.path()).expect("create profile");
+    save_slots::create_slot(dir.path(), user_id).expect("create slot");
+
+    let errors = save_slots::copy_slot(dir.path(), user_id, SaveSlotId(0), SaveSlotId(1))
+        .expect_err("destination is in use");
+    assert_eq!(errors[0].name, "Save slot is already in use");
+
+    let errors = save_slots::copy_slot(dir.path(), user_id, SaveSlotId(5), SaveSlotId(6))
+        .expect_err("source is empty");
+    assert_eq!(errors[0].name, "Save slot is empty");
+}
+
+#[test]
+fn delete_profile_removes_all_slots() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_id = save_slots::create_profile(dir.path()).expect("create profile");
+    save_slots::create_slot(dir.path(), user_id).expect("create slot");
+
+    save_slots::delete_profile(dir.path(), user_id).expect("delete profile");
+    assert!(save_slots::list_profiles(dir.path()).expect("list profiles").is_empty());
+}
+
+#[test]
+fn list_profiles_ignores_unrelated_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    fs::write(dir.path().join("notes.txt"), "hello").expect("write");
+    fs::write(dir.path().join("save-not-a-uuid.json"), "{}").expect("write");
+    assert!(save_slots::list_profiles(dir.path()).expect("list profiles").is_empty());
+
+    let missing = dir.path().join("missing");
+    assert!(save_slots::list_profiles(&missing).expect("list profiles").is_empty());
+    assert!(
+        save_slots::list_slots(&missing, UserId(Uuid::from_u128(1)))
+            .expect("list slots")
+            .is_empty()
+    );
+}
@@ -0,0 +1,169 @@
+use core_data::identifiers::UserId;
+use database::save_sync::{
+    self, ConflictResolution, FilesystemSaveSync, NewestWins, PromptOnConflict, SaveSync,
+    SaveSyncError, SaveSyncMetadata, SyncOutcome, SyncedSave, VersionOrdering, VersionVector,
+};
+use uuid::Uuid;
+
+#[test]
+fn version_vector_ordering() {
+    let mut a = VersionVector::default();
+    a.increment("desktop");
+    let mut b = a.clone();
+    assert_eq!(a.compare(&b), VersionOrdering::Equal);
+
+    b.increment("laptop");
+    assert_eq!(a.compare(&b), VersionOrdering::Before);
+    assert_eq!(b.compare(&a), VersionOrdering::After);
+
+    a.increment("desktop");
+    assert_eq!(a.compare(&b), VersionOrdering::Concurrent);
+
+    let merged = a.merge(&b);
+    assert_eq!(merged.get("desktop"), 2);
+    assert_eq!(merged.get("laptop"), 1);
+    assert_eq!(merged.compare(&a), VersionOrdering::After);
+    assert_eq!(merged.compare(&b), VersionOrdering::After);
+}
+
+#[test]
+fn filesystem_push_pull_and_list() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    assert_eq!(provider.pull(user_id()).expect("pull"), None);
+    assert!(provider.list().expect("list").is_empty());
+
+    let save = synced_save(&[("desktop", 1)], "desktop", 100, b"first");
+    provider.push(&save).expect("push");
+    assert_eq!(provider.pull(user_id()).expect("pull"), Some(save.clone()));
+    assert_eq!(provider.list().expect("list"), vec![save.metadata]);
+}
+
+#[test]
+fn filesystem_push_rejects_older_version() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    provider.push(&synced_save(&[("desktop", 2)], "desktop", 100, b"newer")).expect("push");
+
+    let result = provider.push(&synced_save(&[("desktop", 1)], "desktop", 50, b"older"));
+    assert!(matches!(result, Err(SaveSyncError::Conflict(_))));
+    assert_eq!(provider.pull(user_id()).expect("pull").expect("save").data, b"newer");
+}
+
+#[test]
+fn sync_pushes_when_remote_missing_or_older() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    let first = synced_save(&[("desktop", 1)], "desktop", 100, b"first");
+    assert_eq!(
+        save_sync::sync(&provider, &NewestWins, &first).expect("sync"),
+        SyncOutcome::Pushed(first.metadata.clone())
+    );
+    assert_eq!(
+        save_sync::sync(&provider, &NewestWins, &first).expect("sync"),
+        SyncOutcome::UpToDate
+    );
+
+    let second = synced_save(&[("desktop", 2)], "desktop", 200, b"second");
+    assert_eq!(
+        save_sync::sync(&provider, &NewestWins, &second).expect("sync"),
+        SyncOutcome::Pushed(second.metadata.clone())
+    );
+    assert_eq!(provider.pull(user_id()).expect("pull"), Some(second));
+}
+
+#[test]
+fn sync_pulls_when_remote_newer() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    let remote = synced_save(&[("desktop", 1), ("laptop", 1)], "laptop", 200, b"remote");
+    provider.push(&remote).expect("push");
+
+    let local = synced_save(&[("desktop", 1)], "desktop", 100, b"local");
+    assert_eq!(
+        save_sync::sync(&provider, &NewestWins, &local).expect("sync"),
+        SyncOutcome::Pulled(remote)
+    );
+}
+
+#[test]
+fn newest_wins_keeps_newer_remote_on_conflict() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    let remote = synced_save(&[("laptop", 1)], "laptop", 200, b"remote");
+    provider.push(&remote).expect("push");
+
+    let local = synced_save(&[("desktop", 1)], "desktop", 100, b"local");
+    assert_eq!(
+        save_sync::sync(&provider, &NewestWins, &local).expect("sync"),
+        SyncOutcome::Pulled(remote)
+    );
+}
+
+#[test]
+fn newest_wins_pushes_newer_local_on_conflict() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    provider.push(&synced_save(&[("laptop", 1)], "laptop", 100, b"remote")).expect("push");
+
+    let local = synced_save(&[("desktop", 1)], "desktop", 200, b"local");
+    let SyncOutcome::Pushed(metadata) =
+        save_sync::sync(&provider, &NewestWins, &local).expect("sync")
+    else {
+        panic!("expected local save to be pushed");
+    };
+    assert_eq!(metadata.version.get("desktop"), 2);
+    assert_eq!(metadata.version.get("laptop"), 1);
+
+    let stored = provider.pull(user_id()).expect("pull").expect("save");
+    assert_eq!(stored.data, b"local");
+    assert_eq!(stored.metadata, metadata);
+}
+
+#[test]
+fn prompt_policy_defers_conflict_to_user() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let provider = FilesystemSaveSync::new(dir.path());
+    let remote = synced_save(&[("laptop", 1)], "laptop", 100, b"remote");
+    provider.push(&remote).expect("push");
+
+    let local = synced_save(&[("desktop", 1)], "desktop", 200, b"local");
+    let SyncOutcome::NeedsResolution { conflict, remote: pulled } =
+        save_sync::sync(&provider, &PromptOnConflict, &local).expect("sync")
+    else {
+        panic!("expected conflict to require resolution");
+    };
+    assert_eq!(conflict.local, local.metadata);
+    assert_eq!(conflict.remote, remote.metadata);
+    assert_eq!(provider.pull(user_id()).expect("pull"), Some(remote));
+
+    let outcome =
+        save_sync::apply_resolution(&provider, &local, pulled, ConflictResolution::KeepLocal)
+            .expect("resolve");
+    assert!(matches!(outcome, SyncOutcome::Pushed(_)));
+    assert_eq!(provider.pull(user_id()).expect("pull").expect("save").data, b"local");
+}
+
+fn user_id() -> UserId {
+    UserId(Uuid::from_u128(1))
+}
+
+fn synced_save(
+    counters: &[(&str, u64)],
+    device: &str,
+    modified_at: u64,
+    data: &[u8],
+) -> SyncedSave {
+    let version = VersionVector {
+        counters: counters.iter().map(|&(name, count)| (name.to_string(), count)).collect(),
+    };
+    SyncedSave {
+        metadata: SaveSyncMetadata {
+            user_id: user_id(),
+            version,
+            device: device.to_string(),
+            modified_at,
+        },
+        data: data.to_vec(),
+    }
+}
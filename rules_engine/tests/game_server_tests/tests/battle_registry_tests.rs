@@ -0,0 +1,86 @@
+use core_data::identifiers::UserId;
+use game_server::battle_registry::{BattleRegistry, HostedBattle, JoinError};
+use uuid::Uuid;
+
+fn user() -> UserId {
+    UserId(Uuid::new_v4())
+}
+
+#[test]
+fn first_user_hosts_battle() {
+    let mut registry = BattleRegistry::default();
+    let host = user();
+
+    let hosted = registry.join(&"lobby".to_string(), host).expect("join succeeds");
+
+    assert_eq!(hosted, HostedBattle { host, guest: None });
+    assert_eq!(hosted.save_file_for(host), None, "host uses their own save file");
+}
+
+#[test]
+fn second_user_joins_host_save_file() {
+    let mut registry = BattleRegistry::default();
+    let battle = "lobby".to_string();
+    let host = user();
+    let guest = user();
+    registry.join(&battle, host).expect("host joins");
+
+    let hosted = registry.join(&battle, guest).expect("guest joins");
+
+    assert_eq!(hosted, HostedBattle { host, guest: Some(guest) });
+    assert_eq!(hosted.save_file_for(guest), Some(host), "guest acts in the host's save file");
+}
+
+#[test]
+fn third_user_cannot_join_full_battle() {
+    let mut registry = BattleRegistry::default();
+    let battle = "lobby".to_string();
+    registry.join(&battle, user()).expect("host joins");
+    registry.join(&battle, user()).expect("guest joins");
+
+    assert_eq!(registry.join(&battle, user()), Err(JoinError::BattleFull));
+}
+
+#[test]
+fn user_cannot_open_two_connections() {
+    let mut registry = BattleRegistry::default();
+    let host = user();
+    registry.join(&"first".to_string(), host).expect("host joins");
+
+    assert_eq!(registry.join(&"second".to_string(), host), Err(JoinError::AlreadyConnected));
+}
+
+#[test]
+fn users_rejoin_battle_after_leaving() {
+    let mut registry = BattleRegistry::default();
+    let battle = "lobby".to_string();
+    let host = user();
+    let guest = user();
+    registry.join(&battle, host).expect("host joins");
+    registry.join(&battle, guest).expect("guest joins");
+
+    registry.leave(host);
+    registry.leave(guest);
+
+    assert_eq!(registry.join(&battle, guest), Ok(HostedBattle { host, guest: Some(guest) }));
+    assert_eq!(registry.join(&battle, host), Ok(HostedBattle { host, guest: Some(guest) }));
+}
+
+#[test]
+fn battles_are_independent() {
+    let mut registry = BattleRegistry::default();
+    let first_host = user();
+    let second_host = user();
+
+    registry.join(&"first".to_string(), first_host).expect("first host joins");
+    registry.join(&"second".to_string(), second_host).expect("second host joins");
+
+    assert_eq!(
+        registry.battle(&"first".to_string()),
+        Some(HostedBattle { host: first_host, guest: None })
+    );
+    assert_eq!(
+        registry.battle(&"second".to_string()),
+        Some(HostedBattle { host: second_host, guest: None })
+    );
+}
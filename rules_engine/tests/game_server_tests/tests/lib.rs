@@ -0,0 +1,2 @@
+mod battle_registry_tests;
+mod server_messages_tests;
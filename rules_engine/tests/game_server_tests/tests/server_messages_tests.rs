@@ -0,0 +1,34 @@
+use game_server::server_messages::{ClientMessage, ServerMessage};
+
+#[test]
+fn parse_perform_action_message() {
+    let json = r#"{
+        "PerformAction": {
+            "metadata": {
+                "user_id": "00000000-0000-0000-0000-000000000001",
+                "battle_id": null,
+                "request_id": null,
+                "integration_test_id": null
+            },
+            "action": "NoOp",
+            "save_file_id": null,
+            "last_response_version": null
+        }
+    }"#;
+
+    let message: ClientMessage = serde_json::from_str(json).expect("valid message");
+
+    let ClientMessage::PerformAction(request) = message else {
+        panic!("Expected PerformAction message");
+    };
+    assert_eq!(request.metadata.user_id.0.as_u128(), 1);
+}
+
+#[test]
+fn serialize_error_message() {
+    let json =
+        serde_json::to_string(&ServerMessage::Error("Battle already has two players".into()))
+            .expect("serializable");
+
+    assert_eq!(json, r#"{"Error":"Battle already has two players"}"#);
+}
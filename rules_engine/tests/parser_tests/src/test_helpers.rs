@@ -9,6 +9,8 @@ use core_data::card_types::CardSubtype;
 use core_data::figment_type::FigmentType;
 use parser::builder::parser_builder;
 use parser::builder::parser_spans::SpannedAbility;
+use parser::error::parser_diagnostics;
+use parser::error::parser_errors::ParseDiagnostic;
 use parser::lexer::lexer_tokenize;
 use parser::parser::ability_parser;
 use parser::parser::parser_helpers::{ParserExtra, ParserInput};
@@ -108,6 +110,25 @@ pub fn parse_spanned_abilities(input: &str, vars: &str) -> Vec<SpannedAbility> {
     })
 }
 
+/// Parses `input` as an ability which is expected to fail, returning the
+/// diagnostic for the first parse error.
+pub fn parse_ability_diagnostic(input: &str, vars: &str) -> ParseDiagnostic {
+    with_stack(|| {
+        let lex_result = lexer_tokenize::lex(input).unwrap();
+        let bindings = VariableBindings::parse(vars).unwrap();
+        let resolved =
+            parser_substitutions::resolve_variables(&lex_result.tokens, &bindings).unwrap();
+        ABILITY_PARSER_CACHE.with(|parser| {
+            let parser =
+                unsafe { &*(parser as *const AbilityParser<'static>).cast::<AbilityParser<'_>>() };
+            match parser.parse(&resolved).into_result() {
+                Ok(ability) => panic!("Expected {input:?} to fail to parse, got {ability:?}"),
+                Err(errors) => parser_diagnostics::parse_diagnostic(&errors[0], &resolved),
+            }
+        })
+    })
+}
+
 pub fn build_spanned_ability(ability: &Ability, input: &str) -> SpannedAbility {
     parser_builder::build_spanned_ability(ability, &lexer_tokenize::lex(input).unwrap()).unwrap()
 }
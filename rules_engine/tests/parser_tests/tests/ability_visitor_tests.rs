@@ -0,0 +1,111 @@
+use ability_data::ability::{Ability, EventAbility};
+use ability_data::ability_visitor::{self, AbilityVisitor};
+use ability_data::ability_visitor_mut::{self, AbilityVisitorMut};
+use ability_data::cost::Cost;
+use ability_data::effect::Effect;
+use ability_data::predicate::{CardPredicate, Predicate};
+use ability_data::standard_effect::StandardEffect;
+use insta::assert_ron_snapshot;
+use parser_tests::test_helpers::*;
+
+#[derive(Default)]
+struct NodeRecorder {
+    nodes: Vec<&'static str>,
+}
+
+impl AbilityVisitor for NodeRecorder {
+    fn visit_ability(&mut self, ability: &Ability) {
+        self.nodes.push("ability");
+        ability_visitor::walk_ability(self, ability);
+    }
+
+    fn visit_event_ability(&mut self, ability: &EventAbility) {
+        self.nodes.push("event_ability");
+        ability_visitor::walk_event_ability(self, ability);
+    }
+
+    fn visit_effect(&mut self, effect: &Effect) {
+        self.nodes.push("effect");
+        ability_visitor::walk_effect(self, effect);
+    }
+
+    fn visit_standard_effect(&mut self, effect: &StandardEffect) {
+        self.nodes.push("standard_effect");
+        ability_visitor::walk_standard_effect(self, effect);
+    }
+
+    fn visit_cost(&mut self, cost: &Cost) {
+        self.nodes.push("cost");
+        ability_visitor::walk_cost(self, cost);
+    }
+
+    fn visit_predicate(&mut self, predicate: &Predicate) {
+        self.nodes.push("predicate");
+        ability_visitor::walk_predicate(self, predicate);
+    }
+
+    fn visit_card_predicate(&mut self, predicate: &CardPredicate) {
+        self.nodes.push("card_predicate");
+        ability_visitor::walk_card_predicate(self, predicate);
+    }
+}
+
+struct EnemyToAnyOther;
+
+impl AbilityVisitorMut for EnemyToAnyOther {
+    fn visit_predicate(&mut self, predicate: &mut Predicate) {
+        if let Predicate::Enemy(card_predicate) = predicate {
+            *predicate = Predicate::AnyOther(card_predicate.clone());
+        }
+        ability_visitor_mut::walk_predicate(self, predicate);
+    }
+}
+
+fn record_nodes(ability: &Ability) -> Vec<&'static str> {
+    let mut recorder = NodeRecorder::default();
+    recorder.visit_ability(ability);
+    recorder.nodes
+}
+
+#[test]
+fn test_visitor_walks_event_ability_in_order() {
+    let ability = parse_ability("{Dissolve} an enemy with cost {e} or less.", "e: 3");
+    assert_eq!(record_nodes(&ability), vec![
+        "ability",
+        "event_ability",
+        "effect",
+        "standard_effect",
+        "predicate",
+        "card_predicate",
+        "card_predicate",
+    ]);
+}
+
+#[test]
+fn test_visitor_walks_named_ability_cost() {
+    let ability = parse_ability("{Reclaim} -- Abandon an ally", "");
+    assert_eq!(record_nodes(&ability), vec!["ability", "cost", "predicate", "card_predicate"]);
+}
+
+#[test]
+fn test_visitor_walks_ability_word() {
+    let ability = parse_ability("{Resolve} -- {Judgment} Draw {cards}.", "c: 1");
+    assert_eq!(record_nodes(&ability), vec!["ability", "ability", "effect", "standard_effect"]);
+}
+
+#[test]
+fn test_mutable_visitor_rewrites_predicates() {
+    let mut ability = parse_ability("{Dissolve} an enemy with cost {e} or less.", "e: 3");
+    EnemyToAnyOther.visit_ability(&mut ability);
+    assert_ron_snapshot!(ability, @r###"
+    Event(EventAbility(
+      effect: Effect(DissolveCharacter(
+        target: AnyOther(CardWithCost(
+          target: Character,
+          cost_operator: OrLess,
+          cost: Energy(3),
+        )),
+      )),
+    ))
+    "###);
+}
@@ -493,3 +493,112 @@ fn test_pay_one_or_more_energy_draw() {
     ))
     "###);
 }
+
+#[test]
+fn test_x_energy_draw_x_cards() {
+    let result = parse_ability("{x}: Draw X cards.", "");
+    assert_ron_snapshot!(result, @r###"
+    Activated(ActivatedAbility(
+      costs: [
+        VariableEnergy,
+      ],
+      effect: Effect(DrawCardsForEach(
+        count: 1,
+        for_each: VariableEnergyPaid,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_x_energy_gain_points_for_each_x() {
+    let result = parse_ability("{x}: Gain {points($p)} for each X.", "p: 1");
+    assert_ron_snapshot!(result, @r###"
+    Activated(ActivatedAbility(
+      costs: [
+        VariableEnergy,
+      ],
+      effect: Effect(GainPointsForEach(
+        gain: Points(1),
+        for_count: VariableEnergyPaid,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_block_layout_draw_then_discard() {
+    let result =
+        parse_ability("{energy($e)}:\n> Draw {cards($c)}.\n> Discard {cards($d)}.", "e: 2, c: 2, d: 3");
+    assert_ron_snapshot!(result, @r###"
+    Activated(ActivatedAbility(
+      costs: [
+        Energy(Energy(2)),
+      ],
+      effect: List([
+        EffectWithOptions(
+          effect: DrawCards(
+            count: 2,
+          ),
+          optional: false,
+        ),
+        EffectWithOptions(
+          effect: DiscardCards(
+            count: 3,
+          ),
+          optional: false,
+        ),
+      ]),
+      paragraphs: Some([
+        1,
+        1,
+      ]),
+    ))
+    "###);
+}
+
+#[test]
+fn test_abandon_count_allied_subtype_draw() {
+    let result = parse_ability(
+        "Abandon {count_allied_subtype($a, $t)}: Draw {cards($c)}.",
+        "a: 2, t: Warrior, c: 1",
+    );
+    assert_ron_snapshot!(result, @r###"
+    Activated(ActivatedAbility(
+      costs: [
+        AbandonCharactersCount(
+          target: Another(CharacterType(Warrior)),
+          count: Exactly(2),
+        ),
+      ],
+      effect: Effect(DrawCards(
+        count: 1,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_abandon_count_allies_with_cost_gain_points() {
+    let result = parse_ability(
+        "Abandon {count_allies($a)} with cost {energy($e)} or more: Gain {points($p)}.",
+        "a: 2, e: 3, p: 1",
+    );
+    assert_ron_snapshot!(result, @r###"
+    Activated(ActivatedAbility(
+      costs: [
+        AbandonCharactersCount(
+          target: Another(CardWithCost(
+            target: Character,
+            cost_operator: OrMore,
+            cost: Energy(3),
+          )),
+          count: Exactly(2),
+        ),
+      ],
+      effect: Effect(GainPoints(
+        gains: Points(1),
+      )),
+    ))
+    "###);
+}
@@ -1,4 +1,4 @@
-use parser_benchmarks::benchmark_utils;
+use parser_benchmarks::{benchmark_utils, card_pool};
 
 #[test]
 fn test_parser_bench_draw_cards() {
@@ -10,3 +10,15 @@ fn test_full_pipeline_bench() {
     let cards_file = benchmark_utils::load_cards_toml();
     benchmark_utils::parse_all_cards(cards_file);
 }
+
+#[test]
+fn test_card_pool_bench() {
+    let pool = card_pool::load_card_pool();
+    let corpus = card_pool::synthetic_corpus(&pool);
+    assert!(!pool.is_empty(), "Card pool should contain rules text");
+    assert_eq!(corpus.len(), pool.len() * card_pool::SYNTHETIC_CORPUS_SCALE);
+    assert_eq!(
+        card_pool::parse_card_pool(&corpus),
+        card_pool::parse_card_pool(&pool) * card_pool::SYNTHETIC_CORPUS_SCALE
+    );
+}
@@ -445,6 +445,52 @@ fn test_you_may_return_character_from_void_draw_cards() {
     );
 }
 
+#[test]
+fn test_you_may_discard_if_you_do_draw() {
+    let result = parse_ability("You may discard {discards}. If you do, draw {cards}.", "d: 1, c: 2");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: IfYouDo(IfYouDoEffect(
+        effect: DiscardCards(
+          count: 1,
+        ),
+        optional: true,
+        follow_up: [
+          EffectWithOptions(
+            effect: DrawCards(
+              count: 2,
+            ),
+            optional: false,
+          ),
+        ],
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_discard_if_you_do_gain_energy() {
+    let result = parse_ability("Discard {discards}. If you do, gain {e}.", "d: 1, e: 2");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: IfYouDo(IfYouDoEffect(
+        effect: DiscardCards(
+          count: 1,
+        ),
+        optional: false,
+        follow_up: [
+          EffectWithOptions(
+            effect: GainEnergy(
+              gains: Energy(2),
+            ),
+            optional: false,
+          ),
+        ],
+      )),
+    ))
+    "###);
+}
+
 #[test]
 fn test_judgment_you_may_pay_to_return_this_from_void_to_hand() {
     let result = parse_ability(
@@ -724,7 +770,7 @@ fn test_materialized_card_with_cost_in_void_gains_reclaim() {
           cost: Energy(3),
         )),
         count: Exactly(1),
-        this_turn: false,
+        duration: None,
         cost: Some(Energy(0)),
       )),
     ))
@@ -739,7 +785,7 @@ fn test_event_in_void_gains_reclaim_this_turn() {
       effect: Effect(GainsReclaim(
         target: YourVoid(Event),
         count: Exactly(1),
-        this_turn: true,
+        duration: Some(ThisTurn),
         cost: Some(Energy(0)),
       )),
     ))
@@ -757,7 +803,7 @@ fn test_all_cards_in_void_gain_reclaim_equal_to_cost() {
       effect: Effect(GainsReclaim(
         target: YourVoid(Card),
         count: All,
-        this_turn: true,
+        duration: Some(ThisTurn),
         cost: None,
       )),
     ))
@@ -12,8 +12,28 @@ fn test_materialized_disable_activated_abilities() {
       trigger: Keywords([
         Materialized,
       ]),
-      effect: Effect(DisableActivatedAbilitiesWhileInPlay(
+      effect: Effect(DisableActivatedAbilities(
         target: Enemy(Character),
+        duration: WhileInPlay,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_materialized_disable_activated_abilities_until_your_next_turn() {
+    let result = parse_ability(
+        "{Materialized} Disable the activated abilities of an enemy until your next turn.",
+        "",
+    );
+    assert_ron_snapshot!(result, @r###"
+    Triggered(TriggeredAbility(
+      trigger: Keywords([
+        Materialized,
+      ]),
+      effect: Effect(DisableActivatedAbilities(
+        target: Enemy(Character),
+        duration: UntilYourNextTurn,
       )),
     ))
     "###);
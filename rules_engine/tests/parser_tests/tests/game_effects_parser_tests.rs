@@ -6,17 +6,14 @@ fn test_until_end_of_turn_when_you_play_event_copy_it() {
     let result = parse_ability("Until end of turn, when you play an event, copy it.", "");
     assert_ron_snapshot!(result, @r###"
     Event(EventAbility(
-      effect: Effect(CreateTriggerUntilEndOfTurn(
+      effect: Effect(CreateTrigger(
         trigger: TriggeredAbility(
           trigger: Play(Your(Event)),
           effect: Effect(Copy(
             target: It,
           )),
-          options: Some(TriggeredAbilityOptions(
-            once_per_turn: false,
-            until_end_of_turn: true,
-          )),
         ),
+        duration: ThisTurn,
       )),
     ))
     "###);
@@ -34,7 +31,7 @@ fn test_when_ally_dissolved_gains_reclaim_for_cost() {
       effect: Effect(GainsReclaim(
         target: This,
         count: Exactly(1),
-        this_turn: true,
+        duration: Some(ThisTurn),
         cost: Some(Energy(3)),
       )),
     ))
@@ -423,6 +420,37 @@ fn test_discover_fast_subtype() {
     "###);
 }
 
+#[test]
+fn test_dissolve_enemy_subtype_or_subtype() {
+    let result =
+        parse_ability("{Dissolve} an enemy {subtype($t)} or {subtype($u)}.", "t: Warrior\nu: Mage");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: Effect(DissolveCharacter(
+        target: Enemy(Or([
+          CharacterType(Warrior),
+          CharacterType(Mage),
+        ])),
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_discover_subtype_or_event() {
+    let result = parse_ability("{Discover} a {subtype($t)} or event.", "t: Warrior");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: Effect(Discover(
+        predicate: Or([
+          CharacterType(Warrior),
+          Event,
+        ]),
+      )),
+    ))
+    "###);
+}
+
 #[test]
 fn test_discover_fast_character_with_spark() {
     let result = parse_ability("{Discover} a {fast} character with spark {s} or less.", "s: 2");
@@ -962,8 +990,9 @@ fn test_materialized_banish_enemy_until_next_main_phase() {
       trigger: Keywords([
         Materialized,
       ]),
-      effect: Effect(BanishUntilNextMain(
+      effect: Effect(BanishForDuration(
         target: Enemy(Character),
+        duration: UntilYourNextMain,
       )),
     ))
     "###);
@@ -983,7 +1012,7 @@ fn test_materialized_event_in_void_gains_reclaim() {
       effect: Effect(GainsReclaim(
         target: YourVoid(Event),
         count: Exactly(1),
-        this_turn: true,
+        duration: Some(ThisTurn),
         cost: None,
       )),
     ))
@@ -1182,7 +1211,7 @@ fn test_when_you_discard_card_it_gains_reclaim_equal_to_cost() {
       effect: Effect(GainsReclaim(
         target: It,
         count: Exactly(1),
-        this_turn: true,
+        duration: Some(ThisTurn),
         cost: None,
       )),
     ))
@@ -1,5 +1,6 @@
 use chumsky::span::{SimpleSpan, Span};
 use parser::error::parser_errors::LexError;
+use parser::lexer::lexer_incremental::{self, TextEdit};
 use parser::lexer::lexer_token::Token;
 use parser::lexer::lexer_tokenize::{self, LexResult};
 
@@ -11,6 +12,26 @@ fn spans(result: &LexResult) -> Vec<SimpleSpan> {
     result.tokens.iter().map(|(_, s)| *s).collect()
 }
 
+/// Applies an edit replacing `range` of `input` with `text` incrementally and
+/// checks the result against lexing the edited text from scratch.
+fn assert_relex_matches(input: &str, range: std::ops::Range<usize>, text: &str) {
+    let previous = lexer_tokenize::lex(input).expect("lexing should succeed");
+    let edit = TextEdit { range: range.clone(), text: text.to_string() };
+    let edited = format!("{}{text}{}", &input[..range.start], &input[range.end..]);
+    let incremental = lexer_incremental::relex(&previous, &edit);
+    match lexer_tokenize::lex(&edited) {
+        Ok(expected) => {
+            let incremental = incremental.expect("relexing should succeed");
+            assert_eq!(incremental.original, edited);
+            assert_eq!(incremental.tokens, expected.tokens, "Token mismatch for {edited:?}");
+        }
+        Err(expected) => {
+            let incremental = incremental.expect_err("relexing should fail");
+            assert_eq!(format!("{incremental:?}"), format!("{expected:?}"));
+        }
+    }
+}
+
 #[test]
 fn test_card_1_play_card_count() {
     let input = "When you play {$c} {card:$c} in a turn, {reclaim} this character.";
@@ -303,6 +324,35 @@ fn test_error_empty_directive() {
     assert!(matches!(err, LexError::EmptyDirective { .. }));
 }
 
+#[test]
+fn test_error_unclosed_parenthesis() {
+    let input = "Draw a card. (Reminder";
+    let result = lexer_tokenize::lex(input);
+
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(matches!(err, LexError::UnclosedParenthesis { .. }));
+}
+
+#[test]
+fn test_reminder_text() {
+    let input = "{Discover} a card. (Look at 3 cards, then pick one.)";
+    let result = lexer_tokenize::lex(input).expect("lexing should succeed");
+
+    assert_eq!(tokens(&result), vec![
+        &Token::Directive("discover".to_string()),
+        &Token::Word("a".to_string()),
+        &Token::Word("card".to_string()),
+        &Token::Period,
+        &Token::ReminderText("Look at 3 cards, then pick one.".to_string()),
+    ]);
+    assert_eq!(spans(&result)[4], SimpleSpan::new((), 19..input.len()));
+    assert_eq!(lexer_tokenize::reminder_text(&result), vec![(
+        "Look at 3 cards, then pick one.".to_string(),
+        SimpleSpan::new((), 19..input.len())
+    )]);
+}
+
 #[test]
 fn test_multiple_newlines() {
     let input = "a\n\nb";
@@ -344,3 +394,55 @@ fn test_combined_trigger_directive() {
         &Token::Period,
     ]);
 }
+
+#[test]
+fn test_relex_insert_word() {
+    let input = "Draw {cards($c)}. Gain {e}.";
+    let previous = lexer_tokenize::lex(input).expect("lexing should succeed");
+    let edit = TextEdit { range: 18..18, text: "then ".to_string() };
+    let result = lexer_incremental::relex(&previous, &edit).expect("relexing should succeed");
+
+    assert_eq!(result.original, "Draw {cards($c)}. then Gain {e}.");
+    assert_eq!(tokens(&result)[3..], [
+        &Token::Word("then".to_string()),
+        &Token::Word("gain".to_string()),
+        &Token::Directive("e".to_string()),
+        &Token::Period,
+    ]);
+    assert_eq!(spans(&result)[4], SimpleSpan::new((), 23..27));
+}
+
+#[test]
+fn test_relex_extends_adjacent_word() {
+    assert_relex_matches("Draw a card. Gain {e}.", 12..12, "s");
+    assert_relex_matches("Draw a card. Gain {e}.", 17..17, "s");
+    assert_relex_matches("Draw a card. Gain {e}.", 11..13, "");
+}
+
+#[test]
+fn test_relex_opens_and_closes_braces() {
+    assert_relex_matches("Gain {e}. Draw {cards($c)}.", 5..5, "{");
+    assert_relex_matches("Gain {e}. Draw {cards($c)}.", 7..8, "");
+    assert_relex_matches("Gain {e}. Draw {cards($c)}.", 6..7, "");
+    assert_relex_matches("Gain e}. Draw {cards($c)}.", 5..5, "{");
+}
+
+#[test]
+fn test_relex_reminder_text() {
+    let input = "{Foresee(2)} (Look at the top cards.) Draw a card.";
+    assert_relex_matches(input, 13..14, "");
+    assert_relex_matches(input, 36..37, "");
+    assert_relex_matches(input, 19..19, " two");
+}
+
+#[test]
+fn test_relex_matches_full_lex_for_every_edit_position() {
+    let input = "{Judgment} When you play {$c} {card:$c}, gain {e}.\n(Reminder: text.) Draw.";
+    for start in 0..=input.len() {
+        for end in start..=input.len().min(start + 3) {
+            for text in ["", " ", "x", "{", "}", "(", ")", ".", "a b", "\n"] {
+                assert_relex_matches(input, start..end, text);
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use ability_data::ability::Ability;
+use parser::lexer::lexer_tokenize;
+use parser::serializer::ability_serializer::{self, SerializerOptions};
+use parser::variables::parser_bindings::VariableBindings;
+use parser::variables::parser_substitutions;
+use parser_tests::test_helpers;
+use serde::Deserialize;
+
+const CARDS_TOML_PATH: &str = "../../tabula/cards.toml";
+const TEST_CARDS_TOML_PATH: &str = "../../tabula/test-cards.toml";
+const RUSSIAN_LANGUAGE: &str = "ru";
+const SOURCE_LANGUAGE: &str = "en";
+const MAX_REPORTED_ISSUES: usize = 40;
+
+#[derive(Debug, Deserialize)]
+struct CardsFile {
+    cards: Vec<Card>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestCardsFile {
+    #[serde(rename = "test-cards")]
+    test_cards: Vec<Card>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Card {
+    name: String,
+    #[serde(rename = "rules-text")]
+    rules_text: Option<String>,
+    variables: Option<String>,
+}
+
+/// Parses ability text through the lexer, variable resolution, and parser.
+fn parse_card_ability(ability_text: &str, variables: &str) -> Result<Ability, String> {
+    let bindings =
+        VariableBindings::parse(variables).map_err(|e| format!("variable parse error: {e:?}"))?;
+    let lex_result =
+        lexer_tokenize::lex(ability_text).map_err(|e| format!("lexer error: {e:?}"))?;
+    let resolved = parser_substitutions::resolve_variables(&lex_result.tokens, &bindings)
+        .map_err(|e| format!("variable resolution error: {e}"))?;
+    test_helpers::parse_resolved_ability(&resolved).map_err(|e| format!("parser error: {e}"))
+}
+
+/// Serializes `ability` in `language` via [SerializerOptions], without
+/// changing the active language.
+fn serialize_in_language(ability: &Ability, language: &'static str) -> Result<String, String> {
+    catch_unwind(AssertUnwindSafe(|| {
+        ability_serializer::serialize_ability_with_options(ability, SerializerOptions {
+            language: Some(language),
+            ..SerializerOptions::default()
+        })
+        .text
+    }))
+    .map_err(|panic| format!("serializer panic: {}", panic_message(panic)))
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&'static str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Serializes every card ability in both English and Russian through
+/// [SerializerOptions::language] and asserts that each ability serializes
+/// without panicking, that the Russian text differs from the English text,
+/// and that the active language is left unchanged.
+///
+/// This is the only test in this file because it switches the active
+/// language while serializing.
+#[test]
+fn test_language_option_serializes_all_cards_in_russian() {
+    test_helpers::register_russian_test_locale().expect("Russian locale should load");
+    let active_language = rlf::with_locale(|locale| locale.language().to_string());
+
+    let cards_toml = std::fs::read_to_string(CARDS_TOML_PATH).expect("Failed to read cards.toml");
+    let cards_file: CardsFile = toml::from_str(&cards_toml).expect("Failed to parse cards.toml");
+
+    let test_cards_toml =
+        std::fs::read_to_string(TEST_CARDS_TOML_PATH).expect("Failed to read test-cards.toml");
+    let test_cards_file: TestCardsFile =
+        toml::from_str(&test_cards_toml).expect("Failed to parse test-cards.toml");
+
+    let mut total_abilities = 0usize;
+    let mut issues = Vec::new();
+
+    for (cards, source) in [
+        (cards_file.cards.as_slice(), "cards.toml"),
+        (test_cards_file.test_cards.as_slice(), "test-cards.toml"),
+    ] {
+        for card in cards {
+            let Some(rules_text) = &card.rules_text else {
+                continue;
+            };
+            let variables = card.variables.as_deref().unwrap_or("");
+            for (ability_index, ability_block) in rules_text.split("\n\n").enumerate() {
+                let ability_block = ability_block.trim();
+                if ability_block.is_empty() {
+                    continue;
+                }
+                let Ok(ability) = parse_card_ability(ability_block, variables) else {
+                    continue;
+                };
+                total_abilities += 1;
+                let location = format!("- {source} | {} | ability #{ability_index}", card.name);
+                match (
+                    serialize_in_language(&ability, SOURCE_LANGUAGE),
+                    serialize_in_language(&ability, RUSSIAN_LANGUAGE),
+                ) {
+                    (Ok(english), Ok(russian)) if english == russian => {
+                        issues.push(format!("{location} | untranslated: {russian:?}"));
+                    }
+                    (Ok(_), Ok(_)) => {}
+                    (Err(error), _) | (_, Err(error)) => {
+                        issues.push(format!("{location} | {error}"));
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Language option test: serialized {total_abilities} abilities in Russian");
+    assert_eq!(rlf::with_locale(|locale| locale.language().to_string()), active_language);
+    assert!(total_abilities >= 278, "Expected at least 278 abilities but found {total_abilities}");
+    assert!(
+        issues.is_empty(),
+        "Russian serialization failed for {} abilities (showing up to {MAX_REPORTED_ISSUES}):\n{}",
+        issues.len(),
+        issues.iter().take(MAX_REPORTED_ISSUES).cloned().collect::<Vec<_>>().join("\n")
+    );
+}
@@ -263,3 +263,36 @@ fn test_lose_maximum_energy_alternate_cost_prevent_card() {
     ))
     "###);
 }
+
+#[test]
+fn test_resolve_ability_word_judgment_draw() {
+    let result = parse_ability("{Resolve} -- {Judgment} Draw {cards}.", "c: 1");
+    assert_ron_snapshot!(result, @r###"
+    WithAbilityWord(AbilityWithWord(
+      word: Resolve,
+      ability: Triggered(TriggeredAbility(
+        trigger: Keywords([
+          Judgment,
+        ]),
+        effect: Effect(DrawCards(
+          count: 1,
+        )),
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_materialized_trigger_this_cards_resolve_ability() {
+    let result = parse_ability("{Materialized} Trigger this card's {Resolve} ability.", "");
+    assert_ron_snapshot!(result, @r###"
+    Triggered(TriggeredAbility(
+      trigger: Keywords([
+        Materialized,
+      ]),
+      effect: Effect(TriggerThisCardsAbility(
+        word: Resolve,
+      )),
+    ))
+    "###);
+}
@@ -0,0 +1,45 @@
+use strings::palette::{self, Palette, PaletteColor};
+use strings::strings;
+
+#[test]
+fn standard_palette_leaves_text_unchanged() {
+    let text = strings::energy(3_u32).to_string();
+    assert_eq!(palette::apply(Palette::Standard, &text), text);
+}
+
+#[test]
+fn colorblind_palette_recolors_phrases() {
+    let text = format!("{} {}", strings::energy(3_u32), strings::dissolve());
+    let recolored = palette::apply(Palette::Colorblind, &text);
+    assert_eq!(
+        recolored,
+        format!(
+            "<color={}>3\u{25CF}</color> <color={}>dissolve</color>",
+            PaletteColor::Energy.hex(Palette::Colorblind),
+            PaletteColor::Keyword.hex(Palette::Colorblind)
+        )
+    );
+}
+
+#[test]
+fn colors_outside_palette_are_unchanged() {
+    let text = "<color=#123456>text</color>";
+    assert_eq!(palette::apply(Palette::Colorblind, text), text);
+}
+
+#[test]
+fn palette_colors_are_distinct() {
+    for palette in [Palette::Standard, Palette::Colorblind] {
+        for (i, color) in palette::ALL_COLORS.iter().enumerate() {
+            for other in &palette::ALL_COLORS[i + 1..] {
+                assert_ne!(color.hex(palette), other.hex(palette), "{color:?} and {other:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn standard_hex_lookup_is_case_insensitive() {
+    assert_eq!(palette::from_standard_hex("#aa00ff"), Some(PaletteColor::Keyword));
+    assert_eq!(palette::from_standard_hex("#123456"), None);
+}
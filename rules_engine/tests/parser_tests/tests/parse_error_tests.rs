@@ -1,10 +1,14 @@
 use chumsky::Parser;
+use chumsky::span::Span;
 use parser::error::parser_diagnostics;
 use parser::error::parser_errors::ParserError;
+use parser::lexer::lexer_token::Token;
 use parser::lexer::lexer_tokenize;
 use parser::parser::predicate_parser;
+use parser::rules_text_parser;
 use parser::variables::parser_bindings::VariableBindings;
-use parser::variables::parser_substitutions;
+use parser::variables::parser_substitutions::{self, ResolvedToken};
+use parser_tests::test_helpers::*;
 
 #[test]
 fn test_unclosed_brace_error() {
@@ -252,3 +256,72 @@ fn test_draw_then_discard_missing_variable_suggests_fix() {
     assert!(formatted.contains("c"));
     assert!(formatted.contains("not found"));
 }
+
+#[test]
+fn test_parse_diagnostic_reports_byte_range_of_unexpected_word() {
+    let input = "{Judgment} Gain {e} quickly.";
+    let diagnostic = parse_ability_diagnostic(input, "e: 3");
+
+    assert_eq!(&input[diagnostic.span.start()..diagnostic.span.end()], "quickly");
+    assert_eq!(diagnostic.found, Some(ResolvedToken::Token(Token::Word("quickly".to_string()))));
+    assert!(diagnostic.expected.contains(&"period".to_string()));
+}
+
+#[test]
+fn test_parse_diagnostic_reports_enclosing_clauses() {
+    let diagnostic = parse_ability_diagnostic("{Judgment} Gain {e} quickly.", "e: 3");
+
+    assert!(diagnostic.clauses.contains(&"effect".to_string()));
+    assert!(diagnostic.clauses.contains(&"triggered ability".to_string()));
+}
+
+#[test]
+fn test_parse_diagnostic_at_end_of_input() {
+    let input = "{Judgment} Gain {e}";
+    let diagnostic = parse_ability_diagnostic(input, "e: 3");
+
+    assert_eq!(diagnostic.span.start(), input.len());
+    assert_eq!(diagnostic.span.end(), input.len());
+    assert!(diagnostic.found.is_none());
+    assert!(diagnostic.expected.contains(&"period".to_string()));
+}
+
+#[test]
+fn test_parse_error_formatting_underlines_span() {
+    let input = "{Judgment} Gain {e} quickly.";
+    let error = ParserError::Parse(parse_ability_diagnostic(input, "e: 3"));
+    let formatted = parser_diagnostics::format_error(&error, input, "test");
+
+    assert!(formatted.contains("Unexpected word 'quickly' at bytes 20..27"));
+    assert!(formatted.contains('^'));
+    assert!(formatted.contains("Expected one of:"));
+    assert!(formatted.contains("While parsing"));
+}
+
+#[test]
+fn test_rules_text_parse_error_keeps_other_abilities() {
+    let input = "Draw {cards}.\n\n{Judgment} Gain {e} quickly.\n\nGain {e}.";
+    let bindings = VariableBindings::parse("c: 1, e: 2").unwrap();
+    let parsed = rules_text_parser::parse_rules_text(input, &bindings);
+
+    assert_eq!(parsed.abilities.len(), 2);
+    assert_eq!(parsed.diagnostics.len(), 1);
+    assert_eq!(parsed.diagnostics[0].index, 1);
+    assert_eq!(parsed.diagnostics[0].text, "{Judgment} Gain {e} quickly.");
+    assert!(matches!(parsed.diagnostics[0].error, ParserError::Parse(_)));
+}
+
+#[test]
+fn test_rules_text_resolution_error_keeps_other_abilities() {
+    let input = "Draw {cards}.\n\nGain {e}.";
+    let bindings = VariableBindings::parse("e: 2").unwrap();
+    let parsed = rules_text_parser::parse_rules_text(input, &bindings);
+
+    assert_eq!(parsed.abilities.len(), 1);
+    assert_eq!(parsed.diagnostics.len(), 1);
+    assert_eq!(parsed.diagnostics[0].index, 0);
+    assert!(matches!(parsed.diagnostics[0].error, ParserError::UnresolvedVariable(_)));
+
+    let formatted = rules_text_parser::format_diagnostics(&parsed.diagnostics, "test");
+    assert!(formatted.contains("Unresolved variable"));
+}
@@ -0,0 +1,239 @@
+//! Grammar fuzzing harness for the ability parser.
+//!
+//! Generates random-but-plausible rules text by combining the phrase
+//! templates used in card text, then checks that the parser never panics and
+//! that serialize(parse(x)) renders identically to x. Failing inputs are
+//! shrunk to a minimal case by proptest.
+//!
+//! These tests are long-running and only execute when the
+//! `PARSER_FUZZ_CASES` environment variable is set to the number of cases to
+//! generate, e.g. `PARSER_FUZZ_CASES=10000 cargo test --test
+//! parser_fuzz_tests`.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use ability_data::ability::Ability;
+use parser::lexer::lexer_tokenize;
+use parser::serializer::ability_serializer;
+use parser::variables::parser_bindings::VariableBindings;
+use parser::variables::parser_substitutions;
+use parser_tests::test_helpers;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::test_runner::{TestCaseResult, TestRunner};
+
+const FUZZ_CASES_ENV: &str = "PARSER_FUZZ_CASES";
+const MAX_EFFECTS: usize = 3;
+
+/// Variables referenced by the phrase templates below, bound to a random
+/// value for each case.
+const VARIABLE_NAMES: &[&str] = &["c", "d", "e", "f", "k", "n", "p"];
+
+/// Effect templates, written in their sentence-initial form.
+const EFFECTS: &[&str] = &[
+    "Draw {cards($c)}",
+    "Discard {cards($d)}",
+    "Gain {energy($e)}",
+    "Gain {points($p)}",
+    "{Dissolve} an enemy",
+    "{Dissolve} an enemy with cost {energy($e)} or less",
+    "{Banish} an enemy with cost {energy($e)} or less",
+    "{Kindle($k)}",
+    "{Foresee($f)}",
+    "{Prevent} a played card",
+    "{Discover} an event",
+    "Return an enemy to hand",
+    "Put {spark_counters($n)} on an ally",
+    "Draw {cards($c)} for each card you have played this turn",
+];
+
+const KEYWORD_TRIGGERS: &[&str] = &["{Judgment}", "{Materialized}"];
+
+const EVENT_TRIGGERS: &[&str] = &[
+    "When you discard a card,",
+    "Once per turn, when you {materialize} a character,",
+    "When you play {$c} {card:$c} in a turn,",
+];
+
+const ACTIVATED_COSTS: &[&str] =
+    &["{energy($e)}", "Abandon an ally", "Abandon an ally, once per turn"];
+
+/// Generated rules text along with its variable bindings.
+#[derive(Debug, Clone)]
+struct FuzzCase {
+    text: String,
+    variables: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AbilityShape {
+    Event,
+    KeywordTrigger(usize),
+    EventTrigger(usize),
+    Activated(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mutation {
+    DeleteWord(Index),
+    DuplicateWord(Index),
+    SwapWords(Index, Index),
+    Truncate(Index),
+}
+
+#[test]
+fn test_fuzz_round_trip() {
+    run_fuzzer(fuzz_case(), |case| catch_panic(&case.text, || check_round_trip(&case)));
+}
+
+#[test]
+fn test_fuzz_mutated_text_does_not_panic() {
+    run_fuzzer((fuzz_case(), mutation()), |(case, mutation)| {
+        let text = apply_mutation(&case.text, mutation);
+        catch_panic(&text, || {
+            let _ = parse(&text, &case.variables);
+            Ok(())
+        })
+    });
+}
+
+/// Runs `test` against values from `strategy` if fuzzing is enabled, panicking
+/// with the minimal failing input on failure.
+fn run_fuzzer<S: Strategy>(strategy: S, test: impl Fn(S::Value) -> TestCaseResult)
+where
+    S::Value: Debug,
+{
+    let Some(cases) = fuzz_cases() else {
+        return;
+    };
+    let mut runner = TestRunner::new(ProptestConfig {
+        cases,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    });
+    if let Err(error) = runner.run(&strategy, test) {
+        panic!("{error}");
+    }
+}
+
+fn fuzz_cases() -> Option<u32> {
+    let value = std::env::var(FUZZ_CASES_ENV).ok()?;
+    Some(value.parse().unwrap_or_else(|e| panic!("Invalid {FUZZ_CASES_ENV} value {value:?}: {e}")))
+}
+
+fn fuzz_case() -> impl Strategy<Value = FuzzCase> {
+    let shape = prop_oneof![
+        Just(AbilityShape::Event),
+        (0..KEYWORD_TRIGGERS.len()).prop_map(AbilityShape::KeywordTrigger),
+        (0..EVENT_TRIGGERS.len()).prop_map(AbilityShape::EventTrigger),
+        (0..ACTIVATED_COSTS.len()).prop_map(AbilityShape::Activated),
+    ];
+    let effects = proptest::collection::vec(0..EFFECTS.len(), 1..=MAX_EFFECTS);
+    let values = proptest::collection::vec(1..=5u32, VARIABLE_NAMES.len());
+    (shape, effects, values).prop_map(|(shape, effects, values)| FuzzCase {
+        text: rules_text(shape, &effects),
+        variables: VARIABLE_NAMES
+            .iter()
+            .zip(values)
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    })
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        any::<Index>().prop_map(Mutation::DeleteWord),
+        any::<Index>().prop_map(Mutation::DuplicateWord),
+        (any::<Index>(), any::<Index>()).prop_map(|(a, b)| Mutation::SwapWords(a, b)),
+        any::<Index>().prop_map(Mutation::Truncate),
+    ]
+}
+
+fn rules_text(shape: AbilityShape, effects: &[usize]) -> String {
+    let sentences = effects.iter().map(|&i| format!("{}.", EFFECTS[i])).collect::<Vec<_>>();
+    let body = sentences.join(" ");
+    match shape {
+        AbilityShape::Event => body,
+        AbilityShape::KeywordTrigger(i) => format!("{} {body}", KEYWORD_TRIGGERS[i]),
+        AbilityShape::EventTrigger(i) => {
+            format!("{} {}", EVENT_TRIGGERS[i], lowercase_first(&body))
+        }
+        AbilityShape::Activated(i) => format!("{}: {body}", ACTIVATED_COSTS[i]),
+    }
+}
+
+/// Converts a sentence-initial template into its mid-sentence form, e.g.
+/// "{Dissolve} an enemy" into "{dissolve} an enemy".
+fn lowercase_first(text: &str) -> String {
+    let prefix_len = if text.starts_with('{') { 1 } else { 0 };
+    let (prefix, rest) = text.split_at(prefix_len);
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(first) => format!("{prefix}{}{}", first.to_lowercase(), chars.as_str()),
+        None => text.to_string(),
+    }
+}
+
+fn apply_mutation(text: &str, mutation: Mutation) -> String {
+    let mut words = text.split(' ').collect::<Vec<_>>();
+    match mutation {
+        Mutation::DeleteWord(i) => {
+            words.remove(i.index(words.len()));
+        }
+        Mutation::DuplicateWord(i) => {
+            let index = i.index(words.len());
+            words.insert(index, words[index]);
+        }
+        Mutation::SwapWords(a, b) => {
+            let len = words.len();
+            words.swap(a.index(len), b.index(len));
+        }
+        Mutation::Truncate(i) => {
+            return text.chars().take(i.index(text.chars().count())).collect();
+        }
+    }
+    words.join(" ")
+}
+
+/// Checks that a generated case which parses successfully serializes back to
+/// the same rendered text. Inputs which fail to parse are ignored, since the
+/// generator may produce unsupported combinations.
+fn check_round_trip(case: &FuzzCase) -> TestCaseResult {
+    let Ok(ability) = parse(&case.text, &case.variables) else {
+        return Ok(());
+    };
+    let serialized = ability_serializer::serialize_ability(&ability).text;
+    let bindings = VariableBindings::parse(&case.variables).unwrap();
+    let rendered = test_helpers::eval_str(&case.text, &bindings);
+    prop_assert_eq!(serialized, rendered, "Rendered output mismatch for {:?}", case.text);
+    Ok(())
+}
+
+fn parse(text: &str, variables: &str) -> Result<Ability, String> {
+    let bindings =
+        VariableBindings::parse(variables).map_err(|e| format!("variable parse error: {e:?}"))?;
+    let lex_result = lexer_tokenize::lex(text).map_err(|e| format!("lexer error: {e:?}"))?;
+    let resolved = parser_substitutions::resolve_variables(&lex_result.tokens, &bindings)
+        .map_err(|e| format!("variable resolution error: {e}"))?;
+    test_helpers::parse_resolved_ability(&resolved)
+}
+
+/// Runs `f`, converting any panic into a test failure for `text`.
+fn catch_panic(text: &str, f: impl FnOnce() -> TestCaseResult) -> TestCaseResult {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic| {
+        Err(TestCaseError::fail(format!("Panic on {text:?}: {}", panic_message(panic))))
+    })
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&'static str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
@@ -1,5 +1,8 @@
 use ability_data::ability::Ability;
+use parser::serializer::ability_serializer::{self, SerializationStyle, SerializerOptions};
+use parser::variables::parser_bindings::VariableBindings;
 use parser_tests::test_helpers::*;
+use strings::number_format;
 
 #[test]
 fn test_full_ability_at_end_of_turn_gain_energy() {
@@ -18,3 +21,43 @@ fn test_full_ability_gain_energy() {
     let ability = parse_ability("Gain {e}.", "e: 3");
     assert!(matches!(ability, Ability::Event(_)));
 }
+
+#[test]
+fn test_reminder_text_is_ignored() {
+    let with_reminder =
+        parse_ability("{Foresee($f)}. (Look at the top {$f} cards of your deck.)", "f: 2");
+    assert_eq!(with_reminder, parse_ability("{Foresee($f)}.", "f: 2"));
+}
+
+#[test]
+fn test_serialize_with_reminder_text() {
+    let input = "{Foresee($f)}. (Look at the top {$f} cards of your deck. You may put them into your void or put them back in any order.)";
+    let ability = parse_ability(input, "f: 2");
+    let serialized =
+        ability_serializer::serialize_ability_with_options(&ability, SerializerOptions {
+            style: SerializationStyle::Verbose,
+            ..SerializerOptions::default()
+        });
+    assert_eq!(
+        serialized.text,
+        number_format::spell_out_numbers(&eval_str(
+            input,
+            &VariableBindings::parse("f: 2").unwrap()
+        ))
+    );
+    assert_eq!(
+        ability_serializer::serialize_ability(&ability).text,
+        eval_str("{Foresee($f)}.", &VariableBindings::parse("f: 2").unwrap())
+    );
+}
+
+#[test]
+fn test_verbose_style_spells_out_numbers() {
+    let ability = parse_ability("Draw {cards($c)}. Gain {energy($e)}.", "c: 2\ne: 3");
+    let serialized =
+        ability_serializer::serialize_ability_with_options(&ability, SerializerOptions {
+            style: SerializationStyle::Verbose,
+            ..SerializerOptions::default()
+        });
+    assert_eq!(serialized.text, "Draw two cards. Gain <color=#00838F>3\u{25CF}</color>.");
+}
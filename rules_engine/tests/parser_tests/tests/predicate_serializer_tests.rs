@@ -134,6 +134,30 @@ fn serialize_predicate_your_subtype_singular_and_plural() {
     );
 }
 
+#[test]
+fn serialize_predicate_subtype_or_subtype_singular_and_plural() {
+    register_phrases();
+
+    let or_predicate = CardPredicate::Or(vec![
+        CardPredicate::CharacterType(CardSubtype::Warrior),
+        CardPredicate::CharacterType(CardSubtype::Mage),
+    ]);
+    let enemy_phrase =
+        predicate_serializer::serialize_predicate(&Predicate::Enemy(or_predicate.clone()));
+    assert_eq!(
+        "an enemy <color=#2E7D32><b>Warrior</b></color> or <color=#2E7D32><b>Mage</b></color>",
+        enemy_phrase.to_string()
+    );
+    assert_eq!(
+        "all enemy <color=#2E7D32><b>Warriors</b></color> or <color=#2E7D32><b>Mages</b></color>",
+        strings::collection_all(enemy_phrase).to_string()
+    );
+    assert_eq!(
+        "a <color=#2E7D32><b>Warrior</b></color> or <color=#2E7D32><b>Mage</b></color>",
+        predicate_serializer::serialize_predicate(&Predicate::Any(or_predicate)).to_string()
+    );
+}
+
 #[test]
 fn serialize_predicate_another_subtype_spark_equal_to_count() {
     register_phrases();
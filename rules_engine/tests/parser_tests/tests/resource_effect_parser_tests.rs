@@ -147,10 +147,11 @@ fn test_multiply_energy_gain_from_card_effects() {
     );
     assert_ron_snapshot!(result, @r###"
     Event(EventAbility(
-      effect: Effect(CreateStaticAbilityUntilEndOfTurn(
+      effect: Effect(CreateStaticAbility(
         ability: MultiplyEnergyGainFromCardEffects(
           multiplier: 2,
         ),
+        duration: ThisTurn,
       )),
     ))
     "###);
@@ -164,10 +165,11 @@ fn test_multiply_card_draw_from_card_effects() {
     );
     assert_ron_snapshot!(result, @r###"
     Event(EventAbility(
-      effect: Effect(CreateStaticAbilityUntilEndOfTurn(
+      effect: Effect(CreateStaticAbility(
         ability: MultiplyCardDrawFromCardEffects(
           multiplier: 3,
         ),
+        duration: ThisTurn,
       )),
     ))
     "###);
@@ -1,5 +1,6 @@
 mod round_trip_tests {
     mod activated_ability_round_trip_tests;
+    mod card_pool_snapshot_tests;
     mod cards_toml_round_trip_tests;
     mod dreamwell_toml_round_trip_tests;
     mod event_effect_round_trip_tests;
@@ -138,6 +138,11 @@ fn test_pay_energy_draw_per_energy_spent() {
     );
 }
 
+#[test]
+fn test_x_energy_draw_x_cards() {
+    assert_rendered_match("{x}: Draw X cards.", "");
+}
+
 #[test]
 fn test_abandon_or_discard_dissolve_enemy() {
     assert_rendered_match("Abandon an ally or discard {cards($d)}: {Dissolve} an enemy.", "d: 1");
@@ -189,3 +194,43 @@ fn test_return_all_but_one_ally_draw_per_returned() {
 fn test_pay_variable_energy_draw_per_energy() {
     assert_rendered_match("Pay 1 or more {energy_symbol}: Draw {cards($c)} for each {energy_symbol} spent, then discard {cards($d)}.", "c: 1\nd: 2");
 }
+
+#[test]
+fn test_abandon_count_allied_subtype_draw() {
+    assert_rendered_match(
+        "Abandon {count_allied_subtype($a, $t)}: Draw {cards($c)}.",
+        "a: 2\nt: Warrior\nc: 1",
+    );
+}
+
+#[test]
+fn test_abandon_count_allies_with_cost_gain_points() {
+    assert_rendered_match(
+        "Abandon {count_allies($a)} with cost {energy($e)} or more: Gain {points($p)}.",
+        "a: 2\ne: 3\np: 1",
+    );
+}
+
+#[test]
+fn test_abandon_ally_with_cost_gain_points() {
+    assert_rendered_match(
+        "Abandon an ally with cost {energy($e)} or more: Gain {points($p)}.",
+        "e: 3\np: 1",
+    );
+}
+
+#[test]
+fn test_block_layout_paragraphs() {
+    assert_rendered_match(
+        "{energy($e)}:\n> Draw {cards($c)}. Discard {cards($d)}.\n> Gain {points($p)}.",
+        "e: 2\nc: 2\nd: 3\np: 1",
+    );
+}
+
+#[test]
+fn test_block_layout_once_per_turn() {
+    assert_rendered_match(
+        "Abandon an ally, once per turn:\n> Draw {cards($c)}.\n> Discard {cards($d)}.",
+        "c: 1\nd: 1",
+    );
+}
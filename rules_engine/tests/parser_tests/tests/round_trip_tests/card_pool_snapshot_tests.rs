@@ -0,0 +1,254 @@
+//! Snapshot tests over the whole tabula card pool.
+//!
+//! Parses the rules text of every card in cards.toml, dreamwell.toml,
+//! test-cards.toml, and test-dreamwell.toml, serializes each ability back to
+//! display text, and compares the result against a checked-in snapshot file
+//! for each source. Serializer changes show up as reviewable diffs over the
+//! whole card pool.
+//!
+//! Entries are keyed by card id and ability index, so renaming a card shows
+//! up as a change to its entry rather than a removal and an addition.
+//!
+//! After an intended change, regenerate the snapshots with
+//! `just parser-update-snapshots`, which runs these tests with
+//! `UPDATE_CARD_POOL_SNAPSHOTS=1`, and review the diff. A missing snapshot
+//! file fails the test unless regeneration was requested.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use parser::lexer::lexer_tokenize;
+use parser::serializer::ability_serializer;
+use parser::variables::parser_bindings::VariableBindings;
+use parser::variables::parser_substitutions;
+use parser_tests::test_helpers;
+use serde::Deserialize;
+
+const SNAPSHOT_DIR: &str = "tests/round_trip_tests/fixtures/card_pool_snapshots";
+const TABULA_DIR: &str = "../../tabula";
+const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_CARD_POOL_SNAPSHOTS";
+const MAX_REPORTED_DIFFS: usize = 20;
+
+const CARDS: SnapshotSource = SnapshotSource {
+    toml_file: "cards.toml",
+    table: "cards",
+    snapshot_file: "cards.txt",
+    skip_parse_failures: false,
+};
+const DREAMWELL: SnapshotSource = SnapshotSource {
+    toml_file: "dreamwell.toml",
+    table: "dreamwell",
+    snapshot_file: "dreamwell.txt",
+    skip_parse_failures: false,
+};
+const TEST_CARDS: SnapshotSource = SnapshotSource {
+    toml_file: "test-cards.toml",
+    table: "test-cards",
+    snapshot_file: "test-cards.txt",
+    skip_parse_failures: false,
+};
+const TEST_DREAMWELL: SnapshotSource = SnapshotSource {
+    toml_file: "test-dreamwell.toml",
+    table: "test-dreamwell",
+    snapshot_file: "test-dreamwell.txt",
+    skip_parse_failures: true,
+};
+
+/// A tabula TOML file whose rules text is snapshotted.
+struct SnapshotSource {
+    toml_file: &'static str,
+    table: &'static str,
+    snapshot_file: &'static str,
+
+    /// Test dreamwell cards include deliberately unsupported rules text,
+    /// which is omitted from the snapshot instead of failing the test.
+    skip_parse_failures: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CardRow {
+    id: String,
+    name: String,
+    #[serde(rename = "rules-text")]
+    rules_text: Option<String>,
+    variables: Option<String>,
+}
+
+/// Snapshot of a single ability.
+struct SnapshotEntry {
+    id: String,
+    name: String,
+    ability_index: usize,
+    text: String,
+    variables: String,
+    rendered: String,
+}
+
+#[test]
+fn test_cards_snapshot() {
+    check_snapshot(&CARDS);
+}
+
+#[test]
+fn test_dreamwell_snapshot() {
+    check_snapshot(&DREAMWELL);
+}
+
+#[test]
+fn test_test_cards_snapshot() {
+    check_snapshot(&TEST_CARDS);
+}
+
+#[test]
+fn test_test_dreamwell_snapshot() {
+    check_snapshot(&TEST_DREAMWELL);
+}
+
+/// Compares the generated snapshot for `source` against its snapshot file,
+/// writing the file instead if regeneration was requested.
+fn check_snapshot(source: &SnapshotSource) {
+    let path = PathBuf::from(SNAPSHOT_DIR).join(source.snapshot_file);
+    let generated = generate_snapshot(source);
+
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        std::fs::create_dir_all(SNAPSHOT_DIR)
+            .unwrap_or_else(|e| panic!("Failed to create {SNAPSHOT_DIR}: {e}"));
+        std::fs::write(&path, &generated)
+            .unwrap_or_else(|e| panic!("Failed to write snapshot at {}: {e}", path.display()));
+        return;
+    }
+
+    assert!(
+        path.exists(),
+        "Missing snapshot at {}. Run with {UPDATE_SNAPSHOTS_ENV}=1 to create it.",
+        path.display()
+    );
+    let stored = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read snapshot at {}: {e}", path.display()));
+    if generated != stored {
+        let diffs = snapshot_diffs(&stored, &generated);
+        panic!(
+            "Snapshot mismatch for {} ({} changed entries, showing up to {MAX_REPORTED_DIFFS}):\n\n{}\n\n\
+             If these changes are intended, re-run with {UPDATE_SNAPSHOTS_ENV}=1 and review the diff.",
+            source.toml_file,
+            diffs.len(),
+            diffs.iter().take(MAX_REPORTED_DIFFS).cloned().collect::<Vec<_>>().join("\n\n")
+        );
+    }
+}
+
+/// Renders every ability in `source`, sorted by card name, card id, and ability
+/// index.
+fn generate_snapshot(source: &SnapshotSource) -> String {
+    let path = PathBuf::from(TABULA_DIR).join(source.toml_file);
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    let mut table: toml::Table = toml::from_str(&content)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+    let rows: Vec<CardRow> = table
+        .remove(source.table)
+        .unwrap_or_else(|| panic!("Missing [[{}]] table in {}", source.table, path.display()))
+        .try_into()
+        .unwrap_or_else(|e| panic!("Failed to read rows from {}: {e}", path.display()));
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for card in &rows {
+        let Some(rules_text) = &card.rules_text else {
+            continue;
+        };
+        let variables = card.variables.as_deref().unwrap_or("");
+        for (ability_index, ability_block) in rules_text.split("\n\n").enumerate() {
+            let ability_block = ability_block.trim();
+            if ability_block.is_empty() {
+                continue;
+            }
+            match render_ability(ability_block, variables) {
+                Ok(rendered) => entries.push(SnapshotEntry {
+                    id: card.id.clone(),
+                    name: card.name.clone(),
+                    ability_index,
+                    text: ability_block.to_string(),
+                    variables: variables.to_string(),
+                    rendered,
+                }),
+                Err(_) if source.skip_parse_failures => {}
+                Err(error) => errors.push(format!("{}|{ability_index}|{error}", card.name)),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        panic!(
+            "Failed to render {} abilities in {}:\n{}",
+            errors.len(),
+            source.toml_file,
+            errors.join("\n")
+        );
+    }
+
+    entries
+        .sort_by(|a, b| (&a.name, &a.id, a.ability_index).cmp(&(&b.name, &b.id, b.ability_index)));
+    let mut snapshot = entries.iter().map(format_entry).collect::<Vec<_>>().join("\n\n");
+    snapshot.push('\n');
+    snapshot
+}
+
+fn render_ability(ability_text: &str, variables: &str) -> Result<String, String> {
+    let bindings =
+        VariableBindings::parse(variables).map_err(|e| format!("variable parse: {e:?}"))?;
+    let lex_result = lexer_tokenize::lex(ability_text).map_err(|e| format!("lex: {e:?}"))?;
+    let resolved = parser_substitutions::resolve_variables(&lex_result.tokens, &bindings)
+        .map_err(|e| format!("resolve: {e}"))?;
+    let ability =
+        test_helpers::parse_resolved_ability(&resolved).map_err(|e| format!("parse: {e}"))?;
+    Ok(ability_serializer::serialize_ability(&ability).text)
+}
+
+/// Formats an entry as a `[id #index]` header line followed by one line per
+/// field. Multi-line values are continued on lines indented by two spaces.
+fn format_entry(entry: &SnapshotEntry) -> String {
+    let mut lines = vec![format!("[{} #{}]", entry.id, entry.ability_index)];
+    lines.push(format_field("name", &entry.name));
+    lines.push(format_field("text", &entry.text));
+    if !entry.variables.is_empty() {
+        lines.push(format_field("variables", &entry.variables));
+    }
+    lines.push(format_field("rendered", &entry.rendered));
+    lines.join("\n")
+}
+
+fn format_field(name: &str, value: &str) -> String {
+    format!("{name}: {}", value.replace('\n', "\n  "))
+}
+
+/// Describes the entries which were added, removed, or changed between two
+/// snapshots, matching entries by card id and ability index.
+fn snapshot_diffs(stored: &str, generated: &str) -> Vec<String> {
+    let stored = entries_by_header(stored);
+    let generated = entries_by_header(generated);
+    let mut diffs = Vec::new();
+    for (header, entry) in &generated {
+        match stored.get(header) {
+            None => diffs.push(format!("Added:\n{entry}")),
+            Some(previous) if previous != entry => {
+                diffs.push(format!("Changed from:\n{previous}\nto:\n{entry}"));
+            }
+            Some(_) => {}
+        }
+    }
+    for (header, entry) in &stored {
+        if !generated.contains_key(header) {
+            diffs.push(format!("Removed:\n{entry}"));
+        }
+    }
+    diffs
+}
+
+fn entries_by_header(snapshot: &str) -> BTreeMap<&str, &str> {
+    snapshot
+        .trim_end()
+        .split("\n\n")
+        .map(|entry| (entry.lines().next().unwrap_or_default(), entry))
+        .collect()
+}
@@ -92,6 +92,14 @@ fn test_dissolve_enemy_by_subtype_count() {
     );
 }
 
+#[test]
+fn test_dissolve_enemy_subtype_or_subtype() {
+    assert_rendered_match(
+        "{Dissolve} an enemy {subtype($t)} or {subtype($u)}.",
+        "t: Warrior\nu: Mage",
+    );
+}
+
 #[test]
 fn test_dissolve_enemy_by_void_count() {
     assert_rendered_match(
@@ -237,6 +245,14 @@ fn test_may_return_character_from_void_draw() {
     );
 }
 
+#[test]
+fn test_may_discard_if_you_do_draw() {
+    assert_rendered_match(
+        "You may discard {cards($d)}. If you do, draw {cards($c)}.",
+        "d: 1\nc: 2",
+    );
+}
+
 #[test]
 fn test_draw_per_cards_played() {
     assert_rendered_match("Draw {cards($c)} for each card you have played this turn.", "c: 1");
@@ -387,6 +403,29 @@ fn test_ally_gains_spark_per_subtype() {
     );
 }
 
+#[test]
+fn test_put_spark_counters_on_ally() {
+    assert_rendered_match("Put {spark_counters($n)} on an ally.", "n: 2");
+}
+
+#[test]
+fn test_put_spark_counter_on_ally() {
+    assert_rendered_match("Put {spark_counters($n)} on an ally.", "n: 1");
+}
+
+#[test]
+fn test_remove_spark_counters_from_enemy() {
+    assert_rendered_match("Remove {spark_counters($n)} from an enemy.", "n: 3");
+}
+
+#[test]
+fn test_draw_cards_for_each_spark_counter() {
+    assert_rendered_match(
+        "Draw {cards($c)} for each +1 spark counter on this character.",
+        "c: 1",
+    );
+}
+
 // ============================================================================
 // Materialize effects
 // ============================================================================
@@ -32,6 +32,14 @@ fn test_materialized_disable_activated_abilities() {
     assert_rendered_match("{Materialized} Disable the activated abilities of an enemy while this character is in play.", "");
 }
 
+#[test]
+fn test_materialized_disable_activated_abilities_until_your_next_turn() {
+    assert_rendered_match(
+        "{Materialized} Disable the activated abilities of an enemy until your next turn.",
+        "",
+    );
+}
+
 #[test]
 fn test_materialized_draw_one() {
     assert_rendered_match("{Materialized} Draw {cards($c)}.", "c: 1");
@@ -3,6 +3,7 @@
 //! Tests abilities that trigger on events like "When you...",
 //! "Once per turn, when...", "At the end of...", etc.
 
+use parser::keywords::keyword_registry;
 use parser_tests::test_helpers::*;
 
 #[test]
@@ -96,6 +97,11 @@ fn test_end_of_turn_gain_energy() {
     assert_rendered_match("At the end of your turn, gain {energy($e)}.", "e: 2");
 }
 
+#[test]
+fn test_start_of_turn_gain_spark() {
+    assert_rendered_match("At the start of your turn, this character gains +{$s} spark.", "s: 1");
+}
+
 #[test]
 fn test_play_fast_character_trigger_gain_energy() {
     assert_rendered_match("Characters in your hand have {fast}.", "");
@@ -308,3 +314,30 @@ fn test_until_end_of_turn_ally_leaves_trigger_gain_energy() {
         "e: 2",
     );
 }
+
+#[test]
+fn test_until_your_next_turn_ally_leaves_trigger_gain_energy() {
+    assert_rendered_match(
+        "Until your next turn, when an ally leaves play, gain {energy($e)}.",
+        "e: 2",
+    );
+}
+
+#[test]
+fn test_resolve_ability_word_judgment_draw() {
+    assert_rendered_match("{Resolve} -- {Judgment} Draw {cards($c)}.", "c: 1");
+}
+
+#[test]
+fn test_materialized_trigger_this_cards_resolve_ability() {
+    assert_rendered_match("{Materialized} Trigger this card's {Resolve} ability.", "");
+}
+
+#[test]
+fn test_registered_trigger_keywords() {
+    for (name, _) in keyword_registry::trigger_keywords() {
+        let mut chars = name.chars();
+        let capitalized = chars.next().unwrap().to_uppercase().chain(chars).collect::<String>();
+        assert_rendered_match(&format!("{{{capitalized}}} Draw {{cards($c)}}."), "c: 1");
+    }
+}
@@ -9,8 +9,10 @@ const SERIALIZER_FILES: &[&str] = &[
     "ability_serializer.rs",
     "condition_serializer.rs",
     "cost_serializer.rs",
+    "duration_serializer.rs",
     "effect_serializer.rs",
     "predicate_serializer.rs",
+    "reminder_text_serializer.rs",
     "serializer_utils.rs",
     "static_ability_serializer.rs",
     "trigger_serializer.rs",
@@ -111,3 +111,47 @@ fn test_judgment_each_allied_subtype_gains_spark_for_each_allied_subtype() {
     ))
     "###);
 }
+
+#[test]
+fn test_put_spark_counters_on_ally() {
+    let result = parse_ability("Put {spark_counters($n)} on an ally.", "n: 2");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: Effect(PutSparkCounters(
+        target: Another(Character),
+        count: 2,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_judgment_remove_spark_counter_from_this_character() {
+    let result =
+        parse_ability("{Judgment} Remove {spark_counters($n)} from this character.", "n: 1");
+    assert_ron_snapshot!(result, @r###"
+    Triggered(TriggeredAbility(
+      trigger: Keywords([
+        Judgment,
+      ]),
+      effect: Effect(RemoveSparkCounters(
+        target: This,
+        count: 1,
+      )),
+    ))
+    "###);
+}
+
+#[test]
+fn test_draw_cards_for_each_spark_counter() {
+    let result =
+        parse_ability("Draw {cards($c)} for each +1 spark counter on this character.", "c: 1");
+    assert_ron_snapshot!(result, @r###"
+    Event(EventAbility(
+      effect: Effect(DrawCardsForEach(
+        count: 1,
+        for_each: SparkCountersOn(This),
+      )),
+    ))
+    "###);
+}
@@ -143,7 +143,6 @@ fn test_characters_in_hand_have_fast_with_triggered() {
         )),
         options: Some(TriggeredAbilityOptions(
           once_per_turn: true,
-          until_end_of_turn: false,
         )),
       )),
     ]
@@ -370,6 +369,25 @@ fn test_while_count_or_more_cards_in_void_have_reclaim() {
     "###);
 }
 
+#[test]
+fn test_as_long_as_count_or_more_cards_in_void_spark_bonus() {
+    let result = parse_ability(
+        "As long as you have {count} or more cards in your void, allied {plural_subtype} have +{s} spark.",
+        "n: 3, t: Warrior, s: 2",
+    );
+    assert_ron_snapshot!(result, @r###"
+    Static(WithOptions(StaticAbilityWithOptions(
+      ability: SparkBonusOtherCharacters(
+        matching: CharacterType(Warrior),
+        added_spark: Spark(2),
+      ),
+      condition: Some(CardsInVoidCount(
+        count: 3,
+      )),
+    )))
+    "###);
+}
+
 #[test]
 fn test_play_only_from_void() {
     let result = parse_ability("You may only play this character from your void.", "");
@@ -14,6 +14,21 @@ fn test_at_end_of_turn_gain_energy() {
     "###);
 }
 
+#[test]
+fn test_at_start_of_turn_this_character_gains_spark() {
+    let result =
+        parse_ability("At the start of your turn, this character gains +{s} spark.", "s: 1");
+    assert_ron_snapshot!(result, @r###"
+    Triggered(TriggeredAbility(
+      trigger: StartOfYourTurn,
+      effect: Effect(GainsSpark(
+        target: This,
+        gains: Spark(1),
+      )),
+    ))
+    "###);
+}
+
 #[test]
 fn test_when_you_discard_a_card_gain_points() {
     let result = parse_ability("When you discard a card, gain {points}.", "p: 1");
@@ -65,7 +80,6 @@ fn test_once_per_turn_when_you_discard_a_card_gain_energy_and_kindle() {
       ]),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -96,7 +110,6 @@ fn test_once_per_turn_when_you_materialize_a_character_gain_energy() {
       )),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -120,7 +133,6 @@ fn test_once_per_turn_when_you_materialize_a_character_with_cost_or_less_draw_ca
       )),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -140,7 +152,6 @@ fn test_once_per_turn_when_you_materialize_a_subtype_draw_cards() {
       )),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -369,7 +380,7 @@ fn test_dissolved_a_subtype_in_void_gains_reclaim() {
       effect: Effect(GainsReclaim(
         target: YourVoid(CharacterType(Warrior)),
         count: Exactly(1),
-        this_turn: false,
+        duration: None,
         cost: None,
       )),
     ))
@@ -390,7 +401,7 @@ fn test_dissolved_capitalized_subtype_directive_in_void_gains_reclaim() {
       effect: Effect(GainsReclaim(
         target: YourVoid(CharacterType(Survivor)),
         count: Exactly(1),
-        this_turn: false,
+        duration: None,
         cost: None,
       )),
     ))
@@ -558,7 +569,6 @@ fn test_once_per_turn_when_you_play_a_fast_card_draw_cards() {
       )),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -570,17 +580,14 @@ fn test_until_end_of_turn_when_you_play_a_character_draw_cards() {
         parse_ability("Until end of turn, when you play a character, draw {cards}.", "c: 2");
     assert_ron_snapshot!(result, @r###"
     Event(EventAbility(
-      effect: Effect(CreateTriggerUntilEndOfTurn(
+      effect: Effect(CreateTrigger(
         trigger: TriggeredAbility(
           trigger: Play(Your(Character)),
           effect: Effect(DrawCards(
             count: 2,
           )),
-          options: Some(TriggeredAbilityOptions(
-            once_per_turn: false,
-            until_end_of_turn: true,
-          )),
         ),
+        duration: ThisTurn,
       )),
     ))
     "###);
@@ -591,17 +598,14 @@ fn test_until_end_of_turn_when_an_ally_leaves_play_gain_energy() {
     let result = parse_ability("Until end of turn, when an ally leaves play, gain {e}.", "e: 1");
     assert_ron_snapshot!(result, @r###"
     Event(EventAbility(
-      effect: Effect(CreateTriggerUntilEndOfTurn(
+      effect: Effect(CreateTrigger(
         trigger: TriggeredAbility(
           trigger: LeavesPlay(Another(Character)),
           effect: Effect(GainEnergy(
             gains: Energy(1),
           )),
-          options: Some(TriggeredAbilityOptions(
-            once_per_turn: false,
-            until_end_of_turn: true,
-          )),
         ),
+        duration: ThisTurn,
       )),
     ))
     "###);
@@ -874,7 +878,6 @@ fn test_once_per_turn_play_fast_character_gain_energy() {
       )),
       options: Some(TriggeredAbilityOptions(
         once_per_turn: true,
-        until_end_of_turn: false,
       )),
     ))
     "###);
@@ -979,7 +982,7 @@ fn test_when_you_draw_cards_in_turn_while_in_void_gains_reclaim() {
         effect: GainsReclaim(
           target: It,
           count: Exactly(1),
-          this_turn: true,
+          duration: Some(ThisTurn),
           cost: Some(Energy(2)),
         ),
         optional: false,
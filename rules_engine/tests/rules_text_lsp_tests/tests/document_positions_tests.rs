@@ -0,0 +1,24 @@
+use lsp_types::Position;
+use rules_text_lsp::document_positions;
+
+#[test]
+fn position_counts_lines_and_utf16_columns() {
+    let document = "first\nsé😀cond";
+    let offset = document.find("cond").unwrap();
+    assert_eq!(document_positions::position(document, offset), Position { line: 1, character: 4 });
+}
+
+#[test]
+fn offset_inverts_position() {
+    let document = "first\nsé😀cond\nthird";
+    for offset in document.char_indices().map(|(index, _)| index) {
+        let position = document_positions::position(document, offset);
+        assert_eq!(document_positions::offset(document, position), offset);
+    }
+}
+
+#[test]
+fn offset_clamps_to_end_of_line() {
+    let document = "short\nline";
+    assert_eq!(document_positions::offset(document, Position { line: 0, character: 80 }), 5);
+}
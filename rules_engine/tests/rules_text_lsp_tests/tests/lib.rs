@@ -0,0 +1,5 @@
+mod document_positions_tests;
+mod rules_text_completion_tests;
+mod rules_text_diagnostics_tests;
+mod rules_text_hover_tests;
+mod toml_rules_text_tests;
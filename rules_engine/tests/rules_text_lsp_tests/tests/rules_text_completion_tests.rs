@@ -0,0 +1,29 @@
+use rules_text_lsp::rules_text_completion;
+
+#[test]
+fn completions_match_typed_prefix() {
+    let document = "[[cards]]\nrules-text = \"Draw {car\"\n";
+    let offset = document.find("car\"").unwrap() + 3;
+    let completions = rules_text_completion::completions(document, offset).unwrap();
+
+    assert_eq!(&document[completions.range], "car");
+    assert!(completions.templates.contains(&"cards($c)".to_string()));
+    assert!(completions.templates.iter().all(|template| template.starts_with("car")));
+}
+
+#[test]
+fn completions_preserve_capitalization() {
+    let document = "[[cards]]\nrules-text = \"{Dis\"\n";
+    let offset = document.find("Dis").unwrap() + 3;
+    let completions = rules_text_completion::completions(document, offset).unwrap();
+
+    assert!(completions.templates.contains(&"Dissolve".to_string()));
+    assert!(completions.templates.contains(&"Discover".to_string()));
+}
+
+#[test]
+fn completions_after_closed_directive_return_none() {
+    let document = "[[cards]]\nrules-text = \"{Dissolve} an\"\n";
+    let offset = document.find("an\"").unwrap() + 2;
+    assert!(rules_text_completion::completions(document, offset).is_none());
+}
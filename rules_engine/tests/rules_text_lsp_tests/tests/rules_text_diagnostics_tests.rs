@@ -0,0 +1,42 @@
+use rules_text_lsp::rules_text_diagnostics;
+
+#[test]
+fn valid_rules_text_has_no_diagnostics() {
+    let document = "[[cards]]\nrules-text = \"Draw {cards($c)}.\"\nvariables = \"c: 2\"\n";
+    assert!(rules_text_diagnostics::document_diagnostics(document).is_empty());
+}
+
+#[test]
+fn unresolved_variable_is_reported_at_directive() {
+    let document = "[[cards]]\nrules-text = \"Draw {cards($x)}.\"\nvariables = \"c: 2\"\n";
+    let diagnostics = rules_text_diagnostics::document_diagnostics(document);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(&document[diagnostics[0].range.clone()], "{cards($x)}");
+    assert!(diagnostics[0].message.contains("'x'"), "{}", diagnostics[0].message);
+}
+
+#[test]
+fn unclosed_brace_is_reported_in_second_ability() {
+    let document = "[[cards]]\nrules-text = \"\"\"\n{Judgment} Draw a card.\n\nGain {energy($e).\n\"\"\"\nvariables = \"e: 1\"\n";
+    let diagnostics = rules_text_diagnostics::document_diagnostics(document);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(document[diagnostics[0].range.clone()].starts_with("{energy"));
+    assert!(diagnostics[0].message.starts_with("Unclosed brace"));
+}
+
+#[test]
+fn invalid_variables_are_reported_on_variables_field() {
+    let document = "[[cards]]\nrules-text = \"Draw {cards($c)}.\"\nvariables = \"c 2\"\n";
+    let diagnostics = rules_text_diagnostics::document_diagnostics(document);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(&document[diagnostics[0].range.clone()], "\"c 2\"");
+}
+
+#[test]
+fn invalid_toml_is_reported() {
+    let diagnostics = rules_text_diagnostics::document_diagnostics("[[cards]\n");
+    assert_eq!(diagnostics.len(), 1);
+}
@@ -0,0 +1,37 @@
+use rules_text_lsp::rules_text_hover;
+
+#[test]
+fn hover_shows_keyword_reminder_text() {
+    let document = "[[cards]]\nrules-text = \"{Dissolve} an enemy.\"\n";
+    let offset = document.find("Dissolve").unwrap();
+    let hover = rules_text_hover::hover(document, offset).unwrap();
+
+    assert_eq!(&document[hover.range], "{Dissolve}");
+    assert_eq!(hover.contents, "`{Dissolve}`: Send a character to the void.");
+}
+
+#[test]
+fn hover_uses_bound_variable_values() {
+    let document = "[[cards]]\nrules-text = \"{Kindle($k)}.\"\nvariables = \"k: 2\"\n";
+    let offset = document.find("Kindle").unwrap();
+    let hover = rules_text_hover::hover(document, offset).unwrap();
+
+    assert_eq!(
+        hover.contents,
+        "`{Kindle($k)}`: Add 2 spark to your character with the highest spark.\n\n`$k` = 2"
+    );
+}
+
+#[test]
+fn hover_shows_variables_for_phrases() {
+    let document = "[[cards]]\nrules-text = \"Draw {cards($c)}.\"\nvariables = \"c: 3\"\n";
+    let offset = document.find("cards").unwrap();
+    assert_eq!(rules_text_hover::hover(document, offset).unwrap().contents, "`$c` = 3");
+}
+
+#[test]
+fn hover_outside_directive_returns_none() {
+    let document = "[[cards]]\nrules-text = \"{Dissolve} an enemy.\"\n";
+    let offset = document.find("enemy").unwrap();
+    assert!(rules_text_hover::hover(document, offset).is_none());
+}
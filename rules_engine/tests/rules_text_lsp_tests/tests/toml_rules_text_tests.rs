@@ -0,0 +1,50 @@
+use rules_text_lsp::toml_rules_text;
+
+#[test]
+fn rules_text_fields_maps_basic_string_offsets() {
+    let document = "[[cards]]\nrules-text = \"Draw {cards($c)}.\"\nvariables = \"c: 2\"\n";
+    let cards = toml_rules_text::rules_text_fields(document).unwrap();
+
+    assert_eq!(cards.len(), 1);
+    let rules_text = &cards[0].rules_text;
+    assert_eq!(rules_text.value, "Draw {cards($c)}.");
+    assert_eq!(
+        &document[rules_text.document_offset(5)..],
+        "{cards($c)}.\"\nvariables = \"c: 2\"\n"
+    );
+    assert_eq!(cards[0].variables.as_ref().unwrap().value, "c: 2");
+}
+
+#[test]
+fn rules_text_fields_maps_escaped_string_offsets() {
+    let document = "[[cards]]\nrules-text = \"A \\\"quote\\\" {Judgment}\"\n";
+    let cards = toml_rules_text::rules_text_fields(document).unwrap();
+
+    let rules_text = &cards[0].rules_text;
+    assert_eq!(rules_text.value, "A \"quote\" {Judgment}");
+    let directive = rules_text.value.find('{').unwrap();
+    assert_eq!(&document[rules_text.document_offset(directive)..], "{Judgment}\"\n");
+    assert_eq!(rules_text.value_offset(rules_text.document_offset(directive)), Some(directive));
+}
+
+#[test]
+fn rules_text_fields_maps_multiline_string_offsets() {
+    let document = "[[cards]]\nrules-text = \"\"\"\n{Judgment} Draw a card.\n\n{Fast}\n\"\"\"\n";
+    let cards = toml_rules_text::rules_text_fields(document).unwrap();
+
+    let rules_text = &cards[0].rules_text;
+    assert_eq!(rules_text.value, "{Judgment} Draw a card.\n\n{Fast}\n");
+    let fast = rules_text.value.find("{Fast}").unwrap();
+    assert_eq!(&document[rules_text.document_offset(fast)..], "{Fast}\n\"\"\"\n");
+}
+
+#[test]
+fn rules_text_fields_ignores_rows_without_rules_text() {
+    let document = "[[card-lists]]\nname = \"Starter\"\n";
+    assert!(toml_rules_text::rules_text_fields(document).unwrap().is_empty());
+}
+
+#[test]
+fn rules_text_fields_rejects_invalid_toml() {
+    assert!(toml_rules_text::rules_text_fields("[[cards]]\nrules-text = \"unclosed\n").is_err());
+}
@@ -0,0 +1,130 @@
+use schema_generator::csharp_generator;
+use serde_json::json;
+
+#[test]
+fn struct_fields_become_properties() {
+    let schema = json!({
+        "title": "SchemaTypes",
+        "type": "object",
+        "properties": { "card": { "$ref": "#/definitions/CardView" } },
+        "required": ["card"],
+        "definitions": {
+            "CardView": {
+                "description": "A card <visible> to the player.",
+                "type": "object",
+                "properties": {
+                    "card_id": { "type": "string", "format": "uuid" },
+                    "spark": { "$ref": "#/definitions/Spark" },
+                    "rules_text": { "type": ["string", "null"] },
+                    "tags": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["card_id", "spark", "tags"]
+            },
+            "Spark": { "type": "integer", "format": "uint32", "minimum": 0 }
+        }
+    });
+
+    let csharp = csharp_generator::generate(&schema, "Dreamtides.Schema");
+
+    assert!(csharp.contains("namespace Dreamtides.Schema"));
+    assert!(csharp.contains("public sealed partial class SchemaTypes"));
+    assert!(csharp.contains("public CardView Card { get; set; } = default!;"));
+    assert!(csharp.contains("/// A card &lt;visible&gt; to the player."));
+    assert!(csharp.contains("[JsonPropertyName(\"card_id\")]\n        [JsonRequired]"));
+    assert!(csharp.contains("public Guid CardId { get; set; }"));
+    assert!(csharp.contains("public uint Spark { get; set; }"));
+    assert!(csharp.contains("public string? RulesText { get; set; }"));
+    assert!(csharp.contains("public List<string> Tags { get; set; } = default!;"));
+    assert!(!csharp.contains("class Spark"), "Aliases are replaced by the type they wrap");
+}
+
+#[test]
+fn unit_enums_become_string_enums() {
+    let schema = json!({
+        "title": "SchemaTypes",
+        "type": "object",
+        "properties": { "player": { "$ref": "#/definitions/DisplayPlayer" } },
+        "definitions": {
+            "DisplayPlayer": {
+                "oneOf": [
+                    { "type": "string", "enum": ["User"] },
+                    { "description": "The opponent.", "type": "string", "const": "Enemy" }
+                ]
+            }
+        }
+    });
+
+    let csharp = csharp_generator::generate(&schema, "Dreamtides.Schema");
+
+    assert!(csharp.contains(
+        "[JsonConverter(typeof(JsonStringEnumConverter))]\n    public enum DisplayPlayer"
+    ));
+    assert!(csharp.contains("        User,\n"));
+    assert!(csharp.contains("/// The opponent.\n        /// </summary>\n        Enemy,"));
+    assert!(
+        csharp.contains("public DisplayPlayer Player { get; set; }\n"),
+        "Optional value types are not made nullable"
+    );
+}
+
+#[test]
+fn data_enums_are_externally_tagged() {
+    let schema = json!({
+        "title": "SchemaTypes",
+        "type": "object",
+        "properties": { "action": { "$ref": "#/definitions/GameAction" } },
+        "required": ["action"],
+        "definitions": {
+            "GameAction": {
+                "oneOf": [
+                    { "type": "string", "enum": ["NoOp"] },
+                    {
+                        "type": "object",
+                        "properties": { "SelectCard": { "type": "integer", "format": "uint64" } },
+                        "required": ["SelectCard"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Move": {
+                                "type": "object",
+                                "properties": { "x": { "type": "number", "format": "float" } },
+                                "required": ["x"]
+                            }
+                        },
+                        "required": ["Move"],
+                        "additionalProperties": false
+                    }
+                ]
+            }
+        }
+    });
+
+    let csharp = csharp_generator::generate(&schema, "Dreamtides.Schema");
+
+    assert!(csharp.contains(
+        "[JsonConverter(typeof(GameActionConverter))]\n    public sealed partial class GameAction"
+    ));
+    assert!(csharp.contains("public bool NoOp { get; set; }"));
+    assert!(csharp.contains("public ulong? SelectCard { get; set; }"));
+    assert!(csharp.contains("public GameActionMove? Move { get; set; }"));
+    assert!(csharp.contains("public sealed partial class GameActionMove"));
+    assert!(csharp.contains("public float X { get; set; }"));
+    assert!(csharp.contains("public sealed class GameActionConverter : JsonConverter<GameAction>"));
+    assert!(csharp.contains("case \"NoOp\":\n                        result.NoOp = true;"));
+    assert!(
+        csharp.contains(
+            "result.SelectCard = JsonSerializer.Deserialize<ulong?>(ref reader, options);"
+        )
+    );
+    assert!(csharp.contains("writer.WriteStringValue(\"NoOp\");"));
+    assert!(csharp.contains("writer.WritePropertyName(\"Move\");"));
+}
+
+#[test]
+fn pascal_case_field_names() {
+    assert_eq!(csharp_generator::pascal_case("card_id"), "CardId");
+    assert_eq!(csharp_generator::pascal_case("is_3d"), "Is3d");
+    assert_eq!(csharp_generator::pascal_case("2x_speed"), "_2xSpeed");
+}
@@ -0,0 +1,2 @@
+mod csharp_generator_tests;
+mod schema_diff_tests;
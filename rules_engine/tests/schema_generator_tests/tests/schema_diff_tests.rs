@@ -0,0 +1,137 @@
+use schema_generator::schema_diff::{self, ChangeKind};
+use serde_json::{Value, json};
+
+fn base_schema() -> Value {
+    json!({
+        "title": "SchemaTypes",
+        "type": "object",
+        "properties": { "card": { "$ref": "#/definitions/CardView" } },
+        "required": ["card"],
+        "definitions": {
+            "CardView": {
+                "type": "object",
+                "properties": {
+                    "card_id": { "type": "string", "format": "uuid" },
+                    "rules_text": { "anyOf": [{ "type": "string" }, { "type": "null" }] }
+                },
+                "required": ["card_id"]
+            },
+            "GameAction": {
+                "oneOf": [
+                    { "type": "string", "enum": ["NoOp"] },
+                    {
+                        "type": "object",
+                        "properties": { "SelectCard": { "type": "integer", "format": "uint64" } },
+                        "required": ["SelectCard"],
+                        "additionalProperties": false
+                    }
+                ]
+            }
+        }
+    })
+}
+
+fn change_summary(old: &Value, new: &Value) -> Vec<(ChangeKind, String)> {
+    schema_diff::compare(old, new).into_iter().map(|c| (c.kind, c.path)).collect()
+}
+
+#[test]
+fn identical_schemas_have_no_changes() {
+    let schema = base_schema();
+    assert!(schema_diff::compare(&schema, &schema).is_empty());
+}
+
+#[test]
+fn descriptions_are_ignored() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["definitions"]["CardView"]["description"] = json!("A card.");
+    assert!(schema_diff::compare(&old, &new).is_empty());
+}
+
+#[test]
+fn new_optional_property_and_variant_are_additive() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["definitions"]["CardView"]["properties"]["revealed"] = json!({ "type": "boolean" });
+    new["definitions"]["GameAction"]["oneOf"][0]["enum"] = json!(["NoOp", "PassPriority"]);
+    new["definitions"]["Spark"] = json!({ "type": "integer", "format": "uint32" });
+
+    let changes = schema_diff::compare(&old, &new);
+
+    assert!(!schema_diff::has_breaking_changes(&changes));
+    assert_eq!(
+        change_summary(&old, &new),
+        vec![
+            (ChangeKind::Additive, "/definitions/CardView/properties/revealed".to_string()),
+            (ChangeKind::Additive, "/definitions/GameAction/variants/PassPriority".to_string()),
+            (ChangeKind::Additive, "/definitions/Spark".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn removals_are_breaking() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["definitions"]["CardView"]["properties"].as_object_mut().unwrap().remove("rules_text");
+    new["definitions"]["GameAction"]["oneOf"].as_array_mut().unwrap().remove(0);
+
+    assert_eq!(
+        change_summary(&old, &new),
+        vec![
+            (ChangeKind::Breaking, "/definitions/CardView/properties/rules_text".to_string()),
+            (ChangeKind::Breaking, "/definitions/GameAction/variants/NoOp".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn required_property_added_is_breaking() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["definitions"]["CardView"]["properties"]["revealed"] = json!({ "type": "boolean" });
+    new["definitions"]["CardView"]["required"] = json!(["card_id", "revealed"]);
+
+    assert_eq!(
+        change_summary(&old, &new),
+        vec![(ChangeKind::Breaking, "/definitions/CardView/properties/revealed".to_string())]
+    );
+}
+
+#[test]
+fn type_format_and_nullability_changes_are_breaking() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["definitions"]["CardView"]["properties"]["card_id"] = json!({ "type": "integer" });
+    new["definitions"]["CardView"]["properties"]["rules_text"] = json!({ "type": "string" });
+    new["definitions"]["GameAction"]["oneOf"][1]["properties"]["SelectCard"]["format"] =
+        json!("uint32");
+
+    let changes = schema_diff::compare(&old, &new);
+
+    assert!(schema_diff::has_breaking_changes(&changes));
+    let descriptions = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        descriptions,
+        vec![
+            "breaking: /definitions/CardView/properties/card_id: type changed from string to integer",
+            "breaking: /definitions/CardView/properties/rules_text: no longer nullable",
+            "breaking: /definitions/GameAction/variants/SelectCard: format changed from uint64 to \
+         uint32",
+        ]
+    );
+}
+
+#[test]
+fn changed_reference_is_breaking() {
+    let old = base_schema();
+    let mut new = base_schema();
+    new["properties"]["card"] = json!({ "$ref": "#/definitions/GameAction" });
+
+    let changes = schema_diff::compare(&old, &new);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, "/properties/card");
+    assert_eq!(changes[0].description, "type changed from CardView to GameAction");
+}
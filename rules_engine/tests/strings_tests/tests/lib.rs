@@ -0,0 +1 @@
+mod number_format_tests;
@@ -0,0 +1,112 @@
+use rlf::Value;
+use strings::number_format::{self, DigitShaping, NumberFormat};
+use strings::{language, strings};
+
+#[test]
+fn english_numbers_are_unchanged() {
+    let format = number_format::for_language("en");
+    assert!(format.is_plain());
+    assert_eq!(number_format::format_integer_with(format, 12345), "12345");
+}
+
+#[test]
+fn bracket_test_locale_is_plain() {
+    assert_eq!(number_format::for_language("en-x-bracket"), NumberFormat::PLAIN);
+}
+
+#[test]
+fn russian_groups_only_five_digit_numbers() {
+    let format = number_format::for_language("ru");
+    assert_eq!(number_format::format_integer_with(format, 1234), "1234");
+    assert_eq!(number_format::format_integer_with(format, 12345), "12\u{00A0}345");
+    assert_eq!(number_format::format_integer_with(format, -1234567), "-1\u{00A0}234\u{00A0}567");
+}
+
+#[test]
+fn arabic_uses_eastern_arabic_digits_and_separators() {
+    let format = number_format::for_language("ar-EG");
+    assert_eq!(format.digits, DigitShaping::EasternArabic);
+    assert_eq!(number_format::format_integer_with(format, 3), "\u{0663}");
+    assert_eq!(
+        number_format::format_integer_with(format, 1000),
+        "\u{0661}\u{066C}\u{0660}\u{0660}\u{0660}"
+    );
+}
+
+#[test]
+fn unchanged_numbers_are_substituted_as_numbers() {
+    let english = number_format::number_with(number_format::for_language("en"), "en", 12345);
+    assert!(matches!(english, Value::Number(12345)));
+    let russian = number_format::number_with(number_format::for_language("ru"), "ru", 3);
+    assert!(matches!(russian, Value::Number(3)));
+}
+
+#[test]
+fn formatted_numbers_are_tagged_with_plural_category() {
+    let Value::Phrase(russian) =
+        number_format::number_with(number_format::for_language("ru"), "ru", 10002)
+    else {
+        panic!("Expected a formatted phrase");
+    };
+    assert_eq!(russian.to_string(), "10\u{00A0}002");
+    assert!(russian.has_tag("few"));
+
+    let Value::Phrase(persian) =
+        number_format::number_with(number_format::for_language("fa"), "fa", 15)
+    else {
+        panic!("Expected a formatted phrase");
+    };
+    assert_eq!(persian.to_string(), "\u{06F1}\u{06F5}");
+    assert!(persian.has_tag("other"));
+}
+
+#[test]
+fn plural_categories_follow_language_rules() {
+    assert_eq!(number_format::plural_category("en", 1), "one");
+    assert_eq!(number_format::plural_category("en", 0), "other");
+    assert_eq!(number_format::plural_category("ru", 21), "one");
+    assert_eq!(number_format::plural_category("ru", 12), "many");
+    assert_eq!(number_format::plural_category("ru", 24), "few");
+    assert_eq!(number_format::plural_category("ar-EG", 0), "zero");
+    assert_eq!(number_format::plural_category("ar", 2), "two");
+    assert_eq!(number_format::plural_category("ar", 105), "few");
+    assert_eq!(number_format::plural_category("ar", 111), "many");
+    assert_eq!(number_format::plural_category("ar", 100), "other");
+    assert_eq!(number_format::plural_category("fr", 0), "one");
+}
+
+#[test]
+fn numbers_are_formatted_when_substituted_into_phrases() {
+    let text = language::with_language(Some("ar"), || strings::energy(3u32).to_string());
+    assert_eq!(text, "<color=#00838F>\u{0663}\u{25CF}</color>");
+}
+
+#[test]
+fn numbers_in_english_are_substituted_unchanged() {
+    let text = language::with_language(Some("en"), || strings::cards(12345).to_string());
+    assert_eq!(text, "12345 cards");
+}
+
+#[test]
+fn phrase_text_is_never_reformatted() {
+    let text = language::with_language(Some("ar"), || {
+        strings::energy(Value::String("<2".to_string())).to_string()
+    });
+    assert_eq!(text, "<color=#00838F><2\u{25CF}</color>");
+}
+
+#[test]
+fn spell_out_numbers_rewrites_small_standalone_numbers() {
+    assert_eq!(
+        number_format::spell_out_numbers("Draw 2 cards. Discard 12 cards."),
+        "Draw two cards. Discard 12 cards."
+    );
+}
+
+#[test]
+fn spell_out_numbers_skips_markup_and_modifiers() {
+    assert_eq!(
+        number_format::spell_out_numbers("Gain <color=#00838F>2\u{25CF}</color>. Give +3 spark."),
+        "Gain <color=#00838F>2\u{25CF}</color>. Give +3 spark."
+    );
+}
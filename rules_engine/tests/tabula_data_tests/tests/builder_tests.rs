@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use ability_data::ability::Ability;
+use core_data::identifiers::BaseCardId;
 use tabula_data::card_definition_builder;
 use tabula_data::card_definition_raw::CardDefinitionRaw;
 use tabula_data::tabula_error::TabulaError;
@@ -23,6 +24,10 @@ fn raw_card_character() -> CardDefinitionRaw {
         rules_text: Some("Draw a card.".to_string()),
         variables: None,
         image_number: Some(12345),
+        token_image_number: None,
+        copy_image_number: None,
+        figment_image_number: None,
+        related_cards: None,
         rarity: Some("Common".to_string()),
         energy_produced: None,
         is_fast: Some(false),
@@ -41,6 +46,10 @@ fn raw_card_event() -> CardDefinitionRaw {
         rules_text: Some("Deal damage.".to_string()),
         variables: None,
         image_number: Some(67890),
+        token_image_number: None,
+        copy_image_number: None,
+        figment_image_number: None,
+        related_cards: None,
         rarity: Some("Rare".to_string()),
         energy_produced: None,
         is_fast: Some(true),
@@ -59,6 +68,10 @@ fn raw_dreamwell_card() -> CardDefinitionRaw {
         rules_text: Some("Gain energy.".to_string()),
         variables: None,
         image_number: Some(11111),
+        token_image_number: None,
+        copy_image_number: None,
+        figment_image_number: None,
+        related_cards: None,
         rarity: None,
         energy_produced: Some(3),
         is_fast: None,
@@ -203,3 +216,33 @@ fn build_card_with_abilities() {
     let card = result.unwrap();
     assert_eq!(card.abilities.len(), 1);
 }
+
+#[test]
+fn build_card_with_token_art() {
+    let mut raw = raw_card_character();
+    raw.token_image_number = Some(222);
+    raw.copy_image_number = Some(333);
+
+    let card = card_definition_builder::build_card(&raw, vec![], &test_file()).unwrap();
+
+    assert_eq!(
+        card.token_image.unwrap().sprite,
+        card_definition_builder::build_sprite_address(222).sprite
+    );
+    assert_eq!(
+        card.copy_image.unwrap().sprite,
+        card_definition_builder::build_sprite_address(333).sprite
+    );
+    assert!(card.figment_image.is_none());
+}
+
+#[test]
+fn build_card_with_related_cards() {
+    let related = Uuid::new_v4();
+    let mut raw = raw_card_character();
+    raw.related_cards = Some(vec![related]);
+
+    let card = card_definition_builder::build_card(&raw, vec![], &test_file()).unwrap();
+
+    assert_eq!(card.related_cards, vec![BaseCardId(related)]);
+}
@@ -1,9 +1,12 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use tabula_data::card_effect_row::{
     CardEffectRowObjectPredicate, CardEffectRowTrigger, CardEffectRowType, build_card_effect_row,
+    resolve_fx_name,
 };
-use tabula_data::toml_loader::CardEffectRowRaw;
+use tabula_data::fx_definition_row::{FxDefinitionRow, build_fx_definition_row};
+use tabula_data::toml_loader::{CardEffectRowRaw, FxDefinitionRowRaw};
 
 fn test_file() -> PathBuf {
     PathBuf::from("test.toml")
@@ -14,6 +17,7 @@ fn raw_fire_projectile() -> CardEffectRowRaw {
         card_id: "36c2a4e1-3212-4933-979a-73f109f9b256".to_string(),
         effect_type: "FireProjectile".to_string(),
         effect_trigger: "ApplyTargetedEffect".to_string(),
+        fx_name: None,
         projectile_source: Some("ThisCard".to_string()),
         projectile_target: Some("ForEachTarget".to_string()),
         projectile_address: Some("Assets/Projectiles/Fire.prefab".to_string()),
@@ -38,6 +42,7 @@ fn raw_dissolve_targets() -> CardEffectRowRaw {
         card_id: "36c2a4e1-3212-4933-979a-73f109f9b256".to_string(),
         effect_type: "DissolveTargets".to_string(),
         effect_trigger: "ApplyTargetedEffect".to_string(),
+        fx_name: None,
         projectile_source: None,
         projectile_target: None,
         projectile_address: None,
@@ -62,6 +67,7 @@ fn raw_display_effect() -> CardEffectRowRaw {
         card_id: "a6e8e100-9aa6-4a53-82ec-4ab161829533".to_string(),
         effect_type: "DisplayEffect".to_string(),
         effect_trigger: "DrawCards".to_string(),
+        fx_name: None,
         projectile_source: None,
         projectile_target: None,
         projectile_address: None,
@@ -86,6 +92,7 @@ fn raw_set_card_trail() -> CardEffectRowRaw {
         card_id: "1ce137cb-4234-45f7-abd2-d0ce97549885".to_string(),
         effect_type: "SetCardTrail".to_string(),
         effect_trigger: "SelectedTargetsForCard".to_string(),
+        fx_name: None,
         projectile_source: None,
         projectile_target: None,
         projectile_address: None,
@@ -105,6 +112,26 @@ fn raw_set_card_trail() -> CardEffectRowRaw {
     }
 }
 
+fn fx_definitions() -> BTreeMap<String, FxDefinitionRow> {
+    let raw = FxDefinitionRowRaw {
+        name: "black-fire".to_string(),
+        projectile_address: Some("Assets/Projectiles/BlackFire.prefab".to_string()),
+        projectile_fire_sound: Some("Assets/Sounds/BlackFire.wav".to_string()),
+        projectile_impact_sound: Some("Assets/Sounds/BlackFireImpact.wav".to_string()),
+        dissolve_material: None,
+        dissolve_color: None,
+        dissolve_sound: None,
+        effect_address: None,
+        effect_duration_milliseconds: None,
+        effect_scale: None,
+        effect_sound: None,
+        card_trail_address: None,
+        trail_duration_milliseconds: None,
+    };
+    let row = build_fx_definition_row(&raw, &test_file()).unwrap();
+    BTreeMap::from([(row.name.clone(), row)])
+}
+
 #[test]
 fn build_fire_projectile_effect_succeeds() {
     let raw = raw_fire_projectile();
@@ -223,3 +250,55 @@ fn build_effect_color_parsing() {
     assert!((color.green - 0.757).abs() < 0.01);
     assert!((color.blue - 0.027).abs() < 0.01);
 }
+
+#[test]
+fn resolve_fx_name_fills_missing_assets() {
+    let mut raw = raw_fire_projectile();
+    raw.fx_name = Some("black-fire".to_string());
+    raw.projectile_address = None;
+    let row = build_card_effect_row(&raw, &test_file()).unwrap();
+
+    let effect = resolve_fx_name(row, &fx_definitions(), &test_file()).unwrap();
+    assert_eq!(
+        effect.projectile_address.unwrap().projectile,
+        "Assets/Projectiles/BlackFire.prefab",
+        "missing asset should come from the named effect"
+    );
+    assert_eq!(
+        effect.projectile_fire_sound.unwrap().audio_clip,
+        "Assets/Sounds/Fire.wav",
+        "asset specified on the row should take precedence"
+    );
+}
+
+#[test]
+fn resolve_unknown_fx_name_fails() {
+    let mut raw = raw_fire_projectile();
+    raw.fx_name = Some("missing-effect".to_string());
+    let row = build_card_effect_row(&raw, &test_file()).unwrap();
+
+    let result = resolve_fx_name(row, &fx_definitions(), &test_file());
+    assert!(result.is_err());
+}
+
+#[test]
+fn build_fx_definition_empty_name_fails() {
+    let raw = FxDefinitionRowRaw {
+        name: " ".to_string(),
+        projectile_address: None,
+        projectile_fire_sound: None,
+        projectile_impact_sound: None,
+        dissolve_material: None,
+        dissolve_color: None,
+        dissolve_sound: None,
+        effect_address: None,
+        effect_duration_milliseconds: None,
+        effect_scale: None,
+        effect_sound: None,
+        card_trail_address: None,
+        trail_duration_milliseconds: None,
+    };
+
+    let result = build_fx_definition_row(&raw, &test_file());
+    assert!(result.is_err());
+}
@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+
+use tabula_data::card_variant_row::build_card_variant_row;
+use tabula_data::toml_loader::CardVariantRowRaw;
+
+fn test_file() -> PathBuf {
+    PathBuf::from("test.toml")
+}
+
+fn raw_variant() -> CardVariantRowRaw {
+    CardVariantRowRaw {
+        id: "9f0b7c1e-2d4a-4c55-8a6e-0b3c1d2e4f50".to_string(),
+        card_id: "36c2a4e1-3212-4933-979a-73f109f9b256".to_string(),
+        variant_name: "Promotional".to_string(),
+        image_number: 1234567890,
+        is_foil: Some(true),
+    }
+}
+
+#[test]
+fn build_card_variant_row_succeeds() {
+    let result = build_card_variant_row(&raw_variant(), &test_file());
+
+    assert!(result.is_ok());
+    let row = result.unwrap();
+    assert_eq!(row.variant_name, "Promotional");
+    assert_eq!(row.card_id.0.to_string(), "36c2a4e1-3212-4933-979a-73f109f9b256");
+    assert!(row.image.sprite.contains("1234567890"));
+    assert!(row.is_foil);
+}
+
+#[test]
+fn build_card_variant_row_defaults_to_not_foil() {
+    let mut raw = raw_variant();
+    raw.is_foil = None;
+
+    let row = build_card_variant_row(&raw, &test_file()).unwrap();
+    assert!(!row.is_foil);
+}
+
+#[test]
+fn build_card_variant_row_invalid_card_id_fails() {
+    let mut raw = raw_variant();
+    raw.card_id = "not-a-uuid".to_string();
+
+    let result = build_card_variant_row(&raw, &test_file());
+    assert!(result.is_err());
+}
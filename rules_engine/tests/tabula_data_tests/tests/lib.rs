@@ -1,3 +1,4 @@
 mod builder_tests;
 mod card_effect_row_tests;
 mod card_list_row_tests;
+mod card_variant_row_tests;
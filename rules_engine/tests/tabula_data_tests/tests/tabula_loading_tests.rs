@@ -90,6 +90,31 @@ copies = 2
     )
     .unwrap();
 
+    // Create card-variants.toml
+    fs::write(
+        dir.join("card-variants.toml"),
+        r#"
+[[card-variants]]
+id = "66666666-6666-6666-6666-666666666666"
+card-id = "11111111-1111-1111-1111-111111111111"
+variant-name = "Promo"
+image-number = 987654321
+is-foil = true
+"#,
+    )
+    .unwrap();
+
+    // Create fx.toml
+    fs::write(
+        dir.join("fx.toml"),
+        r#"
+[[fx]]
+name = "fire"
+projectile-address = "Assets/Projectiles/Fire.prefab"
+"#,
+    )
+    .unwrap();
+
     // Create card-fx.toml
     fs::write(
         dir.join("card-fx.toml"),
@@ -98,6 +123,7 @@ copies = 2
 card-id = "11111111-1111-1111-1111-111111111111"
 effect-type = "FireProjectile"
 effect-trigger = "ApplyTargetedEffect"
+fx-name = "fire"
 projectile-source = "ThisCard"
 projectile-target = "ForEachTarget"
 "#,
@@ -117,6 +143,9 @@ fn load_production_source_succeeds() {
     assert_eq!(tabula.dreamwell_cards.len(), 1);
     assert_eq!(tabula.card_lists.len(), 1);
     assert_eq!(tabula.card_effects.len(), 1);
+    assert!(tabula.card_effects[0].projectile_address.is_some());
+    assert_eq!(tabula.fx_definitions.len(), 1);
+    assert_eq!(tabula.card_variants.len(), 1);
 }
 
 #[test]
@@ -152,6 +181,8 @@ fn load_fails_on_invalid_card() {
     .unwrap();
     fs::write(temp_dir.path().join("card-lists.toml"), "card-lists = []\n").unwrap();
     fs::write(temp_dir.path().join("card-fx.toml"), "card-fx = []\n").unwrap();
+    fs::write(temp_dir.path().join("fx.toml"), "fx = []\n").unwrap();
+    fs::write(temp_dir.path().join("card-variants.toml"), "card-variants = []\n").unwrap();
 
     // Card missing required fields
     fs::write(
@@ -169,6 +200,17 @@ name = "Invalid Card"
     assert!(result.is_err());
 }
 
+#[test]
+fn load_fails_on_unknown_fx_name() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    create_test_fixture(temp_dir.path(), TabulaSource::Production);
+    fs::write(temp_dir.path().join("fx.toml"), "fx = []\n").unwrap();
+
+    let result = Tabula::load(TabulaSource::Production, temp_dir.path());
+
+    assert!(result.is_err(), "card effects referencing unknown fx names should fail to load");
+}
+
 #[test]
 fn load_lenient_succeeds_with_invalid_card() {
     let temp_dir = tempfile::tempdir().unwrap();
@@ -181,6 +223,8 @@ fn load_lenient_succeeds_with_invalid_card() {
     // Empty arrays for card-lists and card-fx
     fs::write(temp_dir.path().join("card-lists.toml"), "card-lists = []\n").unwrap();
     fs::write(temp_dir.path().join("card-fx.toml"), "card-fx = []\n").unwrap();
+    fs::write(temp_dir.path().join("fx.toml"), "fx = []\n").unwrap();
+    fs::write(temp_dir.path().join("card-variants.toml"), "card-variants = []\n").unwrap();
 
     // One valid card and one invalid card
     fs::write(
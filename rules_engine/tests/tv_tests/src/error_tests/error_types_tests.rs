@@ -1,6 +1,7 @@
 use std::io;
 
 use tv_lib::error::error_types::{map_io_error_for_read, map_io_error_for_write, TvError};
+use tv_lib::validation::tabula_validation::CardValidationError;
 
 #[test]
 fn test_file_not_found_display() {
@@ -250,6 +251,70 @@ fn test_event_emit_failed_display() {
     assert!(error.to_string().contains("Failed to emit event"));
 }
 
+#[test]
+fn test_journal_conflict_display() {
+    let error = TvError::JournalConflict { path: "/tmp/data.toml".to_string() };
+    assert!(error.to_string().contains("modified outside TV"));
+}
+
+#[test]
+fn test_csv_parse_error_display() {
+    let error = TvError::CsvParseError {
+        path: "/tmp/cards.csv".to_string(),
+        line: Some(3),
+        message: "unequal lengths".to_string(),
+    };
+    let msg = error.to_string();
+    assert!(msg.contains("CSV parse error"));
+    assert!(msg.contains("/tmp/cards.csv"));
+}
+
+#[test]
+fn test_invalid_search_pattern_display() {
+    let error = TvError::InvalidSearchPattern {
+        pattern: "(".to_string(),
+        message: "unclosed group".to_string(),
+    };
+    assert!(error.to_string().contains("Invalid search pattern '('"));
+}
+
+#[test]
+fn test_invalid_comment_target_display() {
+    let error = TvError::InvalidCommentTarget {
+        table_name: "cards".to_string(),
+        row_index: 4,
+        message: "row has no id".to_string(),
+    };
+    assert_eq!(error.to_string(), "Cannot comment on row 4 of table 'cards': row has no id");
+}
+
+#[test]
+fn test_comment_thread_not_found_display() {
+    let error = TvError::CommentThreadNotFound { thread_id: "thread-1".to_string() };
+    assert!(error.to_string().contains("thread-1"));
+}
+
+#[test]
+fn test_card_validation_failed_display() {
+    let error = TvError::CardValidationFailed {
+        path: "/tmp/cards.toml".to_string(),
+        table_name: "cards".to_string(),
+        errors: vec![
+            CardValidationError {
+                row: 2,
+                column: Some("card-type".to_string()),
+                message: "unknown variant `Sorcery`".to_string(),
+            },
+            CardValidationError { row: 3, column: None, message: "missing field".to_string() },
+        ],
+    };
+    assert_eq!(
+        error.to_string(),
+        "Card validation failed in table 'cards' of /tmp/cards.toml: row 2 column 'card-type': \
+         unknown variant `Sorcery`; row 3: missing field"
+    );
+}
+
 // --- variant_name() tests ---
 
 #[test]
@@ -369,6 +434,27 @@ fn test_variant_name_all_variants() {
             message: String::new(),
         }),
         ("EventEmitFailed", TvError::EventEmitFailed { message: String::new() }),
+        ("JournalConflict", TvError::JournalConflict { path: String::new() }),
+        ("CsvParseError", TvError::CsvParseError {
+            path: String::new(),
+            line: None,
+            message: String::new(),
+        }),
+        ("InvalidSearchPattern", TvError::InvalidSearchPattern {
+            pattern: String::new(),
+            message: String::new(),
+        }),
+        ("InvalidCommentTarget", TvError::InvalidCommentTarget {
+            table_name: String::new(),
+            row_index: 0,
+            message: String::new(),
+        }),
+        ("CommentThreadNotFound", TvError::CommentThreadNotFound { thread_id: String::new() }),
+        ("CardValidationFailed", TvError::CardValidationFailed {
+            path: String::new(),
+            table_name: String::new(),
+            errors: Vec::new(),
+        }),
     ];
 
     for (expected_name, error) in variants {
@@ -402,6 +488,13 @@ fn test_path_returns_some_for_file_errors() {
         TvError::FileTooLarge { path: test_path.to_string(), size_bytes: 0, limit_bytes: 0 },
         TvError::WatcherError { path: test_path.to_string(), message: String::new() },
         TvError::WatchPathFailed { path: test_path.to_string(), message: String::new() },
+        TvError::JournalConflict { path: test_path.to_string() },
+        TvError::CsvParseError { path: test_path.to_string(), line: None, message: String::new() },
+        TvError::CardValidationFailed {
+            path: test_path.to_string(),
+            table_name: String::new(),
+            errors: Vec::new(),
+        },
     ];
 
     for error in &errors_with_paths {
@@ -480,6 +573,20 @@ fn test_is_expected_error_for_user_errors() {
         TvError::TableNotFound { table_name: String::new() },
         TvError::NotAnArrayOfTables { table_name: String::new() },
         TvError::RowNotFound { table_name: String::new(), row_index: 0 },
+        TvError::JournalConflict { path: String::new() },
+        TvError::CsvParseError { path: String::new(), line: None, message: String::new() },
+        TvError::InvalidSearchPattern { pattern: String::new(), message: String::new() },
+        TvError::InvalidCommentTarget {
+            table_name: String::new(),
+            row_index: 0,
+            message: String::new(),
+        },
+        TvError::CommentThreadNotFound { thread_id: String::new() },
+        TvError::CardValidationFailed {
+            path: String::new(),
+            table_name: String::new(),
+            errors: Vec::new(),
+        },
     ];
 
     for error in &expected_errors {
@@ -592,3 +592,28 @@ fn test_matches_equals_float_to_integer() {
     let cond = FilterCondition::Equals(serde_json::json!(5));
     assert!(matches_condition(&cell, &cond));
 }
+
+#[test]
+fn test_hidden_rows_after_duplicate() {
+    let manager = FilterStateManager::new();
+    manager.set_hidden_rows("/test.toml", "cards", vec![0, 2]);
+    manager.set_visibility("/test.toml", "cards", vec![false, true, false]);
+
+    manager.on_row_duplicated("/test.toml", "cards", 0, 1);
+    assert_eq!(manager.get_hidden_rows("/test.toml", "cards"), vec![0, 1, 3]);
+    assert_eq!(
+        manager.get_visibility("/test.toml", "cards"),
+        Some(vec![false, false, true, false])
+    );
+}
+
+#[test]
+fn test_hidden_rows_after_move() {
+    let manager = FilterStateManager::new();
+    manager.set_hidden_rows("/test.toml", "cards", vec![0]);
+    manager.set_visibility("/test.toml", "cards", vec![false, true, true]);
+
+    manager.on_row_moved("/test.toml", "cards", 0, 2);
+    assert_eq!(manager.get_hidden_rows("/test.toml", "cards"), vec![2]);
+    assert_eq!(manager.get_visibility("/test.toml", "cards"), Some(vec![true, true, false]));
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use tv_lib::error::error_types::TvError;
+use tv_lib::import::csv_import::{self, CsvColumnMapping, CsvImportOptions};
+
+use crate::test_utils::harness::TvTestHarness;
+
+const CARDS: &str = r#"[[cards]]
+name = "First"
+cost = 1
+fast = false
+
+[metadata]
+schema_version = 1
+
+[[metadata.validation_rules]]
+column = "cost"
+type = "range"
+min = 0
+max = 10
+
+[[metadata.validation_rules]]
+column = "name"
+type = "required"
+"#;
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap_or_else(|| panic!("Invalid path: {path:?}"))
+}
+
+fn create_csv(harness: &TvTestHarness, name: &str, content: &str) -> PathBuf {
+    let path = harness.temp_dir().join(name);
+    std::fs::write(&path, content).unwrap_or_else(|e| panic!("Failed to write CSV: {e}"));
+    path
+}
+
+fn import(
+    harness: &TvTestHarness,
+    path: &Path,
+    csv_path: &Path,
+    options: &CsvImportOptions,
+) -> Result<csv_import::CsvImportResult, TvError> {
+    csv_import::import_csv_file(
+        harness.config(),
+        path_str(path),
+        "cards",
+        path_str(csv_path),
+        options,
+    )
+}
+
+fn dry_run() -> CsvImportOptions {
+    CsvImportOptions { dry_run: true, ..CsvImportOptions::default() }
+}
+
+#[test]
+fn test_import_appends_rows_with_coerced_values() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_append.toml", CARDS);
+    let csv_path = create_csv(&harness, "cards.csv", "Name,Cost,Fast\nSecond,2,yes\nThird,3,no\n");
+
+    let result = import(&harness, &path, &csv_path, &CsvImportOptions::default()).unwrap();
+    assert_eq!((result.row_count, result.imported_count), (2, 2));
+    assert!(result.failures.is_empty(), "Unexpected failures: {:?}", result.failures);
+    assert_eq!(result.coercions.len(), 4);
+
+    let table = harness.load_table(&path, "cards").unwrap();
+    assert_eq!(table.rows.len(), 3);
+    let column = |name: &str| table.headers.iter().position(|h| h == name).unwrap();
+    assert_eq!(table.rows[1][column("name")], json!("Second"));
+    assert_eq!(table.rows[1][column("cost")], json!(2));
+    assert_eq!(table.rows[1][column("fast")], json!(true));
+    assert_eq!(table.rows[2][column("fast")], json!(false));
+}
+
+#[test]
+fn test_dry_run_reports_failures_without_writing() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_dry_run.toml", CARDS);
+    let csv_path =
+        create_csv(&harness, "dry_run.csv", "name,cost\nSecond,2\nThird,many\nFourth,20\n,4\n");
+
+    let result = import(&harness, &path, &csv_path, &dry_run()).unwrap();
+    assert!(result.dry_run);
+    assert_eq!((result.row_count, result.importable_count, result.imported_count), (4, 1, 0));
+
+    let failures = result.failures.iter().map(|f| (f.row, f.column.as_str())).collect::<Vec<_>>();
+    assert_eq!(failures, vec![(1, "cost"), (2, "cost"), (3, "name")]);
+    assert_eq!(harness.read_file_content(&path), CARDS);
+}
+
+#[test]
+fn test_import_skips_rows_with_failures() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_skip.toml", CARDS);
+    let csv_path = create_csv(&harness, "skip.csv", "name,cost\nSecond,2\nThird,many\n");
+
+    let result = import(&harness, &path, &csv_path, &CsvImportOptions::default()).unwrap();
+    assert_eq!(result.imported_count, 1);
+    assert_eq!(harness.load_table(&path, "cards").unwrap().rows.len(), 2);
+}
+
+#[test]
+fn test_explicit_column_mapping() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_mapping.toml", CARDS);
+    let csv_path = create_csv(&harness, "mapping.csv", "Card Title,Energy,Notes\nSecond,2,x\n");
+
+    let options = CsvImportOptions {
+        column_mapping: HashMap::from([
+            ("Card Title".to_string(), "name".to_string()),
+            ("Energy".to_string(), "cost".to_string()),
+        ]),
+        dry_run: true,
+    };
+    let result = import(&harness, &path, &csv_path, &options).unwrap();
+    assert_eq!(
+        result.column_mapping,
+        vec![
+            CsvColumnMapping {
+                csv_column: "Card Title".to_string(),
+                table_column: Some("name".to_string())
+            },
+            CsvColumnMapping {
+                csv_column: "Energy".to_string(),
+                table_column: Some("cost".to_string())
+            },
+            CsvColumnMapping { csv_column: "Notes".to_string(), table_column: None },
+        ]
+    );
+    assert_eq!(result.importable_count, 1);
+}
+
+#[test]
+fn test_quoted_fields_and_byte_order_mark() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_quoted.toml", CARDS);
+    let csv_path =
+        create_csv(&harness, "quoted.csv", "\u{feff}name,cost\n\"Second, the \"\"Best\"\"\",2\n");
+
+    import(&harness, &path, &csv_path, &CsvImportOptions::default()).unwrap();
+    let table = harness.load_table(&path, "cards").unwrap();
+    let name = table.headers.iter().position(|h| h == "name").unwrap();
+    assert_eq!(table.rows[1][name], json!("Second, the \"Best\""));
+}
+
+#[test]
+fn test_missing_csv_file_returns_error() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_missing.toml", CARDS);
+    let csv_path = harness.temp_dir().join("missing.csv");
+
+    let result = import(&harness, &path, &csv_path, &dry_run());
+    assert!(matches!(result, Err(TvError::FileNotFound { .. })), "Got {result:?}");
+}
+
+#[test]
+fn test_malformed_csv_returns_parse_error() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("import_malformed.toml", CARDS);
+    let csv_path = create_csv(&harness, "malformed.csv", "name,cost\nSecond,2,extra\n");
+
+    let result = import(&harness, &path, &csv_path, &dry_run());
+    assert!(matches!(result, Err(TvError::CsvParseError { .. })), "Got {result:?}");
+}
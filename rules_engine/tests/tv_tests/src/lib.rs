@@ -9,8 +9,14 @@ mod filter_tests;
 #[cfg(test)]
 mod image_tests;
 #[cfg(test)]
+mod import_tests;
+#[cfg(test)]
 mod logging_tests;
 #[cfg(test)]
+mod report_tests;
+#[cfg(test)]
+mod search_tests;
+#[cfg(test)]
 mod sort_tests;
 mod test_utils;
 #[cfg(test)]
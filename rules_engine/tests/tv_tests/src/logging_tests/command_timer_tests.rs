@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use tv_lib::logging::command_timer::CommandTimingTracker;
+
+#[test]
+fn test_empty_tracker_reports_nothing() {
+    let tracker = CommandTimingTracker::new();
+    let report = tracker.report();
+    assert!(report.commands.is_empty(), "Empty tracker should have no command summaries");
+    assert!(report.slowest_recent.is_empty(), "Empty tracker should have no recent timings");
+}
+
+#[test]
+fn test_report_computes_percentiles() {
+    let tracker = CommandTimingTracker::new();
+    for ms in 1..=100 {
+        tracker.record("load_toml_table", Duration::from_millis(ms));
+    }
+
+    let report = tracker.report();
+    assert_eq!(report.commands.len(), 1);
+    let summary = &report.commands[0];
+    assert_eq!(summary.command, "load_toml_table");
+    assert_eq!(summary.count, 100);
+    assert!((summary.p50_ms - 50.0).abs() < 0.001, "p50 should be 50ms: {}", summary.p50_ms);
+    assert!((summary.p95_ms - 95.0).abs() < 0.001, "p95 should be 95ms: {}", summary.p95_ms);
+    assert!((summary.max_ms - 100.0).abs() < 0.001, "max should be 100ms: {}", summary.max_ms);
+}
+
+#[test]
+fn test_commands_sorted_by_p95_descending() {
+    let tracker = CommandTimingTracker::new();
+    tracker.record("save_cell", Duration::from_millis(5));
+    tracker.record("load_toml_table", Duration::from_millis(400));
+    tracker.record("get_sort_state", Duration::from_millis(1));
+
+    let report = tracker.report();
+    let names: Vec<&str> = report.commands.iter().map(|c| c.command.as_str()).collect();
+    assert_eq!(names, vec!["load_toml_table", "save_cell", "get_sort_state"]);
+}
+
+#[test]
+fn test_slowest_recent_limited_and_sorted() {
+    let tracker = CommandTimingTracker::new();
+    for ms in 1..=30 {
+        tracker.record("save_cell", Duration::from_millis(ms));
+    }
+
+    let report = tracker.report();
+    assert_eq!(report.slowest_recent.len(), 10, "Report should include 10 slowest invocations");
+    assert!((report.slowest_recent[0].duration_ms - 30.0).abs() < 0.001);
+    assert!(
+        report.slowest_recent.windows(2).all(|w| w[0].duration_ms >= w[1].duration_ms),
+        "Slowest recent invocations should be sorted slowest first"
+    );
+}
+
+#[test]
+fn test_count_includes_evicted_samples() {
+    let tracker = CommandTimingTracker::new();
+    for _ in 0..600 {
+        tracker.record("compute_derived", Duration::from_millis(2));
+    }
+
+    let report = tracker.report();
+    assert_eq!(report.commands[0].count, 600);
+}
+
+#[test]
+fn test_clear_discards_timings() {
+    let tracker = CommandTimingTracker::new();
+    tracker.record("save_cell", Duration::from_millis(5));
+    tracker.clear();
+
+    let report = tracker.report();
+    assert!(report.commands.is_empty());
+    assert!(report.slowest_recent.is_empty());
+}
@@ -1,2 +1,3 @@
+mod command_timer_tests;
 mod json_logger_tests;
 mod log_aggregator_tests;
@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+
+use tv_lib::filter::filter_types::FilterState;
+use tv_lib::report::html_report::{
+    default_report_path, escape_html, render_html, view_data, write_report,
+};
+use tv_lib::sort::sort_types::SortState;
+use tv_lib::toml::conditional_formatting::evaluate_rules;
+use tv_lib::toml::document_loader::TomlTableData;
+use tv_lib::toml::metadata_types::{
+    ColumnFilter, ConditionalFormatRule, FilterCondition, FormatCondition, FormatStyle, TableStyle,
+};
+
+use crate::test_utils::mock_filesystem::{MockFileSystem, MockTestConfig};
+
+fn make_table() -> TomlTableData {
+    TomlTableData {
+        headers: vec!["name".to_string(), "cost".to_string(), "rarity".to_string()],
+        rows: vec![
+            vec![serde_json::json!("Zephyr"), serde_json::json!(3), serde_json::json!("Rare")],
+            vec![serde_json::json!("Alder"), serde_json::json!(1), serde_json::json!("Common")],
+            vec![serde_json::json!("Moss"), serde_json::json!(2), serde_json::json!("Rare")],
+        ],
+    }
+}
+
+fn names(data: &TomlTableData) -> Vec<&str> {
+    data.rows.iter().map(|row| row[0].as_str().unwrap()).collect()
+}
+
+#[test]
+fn test_view_data_without_sort_or_filter_keeps_order() {
+    let data = view_data(make_table(), None, None);
+    assert_eq!(names(&data), vec!["Zephyr", "Alder", "Moss"]);
+}
+
+#[test]
+fn test_view_data_applies_sort_then_filter() {
+    let sort = SortState::ascending("name".to_string());
+    let filter = FilterState::active(vec![ColumnFilter::new(
+        "rarity",
+        FilterCondition::Equals(serde_json::json!("Rare")),
+    )]);
+
+    let data = view_data(make_table(), Some(&sort), Some(&filter));
+    assert_eq!(names(&data), vec!["Moss", "Zephyr"]);
+}
+
+#[test]
+fn test_view_data_ignores_inactive_filter() {
+    let filter = FilterState::new(
+        vec![ColumnFilter::new("rarity", FilterCondition::Equals(serde_json::json!("Rare")))],
+        false,
+    );
+
+    let data = view_data(make_table(), None, Some(&filter));
+    assert_eq!(data.rows.len(), 3);
+}
+
+#[test]
+fn test_escape_html_escapes_special_characters() {
+    assert_eq!(
+        escape_html("<b>\"Fire\" & 'Ice'</b>"),
+        "&lt;b&gt;&quot;Fire&quot; &amp; &#39;Ice&#39;&lt;/b&gt;"
+    );
+}
+
+#[test]
+fn test_render_html_escapes_cell_values() {
+    let data = TomlTableData {
+        headers: vec!["rules-text".to_string()],
+        rows: vec![vec![serde_json::json!("Draw <b>2</b> cards & dissolve")]],
+    };
+
+    let html = render_html("cards", &data, None, &[]);
+    assert!(html.contains("Draw &lt;b&gt;2&lt;/b&gt; cards &amp; dissolve"));
+    assert!(!html.contains("<b>2</b>"));
+}
+
+#[test]
+fn test_render_html_includes_headers_and_values() {
+    let html = render_html("cards", &make_table(), None, &[]);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>cards</title>"));
+    assert!(html.contains(">name</th>"));
+    assert!(html.contains(">Zephyr</td>"));
+    assert!(html.contains(">3</td>"));
+    assert!(html.contains("3 rows"));
+}
+
+#[test]
+fn test_render_html_formats_null_and_array_values() {
+    let data = TomlTableData {
+        headers: vec!["tags".to_string(), "notes".to_string()],
+        rows: vec![vec![serde_json::json!(["fire", "ice"]), serde_json::Value::Null]],
+    };
+
+    let html = render_html("cards", &data, None, &[]);
+    assert!(html.contains(">fire, ice</td>"));
+    assert!(html.contains("<td></td>"));
+}
+
+#[test]
+fn test_render_html_applies_color_scheme_stripes() {
+    let style = TableStyle::new().with_color_scheme("blue_light");
+
+    let html = render_html("cards", &make_table(), Some(&style), &[]);
+    assert!(html.contains("background-color: #4472C4"));
+    assert!(html.contains("background-color: #D6E4F0"));
+    assert!(html.contains("background-color: #FFFFFF"));
+}
+
+#[test]
+fn test_render_html_without_color_scheme_has_no_cell_colors() {
+    let html = render_html("cards", &make_table(), None, &[]);
+    assert!(!html.contains("background-color"));
+    assert!(html.contains("font-weight: bold"));
+}
+
+#[test]
+fn test_render_html_applies_conditional_formatting() {
+    let data = make_table();
+    let rules = vec![ConditionalFormatRule::new(
+        "cost",
+        FormatCondition::GreaterThan(2.0),
+        FormatStyle::new().with_background_color("#FFD700"),
+    )];
+    let formats = evaluate_rules(&rules, &data.headers, &data.rows);
+
+    let html = render_html("cards", &data, None, &formats);
+    assert!(html.contains("<td style=\"background-color: #FFD700\">3</td>"));
+    assert_eq!(html.matches("#FFD700").count(), 1);
+}
+
+#[test]
+fn test_render_html_conditional_formatting_overrides_stripes() {
+    let data = make_table();
+    let style = TableStyle::new().with_color_scheme("blue_light");
+    let format = FormatStyle::new().with_font_color("#FF0000").with_bold(true);
+    let rules = vec![ConditionalFormatRule::new(
+        "name",
+        FormatCondition::Equals(serde_json::json!("Zephyr")),
+        format,
+    )];
+    let formats = evaluate_rules(&rules, &data.headers, &data.rows);
+
+    let html = render_html("cards", &data, Some(&style), &formats);
+    assert!(html.contains(
+        "<td style=\"background-color: #D6E4F0; color: #000000; color: #FF0000; font-weight: bold\">Zephyr</td>"
+    ));
+}
+
+#[test]
+fn test_default_report_path_is_next_to_file() {
+    assert_eq!(
+        default_report_path("/data/tables/cards.toml"),
+        PathBuf::from("/data/tables/cards_report.html")
+    );
+}
+
+#[test]
+fn test_write_report_writes_html() {
+    let config = MockTestConfig::new(MockFileSystem::with_read_and_write(""));
+    let html = render_html("cards", &make_table(), None, &[]);
+
+    write_report(&config.config(), "/data/cards_report.html", &html).unwrap();
+    assert_eq!(config.last_written_content(), Some(html));
+}
@@ -0,0 +1 @@
+mod html_report_tests;
@@ -0,0 +1,280 @@
+use std::path::Path;
+
+use serde_json::json;
+use tv_lib::error::error_types::TvError;
+use tv_lib::search::find_replace::{self, FindOptions, TableRef};
+use tv_lib::toml::document_writer::{JournalOperation, OperationJournal};
+
+use crate::test_utils::harness::TvTestHarness;
+
+const CARDS: &str = r#"[[cards]]
+name = "Fire Bolt"
+text = "Deal 2 damage."
+cost = 2
+tags = ["fire", "spell"]
+
+[[cards]]
+name = "Water Shield"
+text = "Prevent 2 damage."
+cost = 3
+"#;
+
+const EVENTS: &str = r#"[[events]]
+name = "Firestorm"
+"#;
+
+fn table_ref(path: &Path, table_name: &str) -> TableRef {
+    TableRef { file_path: path.to_str().unwrap().to_string(), table_name: table_name.to_string() }
+}
+
+const VALIDATED_EVENTS: &str = r#"[[events]]
+name = "Firestorm"
+
+[metadata]
+schema_version = 1
+
+[[metadata.validation_rules]]
+column = "name"
+type = "pattern"
+pattern = "^[A-Z]"
+"#;
+
+fn options(pattern: &str) -> FindOptions {
+    FindOptions { pattern: pattern.to_string(), ..FindOptions::default() }
+}
+
+fn cell(harness: &TvTestHarness, path: &Path, row: usize, column: &str) -> serde_json::Value {
+    let table = harness.load_table(path, "cards").unwrap();
+    let index = table.headers.iter().position(|h| h == column).unwrap();
+    table.rows[row][index].clone()
+}
+
+#[test]
+fn test_find_across_tables_is_case_insensitive_by_default() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("find_cards.toml", CARDS);
+    let events = harness.create_toml_file("find_events.toml", EVENTS);
+
+    let result = find_replace::find(
+        harness.config(),
+        &[table_ref(&cards, "cards"), table_ref(&events, "events")],
+        &options("fire"),
+    )
+    .unwrap();
+
+    let hits = result
+        .hits
+        .iter()
+        .map(|h| (h.table_name.as_str(), h.row_index, h.column_key.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(hits, vec![("cards", 0, "name"), ("cards", 0, "tags[0]"), ("events", 0, "name")]);
+    assert!(!result.truncated);
+}
+
+#[test]
+fn test_find_with_regex_and_column_scope() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("find_regex.toml", CARDS);
+
+    let options =
+        FindOptions { regex: true, columns: vec!["text".to_string()], ..options(r"\d+ damage") };
+    let result =
+        find_replace::find(harness.config(), &[table_ref(&cards, "cards")], &options).unwrap();
+    assert_eq!(result.hits.len(), 2);
+    assert!(result.hits.iter().all(|h| h.column_key == "text"));
+}
+
+#[test]
+fn test_find_matches_numbers_and_array_columns_by_base_key() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("find_numbers.toml", CARDS);
+
+    let number = find_replace::find(
+        harness.config(),
+        &[table_ref(&cards, "cards")],
+        &FindOptions { columns: vec!["cost".to_string()], ..options("3") },
+    )
+    .unwrap();
+    assert_eq!(number.hits.len(), 1);
+    assert_eq!(number.hits[0].value, json!(3));
+
+    let tags = find_replace::find(
+        harness.config(),
+        &[table_ref(&cards, "cards")],
+        &FindOptions { columns: vec!["tags".to_string()], ..options("spell") },
+    )
+    .unwrap();
+    assert_eq!(tags.hits.len(), 1);
+    assert_eq!(tags.hits[0].column_key, "tags[1]");
+}
+
+#[test]
+fn test_find_reports_tables_which_cannot_be_loaded() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("find_failed.toml", CARDS);
+    let missing = harness.temp_dir().join("missing.toml");
+
+    let result = find_replace::find(
+        harness.config(),
+        &[table_ref(&missing, "cards"), table_ref(&cards, "cards")],
+        &options("bolt"),
+    )
+    .unwrap();
+    assert_eq!(result.hits.len(), 1);
+    assert_eq!(result.failed_tables.len(), 1);
+}
+
+#[test]
+fn test_invalid_regex_returns_error() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("find_invalid.toml", CARDS);
+
+    let result = find_replace::find(
+        harness.config(),
+        &[table_ref(&cards, "cards")],
+        &FindOptions { regex: true, ..options("(") },
+    );
+    assert!(matches!(result, Err(TvError::InvalidSearchPattern { .. })), "Got {result:?}");
+}
+
+#[test]
+fn test_replace_updates_only_string_cells() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("replace_strings.toml", CARDS);
+    let file_path = cards.to_str().unwrap();
+
+    let result =
+        find_replace::replace_in_table(harness.config(), file_path, "cards", &options("2"), "4")
+            .unwrap();
+    assert_eq!((result.replaced_count, result.skipped_count), (2, 1));
+    assert_eq!(cell(&harness, &cards, 0, "text"), json!("Deal 4 damage."));
+    assert_eq!(cell(&harness, &cards, 1, "text"), json!("Prevent 4 damage."));
+    assert_eq!(cell(&harness, &cards, 0, "cost"), json!(2));
+}
+
+#[test]
+fn test_replace_with_regex_capture_groups() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("replace_regex.toml", CARDS);
+    let options = FindOptions {
+        regex: true,
+        case_sensitive: true,
+        columns: vec!["text".to_string()],
+        ..options(r"(\d+) damage")
+    };
+
+    find_replace::replace_in_table(
+        harness.config(),
+        cards.to_str().unwrap(),
+        "cards",
+        &options,
+        "$1 fire damage",
+    )
+    .unwrap();
+    assert_eq!(cell(&harness, &cards, 0, "text"), json!("Deal 2 fire damage."));
+}
+
+#[test]
+fn test_literal_replacement_does_not_expand_captures() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("replace_literal.toml", CARDS);
+
+    find_replace::replace_in_table(
+        harness.config(),
+        cards.to_str().unwrap(),
+        "cards",
+        &options("Deal"),
+        "$1",
+    )
+    .unwrap();
+    assert_eq!(cell(&harness, &cards, 0, "text"), json!("$1 2 damage."));
+}
+
+#[test]
+fn test_replace_failing_validation_leaves_table_unchanged() {
+    let harness = TvTestHarness::new();
+    let content = format!(
+        r#"{CARDS}
+[metadata]
+schema_version = 1
+
+[[metadata.validation_rules]]
+column = "name"
+type = "pattern"
+pattern = "^[A-Z]"
+"#
+    );
+    let cards = harness.create_toml_file("replace_invalid.toml", &content);
+
+    let result = find_replace::replace_in_table(
+        harness.config(),
+        cards.to_str().unwrap(),
+        "cards",
+        &options("Water"),
+        "water",
+    )
+    .unwrap();
+    assert_eq!(result.replaced_count, 0);
+    assert_eq!(result.failed_updates.len(), 1);
+    assert_eq!(harness.read_file_content(&cards), content);
+}
+
+#[test]
+fn test_replace_across_tables_writes_nothing_if_any_table_fails_validation() {
+    let harness = TvTestHarness::new();
+    let cards = harness.create_toml_file("replace_all_cards.toml", CARDS);
+    let events = harness.create_toml_file("replace_all_events.toml", VALIDATED_EVENTS);
+
+    let plan = find_replace::plan_replace(
+        harness.config(),
+        &[table_ref(&cards, "cards"), table_ref(&events, "events")],
+        &FindOptions { case_sensitive: true, ..options("Fire") },
+        "fire",
+    )
+    .unwrap();
+    assert!(plan.has_failures());
+
+    let results = plan.apply(harness.config()).unwrap();
+    assert_eq!(results.iter().map(|r| r.replaced_count).collect::<Vec<_>>(), vec![0, 0]);
+    assert!(results[0].failed_updates.is_empty());
+    assert_eq!(results[1].failed_updates.len(), 1);
+    assert_eq!(harness.read_file_content(&cards), CARDS);
+    assert_eq!(harness.read_file_content(&events), VALIDATED_EVENTS);
+}
+
+#[test]
+fn test_replace_across_tables_is_undone_in_one_step() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let cards = harness.create_toml_file("replace_undo_cards.toml", CARDS);
+    let events = harness.create_toml_file("replace_undo_events.toml", EVENTS);
+    let cards_path = cards.to_str().unwrap();
+    let events_path = events.to_str().unwrap();
+
+    let plan = find_replace::plan_replace(
+        harness.config(),
+        &[table_ref(&cards, "cards"), table_ref(&events, "events")],
+        &options("fire"),
+        "Ice",
+    )
+    .unwrap();
+    let results = journal
+        .record_tables(harness.config(), &plan.changed_tables(), JournalOperation::Replace, || {
+            plan.apply(harness.config())
+        })
+        .unwrap();
+    assert_eq!(results.iter().map(|r| r.replaced_count).collect::<Vec<_>>(), vec![2, 1]);
+    assert!(harness.read_file_content(&events).contains("Icestorm"));
+    assert_eq!(journal.undo_files(events_path), vec![events_path, cards_path]);
+
+    let undo = journal.undo(harness.config(), events_path).unwrap();
+    assert_eq!(undo.operation, Some(JournalOperation::Replace));
+    assert_eq!(cell(&harness, &cards, 0, "name"), json!("Fire Bolt"));
+    assert_eq!(cell(&harness, &cards, 0, "tags[0]"), json!("fire"));
+    assert!(harness.read_file_content(&events).contains("Firestorm"));
+    assert_eq!(journal.status(cards_path).redo_count, 1);
+
+    journal.redo(harness.config(), cards_path).unwrap();
+    assert_eq!(cell(&harness, &cards, 0, "name"), json!("Ice Bolt"));
+    assert!(harness.read_file_content(&events).contains("Icestorm"));
+}
@@ -732,3 +732,21 @@ fn test_apply_sort_negative_and_positive_numbers() {
     assert_eq!(reordered[2][0], serde_json::json!(0));
     assert_eq!(reordered[3][0], serde_json::json!(10));
 }
+
+#[test]
+fn test_row_mapping_after_duplicate() {
+    let manager = SortStateManager::new();
+    manager.set_row_mapping("/test.toml", "cards", vec![2, 0, 1]);
+
+    manager.on_row_duplicated("/test.toml", "cards", 0, 1);
+    assert_eq!(manager.get_row_mapping("/test.toml", "cards"), Some(vec![3, 0, 1, 2]));
+}
+
+#[test]
+fn test_row_mapping_after_move() {
+    let manager = SortStateManager::new();
+    manager.set_row_mapping("/test.toml", "cards", vec![2, 0, 1]);
+
+    manager.on_row_moved("/test.toml", "cards", 0, 2);
+    assert_eq!(manager.get_row_mapping("/test.toml", "cards"), Some(vec![1, 2, 0]));
+}
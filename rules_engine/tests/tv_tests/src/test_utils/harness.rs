@@ -6,8 +6,9 @@ use tempfile::TempDir;
 use tv_lib::error::error_types::TvError;
 use tv_lib::toml::document_loader::{load_toml_document, TomlTableData};
 use tv_lib::toml::document_writer::{
-    add_row, delete_row, save_batch, save_cell, save_toml_document, AddRowResult, CellUpdate,
-    DeleteRowResult, SaveBatchResult, SaveCellResult, SaveTableResult,
+    add_row, delete_row, duplicate_row, move_row, save_batch, save_cell, save_toml_document,
+    AddRowResult, CellUpdate, DeleteRowResult, DuplicateRowResult, MoveRowResult,
+    SaveBatchResult, SaveCellResult, SaveTableResult,
 };
 use tv_lib::toml::metadata;
 use tv_lib::traits::TvConfig;
@@ -137,6 +138,36 @@ impl TvTestHarness {
         )
     }
 
+    pub fn duplicate_row(
+        &self,
+        path: &Path,
+        table_name: &str,
+        row_index: usize,
+    ) -> Result<DuplicateRowResult, TvError> {
+        duplicate_row(
+            &self.config,
+            path.to_str().unwrap_or_else(|| panic!("Invalid path: {path:?}")),
+            table_name,
+            row_index,
+        )
+    }
+
+    pub fn move_row(
+        &self,
+        path: &Path,
+        table_name: &str,
+        from_index: usize,
+        to_index: usize,
+    ) -> Result<MoveRowResult, TvError> {
+        move_row(
+            &self.config,
+            path.to_str().unwrap_or_else(|| panic!("Invalid path: {path:?}")),
+            table_name,
+            from_index,
+            to_index,
+        )
+    }
+
     pub fn parse_validation_rules(&self, path: &Path) -> Result<Vec<ValidationRule>, TvError> {
         metadata::parse_validation_rules_with_fs(
             self.config.fs(),
@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use tv_lib::error::error_types::TvError;
+use tv_lib::toml::cell_comments::{self, NewComment};
+use tv_lib::toml::metadata;
+
+use crate::test_utils::harness::TvTestHarness;
+
+const CARDS: &str = r#"[[cards]]
+id = "card-1"
+name = "First"
+
+[[cards]]
+id = "card-2"
+name = "Second"
+
+[[cards]]
+name = "No id"
+"#;
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap_or_else(|| panic!("Invalid path: {path:?}"))
+}
+
+fn comment(row_index: usize, text: &str, thread_id: Option<&str>) -> NewComment {
+    NewComment {
+        row_index,
+        column: "name".to_string(),
+        author: "alice".to_string(),
+        text: text.to_string(),
+        thread_id: thread_id.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_add_comment_creates_thread() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_add.toml", CARDS);
+
+    let thread = cell_comments::add_comment(
+        harness.config(),
+        path_str(&path),
+        "cards",
+        &comment(1, "Typo?", None),
+    )
+    .unwrap();
+    assert_eq!(thread.row_key, "card-2");
+    assert_eq!(thread.column, "name");
+    assert!(!thread.resolved);
+    assert_eq!(thread.messages.len(), 1);
+    assert_eq!(thread.messages[0].text, "Typo?");
+
+    let content = harness.read_file_content(&path);
+    assert!(content.contains("[[metadata.comments]]"), "Got:\n{content}");
+    assert!(content.contains("name = \"Second\""), "Table should be unchanged");
+}
+
+#[test]
+fn test_reply_appends_and_reopens_thread() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_reply.toml", CARDS);
+    let file_path = path_str(&path);
+
+    let thread = cell_comments::add_comment(
+        harness.config(),
+        file_path,
+        "cards",
+        &comment(0, "Check", None),
+    )
+    .unwrap();
+    cell_comments::set_thread_resolved(harness.config(), file_path, &thread.id, true).unwrap();
+
+    let reply = cell_comments::add_comment(
+        harness.config(),
+        file_path,
+        "cards",
+        &comment(0, "Still wrong", Some(&thread.id)),
+    )
+    .unwrap();
+    assert_eq!(reply.id, thread.id);
+    assert!(!reply.resolved, "Replying should reopen the thread");
+    let texts: Vec<_> = reply.messages.iter().map(|m| m.text.as_str()).collect();
+    assert_eq!(texts, vec!["Check", "Still wrong"]);
+}
+
+#[test]
+fn test_resolve_thread_round_trips_through_metadata() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_resolve.toml", CARDS);
+    let file_path = path_str(&path);
+
+    let thread = cell_comments::add_comment(
+        harness.config(),
+        file_path,
+        "cards",
+        &comment(0, "Done?", None),
+    )
+    .unwrap();
+    cell_comments::set_thread_resolved(harness.config(), file_path, &thread.id, true).unwrap();
+
+    let content = harness.read_file_content(&path);
+    let threads = metadata::parse_comments_from_content(&content, file_path).unwrap();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].id, thread.id);
+    assert!(threads[0].resolved);
+    assert_eq!(threads[0].messages[0].author, "alice");
+    assert_eq!(threads[0].messages[0].created_at, thread.messages[0].created_at);
+}
+
+#[test]
+fn test_comment_on_row_without_id_fails() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_no_id.toml", CARDS);
+
+    let result = cell_comments::add_comment(
+        harness.config(),
+        path_str(&path),
+        "cards",
+        &comment(2, "x", None),
+    );
+    assert!(
+        matches!(result, Err(TvError::InvalidCommentTarget { row_index: 2, .. })),
+        "Got {result:?}"
+    );
+    assert_eq!(harness.read_file_content(&path), CARDS);
+}
+
+#[test]
+fn test_comment_on_missing_column_fails() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_no_column.toml", CARDS);
+
+    let mut new_comment = comment(0, "x", None);
+    new_comment.column = "cost".to_string();
+    let result =
+        cell_comments::add_comment(harness.config(), path_str(&path), "cards", &new_comment);
+    assert!(matches!(result, Err(TvError::InvalidCommentTarget { .. })), "Got {result:?}");
+}
+
+#[test]
+fn test_resolve_unknown_thread_fails() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_unknown.toml", CARDS);
+
+    let result =
+        cell_comments::set_thread_resolved(harness.config(), path_str(&path), "missing", true);
+    assert!(matches!(result, Err(TvError::CommentThreadNotFound { .. })), "Got {result:?}");
+}
+
+#[test]
+fn test_cell_comments_follow_moved_row() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_move.toml", CARDS);
+    let file_path = path_str(&path);
+
+    let thread =
+        cell_comments::add_comment(harness.config(), file_path, "cards", &comment(0, "Here", None))
+            .unwrap();
+    harness.move_row(&path, "cards", 0, 1).unwrap();
+
+    let table = harness.load_table(&path, "cards").unwrap();
+    let comments = cell_comments::cell_comments(harness.config(), file_path, &table).unwrap();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].thread.id, thread.id);
+    assert_eq!(comments[0].row_index, 1);
+}
+
+#[test]
+fn test_cell_comments_skip_deleted_rows() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("comment_delete.toml", CARDS);
+    let file_path = path_str(&path);
+
+    cell_comments::add_comment(harness.config(), file_path, "cards", &comment(0, "Gone", None))
+        .unwrap();
+    harness.delete_row(&path, "cards", 0).unwrap();
+
+    let table = harness.load_table(&path, "cards").unwrap();
+    let comments = cell_comments::cell_comments(harness.config(), file_path, &table).unwrap();
+    assert!(comments.is_empty());
+    let content = harness.read_file_content(&path);
+    assert_eq!(metadata::parse_comments_from_content(&content, file_path).unwrap().len(), 1);
+}
@@ -1,11 +1,13 @@
 mod array_column_tests;
 mod boolean_preservation_tests;
+mod cell_comments_tests;
 mod color_scheme_tests;
 mod conditional_formatting_tests;
 mod filter_metadata_tests;
 mod load_tests;
 mod metadata_serializer_tests;
 mod metadata_types_tests;
+mod operation_journal_tests;
 mod preservation_tests;
 mod row_operation_tests;
 mod save_tests;
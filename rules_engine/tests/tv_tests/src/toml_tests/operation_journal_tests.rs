@@ -0,0 +1,239 @@
+use std::path::Path;
+
+use serde_json::json;
+use tv_lib::error::error_types::TvError;
+use tv_lib::toml::document_writer::{CellUpdate, JournalOperation, OperationJournal};
+use tv_lib::toml::operation_journal::MAX_JOURNAL_ENTRIES;
+
+use crate::test_utils::harness::TvTestHarness;
+
+const CARDS: &str = r#"[[cards]]
+id = "card-1"
+name = "First"
+
+[[cards]]
+id = "card-2"
+name = "Second"
+"#;
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap_or_else(|| panic!("Invalid path: {path:?}"))
+}
+
+fn names(harness: &TvTestHarness, path: &Path) -> Vec<String> {
+    let table = harness.load_table(path, "cards").unwrap();
+    let name_index = table.headers.iter().position(|h| h == "name").unwrap();
+    table.rows.iter().map(|row| row[name_index].as_str().unwrap_or_default().to_string()).collect()
+}
+
+fn journaled_save_cell(
+    harness: &TvTestHarness,
+    journal: &OperationJournal,
+    path: &Path,
+    row_index: usize,
+    column_key: &str,
+    value: serde_json::Value,
+) {
+    journal
+        .record(harness.config(), path_str(path), "cards", JournalOperation::SaveCell, || {
+            harness.save_cell(path, "cards", row_index, column_key, value)
+        })
+        .unwrap_or_else(|e| panic!("Save cell failed: {e}"));
+}
+
+#[test]
+fn test_undo_and_redo_cell_update() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_cell.toml", CARDS);
+
+    journaled_save_cell(&harness, &journal, &path, 0, "name", json!("Updated"));
+    assert!(harness.read_file_content(&path).contains("Updated"));
+
+    let undo = journal.undo(harness.config(), path_str(&path)).unwrap();
+    assert_eq!(undo.operation, Some(JournalOperation::SaveCell));
+    assert_eq!((undo.undo_count, undo.redo_count), (0, 1));
+    assert_eq!(names(&harness, &path), vec!["First", "Second"]);
+
+    let redo = journal.redo(harness.config(), path_str(&path)).unwrap();
+    assert_eq!(redo.operation, Some(JournalOperation::SaveCell));
+    assert_eq!((redo.undo_count, redo.redo_count), (1, 0));
+    assert_eq!(names(&harness, &path), vec!["Updated", "Second"]);
+}
+
+#[test]
+fn test_undo_batch_and_row_operations_in_order() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_rows.toml", CARDS);
+    let file_path = path_str(&path);
+
+    let updates = vec![
+        CellUpdate { row_index: 0, column_key: "name".to_string(), value: json!("A") },
+        CellUpdate { row_index: 1, column_key: "name".to_string(), value: json!("B") },
+    ];
+    journal
+        .record(harness.config(), file_path, "cards", JournalOperation::SaveBatch, || {
+            harness.save_batch(&path, "cards", &updates)
+        })
+        .unwrap();
+    journal
+        .record(harness.config(), file_path, "cards", JournalOperation::AddRow, || {
+            harness.add_row(&path, "cards", None, None)
+        })
+        .unwrap();
+    journal
+        .record(harness.config(), file_path, "cards", JournalOperation::DeleteRow, || {
+            harness.delete_row(&path, "cards", 0)
+        })
+        .unwrap();
+    assert_eq!(harness.load_table(&path, "cards").unwrap().rows.len(), 2);
+
+    let undo = journal.undo(harness.config(), file_path).unwrap();
+    assert_eq!(undo.operation, Some(JournalOperation::DeleteRow));
+    assert_eq!(harness.load_table(&path, "cards").unwrap().rows.len(), 3);
+
+    let undo = journal.undo(harness.config(), file_path).unwrap();
+    assert_eq!(undo.operation, Some(JournalOperation::AddRow));
+    assert_eq!(harness.load_table(&path, "cards").unwrap().rows.len(), 2);
+
+    let undo = journal.undo(harness.config(), file_path).unwrap();
+    assert_eq!(undo.operation, Some(JournalOperation::SaveBatch));
+    assert_eq!(names(&harness, &path), vec!["First", "Second"]);
+}
+
+#[test]
+fn test_undo_with_empty_journal_does_nothing() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_empty.toml", CARDS);
+
+    let undo = journal.undo(harness.config(), path_str(&path)).unwrap();
+    assert_eq!(undo.operation, None);
+    assert_eq!(harness.read_file_content(&path), CARDS);
+}
+
+#[test]
+fn test_new_operation_clears_redo_history() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_redo_cleared.toml", CARDS);
+
+    journaled_save_cell(&harness, &journal, &path, 0, "name", json!("One"));
+    journal.undo(harness.config(), path_str(&path)).unwrap();
+    assert_eq!(journal.status(path_str(&path)).redo_count, 1);
+
+    journaled_save_cell(&harness, &journal, &path, 1, "name", json!("Two"));
+    let status = journal.status(path_str(&path));
+    assert_eq!((status.undo_count, status.redo_count), (1, 0));
+}
+
+#[test]
+fn test_failed_operation_is_not_recorded() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_failed.toml", CARDS);
+
+    let result = journal.record(
+        harness.config(),
+        path_str(&path),
+        "cards",
+        JournalOperation::SaveCell,
+        || harness.save_cell(&path, "cards", 10, "name", json!("Missing")),
+    );
+    assert!(result.is_err(), "Save to a missing row should fail");
+    assert_eq!(journal.status(path_str(&path)).undo_count, 0);
+}
+
+#[test]
+fn test_undo_keeps_changes_outside_table() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_metadata.toml", CARDS);
+
+    journaled_save_cell(&harness, &journal, &path, 0, "name", json!("Updated"));
+    let content = harness.read_file_content(&path);
+    std::fs::write(&path, format!("{content}\n[metadata]\nschema_version = 1\n")).unwrap();
+
+    journal.undo(harness.config(), path_str(&path)).unwrap();
+    let content = harness.read_file_content(&path);
+    assert!(!content.contains("Updated"), "Cell update should be undone");
+    assert!(content.contains("schema_version = 1"), "Metadata should be kept");
+}
+
+#[test]
+fn test_external_table_change_discards_journal() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_conflict.toml", CARDS);
+
+    journaled_save_cell(&harness, &journal, &path, 0, "name", json!("Updated"));
+    let external = harness.read_file_content(&path).replace("Second", "Edited elsewhere");
+    std::fs::write(&path, &external).unwrap();
+
+    let result = journal.undo(harness.config(), path_str(&path));
+    assert!(matches!(result, Err(TvError::JournalConflict { .. })), "Got {result:?}");
+    assert_eq!(harness.read_file_content(&path), external);
+    assert_eq!(journal.status(path_str(&path)).undo_count, 0);
+}
+
+#[test]
+fn test_journal_is_capped() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let path = harness.create_toml_file("undo_capped.toml", CARDS);
+
+    for i in 0..MAX_JOURNAL_ENTRIES + 5 {
+        journaled_save_cell(&harness, &journal, &path, 0, "name", json!(format!("Name {i}")));
+    }
+    assert_eq!(journal.status(path_str(&path)).undo_count, MAX_JOURNAL_ENTRIES);
+}
+
+#[test]
+fn test_failed_operation_restores_every_file() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let first = harness.create_toml_file("undo_failed_first.toml", CARDS);
+    let second = harness.create_toml_file("undo_failed_second.toml", CARDS);
+
+    let result = journal.record_tables(
+        harness.config(),
+        &[(path_str(&first), "cards"), (path_str(&second), "cards")],
+        JournalOperation::Replace,
+        || {
+            harness.save_cell(&first, "cards", 0, "name", json!("Updated"))?;
+            harness.save_cell(&second, "cards", 10, "name", json!("Missing"))
+        },
+    );
+    assert!(result.is_err(), "Save to a missing row should fail");
+    assert_eq!(harness.read_file_content(&first), CARDS);
+    assert_eq!(journal.status(path_str(&first)).undo_count, 0);
+}
+
+#[test]
+fn test_undo_restores_every_table_in_operation() {
+    let harness = TvTestHarness::new();
+    let journal = OperationJournal::new();
+    let content = format!("{CARDS}\n{}", CARDS.replace("[[cards]]", "[[events]]"));
+    let path = harness.create_toml_file("undo_tables.toml", &content);
+    let file_path = path_str(&path);
+
+    journal
+        .record_tables(
+            harness.config(),
+            &[(file_path, "cards"), (file_path, "events")],
+            JournalOperation::Replace,
+            || {
+                harness.save_cell(&path, "cards", 0, "name", json!("Card"))?;
+                harness.save_cell(&path, "events", 0, "name", json!("Event"))
+            },
+        )
+        .unwrap();
+    assert_eq!(journal.status(file_path).undo_count, 1);
+
+    let undo = journal.undo(harness.config(), file_path).unwrap();
+    assert_eq!(undo.file_paths, vec![file_path]);
+    let content = harness.read_file_content(&path);
+    assert!(!content.contains("\"Card\"") && !content.contains("\"Event\""), "Got {content}");
+    assert_eq!(names(&harness, &path), vec!["First", "Second"]);
+}
@@ -588,3 +588,81 @@ name = "First"
         "File should have exactly 2 [[cards]] entries"
     );
 }
+
+const THREE_CARDS: &str = r#"[[cards]]
+id = "4b5d0a3e-5d1f-4d8a-9a53-8f3c0a7e2b11"
+name = "First"  # the original
+
+[[cards]]
+id = "card-2"
+name = "Second"
+
+[[cards]]
+id = "card-3"
+name = "Third"
+"#;
+
+fn names(harness: &TvTestHarness, path: &std::path::Path) -> Vec<String> {
+    let table = harness.load_table(path, "cards").unwrap();
+    let name_index = table.headers.iter().position(|h| h == "name").unwrap();
+    table.rows.iter().map(|row| row[name_index].as_str().unwrap_or_default().to_string()).collect()
+}
+
+#[test]
+fn test_duplicate_row_inserts_copy_after_source() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("duplicate_row.toml", THREE_CARDS);
+
+    let result = harness.duplicate_row(&path, "cards", 0).unwrap();
+    assert_eq!((result.source_index, result.row_index), (0, 1));
+    assert_eq!(names(&harness, &path), vec!["First", "First", "Second", "Third"]);
+
+    let content = harness.read_file_content(&path);
+    assert_eq!(content.matches("# the original").count(), 2, "Comments should be copied");
+}
+
+#[test]
+fn test_duplicate_row_generates_new_id() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("duplicate_row_id.toml", THREE_CARDS);
+
+    let result = harness.duplicate_row(&path, "cards", 0).unwrap();
+    let generated_id = result.generated_id.expect("Copy should get a new id");
+    assert_ne!(generated_id, "4b5d0a3e-5d1f-4d8a-9a53-8f3c0a7e2b11");
+
+    let table = harness.load_table(&path, "cards").unwrap();
+    let id_index = table.headers.iter().position(|h| h == "id").unwrap();
+    assert_eq!(table.rows[1][id_index], json!(generated_id));
+}
+
+#[test]
+fn test_duplicate_row_out_of_bounds() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("duplicate_row_oob.toml", THREE_CARDS);
+
+    let result = harness.duplicate_row(&path, "cards", 3);
+    assert!(matches!(result, Err(TvError::RowNotFound { row_index: 3, .. })), "Got {result:?}");
+    assert_eq!(harness.read_file_content(&path), THREE_CARDS);
+}
+
+#[test]
+fn test_move_row_down_and_up() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("move_row.toml", THREE_CARDS);
+
+    harness.move_row(&path, "cards", 0, 2).unwrap();
+    assert_eq!(names(&harness, &path), vec!["Second", "Third", "First"]);
+    assert!(harness.read_file_content(&path).contains("# the original"));
+
+    harness.move_row(&path, "cards", 2, 0).unwrap();
+    assert_eq!(harness.read_file_content(&path), THREE_CARDS);
+}
+
+#[test]
+fn test_move_row_out_of_bounds() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("move_row_oob.toml", THREE_CARDS);
+
+    let result = harness.move_row(&path, "cards", 1, 5);
+    assert!(matches!(result, Err(TvError::RowNotFound { row_index: 5, .. })), "Got {result:?}");
+}
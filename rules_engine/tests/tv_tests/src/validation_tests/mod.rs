@@ -1,5 +1,6 @@
 mod dropdown_tests;
 mod rule_tests;
 mod save_validation_tests;
+mod tabula_validation_tests;
 mod validation_parser_tests;
 mod validators_tests;
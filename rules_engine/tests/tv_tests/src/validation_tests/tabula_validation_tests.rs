@@ -0,0 +1,227 @@
+use serde_json::{json, Value};
+use tv_lib::error::error_types::TvError;
+use tv_lib::toml::document_loader::TomlTableData;
+use tv_lib::validation::tabula_validation::{
+    card_table_kind, validate_changed_rows, validated_write, CardTableKind,
+};
+
+use crate::test_utils::harness::TvTestHarness;
+
+const CARD_TOML: &str = r#"[[test-cards]]
+name = "Test Dissolve"
+id = "5e4c7b8a-1f2d-4e3c-9a6b-0c1d2e3f4a5b"
+energy-cost = 2
+rules-text = "{Dissolve} an enemy."
+card-type = "Event"
+subtype = ""
+is-fast = false
+image-number = 1794244540
+variables = ""
+"#;
+
+fn card_headers() -> Vec<String> {
+    ["name", "id", "energy-cost", "rules-text", "card-type", "image-number", "variables"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn card_row(name: &str, id: &str, rules_text: &str, variables: &str) -> Vec<Value> {
+    vec![
+        json!(name),
+        json!(id),
+        json!(2),
+        json!(rules_text),
+        json!("Event"),
+        json!(1794244540),
+        json!(variables),
+    ]
+}
+
+fn table(rows: Vec<Vec<Value>>) -> TomlTableData {
+    TomlTableData { headers: card_headers(), rows }
+}
+
+fn valid_row() -> Vec<Value> {
+    card_row("Test Dissolve", "5e4c7b8a-1f2d-4e3c-9a6b-0c1d2e3f4a5b", "{Dissolve} an enemy.", "")
+}
+
+#[test]
+fn test_card_table_kind() {
+    assert_eq!(card_table_kind("cards"), Some(CardTableKind::Card));
+    assert_eq!(card_table_kind("test-cards"), Some(CardTableKind::Card));
+    assert_eq!(card_table_kind("test-dreamwell"), Some(CardTableKind::Dreamwell));
+    assert_eq!(card_table_kind("card-lists"), None);
+}
+
+#[test]
+fn test_valid_card_row_has_no_errors() {
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![valid_row()]));
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_unparseable_rules_text_is_reported() {
+    let mut row = valid_row();
+    row[3] = json!("{Dissolve} an enemy. Then juggle.");
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert!(!errors.is_empty());
+    assert!(errors.iter().all(|e| e.row == 0 && e.column.as_deref() == Some("rules-text")));
+    assert!(errors[0].message.starts_with("Ability 1:"), "Message: {}", errors[0].message);
+}
+
+#[test]
+fn test_unresolved_variable_is_reported() {
+    let row = card_row("Test Draw", "", "Draw {cards($c)}.", "");
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].column.as_deref(), Some("rules-text"));
+    assert!(errors[0].message.contains("Unresolved variable"), "Message: {}", errors[0].message);
+}
+
+#[test]
+fn test_invalid_variables_are_reported() {
+    let row = card_row("Test Draw", "", "Draw {cards($c)}.", "c = 2");
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].column.as_deref(), Some("variables"));
+}
+
+#[test]
+fn test_missing_required_field_is_reported() {
+    let mut row = valid_row();
+    row[4] = Value::Null;
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].column.as_deref(), Some("card-type"));
+}
+
+#[test]
+fn test_invalid_card_type_is_reported() {
+    let mut row = valid_row();
+    row[4] = json!("Sorcery");
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].column.as_deref(), Some("card-type"));
+}
+
+#[test]
+fn test_missing_id_is_allowed() {
+    let row = card_row("Test Dissolve", "", "{Dissolve} an enemy.", "");
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_duplicate_id_is_reported() {
+    let original = valid_row();
+    let copy = card_row("Test Copy", "5e4c7b8a-1f2d-4e3c-9a6b-0c1d2e3f4a5b", "", "");
+    let errors = validate_changed_rows(
+        "cards",
+        &table(vec![original.clone()]),
+        &table(vec![original, copy]),
+    );
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].row, 1);
+    assert_eq!(errors[0].column.as_deref(), Some("id"));
+    assert!(errors[0].message.contains("Test Dissolve"));
+}
+
+#[test]
+fn test_blank_row_is_not_validated() {
+    let row =
+        vec![Value::Null, json!(""), Value::Null, json!(""), Value::Null, Value::Null, json!("")];
+    let errors = validate_changed_rows("cards", &table(vec![]), &table(vec![row]));
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_unchanged_rows_are_not_validated() {
+    let mut invalid = valid_row();
+    invalid[4] = json!("Sorcery");
+    let previous = table(vec![invalid.clone(), valid_row()]);
+    let mut edited = valid_row();
+    edited[0] = json!("Test Dissolve Renamed");
+    let errors = validate_changed_rows("cards", &previous, &table(vec![invalid, edited]));
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_non_card_table_is_not_validated() {
+    let mut row = valid_row();
+    row[4] = json!("Sorcery");
+    let errors = validate_changed_rows("card-lists", &table(vec![]), &table(vec![row]));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_moved_rows_are_not_validated() {
+    let mut invalid = valid_row();
+    invalid[4] = json!("Sorcery");
+    let previous = table(vec![invalid.clone(), valid_row()]);
+    let errors = validate_changed_rows("cards", &previous, &table(vec![valid_row(), invalid]));
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_copied_row_is_validated() {
+    let previous = table(vec![valid_row()]);
+    let errors = validate_changed_rows("cards", &previous, &table(vec![valid_row(), valid_row()]));
+    assert_eq!(errors.len(), 1, "Expected one error: {errors:?}");
+    assert_eq!(errors[0].row, 1);
+    assert_eq!(errors[0].column.as_deref(), Some("id"));
+}
+
+#[test]
+fn test_validated_write_rejects_invalid_cell_and_restores_file() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("test-cards.toml", CARD_TOML);
+    let rules_text = json!("{Dissolve} an enemy. Then juggle.");
+
+    let result = validated_write(harness.config(), path.to_str().unwrap(), "test-cards", || {
+        harness.save_cell(&path, "test-cards", 0, "rules-text", rules_text)
+    });
+    let Err(TvError::CardValidationFailed { errors, .. }) = result else {
+        panic!("Expected CardValidationFailed, got {result:?}");
+    };
+    assert!(errors.iter().all(|e| e.column.as_deref() == Some("rules-text")));
+    assert_eq!(harness.read_file_content(&path), CARD_TOML);
+}
+
+#[test]
+fn test_validated_write_rejects_invalid_added_row() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("test-cards.toml", CARD_TOML);
+    let initial_values = [("name".to_string(), json!("Test Missing Fields"))].into();
+
+    let result = validated_write(harness.config(), path.to_str().unwrap(), "test-cards", || {
+        harness.add_row(&path, "test-cards", None, Some(initial_values))
+    });
+    assert!(matches!(result, Err(TvError::CardValidationFailed { .. })), "Got {result:?}");
+    assert_eq!(harness.read_file_content(&path), CARD_TOML);
+}
+
+#[test]
+fn test_validated_write_accepts_valid_edit() {
+    let harness = TvTestHarness::new();
+    let path = harness.create_toml_file("test-cards.toml", CARD_TOML);
+
+    validated_write(harness.config(), path.to_str().unwrap(), "test-cards", || {
+        harness.save_cell(&path, "test-cards", 0, "name", json!("Test Dissolve Renamed"))
+    })
+    .unwrap();
+    assert!(harness.read_file_content(&path).contains("Test Dissolve Renamed"));
+}
+
+#[test]
+fn test_validated_write_does_not_check_other_tables() {
+    let harness = TvTestHarness::new();
+    let content = CARD_TOML.replace("[[test-cards]]", "[[notes]]");
+    let path = harness.create_toml_file("notes.toml", &content);
+
+    validated_write(harness.config(), path.to_str().unwrap(), "notes", || {
+        harness.save_cell(&path, "notes", 0, "card-type", json!("Sorcery"))
+    })
+    .unwrap();
+    assert!(harness.read_file_content(&path).contains("Sorcery"));
+}
@@ -7,6 +7,8 @@ This script handles:
 1. Extracting XLSM files to directories for git storage
 2. Reconstructing XLSM files from directories
 3. Managing embedded images (replacing with placeholders for git, restoring from cache)
+4. Recording the cell and source URL of each stripped image, so that images which
+   cannot be restored are reported individually
 
 The key challenge is that Excel is extremely sensitive to ZIP file format details.
 This script carefully preserves:
@@ -20,9 +22,12 @@ import argparse
 import hashlib
 import json
 import os
+import posixpath
+import re
 import shutil
 import struct
 import sys
+import xml.etree.ElementTree as ET
 import zipfile
 from datetime import datetime
 from pathlib import Path
@@ -373,8 +378,12 @@ PLACEHOLDER_JPEG = bytes(
 )
 
 MANIFEST_FILENAME = "_xlsm_manifest.json"
+MANIFEST_VERSION = 2
 IMAGE_CACHE_DIRNAME = "xlsm_image_cache"
 
+IMAGE_FORMULA_PATTERN = re.compile(r'IMAGE\(\s*"([^"]+)"')
+CELL_REFERENCE_PATTERN = re.compile(r"([A-Z]+)(\d+)")
+
 
 SAFE_TO_FORMAT_XML = {
     "[Content_Types].xml",
@@ -419,6 +428,318 @@ def get_compression_for_file(filename: str) -> int:
     return zipfile.ZIP_DEFLATED
 
 
+def local_name(tag: str) -> str:
+    return tag.rsplit("}", 1)[-1]
+
+
+def attribute(element: ET.Element, name: str) -> str | None:
+    """Returns an attribute by local name, ignoring its namespace."""
+    for key, value in element.attrib.items():
+        if local_name(key) == name:
+            return value
+    return None
+
+
+def read_xml(zf: zipfile.ZipFile, part: str) -> ET.Element | None:
+    try:
+        return ET.fromstring(zf.read(part))
+    except (KeyError, ET.ParseError):
+        return None
+
+
+def read_relationships(zf: zipfile.ZipFile, part: str) -> dict[str, str]:
+    """
+    Returns the relationships of a package part, mapping relationship IDs to
+    target part paths. External targets such as URLs are returned unchanged.
+    """
+    directory, name = posixpath.split(part)
+    root = read_xml(zf, posixpath.join(directory, "_rels", f"{name}.rels"))
+    if root is None:
+        return {}
+
+    relationships = {}
+    for rel in root:
+        rel_id = rel.get("Id")
+        target = rel.get("Target")
+        if rel_id is None or target is None:
+            continue
+        if rel.get("TargetMode") == "External":
+            relationships[rel_id] = target
+        elif target.startswith("/"):
+            relationships[rel_id] = target.lstrip("/")
+        else:
+            relationships[rel_id] = posixpath.normpath(
+                posixpath.join(directory, target)
+            )
+    return relationships
+
+
+def column_letter(index: int) -> str:
+    """Converts a zero-based column index to a spreadsheet column name."""
+    letters = ""
+    index += 1
+    while index > 0:
+        index, remainder = divmod(index - 1, 26)
+        letters = chr(ord("A") + remainder) + letters
+    return letters
+
+
+def sheet_parts(zf: zipfile.ZipFile) -> list[tuple[str, str]]:
+    """Returns the name and package part path of each worksheet."""
+    workbook = read_xml(zf, "xl/workbook.xml")
+    if workbook is None:
+        return []
+    relationships = read_relationships(zf, "xl/workbook.xml")
+    sheets = []
+    for element in workbook.iter():
+        if local_name(element.tag) != "sheet":
+            continue
+        part = relationships.get(attribute(element, "id") or "")
+        if part:
+            sheets.append((element.get("name", part), part))
+    return sheets
+
+
+def rich_value_images(zf: zipfile.ZipFile) -> list[str | None]:
+    """
+    Returns the image part referenced by each cell value metadata record, for
+    images placed in cells.
+
+    Cells refer to these records via their 1-based "vm" attribute. Records
+    which do not refer to an image are None.
+    """
+    metadata = read_xml(zf, "xl/metadata.xml")
+    rich_values = read_xml(zf, "xl/richData/rdrichvalue.xml")
+    structures = read_xml(zf, "xl/richData/rdrichvaluestructure.xml")
+    rel_list = read_xml(zf, "xl/richData/richValueRel.xml")
+    if any(
+        part is None for part in (metadata, rich_values, structures, rel_list)
+    ):
+        return []
+
+    rel_targets = read_relationships(zf, "xl/richData/richValueRel.xml")
+    images = [rel_targets.get(attribute(rel, "id") or "") for rel in rel_list]
+
+    image_key_positions = []
+    for structure in structures:
+        keys = [key.get("n") for key in structure]
+        if "_rvRel:LocalImageIdentifier" in keys:
+            image_key_positions.append(keys.index("_rvRel:LocalImageIdentifier"))
+        else:
+            image_key_positions.append(None)
+
+    images_by_rich_value = []
+    for rich_value in rich_values:
+        structure = int(rich_value.get("s", "-1"))
+        position = (
+            image_key_positions[structure]
+            if 0 <= structure < len(image_key_positions)
+            else None
+        )
+        values = [value.text for value in rich_value if local_name(value.tag) == "v"]
+        image = None
+        if position is not None and position < len(values):
+            index = int(values[position] or "-1")
+            if 0 <= index < len(images):
+                image = images[index]
+        images_by_rich_value.append(image)
+
+    future_blocks = []
+    for element in metadata.iter():
+        if local_name(element.tag) != "futureMetadata":
+            continue
+        if element.get("name") != "XLRICHVALUE":
+            continue
+        for block in element:
+            if local_name(block.tag) != "bk":
+                continue
+            rvb = next((e for e in block.iter() if local_name(e.tag) == "rvb"), None)
+            future_blocks.append(int(rvb.get("i", "-1")) if rvb is not None else -1)
+
+    value_metadata_images = []
+    for element in metadata.iter():
+        if local_name(element.tag) != "valueMetadata":
+            continue
+        for block in element:
+            record = next((e for e in block if local_name(e.tag) == "rc"), None)
+            image = None
+            if record is not None:
+                future_index = int(record.get("v", "-1"))
+                if 0 <= future_index < len(future_blocks):
+                    rich_index = future_blocks[future_index]
+                    if 0 <= rich_index < len(images_by_rich_value):
+                        image = images_by_rich_value[rich_index]
+            value_metadata_images.append(image)
+    return value_metadata_images
+
+
+def locate_cell_images(
+    zf: zipfile.ZipFile,
+    sheet_name: str,
+    sheet_part: str,
+    cell_images: list[str | None],
+    locations: dict[str, list[dict]],
+) -> None:
+    if not cell_images:
+        return
+    sheet = read_xml(zf, sheet_part)
+    if sheet is None:
+        return
+    for cell in sheet.iter():
+        if local_name(cell.tag) != "c" or cell.get("vm") is None:
+            continue
+        index = int(cell.get("vm", "0")) - 1
+        if not 0 <= index < len(cell_images) or cell_images[index] is None:
+            continue
+        formula = next((e.text for e in cell if local_name(e.tag) == "f"), None)
+        match = IMAGE_FORMULA_PATTERN.search(formula or "")
+        locations.setdefault(cell_images[index], []).append(
+            {
+                "sheet": sheet_name,
+                "cell": cell.get("r"),
+                "source_url": match.group(1) if match else None,
+            }
+        )
+
+
+def locate_drawing_images(
+    zf: zipfile.ZipFile,
+    sheet_name: str,
+    sheet_part: str,
+    locations: dict[str, list[dict]],
+) -> None:
+    for drawing_part in read_relationships(zf, sheet_part).values():
+        if not drawing_part.startswith("xl/drawings/"):
+            continue
+        drawing = read_xml(zf, drawing_part)
+        if drawing is None:
+            continue
+        relationships = read_relationships(zf, drawing_part)
+        for anchor in drawing:
+            start = next((e for e in anchor if local_name(e.tag) == "from"), None)
+            blip = next((e for e in anchor.iter() if local_name(e.tag) == "blip"), None)
+            if blip is None:
+                continue
+            image = relationships.get(attribute(blip, "embed") or "")
+            if image is None:
+                continue
+            cell = None
+            if start is not None:
+                position = {local_name(e.tag): e.text for e in start}
+                column = column_letter(int(position.get("col") or 0))
+                cell = f"{column}{int(position.get('row') or 0) + 1}"
+            link = next(
+                (e for e in anchor.iter() if local_name(e.tag) == "hlinkClick"), None
+            )
+            source_url = None
+            if link is not None:
+                source_url = relationships.get(attribute(link, "id") or "")
+            if source_url is None:
+                source_url = relationships.get(attribute(blip, "link") or "")
+            locations.setdefault(image, []).append(
+                {"sheet": sheet_name, "cell": cell, "source_url": source_url}
+            )
+
+
+def locate_images(zf: zipfile.ZipFile) -> dict[str, list[dict]]:
+    """
+    Returns the cells each embedded image appears in, keyed by image part path.
+
+    Covers both images placed in cells (e.g. via the IMAGE function) and images
+    floating over cells in a drawing, anchored at their top-left cell.
+    """
+    locations: dict[str, list[dict]] = {}
+    cell_images = rich_value_images(zf)
+    for sheet_name, sheet_part in sheet_parts(zf):
+        locate_cell_images(zf, sheet_name, sheet_part, cell_images, locations)
+        locate_drawing_images(zf, sheet_name, sheet_part, locations)
+    for image_locations in locations.values():
+        image_locations.sort(
+            key=lambda location: (
+                location["sheet"],
+                cell_sort_key(location["cell"]),
+            )
+        )
+    return locations
+
+
+def cell_sort_key(cell: str | None) -> tuple[int, int]:
+    match = CELL_REFERENCE_PATTERN.fullmatch(cell or "")
+    if match is None:
+        return (0, 0)
+    column = 0
+    for letter in match.group(1):
+        column = column * 26 + ord(letter) - ord("A") + 1
+    return (int(match.group(2)), column)
+
+
+def describe_locations(image_info: dict) -> str:
+    locations = image_info.get("locations", [])
+    if not locations:
+        return "location unknown"
+    descriptions = []
+    for location in locations:
+        description = f"{location['sheet']}!{location['cell'] or '?'}"
+        if location.get("source_url"):
+            description += f" ({location['source_url']})"
+        descriptions.append(description)
+    return ", ".join(descriptions)
+
+
+def restore_image(
+    image_info: dict, image_cache_dir: Path | None
+) -> tuple[bytes | None, str | None]:
+    """
+    Looks up the original bytes of a stripped image in the image cache.
+
+    Returns the image data, or None and a reason the image could not be
+    restored.
+    """
+    if image_cache_dir is None:
+        return None, "no image cache configured"
+    cache_file = image_cache_dir / image_info["hash"]
+    if not cache_file.exists():
+        return None, "not found in image cache"
+    data = cache_file.read_bytes()
+    if hashlib.sha256(data).hexdigest() != image_info["hash"]:
+        return None, "cached image hash mismatch"
+    return data, None
+
+
+def report_unrestored_images(unrestored: list[dict]) -> None:
+    if not unrestored:
+        return
+    print(f"WARNING: {len(unrestored)} image(s) could not be restored:")
+    for entry in unrestored:
+        print(f"  {entry['filename']}: {entry['reason']} [{entry['locations']}]")
+
+
+def verify_image_cache(input_dir: Path, image_cache_dir: Path | None) -> list[dict]:
+    """
+    Checks that every image stripped from an extracted directory can be
+    restored from the image cache, returning the images which cannot.
+    """
+    manifest_path = input_dir / MANIFEST_FILENAME
+    if not manifest_path.exists():
+        raise FileNotFoundError(f"Manifest not found: {manifest_path}")
+
+    with open(manifest_path, "r", encoding="utf-8") as f:
+        manifest = json.load(f)
+
+    unrestored = []
+    for filename, image_info in manifest.get("images", {}).items():
+        _, reason = restore_image(image_info, image_cache_dir)
+        if reason is not None:
+            unrestored.append(
+                {
+                    "filename": filename,
+                    "reason": reason,
+                    "locations": describe_locations(image_info),
+                }
+            )
+    return unrestored
+
+
 def extract_xlsm_to_directory(
     xlsm_path: Path,
     output_dir: Path,
@@ -442,6 +763,7 @@ def extract_xlsm_to_directory(
     image_manifest = {}
 
     with zipfile.ZipFile(xlsm_path, "r") as zf:
+        image_locations = locate_images(zf) if strip_images else {}
         for info in zf.infolist():
             file_order.append(info.filename)
 
@@ -463,6 +785,7 @@ def extract_xlsm_to_directory(
                     "hash": file_hash,
                     "size": len(data),
                     "original_name": os.path.basename(info.filename),
+                    "locations": image_locations.get(info.filename, []),
                 }
 
                 if image_cache_dir:
@@ -478,7 +801,7 @@ def extract_xlsm_to_directory(
                 file_path.write_bytes(data)
 
     manifest = {
-        "version": 1,
+        "version": MANIFEST_VERSION,
         "file_order": file_order,
         "images": image_manifest,
         "source_file": xlsm_path.name,
@@ -499,7 +822,7 @@ def reconstruct_xlsm_from_directory(
     image_cache_dir: Path | None = None,
     restore_images: bool = True,
     quiet: bool = False,
-) -> None:
+) -> list[dict]:
     """
     Reconstruct an XLSM file from a directory structure.
 
@@ -508,6 +831,10 @@ def reconstruct_xlsm_from_directory(
         xlsm_path: Path for the output XLSM file
         image_cache_dir: Optional directory containing cached images
         restore_images: If True, attempt to restore original images from cache
+
+    Returns:
+        The images left as placeholders, with the reason each could not be
+        restored and the cells it appears in
     """
     manifest_path = input_dir / MANIFEST_FILENAME
     if not manifest_path.exists():
@@ -523,7 +850,7 @@ def reconstruct_xlsm_from_directory(
         xlsm_path.unlink()
 
     restored_count = 0
-    placeholder_count = 0
+    unrestored = []
 
     with zipfile.ZipFile(xlsm_path, "w", allowZip64=False) as zf:
         for filename in file_order:
@@ -537,20 +864,23 @@ def reconstruct_xlsm_from_directory(
 
             data = file_path.read_bytes()
 
-            if filename in image_manifest and restore_images and image_cache_dir:
+            if filename in image_manifest:
                 image_info = image_manifest[filename]
-                cache_file = image_cache_dir / image_info["hash"]
-                if cache_file.exists():
-                    cached_data = cache_file.read_bytes()
-                    if hashlib.sha256(cached_data).hexdigest() == image_info["hash"]:
-                        data = cached_data
-                        restored_count += 1
-                    else:
-                        placeholder_count += 1
+                if restore_images:
+                    cached_data, reason = restore_image(image_info, image_cache_dir)
                 else:
-                    placeholder_count += 1
-            elif filename in image_manifest:
-                placeholder_count += 1
+                    cached_data, reason = None, "restore disabled"
+                if cached_data is not None:
+                    data = cached_data
+                    restored_count += 1
+                else:
+                    unrestored.append(
+                        {
+                            "filename": filename,
+                            "reason": reason,
+                            "locations": describe_locations(image_info),
+                        }
+                    )
 
             info = zipfile.ZipInfo(filename, date_time=XLSM_TIMESTAMP)
             info.compress_type = get_compression_for_file(filename)
@@ -564,8 +894,13 @@ def reconstruct_xlsm_from_directory(
         print(f"Created {xlsm_path}")
         if image_manifest:
             print(
-                f"Images: {restored_count} restored from cache, {placeholder_count} using placeholders"
+                f"Images: {restored_count} restored from cache, "
+                f"{len(unrestored)} using placeholders"
             )
+        if restore_images:
+            report_unrestored_images(unrestored)
+
+    return unrestored
 
 
 def roundtrip_test(
@@ -825,6 +1160,20 @@ def main():
     reconstruct_parser.add_argument(
         "--no-restore", action="store_true", help="Do not restore images from cache"
     )
+    reconstruct_parser.add_argument(
+        "--strict",
+        action="store_true",
+        help="Exit with an error if any image could not be restored",
+    )
+
+    verify_parser = subparsers.add_parser(
+        "verify-images",
+        help="Check that every stripped image can be restored from the cache",
+    )
+    verify_parser.add_argument("input_dir", type=Path, help="Extracted directory")
+    verify_parser.add_argument(
+        "--image-cache", type=Path, help="Directory containing cached images"
+    )
 
     roundtrip_parser = subparsers.add_parser(
         "roundtrip", help="Test round-trip conversion"
@@ -853,12 +1202,20 @@ def main():
             strip_images=not args.keep_images,
         )
     elif args.command == "reconstruct":
-        reconstruct_xlsm_from_directory(
+        unrestored = reconstruct_xlsm_from_directory(
             args.input_dir,
             args.xlsm_path,
             image_cache_dir=args.image_cache,
             restore_images=not args.no_restore,
         )
+        if args.strict and unrestored:
+            sys.exit(1)
+    elif args.command == "verify-images":
+        unrestored = verify_image_cache(args.input_dir, args.image_cache)
+        if unrestored:
+            report_unrestored_images(unrestored)
+            sys.exit(1)
+        print("All images can be restored from the cache")
     elif args.command == "roundtrip":
         if args.xlsm_path.resolve() == args.output_path.resolve():
             print("ERROR: Output path must be different from source path")